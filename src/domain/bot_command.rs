@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+
+use super::completion_state::CompletionItem;
+
+/// A command a bot advertises for its chats (`/start`, `/help`, ...).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BotCommand {
+    /// Command text without the leading slash.
+    pub command: String,
+    pub description: String,
+}
+
+/// Completion candidates for the commands starting with `query`
+/// (case-insensitive), in the order the bots declared them.
+pub fn command_completions(commands: &[BotCommand], query: &str) -> Vec<CompletionItem> {
+    let query = query.to_lowercase();
+    commands
+        .iter()
+        .filter(|c| c.command.to_lowercase().starts_with(&query))
+        .map(|c| CompletionItem {
+            label: format!("/{}", c.command),
+            detail: c.description.clone(),
+            insert_text: format!("/{} ", c.command),
+            mention_user_id: None,
+        })
+        .collect()
+}
+
+/// Bot commands per chat, loaded once per session on first `/`.
+#[derive(Debug, Clone, Default)]
+pub struct BotCommandCache {
+    /// `None` while the request is in flight.
+    entries: HashMap<i64, Option<Vec<BotCommand>>>,
+}
+
+impl BotCommandCache {
+    /// Loaded commands of a chat; `None` if not loaded (yet).
+    pub fn get(&self, chat_id: i64) -> Option<&[BotCommand]> {
+        self.entries.get(&chat_id)?.as_deref()
+    }
+
+    /// Returns `true` and marks the chat as requested if its commands
+    /// were never requested before.
+    pub fn begin_request(&mut self, chat_id: i64) -> bool {
+        if self.entries.contains_key(&chat_id) {
+            return false;
+        }
+        self.entries.insert(chat_id, None);
+        true
+    }
+
+    pub fn store(&mut self, chat_id: i64, commands: Vec<BotCommand>) {
+        self.entries.insert(chat_id, Some(commands));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn command(name: &str, description: &str) -> BotCommand {
+        BotCommand {
+            command: name.to_owned(),
+            description: description.to_owned(),
+        }
+    }
+
+    #[test]
+    fn completions_filter_by_prefix_ignoring_case() {
+        let commands = vec![
+            command("start", "Start the bot"),
+            command("status", "Show status"),
+            command("help", "Show help"),
+        ];
+
+        let items = command_completions(&commands, "ST");
+
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].label, "/start");
+        assert_eq!(items[0].detail, "Start the bot");
+        assert_eq!(items[0].insert_text, "/start ");
+        assert_eq!(items[1].label, "/status");
+    }
+
+    #[test]
+    fn cache_requests_each_chat_once() {
+        let mut cache = BotCommandCache::default();
+
+        assert!(cache.begin_request(1));
+        assert!(!cache.begin_request(1));
+        assert_eq!(cache.get(1), None);
+
+        cache.store(1, vec![command("start", "")]);
+        assert_eq!(cache.get(1).map(<[BotCommand]>::len), Some(1));
+        assert!(!cache.begin_request(1));
+    }
+
+    #[test]
+    fn empty_query_offers_all_commands() {
+        let commands = vec![command("start", ""), command("help", "")];
+        assert_eq!(command_completions(&commands, "").len(), 2);
+    }
+}
@@ -0,0 +1,170 @@
+use super::message::{InlineButton, InlineButtonAction};
+
+/// Button-selection mode over a keyboard attached to one message
+/// (inline keyboards, custom reply keyboards).
+///
+/// Keeps the keyboard shape so that movement can be clamped without
+/// looking the message up again.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ButtonGridSelection<T> {
+    message_id: i64,
+    rows: Vec<Vec<T>>,
+    row: usize,
+    col: usize,
+}
+
+/// Selection over the inline keyboard of a bot message.
+pub type InlineButtonSelection = ButtonGridSelection<InlineButton>;
+
+/// Selection over a custom reply keyboard; buttons are the texts to send.
+pub type ReplyKeyboardSelection = ButtonGridSelection<String>;
+
+impl<T> ButtonGridSelection<T> {
+    /// Starts selection on the first button. Returns `None` when the
+    /// keyboard has no buttons.
+    pub fn new(message_id: i64, rows: Vec<Vec<T>>) -> Option<Self> {
+        if rows.iter().all(Vec::is_empty) {
+            return None;
+        }
+        let row = rows.iter().position(|r| !r.is_empty()).unwrap_or(0);
+        Some(Self {
+            message_id,
+            rows,
+            row,
+            col: 0,
+        })
+    }
+
+    pub fn message_id(&self) -> i64 {
+        self.message_id
+    }
+
+    /// `(row, column)` of the selected button.
+    pub fn position(&self) -> (usize, usize) {
+        (self.row, self.col)
+    }
+
+    pub fn selected(&self) -> &T {
+        &self.rows[self.row][self.col]
+    }
+
+    pub fn move_left(&mut self) {
+        self.col = self.col.saturating_sub(1);
+    }
+
+    pub fn move_right(&mut self) {
+        if self.col + 1 < self.rows[self.row].len() {
+            self.col += 1;
+        }
+    }
+
+    pub fn move_up(&mut self) {
+        if let Some(row) = (0..self.row).rev().find(|&r| !self.rows[r].is_empty()) {
+            self.select_row(row);
+        }
+    }
+
+    pub fn move_down(&mut self) {
+        if let Some(row) = (self.row + 1..self.rows.len()).find(|&r| !self.rows[r].is_empty()) {
+            self.select_row(row);
+        }
+    }
+
+    fn select_row(&mut self, row: usize) {
+        self.row = row;
+        self.col = self.col.min(self.rows[row].len() - 1);
+    }
+}
+
+impl InlineButtonSelection {
+    pub fn selected_action(&self) -> &InlineButtonAction {
+        &self.selected().action
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn button(text: &str) -> InlineButton {
+        InlineButton {
+            text: text.to_owned(),
+            action: InlineButtonAction::Callback {
+                data: text.to_owned(),
+            },
+        }
+    }
+
+    fn selection() -> InlineButtonSelection {
+        InlineButtonSelection::new(
+            7,
+            vec![
+                vec![button("a"), button("b"), button("c")],
+                vec![button("d")],
+            ],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn empty_keyboard_has_no_selection() {
+        assert!(InlineButtonSelection::new(7, vec![]).is_none());
+        assert!(InlineButtonSelection::new(7, vec![vec![]]).is_none());
+    }
+
+    #[test]
+    fn reply_keyboard_selection_skips_empty_rows() {
+        let mut s = ReplyKeyboardSelection::new(
+            3,
+            vec![
+                vec![],
+                vec!["Yes".to_owned()],
+                vec![],
+                vec!["No".to_owned()],
+            ],
+        )
+        .unwrap();
+        assert_eq!(s.selected(), "Yes");
+
+        s.move_down();
+        assert_eq!(s.position(), (3, 0));
+        assert_eq!(s.selected(), "No");
+    }
+
+    #[test]
+    fn starts_on_first_button() {
+        let s = selection();
+        assert_eq!(s.message_id(), 7);
+        assert_eq!(s.position(), (0, 0));
+        assert_eq!(s.selected().text, "a");
+    }
+
+    #[test]
+    fn horizontal_movement_is_clamped_to_row() {
+        let mut s = selection();
+        s.move_left();
+        assert_eq!(s.position(), (0, 0));
+
+        s.move_right();
+        s.move_right();
+        s.move_right();
+        assert_eq!(s.position(), (0, 2));
+    }
+
+    #[test]
+    fn vertical_movement_clamps_column_to_shorter_row() {
+        let mut s = selection();
+        s.move_right();
+        s.move_right();
+
+        s.move_down();
+        assert_eq!(s.position(), (1, 0));
+        assert_eq!(s.selected().text, "d");
+
+        s.move_down();
+        assert_eq!(s.position(), (1, 0));
+
+        s.move_up();
+        assert_eq!(s.position(), (0, 0));
+    }
+}
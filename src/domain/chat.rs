@@ -23,6 +23,46 @@ pub enum OutgoingReadStatus {
     },
 }
 
+/// Kind of unread marker to jump to inside an open chat.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnreadJumpKind {
+    /// A message mentioning the current user (or replying to them).
+    Mention,
+    /// An own message with reactions the user has not seen yet.
+    Reaction,
+}
+
+/// How long a chat stays muted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MuteDuration {
+    OneHour,
+    EightHours,
+    TwoDays,
+    Forever,
+}
+
+impl MuteDuration {
+    /// Value for TDLib's `mute_for`; anything over a year counts as forever.
+    pub fn seconds(self) -> i32 {
+        match self {
+            Self::OneHour => 60 * 60,
+            Self::EightHours => 8 * 60 * 60,
+            Self::TwoDays => 2 * 24 * 60 * 60,
+            Self::Forever => i32::MAX,
+        }
+    }
+
+    /// Completes "Muted <chat> ..." notifications.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::OneHour => "for 1 hour",
+            Self::EightHours => "for 8 hours",
+            Self::TwoDays => "for 2 days",
+            Self::Forever => "forever",
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ChatSummary {
     pub chat_id: i64,
@@ -45,6 +85,9 @@ pub struct ChatSummary {
     pub last_message_id: Option<i64>,
     /// Number of unread reactions on own messages in this chat.
     pub unread_reaction_count: u32,
+    /// Number of unread messages mentioning the current user (or replying
+    /// to their messages) in this chat.
+    pub unread_mention_count: u32,
     /// `true` if this chat is a supergroup forum (topics enabled).
     ///
     /// Forum chats are rendered as a topic list instead of a flat message
@@ -60,4 +103,15 @@ pub struct ChatSummary {
     /// messages across all topics and is not decremented on per-topic reads, so
     /// it is unreliable for forums (both wrong unit and stale).
     pub unread_topic_count: Option<u32>,
+    /// Whether notifications for the chat are muted. Muted chats get a
+    /// dimmed unread badge.
+    pub is_muted: bool,
+    /// Whether the user marked the chat as unread. Shown as an unread dot
+    /// when there are no unread messages.
+    pub is_marked_as_unread: bool,
+    /// Whether the user of a private chat is blocked.
+    pub is_blocked: bool,
+    /// Primary public username without `@`: the user's for private chats,
+    /// the chat's for public groups and channels.
+    pub username: Option<String>,
 }
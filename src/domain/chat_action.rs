@@ -0,0 +1,124 @@
+use std::time::{Duration, Instant};
+
+/// Activity broadcast to the other members of a chat
+/// ("typing...", "recording voice...").
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChatAction {
+    Typing,
+    RecordingVoiceNote,
+    UploadingVoiceNote,
+    /// Clears the currently broadcast action.
+    Cancel,
+}
+
+/// Telegram drops an action after ~5 seconds, so an ongoing activity
+/// is re-sent slightly more often than that.
+const RESEND_INTERVAL: Duration = Duration::from_secs(4);
+
+struct ActiveAction {
+    chat_id: i64,
+    topic_id: Option<i32>,
+    action: ChatAction,
+    sent_at: Instant,
+}
+
+/// Rate-limits outgoing chat actions and remembers whether one is active.
+#[derive(Default)]
+pub struct ChatActionThrottle {
+    active: Option<ActiveAction>,
+}
+
+impl ChatActionThrottle {
+    /// Records `action` as active and returns `true` if it should be sent
+    /// now: it differs from the active one or the resend interval elapsed.
+    pub fn begin(
+        &mut self,
+        chat_id: i64,
+        topic_id: Option<i32>,
+        action: ChatAction,
+        now: Instant,
+    ) -> bool {
+        if let Some(active) = &self.active {
+            if active.chat_id == chat_id
+                && active.topic_id == topic_id
+                && active.action == action
+                && now.duration_since(active.sent_at) < RESEND_INTERVAL
+            {
+                return false;
+            }
+        }
+
+        self.active = Some(ActiveAction {
+            chat_id,
+            topic_id,
+            action,
+            sent_at: now,
+        });
+        true
+    }
+
+    /// Clears the active action and returns the chat it was sent to,
+    /// so the caller can broadcast a cancel there.
+    pub fn finish(&mut self) -> Option<(i64, Option<i32>)> {
+        self.active.take().map(|a| (a.chat_id, a.topic_id))
+    }
+
+    /// Forgets the active action without cancelling it (e.g. after a
+    /// message was sent — Telegram clears the action by itself).
+    pub fn reset(&mut self) {
+        self.active = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_action_is_sent() {
+        let mut throttle = ChatActionThrottle::default();
+        assert!(throttle.begin(1, None, ChatAction::Typing, Instant::now()));
+    }
+
+    #[test]
+    fn repeated_action_is_throttled_until_resend_interval() {
+        let mut throttle = ChatActionThrottle::default();
+        let start = Instant::now();
+
+        assert!(throttle.begin(1, None, ChatAction::Typing, start));
+        assert!(!throttle.begin(1, None, ChatAction::Typing, start + Duration::from_secs(1)));
+        assert!(throttle.begin(1, None, ChatAction::Typing, start + RESEND_INTERVAL));
+    }
+
+    #[test]
+    fn different_action_or_chat_is_sent_immediately() {
+        let mut throttle = ChatActionThrottle::default();
+        let start = Instant::now();
+
+        assert!(throttle.begin(1, None, ChatAction::Typing, start));
+        assert!(throttle.begin(1, None, ChatAction::RecordingVoiceNote, start));
+        assert!(throttle.begin(2, None, ChatAction::RecordingVoiceNote, start));
+        assert!(throttle.begin(2, Some(7), ChatAction::RecordingVoiceNote, start));
+    }
+
+    #[test]
+    fn finish_returns_active_chat_once() {
+        let mut throttle = ChatActionThrottle::default();
+        throttle.begin(1, Some(3), ChatAction::Typing, Instant::now());
+
+        assert_eq!(throttle.finish(), Some((1, Some(3))));
+        assert_eq!(throttle.finish(), None);
+    }
+
+    #[test]
+    fn reset_forgets_action_without_cancel_target() {
+        let mut throttle = ChatActionThrottle::default();
+        let start = Instant::now();
+        throttle.begin(1, None, ChatAction::Typing, start);
+
+        throttle.reset();
+
+        assert_eq!(throttle.finish(), None);
+        assert!(throttle.begin(1, None, ChatAction::Typing, start));
+    }
+}
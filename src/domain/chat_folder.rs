@@ -0,0 +1,27 @@
+/// Which TDLib chat list the chat list panel shows.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ChatListId {
+    /// The main list with every non-archived chat.
+    #[default]
+    Main,
+    /// A user-defined chat folder.
+    Folder(i32),
+    /// Archived chats, opened from the "Archive" row of the main list.
+    Archive,
+}
+
+/// A chat folder as configured in Telegram (e.g. Work, Bots, Family).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChatFolder {
+    pub id: i32,
+    pub title: String,
+}
+
+/// One tab above the chat list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChatListTab {
+    pub list: ChatListId,
+    pub title: String,
+    /// Unread chats in the list, as reported by TDLib.
+    pub unread_count: u32,
+}
@@ -0,0 +1,209 @@
+//! Quick filters over the loaded chat list (unread only, private only, ...).
+//!
+//! Filters narrow what the chat list panel shows without changing what is
+//! loaded from TDLib; the chat type filters exclude each other.
+
+use super::chat::{ChatSummary, ChatType};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChatListFilter {
+    /// Chats with unread messages or marked as unread.
+    Unread,
+    /// Private chats with people (no bots).
+    Private,
+    Groups,
+    Channels,
+    Bots,
+    HideMuted,
+}
+
+impl ChatListFilter {
+    pub const ALL: [Self; 6] = [
+        Self::Unread,
+        Self::Private,
+        Self::Groups,
+        Self::Channels,
+        Self::Bots,
+        Self::HideMuted,
+    ];
+
+    /// Short label for the chat list header.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Unread => "unread",
+            Self::Private => "DMs",
+            Self::Groups => "groups",
+            Self::Channels => "channels",
+            Self::Bots => "bots",
+            Self::HideMuted => "-muted",
+        }
+    }
+
+    /// Filters on the chat type; only one of them can be on at a time.
+    fn is_type_filter(self) -> bool {
+        matches!(
+            self,
+            Self::Private | Self::Groups | Self::Channels | Self::Bots
+        )
+    }
+
+    fn matches(self, chat: &ChatSummary) -> bool {
+        match self {
+            Self::Unread => {
+                // Forums count unread topics; see `ChatSummary::unread_topic_count`.
+                let unread = if chat.is_forum {
+                    chat.unread_topic_count
+                        .map_or(chat.unread_count > 0, |count| count > 0)
+                } else {
+                    chat.unread_count > 0
+                };
+                unread || chat.is_marked_as_unread
+            }
+            Self::Private => chat.chat_type == ChatType::Private && !chat.is_bot,
+            Self::Groups => chat.chat_type == ChatType::Group,
+            Self::Channels => chat.chat_type == ChatType::Channel,
+            Self::Bots => chat.chat_type == ChatType::Private && chat.is_bot,
+            Self::HideMuted => !chat.is_muted,
+        }
+    }
+}
+
+/// The set of filters that are on.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChatListFilters {
+    active: Vec<ChatListFilter>,
+}
+
+impl ChatListFilters {
+    pub fn is_empty(&self) -> bool {
+        self.active.is_empty()
+    }
+
+    pub fn is_on(&self, filter: ChatListFilter) -> bool {
+        self.active.contains(&filter)
+    }
+
+    /// Turns `filter` on or off. Turning a chat type filter on turns the
+    /// other chat type filters off.
+    pub fn toggle(&mut self, filter: ChatListFilter) {
+        if self.is_on(filter) {
+            self.active.retain(|&f| f != filter);
+            return;
+        }
+        if filter.is_type_filter() {
+            self.active.retain(|f| !f.is_type_filter());
+        }
+        self.active.push(filter);
+    }
+
+    pub fn clear(&mut self) {
+        self.active.clear();
+    }
+
+    pub fn matches(&self, chat: &ChatSummary) -> bool {
+        self.active.iter().all(|filter| filter.matches(chat))
+    }
+
+    /// Compact summary for the chat list header, e.g. "unread DMs"; empty
+    /// when no filter is on.
+    pub fn indicator(&self) -> String {
+        ChatListFilter::ALL
+            .iter()
+            .filter(|&&filter| self.is_on(filter))
+            .map(|filter| filter.label())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::chat::OutgoingReadStatus;
+
+    fn chat(chat_type: ChatType, unread_count: u32) -> ChatSummary {
+        ChatSummary {
+            chat_id: 1,
+            title: "Chat".to_owned(),
+            unread_count,
+            last_message_preview: None,
+            last_message_unix_ms: None,
+            is_pinned: false,
+            chat_type,
+            last_message_sender: None,
+            is_online: None,
+            is_bot: false,
+            outgoing_status: OutgoingReadStatus::default(),
+            last_message_id: None,
+            unread_reaction_count: 0,
+            unread_mention_count: 0,
+            is_forum: false,
+            unread_topic_count: None,
+            is_muted: false,
+            is_marked_as_unread: false,
+            is_blocked: false,
+            username: None,
+        }
+    }
+
+    #[test]
+    fn no_filters_match_everything() {
+        let filters = ChatListFilters::default();
+
+        assert!(filters.matches(&chat(ChatType::Channel, 0)));
+        assert_eq!(filters.indicator(), "");
+    }
+
+    #[test]
+    fn unread_direct_messages() {
+        let mut filters = ChatListFilters::default();
+        filters.toggle(ChatListFilter::Private);
+        filters.toggle(ChatListFilter::Unread);
+
+        assert!(filters.matches(&chat(ChatType::Private, 2)));
+        assert!(!filters.matches(&chat(ChatType::Private, 0)));
+        assert!(!filters.matches(&chat(ChatType::Group, 2)));
+
+        let mut bot = chat(ChatType::Private, 2);
+        bot.is_bot = true;
+        assert!(!filters.matches(&bot));
+
+        let mut marked = chat(ChatType::Private, 0);
+        marked.is_marked_as_unread = true;
+        assert!(filters.matches(&marked));
+
+        assert_eq!(filters.indicator(), "unread DMs");
+    }
+
+    #[test]
+    fn chat_type_filters_exclude_each_other() {
+        let mut filters = ChatListFilters::default();
+        filters.toggle(ChatListFilter::Groups);
+        filters.toggle(ChatListFilter::HideMuted);
+        filters.toggle(ChatListFilter::Channels);
+
+        assert!(!filters.is_on(ChatListFilter::Groups));
+        assert!(filters.is_on(ChatListFilter::Channels));
+        assert!(filters.is_on(ChatListFilter::HideMuted));
+    }
+
+    #[test]
+    fn toggling_twice_turns_filter_off() {
+        let mut filters = ChatListFilters::default();
+        filters.toggle(ChatListFilter::HideMuted);
+        filters.toggle(ChatListFilter::HideMuted);
+
+        assert!(filters.is_empty());
+    }
+
+    #[test]
+    fn hide_muted_drops_muted_chats() {
+        let mut filters = ChatListFilters::default();
+        filters.toggle(ChatListFilter::HideMuted);
+        let mut muted = chat(ChatType::Group, 5);
+        muted.is_muted = true;
+
+        assert!(!filters.matches(&muted));
+        assert!(filters.matches(&chat(ChatType::Group, 5)));
+    }
+}
@@ -1,4 +1,8 @@
+use std::collections::{HashMap, HashSet};
+
 use super::chat::ChatSummary;
+use super::chat_folder::{ChatFolder, ChatListId, ChatListTab};
+use super::chat_list_filter::{ChatListFilter, ChatListFilters};
 use super::selectable_list::SelectableList;
 
 #[cfg_attr(not(test), allow(dead_code))]
@@ -14,13 +18,48 @@ use crate::usecases::list_chats::DEFAULT_CHAT_PAGE_SIZE;
 
 const LOAD_MORE_OFFSET: usize = 10;
 
+const MAIN_TAB_TITLE: &str = "All chats";
+
+/// Chat counts of a chat list as reported by TDLib.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct ListCounts {
+    unread: u32,
+    total: u32,
+}
+
+/// Selection and pagination of a chat list that is not on screen, restored
+/// when the user switches back to it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct SavedListView {
+    selected_chat_id: Option<i64>,
+    total_limit: usize,
+}
+
 #[cfg_attr(not(test), allow(dead_code))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ChatListState {
     ui_state: ChatListUiState,
+    /// The chats shown: the loaded chats that pass `filters`.
     list: SelectableList<ChatSummary>,
+    /// Every loaded chat, in TDLib order.
+    loaded: Vec<ChatSummary>,
+    filters: ChatListFilters,
     all_chats_loaded: bool,
     total_limit: usize,
+    /// The TDLib chat list shown: the main list or a folder.
+    active_list: ChatListId,
+    folders: Vec<ChatFolder>,
+    /// Position of the "All chats" tab among the folder tabs.
+    main_tab_position: usize,
+    counts: HashMap<ChatListId, ListCounts>,
+    /// The cursor is on the collapsed "Archive" row above the chats.
+    archive_row_selected: bool,
+    saved_views: HashMap<ChatListId, SavedListView>,
+    /// Chat to select once the list switched to has loaded.
+    restore_selection: Option<i64>,
+    /// Chats left or deleted while the request waits out the undo grace
+    /// period and runs; kept out of every reload.
+    hidden_chats: HashSet<i64>,
 }
 
 impl Default for ChatListState {
@@ -28,8 +67,18 @@ impl Default for ChatListState {
         Self {
             ui_state: ChatListUiState::Loading,
             list: SelectableList::default(),
+            loaded: Vec::new(),
+            filters: ChatListFilters::default(),
             all_chats_loaded: false,
             total_limit: DEFAULT_CHAT_PAGE_SIZE,
+            active_list: ChatListId::Main,
+            folders: Vec::new(),
+            main_tab_position: 0,
+            counts: HashMap::new(),
+            archive_row_selected: false,
+            saved_views: HashMap::new(),
+            restore_selection: None,
+            hidden_chats: HashSet::new(),
         }
     }
 }
@@ -47,12 +96,14 @@ impl ChatListState {
 
         let total_limit = chats.len().max(DEFAULT_CHAT_PAGE_SIZE);
         let mut list = SelectableList::default();
-        list.replace(chats, None);
+        list.replace(chats.clone(), None);
         Self {
             ui_state: ChatListUiState::Ready,
             all_chats_loaded: false,
             total_limit,
             list,
+            loaded: chats,
+            ..Self::default()
         }
     }
 }
@@ -71,7 +122,11 @@ impl ChatListState {
         self.list.selected_index()
     }
 
+    /// The chat under the cursor; `None` while the archive row is selected.
     pub fn selected_chat(&self) -> Option<&ChatSummary> {
+        if self.archive_row_selected() {
+            return None;
+        }
         self.list.selected()
     }
 
@@ -83,12 +138,15 @@ impl ChatListState {
         self.total_limit
     }
 
+    /// Whether the cursor is close enough to the end of the shown chats
+    /// that the next page should be fetched. With filters on, a filtered
+    /// list without any match also keeps paging until everything is loaded.
     pub fn needs_more_chats(&self) -> bool {
         if self.all_chats_loaded {
             return false;
         }
         let Some(index) = self.list.selected_index() else {
-            return false;
+            return self.ui_state == ChatListUiState::Ready && !self.filters.is_empty();
         };
         let last = self.list.items().len().saturating_sub(1);
         last.saturating_sub(index) < LOAD_MORE_OFFSET
@@ -105,80 +163,329 @@ impl ChatListState {
     pub fn set_loading(&mut self) {
         self.ui_state = ChatListUiState::Loading;
         self.list.clear();
+        self.loaded.clear();
         self.all_chats_loaded = false;
         self.total_limit = DEFAULT_CHAT_PAGE_SIZE;
     }
 
     pub fn set_ready(&mut self, chats: Vec<ChatSummary>) {
-        let previous_selected_chat_id = self.selected_chat().map(|chat| chat.chat_id);
+        let restore = self.restore_selection.take();
+        let previous_selected_chat_id = self.selected_chat().map(|chat| chat.chat_id).or(restore);
         self.set_ready_with_selection_hint(chats, previous_selected_chat_id);
     }
 
     fn set_ready_with_selection_hint(
         &mut self,
-        chats: Vec<ChatSummary>,
+        mut chats: Vec<ChatSummary>,
         preferred_chat_id: Option<i64>,
     ) {
+        chats.retain(|chat| !self.hidden_chats.contains(&chat.chat_id));
         if chats.is_empty() {
             self.set_empty();
             return;
         }
 
-        let preferred_index = preferred_chat_id
-            .and_then(|chat_id| chats.iter().position(|chat| chat.chat_id == chat_id));
-        self.list.replace(chats, preferred_index);
+        self.loaded = chats;
+        self.apply_filters(preferred_chat_id);
         self.ui_state = ChatListUiState::Ready;
     }
 
+    /// Rebuilds the shown chats from the loaded ones. The preferred chat
+    /// stays selected when it passes the filters; otherwise the closest
+    /// shown chat below it (or above, at the end) is selected.
+    fn apply_filters(&mut self, preferred_chat_id: Option<i64>) {
+        let preferred_pos = preferred_chat_id
+            .and_then(|chat_id| self.loaded.iter().position(|c| c.chat_id == chat_id));
+        let mut shown = Vec::new();
+        let mut preferred_index = None;
+        for (pos, chat) in self.loaded.iter().enumerate() {
+            if !self.filters.matches(chat) {
+                continue;
+            }
+            if preferred_index.is_none() && preferred_pos.is_some_and(|p| pos >= p) {
+                preferred_index = Some(shown.len());
+            }
+            shown.push(chat.clone());
+        }
+        if preferred_pos.is_some() && preferred_index.is_none() {
+            preferred_index = shown.len().checked_sub(1);
+        }
+        self.list.replace(shown, preferred_index);
+    }
+
+    /// Every loaded chat of the shown list, filters aside.
+    pub fn loaded_chats(&self) -> &[ChatSummary] {
+        &self.loaded
+    }
+
+    pub fn filters(&self) -> &ChatListFilters {
+        &self.filters
+    }
+
+    /// Turns a filter on or off, keeping the selected chat when it is
+    /// still shown.
+    pub fn toggle_filter(&mut self, filter: ChatListFilter) {
+        self.filters.toggle(filter);
+        self.refilter();
+    }
+
+    /// Turns all filters off. Returns `false` when none was on.
+    pub fn clear_filters(&mut self) -> bool {
+        if self.filters.is_empty() {
+            return false;
+        }
+        self.filters.clear();
+        self.refilter();
+        true
+    }
+
+    fn refilter(&mut self) {
+        if self.ui_state != ChatListUiState::Ready {
+            return;
+        }
+        let selected = self.selected_chat().map(|chat| chat.chat_id);
+        self.apply_filters(selected);
+    }
+
+    /// Applies `patch` to the chat with `chat_id`, both in the loaded and
+    /// the shown chats.
+    fn patch_chat(&mut self, chat_id: i64, patch: impl Fn(&mut ChatSummary)) {
+        let shown = self.list.items_mut().iter_mut();
+        for chat in shown.chain(self.loaded.iter_mut()) {
+            if chat.chat_id == chat_id {
+                patch(chat);
+            }
+        }
+    }
+
+    pub fn active_list(&self) -> ChatListId {
+        self.active_list
+    }
+
+    pub fn has_folders(&self) -> bool {
+        !self.folders.is_empty()
+    }
+
+    /// Tabs in display order: the folders with "All chats" at the position
+    /// the user chose in Telegram.
+    pub fn tabs(&self) -> Vec<ChatListTab> {
+        let mut tabs: Vec<ChatListTab> = self
+            .folders
+            .iter()
+            .map(|folder| {
+                let list = ChatListId::Folder(folder.id);
+                ChatListTab {
+                    list,
+                    title: folder.title.clone(),
+                    unread_count: self.unread_count(list),
+                }
+            })
+            .collect();
+        let main_position = self.main_tab_position.min(tabs.len());
+        tabs.insert(
+            main_position,
+            ChatListTab {
+                list: ChatListId::Main,
+                title: MAIN_TAB_TITLE.to_owned(),
+                unread_count: self.unread_count(ChatListId::Main),
+            },
+        );
+        tabs
+    }
+
+    pub fn unread_count(&self, list: ChatListId) -> u32 {
+        self.counts.get(&list).map_or(0, |counts| counts.unread)
+    }
+
+    pub fn set_chat_counts(&mut self, list: ChatListId, unread: u32, total: u32) {
+        self.counts.insert(list, ListCounts { unread, total });
+    }
+
+    /// The main list shows a collapsed "Archive" row on top while there are
+    /// archived chats.
+    pub fn shows_archive_row(&self) -> bool {
+        self.active_list == ChatListId::Main
+            && self
+                .counts
+                .get(&ChatListId::Archive)
+                .is_some_and(|counts| counts.total > 0)
+    }
+
+    pub fn archive_row_selected(&self) -> bool {
+        self.archive_row_selected && self.shows_archive_row()
+    }
+
+    /// Opens the archived chats in place of the main list.
+    pub fn open_archive(&mut self) {
+        self.show_list(ChatListId::Archive);
+    }
+
+    /// Returns to the main list. Returns `false` when the archive is not
+    /// open.
+    pub fn close_archive(&mut self) -> bool {
+        if self.active_list != ChatListId::Archive {
+            return false;
+        }
+        self.show_list(ChatListId::Main);
+        true
+    }
+
+    /// Drops a chat from the list right away (e.g. after archiving it) and
+    /// keeps the cursor at the same position; the next refresh confirms.
+    pub fn remove_chat(&mut self, chat_id: i64) {
+        self.loaded.retain(|c| c.chat_id != chat_id);
+        let Some(pos) = self.list.items().iter().position(|c| c.chat_id == chat_id) else {
+            return;
+        };
+        let mut chats = self.list.items().to_vec();
+        chats.remove(pos);
+        if self.loaded.is_empty() {
+            self.set_empty();
+            return;
+        }
+        let preferred = self
+            .list
+            .selected_index()
+            .map(|idx| if idx > pos { idx - 1 } else { idx })
+            .map(|idx| idx.min(chats.len().saturating_sub(1)));
+        self.list.replace(chats, preferred);
+    }
+
+    /// Removes a chat like [`remove_chat`](Self::remove_chat) and keeps it
+    /// out of later reloads until [`unhide_chat`](Self::unhide_chat).
+    pub fn hide_chat(&mut self, chat_id: i64) {
+        self.hidden_chats.insert(chat_id);
+        self.remove_chat(chat_id);
+    }
+
+    /// Stops hiding a chat; the next reload shows it again if it still
+    /// exists.
+    pub fn unhide_chat(&mut self, chat_id: i64) {
+        self.hidden_chats.remove(&chat_id);
+    }
+
+    /// Replaces the folders. Returns `true` when the active folder was
+    /// deleted and the main list is shown instead, which needs a reload.
+    pub fn set_folders(&mut self, folders: Vec<ChatFolder>, main_tab_position: usize) -> bool {
+        self.folders = folders;
+        self.main_tab_position = main_tab_position;
+        self.saved_views.retain(|list, _| match list {
+            ChatListId::Main | ChatListId::Archive => true,
+            ChatListId::Folder(id) => self.folders.iter().any(|f| f.id == *id),
+        });
+
+        let ChatListId::Folder(active_id) = self.active_list else {
+            return false;
+        };
+        if self.folders.iter().any(|f| f.id == active_id) {
+            return false;
+        }
+        self.show_list(ChatListId::Main);
+        true
+    }
+
+    /// Moves to the next (or previous) tab, wrapping around. Returns `false`
+    /// when there are no folders to move between.
+    pub fn cycle_list(&mut self, forward: bool) -> bool {
+        if self.folders.is_empty() {
+            return false;
+        }
+        let tabs = self.tabs();
+        let len = tabs.len();
+        let current = tabs
+            .iter()
+            .position(|tab| tab.list == self.active_list)
+            .unwrap_or(0);
+        let next = if forward {
+            (current + 1) % len
+        } else {
+            (current + len - 1) % len
+        };
+        self.show_list(tabs[next].list);
+        true
+    }
+
+    /// Switches to another chat list and starts loading it. The current
+    /// list's selection and pagination are kept for when the user returns.
+    pub fn show_list(&mut self, list: ChatListId) {
+        if list == self.active_list {
+            return;
+        }
+        self.saved_views.insert(
+            self.active_list,
+            SavedListView {
+                selected_chat_id: self.selected_chat().map(|chat| chat.chat_id),
+                total_limit: self.total_limit,
+            },
+        );
+        let saved = self.saved_views.remove(&list);
+
+        self.active_list = list;
+        self.set_loading();
+        if let Some(saved) = saved {
+            self.total_limit = saved.total_limit;
+            self.restore_selection = saved.selected_chat_id;
+        }
+    }
+
     pub fn set_empty(&mut self) {
         self.ui_state = ChatListUiState::Empty;
         self.list.clear();
+        self.loaded.clear();
         self.all_chats_loaded = true;
     }
 
     pub fn set_error(&mut self) {
         self.ui_state = ChatListUiState::Error;
         self.list.clear();
+        self.loaded.clear();
         self.all_chats_loaded = false;
     }
 
     pub fn select_next(&mut self) {
+        if self.archive_row_selected() {
+            self.archive_row_selected = false;
+            return;
+        }
         self.list.select_next();
     }
 
     pub fn select_first(&mut self) {
         self.list.select_first();
+        self.archive_row_selected = self.shows_archive_row();
     }
 
     pub fn select_previous(&mut self) {
+        let at_top = matches!(self.list.selected_index(), None | Some(0));
+        if at_top && self.shows_archive_row() {
+            self.archive_row_selected = true;
+            return;
+        }
         self.list.select_previous();
     }
 
-    pub fn select_by_query(&mut self, query: &str) -> bool {
-        let chats = self.list.items();
-        if chats.is_empty() || query.is_empty() {
-            return false;
-        }
-        let query_lower = query.to_lowercase();
-        let start = self.list.selected_index().unwrap_or(0);
-        let len = chats.len();
-        for offset in 0..len {
-            let idx = (start + offset) % len;
-            if chats[idx].title.to_lowercase().contains(&query_lower) {
+    /// Selects the chat with `chat_id`; returns `false` if it is not listed.
+    pub fn select_chat(&mut self, chat_id: i64) -> bool {
+        match self.list.items().iter().position(|c| c.chat_id == chat_id) {
+            Some(idx) => {
+                self.archive_row_selected = false;
                 self.list.set_selected_index(Some(idx));
-                return true;
+                true
             }
+            None => false,
         }
-        false
     }
 
     pub fn clear_selected_chat_unread(&mut self) {
-        if let Some(chat) = self.list.selected_mut() {
+        let Some(chat_id) = self.selected_chat().map(|chat| chat.chat_id) else {
+            return;
+        };
+        self.patch_chat(chat_id, |chat| {
             chat.unread_count = 0;
             if chat.is_forum {
                 chat.unread_topic_count = Some(0);
             }
-        }
+        });
     }
 
     /// Sets a forum chat's unread-topic count directly.
@@ -187,16 +494,11 @@ impl ChatListState {
     /// memory (e.g. when leaving a forum) without a `getForumTopics` round-trip.
     /// No-op for non-forum chats and unknown ids.
     pub fn set_forum_unread_topic_count(&mut self, chat_id: i64, count: u32) {
-        if let Some(chat) = self
-            .list
-            .items_mut()
-            .iter_mut()
-            .find(|chat| chat.chat_id == chat_id)
-        {
+        self.patch_chat(chat_id, |chat| {
             if chat.is_forum {
                 chat.unread_topic_count = Some(count);
             }
-        }
+        });
     }
 
     /// Optimistically decrements a forum chat's unread-topic count by one.
@@ -208,16 +510,63 @@ impl ChatListState {
     /// until the next chat-list refresh recomputes the authoritative value from
     /// `getForumTopics`. No-op when the count is unknown (`None`).
     pub fn mark_forum_topic_read(&mut self, chat_id: i64) {
-        if let Some(chat) = self
-            .list
-            .items_mut()
-            .iter_mut()
-            .find(|chat| chat.chat_id == chat_id)
-        {
+        self.patch_chat(chat_id, |chat| {
             if let Some(count) = chat.unread_topic_count.as_mut() {
                 *count = count.saturating_sub(1);
             }
+        });
+    }
+
+    /// Optimistically flips a chat's mute state until TDLib's notification
+    /// settings update refreshes the list.
+    pub fn set_chat_muted(&mut self, chat_id: i64, muted: bool) {
+        self.patch_chat(chat_id, |chat| chat.is_muted = muted);
+    }
+
+    /// Optimistically sets a chat's "marked as unread" flag.
+    pub fn set_chat_marked_as_unread(&mut self, chat_id: i64, marked: bool) {
+        self.patch_chat(chat_id, |chat| chat.is_marked_as_unread = marked);
+    }
+
+    /// Optimistically sets whether a private chat's user is blocked.
+    pub fn set_chat_blocked(&mut self, chat_id: i64, blocked: bool) {
+        self.patch_chat(chat_id, |chat| chat.is_blocked = blocked);
+    }
+
+    /// Swaps the selected pinned chat with the pinned chat above (`up`) or
+    /// below it, keeping it selected. Returns the new order of the pinned
+    /// chats, or `None` when the selected chat is not pinned or already at
+    /// the edge of the pinned section.
+    pub fn move_selected_pinned(&mut self, up: bool) -> Option<Vec<i64>> {
+        if self.archive_row_selected() {
+            return None;
+        }
+        let idx = self.list.selected_index()?;
+        let chats = self.list.items();
+        if !chats.get(idx)?.is_pinned {
+            return None;
+        }
+        let target = if up { idx.checked_sub(1)? } else { idx + 1 };
+        if !chats.get(target)?.is_pinned {
+            return None;
+        }
+
+        let (moved_id, other_id) = (chats[idx].chat_id, chats[target].chat_id);
+        self.list.items_mut().swap(idx, target);
+        self.list.set_selected_index(Some(target));
+
+        // Pinned chats hidden by the filters keep their place in the order.
+        let loaded_pos = |id: i64| self.loaded.iter().position(|c| c.chat_id == id);
+        if let (Some(a), Some(b)) = (loaded_pos(moved_id), loaded_pos(other_id)) {
+            self.loaded.swap(a, b);
         }
+        Some(
+            self.loaded
+                .iter()
+                .filter(|chat| chat.is_pinned)
+                .map(|chat| chat.chat_id)
+                .collect(),
+        )
     }
 }
 
@@ -241,8 +590,13 @@ mod tests {
             outgoing_status: OutgoingReadStatus::default(),
             last_message_id: None,
             unread_reaction_count: 0,
+            unread_mention_count: 0,
             is_forum: false,
             unread_topic_count: None,
+            is_muted: false,
+            is_marked_as_unread: false,
+            is_blocked: false,
+            username: None,
         }
     }
 
@@ -419,6 +773,48 @@ mod tests {
         assert_eq!(state.chats()[0].unread_count, 3);
     }
 
+    #[test]
+    fn set_chat_blocked_patches_only_that_chat() {
+        let mut state = ChatListState::default();
+        state.set_ready(vec![chat(1, "Alice"), chat(2, "Bob")]);
+
+        state.set_chat_blocked(1, true);
+
+        assert!(state.chats()[0].is_blocked);
+        assert!(!state.chats()[1].is_blocked);
+    }
+
+    #[test]
+    fn set_chat_muted_patches_only_that_chat() {
+        let mut state = ChatListState::default();
+        state.set_ready(vec![chat(1, "General"), chat(2, "News")]);
+
+        state.set_chat_muted(2, true);
+        state.set_chat_muted(99, true);
+
+        assert!(!state.chats()[0].is_muted);
+        assert!(state.chats()[1].is_muted);
+    }
+
+    #[test]
+    fn move_selected_pinned_stays_within_pinned_section() {
+        let pinned = |chat_id, title| {
+            let mut c = chat(chat_id, title);
+            c.is_pinned = true;
+            c
+        };
+        let mut state = ChatListState::default();
+        state.set_ready(vec![pinned(1, "A"), pinned(2, "B"), chat(3, "C")]);
+
+        assert_eq!(state.move_selected_pinned(true), None);
+        assert_eq!(state.move_selected_pinned(false), Some(vec![2, 1]));
+        assert_eq!(state.selected_chat().map(|c| c.chat_id), Some(1));
+        assert_eq!(state.move_selected_pinned(false), None);
+
+        state.select_next();
+        assert_eq!(state.move_selected_pinned(true), None);
+    }
+
     #[test]
     fn clear_selected_chat_unread_noop_without_selection() {
         let mut state = ChatListState::default();
@@ -477,58 +873,13 @@ mod tests {
     }
 
     #[test]
-    fn select_by_query_finds_match_forward() {
-        let mut state = ChatListState::default();
-        state.set_ready(vec![chat(1, "Alice"), chat(2, "Bob"), chat(3, "Charlie")]);
-        assert!(state.select_by_query("bob"));
-        assert_eq!(state.selected_index(), Some(1));
-    }
-
-    #[test]
-    fn select_by_query_case_insensitive() {
+    fn select_chat_selects_listed_chat_only() {
         let mut state = ChatListState::default();
         state.set_ready(vec![chat(1, "Alice"), chat(2, "Bob")]);
-        assert!(state.select_by_query("BOB"));
+        assert!(state.select_chat(2));
+        assert_eq!(state.selected_index(), Some(1));
+        assert!(!state.select_chat(99));
         assert_eq!(state.selected_index(), Some(1));
-    }
-
-    #[test]
-    fn select_by_query_wraps_around() {
-        let mut state = ChatListState::default();
-        state.set_ready(vec![chat(1, "Alice"), chat(2, "Bob"), chat(3, "Charlie")]);
-        state.select_next();
-        state.select_next(); // now at Charlie (index 2)
-        assert!(state.select_by_query("alice"));
-        assert_eq!(state.selected_index(), Some(0));
-    }
-
-    #[test]
-    fn select_by_query_no_match_returns_false() {
-        let mut state = ChatListState::default();
-        state.set_ready(vec![chat(1, "Alice"), chat(2, "Bob")]);
-        assert!(!state.select_by_query("xyz"));
-        assert_eq!(state.selected_index(), Some(0));
-    }
-
-    #[test]
-    fn select_by_query_empty_query_returns_false() {
-        let mut state = ChatListState::default();
-        state.set_ready(vec![chat(1, "Alice")]);
-        assert!(!state.select_by_query(""));
-    }
-
-    #[test]
-    fn select_by_query_empty_list_returns_false() {
-        let mut state = ChatListState::default();
-        assert!(!state.select_by_query("alice"));
-    }
-
-    #[test]
-    fn select_by_query_substring_match() {
-        let mut state = ChatListState::default();
-        state.set_ready(vec![chat(1, "Alice Johnson"), chat(2, "Bob Smith")]);
-        assert!(state.select_by_query("john"));
-        assert_eq!(state.selected_index(), Some(0));
     }
 
     #[test]
@@ -599,4 +950,235 @@ mod tests {
         state.set_empty();
         assert!(state.all_chats_loaded());
     }
+
+    fn folder(id: i32, title: &str) -> ChatFolder {
+        ChatFolder {
+            id,
+            title: title.to_owned(),
+        }
+    }
+
+    #[test]
+    fn tabs_place_main_list_at_its_position() {
+        let mut state = ChatListState::default();
+        state.set_folders(vec![folder(3, "Work"), folder(5, "Bots")], 1);
+        state.set_chat_counts(ChatListId::Folder(5), 4, 9);
+
+        let tabs = state.tabs();
+
+        let titles: Vec<&str> = tabs.iter().map(|tab| tab.title.as_str()).collect();
+        assert_eq!(titles, vec!["Work", "All chats", "Bots"]);
+        assert_eq!(tabs[2].unread_count, 4);
+        assert_eq!(tabs[1].list, ChatListId::Main);
+    }
+
+    #[test]
+    fn cycle_list_wraps_and_needs_folders() {
+        let mut state = ChatListState::default();
+        assert!(!state.cycle_list(true));
+
+        state.set_folders(vec![folder(3, "Work")], 0);
+        assert!(state.cycle_list(true));
+        assert_eq!(state.active_list(), ChatListId::Folder(3));
+        assert_eq!(state.ui_state(), ChatListUiState::Loading);
+
+        assert!(state.cycle_list(true));
+        assert_eq!(state.active_list(), ChatListId::Main);
+        assert!(state.cycle_list(false));
+        assert_eq!(state.active_list(), ChatListId::Folder(3));
+    }
+
+    #[test]
+    fn switching_back_restores_selection_and_pagination() {
+        let mut state = ChatListState::default();
+        state.set_folders(vec![folder(3, "Work")], 0);
+        state.set_ready(vec![chat(1, "General"), chat(2, "Backend")]);
+        state.select_next();
+        state.request_more_chats();
+
+        state.show_list(ChatListId::Folder(3));
+        assert_eq!(state.total_limit(), 50);
+        state.set_ready(vec![chat(7, "Standup")]);
+
+        state.show_list(ChatListId::Main);
+        assert_eq!(state.total_limit(), 100);
+        state.set_ready(vec![chat(1, "General"), chat(2, "Backend")]);
+
+        assert_eq!(state.selected_chat().map(|item| item.chat_id), Some(2));
+    }
+
+    #[test]
+    fn removing_active_folder_falls_back_to_main_list() {
+        let mut state = ChatListState::default();
+        state.set_folders(vec![folder(3, "Work"), folder(5, "Bots")], 0);
+        state.show_list(ChatListId::Folder(5));
+
+        assert!(!state.set_folders(vec![folder(5, "Robots")], 0));
+        assert_eq!(state.active_list(), ChatListId::Folder(5));
+
+        assert!(state.set_folders(vec![folder(3, "Work")], 0));
+        assert_eq!(state.active_list(), ChatListId::Main);
+        assert_eq!(state.ui_state(), ChatListUiState::Loading);
+    }
+
+    #[test]
+    fn archive_row_sits_above_first_chat() {
+        let mut state = ChatListState::default();
+        state.set_ready(vec![chat(1, "General"), chat(2, "Random")]);
+        state.select_previous();
+        assert!(!state.archive_row_selected());
+
+        state.set_chat_counts(ChatListId::Archive, 1, 4);
+        state.select_previous();
+        assert!(state.archive_row_selected());
+        assert!(state.selected_chat().is_none());
+
+        state.select_next();
+        assert_eq!(state.selected_chat().map(|c| c.chat_id), Some(1));
+        state.select_first();
+        assert!(state.archive_row_selected());
+    }
+
+    #[test]
+    fn archive_opens_in_place_of_main_list() {
+        let mut state = ChatListState::default();
+        state.set_chat_counts(ChatListId::Archive, 0, 2);
+        state.set_ready(vec![chat(1, "General")]);
+        assert!(!state.close_archive());
+
+        state.open_archive();
+        assert_eq!(state.active_list(), ChatListId::Archive);
+        assert!(!state.shows_archive_row());
+
+        assert!(state.close_archive());
+        assert_eq!(state.active_list(), ChatListId::Main);
+    }
+
+    #[test]
+    fn remove_chat_keeps_cursor_in_place() {
+        let mut state = ChatListState::default();
+        state.set_ready(vec![chat(1, "A"), chat(2, "B"), chat(3, "C")]);
+        state.select_next();
+
+        state.remove_chat(2);
+        assert_eq!(state.selected_chat().map(|c| c.chat_id), Some(3));
+
+        state.remove_chat(1);
+        state.remove_chat(3);
+        assert_eq!(state.ui_state(), ChatListUiState::Empty);
+    }
+
+    #[test]
+    fn hidden_chat_stays_out_of_reloads_until_unhidden() {
+        let mut state = ChatListState::default();
+        state.set_ready(vec![chat(1, "A"), chat(2, "B")]);
+
+        state.hide_chat(2);
+        state.set_ready(vec![chat(1, "A"), chat(2, "B")]);
+        assert_eq!(state.loaded_chats().len(), 1);
+
+        state.unhide_chat(2);
+        state.set_ready(vec![chat(1, "A"), chat(2, "B")]);
+        assert_eq!(state.chats().len(), 2);
+    }
+
+    fn unread(chat_id: i64, title: &str) -> ChatSummary {
+        ChatSummary {
+            unread_count: 2,
+            ..chat(chat_id, title)
+        }
+    }
+
+    #[test]
+    fn filters_hide_chats_and_keep_selected_chat() {
+        let mut state = ChatListState::default();
+        state.set_ready(vec![chat(1, "A"), unread(2, "B"), unread(3, "C")]);
+        state.select_next();
+        state.select_next();
+
+        state.toggle_filter(ChatListFilter::Unread);
+
+        assert_eq!(state.chats().len(), 2);
+        assert_eq!(state.selected_chat().map(|c| c.chat_id), Some(3));
+
+        assert!(state.clear_filters());
+        assert_eq!(state.chats().len(), 3);
+        assert_eq!(state.selected_chat().map(|c| c.chat_id), Some(3));
+        assert!(!state.clear_filters());
+    }
+
+    #[test]
+    fn hidden_selected_chat_moves_selection_to_next_match() {
+        let mut state = ChatListState::default();
+        state.set_ready(vec![unread(1, "A"), chat(2, "B"), unread(3, "C")]);
+        state.select_next();
+
+        state.toggle_filter(ChatListFilter::Unread);
+
+        assert_eq!(state.selected_chat().map(|c| c.chat_id), Some(3));
+    }
+
+    #[test]
+    fn refresh_keeps_filters_and_selection() {
+        let mut state = ChatListState::default();
+        state.set_ready(vec![unread(1, "A"), unread(2, "B")]);
+        state.toggle_filter(ChatListFilter::Unread);
+        state.select_next();
+
+        state.set_ready(vec![chat(1, "A"), unread(2, "B"), unread(3, "C")]);
+
+        assert_eq!(state.chats().len(), 2);
+        assert_eq!(state.selected_chat().map(|c| c.chat_id), Some(2));
+    }
+
+    #[test]
+    fn filtered_patches_reach_hidden_chats() {
+        let mut state = ChatListState::default();
+        state.set_ready(vec![chat(1, "A"), chat(2, "B")]);
+        state.toggle_filter(ChatListFilter::HideMuted);
+
+        state.set_chat_muted(1, true);
+        state.set_chat_marked_as_unread(1, true);
+        assert_eq!(state.chats().len(), 2);
+
+        state.toggle_filter(ChatListFilter::HideMuted);
+        state.toggle_filter(ChatListFilter::HideMuted);
+        assert_eq!(state.chats().len(), 1);
+        state.toggle_filter(ChatListFilter::Unread);
+        assert!(state.chats().is_empty());
+        assert_eq!(state.ui_state(), ChatListUiState::Ready);
+    }
+
+    #[test]
+    fn filtered_list_pages_until_matches_fill_the_view() {
+        let mut chats: Vec<ChatSummary> = (1..=50).map(|i| chat(i, &format!("Chat {i}"))).collect();
+        chats[10].unread_count = 1;
+        let mut state = ChatListState::default();
+        state.set_ready(chats);
+        assert!(!state.needs_more_chats());
+
+        state.toggle_filter(ChatListFilter::Unread);
+        assert!(state.needs_more_chats());
+
+        state.toggle_filter(ChatListFilter::Groups);
+        assert!(state.chats().is_empty());
+        assert!(state.needs_more_chats());
+
+        state.set_all_chats_loaded(true);
+        assert!(!state.needs_more_chats());
+    }
+
+    #[test]
+    fn removing_the_last_match_keeps_other_loaded_chats() {
+        let mut state = ChatListState::default();
+        state.set_ready(vec![chat(1, "A"), unread(2, "B")]);
+        state.toggle_filter(ChatListFilter::Unread);
+
+        state.remove_chat(2);
+
+        assert!(state.chats().is_empty());
+        assert_eq!(state.ui_state(), ChatListUiState::Ready);
+        assert!(state.clear_filters());
+        assert_eq!(state.chats().len(), 1);
+    }
 }
@@ -0,0 +1,308 @@
+//! Chat management from the chat list: leaving, deleting, clearing history
+//! and blocking.
+//!
+//! Leaving, deleting and clearing history go through a confirmation popup
+//! that explains what happens for the chat's type; the popup only offers
+//! the options Telegram allows for that type.
+
+use super::chat::{ChatSummary, ChatType};
+
+/// A chat management request sent to Telegram.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChatManageOp {
+    /// Leaves a group or channel (`leaveChat`).
+    Leave,
+    /// Deletes a private chat for both sides (`deleteChat`).
+    Delete,
+    /// Deletes a private chat only for the current user: its history is
+    /// removed and the chat leaves the chat list.
+    DeleteForMe,
+    /// Deletes all messages but keeps the chat. `for_everyone` also
+    /// removes them for the other side.
+    ClearHistory { for_everyone: bool },
+    /// Blocks (`true`) or unblocks the user of a private chat.
+    SetBlocked(bool),
+}
+
+impl ChatManageOp {
+    /// Whether the chat disappears from the chat list once this succeeds.
+    pub fn removes_chat(self) -> bool {
+        matches!(self, Self::Leave | Self::Delete | Self::DeleteForMe)
+    }
+
+    /// Notification shown once the request is sent.
+    pub fn done_message(self, title: &str) -> String {
+        match self {
+            Self::Leave => format!("Left {title}"),
+            Self::Delete | Self::DeleteForMe => format!("Deleted {title}"),
+            Self::ClearHistory { .. } => format!("Cleared history of {title}"),
+            Self::SetBlocked(true) => format!("Blocked {title}"),
+            Self::SetBlocked(false) => format!("Unblocked {title}"),
+        }
+    }
+
+    pub fn failure_message(self) -> &'static str {
+        match self {
+            Self::Leave => "Failed to leave chat",
+            Self::Delete | Self::DeleteForMe => "Failed to delete chat",
+            Self::ClearHistory { .. } => "Failed to clear chat history",
+            Self::SetBlocked(true) => "Failed to block user",
+            Self::SetBlocked(false) => "Failed to unblock user",
+        }
+    }
+}
+
+/// Which confirmation the popup asks for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChatConfirmKind {
+    /// Leave a group or channel, or delete a private chat, depending on
+    /// the chat type.
+    Remove,
+    ClearHistory,
+}
+
+/// One choice of the confirmation popup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChatConfirmOption {
+    pub key: &'static str,
+    pub label: &'static str,
+    pub op: ChatManageOp,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChatConfirmState {
+    chat_id: i64,
+    title: String,
+    chat_type: ChatType,
+    is_bot: bool,
+    kind: ChatConfirmKind,
+}
+
+impl ChatConfirmState {
+    pub fn new(chat: &ChatSummary, kind: ChatConfirmKind) -> Self {
+        Self {
+            chat_id: chat.chat_id,
+            title: chat.title.clone(),
+            chat_type: chat.chat_type,
+            is_bot: chat.is_bot,
+            kind,
+        }
+    }
+
+    pub fn chat_id(&self) -> i64 {
+        self.chat_id
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Popup title, e.g. "Leave" or "Clear history".
+    pub fn heading(&self) -> &'static str {
+        match (self.kind, self.chat_type) {
+            (ChatConfirmKind::Remove, ChatType::Private) => "Delete chat",
+            (ChatConfirmKind::Remove, _) => "Leave",
+            (ChatConfirmKind::ClearHistory, _) => "Clear history",
+        }
+    }
+
+    pub fn question(&self) -> String {
+        let title = &self.title;
+        match (self.kind, self.chat_type) {
+            (ChatConfirmKind::Remove, ChatType::Private) => {
+                format!("Delete the chat with {title}?")
+            }
+            (ChatConfirmKind::Remove, ChatType::Group) => format!("Leave the group {title}?"),
+            (ChatConfirmKind::Remove, ChatType::Channel) => format!("Leave the channel {title}?"),
+            (ChatConfirmKind::ClearHistory, _) => format!("Clear the history of {title}?"),
+        }
+    }
+
+    /// What happens to the chat, for the chat's type.
+    pub fn explanation(&self) -> &'static str {
+        match (self.kind, self.chat_type) {
+            (ChatConfirmKind::Remove, ChatType::Private) if self.is_bot => {
+                "The chat and its messages disappear from your chat list. \
+                 The bot keeps working if you start it again."
+            }
+            (ChatConfirmKind::Remove, ChatType::Private) => {
+                "The chat and its messages disappear from your chat list. \
+                 Deleting for both also removes the messages for the other person."
+            }
+            (ChatConfirmKind::Remove, ChatType::Group) => {
+                "You stop receiving messages from the group. \
+                 Rejoining a private group needs a new invite link."
+            }
+            (ChatConfirmKind::Remove, ChatType::Channel) => {
+                "You stop receiving posts from the channel. \
+                 Rejoining a private channel needs a new invite link."
+            }
+            (ChatConfirmKind::ClearHistory, ChatType::Private) if self.is_bot => {
+                "All messages are removed for you. The chat stays in your list."
+            }
+            (ChatConfirmKind::ClearHistory, ChatType::Private) => {
+                "All messages are removed but the chat stays in your list. \
+                 Clearing for both also removes them for the other person."
+            }
+            (ChatConfirmKind::ClearHistory, ChatType::Group) => {
+                "All messages are removed for you only; other members keep them. \
+                 The group stays in your list."
+            }
+            (ChatConfirmKind::ClearHistory, ChatType::Channel) => {
+                "Channel history can't be cleared. Leave the channel instead."
+            }
+        }
+    }
+
+    /// Choices offered for the chat's type; empty when nothing is allowed.
+    pub fn options(&self) -> Vec<ChatConfirmOption> {
+        let option = |key, label, op| ChatConfirmOption { key, label, op };
+        match (self.kind, self.chat_type) {
+            (ChatConfirmKind::Remove, ChatType::Private) if self.is_bot => {
+                vec![option("m", "Delete chat", ChatManageOp::DeleteForMe)]
+            }
+            (ChatConfirmKind::Remove, ChatType::Private) => vec![
+                option("m", "Delete just for me", ChatManageOp::DeleteForMe),
+                option("e", "Delete for both", ChatManageOp::Delete),
+            ],
+            (ChatConfirmKind::Remove, ChatType::Group) => {
+                vec![option("y", "Leave group", ChatManageOp::Leave)]
+            }
+            (ChatConfirmKind::Remove, ChatType::Channel) => {
+                vec![option("y", "Leave channel", ChatManageOp::Leave)]
+            }
+            (ChatConfirmKind::ClearHistory, ChatType::Private) if self.is_bot => vec![option(
+                "m",
+                "Clear history",
+                ChatManageOp::ClearHistory {
+                    for_everyone: false,
+                },
+            )],
+            (ChatConfirmKind::ClearHistory, ChatType::Private) => vec![
+                option(
+                    "m",
+                    "Clear just for me",
+                    ChatManageOp::ClearHistory {
+                        for_everyone: false,
+                    },
+                ),
+                option(
+                    "e",
+                    "Clear for both",
+                    ChatManageOp::ClearHistory { for_everyone: true },
+                ),
+            ],
+            (ChatConfirmKind::ClearHistory, ChatType::Group) => vec![option(
+                "m",
+                "Clear for me",
+                ChatManageOp::ClearHistory {
+                    for_everyone: false,
+                },
+            )],
+            (ChatConfirmKind::ClearHistory, ChatType::Channel) => Vec::new(),
+        }
+    }
+
+    /// The operation bound to `key`, if the popup offers it.
+    pub fn op_for_key(&self, key: &str) -> Option<ChatManageOp> {
+        self.options()
+            .into_iter()
+            .find(|option| option.key == key)
+            .map(|option| option.op)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn summary(chat_type: ChatType, is_bot: bool) -> ChatSummary {
+        ChatSummary {
+            chat_id: 7,
+            title: "Ops".to_owned(),
+            unread_count: 0,
+            last_message_preview: None,
+            last_message_unix_ms: None,
+            is_pinned: false,
+            chat_type,
+            last_message_sender: None,
+            is_online: None,
+            is_bot,
+            outgoing_status: Default::default(),
+            last_message_id: None,
+            unread_reaction_count: 0,
+            unread_mention_count: 0,
+            is_forum: false,
+            unread_topic_count: None,
+            is_muted: false,
+            is_marked_as_unread: false,
+            is_blocked: false,
+            username: None,
+        }
+    }
+
+    #[test]
+    fn removing_a_group_leaves_it() {
+        let state =
+            ChatConfirmState::new(&summary(ChatType::Group, false), ChatConfirmKind::Remove);
+
+        assert_eq!(state.heading(), "Leave");
+        assert_eq!(state.question(), "Leave the group Ops?");
+        assert_eq!(state.op_for_key("y"), Some(ChatManageOp::Leave));
+        assert_eq!(state.op_for_key("m"), None);
+    }
+
+    #[test]
+    fn removing_a_private_chat_offers_both_sides() {
+        let state =
+            ChatConfirmState::new(&summary(ChatType::Private, false), ChatConfirmKind::Remove);
+
+        assert_eq!(state.op_for_key("m"), Some(ChatManageOp::DeleteForMe));
+        assert_eq!(state.op_for_key("e"), Some(ChatManageOp::Delete));
+        assert_eq!(state.op_for_key("y"), None);
+    }
+
+    #[test]
+    fn bot_chats_are_only_deleted_for_me() {
+        let state =
+            ChatConfirmState::new(&summary(ChatType::Private, true), ChatConfirmKind::Remove);
+
+        assert_eq!(state.options().len(), 1);
+        assert_eq!(state.op_for_key("e"), None);
+    }
+
+    #[test]
+    fn group_history_is_cleared_only_for_me() {
+        let state = ChatConfirmState::new(
+            &summary(ChatType::Group, false),
+            ChatConfirmKind::ClearHistory,
+        );
+
+        assert_eq!(
+            state.op_for_key("m"),
+            Some(ChatManageOp::ClearHistory {
+                for_everyone: false
+            })
+        );
+        assert_eq!(state.op_for_key("e"), None);
+    }
+
+    #[test]
+    fn channel_history_cannot_be_cleared() {
+        let state = ChatConfirmState::new(
+            &summary(ChatType::Channel, false),
+            ChatConfirmKind::ClearHistory,
+        );
+
+        assert!(state.options().is_empty());
+        assert!(state.explanation().contains("can't be cleared"));
+    }
+
+    #[test]
+    fn only_leave_and_delete_remove_the_chat() {
+        assert!(ChatManageOp::Leave.removes_chat());
+        assert!(ChatManageOp::DeleteForMe.removes_chat());
+        assert!(!ChatManageOp::ClearHistory { for_everyone: true }.removes_chat());
+        assert!(!ChatManageOp::SetBlocked(true).removes_chat());
+    }
+}
@@ -0,0 +1,205 @@
+use super::completion_state::CompletionItem;
+
+/// Maximum number of members offered in the mention popup.
+const MAX_MENTION_ITEMS: usize = 20;
+
+/// A group member that can be mentioned.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChatMember {
+    pub user_id: i64,
+    pub display_name: String,
+    /// Primary username without `@`; empty if the user has none.
+    pub username: String,
+}
+
+impl ChatMember {
+    /// Whether the member's name or username matches an `@` query:
+    /// any word of the name or the username starts with it.
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.to_lowercase();
+        self.username.to_lowercase().starts_with(&query)
+            || self
+                .display_name
+                .to_lowercase()
+                .split_whitespace()
+                .any(|word| word.starts_with(&query))
+    }
+}
+
+/// Members found for the latest `@` query in the open group.
+///
+/// Results of the previous query stay available (filtered locally) while
+/// the request for the current one is in flight, so the popup does not
+/// flicker on every keystroke.
+#[derive(Debug, Clone, Default)]
+pub struct MemberSearch {
+    chat_id: Option<i64>,
+    /// Query the stored `members` were loaded for.
+    query: Option<String>,
+    members: Vec<ChatMember>,
+    pending_query: Option<String>,
+}
+
+impl MemberSearch {
+    /// Returns `true` and marks the request as in flight if neither the
+    /// loaded nor the pending results are for `query`. Switching chats
+    /// drops the results of the previous chat.
+    pub fn begin_request(&mut self, chat_id: i64, query: &str) -> bool {
+        if self.chat_id != Some(chat_id) {
+            *self = Self {
+                chat_id: Some(chat_id),
+                ..Self::default()
+            };
+        }
+        if self.query.as_deref() == Some(query) || self.pending_query.as_deref() == Some(query) {
+            return false;
+        }
+        self.pending_query = Some(query.to_owned());
+        true
+    }
+
+    /// Stores search results; results for another chat are ignored.
+    pub fn store(&mut self, chat_id: i64, query: String, members: Vec<ChatMember>) {
+        if self.chat_id != Some(chat_id) {
+            return;
+        }
+        if self.pending_query.as_deref() == Some(query.as_str()) {
+            self.pending_query = None;
+        }
+        self.query = Some(query);
+        self.members = members;
+    }
+
+    /// Members known for the chat (possibly for an earlier query).
+    pub fn members(&self, chat_id: i64) -> &[ChatMember] {
+        if self.chat_id == Some(chat_id) {
+            &self.members
+        } else {
+            &[]
+        }
+    }
+}
+
+/// Completion candidates for an `@` query. Members who wrote most
+/// recently come first (`recent_senders` is newest first); the rest keep
+/// the server's order.
+pub fn mention_completions(
+    members: &[ChatMember],
+    query: &str,
+    recent_senders: &[&str],
+) -> Vec<CompletionItem> {
+    let mut matching: Vec<&ChatMember> = members.iter().filter(|m| m.matches(query)).collect();
+    matching.sort_by_key(|m| {
+        recent_senders
+            .iter()
+            .position(|name| *name == m.display_name)
+            .unwrap_or(usize::MAX)
+    });
+
+    matching
+        .into_iter()
+        .take(MAX_MENTION_ITEMS)
+        .map(|m| {
+            if m.username.is_empty() {
+                CompletionItem {
+                    label: m.display_name.clone(),
+                    detail: String::new(),
+                    insert_text: format!("{} ", m.display_name),
+                    mention_user_id: Some(m.user_id),
+                }
+            } else {
+                CompletionItem {
+                    label: m.display_name.clone(),
+                    detail: format!("@{}", m.username),
+                    insert_text: format!("@{} ", m.username),
+                    mention_user_id: None,
+                }
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn member(user_id: i64, name: &str, username: &str) -> ChatMember {
+        ChatMember {
+            user_id,
+            display_name: name.to_owned(),
+            username: username.to_owned(),
+        }
+    }
+
+    #[test]
+    fn query_matches_name_words_and_username() {
+        let members = vec![
+            member(1, "Alice Smith", "alice"),
+            member(2, "Bob Stone", ""),
+            member(3, "Carol", "smithy"),
+        ];
+
+        let labels: Vec<String> = mention_completions(&members, "smi", &[])
+            .into_iter()
+            .map(|i| i.label)
+            .collect();
+
+        assert_eq!(labels, vec!["Alice Smith", "Carol"]);
+    }
+
+    #[test]
+    fn recent_senders_are_ranked_first() {
+        let members = vec![
+            member(1, "Alice", "alice"),
+            member(2, "Bob", "bob"),
+            member(3, "Carol", "carol"),
+        ];
+
+        let labels: Vec<String> = mention_completions(&members, "", &["Carol", "Bob", "Carol"])
+            .into_iter()
+            .map(|i| i.label)
+            .collect();
+
+        assert_eq!(labels, vec!["Carol", "Bob", "Alice"]);
+    }
+
+    #[test]
+    fn members_without_username_are_mentioned_by_name() {
+        let items = mention_completions(
+            &[member(1, "Alice", "alice"), member(2, "Bob", "")],
+            "",
+            &[],
+        );
+
+        assert_eq!(items[0].insert_text, "@alice ");
+        assert_eq!(items[0].mention_user_id, None);
+        assert_eq!(items[1].insert_text, "Bob ");
+        assert_eq!(items[1].mention_user_id, Some(2));
+    }
+
+    #[test]
+    fn search_requests_each_query_once_per_chat() {
+        let mut search = MemberSearch::default();
+
+        assert!(search.begin_request(1, "al"));
+        assert!(!search.begin_request(1, "al"));
+
+        search.store(1, "al".to_owned(), vec![member(1, "Alice", "")]);
+        assert!(!search.begin_request(1, "al"));
+        assert_eq!(search.members(1).len(), 1);
+
+        assert!(search.begin_request(2, "al"));
+        assert!(search.members(1).is_empty());
+    }
+
+    #[test]
+    fn results_for_other_chat_are_ignored() {
+        let mut search = MemberSearch::default();
+        search.begin_request(1, "");
+
+        search.store(2, String::new(), vec![member(1, "Alice", "")]);
+
+        assert!(search.members(1).is_empty());
+        assert!(search.members(2).is_empty());
+    }
+}
@@ -1,15 +1,90 @@
+//! Chat search popup: ranks chats by a fuzzy match on their title and
+//! username.
+//!
+//! Candidates are the chats loaded into the chat list plus the ones TDLib
+//! finds for the query (`searchChats` locally, `searchChatsOnServer`
+//! remotely), so chats not paged into the list yet show up too.
+
+use std::{
+    collections::HashSet,
+    time::{Duration, Instant},
+};
+
+use super::{chat::ChatSummary, fuzzy::fuzzy_match, selectable_list::SelectableList};
+
+/// A chat matching the search query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChatSearchResult {
+    pub chat: ChatSummary,
+    pub score: i32,
+    /// Indices (in chars) of the title characters matched by the query.
+    pub title_matches: Vec<usize>,
+    /// Indices (in chars) of the username characters matched by the query.
+    pub username_matches: Vec<usize>,
+}
+
+impl ChatSearchResult {
+    /// Ranks `chat` against `query`; `None` when neither the title nor the
+    /// username matches.
+    fn rank(chat: &ChatSummary, query: &str) -> Option<Self> {
+        let by_title = fuzzy_match(query, &chat.title);
+        let by_username = chat
+            .username
+            .as_deref()
+            .and_then(|username| fuzzy_match(query, username));
+        let score = [&by_title, &by_username]
+            .into_iter()
+            .filter_map(|m| m.as_ref().map(|m| m.score))
+            .max()?;
+        Some(Self {
+            chat: chat.clone(),
+            score,
+            title_matches: by_title.map(|m| m.positions).unwrap_or_default(),
+            username_matches: by_username.map(|m| m.positions).unwrap_or_default(),
+        })
+    }
+
+    /// A chat TDLib found for the query although the fuzzy match misses
+    /// it (e.g. a match on another spelling). Ranked last.
+    fn unranked(chat: &ChatSummary) -> Self {
+        Self {
+            chat: chat.clone(),
+            score: 0,
+            title_matches: Vec::new(),
+            username_matches: Vec::new(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct ChatSearchState {
     query: String,
     cursor_position: usize,
+    /// Chats loaded into the chat list when the search opened.
+    loaded: Vec<ChatSummary>,
+    /// Chats TDLib found for `found_query`.
+    found: Vec<ChatSummary>,
+    found_query: String,
+    /// Query whose TDLib search is in flight.
+    pending_query: Option<String>,
+    /// When the query last changed; cleared once its search is asked for.
+    edited_at: Option<Instant>,
+    results: SelectableList<ChatSearchResult>,
 }
 
-#[cfg_attr(not(test), allow(dead_code))]
 impl ChatSearchState {
+    pub fn new(loaded: Vec<ChatSummary>) -> Self {
+        Self {
+            loaded,
+            ..Self::default()
+        }
+    }
+
     pub fn query(&self) -> &str {
         &self.query
     }
 
+    #[cfg_attr(not(test), allow(dead_code))]
     pub fn cursor_position(&self) -> usize {
         self.cursor_position
     }
@@ -17,6 +92,8 @@ impl ChatSearchState {
     pub fn insert_char(&mut self, ch: char) {
         self.query.insert(self.cursor_position, ch);
         self.cursor_position += ch.len_utf8();
+        self.edited_at = Some(Instant::now());
+        self.rerank(None);
     }
 
     pub fn delete_char_before(&mut self) {
@@ -30,12 +107,162 @@ impl ChatSearchState {
             .unwrap_or(0);
         self.query.drain(prev..self.cursor_position);
         self.cursor_position = prev;
+        self.edited_at = Some(Instant::now());
+        self.rerank(None);
+    }
+
+    /// Ranked results, best first; empty while the query is.
+    pub fn results(&self) -> &[ChatSearchResult] {
+        self.results.items()
+    }
+
+    pub fn selected_index(&self) -> Option<usize> {
+        self.results.selected_index()
+    }
+
+    pub fn selected_chat(&self) -> Option<&ChatSummary> {
+        self.results.selected().map(|result| &result.chat)
+    }
+
+    pub fn select_next(&mut self) {
+        self.results.select_next();
+    }
+
+    pub fn select_previous(&mut self) {
+        self.results.select_previous();
+    }
+
+    /// Whether a TDLib search for the current query is running or waits
+    /// for the user to stop typing.
+    pub fn is_searching(&self) -> bool {
+        let waiting = self.edited_at.is_some() && !self.query.is_empty();
+        waiting || self.pending_query.as_deref() == Some(self.query.as_str())
+    }
+
+    /// Like [`begin_request`](Self::begin_request), once the query has not
+    /// changed for `pause`; `false` while the user is still typing.
+    pub fn begin_request_after(&mut self, pause: Duration) -> bool {
+        match self.edited_at {
+            Some(at) if at.elapsed() >= pause => self.begin_request(),
+            _ => false,
+        }
+    }
+
+    /// Pretends the user stopped typing long ago.
+    #[cfg(test)]
+    pub fn expire_typing_pause(&mut self) {
+        if self.edited_at.is_some() {
+            self.edited_at = Some(Instant::now() - Duration::from_secs(60));
+        }
+    }
+
+    /// Returns `true` and marks the TDLib search for the current query as
+    /// in flight, unless it already ran or is running.
+    pub fn begin_request(&mut self) -> bool {
+        self.edited_at = None;
+        if self.query.is_empty()
+            || self.found_query == self.query
+            || self.pending_query.as_deref() == Some(self.query.as_str())
+        {
+            return false;
+        }
+        self.pending_query = Some(self.query.clone());
+        true
+    }
+
+    /// Adds chats TDLib found for `query`. Results for the same query are
+    /// merged (local ones arrive before the server's); `done` marks the
+    /// last batch for the query. The selected chat stays selected.
+    pub fn store_found(&mut self, query: String, chats: Vec<ChatSummary>, done: bool) {
+        if query != self.found_query {
+            self.found.clear();
+            self.found_query = query;
+        }
+        for chat in chats {
+            if !self.found.iter().any(|c| c.chat_id == chat.chat_id) {
+                self.found.push(chat);
+            }
+        }
+        if done && self.pending_query.as_deref() == Some(self.found_query.as_str()) {
+            self.pending_query = None;
+        }
+        let selected = self.selected_chat().map(|chat| chat.chat_id);
+        self.rerank(selected);
+    }
+
+    fn rerank(&mut self, preferred_chat_id: Option<i64>) {
+        if self.query.is_empty() {
+            self.results.clear();
+            return;
+        }
+
+        let found_ids: HashSet<i64> = if self.found_query == self.query {
+            self.found.iter().map(|chat| chat.chat_id).collect()
+        } else {
+            HashSet::new()
+        };
+        let mut seen = HashSet::new();
+        let mut results: Vec<ChatSearchResult> = self
+            .loaded
+            .iter()
+            .chain(&self.found)
+            .filter(|chat| seen.insert(chat.chat_id))
+            .filter_map(|chat| {
+                ChatSearchResult::rank(chat, &self.query).or_else(|| {
+                    found_ids
+                        .contains(&chat.chat_id)
+                        .then(|| ChatSearchResult::unranked(chat))
+                })
+            })
+            .collect();
+        // Stable: equally ranked chats keep the chat list order.
+        results.sort_by_key(|result| std::cmp::Reverse(result.score));
+
+        let preferred = preferred_chat_id
+            .and_then(|chat_id| results.iter().position(|r| r.chat.chat_id == chat_id));
+        self.results.replace(results, preferred);
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::chat::{ChatType, OutgoingReadStatus};
+
+    fn chat(chat_id: i64, title: &str, username: Option<&str>) -> ChatSummary {
+        ChatSummary {
+            chat_id,
+            title: title.to_owned(),
+            unread_count: 0,
+            last_message_preview: None,
+            last_message_unix_ms: None,
+            is_pinned: false,
+            chat_type: ChatType::Private,
+            last_message_sender: None,
+            is_online: None,
+            is_bot: false,
+            outgoing_status: OutgoingReadStatus::default(),
+            last_message_id: None,
+            unread_reaction_count: 0,
+            unread_mention_count: 0,
+            is_forum: false,
+            unread_topic_count: None,
+            is_muted: false,
+            is_marked_as_unread: false,
+            is_blocked: false,
+            username: username.map(str::to_owned),
+        }
+    }
+
+    fn type_query(state: &mut ChatSearchState, query: &str) {
+        for ch in query.chars() {
+            state.insert_char(ch);
+        }
+    }
+
+    fn result_ids(state: &ChatSearchState) -> Vec<i64> {
+        state.results().iter().map(|r| r.chat.chat_id).collect()
+    }
 
     #[test]
     fn default_is_empty() {
@@ -87,4 +314,99 @@ mod tests {
         s.delete_char_before();
         assert_eq!(s.query(), "п");
     }
+
+    #[test]
+    fn ranks_loaded_chats_by_title_and_username() {
+        let mut state = ChatSearchState::new(vec![
+            chat(1, "Vasya", None),
+            chat(2, "Alice Smith", None),
+            chat(3, "Team", Some("asgard")),
+            chat(4, "Bob", None),
+        ]);
+
+        type_query(&mut state, "as");
+        assert_eq!(result_ids(&state), vec![2, 3, 1]);
+        assert_eq!(state.results()[0].title_matches, vec![0, 6]);
+        assert_eq!(state.selected_chat().map(|c| c.chat_id), Some(2));
+
+        state.delete_char_before();
+        state.delete_char_before();
+        assert!(state.results().is_empty());
+    }
+
+    #[test]
+    fn username_matches_are_highlighted() {
+        let mut state = ChatSearchState::new(vec![chat(3, "Rust", Some("rustlang"))]);
+
+        type_query(&mut state, "lang");
+
+        let result = &state.results()[0];
+        assert!(result.title_matches.is_empty());
+        assert_eq!(result.username_matches, vec![4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn found_chats_join_the_results_and_keep_selection() {
+        let mut state = ChatSearchState::new(vec![chat(1, "Cold room", None)]);
+        type_query(&mut state, "old");
+        assert!(state.begin_request());
+        assert!(!state.begin_request());
+        assert!(state.is_searching());
+
+        state.store_found(
+            "old".to_owned(),
+            vec![chat(1, "Cold room", None), chat(7, "Oldtimers", None)],
+            false,
+        );
+        assert_eq!(result_ids(&state), vec![7, 1]);
+        assert_eq!(state.selected_chat().map(|c| c.chat_id), Some(1));
+        assert!(state.is_searching());
+
+        // Server results match on things the fuzzy match cannot see.
+        state.store_found("old".to_owned(), vec![chat(9, "Ретро", None)], true);
+        assert_eq!(result_ids(&state), vec![7, 1, 9]);
+        assert!(!state.is_searching());
+        assert!(!state.begin_request());
+    }
+
+    #[test]
+    fn search_waits_for_a_pause_in_typing() {
+        let mut state = ChatSearchState::new(Vec::new());
+        type_query(&mut state, "rust");
+
+        assert!(!state.begin_request_after(Duration::from_secs(60)));
+        assert!(state.is_searching(), "waiting counts as searching");
+
+        state.expire_typing_pause();
+        assert!(state.begin_request_after(Duration::from_secs(60)));
+        assert!(!state.begin_request_after(Duration::ZERO));
+    }
+
+    #[test]
+    fn results_for_an_older_query_are_reranked() {
+        let mut state = ChatSearchState::new(Vec::new());
+        type_query(&mut state, "ru");
+        state.begin_request();
+        type_query(&mut state, "x");
+
+        state.store_found(
+            "ru".to_owned(),
+            vec![chat(1, "Rust", None), chat(2, "Rux", None)],
+            true,
+        );
+
+        assert_eq!(result_ids(&state), vec![2]);
+        assert!(state.begin_request());
+    }
+
+    #[test]
+    fn up_and_down_move_through_results() {
+        let mut state = ChatSearchState::new(vec![chat(1, "Ann", None), chat(2, "Anna", None)]);
+        type_query(&mut state, "an");
+
+        state.select_next();
+        assert_eq!(state.selected_index(), Some(1));
+        state.select_previous();
+        assert_eq!(state.selected_index(), Some(0));
+    }
 }
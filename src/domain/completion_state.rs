@@ -0,0 +1,191 @@
+//! Completion popup shown above the message input.
+//!
+//! The input reports the token under the cursor via [`find_completion_token`];
+//! the shell resolves candidates for it and keeps them in a [`CompletionState`]
+//! until the token changes or a candidate is accepted.
+
+/// What kind of token the cursor is on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompletionKind {
+    /// `/command` at the very start of the message.
+    BotCommand,
+    /// `@name` at the start of any word.
+    Mention,
+    /// `:shortcode` at the start of any word, once two characters of the
+    /// name are typed (so `:)` and `:D` never open the popup).
+    Emoji,
+}
+
+/// The token being completed: everything from `start` up to the cursor.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompletionToken {
+    pub kind: CompletionKind,
+    /// Character index where the token (including its trigger) begins.
+    pub start: usize,
+    /// Token text after the trigger character.
+    pub query: String,
+}
+
+/// Finds the completable token ending at `cursor` (a character index).
+///
+/// Returns `None` when the cursor is not on a token that has a completion
+/// source.
+pub fn find_completion_token(text: &str, cursor: usize) -> Option<CompletionToken> {
+    let before: Vec<char> = text.chars().take(cursor).collect();
+    let start = before
+        .iter()
+        .rposition(|c| c.is_whitespace())
+        .map_or(0, |i| i + 1);
+    let token: String = before[start..].iter().collect();
+
+    let mut chars = token.chars();
+    let kind = match chars.next()? {
+        '/' if start == 0 => CompletionKind::BotCommand,
+        '@' => CompletionKind::Mention,
+        ':' if chars.as_str().chars().count() >= 2 => CompletionKind::Emoji,
+        _ => return None,
+    };
+
+    Some(CompletionToken {
+        kind,
+        start,
+        query: chars.as_str().to_owned(),
+    })
+}
+
+/// One candidate in the completion popup.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompletionItem {
+    /// Text shown in the popup.
+    pub label: String,
+    /// Secondary text shown dimmed next to the label (e.g. a description).
+    pub detail: String,
+    /// Replacement for the token when the item is accepted.
+    pub insert_text: String,
+    /// Set when the inserted text must be sent as a mention of this user
+    /// (users without a username are mentioned by name).
+    pub mention_user_id: Option<i64>,
+}
+
+/// Visible completion popup: candidates for the token starting at `token_start`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompletionState {
+    token_start: usize,
+    items: Vec<CompletionItem>,
+    selected: usize,
+}
+
+impl CompletionState {
+    /// Returns `None` when there is nothing to offer.
+    pub fn new(token_start: usize, items: Vec<CompletionItem>) -> Option<Self> {
+        if items.is_empty() {
+            return None;
+        }
+        Some(Self {
+            token_start,
+            items,
+            selected: 0,
+        })
+    }
+
+    pub fn token_start(&self) -> usize {
+        self.token_start
+    }
+
+    pub fn items(&self) -> &[CompletionItem] {
+        &self.items
+    }
+
+    pub fn selected_index(&self) -> usize {
+        self.selected
+    }
+
+    pub fn selected_item(&self) -> &CompletionItem {
+        &self.items[self.selected]
+    }
+
+    /// Moves the selection down, wrapping to the first item.
+    pub fn select_next(&mut self) {
+        self.selected = (self.selected + 1) % self.items.len();
+    }
+
+    /// Moves the selection up, wrapping to the last item.
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.checked_sub(1).unwrap_or(self.items.len() - 1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(label: &str) -> CompletionItem {
+        CompletionItem {
+            label: label.to_owned(),
+            detail: String::new(),
+            insert_text: label.to_owned(),
+            mention_user_id: None,
+        }
+    }
+
+    #[test]
+    fn slash_at_start_is_bot_command_token() {
+        assert_eq!(
+            find_completion_token("/sta", 4),
+            Some(CompletionToken {
+                kind: CompletionKind::BotCommand,
+                start: 0,
+                query: "sta".to_owned(),
+            })
+        );
+        assert_eq!(find_completion_token("/", 1).unwrap().query, "");
+    }
+
+    #[test]
+    fn slash_elsewhere_or_after_space_is_not_completed() {
+        assert_eq!(find_completion_token("/start now", 10), None);
+        assert_eq!(find_completion_token("see /help", 9), None);
+        assert_eq!(find_completion_token("hello", 5), None);
+        assert_eq!(find_completion_token("", 0), None);
+    }
+
+    #[test]
+    fn at_sign_starts_mention_token_in_any_word() {
+        assert_eq!(
+            find_completion_token("hi @al", 6),
+            Some(CompletionToken {
+                kind: CompletionKind::Mention,
+                start: 3,
+                query: "al".to_owned(),
+            })
+        );
+        assert_eq!(
+            find_completion_token("@", 1).map(|t| t.kind),
+            Some(CompletionKind::Mention)
+        );
+        assert_eq!(find_completion_token("mail@host", 9), None);
+    }
+
+    #[test]
+    fn token_ends_at_cursor() {
+        let token = find_completion_token("/start", 3).unwrap();
+        assert_eq!(token.query, "st");
+    }
+
+    #[test]
+    fn empty_items_yield_no_state() {
+        assert!(CompletionState::new(0, vec![]).is_none());
+    }
+
+    #[test]
+    fn selection_wraps_both_ways() {
+        let mut state = CompletionState::new(0, vec![item("a"), item("b")]).unwrap();
+        assert_eq!(state.selected_item().label, "a");
+
+        state.select_previous();
+        assert_eq!(state.selected_index(), 1);
+
+        state.select_next();
+        assert_eq!(state.selected_item().label, "a");
+    }
+}
@@ -0,0 +1,24 @@
+use super::fuzzy::fuzzy_score;
+
+/// A user from the account's contact list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Contact {
+    pub user_id: i64,
+    pub display_name: String,
+    /// Primary username without `@`; empty if the user has none.
+    pub username: String,
+    pub is_online: bool,
+}
+
+impl Contact {
+    /// Best fuzzy score of `query` against the name or the username.
+    pub fn match_score(&self, query: &str) -> Option<i32> {
+        let by_name = fuzzy_score(query, &self.display_name);
+        let by_username = if self.username.is_empty() {
+            None
+        } else {
+            fuzzy_score(query, &self.username)
+        };
+        by_name.max(by_username)
+    }
+}
@@ -0,0 +1,244 @@
+use super::contact::Contact;
+use super::selectable_list::SelectableList;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContactsUiState {
+    Loading,
+    Ready,
+    Empty,
+    Error,
+}
+
+/// Left-panel state when the user is browsing their contacts.
+///
+/// Like `ForumTopicListState`, it sits alongside the root `ChatListState`:
+/// opening the contacts installs it and closing drops it, leaving the chat
+/// list selection untouched. The visible list is the fuzzy-filtered subset
+/// of all contacts, best match first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContactsState {
+    ui_state: ContactsUiState,
+    /// All contacts, sorted by name.
+    all: Vec<Contact>,
+    list: SelectableList<Contact>,
+    filter: String,
+    /// Keys go to the filter instead of the keymap.
+    filtering: bool,
+}
+
+impl ContactsState {
+    pub fn loading() -> Self {
+        Self {
+            ui_state: ContactsUiState::Loading,
+            all: Vec::new(),
+            list: SelectableList::default(),
+            filter: String::new(),
+            filtering: false,
+        }
+    }
+
+    pub fn ui_state(&self) -> ContactsUiState {
+        self.ui_state
+    }
+
+    /// Contacts matching the filter.
+    pub fn contacts(&self) -> &[Contact] {
+        self.list.items()
+    }
+
+    pub fn total_count(&self) -> usize {
+        self.all.len()
+    }
+
+    pub fn selected_index(&self) -> Option<usize> {
+        self.list.selected_index()
+    }
+
+    pub fn selected_contact(&self) -> Option<&Contact> {
+        self.list.selected()
+    }
+
+    pub fn set_ready(&mut self, mut contacts: Vec<Contact>) {
+        if contacts.is_empty() {
+            self.ui_state = ContactsUiState::Empty;
+            self.all.clear();
+            self.list.clear();
+            return;
+        }
+        contacts.sort_by_key(|c| c.display_name.to_lowercase());
+        self.all = contacts;
+        self.ui_state = ContactsUiState::Ready;
+        self.apply_filter();
+    }
+
+    pub fn set_error(&mut self) {
+        self.ui_state = ContactsUiState::Error;
+        self.all.clear();
+        self.list.clear();
+    }
+
+    pub fn set_loading(&mut self) {
+        self.ui_state = ContactsUiState::Loading;
+    }
+
+    /// Drops a contact locally, e.g. right after asking to remove it.
+    pub fn remove(&mut self, user_id: i64) -> Option<Contact> {
+        let idx = self.all.iter().position(|c| c.user_id == user_id)?;
+        let removed = self.all.remove(idx);
+        if self.all.is_empty() {
+            self.ui_state = ContactsUiState::Empty;
+        }
+        self.apply_filter();
+        Some(removed)
+    }
+
+    pub fn select_next(&mut self) {
+        self.list.select_next();
+    }
+
+    pub fn select_previous(&mut self) {
+        self.list.select_previous();
+    }
+
+    pub fn select_first(&mut self) {
+        self.list.select_first();
+    }
+
+    pub fn filter(&self) -> &str {
+        &self.filter
+    }
+
+    pub fn is_filtering(&self) -> bool {
+        self.filtering
+    }
+
+    pub fn start_filtering(&mut self) {
+        self.filtering = true;
+    }
+
+    /// Stops typing into the filter but keeps it applied.
+    pub fn finish_filtering(&mut self) {
+        self.filtering = false;
+    }
+
+    pub fn clear_filter(&mut self) {
+        self.filtering = false;
+        self.filter.clear();
+        self.apply_filter();
+    }
+
+    pub fn push_filter_char(&mut self, ch: char) {
+        self.filter.push(ch);
+        self.apply_filter();
+    }
+
+    pub fn pop_filter_char(&mut self) {
+        self.filter.pop();
+        self.apply_filter();
+    }
+
+    /// Rebuilds the visible list, keeping the selected contact when it
+    /// still matches.
+    fn apply_filter(&mut self) {
+        let selected_id = self.selected_contact().map(|c| c.user_id);
+
+        let mut matching: Vec<(i32, &Contact)> = self
+            .all
+            .iter()
+            .filter_map(|c| c.match_score(&self.filter).map(|score| (score, c)))
+            .collect();
+        // Stable sort: equal scores keep alphabetical order.
+        matching.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+        let visible: Vec<Contact> = matching.into_iter().map(|(_, c)| c.clone()).collect();
+
+        let preferred = selected_id.and_then(|id| visible.iter().position(|c| c.user_id == id));
+        self.list.replace(visible, preferred);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn contact(user_id: i64, name: &str, username: &str) -> Contact {
+        Contact {
+            user_id,
+            display_name: name.to_owned(),
+            username: username.to_owned(),
+            is_online: false,
+        }
+    }
+
+    fn names(state: &ContactsState) -> Vec<&str> {
+        state
+            .contacts()
+            .iter()
+            .map(|c| c.display_name.as_str())
+            .collect()
+    }
+
+    fn ready() -> ContactsState {
+        let mut state = ContactsState::loading();
+        state.set_ready(vec![
+            contact(1, "Vasya", ""),
+            contact(2, "Alice Smith", "alice"),
+            contact(3, "Bob", "builder"),
+        ]);
+        state
+    }
+
+    #[test]
+    fn contacts_are_sorted_by_name() {
+        let state = ready();
+
+        assert_eq!(names(&state), vec!["Alice Smith", "Bob", "Vasya"]);
+        assert_eq!(state.selected_index(), Some(0));
+    }
+
+    #[test]
+    fn filter_ranks_best_match_first() {
+        let mut state = ready();
+
+        state.push_filter_char('a');
+        state.push_filter_char('s');
+
+        assert_eq!(names(&state), vec!["Alice Smith", "Vasya"]);
+    }
+
+    #[test]
+    fn filter_matches_usernames() {
+        let mut state = ready();
+
+        for ch in "build".chars() {
+            state.push_filter_char(ch);
+        }
+
+        assert_eq!(names(&state), vec!["Bob"]);
+    }
+
+    #[test]
+    fn selection_follows_contact_through_filtering() {
+        let mut state = ready();
+        state.select_next();
+        state.select_next();
+
+        state.push_filter_char('a');
+
+        assert_eq!(state.selected_contact().map(|c| c.user_id), Some(1));
+
+        state.clear_filter();
+        assert_eq!(names(&state).len(), 3);
+        assert_eq!(state.selected_contact().map(|c| c.user_id), Some(1));
+    }
+
+    #[test]
+    fn removing_last_contact_leaves_empty_state() {
+        let mut state = ContactsState::loading();
+        state.set_ready(vec![contact(1, "Alice", "")]);
+
+        assert!(state.remove(1).is_some());
+
+        assert_eq!(state.ui_state(), ContactsUiState::Empty);
+        assert!(state.selected_contact().is_none());
+    }
+}
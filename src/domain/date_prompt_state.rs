@@ -0,0 +1,207 @@
+//! State and parsing for the "jump to date" prompt in the messages panel.
+//!
+//! The prompt accepts absolute dates (`2026-09-01`, `01.09.2026`, `09-01`),
+//! keywords (`today`, `yesterday`, weekday names) and relative offsets
+//! (`-3d`, `2w`, `-1m`, `1y`). Parsing is pure: the caller passes `today`.
+
+use chrono::{Datelike, Days, Months, NaiveDate, Weekday};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DatePromptState {
+    input: String,
+    cursor_position: usize,
+}
+
+impl DatePromptState {
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    pub fn cursor_position(&self) -> usize {
+        self.cursor_position
+    }
+
+    pub fn insert_char(&mut self, ch: char) {
+        self.input.insert(self.cursor_position, ch);
+        self.cursor_position += ch.len_utf8();
+    }
+
+    pub fn delete_char_before(&mut self) {
+        if self.cursor_position == 0 {
+            return;
+        }
+        let prev = self.input[..self.cursor_position]
+            .char_indices()
+            .next_back()
+            .map(|(i, _)| i)
+            .unwrap_or(0);
+        self.input.drain(prev..self.cursor_position);
+        self.cursor_position = prev;
+    }
+}
+
+/// Resolves user input from the date prompt to a calendar date.
+///
+/// Returns `None` for unrecognized input and for dates after `today`
+/// (there is no history to jump to in the future).
+pub fn parse_jump_date(input: &str, today: NaiveDate) -> Option<NaiveDate> {
+    let input = input.trim().to_lowercase();
+    if input.is_empty() {
+        return None;
+    }
+
+    let date = match input.as_str() {
+        "today" | "now" => Some(today),
+        "yesterday" => today.checked_sub_days(Days::new(1)),
+        _ => parse_weekday(&input)
+            .map(|weekday| most_recent_weekday(today, weekday))
+            .or_else(|| parse_relative(&input, today))
+            .or_else(|| parse_absolute(&input, today)),
+    }?;
+
+    (date <= today).then_some(date)
+}
+
+/// Parses `-3d`, `3d`, `2w`, `-1m`, `1y` as an offset back from `today`.
+fn parse_relative(input: &str, today: NaiveDate) -> Option<NaiveDate> {
+    let body = input.strip_prefix('-').unwrap_or(input);
+    let unit = body.chars().last()?;
+    let amount: u32 = body[..body.len() - unit.len_utf8()].parse().ok()?;
+
+    match unit {
+        'd' => today.checked_sub_days(Days::new(u64::from(amount))),
+        'w' => today.checked_sub_days(Days::new(u64::from(amount) * 7)),
+        'm' => today.checked_sub_months(Months::new(amount)),
+        'y' => today.checked_sub_months(Months::new(amount.checked_mul(12)?)),
+        _ => None,
+    }
+}
+
+/// Parses `YYYY-MM-DD`, `DD.MM.YYYY` and year-less `MM-DD` / `DD.MM`.
+///
+/// Year-less dates resolve to the most recent occurrence: a day later in
+/// the year than `today` means last year.
+fn parse_absolute(input: &str, today: NaiveDate) -> Option<NaiveDate> {
+    for format in ["%Y-%m-%d", "%d.%m.%Y", "%Y/%m/%d"] {
+        if let Ok(date) = NaiveDate::parse_from_str(input, format) {
+            return Some(date);
+        }
+    }
+
+    let (month, day) = if let Some((m, d)) = input.split_once('-') {
+        (m.parse().ok()?, d.parse().ok()?)
+    } else if let Some((d, m)) = input.split_once('.') {
+        (m.parse().ok()?, d.parse().ok()?)
+    } else {
+        return None;
+    };
+
+    let this_year = NaiveDate::from_ymd_opt(today.year(), month, day);
+    match this_year {
+        Some(date) if date <= today => Some(date),
+        _ => NaiveDate::from_ymd_opt(today.year() - 1, month, day),
+    }
+}
+
+fn parse_weekday(input: &str) -> Option<Weekday> {
+    match input {
+        "mon" | "monday" => Some(Weekday::Mon),
+        "tue" | "tuesday" => Some(Weekday::Tue),
+        "wed" | "wednesday" => Some(Weekday::Wed),
+        "thu" | "thursday" => Some(Weekday::Thu),
+        "fri" | "friday" => Some(Weekday::Fri),
+        "sat" | "saturday" => Some(Weekday::Sat),
+        "sun" | "sunday" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+/// Returns the latest date on or before `today` that falls on `weekday`.
+fn most_recent_weekday(today: NaiveDate, weekday: Weekday) -> NaiveDate {
+    let back = (7 + today.weekday().num_days_from_monday() - weekday.num_days_from_monday()) % 7;
+    today - Days::new(u64::from(back))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    // 2026-09-16 is a Wednesday.
+    const TODAY: (i32, u32, u32) = (2026, 9, 16);
+
+    fn today() -> NaiveDate {
+        date(TODAY.0, TODAY.1, TODAY.2)
+    }
+
+    #[test]
+    fn parses_iso_date() {
+        assert_eq!(
+            parse_jump_date("2026-09-01", today()),
+            Some(date(2026, 9, 1))
+        );
+    }
+
+    #[test]
+    fn parses_dotted_date() {
+        assert_eq!(
+            parse_jump_date("01.09.2026", today()),
+            Some(date(2026, 9, 1))
+        );
+    }
+
+    #[test]
+    fn parses_keywords() {
+        assert_eq!(parse_jump_date("today", today()), Some(today()));
+        assert_eq!(
+            parse_jump_date("Yesterday", today()),
+            Some(date(2026, 9, 15))
+        );
+    }
+
+    #[test]
+    fn parses_relative_offsets() {
+        assert_eq!(parse_jump_date("-3d", today()), Some(date(2026, 9, 13)));
+        assert_eq!(parse_jump_date("3d", today()), Some(date(2026, 9, 13)));
+        assert_eq!(parse_jump_date("-2w", today()), Some(date(2026, 9, 2)));
+        assert_eq!(parse_jump_date("-1m", today()), Some(date(2026, 8, 16)));
+        assert_eq!(parse_jump_date("1y", today()), Some(date(2025, 9, 16)));
+    }
+
+    #[test]
+    fn parses_weekday_as_most_recent_occurrence() {
+        assert_eq!(parse_jump_date("wed", today()), Some(today()));
+        assert_eq!(parse_jump_date("monday", today()), Some(date(2026, 9, 14)));
+        assert_eq!(parse_jump_date("thu", today()), Some(date(2026, 9, 10)));
+    }
+
+    #[test]
+    fn yearless_date_resolves_to_most_recent_occurrence() {
+        assert_eq!(parse_jump_date("09-01", today()), Some(date(2026, 9, 1)));
+        assert_eq!(parse_jump_date("12-24", today()), Some(date(2025, 12, 24)));
+        assert_eq!(parse_jump_date("24.12", today()), Some(date(2025, 12, 24)));
+    }
+
+    #[test]
+    fn rejects_future_and_garbage() {
+        assert_eq!(parse_jump_date("2027-01-01", today()), None);
+        assert_eq!(parse_jump_date("", today()), None);
+        assert_eq!(parse_jump_date("soon", today()), None);
+        assert_eq!(parse_jump_date("-3x", today()), None);
+        assert_eq!(parse_jump_date("2026-02-30", today()), None);
+    }
+
+    #[test]
+    fn prompt_editing() {
+        let mut s = DatePromptState::default();
+        s.insert_char('-');
+        s.insert_char('3');
+        s.insert_char('d');
+        s.delete_char_before();
+        assert_eq!(s.input(), "-3");
+        assert_eq!(s.cursor_position(), 2);
+    }
+}
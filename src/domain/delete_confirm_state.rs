@@ -0,0 +1,181 @@
+//! State of the "delete messages?" confirmation popup.
+//!
+//! The popup opens immediately; what the messages can be deleted for is
+//! resolved in the background and filled in when it arrives.
+
+/// Who the selected messages may be deleted for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeletePermissions {
+    /// Deleting only for the current user is allowed (`revoke == false`).
+    pub for_self: bool,
+    /// Deleting for all chat members is allowed (`revoke == true`).
+    pub for_everyone: bool,
+}
+
+impl DeletePermissions {
+    /// Permissions for a set of messages: an option is only offered
+    /// when every message allows it.
+    pub fn intersect(self, other: Self) -> Self {
+        Self {
+            for_self: self.for_self && other.for_self,
+            for_everyone: self.for_everyone && other.for_everyone,
+        }
+    }
+}
+
+/// How messages are deleted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeleteScope {
+    /// Only for the current user; other members still see the messages.
+    ForMe,
+    /// For all chat members.
+    ForEveryone,
+    /// For everyone where allowed, otherwise only for the current user.
+    /// Used when confirmation is disabled in the config.
+    Auto,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PermissionStatus {
+    Loading,
+    Known(DeletePermissions),
+    /// The query failed; both options are offered and Telegram decides.
+    Unknown,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeleteConfirmState {
+    chat_id: i64,
+    message_ids: Vec<i64>,
+    permissions: PermissionStatus,
+}
+
+impl DeleteConfirmState {
+    pub fn new(chat_id: i64, message_ids: Vec<i64>) -> Self {
+        Self {
+            chat_id,
+            message_ids,
+            permissions: PermissionStatus::Loading,
+        }
+    }
+
+    pub fn chat_id(&self) -> i64 {
+        self.chat_id
+    }
+
+    pub fn message_ids(&self) -> &[i64] {
+        &self.message_ids
+    }
+
+    pub fn is_loading(&self) -> bool {
+        self.permissions == PermissionStatus::Loading
+    }
+
+    /// Whether the permission query failed.
+    pub fn is_unknown(&self) -> bool {
+        self.permissions == PermissionStatus::Unknown
+    }
+
+    /// Stores the query result; `None` means it could not be resolved.
+    pub fn resolve(&mut self, permissions: Option<DeletePermissions>) {
+        self.permissions = match permissions {
+            Some(p) => PermissionStatus::Known(p),
+            None => PermissionStatus::Unknown,
+        };
+    }
+
+    pub fn can_delete_for_me(&self) -> bool {
+        match self.permissions {
+            PermissionStatus::Loading => false,
+            PermissionStatus::Known(p) => p.for_self,
+            PermissionStatus::Unknown => true,
+        }
+    }
+
+    pub fn can_delete_for_everyone(&self) -> bool {
+        match self.permissions {
+            PermissionStatus::Loading => false,
+            PermissionStatus::Known(p) => p.for_everyone,
+            PermissionStatus::Unknown => true,
+        }
+    }
+
+    /// The permissions are known and neither option is allowed.
+    pub fn cannot_delete(&self) -> bool {
+        matches!(
+            self.permissions,
+            PermissionStatus::Known(DeletePermissions {
+                for_self: false,
+                for_everyone: false,
+            })
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn intersect_keeps_options_allowed_by_both() {
+        let a = DeletePermissions {
+            for_self: true,
+            for_everyone: true,
+        };
+        let b = DeletePermissions {
+            for_self: true,
+            for_everyone: false,
+        };
+
+        assert_eq!(
+            a.intersect(b),
+            DeletePermissions {
+                for_self: true,
+                for_everyone: false,
+            }
+        );
+    }
+
+    #[test]
+    fn nothing_is_offered_while_loading() {
+        let state = DeleteConfirmState::new(1, vec![10]);
+
+        assert!(state.is_loading());
+        assert!(!state.can_delete_for_me());
+        assert!(!state.can_delete_for_everyone());
+        assert!(!state.cannot_delete());
+    }
+
+    #[test]
+    fn known_permissions_limit_options() {
+        let mut state = DeleteConfirmState::new(1, vec![10]);
+        state.resolve(Some(DeletePermissions {
+            for_self: true,
+            for_everyone: false,
+        }));
+
+        assert!(state.can_delete_for_me());
+        assert!(!state.can_delete_for_everyone());
+    }
+
+    #[test]
+    fn failed_query_offers_both_options() {
+        let mut state = DeleteConfirmState::new(1, vec![10]);
+        state.resolve(None);
+
+        assert!(state.is_unknown());
+        assert!(state.can_delete_for_me());
+        assert!(state.can_delete_for_everyone());
+    }
+
+    #[test]
+    fn no_permissions_means_cannot_delete() {
+        let mut state = DeleteConfirmState::new(1, vec![10]);
+        state.resolve(Some(DeletePermissions {
+            for_self: false,
+            for_everyone: false,
+        }));
+
+        assert!(state.cannot_delete());
+    }
+}
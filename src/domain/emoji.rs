@@ -0,0 +1,677 @@
+//! Bundled emoji table used by `:shortcode` completion and the emoji picker.
+
+use super::completion_state::CompletionItem;
+
+/// Maximum number of candidates offered for a `:shortcode` token.
+const MAX_SHORTCODE_ITEMS: usize = 10;
+
+/// Picker tab an emoji is listed under.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmojiCategory {
+    Smileys,
+    People,
+    Nature,
+    Food,
+    Activities,
+    Travel,
+    Objects,
+    Symbols,
+}
+
+impl EmojiCategory {
+    /// Categories in picker tab order.
+    pub const ALL: [EmojiCategory; 8] = [
+        Self::Smileys,
+        Self::People,
+        Self::Nature,
+        Self::Food,
+        Self::Activities,
+        Self::Travel,
+        Self::Objects,
+        Self::Symbols,
+    ];
+
+    pub fn title(self) -> &'static str {
+        match self {
+            Self::Smileys => "Smileys",
+            Self::People => "People",
+            Self::Nature => "Nature",
+            Self::Food => "Food",
+            Self::Activities => "Activities",
+            Self::Travel => "Travel",
+            Self::Objects => "Objects",
+            Self::Symbols => "Symbols",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Emoji {
+    pub emoji: &'static str,
+    /// Shortcode without the surrounding colons (`fire` for `:fire:`).
+    pub name: &'static str,
+    pub category: EmojiCategory,
+}
+
+const fn emoji(emoji: &'static str, name: &'static str, category: EmojiCategory) -> Emoji {
+    Emoji {
+        emoji,
+        name,
+        category,
+    }
+}
+
+/// Commonly used emoji, grouped by category in picker order.
+pub const EMOJI: &[Emoji] = &[
+    emoji("😀", "grinning", EmojiCategory::Smileys),
+    emoji("😃", "smiley", EmojiCategory::Smileys),
+    emoji("😄", "smile", EmojiCategory::Smileys),
+    emoji("😁", "grin", EmojiCategory::Smileys),
+    emoji("😆", "laughing", EmojiCategory::Smileys),
+    emoji("😅", "sweat_smile", EmojiCategory::Smileys),
+    emoji("🤣", "rofl", EmojiCategory::Smileys),
+    emoji("😂", "joy", EmojiCategory::Smileys),
+    emoji("🙂", "slightly_smiling_face", EmojiCategory::Smileys),
+    emoji("🙃", "upside_down_face", EmojiCategory::Smileys),
+    emoji("😉", "wink", EmojiCategory::Smileys),
+    emoji("😊", "blush", EmojiCategory::Smileys),
+    emoji("😇", "innocent", EmojiCategory::Smileys),
+    emoji("🥰", "smiling_face_with_hearts", EmojiCategory::Smileys),
+    emoji("😍", "heart_eyes", EmojiCategory::Smileys),
+    emoji("🤩", "star_struck", EmojiCategory::Smileys),
+    emoji("😘", "kissing_heart", EmojiCategory::Smileys),
+    emoji("😋", "yum", EmojiCategory::Smileys),
+    emoji("😛", "stuck_out_tongue", EmojiCategory::Smileys),
+    emoji("😜", "stuck_out_tongue_winking_eye", EmojiCategory::Smileys),
+    emoji("🤪", "zany_face", EmojiCategory::Smileys),
+    emoji("🤑", "money_mouth_face", EmojiCategory::Smileys),
+    emoji("🤗", "hugs", EmojiCategory::Smileys),
+    emoji("🤭", "hand_over_mouth", EmojiCategory::Smileys),
+    emoji("🤫", "shushing_face", EmojiCategory::Smileys),
+    emoji("🤔", "thinking", EmojiCategory::Smileys),
+    emoji("🤐", "zipper_mouth_face", EmojiCategory::Smileys),
+    emoji("🤨", "raised_eyebrow", EmojiCategory::Smileys),
+    emoji("😐", "neutral_face", EmojiCategory::Smileys),
+    emoji("😑", "expressionless", EmojiCategory::Smileys),
+    emoji("😶", "no_mouth", EmojiCategory::Smileys),
+    emoji("😏", "smirk", EmojiCategory::Smileys),
+    emoji("😒", "unamused", EmojiCategory::Smileys),
+    emoji("🙄", "roll_eyes", EmojiCategory::Smileys),
+    emoji("😬", "grimacing", EmojiCategory::Smileys),
+    emoji("😌", "relieved", EmojiCategory::Smileys),
+    emoji("😔", "pensive", EmojiCategory::Smileys),
+    emoji("😪", "sleepy", EmojiCategory::Smileys),
+    emoji("🤤", "drooling_face", EmojiCategory::Smileys),
+    emoji("😴", "sleeping", EmojiCategory::Smileys),
+    emoji("😷", "mask", EmojiCategory::Smileys),
+    emoji("🤒", "face_with_thermometer", EmojiCategory::Smileys),
+    emoji("🤕", "face_with_head_bandage", EmojiCategory::Smileys),
+    emoji("🤢", "nauseated_face", EmojiCategory::Smileys),
+    emoji("🤮", "vomiting_face", EmojiCategory::Smileys),
+    emoji("🥵", "hot_face", EmojiCategory::Smileys),
+    emoji("🥶", "cold_face", EmojiCategory::Smileys),
+    emoji("🥴", "woozy_face", EmojiCategory::Smileys),
+    emoji("😵", "dizzy_face", EmojiCategory::Smileys),
+    emoji("🤯", "exploding_head", EmojiCategory::Smileys),
+    emoji("🤠", "cowboy_hat_face", EmojiCategory::Smileys),
+    emoji("🥳", "partying_face", EmojiCategory::Smileys),
+    emoji("😎", "sunglasses", EmojiCategory::Smileys),
+    emoji("🤓", "nerd_face", EmojiCategory::Smileys),
+    emoji("😕", "confused", EmojiCategory::Smileys),
+    emoji("😟", "worried", EmojiCategory::Smileys),
+    emoji("🙁", "slightly_frowning_face", EmojiCategory::Smileys),
+    emoji("😮", "open_mouth", EmojiCategory::Smileys),
+    emoji("😲", "astonished", EmojiCategory::Smileys),
+    emoji("😳", "flushed", EmojiCategory::Smileys),
+    emoji("🥺", "pleading_face", EmojiCategory::Smileys),
+    emoji("😦", "frowning", EmojiCategory::Smileys),
+    emoji("😨", "fearful", EmojiCategory::Smileys),
+    emoji("😰", "cold_sweat", EmojiCategory::Smileys),
+    emoji("😥", "disappointed_relieved", EmojiCategory::Smileys),
+    emoji("😢", "cry", EmojiCategory::Smileys),
+    emoji("😭", "sob", EmojiCategory::Smileys),
+    emoji("😱", "scream", EmojiCategory::Smileys),
+    emoji("😖", "confounded", EmojiCategory::Smileys),
+    emoji("😣", "persevere", EmojiCategory::Smileys),
+    emoji("😞", "disappointed", EmojiCategory::Smileys),
+    emoji("😓", "sweat", EmojiCategory::Smileys),
+    emoji("😩", "weary", EmojiCategory::Smileys),
+    emoji("😫", "tired_face", EmojiCategory::Smileys),
+    emoji("🥱", "yawning_face", EmojiCategory::Smileys),
+    emoji("😤", "triumph", EmojiCategory::Smileys),
+    emoji("😡", "rage", EmojiCategory::Smileys),
+    emoji("😠", "angry", EmojiCategory::Smileys),
+    emoji("🤬", "cursing_face", EmojiCategory::Smileys),
+    emoji("😈", "smiling_imp", EmojiCategory::Smileys),
+    emoji("💀", "skull", EmojiCategory::Smileys),
+    emoji("💩", "poop", EmojiCategory::Smileys),
+    emoji("🤡", "clown_face", EmojiCategory::Smileys),
+    emoji("👻", "ghost", EmojiCategory::Smileys),
+    emoji("👽", "alien", EmojiCategory::Smileys),
+    emoji("🤖", "robot", EmojiCategory::Smileys),
+    emoji("😺", "smiley_cat", EmojiCategory::Smileys),
+    emoji("😹", "joy_cat", EmojiCategory::Smileys),
+    emoji("😻", "heart_eyes_cat", EmojiCategory::Smileys),
+    emoji("🙈", "see_no_evil", EmojiCategory::Smileys),
+    emoji("🙉", "hear_no_evil", EmojiCategory::Smileys),
+    emoji("🙊", "speak_no_evil", EmojiCategory::Smileys),
+    emoji("👋", "wave", EmojiCategory::People),
+    emoji("🤚", "raised_back_of_hand", EmojiCategory::People),
+    emoji("✋", "raised_hand", EmojiCategory::People),
+    emoji("🖖", "vulcan_salute", EmojiCategory::People),
+    emoji("👌", "ok_hand", EmojiCategory::People),
+    emoji("🤌", "pinched_fingers", EmojiCategory::People),
+    emoji("✌️", "v", EmojiCategory::People),
+    emoji("🤞", "crossed_fingers", EmojiCategory::People),
+    emoji("🤟", "love_you_gesture", EmojiCategory::People),
+    emoji("🤘", "metal", EmojiCategory::People),
+    emoji("🤙", "call_me_hand", EmojiCategory::People),
+    emoji("👈", "point_left", EmojiCategory::People),
+    emoji("👉", "point_right", EmojiCategory::People),
+    emoji("👆", "point_up", EmojiCategory::People),
+    emoji("👇", "point_down", EmojiCategory::People),
+    emoji("👍", "thumbs_up", EmojiCategory::People),
+    emoji("👎", "thumbs_down", EmojiCategory::People),
+    emoji("✊", "fist", EmojiCategory::People),
+    emoji("👊", "punch", EmojiCategory::People),
+    emoji("👏", "clap", EmojiCategory::People),
+    emoji("🙌", "raised_hands", EmojiCategory::People),
+    emoji("👐", "open_hands", EmojiCategory::People),
+    emoji("🤝", "handshake", EmojiCategory::People),
+    emoji("🙏", "pray", EmojiCategory::People),
+    emoji("✍️", "writing_hand", EmojiCategory::People),
+    emoji("💪", "muscle", EmojiCategory::People),
+    emoji("🧠", "brain", EmojiCategory::People),
+    emoji("👀", "eyes", EmojiCategory::People),
+    emoji("👁️", "eye", EmojiCategory::People),
+    emoji("👅", "tongue", EmojiCategory::People),
+    emoji("👄", "lips", EmojiCategory::People),
+    emoji("👶", "baby", EmojiCategory::People),
+    emoji("🧒", "child", EmojiCategory::People),
+    emoji("🧑", "adult", EmojiCategory::People),
+    emoji("👨", "man", EmojiCategory::People),
+    emoji("👩", "woman", EmojiCategory::People),
+    emoji("🧓", "older_adult", EmojiCategory::People),
+    emoji("🙋", "raising_hand", EmojiCategory::People),
+    emoji("🤷", "shrug", EmojiCategory::People),
+    emoji("🤦", "facepalm", EmojiCategory::People),
+    emoji("🙇", "bow", EmojiCategory::People),
+    emoji("💃", "dancer", EmojiCategory::People),
+    emoji("🕺", "man_dancing", EmojiCategory::People),
+    emoji("🏃", "runner", EmojiCategory::People),
+    emoji("🚶", "walking", EmojiCategory::People),
+    emoji("🐶", "dog", EmojiCategory::Nature),
+    emoji("🐱", "cat", EmojiCategory::Nature),
+    emoji("🐭", "mouse", EmojiCategory::Nature),
+    emoji("🐹", "hamster", EmojiCategory::Nature),
+    emoji("🐰", "rabbit", EmojiCategory::Nature),
+    emoji("🦊", "fox_face", EmojiCategory::Nature),
+    emoji("🐻", "bear", EmojiCategory::Nature),
+    emoji("🐼", "panda_face", EmojiCategory::Nature),
+    emoji("🐨", "koala", EmojiCategory::Nature),
+    emoji("🐯", "tiger", EmojiCategory::Nature),
+    emoji("🦁", "lion", EmojiCategory::Nature),
+    emoji("🐮", "cow", EmojiCategory::Nature),
+    emoji("🐷", "pig", EmojiCategory::Nature),
+    emoji("🐸", "frog", EmojiCategory::Nature),
+    emoji("🐵", "monkey_face", EmojiCategory::Nature),
+    emoji("🐔", "chicken", EmojiCategory::Nature),
+    emoji("🐧", "penguin", EmojiCategory::Nature),
+    emoji("🐦", "bird", EmojiCategory::Nature),
+    emoji("🐤", "baby_chick", EmojiCategory::Nature),
+    emoji("🦆", "duck", EmojiCategory::Nature),
+    emoji("🦅", "eagle", EmojiCategory::Nature),
+    emoji("🦉", "owl", EmojiCategory::Nature),
+    emoji("🦇", "bat", EmojiCategory::Nature),
+    emoji("🐺", "wolf", EmojiCategory::Nature),
+    emoji("🐴", "horse", EmojiCategory::Nature),
+    emoji("🦄", "unicorn", EmojiCategory::Nature),
+    emoji("🐝", "bee", EmojiCategory::Nature),
+    emoji("🐛", "bug", EmojiCategory::Nature),
+    emoji("🦋", "butterfly", EmojiCategory::Nature),
+    emoji("🐌", "snail", EmojiCategory::Nature),
+    emoji("🐞", "lady_beetle", EmojiCategory::Nature),
+    emoji("🐢", "turtle", EmojiCategory::Nature),
+    emoji("🐍", "snake", EmojiCategory::Nature),
+    emoji("🐙", "octopus", EmojiCategory::Nature),
+    emoji("🦀", "crab", EmojiCategory::Nature),
+    emoji("🐠", "tropical_fish", EmojiCategory::Nature),
+    emoji("🐟", "fish", EmojiCategory::Nature),
+    emoji("🐬", "dolphin", EmojiCategory::Nature),
+    emoji("🐳", "whale", EmojiCategory::Nature),
+    emoji("🦈", "shark", EmojiCategory::Nature),
+    emoji("🐘", "elephant", EmojiCategory::Nature),
+    emoji("🦒", "giraffe", EmojiCategory::Nature),
+    emoji("🐈", "cat2", EmojiCategory::Nature),
+    emoji("🐕", "dog2", EmojiCategory::Nature),
+    emoji("🌵", "cactus", EmojiCategory::Nature),
+    emoji("🎄", "christmas_tree", EmojiCategory::Nature),
+    emoji("🌲", "evergreen_tree", EmojiCategory::Nature),
+    emoji("🌳", "deciduous_tree", EmojiCategory::Nature),
+    emoji("🌴", "palm_tree", EmojiCategory::Nature),
+    emoji("🌱", "seedling", EmojiCategory::Nature),
+    emoji("🍀", "four_leaf_clover", EmojiCategory::Nature),
+    emoji("🍁", "maple_leaf", EmojiCategory::Nature),
+    emoji("🍂", "fallen_leaf", EmojiCategory::Nature),
+    emoji("🌷", "tulip", EmojiCategory::Nature),
+    emoji("🌹", "rose", EmojiCategory::Nature),
+    emoji("🌻", "sunflower", EmojiCategory::Nature),
+    emoji("🌸", "cherry_blossom", EmojiCategory::Nature),
+    emoji("💐", "bouquet", EmojiCategory::Nature),
+    emoji("🌚", "new_moon_with_face", EmojiCategory::Nature),
+    emoji("🌝", "full_moon_with_face", EmojiCategory::Nature),
+    emoji("🌞", "sun_with_face", EmojiCategory::Nature),
+    emoji("⭐", "star", EmojiCategory::Nature),
+    emoji("🌟", "star2", EmojiCategory::Nature),
+    emoji("⚡", "zap", EmojiCategory::Nature),
+    emoji("🔥", "fire", EmojiCategory::Nature),
+    emoji("🌈", "rainbow", EmojiCategory::Nature),
+    emoji("☀️", "sunny", EmojiCategory::Nature),
+    emoji("⛅", "partly_sunny", EmojiCategory::Nature),
+    emoji("☁️", "cloud", EmojiCategory::Nature),
+    emoji("🌧️", "cloud_with_rain", EmojiCategory::Nature),
+    emoji("❄️", "snowflake", EmojiCategory::Nature),
+    emoji("☃️", "snowman", EmojiCategory::Nature),
+    emoji("💧", "droplet", EmojiCategory::Nature),
+    emoji("🌊", "ocean", EmojiCategory::Nature),
+    emoji("🍏", "green_apple", EmojiCategory::Food),
+    emoji("🍎", "apple", EmojiCategory::Food),
+    emoji("🍐", "pear", EmojiCategory::Food),
+    emoji("🍊", "tangerine", EmojiCategory::Food),
+    emoji("🍋", "lemon", EmojiCategory::Food),
+    emoji("🍌", "banana", EmojiCategory::Food),
+    emoji("🍉", "watermelon", EmojiCategory::Food),
+    emoji("🍇", "grapes", EmojiCategory::Food),
+    emoji("🍓", "strawberry", EmojiCategory::Food),
+    emoji("🍒", "cherries", EmojiCategory::Food),
+    emoji("🍑", "peach", EmojiCategory::Food),
+    emoji("🥭", "mango", EmojiCategory::Food),
+    emoji("🍍", "pineapple", EmojiCategory::Food),
+    emoji("🥥", "coconut", EmojiCategory::Food),
+    emoji("🥝", "kiwi_fruit", EmojiCategory::Food),
+    emoji("🍅", "tomato", EmojiCategory::Food),
+    emoji("🥑", "avocado", EmojiCategory::Food),
+    emoji("🍆", "eggplant", EmojiCategory::Food),
+    emoji("🥔", "potato", EmojiCategory::Food),
+    emoji("🥕", "carrot", EmojiCategory::Food),
+    emoji("🌽", "corn", EmojiCategory::Food),
+    emoji("🌶️", "hot_pepper", EmojiCategory::Food),
+    emoji("🥒", "cucumber", EmojiCategory::Food),
+    emoji("🥦", "broccoli", EmojiCategory::Food),
+    emoji("🍄", "mushroom", EmojiCategory::Food),
+    emoji("🥜", "peanuts", EmojiCategory::Food),
+    emoji("🍞", "bread", EmojiCategory::Food),
+    emoji("🥐", "croissant", EmojiCategory::Food),
+    emoji("🧀", "cheese", EmojiCategory::Food),
+    emoji("🥚", "egg", EmojiCategory::Food),
+    emoji("🍳", "fried_egg", EmojiCategory::Food),
+    emoji("🥓", "bacon", EmojiCategory::Food),
+    emoji("🍗", "poultry_leg", EmojiCategory::Food),
+    emoji("🍖", "meat_on_bone", EmojiCategory::Food),
+    emoji("🍔", "hamburger", EmojiCategory::Food),
+    emoji("🍟", "fries", EmojiCategory::Food),
+    emoji("🍕", "pizza", EmojiCategory::Food),
+    emoji("🌭", "hotdog", EmojiCategory::Food),
+    emoji("🌮", "taco", EmojiCategory::Food),
+    emoji("🌯", "burrito", EmojiCategory::Food),
+    emoji("🍝", "spaghetti", EmojiCategory::Food),
+    emoji("🍜", "ramen", EmojiCategory::Food),
+    emoji("🍣", "sushi", EmojiCategory::Food),
+    emoji("🍤", "fried_shrimp", EmojiCategory::Food),
+    emoji("🍦", "icecream", EmojiCategory::Food),
+    emoji("🍩", "doughnut", EmojiCategory::Food),
+    emoji("🍪", "cookie", EmojiCategory::Food),
+    emoji("🎂", "birthday", EmojiCategory::Food),
+    emoji("🍰", "cake", EmojiCategory::Food),
+    emoji("🧁", "cupcake", EmojiCategory::Food),
+    emoji("🍫", "chocolate_bar", EmojiCategory::Food),
+    emoji("🍬", "candy", EmojiCategory::Food),
+    emoji("🍭", "lollipop", EmojiCategory::Food),
+    emoji("🍯", "honey_pot", EmojiCategory::Food),
+    emoji("🍼", "baby_bottle", EmojiCategory::Food),
+    emoji("☕", "coffee", EmojiCategory::Food),
+    emoji("🍵", "tea", EmojiCategory::Food),
+    emoji("🥤", "cup_with_straw", EmojiCategory::Food),
+    emoji("🍺", "beer", EmojiCategory::Food),
+    emoji("🍻", "beers", EmojiCategory::Food),
+    emoji("🥂", "clinking_glasses", EmojiCategory::Food),
+    emoji("🍷", "wine_glass", EmojiCategory::Food),
+    emoji("🥃", "tumbler_glass", EmojiCategory::Food),
+    emoji("🍸", "cocktail", EmojiCategory::Food),
+    emoji("🍾", "champagne", EmojiCategory::Food),
+    emoji("⚽", "soccer", EmojiCategory::Activities),
+    emoji("🏀", "basketball", EmojiCategory::Activities),
+    emoji("🏈", "football", EmojiCategory::Activities),
+    emoji("⚾", "baseball", EmojiCategory::Activities),
+    emoji("🎾", "tennis", EmojiCategory::Activities),
+    emoji("🏐", "volleyball", EmojiCategory::Activities),
+    emoji("🏉", "rugby_football", EmojiCategory::Activities),
+    emoji("🎱", "8ball", EmojiCategory::Activities),
+    emoji("🏓", "ping_pong", EmojiCategory::Activities),
+    emoji("🏸", "badminton", EmojiCategory::Activities),
+    emoji("🥊", "boxing_glove", EmojiCategory::Activities),
+    emoji("🥋", "martial_arts_uniform", EmojiCategory::Activities),
+    emoji("⛳", "golf", EmojiCategory::Activities),
+    emoji("🎣", "fishing_pole_and_fish", EmojiCategory::Activities),
+    emoji("🎿", "ski", EmojiCategory::Activities),
+    emoji("🏂", "snowboarder", EmojiCategory::Activities),
+    emoji("🏋️", "weight_lifting", EmojiCategory::Activities),
+    emoji("🚴", "bicyclist", EmojiCategory::Activities),
+    emoji("🏆", "trophy", EmojiCategory::Activities),
+    emoji("🥇", "1st_place_medal", EmojiCategory::Activities),
+    emoji("🥈", "2nd_place_medal", EmojiCategory::Activities),
+    emoji("🥉", "3rd_place_medal", EmojiCategory::Activities),
+    emoji("🏅", "medal_sports", EmojiCategory::Activities),
+    emoji("🎫", "ticket", EmojiCategory::Activities),
+    emoji("🎪", "circus_tent", EmojiCategory::Activities),
+    emoji("🎭", "performing_arts", EmojiCategory::Activities),
+    emoji("🎨", "art", EmojiCategory::Activities),
+    emoji("🎬", "clapper", EmojiCategory::Activities),
+    emoji("🎤", "microphone", EmojiCategory::Activities),
+    emoji("🎧", "headphones", EmojiCategory::Activities),
+    emoji("🎼", "musical_score", EmojiCategory::Activities),
+    emoji("🎹", "musical_keyboard", EmojiCategory::Activities),
+    emoji("🥁", "drum", EmojiCategory::Activities),
+    emoji("🎷", "saxophone", EmojiCategory::Activities),
+    emoji("🎺", "trumpet", EmojiCategory::Activities),
+    emoji("🎸", "guitar", EmojiCategory::Activities),
+    emoji("🎻", "violin", EmojiCategory::Activities),
+    emoji("🎲", "game_die", EmojiCategory::Activities),
+    emoji("♟️", "chess_pawn", EmojiCategory::Activities),
+    emoji("🎯", "dart", EmojiCategory::Activities),
+    emoji("🎳", "bowling", EmojiCategory::Activities),
+    emoji("🎮", "video_game", EmojiCategory::Activities),
+    emoji("🧩", "jigsaw", EmojiCategory::Activities),
+    emoji("🎉", "tada", EmojiCategory::Activities),
+    emoji("🎊", "confetti_ball", EmojiCategory::Activities),
+    emoji("🎈", "balloon", EmojiCategory::Activities),
+    emoji("🎁", "gift", EmojiCategory::Activities),
+    emoji("🎃", "jack_o_lantern", EmojiCategory::Activities),
+    emoji("🚗", "car", EmojiCategory::Travel),
+    emoji("🚕", "taxi", EmojiCategory::Travel),
+    emoji("🚙", "blue_car", EmojiCategory::Travel),
+    emoji("🚌", "bus", EmojiCategory::Travel),
+    emoji("🚎", "trolleybus", EmojiCategory::Travel),
+    emoji("🏎️", "racing_car", EmojiCategory::Travel),
+    emoji("🚓", "police_car", EmojiCategory::Travel),
+    emoji("🚑", "ambulance", EmojiCategory::Travel),
+    emoji("🚒", "fire_engine", EmojiCategory::Travel),
+    emoji("🚚", "truck", EmojiCategory::Travel),
+    emoji("🚲", "bike", EmojiCategory::Travel),
+    emoji("🛴", "kick_scooter", EmojiCategory::Travel),
+    emoji("🏍️", "motorcycle", EmojiCategory::Travel),
+    emoji("🚨", "rotating_light", EmojiCategory::Travel),
+    emoji("🚄", "bullettrain_side", EmojiCategory::Travel),
+    emoji("🚆", "train2", EmojiCategory::Travel),
+    emoji("🚇", "metro", EmojiCategory::Travel),
+    emoji("✈️", "airplane", EmojiCategory::Travel),
+    emoji("🛫", "flight_departure", EmojiCategory::Travel),
+    emoji("🛬", "flight_arrival", EmojiCategory::Travel),
+    emoji("🚀", "rocket", EmojiCategory::Travel),
+    emoji("🛸", "flying_saucer", EmojiCategory::Travel),
+    emoji("🚁", "helicopter", EmojiCategory::Travel),
+    emoji("⛵", "boat", EmojiCategory::Travel),
+    emoji("🚤", "speedboat", EmojiCategory::Travel),
+    emoji("🚢", "ship", EmojiCategory::Travel),
+    emoji("⚓", "anchor", EmojiCategory::Travel),
+    emoji("⛽", "fuelpump", EmojiCategory::Travel),
+    emoji("🚦", "vertical_traffic_light", EmojiCategory::Travel),
+    emoji("🗺️", "world_map", EmojiCategory::Travel),
+    emoji("🗽", "statue_of_liberty", EmojiCategory::Travel),
+    emoji("🗼", "tokyo_tower", EmojiCategory::Travel),
+    emoji("🏰", "european_castle", EmojiCategory::Travel),
+    emoji("🏟️", "stadium", EmojiCategory::Travel),
+    emoji("🎡", "ferris_wheel", EmojiCategory::Travel),
+    emoji("🎢", "roller_coaster", EmojiCategory::Travel),
+    emoji("🏖️", "beach_umbrella", EmojiCategory::Travel),
+    emoji("🏝️", "desert_island", EmojiCategory::Travel),
+    emoji("⛰️", "mountain", EmojiCategory::Travel),
+    emoji("🏔️", "mountain_snow", EmojiCategory::Travel),
+    emoji("🌋", "volcano", EmojiCategory::Travel),
+    emoji("🏕️", "camping", EmojiCategory::Travel),
+    emoji("🏠", "house", EmojiCategory::Travel),
+    emoji("🏡", "house_with_garden", EmojiCategory::Travel),
+    emoji("🏢", "office", EmojiCategory::Travel),
+    emoji("🏥", "hospital", EmojiCategory::Travel),
+    emoji("🏦", "bank", EmojiCategory::Travel),
+    emoji("🏨", "hotel", EmojiCategory::Travel),
+    emoji("🏫", "school", EmojiCategory::Travel),
+    emoji("⛪", "church", EmojiCategory::Travel),
+    emoji("🌍", "earth_africa", EmojiCategory::Travel),
+    emoji("🌎", "earth_americas", EmojiCategory::Travel),
+    emoji("🌏", "earth_asia", EmojiCategory::Travel),
+    emoji("🌙", "crescent_moon", EmojiCategory::Travel),
+    emoji("🌃", "night_with_stars", EmojiCategory::Travel),
+    emoji("🌅", "sunrise", EmojiCategory::Travel),
+    emoji("⌚", "watch", EmojiCategory::Objects),
+    emoji("📱", "iphone", EmojiCategory::Objects),
+    emoji("💻", "computer", EmojiCategory::Objects),
+    emoji("⌨️", "keyboard", EmojiCategory::Objects),
+    emoji("🖥️", "desktop_computer", EmojiCategory::Objects),
+    emoji("🖨️", "printer", EmojiCategory::Objects),
+    emoji("🖱️", "computer_mouse", EmojiCategory::Objects),
+    emoji("💾", "floppy_disk", EmojiCategory::Objects),
+    emoji("💿", "cd", EmojiCategory::Objects),
+    emoji("📷", "camera", EmojiCategory::Objects),
+    emoji("📹", "video_camera", EmojiCategory::Objects),
+    emoji("🎥", "movie_camera", EmojiCategory::Objects),
+    emoji("📞", "telephone_receiver", EmojiCategory::Objects),
+    emoji("📺", "tv", EmojiCategory::Objects),
+    emoji("📻", "radio", EmojiCategory::Objects),
+    emoji("⏰", "alarm_clock", EmojiCategory::Objects),
+    emoji("⌛", "hourglass", EmojiCategory::Objects),
+    emoji("🔋", "battery", EmojiCategory::Objects),
+    emoji("🔌", "electric_plug", EmojiCategory::Objects),
+    emoji("💡", "bulb", EmojiCategory::Objects),
+    emoji("🔦", "flashlight", EmojiCategory::Objects),
+    emoji("🕯️", "candle", EmojiCategory::Objects),
+    emoji("💸", "money_with_wings", EmojiCategory::Objects),
+    emoji("💵", "dollar", EmojiCategory::Objects),
+    emoji("💰", "moneybag", EmojiCategory::Objects),
+    emoji("💳", "credit_card", EmojiCategory::Objects),
+    emoji("💎", "gem", EmojiCategory::Objects),
+    emoji("🔧", "wrench", EmojiCategory::Objects),
+    emoji("🔨", "hammer", EmojiCategory::Objects),
+    emoji("🛠️", "hammer_and_wrench", EmojiCategory::Objects),
+    emoji("⚙️", "gear", EmojiCategory::Objects),
+    emoji("🔩", "nut_and_bolt", EmojiCategory::Objects),
+    emoji("🧲", "magnet", EmojiCategory::Objects),
+    emoji("💣", "bomb", EmojiCategory::Objects),
+    emoji("🔪", "hocho", EmojiCategory::Objects),
+    emoji("🛡️", "shield", EmojiCategory::Objects),
+    emoji("🔮", "crystal_ball", EmojiCategory::Objects),
+    emoji("💊", "pill", EmojiCategory::Objects),
+    emoji("💉", "syringe", EmojiCategory::Objects),
+    emoji("🧪", "test_tube", EmojiCategory::Objects),
+    emoji("🔬", "microscope", EmojiCategory::Objects),
+    emoji("🔭", "telescope", EmojiCategory::Objects),
+    emoji("🧹", "broom", EmojiCategory::Objects),
+    emoji("🔑", "key", EmojiCategory::Objects),
+    emoji("🚪", "door", EmojiCategory::Objects),
+    emoji("🛏️", "bed", EmojiCategory::Objects),
+    emoji("🛒", "shopping_cart", EmojiCategory::Objects),
+    emoji("📦", "package", EmojiCategory::Objects),
+    emoji("📫", "mailbox", EmojiCategory::Objects),
+    emoji("✉️", "envelope", EmojiCategory::Objects),
+    emoji("📧", "email", EmojiCategory::Objects),
+    emoji("📝", "memo", EmojiCategory::Objects),
+    emoji("📎", "paperclip", EmojiCategory::Objects),
+    emoji("📌", "pushpin", EmojiCategory::Objects),
+    emoji("📍", "round_pushpin", EmojiCategory::Objects),
+    emoji("✂️", "scissors", EmojiCategory::Objects),
+    emoji("📅", "date", EmojiCategory::Objects),
+    emoji("📈", "chart_with_upwards_trend", EmojiCategory::Objects),
+    emoji("📉", "chart_with_downwards_trend", EmojiCategory::Objects),
+    emoji("📊", "bar_chart", EmojiCategory::Objects),
+    emoji("📋", "clipboard", EmojiCategory::Objects),
+    emoji("📁", "file_folder", EmojiCategory::Objects),
+    emoji("📚", "books", EmojiCategory::Objects),
+    emoji("📖", "book", EmojiCategory::Objects),
+    emoji("🔖", "bookmark", EmojiCategory::Objects),
+    emoji("🔗", "link", EmojiCategory::Objects),
+    emoji("✏️", "pencil2", EmojiCategory::Objects),
+    emoji("🖊️", "pen", EmojiCategory::Objects),
+    emoji("🔍", "mag", EmojiCategory::Objects),
+    emoji("🔒", "lock", EmojiCategory::Objects),
+    emoji("🔓", "unlock", EmojiCategory::Objects),
+    emoji("🔔", "bell", EmojiCategory::Objects),
+    emoji("🔕", "no_bell", EmojiCategory::Objects),
+    emoji("📣", "mega", EmojiCategory::Objects),
+    emoji("📢", "loudspeaker", EmojiCategory::Objects),
+    emoji("❤️", "heart", EmojiCategory::Symbols),
+    emoji("🧡", "orange_heart", EmojiCategory::Symbols),
+    emoji("💛", "yellow_heart", EmojiCategory::Symbols),
+    emoji("💚", "green_heart", EmojiCategory::Symbols),
+    emoji("💙", "blue_heart", EmojiCategory::Symbols),
+    emoji("💜", "purple_heart", EmojiCategory::Symbols),
+    emoji("🖤", "black_heart", EmojiCategory::Symbols),
+    emoji("🤍", "white_heart", EmojiCategory::Symbols),
+    emoji("🤎", "brown_heart", EmojiCategory::Symbols),
+    emoji("💔", "broken_heart", EmojiCategory::Symbols),
+    emoji("❣️", "heavy_heart_exclamation", EmojiCategory::Symbols),
+    emoji("💕", "two_hearts", EmojiCategory::Symbols),
+    emoji("💞", "revolving_hearts", EmojiCategory::Symbols),
+    emoji("💓", "heartbeat", EmojiCategory::Symbols),
+    emoji("💗", "heartpulse", EmojiCategory::Symbols),
+    emoji("💖", "sparkling_heart", EmojiCategory::Symbols),
+    emoji("💘", "cupid", EmojiCategory::Symbols),
+    emoji("💝", "gift_heart", EmojiCategory::Symbols),
+    emoji("💯", "100", EmojiCategory::Symbols),
+    emoji("💢", "anger", EmojiCategory::Symbols),
+    emoji("💥", "boom", EmojiCategory::Symbols),
+    emoji("💫", "dizzy", EmojiCategory::Symbols),
+    emoji("💦", "sweat_drops", EmojiCategory::Symbols),
+    emoji("💨", "dash", EmojiCategory::Symbols),
+    emoji("💬", "speech_balloon", EmojiCategory::Symbols),
+    emoji("💭", "thought_balloon", EmojiCategory::Symbols),
+    emoji("💤", "zzz", EmojiCategory::Symbols),
+    emoji("✨", "sparkles", EmojiCategory::Symbols),
+    emoji("✅", "white_check_mark", EmojiCategory::Symbols),
+    emoji("☑️", "ballot_box_with_check", EmojiCategory::Symbols),
+    emoji("✔️", "heavy_check_mark", EmojiCategory::Symbols),
+    emoji("❌", "x", EmojiCategory::Symbols),
+    emoji("❎", "negative_squared_cross_mark", EmojiCategory::Symbols),
+    emoji("➕", "heavy_plus_sign", EmojiCategory::Symbols),
+    emoji("➖", "heavy_minus_sign", EmojiCategory::Symbols),
+    emoji("❓", "question", EmojiCategory::Symbols),
+    emoji("❗", "exclamation", EmojiCategory::Symbols),
+    emoji("‼️", "bangbang", EmojiCategory::Symbols),
+    emoji("⁉️", "interrobang", EmojiCategory::Symbols),
+    emoji("⚠️", "warning", EmojiCategory::Symbols),
+    emoji("🚫", "no_entry_sign", EmojiCategory::Symbols),
+    emoji("⛔", "no_entry", EmojiCategory::Symbols),
+    emoji("♻️", "recycle", EmojiCategory::Symbols),
+    emoji("🔴", "red_circle", EmojiCategory::Symbols),
+    emoji("🟠", "orange_circle", EmojiCategory::Symbols),
+    emoji("🟡", "yellow_circle", EmojiCategory::Symbols),
+    emoji("🟢", "green_circle", EmojiCategory::Symbols),
+    emoji("🔵", "large_blue_circle", EmojiCategory::Symbols),
+    emoji("🟣", "purple_circle", EmojiCategory::Symbols),
+    emoji("⚫", "black_circle", EmojiCategory::Symbols),
+    emoji("⚪", "white_circle", EmojiCategory::Symbols),
+    emoji("🔺", "small_red_triangle", EmojiCategory::Symbols),
+    emoji("🔻", "small_red_triangle_down", EmojiCategory::Symbols),
+    emoji("➡️", "arrow_right", EmojiCategory::Symbols),
+    emoji("⬅️", "arrow_left", EmojiCategory::Symbols),
+    emoji("⬆️", "arrow_up", EmojiCategory::Symbols),
+    emoji("⬇️", "arrow_down", EmojiCategory::Symbols),
+    emoji("🔄", "arrows_counterclockwise", EmojiCategory::Symbols),
+    emoji("🆗", "ok", EmojiCategory::Symbols),
+    emoji("🆕", "new", EmojiCategory::Symbols),
+    emoji("🆒", "cool", EmojiCategory::Symbols),
+    emoji("🆓", "free", EmojiCategory::Symbols),
+    emoji("🆘", "sos", EmojiCategory::Symbols),
+    emoji("🔞", "underage", EmojiCategory::Symbols),
+    emoji("©️", "copyright", EmojiCategory::Symbols),
+    emoji("®️", "registered", EmojiCategory::Symbols),
+    emoji("™️", "tm", EmojiCategory::Symbols),
+    emoji("🏁", "checkered_flag", EmojiCategory::Symbols),
+    emoji("🚩", "triangular_flag_on_post", EmojiCategory::Symbols),
+    emoji("🏳️", "white_flag", EmojiCategory::Symbols),
+    emoji("🏴", "black_flag", EmojiCategory::Symbols),
+];
+
+/// Emoji listed under `category`, in table order.
+pub fn emoji_in_category(category: EmojiCategory) -> impl Iterator<Item = &'static Emoji> {
+    EMOJI.iter().filter(move |e| e.category == category)
+}
+
+/// Emoji whose shortcode matches `query` (case-insensitive): names
+/// starting with it first, then names containing it after a `_`.
+pub fn search_emoji(query: &str) -> Vec<&'static Emoji> {
+    let query = query.to_lowercase();
+    let prefix = EMOJI.iter().filter(|e| e.name.starts_with(&query));
+    let word = EMOJI.iter().filter(|e| {
+        !e.name.starts_with(&query)
+            && e.name
+                .match_indices('_')
+                .any(|(i, _)| e.name[i + 1..].starts_with(&query))
+    });
+    prefix.chain(word).collect()
+}
+
+/// Completion candidates for a `:shortcode` token; accepting one
+/// replaces the token with the emoji itself.
+pub fn shortcode_completions(query: &str) -> Vec<CompletionItem> {
+    search_emoji(query)
+        .into_iter()
+        .take(MAX_SHORTCODE_ITEMS)
+        .map(|e| CompletionItem {
+            label: format!("{} :{}:", e.emoji, e.name),
+            detail: String::new(),
+            insert_text: e.emoji.to_owned(),
+            mention_user_id: None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shortcodes_are_unique() {
+        let mut names: Vec<&str> = EMOJI.iter().map(|e| e.name).collect();
+        names.sort_unstable();
+        let count = names.len();
+        names.dedup();
+        assert_eq!(names.len(), count);
+    }
+
+    #[test]
+    fn every_category_has_emoji() {
+        for category in EmojiCategory::ALL {
+            assert!(emoji_in_category(category).next().is_some(), "{category:?}");
+        }
+    }
+
+    #[test]
+    fn prefix_matches_come_before_word_matches() {
+        let names: Vec<&str> = search_emoji("heart").iter().map(|e| e.name).collect();
+
+        assert_eq!(names[0], "heart_eyes");
+        assert!(names.contains(&"heart"));
+        let first_word_match = names.iter().position(|n| *n == "yellow_heart").unwrap();
+        let last_prefix_match = names.iter().rposition(|n| n.starts_with("heart")).unwrap();
+        assert!(last_prefix_match < first_word_match);
+    }
+
+    #[test]
+    fn search_is_case_insensitive() {
+        assert_eq!(search_emoji("FIRE")[0].emoji, "🔥");
+    }
+
+    #[test]
+    fn shortcode_completion_inserts_emoji() {
+        let items = shortcode_completions("fi");
+
+        let fire = items.iter().find(|i| i.label == "🔥 :fire:").unwrap();
+        assert_eq!(fire.insert_text, "🔥");
+        assert!(items.len() <= MAX_SHORTCODE_ITEMS);
+    }
+
+    #[test]
+    fn unknown_shortcode_has_no_completions() {
+        assert!(shortcode_completions("zzqx").is_empty());
+    }
+}
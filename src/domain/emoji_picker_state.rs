@@ -0,0 +1,209 @@
+//! State of the emoji picker popup.
+//!
+//! With an empty query the grid shows one category at a time (switched with
+//! Tab / Shift+Tab); typing a query searches shortcodes across all categories.
+
+use super::emoji::{emoji_in_category, search_emoji, Emoji, EmojiCategory};
+
+/// Number of emoji per grid row.
+pub const EMOJI_GRID_COLUMNS: usize = 8;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmojiPickerState {
+    query: String,
+    category: usize,
+    /// Index of the selected emoji in [`visible`](Self::visible).
+    selected: usize,
+    visible: Vec<&'static Emoji>,
+}
+
+impl Default for EmojiPickerState {
+    fn default() -> Self {
+        let mut state = Self {
+            query: String::new(),
+            category: 0,
+            selected: 0,
+            visible: Vec::new(),
+        };
+        state.refresh();
+        state
+    }
+}
+
+impl EmojiPickerState {
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// The category tab being shown; `None` while searching.
+    pub fn category(&self) -> Option<EmojiCategory> {
+        if self.query.is_empty() {
+            Some(EmojiCategory::ALL[self.category])
+        } else {
+            None
+        }
+    }
+
+    /// Emoji in the grid, row by row.
+    pub fn visible(&self) -> &[&'static Emoji] {
+        &self.visible
+    }
+
+    pub fn selected_index(&self) -> usize {
+        self.selected
+    }
+
+    pub fn selected_emoji(&self) -> Option<&'static Emoji> {
+        self.visible.get(self.selected).copied()
+    }
+
+    pub fn insert_char(&mut self, ch: char) {
+        self.query.push(ch);
+        self.refresh();
+    }
+
+    pub fn delete_char_before(&mut self) {
+        self.query.pop();
+        self.refresh();
+    }
+
+    pub fn next_category(&mut self) {
+        self.category = (self.category + 1) % EmojiCategory::ALL.len();
+        self.refresh();
+    }
+
+    pub fn previous_category(&mut self) {
+        self.category = self
+            .category
+            .checked_sub(1)
+            .unwrap_or(EmojiCategory::ALL.len() - 1);
+        self.refresh();
+    }
+
+    pub fn move_left(&mut self) {
+        if !self.selected.is_multiple_of(EMOJI_GRID_COLUMNS) {
+            self.selected -= 1;
+        }
+    }
+
+    pub fn move_right(&mut self) {
+        if self.selected % EMOJI_GRID_COLUMNS + 1 < EMOJI_GRID_COLUMNS
+            && self.selected + 1 < self.visible.len()
+        {
+            self.selected += 1;
+        }
+    }
+
+    pub fn move_up(&mut self) {
+        if self.selected >= EMOJI_GRID_COLUMNS {
+            self.selected -= EMOJI_GRID_COLUMNS;
+        }
+    }
+
+    /// Moves one row down, to the last emoji if the next row is shorter.
+    pub fn move_down(&mut self) {
+        let row_start = self.selected - self.selected % EMOJI_GRID_COLUMNS;
+        if row_start + EMOJI_GRID_COLUMNS < self.visible.len() {
+            self.selected = (self.selected + EMOJI_GRID_COLUMNS).min(self.visible.len() - 1);
+        }
+    }
+
+    fn refresh(&mut self) {
+        self.visible = match self.category() {
+            Some(category) => emoji_in_category(category).collect(),
+            None => search_emoji(&self.query),
+        };
+        self.selected = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn starts_on_first_category() {
+        let picker = EmojiPickerState::default();
+
+        assert_eq!(picker.category(), Some(EmojiCategory::Smileys));
+        assert_eq!(picker.selected_emoji().unwrap().name, "grinning");
+    }
+
+    #[test]
+    fn categories_wrap_around() {
+        let mut picker = EmojiPickerState::default();
+
+        picker.previous_category();
+        assert_eq!(picker.category(), Some(EmojiCategory::Symbols));
+
+        picker.next_category();
+        picker.next_category();
+        assert_eq!(picker.category(), Some(EmojiCategory::People));
+    }
+
+    #[test]
+    fn query_searches_all_categories() {
+        let mut picker = EmojiPickerState::default();
+
+        for ch in "rocket".chars() {
+            picker.insert_char(ch);
+        }
+
+        assert_eq!(picker.category(), None);
+        assert_eq!(picker.selected_emoji().unwrap().emoji, "🚀");
+
+        for _ in 0.."rocket".len() {
+            picker.delete_char_before();
+        }
+        assert_eq!(picker.category(), Some(EmojiCategory::Smileys));
+    }
+
+    #[test]
+    fn grid_movement_stays_within_rows_and_bounds() {
+        let mut picker = EmojiPickerState::default();
+
+        picker.move_left();
+        assert_eq!(picker.selected_index(), 0);
+
+        picker.move_down();
+        assert_eq!(picker.selected_index(), EMOJI_GRID_COLUMNS);
+
+        for _ in 0..EMOJI_GRID_COLUMNS + 2 {
+            picker.move_right();
+        }
+        assert_eq!(picker.selected_index(), 2 * EMOJI_GRID_COLUMNS - 1);
+
+        picker.move_up();
+        picker.move_up();
+        assert_eq!(picker.selected_index(), EMOJI_GRID_COLUMNS - 1);
+    }
+
+    #[test]
+    fn move_down_clamps_to_last_emoji() {
+        let mut picker = EmojiPickerState::default();
+        for ch in "heart".chars() {
+            picker.insert_char(ch);
+        }
+        let len = picker.visible().len();
+        assert!(len > EMOJI_GRID_COLUMNS && len < 3 * EMOJI_GRID_COLUMNS);
+
+        for _ in 0..EMOJI_GRID_COLUMNS - 1 {
+            picker.move_right();
+        }
+        picker.move_down();
+        picker.move_down();
+
+        assert_eq!(picker.selected_index(), len - 1);
+    }
+
+    #[test]
+    fn unknown_query_shows_nothing() {
+        let mut picker = EmojiPickerState::default();
+        for ch in "zzqx".chars() {
+            picker.insert_char(ch);
+        }
+
+        assert!(picker.visible().is_empty());
+        assert_eq!(picker.selected_emoji(), None);
+    }
+}
@@ -33,6 +33,9 @@ pub enum AppEvent {
 pub enum BackgroundTaskResult {
     /// Chat list fetch completed.
     ChatListLoaded {
+        /// The list that was fetched; results for a list the user has since
+        /// switched away from are discarded.
+        list: super::chat_folder::ChatListId,
         result: Result<Vec<super::chat::ChatSummary>, BackgroundError>,
         all_loaded: bool,
     },
@@ -78,6 +81,23 @@ pub enum BackgroundTaskResult {
         topic_id: Option<i32>,
         result: Result<Vec<super::message::Message>, BackgroundError>,
     },
+    /// History window around a date loaded for "jump to date". An empty
+    /// list means the chat has no messages that old.
+    MessagesAroundDateLoaded {
+        chat_id: i64,
+        topic_id: Option<i32>,
+        /// Start of the requested day, used to pick the message to select.
+        target_unix_ms: i64,
+        result: Result<Vec<super::message::Message>, BackgroundError>,
+    },
+    /// History window around the oldest unread mention/reaction loaded.
+    /// `None` means there was nothing of that kind left to read.
+    UnreadWindowLoaded {
+        chat_id: i64,
+        topic_id: Option<i32>,
+        kind: super::chat::UnreadJumpKind,
+        result: Result<Option<super::message::UnreadMessageWindow>, BackgroundError>,
+    },
     /// Message edit operation completed.
     MessageEdited {
         chat_id: i64,
@@ -103,12 +123,134 @@ pub enum BackgroundTaskResult {
         message_id: i64,
         result: Result<super::message_info_state::MessageInfo, BackgroundError>,
     },
+    /// Deletion of messages hidden by a committed undoable delete finished.
+    MessagesDeleted {
+        chat_id: i64,
+        message_ids: Vec<i64>,
+        result: Result<(), BackgroundError>,
+    },
+    /// Forwarding messages to another chat finished.
+    MessagesForwarded {
+        to_chat_id: i64,
+        count: usize,
+        result: Result<(), BackgroundError>,
+    },
+    /// Delete permissions resolved for the delete confirmation popup.
+    DeletePermissionsLoaded {
+        chat_id: i64,
+        message_ids: Vec<i64>,
+        result: Result<super::delete_confirm_state::DeletePermissions, BackgroundError>,
+    },
     /// Available reactions loaded for the reaction picker popup.
     AvailableReactionsLoaded {
         chat_id: i64,
         message_id: i64,
         result: Result<Vec<super::reaction_picker_state::AvailableReaction>, BackgroundError>,
     },
+    /// A bot answered an inline keyboard callback button.
+    CallbackAnswerLoaded {
+        chat_id: i64,
+        message_id: i64,
+        result: Result<super::message::CallbackAnswer, BackgroundError>,
+    },
+    /// Bot commands of a chat loaded for `/` completion.
+    BotCommandsLoaded {
+        chat_id: i64,
+        result: Result<Vec<super::bot_command::BotCommand>, BackgroundError>,
+    },
+    /// Group members found for an `@` completion query.
+    ChatMembersLoaded {
+        chat_id: i64,
+        query: String,
+        result: Result<Vec<super::chat_member::ChatMember>, BackgroundError>,
+    },
+    /// The `t.me` link of a message resolved for copying.
+    MessageLinkLoaded {
+        chat_id: i64,
+        message_id: i64,
+        result: Result<String, BackgroundError>,
+    },
+    /// A Telegram link resolved to the chat or message it points to.
+    LinkResolved {
+        url: String,
+        result: Result<super::link_target::LinkTarget, BackgroundError>,
+    },
+    /// Joining a chat by invite link completed.
+    ChatJoined {
+        invite_link: String,
+        result: Result<super::chat::ChatSummary, BackgroundError>,
+    },
+    /// The contact list loaded for the contacts panel.
+    ContactsLoaded {
+        result: Result<Vec<super::contact::Contact>, BackgroundError>,
+    },
+    /// Adding the peer of a private chat as a contact completed.
+    ContactAdded {
+        chat_id: i64,
+        result: Result<super::contact::Contact, BackgroundError>,
+    },
+    /// Removing a contact completed.
+    ContactRemoved {
+        user_id: i64,
+        result: Result<(), BackgroundError>,
+    },
+    /// The private chat with a contact is ready to open.
+    ContactChatLoaded {
+        user_id: i64,
+        result: Result<super::chat::ChatSummary, BackgroundError>,
+    },
+    /// A "go to chat" lookup found (or created) the chat to open.
+    ChatFound {
+        query: super::go_to_prompt_state::ChatQuery,
+        result: Result<super::chat::ChatSummary, BackgroundError>,
+    },
+    /// Chats found by the chat search. Local results come first;
+    /// `on_server` marks the server's, the last ones for `query`.
+    ChatsSearched {
+        query: String,
+        on_server: bool,
+        result: Result<Vec<super::chat::ChatSummary>, BackgroundError>,
+    },
+    /// A chat created from the "new chat" wizard is ready to open.
+    ChatCreated {
+        title: String,
+        result: Result<super::new_chat_wizard_state::CreatedChat, BackgroundError>,
+    },
+    /// A chat was moved to another chat list (archived or unarchived).
+    ChatMovedToList {
+        chat_id: i64,
+        list: super::chat_folder::ChatListId,
+        result: Result<(), BackgroundError>,
+    },
+    /// A chat was muted (`muted`) or unmuted.
+    ChatMuteChanged {
+        chat_id: i64,
+        muted: bool,
+        result: Result<(), BackgroundError>,
+    },
+    /// A chat's "marked as unread" flag was set (`marked`) or cleared.
+    ChatMarkedAsUnread {
+        chat_id: i64,
+        marked: bool,
+        result: Result<(), BackgroundError>,
+    },
+    /// A chat was pinned (`pinned`) or unpinned.
+    ChatPinToggled {
+        chat_id: i64,
+        pinned: bool,
+        result: Result<(), BackgroundError>,
+    },
+    /// The pinned chats of a chat list were reordered.
+    PinnedChatsReordered {
+        list: super::chat_folder::ChatListId,
+        result: Result<(), BackgroundError>,
+    },
+    /// A chat was left, deleted, cleared, or its user (un)blocked.
+    ChatManaged {
+        chat_id: i64,
+        op: super::chat_manage_state::ChatManageOp,
+        result: Result<(), BackgroundError>,
+    },
     /// Platform default file opener failed (non-zero exit code).
     /// The orchestrator should display the error as a notification.
     OpenFileFailed { stderr: String },
@@ -150,11 +292,17 @@ pub enum ChatUpdate {
     /// Chat metadata changed (last message, position, read state, etc.).
     /// The orchestrator should refresh the chat list.
     ChatMetadataChanged { chat_id: i64 },
-    /// Reaction count changed on a specific message.
+    /// Reactions changed on a specific message.
     MessageReactionsChanged {
         chat_id: i64,
         message_id: i64,
-        reaction_count: u32,
+        reactions: Vec<super::message::MessageReaction>,
+    },
+    /// The peer read outgoing messages up to `last_read_outbox_message_id`.
+    /// Read ticks are updated in place; the history is not refetched.
+    OutboxRead {
+        chat_id: i64,
+        last_read_outbox_message_id: i64,
     },
     /// User online/offline status changed.
     /// The orchestrator should update the open chat subtitle if it belongs to this user.
@@ -186,6 +334,21 @@ pub enum ChatUpdate {
         topic_id: i32,
         unread_topic_count: Option<u32>,
     },
+    /// Default mute settings of a kind of chats changed; the chat list is
+    /// refreshed so chats that follow them show the new mute state.
+    NotificationDefaultsChanged,
+    /// The user's chat folders changed; the folder tabs are rebuilt.
+    ChatFoldersChanged {
+        folders: Vec<super::chat_folder::ChatFolder>,
+        main_position: usize,
+    },
+    /// The chat counts of a chat list changed: the unread badge of a tab,
+    /// or the "Archive" row.
+    UnreadChatCountChanged {
+        list: super::chat_folder::ChatListId,
+        unread_count: u32,
+        total_count: u32,
+    },
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -0,0 +1,104 @@
+//! Fuzzy matching for filter-as-you-type lists.
+//!
+//! A query matches when its characters appear in the text in order,
+//! ignoring case. Matches at word starts and runs of consecutive
+//! characters score higher, so `as` ranks "Alice Smith" above "Vasya".
+
+const MATCH_SCORE: i32 = 1;
+const WORD_START_BONUS: i32 = 8;
+const CONSECUTIVE_BONUS: i32 = 4;
+
+/// A successful fuzzy match.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuzzyMatch {
+    pub score: i32,
+    /// Indices (in chars) of the characters of `text` the query matched,
+    /// for highlighting.
+    pub positions: Vec<usize>,
+}
+
+/// Scores `text` against `query`; `None` when it does not match.
+/// An empty query matches everything with score 0.
+pub fn fuzzy_score(query: &str, text: &str) -> Option<i32> {
+    fuzzy_match(query, text).map(|m| m.score)
+}
+
+/// Like [`fuzzy_score`], also reporting which characters matched.
+pub fn fuzzy_match(query: &str, text: &str) -> Option<FuzzyMatch> {
+    let query: Vec<char> = query.chars().flat_map(char::to_lowercase).collect();
+    if query.is_empty() {
+        return Some(FuzzyMatch {
+            score: 0,
+            positions: Vec::new(),
+        });
+    }
+
+    let mut score = 0;
+    let mut positions = Vec::new();
+    let mut next = 0;
+    let mut prev_char: Option<char> = None;
+    let mut prev_matched = false;
+
+    for (index, original) in text.chars().enumerate() {
+        for ch in original.to_lowercase() {
+            if next < query.len() && ch == query[next] {
+                score += MATCH_SCORE;
+                if prev_char.is_none_or(|p| !p.is_alphanumeric()) {
+                    score += WORD_START_BONUS;
+                }
+                if prev_matched {
+                    score += CONSECUTIVE_BONUS;
+                }
+                if positions.last() != Some(&index) {
+                    positions.push(index);
+                }
+                next += 1;
+                prev_matched = true;
+            } else {
+                prev_matched = false;
+            }
+            prev_char = Some(ch);
+        }
+    }
+
+    (next == query.len()).then_some(FuzzyMatch { score, positions })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_characters_in_order_ignoring_case() {
+        assert!(fuzzy_score("asm", "Alice Smith").is_some());
+        assert!(fuzzy_score("ALI", "alice").is_some());
+        assert!(fuzzy_score("ma", "Alice Smith").is_none());
+        assert!(fuzzy_score("alicex", "Alice").is_none());
+    }
+
+    #[test]
+    fn empty_query_matches_everything() {
+        assert_eq!(fuzzy_score("", "anything"), Some(0));
+    }
+
+    #[test]
+    fn word_starts_and_runs_rank_higher() {
+        let initials = fuzzy_score("as", "Alice Smith").unwrap();
+        let scattered = fuzzy_score("as", "Vasya").unwrap();
+        assert!(initials > scattered);
+
+        let run = fuzzy_score("ali", "Alice").unwrap();
+        let gaps = fuzzy_score("ali", "Amelia").unwrap();
+        assert!(run > gaps);
+    }
+
+    #[test]
+    fn reports_matched_positions_in_chars() {
+        let m = fuzzy_match("ёs", "Алёна Smith").unwrap();
+        assert_eq!(m.positions, vec![2, 6]);
+        assert_eq!(
+            fuzzy_match("", "Alice").unwrap().positions,
+            Vec::<usize>::new()
+        );
+    }
+}
@@ -0,0 +1,165 @@
+//! State and parsing for the "go to chat" prompt.
+//!
+//! The prompt accepts `@username`, a phone number (`+1 555 123-4567`) or a
+//! contact name. Parsing is pure; resolution happens in the background.
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GoToPromptState {
+    input: String,
+    cursor_position: usize,
+}
+
+impl GoToPromptState {
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    pub fn cursor_position(&self) -> usize {
+        self.cursor_position
+    }
+
+    pub fn insert_char(&mut self, ch: char) {
+        self.input.insert(self.cursor_position, ch);
+        self.cursor_position += ch.len_utf8();
+    }
+
+    pub fn delete_char_before(&mut self) {
+        if self.cursor_position == 0 {
+            return;
+        }
+        let prev = self.input[..self.cursor_position]
+            .char_indices()
+            .next_back()
+            .map(|(i, _)| i)
+            .unwrap_or(0);
+        self.input.drain(prev..self.cursor_position);
+        self.cursor_position = prev;
+    }
+}
+
+/// What the user typed into the "go to chat" prompt.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChatQuery {
+    /// A public username, without the leading `@`.
+    Username(String),
+    /// A phone number reduced to its digits.
+    Phone(String),
+    /// Free text matched against contact names.
+    Name(String),
+}
+
+impl ChatQuery {
+    /// Classifies prompt input. Returns `None` for blank input.
+    pub fn parse(input: &str) -> Option<Self> {
+        let input = input.trim();
+        if input.is_empty() {
+            return None;
+        }
+
+        if let Some(username) = input.strip_prefix('@') {
+            let username = username.trim();
+            return (!username.is_empty()).then(|| Self::Username(username.to_owned()));
+        }
+
+        if is_phone_like(input) {
+            let digits: String = input.chars().filter(char::is_ascii_digit).collect();
+            return Some(Self::Phone(digits));
+        }
+
+        Some(Self::Name(input.to_owned()))
+    }
+
+    /// Whether a name query could also be a username typed without `@`.
+    pub fn is_username_like(&self) -> bool {
+        match self {
+            Self::Username(_) => true,
+            Self::Name(name) => {
+                name.len() >= 4
+                    && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+                    && name.starts_with(|c: char| c.is_ascii_alphabetic())
+            }
+            Self::Phone(_) => false,
+        }
+    }
+}
+
+impl std::fmt::Display for ChatQuery {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Username(username) => write!(f, "@{username}"),
+            Self::Phone(digits) => write!(f, "+{digits}"),
+            Self::Name(name) => f.write_str(name),
+        }
+    }
+}
+
+/// Digits with optional `+` prefix and common separators, at least five
+/// digits long so short numeric names are not taken for phone numbers.
+fn is_phone_like(input: &str) -> bool {
+    let body = input.strip_prefix('+').unwrap_or(input);
+    let digits = body.chars().filter(char::is_ascii_digit).count();
+    digits >= 5
+        && body
+            .chars()
+            .all(|c| c.is_ascii_digit() || matches!(c, ' ' | '-' | '(' | ')' | '.'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn at_prefix_is_username() {
+        assert_eq!(
+            ChatQuery::parse(" @durov "),
+            Some(ChatQuery::Username("durov".to_owned()))
+        );
+        assert_eq!(ChatQuery::parse("@"), None);
+    }
+
+    #[test]
+    fn phone_numbers_are_reduced_to_digits() {
+        assert_eq!(
+            ChatQuery::parse("+1 (555) 123-4567"),
+            Some(ChatQuery::Phone("15551234567".to_owned()))
+        );
+        assert_eq!(
+            ChatQuery::parse("79991234567"),
+            Some(ChatQuery::Phone("79991234567".to_owned()))
+        );
+    }
+
+    #[test]
+    fn other_input_is_a_name() {
+        assert_eq!(
+            ChatQuery::parse("Alice Smith"),
+            Some(ChatQuery::Name("Alice Smith".to_owned()))
+        );
+        assert_eq!(
+            ChatQuery::parse("1234"),
+            Some(ChatQuery::Name("1234".to_owned()))
+        );
+        assert_eq!(ChatQuery::parse("   "), None);
+    }
+
+    #[test]
+    fn single_word_names_may_be_usernames() {
+        assert!(ChatQuery::Name("rust_lang".to_owned()).is_username_like());
+        assert!(!ChatQuery::Name("Alice Smith".to_owned()).is_username_like());
+        assert!(!ChatQuery::Name("bob".to_owned()).is_username_like());
+        assert!(!ChatQuery::Phone("15551234567".to_owned()).is_username_like());
+    }
+
+    #[test]
+    fn editing_handles_multibyte_chars() {
+        let mut state = GoToPromptState::default();
+        state.insert_char('ж');
+        state.insert_char('a');
+        state.delete_char_before();
+        state.delete_char_before();
+        state.delete_char_before();
+
+        assert_eq!(state.input(), "");
+        assert_eq!(state.cursor_position(), 0);
+    }
+}
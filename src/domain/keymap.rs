@@ -13,6 +13,33 @@ pub enum Action {
     ShowChatInfo,
     SearchChats,
     SelectFirstChat,
+    ShowContacts,
+    AddContact,
+    NewChat,
+    NextFolder,
+    PreviousFolder,
+    ArchiveChat,
+    UnarchiveChat,
+    CloseArchive,
+    MuteChatOneHour,
+    MuteChatEightHours,
+    MuteChatTwoDays,
+    MuteChatForever,
+    UnmuteChat,
+    TogglePinChat,
+    MovePinnedChatUp,
+    MovePinnedChatDown,
+    MarkChatAsUnread,
+    LeaveOrDeleteChat,
+    ClearChatHistory,
+    ToggleBlockUser,
+    FilterUnreadChats,
+    FilterPrivateChats,
+    FilterGroupChats,
+    FilterChannelChats,
+    FilterBotChats,
+    FilterHideMutedChats,
+    ClearChatFilters,
     // ForumTopicList
     SelectNextTopic,
     SelectPreviousTopic,
@@ -20,6 +47,15 @@ pub enum Action {
     OpenForumTopic,
     BackFromForum,
     ReloadForumTopics,
+    // Contacts
+    SelectNextContact,
+    SelectPreviousContact,
+    SelectFirstContact,
+    OpenContactChat,
+    FilterContacts,
+    RemoveContact,
+    ReloadContacts,
+    CloseContacts,
     // Messages
     ScrollNextMessage,
     ScrollPreviousMessage,
@@ -28,7 +64,10 @@ pub enum Action {
     ReplyToMessage,
     EditMessage,
     CopyMessage,
+    CopyMessageLink,
     DeleteMessage,
+    ForwardMessage,
+    ToggleVisualMode,
     OpenMessage,
     OpenLink,
     RecordVoice,
@@ -37,9 +76,20 @@ pub enum Action {
     DownloadFile,
     SaveFile,
     ScrollToLastMessage,
+    JumpToDate,
+    NextMention,
+    NextUnreadReaction,
+    SelectInlineButton,
+    SelectReplyKeyboardButton,
+    // MessageInput
+    InsertEmoji,
     // Global
     Quit,
     ShowHelp,
+    Undo,
+    GoToChat,
+    QuickSwitchChat,
+    PreviousChat,
 }
 
 impl Action {
@@ -53,12 +103,47 @@ impl Action {
             Self::ShowChatInfo => "show_chat_info",
             Self::SearchChats => "search_chats",
             Self::SelectFirstChat => "select_first_chat",
+            Self::ShowContacts => "show_contacts",
+            Self::AddContact => "add_contact",
+            Self::NewChat => "new_chat",
+            Self::NextFolder => "next_folder",
+            Self::PreviousFolder => "previous_folder",
+            Self::ArchiveChat => "archive_chat",
+            Self::UnarchiveChat => "unarchive_chat",
+            Self::CloseArchive => "close_archive",
+            Self::MuteChatOneHour => "mute_chat_1h",
+            Self::MuteChatEightHours => "mute_chat_8h",
+            Self::MuteChatTwoDays => "mute_chat_2d",
+            Self::MuteChatForever => "mute_chat_forever",
+            Self::UnmuteChat => "unmute_chat",
+            Self::TogglePinChat => "toggle_pin_chat",
+            Self::MovePinnedChatUp => "move_pinned_chat_up",
+            Self::MovePinnedChatDown => "move_pinned_chat_down",
+            Self::MarkChatAsUnread => "mark_chat_as_unread",
+            Self::LeaveOrDeleteChat => "leave_or_delete_chat",
+            Self::ClearChatHistory => "clear_chat_history",
+            Self::ToggleBlockUser => "toggle_block_user",
+            Self::FilterUnreadChats => "filter_unread_chats",
+            Self::FilterPrivateChats => "filter_private_chats",
+            Self::FilterGroupChats => "filter_group_chats",
+            Self::FilterChannelChats => "filter_channel_chats",
+            Self::FilterBotChats => "filter_bot_chats",
+            Self::FilterHideMutedChats => "filter_hide_muted_chats",
+            Self::ClearChatFilters => "clear_chat_filters",
             Self::SelectNextTopic => "select_next_topic",
             Self::SelectPreviousTopic => "select_previous_topic",
             Self::SelectFirstTopic => "select_first_topic",
             Self::OpenForumTopic => "open_forum_topic",
             Self::BackFromForum => "back_from_forum",
             Self::ReloadForumTopics => "reload_forum_topics",
+            Self::SelectNextContact => "select_next_contact",
+            Self::SelectPreviousContact => "select_previous_contact",
+            Self::SelectFirstContact => "select_first_contact",
+            Self::OpenContactChat => "open_contact_chat",
+            Self::FilterContacts => "filter_contacts",
+            Self::RemoveContact => "remove_contact",
+            Self::ReloadContacts => "reload_contacts",
+            Self::CloseContacts => "close_contacts",
             Self::ScrollNextMessage => "scroll_to_next_message",
             Self::ScrollPreviousMessage => "scroll_to_previous_message",
             Self::BackToChatList => "back_to_chat_list",
@@ -66,7 +151,10 @@ impl Action {
             Self::ReplyToMessage => "reply_to_message",
             Self::EditMessage => "edit_message",
             Self::CopyMessage => "copy_message_to_clipboard",
+            Self::CopyMessageLink => "copy_message_link",
             Self::DeleteMessage => "delete_message",
+            Self::ForwardMessage => "forward_message",
+            Self::ToggleVisualMode => "toggle_visual_mode",
             Self::OpenMessage => "open_message",
             Self::OpenLink => "open_link_in_browser",
             Self::RecordVoice => "record_voice_message",
@@ -75,8 +163,18 @@ impl Action {
             Self::DownloadFile => "download_file",
             Self::SaveFile => "save_file_to_downloads",
             Self::ScrollToLastMessage => "scroll_to_last_message",
+            Self::JumpToDate => "jump_to_date",
+            Self::NextMention => "next_mention",
+            Self::NextUnreadReaction => "next_unread_reaction",
+            Self::SelectInlineButton => "select_inline_button",
+            Self::SelectReplyKeyboardButton => "select_reply_keyboard_button",
+            Self::InsertEmoji => "insert_emoji",
             Self::Quit => "quit",
             Self::ShowHelp => "show_help",
+            Self::Undo => "undo",
+            Self::GoToChat => "go_to_chat",
+            Self::QuickSwitchChat => "quick_switch_chat",
+            Self::PreviousChat => "previous_chat",
         }
     }
 
@@ -90,12 +188,47 @@ impl Action {
             "show_chat_info" => Some(Self::ShowChatInfo),
             "search_chats" => Some(Self::SearchChats),
             "select_first_chat" => Some(Self::SelectFirstChat),
+            "show_contacts" => Some(Self::ShowContacts),
+            "add_contact" => Some(Self::AddContact),
+            "new_chat" => Some(Self::NewChat),
+            "next_folder" => Some(Self::NextFolder),
+            "previous_folder" => Some(Self::PreviousFolder),
+            "archive_chat" => Some(Self::ArchiveChat),
+            "unarchive_chat" => Some(Self::UnarchiveChat),
+            "close_archive" => Some(Self::CloseArchive),
+            "mute_chat_1h" => Some(Self::MuteChatOneHour),
+            "mute_chat_8h" => Some(Self::MuteChatEightHours),
+            "mute_chat_2d" => Some(Self::MuteChatTwoDays),
+            "mute_chat_forever" => Some(Self::MuteChatForever),
+            "unmute_chat" => Some(Self::UnmuteChat),
+            "toggle_pin_chat" => Some(Self::TogglePinChat),
+            "move_pinned_chat_up" => Some(Self::MovePinnedChatUp),
+            "move_pinned_chat_down" => Some(Self::MovePinnedChatDown),
+            "mark_chat_as_unread" => Some(Self::MarkChatAsUnread),
+            "leave_or_delete_chat" => Some(Self::LeaveOrDeleteChat),
+            "clear_chat_history" => Some(Self::ClearChatHistory),
+            "toggle_block_user" => Some(Self::ToggleBlockUser),
+            "filter_unread_chats" => Some(Self::FilterUnreadChats),
+            "filter_private_chats" => Some(Self::FilterPrivateChats),
+            "filter_group_chats" => Some(Self::FilterGroupChats),
+            "filter_channel_chats" => Some(Self::FilterChannelChats),
+            "filter_bot_chats" => Some(Self::FilterBotChats),
+            "filter_hide_muted_chats" => Some(Self::FilterHideMutedChats),
+            "clear_chat_filters" => Some(Self::ClearChatFilters),
             "select_next_topic" => Some(Self::SelectNextTopic),
             "select_previous_topic" => Some(Self::SelectPreviousTopic),
             "select_first_topic" => Some(Self::SelectFirstTopic),
             "open_forum_topic" => Some(Self::OpenForumTopic),
             "back_from_forum" => Some(Self::BackFromForum),
             "reload_forum_topics" => Some(Self::ReloadForumTopics),
+            "select_next_contact" => Some(Self::SelectNextContact),
+            "select_previous_contact" => Some(Self::SelectPreviousContact),
+            "select_first_contact" => Some(Self::SelectFirstContact),
+            "open_contact_chat" => Some(Self::OpenContactChat),
+            "filter_contacts" => Some(Self::FilterContacts),
+            "remove_contact" => Some(Self::RemoveContact),
+            "reload_contacts" => Some(Self::ReloadContacts),
+            "close_contacts" => Some(Self::CloseContacts),
             "scroll_to_next_message" => Some(Self::ScrollNextMessage),
             "scroll_to_previous_message" => Some(Self::ScrollPreviousMessage),
             "back_to_chat_list" => Some(Self::BackToChatList),
@@ -103,7 +236,10 @@ impl Action {
             "reply_to_message" => Some(Self::ReplyToMessage),
             "edit_message" => Some(Self::EditMessage),
             "copy_message_to_clipboard" => Some(Self::CopyMessage),
+            "copy_message_link" => Some(Self::CopyMessageLink),
             "delete_message" => Some(Self::DeleteMessage),
+            "forward_message" => Some(Self::ForwardMessage),
+            "toggle_visual_mode" => Some(Self::ToggleVisualMode),
             "open_message" => Some(Self::OpenMessage),
             "open_link_in_browser" => Some(Self::OpenLink),
             "record_voice_message" => Some(Self::RecordVoice),
@@ -112,8 +248,18 @@ impl Action {
             "download_file" => Some(Self::DownloadFile),
             "save_file_to_downloads" => Some(Self::SaveFile),
             "scroll_to_last_message" => Some(Self::ScrollToLastMessage),
+            "jump_to_date" => Some(Self::JumpToDate),
+            "next_mention" => Some(Self::NextMention),
+            "next_unread_reaction" => Some(Self::NextUnreadReaction),
+            "select_inline_button" => Some(Self::SelectInlineButton),
+            "select_reply_keyboard_button" => Some(Self::SelectReplyKeyboardButton),
+            "insert_emoji" => Some(Self::InsertEmoji),
             "quit" => Some(Self::Quit),
             "show_help" => Some(Self::ShowHelp),
+            "undo" => Some(Self::Undo),
+            "go_to_chat" => Some(Self::GoToChat),
+            "quick_switch_chat" => Some(Self::QuickSwitchChat),
+            "previous_chat" => Some(Self::PreviousChat),
             _ => None,
         }
     }
@@ -197,7 +343,11 @@ impl KeyPattern {
 pub enum KeyContext {
     ChatList,
     ForumTopicList,
+    /// The contacts panel shown over the chat list.
+    Contacts,
     Messages,
+    /// Typing in the message input; only Ctrl bindings are resolved here.
+    MessageInput,
     Global,
 }
 
@@ -469,6 +619,146 @@ fn default_bindings() -> Vec<KeyBinding> {
             action: Action::SelectFirstChat,
             context: KeyContext::ChatList,
         },
+        KeyBinding {
+            pattern: KeyPattern::single("C"),
+            action: Action::ShowContacts,
+            context: KeyContext::ChatList,
+        },
+        KeyBinding {
+            pattern: KeyPattern::single("A"),
+            action: Action::AddContact,
+            context: KeyContext::ChatList,
+        },
+        KeyBinding {
+            pattern: KeyPattern::single("N"),
+            action: Action::NewChat,
+            context: KeyContext::ChatList,
+        },
+        KeyBinding {
+            pattern: KeyPattern::single("]"),
+            action: Action::NextFolder,
+            context: KeyContext::ChatList,
+        },
+        KeyBinding {
+            pattern: KeyPattern::single("["),
+            action: Action::PreviousFolder,
+            context: KeyContext::ChatList,
+        },
+        KeyBinding {
+            pattern: KeyPattern::single("a"),
+            action: Action::ArchiveChat,
+            context: KeyContext::ChatList,
+        },
+        KeyBinding {
+            pattern: KeyPattern::single("U"),
+            action: Action::UnarchiveChat,
+            context: KeyContext::ChatList,
+        },
+        KeyBinding {
+            pattern: KeyPattern::single("h"),
+            action: Action::CloseArchive,
+            context: KeyContext::ChatList,
+        },
+        KeyBinding {
+            pattern: KeyPattern::single("esc"),
+            action: Action::CloseArchive,
+            context: KeyContext::ChatList,
+        },
+        KeyBinding {
+            pattern: KeyPattern::sequence(vec!["m", "1"]),
+            action: Action::MuteChatOneHour,
+            context: KeyContext::ChatList,
+        },
+        KeyBinding {
+            pattern: KeyPattern::sequence(vec!["m", "8"]),
+            action: Action::MuteChatEightHours,
+            context: KeyContext::ChatList,
+        },
+        KeyBinding {
+            pattern: KeyPattern::sequence(vec!["m", "2"]),
+            action: Action::MuteChatTwoDays,
+            context: KeyContext::ChatList,
+        },
+        KeyBinding {
+            pattern: KeyPattern::sequence(vec!["m", "m"]),
+            action: Action::MuteChatForever,
+            context: KeyContext::ChatList,
+        },
+        KeyBinding {
+            pattern: KeyPattern::single("M"),
+            action: Action::UnmuteChat,
+            context: KeyContext::ChatList,
+        },
+        KeyBinding {
+            pattern: KeyPattern::single("p"),
+            action: Action::TogglePinChat,
+            context: KeyContext::ChatList,
+        },
+        KeyBinding {
+            pattern: KeyPattern::single("K"),
+            action: Action::MovePinnedChatUp,
+            context: KeyContext::ChatList,
+        },
+        KeyBinding {
+            pattern: KeyPattern::single("J"),
+            action: Action::MovePinnedChatDown,
+            context: KeyContext::ChatList,
+        },
+        KeyBinding {
+            pattern: KeyPattern::single("!"),
+            action: Action::MarkChatAsUnread,
+            context: KeyContext::ChatList,
+        },
+        KeyBinding {
+            pattern: KeyPattern::sequence(vec!["d", "d"]),
+            action: Action::LeaveOrDeleteChat,
+            context: KeyContext::ChatList,
+        },
+        KeyBinding {
+            pattern: KeyPattern::single("X"),
+            action: Action::ClearChatHistory,
+            context: KeyContext::ChatList,
+        },
+        KeyBinding {
+            pattern: KeyPattern::single("B"),
+            action: Action::ToggleBlockUser,
+            context: KeyContext::ChatList,
+        },
+        KeyBinding {
+            pattern: KeyPattern::sequence(vec!["f", "u"]),
+            action: Action::FilterUnreadChats,
+            context: KeyContext::ChatList,
+        },
+        KeyBinding {
+            pattern: KeyPattern::sequence(vec!["f", "p"]),
+            action: Action::FilterPrivateChats,
+            context: KeyContext::ChatList,
+        },
+        KeyBinding {
+            pattern: KeyPattern::sequence(vec!["f", "g"]),
+            action: Action::FilterGroupChats,
+            context: KeyContext::ChatList,
+        },
+        KeyBinding {
+            pattern: KeyPattern::sequence(vec!["f", "c"]),
+            action: Action::FilterChannelChats,
+            context: KeyContext::ChatList,
+        },
+        KeyBinding {
+            pattern: KeyPattern::sequence(vec!["f", "b"]),
+            action: Action::FilterBotChats,
+            context: KeyContext::ChatList,
+        },
+        KeyBinding {
+            pattern: KeyPattern::sequence(vec!["f", "m"]),
+            action: Action::FilterHideMutedChats,
+            context: KeyContext::ChatList,
+        },
+        KeyBinding {
+            pattern: KeyPattern::sequence(vec!["f", "0"]),
+            action: Action::ClearChatFilters,
+            context: KeyContext::ChatList,
+        },
         // ── ForumTopicList ──
         KeyBinding {
             pattern: KeyPattern::single("j"),
@@ -505,6 +795,62 @@ fn default_bindings() -> Vec<KeyBinding> {
             action: Action::ReloadForumTopics,
             context: KeyContext::ForumTopicList,
         },
+        // ── Contacts ──
+        KeyBinding {
+            pattern: KeyPattern::single("j"),
+            action: Action::SelectNextContact,
+            context: KeyContext::Contacts,
+        },
+        KeyBinding {
+            pattern: KeyPattern::single("k"),
+            action: Action::SelectPreviousContact,
+            context: KeyContext::Contacts,
+        },
+        KeyBinding {
+            pattern: KeyPattern::sequence(vec!["g", "g"]),
+            action: Action::SelectFirstContact,
+            context: KeyContext::Contacts,
+        },
+        KeyBinding {
+            pattern: KeyPattern::single("enter"),
+            action: Action::OpenContactChat,
+            context: KeyContext::Contacts,
+        },
+        KeyBinding {
+            pattern: KeyPattern::single("l"),
+            action: Action::OpenContactChat,
+            context: KeyContext::Contacts,
+        },
+        KeyBinding {
+            pattern: KeyPattern::single("/"),
+            action: Action::FilterContacts,
+            context: KeyContext::Contacts,
+        },
+        KeyBinding {
+            pattern: KeyPattern::sequence(vec!["d", "d"]),
+            action: Action::RemoveContact,
+            context: KeyContext::Contacts,
+        },
+        KeyBinding {
+            pattern: KeyPattern::single("R"),
+            action: Action::ReloadContacts,
+            context: KeyContext::Contacts,
+        },
+        KeyBinding {
+            pattern: KeyPattern::single("h"),
+            action: Action::CloseContacts,
+            context: KeyContext::Contacts,
+        },
+        KeyBinding {
+            pattern: KeyPattern::single("esc"),
+            action: Action::CloseContacts,
+            context: KeyContext::Contacts,
+        },
+        KeyBinding {
+            pattern: KeyPattern::single("C"),
+            action: Action::CloseContacts,
+            context: KeyContext::Contacts,
+        },
         // ── Messages ──
         KeyBinding {
             pattern: KeyPattern::single("G"),
@@ -551,6 +897,11 @@ fn default_bindings() -> Vec<KeyBinding> {
             action: Action::CopyMessage,
             context: KeyContext::Messages,
         },
+        KeyBinding {
+            pattern: KeyPattern::single("Y"),
+            action: Action::CopyMessageLink,
+            context: KeyContext::Messages,
+        },
         KeyBinding {
             pattern: KeyPattern::sequence(vec!["d", "d"]),
             action: Action::DeleteMessage,
@@ -566,8 +917,18 @@ fn default_bindings() -> Vec<KeyBinding> {
             action: Action::OpenLink,
             context: KeyContext::Messages,
         },
+        KeyBinding {
+            pattern: KeyPattern::single("f"),
+            action: Action::ForwardMessage,
+            context: KeyContext::Messages,
+        },
         KeyBinding {
             pattern: KeyPattern::single("v"),
+            action: Action::ToggleVisualMode,
+            context: KeyContext::Messages,
+        },
+        KeyBinding {
+            pattern: KeyPattern::single("V"),
             action: Action::RecordVoice,
             context: KeyContext::Messages,
         },
@@ -591,6 +952,42 @@ fn default_bindings() -> Vec<KeyBinding> {
             action: Action::AddReaction,
             context: KeyContext::Messages,
         },
+        KeyBinding {
+            pattern: KeyPattern::sequence(vec!["g", "d"]),
+            action: Action::JumpToDate,
+            context: KeyContext::Messages,
+        },
+        KeyBinding {
+            pattern: KeyPattern::sequence(vec!["g", "m"]),
+            action: Action::NextMention,
+            context: KeyContext::Messages,
+        },
+        KeyBinding {
+            pattern: KeyPattern::sequence(vec!["g", "r"]),
+            action: Action::NextUnreadReaction,
+            context: KeyContext::Messages,
+        },
+        KeyBinding {
+            pattern: KeyPattern::single("b"),
+            action: Action::SelectInlineButton,
+            context: KeyContext::Messages,
+        },
+        KeyBinding {
+            pattern: KeyPattern::single("B"),
+            action: Action::SelectReplyKeyboardButton,
+            context: KeyContext::Messages,
+        },
+        KeyBinding {
+            pattern: KeyPattern::single("A"),
+            action: Action::AddContact,
+            context: KeyContext::Messages,
+        },
+        // ── MessageInput ──
+        KeyBinding {
+            pattern: KeyPattern::single_ctrl("e"),
+            action: Action::InsertEmoji,
+            context: KeyContext::MessageInput,
+        },
         // ── Global ──
         KeyBinding {
             pattern: KeyPattern::single("q"),
@@ -602,6 +999,27 @@ fn default_bindings() -> Vec<KeyBinding> {
             action: Action::ShowHelp,
             context: KeyContext::Global,
         },
+        KeyBinding {
+            pattern: KeyPattern::single("u"),
+            action: Action::Undo,
+            context: KeyContext::Global,
+        },
+        KeyBinding {
+            pattern: KeyPattern::single("@"),
+            action: Action::GoToChat,
+            context: KeyContext::Global,
+        },
+        KeyBinding {
+            pattern: KeyPattern::single_ctrl("k"),
+            action: Action::QuickSwitchChat,
+            context: KeyContext::Global,
+        },
+        // Terminals send Ctrl+^ as Ctrl+6.
+        KeyBinding {
+            pattern: KeyPattern::single_ctrl("6"),
+            action: Action::PreviousChat,
+            context: KeyContext::Global,
+        },
     ]
 }
 
@@ -826,6 +1244,7 @@ mod tests {
             Action::SelectNextChat,
             Action::SelectFirstChat,
             Action::DeleteMessage,
+            Action::JumpToDate,
             Action::Quit,
             Action::ShowHelp,
         ];
@@ -851,12 +1270,336 @@ mod tests {
     #[test]
     fn gg_sequence_not_in_messages() {
         let mut km = Keymap::default();
+        // `g` is a prefix of `gd` in Messages, but `gg` is not bound there.
+        assert_eq!(
+            km.resolve("g", false, KeyContext::Messages),
+            ResolveResult::Pending
+        );
         assert_eq!(
             km.resolve("g", false, KeyContext::Messages),
             ResolveResult::Unmatched
         );
     }
 
+    #[test]
+    fn gd_sequence_jumps_to_date_in_messages() {
+        let mut km = Keymap::default();
+        assert_eq!(
+            km.resolve("g", false, KeyContext::Messages),
+            ResolveResult::Pending
+        );
+        assert_eq!(
+            km.resolve("d", false, KeyContext::Messages),
+            ResolveResult::Action(Action::JumpToDate)
+        );
+    }
+
+    #[test]
+    fn b_selects_inline_button_in_messages() {
+        let mut km = Keymap::default();
+        assert_eq!(
+            km.resolve("b", false, KeyContext::Messages),
+            ResolveResult::Action(Action::SelectInlineButton)
+        );
+    }
+
+    #[test]
+    fn shift_b_selects_reply_keyboard_button_in_messages() {
+        let mut km = Keymap::default();
+        assert_eq!(
+            km.resolve("B", false, KeyContext::Messages),
+            ResolveResult::Action(Action::SelectReplyKeyboardButton)
+        );
+    }
+
+    #[test]
+    fn ctrl_e_inserts_emoji_only_in_message_input() {
+        let mut km = Keymap::default();
+        assert_eq!(
+            km.resolve("e", true, KeyContext::MessageInput),
+            ResolveResult::Action(Action::InsertEmoji)
+        );
+        assert_eq!(
+            km.resolve("e", false, KeyContext::MessageInput),
+            ResolveResult::Unmatched
+        );
+        assert_eq!(
+            km.resolve("e", true, KeyContext::Messages),
+            ResolveResult::Unmatched
+        );
+    }
+
+    #[test]
+    fn u_undoes_in_every_pane() {
+        let mut km = Keymap::default();
+        for context in [
+            KeyContext::ChatList,
+            KeyContext::ForumTopicList,
+            KeyContext::Messages,
+        ] {
+            assert_eq!(
+                km.resolve("u", false, context),
+                ResolveResult::Action(Action::Undo)
+            );
+        }
+    }
+
+    #[test]
+    fn contacts_keys_resolve_in_contacts_context() {
+        let mut km = Keymap::default();
+        assert_eq!(
+            km.resolve("C", false, KeyContext::ChatList),
+            ResolveResult::Action(Action::ShowContacts)
+        );
+        assert_eq!(
+            km.resolve("j", false, KeyContext::Contacts),
+            ResolveResult::Action(Action::SelectNextContact)
+        );
+        assert_eq!(
+            km.resolve("C", false, KeyContext::Contacts),
+            ResolveResult::Action(Action::CloseContacts)
+        );
+        assert_eq!(
+            km.resolve("d", false, KeyContext::Contacts),
+            ResolveResult::Pending
+        );
+        assert_eq!(
+            km.resolve("d", false, KeyContext::Contacts),
+            ResolveResult::Action(Action::RemoveContact)
+        );
+        assert_eq!(
+            km.resolve("A", false, KeyContext::Messages),
+            ResolveResult::Action(Action::AddContact)
+        );
+    }
+
+    #[test]
+    fn n_opens_new_chat_wizard_from_chat_list() {
+        let mut km = Keymap::default();
+        assert_eq!(
+            km.resolve("N", false, KeyContext::ChatList),
+            ResolveResult::Action(Action::NewChat)
+        );
+        assert_eq!(Action::from_name("new_chat"), Some(Action::NewChat));
+    }
+
+    #[test]
+    fn brackets_cycle_chat_folders() {
+        let mut km = Keymap::default();
+        assert_eq!(
+            km.resolve("]", false, KeyContext::ChatList),
+            ResolveResult::Action(Action::NextFolder)
+        );
+        assert_eq!(
+            km.resolve("[", false, KeyContext::ChatList),
+            ResolveResult::Action(Action::PreviousFolder)
+        );
+        assert_eq!(
+            Action::from_name("previous_folder"),
+            Some(Action::PreviousFolder)
+        );
+    }
+
+    #[test]
+    fn m_sequences_mute_chat_and_shift_m_unmutes() {
+        let mut km = Keymap::default();
+        assert_eq!(
+            km.resolve("m", false, KeyContext::ChatList),
+            ResolveResult::Pending
+        );
+        assert_eq!(
+            km.resolve("8", false, KeyContext::ChatList),
+            ResolveResult::Action(Action::MuteChatEightHours)
+        );
+        km.resolve("m", false, KeyContext::ChatList);
+        assert_eq!(
+            km.resolve("m", false, KeyContext::ChatList),
+            ResolveResult::Action(Action::MuteChatForever)
+        );
+        assert_eq!(
+            km.resolve("M", false, KeyContext::ChatList),
+            ResolveResult::Action(Action::UnmuteChat)
+        );
+        assert_eq!(
+            Action::from_name("mute_chat_2d"),
+            Some(Action::MuteChatTwoDays)
+        );
+    }
+
+    #[test]
+    fn pin_and_unread_mark_keys_in_chat_list() {
+        let mut km = Keymap::default();
+        assert_eq!(
+            km.resolve("p", false, KeyContext::ChatList),
+            ResolveResult::Action(Action::TogglePinChat)
+        );
+        assert_eq!(
+            km.resolve("K", false, KeyContext::ChatList),
+            ResolveResult::Action(Action::MovePinnedChatUp)
+        );
+        assert_eq!(
+            km.resolve("J", false, KeyContext::ChatList),
+            ResolveResult::Action(Action::MovePinnedChatDown)
+        );
+        assert_eq!(
+            km.resolve("!", false, KeyContext::ChatList),
+            ResolveResult::Action(Action::MarkChatAsUnread)
+        );
+    }
+
+    #[test]
+    fn chat_management_keys_in_chat_list() {
+        let mut km = Keymap::default();
+        assert_eq!(
+            km.resolve("d", false, KeyContext::ChatList),
+            ResolveResult::Pending
+        );
+        assert_eq!(
+            km.resolve("d", false, KeyContext::ChatList),
+            ResolveResult::Action(Action::LeaveOrDeleteChat)
+        );
+        assert_eq!(
+            km.resolve("X", false, KeyContext::ChatList),
+            ResolveResult::Action(Action::ClearChatHistory)
+        );
+        assert_eq!(
+            km.resolve("B", false, KeyContext::ChatList),
+            ResolveResult::Action(Action::ToggleBlockUser)
+        );
+    }
+
+    #[test]
+    fn filter_keys_in_chat_list() {
+        let mut km = Keymap::default();
+        assert_eq!(
+            km.resolve("f", false, KeyContext::ChatList),
+            ResolveResult::Pending
+        );
+        assert_eq!(
+            km.resolve("u", false, KeyContext::ChatList),
+            ResolveResult::Action(Action::FilterUnreadChats)
+        );
+        km.resolve("f", false, KeyContext::ChatList);
+        assert_eq!(
+            km.resolve("p", false, KeyContext::ChatList),
+            ResolveResult::Action(Action::FilterPrivateChats)
+        );
+        km.resolve("f", false, KeyContext::ChatList);
+        assert_eq!(
+            km.resolve("0", false, KeyContext::ChatList),
+            ResolveResult::Action(Action::ClearChatFilters)
+        );
+    }
+
+    #[test]
+    fn archive_keys_in_chat_list() {
+        let mut km = Keymap::default();
+        assert_eq!(
+            km.resolve("a", false, KeyContext::ChatList),
+            ResolveResult::Action(Action::ArchiveChat)
+        );
+        assert_eq!(
+            km.resolve("U", false, KeyContext::ChatList),
+            ResolveResult::Action(Action::UnarchiveChat)
+        );
+        assert_eq!(
+            km.resolve("h", false, KeyContext::ChatList),
+            ResolveResult::Action(Action::CloseArchive)
+        );
+        assert_eq!(
+            km.resolve("esc", false, KeyContext::ChatList),
+            ResolveResult::Action(Action::CloseArchive)
+        );
+    }
+
+    #[test]
+    fn at_opens_go_to_chat_everywhere() {
+        let mut km = Keymap::default();
+        for context in [KeyContext::ChatList, KeyContext::Messages] {
+            assert_eq!(
+                km.resolve("@", false, context),
+                ResolveResult::Action(Action::GoToChat)
+            );
+        }
+        assert_eq!(Action::from_name("go_to_chat"), Some(Action::GoToChat));
+    }
+
+    #[test]
+    fn ctrl_k_and_ctrl_6_switch_chats_everywhere() {
+        let mut km = Keymap::default();
+        for context in [
+            KeyContext::ChatList,
+            KeyContext::ForumTopicList,
+            KeyContext::Messages,
+        ] {
+            assert_eq!(
+                km.resolve("k", true, context),
+                ResolveResult::Action(Action::QuickSwitchChat)
+            );
+            assert_eq!(
+                km.resolve("6", true, context),
+                ResolveResult::Action(Action::PreviousChat)
+            );
+        }
+        assert_eq!(
+            km.resolve("k", false, KeyContext::ChatList),
+            ResolveResult::Action(Action::SelectPreviousChat)
+        );
+        assert_eq!(
+            Action::from_name("quick_switch_chat"),
+            Some(Action::QuickSwitchChat)
+        );
+        assert_eq!(
+            Action::from_name("previous_chat"),
+            Some(Action::PreviousChat)
+        );
+    }
+
+    #[test]
+    fn shift_y_copies_message_link() {
+        let mut km = Keymap::default();
+        assert_eq!(
+            km.resolve("Y", false, KeyContext::Messages),
+            ResolveResult::Action(Action::CopyMessageLink)
+        );
+        assert_eq!(
+            Action::from_name("copy_message_link"),
+            Some(Action::CopyMessageLink)
+        );
+    }
+
+    #[test]
+    fn v_toggles_visual_mode_and_shift_v_records_voice() {
+        let mut km = Keymap::default();
+        assert_eq!(
+            km.resolve("v", false, KeyContext::Messages),
+            ResolveResult::Action(Action::ToggleVisualMode)
+        );
+        assert_eq!(
+            km.resolve("V", false, KeyContext::Messages),
+            ResolveResult::Action(Action::RecordVoice)
+        );
+        assert_eq!(
+            km.resolve("f", false, KeyContext::Messages),
+            ResolveResult::Action(Action::ForwardMessage)
+        );
+    }
+
+    #[test]
+    fn gm_and_gr_jump_to_unread_in_messages() {
+        let mut km = Keymap::default();
+        km.resolve("g", false, KeyContext::Messages);
+        assert_eq!(
+            km.resolve("m", false, KeyContext::Messages),
+            ResolveResult::Action(Action::NextMention)
+        );
+        km.resolve("g", false, KeyContext::Messages);
+        assert_eq!(
+            km.resolve("r", false, KeyContext::Messages),
+            ResolveResult::Action(Action::NextUnreadReaction)
+        );
+    }
+
     #[test]
     fn shift_g_scrolls_to_last_message() {
         let mut km = Keymap::default();
@@ -867,12 +1610,17 @@ mod tests {
     }
 
     #[test]
-    fn d_in_chat_list_is_unmatched() {
+    fn single_d_in_chat_list_does_not_leave_chat() {
         let mut km = Keymap::default();
         assert_eq!(
             km.resolve("d", false, KeyContext::ChatList),
-            ResolveResult::Unmatched
+            ResolveResult::Pending
         );
+        assert_eq!(
+            km.resolve("j", false, KeyContext::ChatList),
+            ResolveResult::Action(Action::SelectNextChat)
+        );
+        assert!(!km.has_pending());
     }
 
     #[test]
@@ -0,0 +1,114 @@
+//! State of the link hints popup shown when a message has several links.
+//!
+//! Links are numbered like urlscan / vimium hints: a digit picks a link
+//! directly, `j`/`k` and `Enter` reach links past the ninth.
+
+use super::message::MessageUrl;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinkHintsState {
+    urls: Vec<MessageUrl>,
+    selected: usize,
+    /// The picked link is copied instead of opened.
+    copy_mode: bool,
+}
+
+impl LinkHintsState {
+    pub fn new(urls: Vec<MessageUrl>) -> Self {
+        Self {
+            urls,
+            selected: 0,
+            copy_mode: false,
+        }
+    }
+
+    pub fn urls(&self) -> &[MessageUrl] {
+        &self.urls
+    }
+
+    pub fn selected_index(&self) -> usize {
+        self.selected
+    }
+
+    pub fn selected(&self) -> Option<&MessageUrl> {
+        self.urls.get(self.selected)
+    }
+
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.urls.len() {
+            self.selected += 1;
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// The link labelled with hint `key` (`"1"` to `"9"`).
+    pub fn by_hint(&self, key: &str) -> Option<&MessageUrl> {
+        let n: usize = key.parse().ok()?;
+        n.checked_sub(1).and_then(|idx| self.urls.get(idx))
+    }
+
+    pub fn is_copy_mode(&self) -> bool {
+        self.copy_mode
+    }
+
+    pub fn toggle_copy_mode(&mut self) {
+        self.copy_mode = !self.copy_mode;
+    }
+}
+
+/// Hint label of the link at `index`, if it has one.
+pub fn hint_label(index: usize) -> Option<char> {
+    if index < 9 {
+        char::from_digit(index as u32 + 1, 10)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn urls(count: usize) -> Vec<MessageUrl> {
+        (1..=count)
+            .map(|i| MessageUrl {
+                url: format!("https://example.com/{i}"),
+                anchor: format!("link {i}"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn hints_are_one_based_digits() {
+        let state = LinkHintsState::new(urls(3));
+
+        assert_eq!(
+            state.by_hint("2").map(|u| u.url.as_str()),
+            Some("https://example.com/2")
+        );
+        assert!(state.by_hint("0").is_none());
+        assert!(state.by_hint("4").is_none());
+        assert!(state.by_hint("x").is_none());
+    }
+
+    #[test]
+    fn only_first_nine_links_get_labels() {
+        assert_eq!(hint_label(0), Some('1'));
+        assert_eq!(hint_label(8), Some('9'));
+        assert_eq!(hint_label(9), None);
+    }
+
+    #[test]
+    fn selection_stays_in_bounds() {
+        let mut state = LinkHintsState::new(urls(2));
+
+        state.select_previous();
+        assert_eq!(state.selected_index(), 0);
+        state.select_next();
+        state.select_next();
+        assert_eq!(state.selected_index(), 1);
+    }
+}
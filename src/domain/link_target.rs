@@ -0,0 +1,31 @@
+//! Telegram links (`t.me/...`, `tg://...`) resolved to what rtg opens.
+
+use super::{chat::ChatSummary, message::UnreadMessageWindow};
+
+/// What a Telegram link points to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LinkTarget {
+    /// A chat, e.g. a public username or an invite link to a chat the
+    /// user is already a member of.
+    Chat(ChatSummary),
+    /// A message inside a chat, with the history around it.
+    Message {
+        chat: ChatSummary,
+        window: UnreadMessageWindow,
+    },
+    /// An invite link to a chat the user has not joined yet.
+    Invite(ChatInvite),
+    /// A link rtg cannot handle itself (stickers, proxies, ...); it is
+    /// handed to the browser.
+    External,
+}
+
+/// Preview of a chat behind an invite link, shown before joining.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChatInvite {
+    pub invite_link: String,
+    pub title: String,
+    pub member_count: u32,
+    /// Joining sends a request the admins must approve.
+    pub creates_join_request: bool,
+}
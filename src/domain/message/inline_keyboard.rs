@@ -0,0 +1,51 @@
+/// A button of an inline keyboard attached to a bot message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InlineButton {
+    pub text: String,
+    pub action: InlineButtonAction,
+}
+
+/// What happens when an inline keyboard button is pressed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InlineButtonAction {
+    /// Sends a callback query carrying `data` to the bot.
+    Callback { data: String },
+    /// Opens a URL in the external opener.
+    Url { url: String },
+    /// Starts an inline query to the bot in the current chat.
+    /// `bot_username` is resolved by the Telegram adapter when known.
+    SwitchInline {
+        query: String,
+        bot_username: Option<String>,
+    },
+    /// Button types rtg cannot activate (games, payments, web apps, ...).
+    Unsupported,
+}
+
+impl InlineButtonAction {
+    /// Text to put into the message input for a switch-inline button:
+    /// `@bot query`, or just the query when the bot username is unknown.
+    pub fn switch_inline_text(&self) -> Option<String> {
+        match self {
+            InlineButtonAction::SwitchInline {
+                query,
+                bot_username: Some(username),
+            } => Some(format!("@{username} {query}")),
+            InlineButtonAction::SwitchInline {
+                query,
+                bot_username: None,
+            } => Some(query.clone()),
+            _ => None,
+        }
+    }
+}
+
+/// The bot's answer to a callback query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallbackAnswer {
+    pub text: String,
+    /// Whether the answer must be shown as an alert instead of a toast.
+    pub show_alert: bool,
+    /// URL to open (games, deep links); empty if none.
+    pub url: String,
+}
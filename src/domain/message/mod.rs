@@ -1,5 +1,9 @@
 mod call_info;
 mod file_info;
+mod inline_keyboard;
+mod reaction;
+mod reply_keyboard;
+mod transcript;
 mod url;
 
 #[cfg(test)]
@@ -11,7 +15,11 @@ pub use file_info::{
     build_file_metadata_display, file_extension, format_duration, format_file_size, DownloadStatus,
     FileInfo,
 };
-pub use url::{extract_first_url, TextLink};
+pub use inline_keyboard::{CallbackAnswer, InlineButton, InlineButtonAction};
+pub use reaction::MessageReaction;
+pub use reply_keyboard::ReplyKeyboard;
+pub use transcript::format_transcript;
+pub use url::{extract_urls, is_telegram_link, MessageUrl, TextLink};
 
 /// Type of media attached to a message.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -81,6 +89,8 @@ pub enum MessageStatus {
     Delivered,
     /// Message is being sent (optimistic display).
     Sending,
+    /// Outgoing message has been read by the recipient.
+    Read,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -101,14 +111,28 @@ pub struct Message {
     /// `None` if the message is not a reply.
     pub reply_to: Option<ReplyInfo>,
     pub forward_info: Option<ForwardInfo>,
-    /// Total number of reactions on this message (summed across all reaction types).
-    pub reaction_count: u32,
+    /// Reactions on this message by type, in Telegram's order.
+    pub reactions: Vec<MessageReaction>,
     /// Hyperlinks embedded in the message text via text entities.
     pub links: Vec<TextLink>,
     /// Whether the message has been edited after sending.
     pub is_edited: bool,
     /// Whether this is a service/system message (member added, photo changed, etc.).
     pub is_service: bool,
+    /// Inline keyboard rows attached by a bot; empty when there is none.
+    pub inline_keyboard: Vec<Vec<InlineButton>>,
+    /// Custom reply keyboard shown above the input while this message is
+    /// the chat's active reply markup.
+    pub reply_keyboard: Option<ReplyKeyboard>,
+}
+
+/// History window loaded around an unread mention or reaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnreadMessageWindow {
+    /// The unread message to select and mark as read.
+    pub target_message_id: i64,
+    /// Messages around the target in chronological order (oldest first).
+    pub messages: Vec<Message>,
 }
 
 impl Message {
@@ -120,4 +144,15 @@ impl Message {
             (None, _) => self.text.clone(),
         }
     }
+
+    /// Marks a delivered outgoing message as read when the peer has read
+    /// the chat up to `last_read_outbox_message_id`.
+    pub fn apply_outbox_read(&mut self, last_read_outbox_message_id: i64) {
+        if self.is_outgoing
+            && self.status == MessageStatus::Delivered
+            && self.id <= last_read_outbox_message_id
+        {
+            self.status = MessageStatus::Read;
+        }
+    }
 }
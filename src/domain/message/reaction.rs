@@ -0,0 +1,10 @@
+/// One reaction type on a message with its number of senders.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MessageReaction {
+    /// The reaction emoji; custom emoji and paid reactions are shown as
+    /// `⭐` and `💎`.
+    pub emoji: String,
+    pub count: u32,
+    /// Whether the current user added this reaction.
+    pub is_chosen: bool,
+}
@@ -0,0 +1,12 @@
+/// Custom reply keyboard sent by a bot: buttons that replace the
+/// keyboard and send their text as a regular message when chosen.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReplyKeyboard {
+    /// Button texts by row.
+    pub rows: Vec<Vec<String>>,
+    /// Whether the bot asked to hide the keyboard after one use.
+    pub one_time: bool,
+    /// Placeholder for the message input while the keyboard is shown;
+    /// empty if the bot did not set one.
+    pub placeholder: String,
+}
@@ -113,3 +113,29 @@ fn display_content_handles_all_media_types() {
         );
     }
 }
+
+#[test]
+fn apply_outbox_read_marks_outgoing_messages_up_to_marker() {
+    let mut message = msg("hi", MessageMedia::None);
+    message.id = 10;
+    message.is_outgoing = true;
+
+    message.apply_outbox_read(9);
+    assert_eq!(message.status, MessageStatus::Delivered);
+
+    message.apply_outbox_read(10);
+    assert_eq!(message.status, MessageStatus::Read);
+}
+
+#[test]
+fn apply_outbox_read_ignores_incoming_and_sending_messages() {
+    let mut incoming = msg("hi", MessageMedia::None);
+    incoming.apply_outbox_read(100);
+    assert_eq!(incoming.status, MessageStatus::Delivered);
+
+    let mut sending = msg("hi", MessageMedia::None);
+    sending.is_outgoing = true;
+    sending.status = MessageStatus::Sending;
+    sending.apply_outbox_read(100);
+    assert_eq!(sending.status, MessageStatus::Sending);
+}
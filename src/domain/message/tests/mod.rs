@@ -1,6 +1,7 @@
 mod call_metadata;
 mod display;
 mod file_metadata;
+mod transcript;
 mod url;
 
 use super::*;
@@ -18,9 +19,11 @@ pub(super) fn msg(text: &str, media: MessageMedia) -> Message {
         call_info: None,
         reply_to: None,
         forward_info: None,
-        reaction_count: 0,
+        reactions: Vec::new(),
         links: Vec::new(),
         is_edited: false,
         is_service: false,
+        inline_keyboard: Vec::new(),
+        reply_keyboard: None,
     }
 }
@@ -0,0 +1,62 @@
+use chrono::{Local, TimeZone};
+
+use super::*;
+
+fn at(text: &str, sender: &str, y: i32, mo: u32, d: u32, h: u32, mi: u32) -> Message {
+    Message {
+        sender_name: sender.to_owned(),
+        timestamp_ms: Local
+            .with_ymd_and_hms(y, mo, d, h, mi, 0)
+            .unwrap()
+            .timestamp_millis(),
+        ..msg(text, MessageMedia::None)
+    }
+}
+
+#[test]
+fn transcript_prefixes_time_and_sender() {
+    let first = at("hi", "Alice", 2024, 3, 5, 9, 7);
+    let second = Message {
+        is_outgoing: true,
+        ..at("hello", "Me", 2024, 3, 5, 9, 8)
+    };
+
+    assert_eq!(
+        format_transcript(&[&first, &second]),
+        "[09:07] Alice: hi\n[09:08] You: hello"
+    );
+}
+
+#[test]
+fn transcript_spanning_days_includes_dates() {
+    let first = at("late", "Alice", 2024, 3, 5, 23, 59);
+    let second = at("early", "Bob", 2024, 3, 6, 0, 1);
+
+    assert_eq!(
+        format_transcript(&[&first, &second]),
+        "[2024-03-05 23:59] Alice: late\n[2024-03-06 00:01] Bob: early"
+    );
+}
+
+#[test]
+fn transcript_uses_media_label_and_skips_sender_of_service_messages() {
+    let photo = at("", "Alice", 2024, 3, 5, 10, 0);
+    let photo = Message {
+        media: MessageMedia::Photo,
+        ..photo
+    };
+    let service = Message {
+        is_service: true,
+        ..at("Alice joined the group", "", 2024, 3, 5, 10, 1)
+    };
+
+    let transcript = format_transcript(&[&photo, &service]);
+
+    assert_eq!(
+        transcript,
+        format!(
+            "[10:00] Alice: {}\n[10:01] Alice joined the group",
+            MessageMedia::Photo.display_label().unwrap()
+        )
+    );
+}
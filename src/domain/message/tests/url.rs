@@ -1,5 +1,10 @@
 use super::*;
 
+/// The URL `OpenLink` opens right away when a message has a single link.
+fn extract_first_url(text: &str, links: &[TextLink]) -> Option<String> {
+    extract_urls(text, links).into_iter().next().map(|u| u.url)
+}
+
 // ── extract_first_url tests ──
 
 #[test]
@@ -103,3 +108,57 @@ fn extract_first_url_preserves_existing_scheme() {
         Some("https://example.com".to_owned())
     );
 }
+
+// ── extract_urls tests ──
+
+#[test]
+fn extract_urls_lists_entities_then_bare_urls() {
+    let links = vec![TextLink {
+        offset: 0,
+        length: 4,
+        url: "https://docs.rs".to_owned(),
+    }];
+
+    let urls = extract_urls("docs and https://crates.io", &links);
+
+    assert_eq!(
+        urls,
+        vec![
+            MessageUrl {
+                url: "https://docs.rs".to_owned(),
+                anchor: "docs".to_owned(),
+            },
+            MessageUrl {
+                url: "https://crates.io".to_owned(),
+                anchor: "https://crates.io".to_owned(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn extract_urls_skips_duplicates() {
+    let links = vec![TextLink {
+        offset: 4,
+        length: 19,
+        url: "https://example.com".to_owned(),
+    }];
+
+    let urls = extract_urls("see https://example.com and https://example.com", &links);
+
+    assert_eq!(urls.len(), 1);
+    assert_eq!(urls[0].anchor, "https://example.com");
+}
+
+#[test]
+fn extract_urls_falls_back_to_url_when_anchor_is_out_of_range() {
+    let links = vec![TextLink {
+        offset: 10,
+        length: 5,
+        url: "https://example.com".to_owned(),
+    }];
+
+    let urls = extract_urls("short", &links);
+
+    assert_eq!(urls[0].anchor, "https://example.com");
+}
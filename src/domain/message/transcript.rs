@@ -0,0 +1,47 @@
+use chrono::{Local, TimeZone};
+
+use super::Message;
+
+/// Formats messages as a plain-text transcript, one `[time] Sender: text`
+/// entry per message. Dates are included when the messages span more than
+/// one day.
+pub fn format_transcript(messages: &[&Message]) -> String {
+    let times: Vec<_> = messages
+        .iter()
+        .map(|m| Local.timestamp_millis_opt(m.timestamp_ms).single())
+        .collect();
+    let single_day = times
+        .iter()
+        .flatten()
+        .map(|t| t.date_naive())
+        .collect::<std::collections::HashSet<_>>()
+        .len()
+        <= 1;
+    let time_format = if single_day {
+        "%H:%M"
+    } else {
+        "%Y-%m-%d %H:%M"
+    };
+
+    messages
+        .iter()
+        .zip(times)
+        .map(|(msg, time)| {
+            let time = time
+                .map(|t| t.format(time_format).to_string())
+                .unwrap_or_default();
+            let content = msg.display_content();
+            if msg.is_service {
+                format!("[{time}] {content}")
+            } else {
+                let sender = if msg.is_outgoing {
+                    "You"
+                } else {
+                    msg.sender_name.as_str()
+                };
+                format!("[{time}] {sender}: {content}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
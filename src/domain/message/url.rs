@@ -13,18 +13,65 @@ pub struct TextLink {
     pub url: String,
 }
 
-/// Extracts the first URL from message text and link entities.
+/// A link found in a message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MessageUrl {
+    /// The target URL to open.
+    pub url: String,
+    /// The text the link is attached to; the URL itself for bare links.
+    pub anchor: String,
+}
+
+/// Extracts every distinct URL from message text and link entities.
 ///
-/// Checks entity links first (they may contain URLs not visible in text),
-/// then falls back to whitespace-delimited scanning of plain text.
+/// Entity links come first (they may contain URLs not visible in text),
+/// followed by whitespace-delimited URLs found in the plain text.
 /// URLs without a scheme get `http://` prepended so they can be opened by the OS.
-pub fn extract_first_url(text: &str, links: &[TextLink]) -> Option<String> {
-    if let Some(link) = links.first() {
-        return Some(normalize_url(&link.url));
+pub fn extract_urls(text: &str, links: &[TextLink]) -> Vec<MessageUrl> {
+    let mut urls: Vec<MessageUrl> = Vec::new();
+    for link in links {
+        let url = normalize_url(&link.url);
+        if urls.iter().any(|u| u.url == url) {
+            continue;
+        }
+        let anchor = text
+            .get(link.offset..link.offset.saturating_add(link.length))
+            .map(str::trim)
+            .filter(|a| !a.is_empty())
+            .map_or_else(|| url.clone(), str::to_owned);
+        urls.push(MessageUrl { url, anchor });
     }
-    text.split_whitespace()
-        .find(|word| word.starts_with("https://") || word.starts_with("http://"))
-        .map(|s| s.to_owned())
+
+    let bare = text.split_whitespace().filter(|word| {
+        word.starts_with("https://") || word.starts_with("http://") || word.starts_with("tg://")
+    });
+    for word in bare {
+        if !urls.iter().any(|u| u.url == word) {
+            urls.push(MessageUrl {
+                url: word.to_owned(),
+                anchor: word.to_owned(),
+            });
+        }
+    }
+    urls
+}
+
+/// Whether `url` is a Telegram link (`t.me`, `telegram.me`, `tg://`) that
+/// rtg resolves itself instead of opening a browser.
+pub fn is_telegram_link(url: &str) -> bool {
+    if url.starts_with("tg://") {
+        return true;
+    }
+    let rest = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+        .unwrap_or(url);
+    let host = rest
+        .split(['/', '?', '#'])
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    matches!(host.as_str(), "t.me" | "telegram.me" | "telegram.dog") || host.ends_with(".t.me")
 }
 
 /// Ensures a URL has an `http://` or `https://` scheme.
@@ -32,7 +79,7 @@ pub fn extract_first_url(text: &str, links: &[TextLink]) -> Option<String> {
 /// TDLib `TextEntityTypeUrl` may match bare hosts like `127.0.0.1:8080`
 /// or `example.com/path` — the OS launcher needs a full scheme to work.
 fn normalize_url(url: &str) -> String {
-    if url.starts_with("http://") || url.starts_with("https://") {
+    if url.starts_with("http://") || url.starts_with("https://") || url.starts_with("tg://") {
         url.to_owned()
     } else {
         format!("http://{url}")
@@ -58,4 +105,35 @@ mod tests {
     fn normalize_url_keeps_http() {
         assert_eq!(normalize_url("http://example.com"), "http://example.com");
     }
+
+    #[test]
+    fn normalize_url_keeps_tg_scheme() {
+        assert_eq!(
+            normalize_url("tg://resolve?domain=rust"),
+            "tg://resolve?domain=rust"
+        );
+    }
+
+    #[test]
+    fn extract_urls_finds_tg_links_in_text() {
+        let urls = extract_urls("open tg://resolve?domain=rust now", &[]);
+        assert_eq!(urls[0].url, "tg://resolve?domain=rust");
+    }
+
+    #[test]
+    fn telegram_links_are_recognised() {
+        assert!(is_telegram_link("https://t.me/rustlang"));
+        assert!(is_telegram_link("http://t.me/c/123/45"));
+        assert!(is_telegram_link("https://telegram.me/+AbCdEf"));
+        assert!(is_telegram_link("https://rustlang.t.me/"));
+        assert!(is_telegram_link("tg://resolve?domain=rustlang"));
+        assert!(is_telegram_link("https://T.ME/rustlang"));
+    }
+
+    #[test]
+    fn other_links_are_not_telegram_links() {
+        assert!(!is_telegram_link("https://example.com/t.me"));
+        assert!(!is_telegram_link("https://nott.me/x"));
+        assert!(!is_telegram_link("https://telegram.org"));
+    }
 }
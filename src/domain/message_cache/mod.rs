@@ -1,6 +1,6 @@
 use std::collections::{HashMap, VecDeque};
 
-use super::message::Message;
+use super::message::{Message, MessageReaction};
 
 #[cfg(test)]
 mod tests;
@@ -165,11 +165,25 @@ impl MessageCache {
         }
     }
 
-    /// Updates the `reaction_count` of a specific message in the cache.
-    pub fn update_reaction_count(&mut self, chat_id: i64, message_id: i64, reaction_count: u32) {
+    /// Replaces the reactions of a specific message in the cache.
+    pub fn update_reactions(
+        &mut self,
+        chat_id: i64,
+        message_id: i64,
+        reactions: Vec<MessageReaction>,
+    ) {
         if let Some(entry) = self.chats.get_mut(&chat_id) {
             if let Some(msg) = entry.messages.iter_mut().find(|m| m.id == message_id) {
-                msg.reaction_count = reaction_count;
+                msg.reactions = reactions;
+            }
+        }
+    }
+
+    /// Marks cached outgoing messages up to `last_read_outbox_message_id` as read.
+    pub fn apply_outbox_read(&mut self, chat_id: i64, last_read_outbox_message_id: i64) {
+        if let Some(entry) = self.chats.get_mut(&chat_id) {
+            for msg in &mut entry.messages {
+                msg.apply_outbox_read(last_read_outbox_message_id);
             }
         }
     }
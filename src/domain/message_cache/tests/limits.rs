@@ -105,34 +105,58 @@ fn default_cache_has_expected_limits() {
     assert_eq!(cache.max_messages_per_chat, DEFAULT_MAX_MESSAGES_PER_CHAT);
 }
 
-// ── Reaction count update tests ──
+// ── Reaction update tests ──
+
+fn thumbs_up(count: u32) -> Vec<MessageReaction> {
+    vec![MessageReaction {
+        emoji: "👍".to_owned(),
+        count,
+        is_chosen: false,
+    }]
+}
 
 #[test]
-fn update_reaction_count_modifies_existing_message() {
+fn update_reactions_modifies_existing_message() {
     let mut cache = MessageCache::default();
     cache.put(1, vec![msg(10, "hello"), msg(20, "world")], false);
 
-    cache.update_reaction_count(1, 20, 5);
+    cache.update_reactions(1, 20, thumbs_up(5));
 
     let messages = cache.get(1).unwrap();
-    assert_eq!(messages[0].reaction_count, 0);
-    assert_eq!(messages[1].reaction_count, 5);
+    assert!(messages[0].reactions.is_empty());
+    assert_eq!(messages[1].reactions, thumbs_up(5));
 }
 
 #[test]
-fn update_reaction_count_noop_for_unknown_chat() {
+fn update_reactions_noop_for_unknown_chat() {
     let mut cache = MessageCache::default();
-    cache.update_reaction_count(999, 1, 3);
+    cache.update_reactions(999, 1, thumbs_up(3));
     assert!(cache.get(999).is_none());
 }
 
 #[test]
-fn update_reaction_count_noop_for_unknown_message() {
+fn update_reactions_noop_for_unknown_message() {
     let mut cache = MessageCache::default();
     cache.put(1, vec![msg(10, "hello")], false);
 
-    cache.update_reaction_count(1, 999, 3);
+    cache.update_reactions(1, 999, thumbs_up(3));
+
+    let messages = cache.get(1).unwrap();
+    assert!(messages[0].reactions.is_empty());
+}
+
+#[test]
+fn apply_outbox_read_marks_cached_outgoing_messages() {
+    let mut cache = MessageCache::default();
+    let mut sent = msg(10, "sent");
+    sent.is_outgoing = true;
+    let mut later = msg(20, "later");
+    later.is_outgoing = true;
+    cache.put(1, vec![sent, later], false);
+
+    cache.apply_outbox_read(1, 10);
 
     let messages = cache.get(1).unwrap();
-    assert_eq!(messages[0].reaction_count, 0);
+    assert_eq!(messages[0].status, MessageStatus::Read);
+    assert_eq!(messages[1].status, MessageStatus::Delivered);
 }
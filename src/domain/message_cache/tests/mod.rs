@@ -19,10 +19,12 @@ fn msg(id: i64, text: &str) -> Message {
         call_info: None,
         reply_to: None,
         forward_info: None,
-        reaction_count: 0,
+        reactions: Vec::new(),
         links: Vec::new(),
         is_edited: false,
         is_service: false,
+        inline_keyboard: Vec::new(),
+        reply_keyboard: None,
     }
 }
 
@@ -39,9 +41,11 @@ fn msg_with_ts(id: i64, text: &str, timestamp_ms: i64) -> Message {
         call_info: None,
         reply_to: None,
         forward_info: None,
-        reaction_count: 0,
+        reactions: Vec::new(),
         links: Vec::new(),
         is_edited: false,
         is_service: false,
+        inline_keyboard: Vec::new(),
+        reply_keyboard: None,
     }
 }
@@ -27,6 +27,23 @@ pub struct EditContext {
     pub original_text: String,
 }
 
+/// A mention inserted by name for a user without a username.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputMention {
+    pub user_id: i64,
+    /// Text the mention was inserted as (the user's display name).
+    pub text: String,
+}
+
+/// A mention-by-name entity of an outgoing message.
+/// Offsets are character indices into the message text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextMention {
+    pub offset: usize,
+    pub length: usize,
+    pub user_id: i64,
+}
+
 /// State for the message composition input field.
 #[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct MessageInputState {
@@ -38,6 +55,8 @@ pub struct MessageInputState {
     reply_to: Option<ReplyContext>,
     /// Active edit context (set when user presses `e` on a message).
     editing: Option<EditContext>,
+    /// Mentions by name inserted from the completion popup.
+    mentions: Vec<InputMention>,
 }
 
 impl MessageInputState {
@@ -120,6 +139,7 @@ impl MessageInputState {
         self.cursor_position = 0;
         self.reply_to = None;
         self.editing = None;
+        self.mentions.clear();
     }
 
     /// Replaces the current text and moves cursor to the end.
@@ -128,6 +148,60 @@ impl MessageInputState {
     pub fn set_text(&mut self, text: &str) {
         self.text = text.to_owned();
         self.cursor_position = self.text.chars().count();
+        self.mentions.clear();
+    }
+
+    /// Remembers that `text`, just inserted, mentions `user_id`.
+    pub fn add_mention(&mut self, user_id: i64, text: &str) {
+        self.mentions.push(InputMention {
+            user_id,
+            text: text.to_owned(),
+        });
+    }
+
+    /// Locates the inserted mentions in the current text. Mentions whose
+    /// text was edited away are dropped; repeated names are matched to
+    /// successive occurrences.
+    pub fn text_mentions(&self) -> Vec<TextMention> {
+        let chars: Vec<char> = self.text.chars().collect();
+        let mut taken: Vec<(usize, usize)> = Vec::new();
+        let mut result = Vec::new();
+
+        for mention in &self.mentions {
+            let needle: Vec<char> = mention.text.chars().collect();
+            if needle.is_empty() || needle.len() > chars.len() {
+                continue;
+            }
+            let found = (0..=chars.len() - needle.len()).find(|&offset| {
+                chars[offset..offset + needle.len()] == needle[..]
+                    && !taken
+                        .iter()
+                        .any(|&(start, end)| offset < end && start < offset + needle.len())
+            });
+            if let Some(offset) = found {
+                taken.push((offset, offset + needle.len()));
+                result.push(TextMention {
+                    offset,
+                    length: needle.len(),
+                    user_id: mention.user_id,
+                });
+            }
+        }
+
+        result.sort_by_key(|m| m.offset);
+        result
+    }
+
+    /// Replaces the text between character index `start` and the cursor
+    /// with `replacement`, leaving the cursor right after it.
+    ///
+    /// Used to accept a completion for the token being typed.
+    pub fn replace_before_cursor(&mut self, start: usize, replacement: &str) {
+        let start = start.min(self.cursor_position);
+        let start_byte = self.char_to_byte_index(start);
+        let end_byte = self.char_to_byte_index(self.cursor_position);
+        self.text.replace_range(start_byte..end_byte, replacement);
+        self.cursor_position = start + replacement.chars().count();
     }
 
     /// Returns the active reply context, if any.
@@ -184,6 +258,66 @@ impl MessageInputState {
 mod tests {
     use super::*;
 
+    #[test]
+    fn text_mentions_locate_inserted_names() {
+        let mut state = MessageInputState::default();
+        state.set_text("Bob and Bob, meet Ann");
+        state.add_mention(2, "Bob");
+        state.add_mention(3, "Ann");
+        state.add_mention(2, "Bob");
+        state.add_mention(4, "Zed");
+
+        assert_eq!(
+            state.text_mentions(),
+            vec![
+                TextMention {
+                    offset: 0,
+                    length: 3,
+                    user_id: 2
+                },
+                TextMention {
+                    offset: 8,
+                    length: 3,
+                    user_id: 2
+                },
+                TextMention {
+                    offset: 18,
+                    length: 3,
+                    user_id: 3
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn clear_forgets_mentions() {
+        let mut state = MessageInputState::default();
+        state.set_text("Bob");
+        state.add_mention(2, "Bob");
+
+        state.clear();
+        for ch in "Bob".chars() {
+            state.insert_char(ch);
+        }
+
+        assert!(state.text_mentions().is_empty());
+    }
+
+    #[test]
+    fn replace_before_cursor_keeps_text_after_cursor() {
+        let mut state = MessageInputState::default();
+        state.set_text("/st tail");
+        state.move_cursor_home();
+        for _ in 0..3 {
+            state.move_cursor_right();
+        }
+
+        state.replace_before_cursor(0, "/start ");
+
+        assert_eq!(state.text(), "/start  tail");
+        assert_eq!(state.cursor_position(), 7);
+    }
+
     #[test]
     fn new_state_is_empty() {
         let state = MessageInputState::default();
@@ -1,23 +1,44 @@
 //! Domain layer: core entities and business rules.
 
+pub mod bot_command;
+pub mod button_grid_state;
 pub mod chat;
+pub mod chat_action;
+pub mod chat_folder;
 pub mod chat_info_state;
+pub mod chat_list_filter;
 pub mod chat_list_state;
+pub mod chat_manage_state;
+pub mod chat_member;
 pub mod chat_search_state;
 pub mod chat_subtitle;
 pub mod command_popup_state;
+pub mod completion_state;
+pub mod contact;
+pub mod contacts_state;
+pub mod date_prompt_state;
+pub mod delete_confirm_state;
+pub mod emoji;
+pub mod emoji_picker_state;
 pub mod events;
 pub mod forum_topic;
 pub mod forum_topic_list_state;
+pub mod fuzzy;
+pub mod go_to_prompt_state;
 pub mod keymap;
+pub mod link_hints_state;
+pub mod link_target;
 pub mod message;
 pub mod message_cache;
 pub mod message_info_state;
 pub mod message_input_state;
+pub mod new_chat_wizard_state;
 pub mod open_chat_state;
 pub mod open_defaults;
 pub mod open_handler;
+pub mod quick_switcher_state;
 pub mod reaction_picker_state;
+pub mod recent_chats;
 pub mod selectable_list;
 pub mod shell_state;
 pub mod status;
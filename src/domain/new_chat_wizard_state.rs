@@ -0,0 +1,360 @@
+//! State for the "new chat" wizard popup.
+//!
+//! The wizard walks through four steps: chat kind, title, optional
+//! description and members picked from contacts. It only collects input;
+//! creating the chat happens in the background once the user confirms.
+
+use super::{chat::ChatSummary, contact::Contact};
+
+const MAX_TITLE_CHARS: usize = 128;
+const MAX_DESCRIPTION_CHARS: usize = 255;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NewChatKind {
+    BasicGroup,
+    Supergroup,
+    Forum,
+    Channel,
+}
+
+impl NewChatKind {
+    pub const ALL: [Self; 4] = [
+        Self::BasicGroup,
+        Self::Supergroup,
+        Self::Forum,
+        Self::Channel,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::BasicGroup => "Group",
+            Self::Supergroup => "Supergroup",
+            Self::Forum => "Forum",
+            Self::Channel => "Channel",
+        }
+    }
+
+    /// Telegram refuses to create a basic group without members.
+    pub fn requires_members(self) -> bool {
+        self == Self::BasicGroup
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NewChatStep {
+    Kind,
+    Title,
+    Description,
+    Members,
+}
+
+/// Everything needed to create the chat.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewChatRequest {
+    pub kind: NewChatKind,
+    pub title: String,
+    pub description: String,
+    pub member_ids: Vec<i64>,
+}
+
+/// A created chat and the parts of its request Telegram did not apply.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreatedChat {
+    pub chat: ChatSummary,
+    /// Members that could not be added, e.g. because of their privacy
+    /// settings.
+    pub members_not_added: usize,
+    /// The description could not be set.
+    pub description_failed: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NewChatWizardState {
+    step: NewChatStep,
+    kind_index: usize,
+    title: String,
+    description: String,
+    /// `None` while contacts are still loading.
+    contacts: Option<Vec<Contact>>,
+    member_filter: String,
+    /// Index into `visible_members()`.
+    member_cursor: usize,
+    /// Picked user ids, in pick order.
+    member_ids: Vec<i64>,
+}
+
+impl Default for NewChatWizardState {
+    fn default() -> Self {
+        Self {
+            step: NewChatStep::Kind,
+            kind_index: 0,
+            title: String::new(),
+            description: String::new(),
+            contacts: None,
+            member_filter: String::new(),
+            member_cursor: 0,
+            member_ids: Vec::new(),
+        }
+    }
+}
+
+impl NewChatWizardState {
+    pub fn step(&self) -> NewChatStep {
+        self.step
+    }
+
+    pub fn kind(&self) -> NewChatKind {
+        NewChatKind::ALL[self.kind_index]
+    }
+
+    pub fn select_next_kind(&mut self) {
+        self.kind_index = (self.kind_index + 1).min(NewChatKind::ALL.len() - 1);
+    }
+
+    pub fn select_previous_kind(&mut self) {
+        self.kind_index = self.kind_index.saturating_sub(1);
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn member_filter(&self) -> &str {
+        &self.member_filter
+    }
+
+    /// Types into the field of the current step. Ignored on the kind step.
+    pub fn push_char(&mut self, ch: char) {
+        match self.step {
+            NewChatStep::Kind => {}
+            NewChatStep::Title => push_limited(&mut self.title, ch, MAX_TITLE_CHARS),
+            NewChatStep::Description => {
+                push_limited(&mut self.description, ch, MAX_DESCRIPTION_CHARS)
+            }
+            NewChatStep::Members => {
+                self.member_filter.push(ch);
+                self.member_cursor = 0;
+            }
+        }
+    }
+
+    pub fn pop_char(&mut self) {
+        match self.step {
+            NewChatStep::Kind => {}
+            NewChatStep::Title => {
+                self.title.pop();
+            }
+            NewChatStep::Description => {
+                self.description.pop();
+            }
+            NewChatStep::Members => {
+                self.member_filter.pop();
+                self.member_cursor = 0;
+            }
+        }
+    }
+
+    /// Moves to the next step. Returns `false` when the current step is
+    /// incomplete (blank title) or already the last one.
+    pub fn advance(&mut self) -> bool {
+        self.step = match self.step {
+            NewChatStep::Kind => NewChatStep::Title,
+            NewChatStep::Title if self.title.trim().is_empty() => return false,
+            NewChatStep::Title => NewChatStep::Description,
+            NewChatStep::Description => NewChatStep::Members,
+            NewChatStep::Members => return false,
+        };
+        true
+    }
+
+    /// Moves to the previous step. Returns `false` on the first step.
+    pub fn back(&mut self) -> bool {
+        self.step = match self.step {
+            NewChatStep::Kind => return false,
+            NewChatStep::Title => NewChatStep::Kind,
+            NewChatStep::Description => NewChatStep::Title,
+            NewChatStep::Members => NewChatStep::Description,
+        };
+        true
+    }
+
+    pub fn contacts_loaded(&self) -> bool {
+        self.contacts.is_some()
+    }
+
+    pub fn set_contacts(&mut self, mut contacts: Vec<Contact>) {
+        contacts.sort_by_key(|c| c.display_name.to_lowercase());
+        self.contacts = Some(contacts);
+        self.member_cursor = 0;
+    }
+
+    /// Contacts matching the member filter, best match first.
+    pub fn visible_members(&self) -> Vec<&Contact> {
+        let Some(contacts) = &self.contacts else {
+            return Vec::new();
+        };
+        let mut matching: Vec<(i32, &Contact)> = contacts
+            .iter()
+            .filter_map(|c| c.match_score(&self.member_filter).map(|s| (s, c)))
+            .collect();
+        matching.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+        matching.into_iter().map(|(_, c)| c).collect()
+    }
+
+    pub fn member_cursor(&self) -> usize {
+        self.member_cursor
+    }
+
+    pub fn select_next_member(&mut self) {
+        let count = self.visible_members().len();
+        if self.member_cursor + 1 < count {
+            self.member_cursor += 1;
+        }
+    }
+
+    pub fn select_previous_member(&mut self) {
+        self.member_cursor = self.member_cursor.saturating_sub(1);
+    }
+
+    /// Picks or unpicks the contact under the cursor.
+    pub fn toggle_member(&mut self) {
+        let Some(user_id) = self
+            .visible_members()
+            .get(self.member_cursor)
+            .map(|c| c.user_id)
+        else {
+            return;
+        };
+        if let Some(pos) = self.member_ids.iter().position(|&id| id == user_id) {
+            self.member_ids.remove(pos);
+        } else {
+            self.member_ids.push(user_id);
+        }
+    }
+
+    pub fn is_member(&self, user_id: i64) -> bool {
+        self.member_ids.contains(&user_id)
+    }
+
+    pub fn member_count(&self) -> usize {
+        self.member_ids.len()
+    }
+
+    pub fn request(&self) -> NewChatRequest {
+        NewChatRequest {
+            kind: self.kind(),
+            title: self.title.trim().to_owned(),
+            description: self.description.trim().to_owned(),
+            member_ids: self.member_ids.clone(),
+        }
+    }
+}
+
+fn push_limited(text: &mut String, ch: char, max_chars: usize) {
+    if text.chars().count() < max_chars {
+        text.push(ch);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn contact(user_id: i64, name: &str) -> Contact {
+        Contact {
+            user_id,
+            display_name: name.to_owned(),
+            username: String::new(),
+            is_online: false,
+        }
+    }
+
+    fn type_text(state: &mut NewChatWizardState, text: &str) {
+        for ch in text.chars() {
+            state.push_char(ch);
+        }
+    }
+
+    #[test]
+    fn title_is_required_to_advance() {
+        let mut state = NewChatWizardState::default();
+        state.select_next_kind();
+        assert!(state.advance());
+
+        assert!(!state.advance());
+        type_text(&mut state, "  ");
+        assert!(!state.advance());
+
+        type_text(&mut state, "War room");
+        assert!(state.advance());
+        assert_eq!(state.step(), NewChatStep::Description);
+    }
+
+    #[test]
+    fn kind_selection_is_clamped() {
+        let mut state = NewChatWizardState::default();
+        state.select_previous_kind();
+        assert_eq!(state.kind(), NewChatKind::BasicGroup);
+
+        for _ in 0..10 {
+            state.select_next_kind();
+        }
+        assert_eq!(state.kind(), NewChatKind::Channel);
+    }
+
+    #[test]
+    fn back_keeps_entered_text() {
+        let mut state = NewChatWizardState::default();
+        state.advance();
+        type_text(&mut state, "Ops");
+        state.advance();
+
+        assert!(state.back());
+        assert_eq!(state.step(), NewChatStep::Title);
+        assert_eq!(state.title(), "Ops");
+        assert!(state.back());
+        assert!(!state.back());
+    }
+
+    #[test]
+    fn members_are_filtered_and_toggled() {
+        let mut state = NewChatWizardState::default();
+        state.advance();
+        type_text(&mut state, " Incident ");
+        state.advance();
+        state.advance();
+        state.set_contacts(vec![
+            contact(1, "Bob"),
+            contact(2, "Alice"),
+            contact(3, "Bella"),
+        ]);
+
+        type_text(&mut state, "b");
+        state.toggle_member();
+        state.pop_char();
+        state.toggle_member();
+
+        let request = state.request();
+        assert_eq!(request.title, "Incident");
+        assert_eq!(request.member_ids, vec![3, 2]);
+        assert!(state.is_member(2));
+        assert!(!state.is_member(1));
+
+        state.toggle_member();
+        assert_eq!(state.member_count(), 1);
+    }
+
+    #[test]
+    fn title_length_is_limited() {
+        let mut state = NewChatWizardState::default();
+        state.advance();
+
+        type_text(&mut state, &"x".repeat(MAX_TITLE_CHARS + 5));
+
+        assert_eq!(state.title().chars().count(), MAX_TITLE_CHARS);
+    }
+}
@@ -1,6 +1,9 @@
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+
 use super::chat::ChatType;
 use super::chat_subtitle::ChatSubtitle;
-use super::message::{Message, MessageStatus, ReplyInfo};
+use super::message::{Message, MessageReaction, MessageStatus, ReplyInfo, ReplyKeyboard};
 use super::typing_state::TypingState;
 
 #[cfg(test)]
@@ -79,6 +82,20 @@ pub struct OpenChatState {
     typing_state: TypingState,
     /// Whether all older messages have been loaded (no more history to fetch).
     all_messages_loaded: bool,
+    /// Whether `messages` is a window around a jump target rather than the
+    /// latest page. Live refreshes must not replace it until the user
+    /// returns to the latest messages.
+    history_window: bool,
+    /// One-time reply keyboard the user already used; hidden until the
+    /// bot sends another one.
+    used_reply_keyboard: Option<i64>,
+    /// Id of the message where visual (range) selection started; the range
+    /// runs from it to the cursor.
+    visual_anchor: Option<i64>,
+    /// Messages hidden while their deletion waits out the undo grace
+    /// period and runs, as `(chat_id, message_id)`. Kept across chat switches so
+    /// they stay hidden when the chat is reopened.
+    hidden_messages: HashSet<(i64, i64)>,
 }
 
 impl Default for OpenChatState {
@@ -97,6 +114,10 @@ impl Default for OpenChatState {
             message_source: MessageSource::None,
             typing_state: TypingState::default(),
             all_messages_loaded: false,
+            history_window: false,
+            used_reply_keyboard: None,
+            visual_anchor: None,
+            hidden_messages: HashSet::new(),
         }
     }
 }
@@ -142,6 +163,21 @@ impl OpenChatState {
         &self.messages
     }
 
+    /// The chat's active custom reply keyboard with the id of the message
+    /// that carries it.
+    pub fn reply_keyboard(&self) -> Option<(i64, &ReplyKeyboard)> {
+        self.messages
+            .iter()
+            .rev()
+            .find_map(|m| m.reply_keyboard.as_ref().map(|k| (m.id, k)))
+            .filter(|(id, _)| self.used_reply_keyboard != Some(*id))
+    }
+
+    /// Hides a one-time reply keyboard after one of its buttons was sent.
+    pub fn hide_reply_keyboard(&mut self, message_id: i64) {
+        self.used_reply_keyboard = Some(message_id);
+    }
+
     /// Updates the `FileInfo` of a specific message by ID.
     ///
     /// If the message is found and has `file_info`, the closure is called
@@ -165,10 +201,17 @@ impl OpenChatState {
         }
     }
 
-    /// Updates the `reaction_count` of a specific message by ID.
-    pub fn update_message_reaction_count(&mut self, message_id: i64, reaction_count: u32) {
+    /// Replaces the reactions of a specific message by ID.
+    pub fn update_message_reactions(&mut self, message_id: i64, reactions: Vec<MessageReaction>) {
         if let Some(msg) = self.messages.iter_mut().find(|m| m.id == message_id) {
-            msg.reaction_count = reaction_count;
+            msg.reactions = reactions;
+        }
+    }
+
+    /// Marks outgoing messages up to `last_read_outbox_message_id` as read.
+    pub fn apply_outbox_read(&mut self, last_read_outbox_message_id: i64) {
+        for msg in &mut self.messages {
+            msg.apply_outbox_read(last_read_outbox_message_id);
         }
     }
 
@@ -221,6 +264,57 @@ impl OpenChatState {
         self.all_messages_loaded = loaded;
     }
 
+    pub fn is_history_window(&self) -> bool {
+        self.history_window
+    }
+
+    /// Replaces the messages with a history window loaded around a date.
+    ///
+    /// Selects the first message sent at or after `target_unix_ms` (falling
+    /// back to the newest one in the window) and detaches the view from the
+    /// latest page until [`leave_history_window`](Self::leave_history_window).
+    pub fn show_history_window(&mut self, messages: Vec<Message>, target_unix_ms: i64) {
+        let messages = self.without_hidden(messages);
+        let selected = messages
+            .iter()
+            .position(|m| m.timestamp_ms >= target_unix_ms)
+            .or_else(|| messages.len().checked_sub(1));
+        self.enter_history_window(messages, selected);
+    }
+
+    /// Like [`show_history_window`](Self::show_history_window), but selects
+    /// the message with `message_id` (falling back to the newest one).
+    pub fn show_history_window_at(&mut self, messages: Vec<Message>, message_id: i64) {
+        let messages = self.without_hidden(messages);
+        let selected = messages
+            .iter()
+            .position(|m| m.id == message_id)
+            .or_else(|| messages.len().checked_sub(1));
+        self.enter_history_window(messages, selected);
+    }
+
+    fn enter_history_window(&mut self, messages: Vec<Message>, selected: Option<usize>) {
+        self.selected_index = selected;
+        self.scroll_offset = ScrollOffset::ZERO;
+        self.messages = messages;
+        self.ui_state = OpenChatUiState::Ready;
+        self.refreshing = false;
+        self.message_source = MessageSource::Live;
+        self.all_messages_loaded = false;
+        self.history_window = true;
+    }
+
+    /// Drops the history window and goes back to `Loading` so the next
+    /// messages load shows the latest page anchored at the bottom.
+    pub fn leave_history_window(&mut self) {
+        self.history_window = false;
+        self.messages.clear();
+        self.ui_state = OpenChatUiState::Loading;
+        self.selected_index = None;
+        self.scroll_offset = ScrollOffset::ZERO;
+        self.all_messages_loaded = false;
+    }
+
     pub fn needs_more_messages(&self) -> bool {
         if self.all_messages_loaded || self.messages.is_empty() {
             return false;
@@ -240,8 +334,8 @@ impl OpenChatState {
             self.all_messages_loaded = true;
             return;
         }
-        let prepend_count = older.len();
-        let mut combined = older;
+        let mut combined = self.without_hidden(older);
+        let prepend_count = combined.len();
         combined.append(&mut self.messages);
         self.messages = combined;
 
@@ -282,6 +376,9 @@ impl OpenChatState {
         self.message_source = MessageSource::None;
         self.typing_state.clear();
         self.all_messages_loaded = false;
+        self.history_window = false;
+        self.used_reply_keyboard = None;
+        self.visual_anchor = None;
     }
 
     /// Transitions to `Ready` with the given messages.
@@ -290,6 +387,7 @@ impl OpenChatState {
     /// set these explicitly after calling `set_ready()` to indicate
     /// whether the data is cached/live and if a refresh is in-flight.
     pub fn set_ready(&mut self, messages: Vec<Message>) {
+        let messages = self.without_hidden(messages);
         self.selected_index = if messages.is_empty() {
             None
         } else {
@@ -304,6 +402,7 @@ impl OpenChatState {
         };
         self.messages = messages;
         self.ui_state = OpenChatUiState::Ready;
+        self.history_window = false;
     }
 
     /// Updates messages in an already-`Ready` chat without resetting scroll.
@@ -320,7 +419,7 @@ impl OpenChatState {
             .and_then(|idx| self.messages.get(idx))
             .map(|m| m.id);
 
-        self.messages = messages;
+        self.messages = self.without_hidden(messages);
         self.ui_state = OpenChatUiState::Ready;
         self.refreshing = false;
         self.message_source = MessageSource::Live;
@@ -371,10 +470,12 @@ impl OpenChatState {
             call_info: None,
             reply_to,
             forward_info: None,
-            reaction_count: 0,
+            reactions: Vec::new(),
             links: Vec::new(),
             is_edited: false,
             is_service: false,
+            inline_keyboard: Vec::new(),
+            reply_keyboard: None,
         };
         self.messages.push(pending);
         self.selected_index = Some(self.messages.len() - 1);
@@ -414,13 +515,71 @@ impl OpenChatState {
         }
     }
 
+    /// Removes messages from the list and keeps them hidden from later
+    /// loads until [`unhide_messages`](Self::unhide_messages). Returns the
+    /// removed messages, oldest first.
+    pub fn hide_messages(&mut self, message_ids: &[i64]) -> Vec<Message> {
+        let Some(chat_id) = self.chat_id else {
+            return Vec::new();
+        };
+        self.hidden_messages
+            .extend(message_ids.iter().map(|&id| (chat_id, id)));
+
+        let removed: Vec<Message> = self
+            .messages
+            .iter()
+            .filter(|m| message_ids.contains(&m.id))
+            .cloned()
+            .collect();
+        for message in &removed {
+            self.remove_message(message.id);
+        }
+        removed
+    }
+
+    /// Stops hiding messages of `chat_id`. Already removed messages are not
+    /// brought back; see [`restore_messages`](Self::restore_messages).
+    pub fn unhide_messages(&mut self, chat_id: i64, message_ids: &[i64]) {
+        self.hidden_messages
+            .retain(|&(c, id)| c != chat_id || !message_ids.contains(&id));
+    }
+
+    /// Puts previously hidden messages back in id order and selects the
+    /// first of them. Ignored when a different chat is open.
+    pub fn restore_messages(&mut self, chat_id: i64, messages: Vec<Message>) {
+        if self.chat_id != Some(chat_id) {
+            return;
+        }
+        let Some(first_id) = messages.first().map(|m| m.id) else {
+            return;
+        };
+        for message in messages {
+            if self.messages.iter().any(|m| m.id == message.id) {
+                continue;
+            }
+            let pos = self
+                .messages
+                .iter()
+                .position(|m| m.id == 0 || m.id > message.id)
+                .unwrap_or(self.messages.len());
+            self.messages.insert(pos, message);
+        }
+        self.select_message_by_id(first_id);
+    }
+
+    fn without_hidden(&self, mut messages: Vec<Message>) -> Vec<Message> {
+        if let Some(chat_id) = self.chat_id {
+            messages.retain(|m| !self.hidden_messages.contains(&(chat_id, m.id)));
+        }
+        messages
+    }
+
     pub fn set_error(&mut self) {
         self.ui_state = OpenChatUiState::Error;
         self.refreshing = false;
         self.message_source = MessageSource::None;
     }
 
-    #[allow(dead_code)]
     pub fn clear(&mut self) {
         self.chat_id = None;
         self.topic_id = None;
@@ -435,6 +594,9 @@ impl OpenChatState {
         self.message_source = MessageSource::None;
         self.typing_state.clear();
         self.all_messages_loaded = false;
+        self.history_window = false;
+        self.used_reply_keyboard = None;
+        self.visual_anchor = None;
     }
 
     pub fn is_open(&self) -> bool {
@@ -446,6 +608,45 @@ impl OpenChatState {
         self.selected_index.and_then(|idx| self.messages.get(idx))
     }
 
+    /// Starts visual mode at the selected message. Pending messages
+    /// cannot anchor a selection.
+    pub fn start_visual(&mut self) -> bool {
+        match self.selected_message() {
+            Some(msg) if msg.id != 0 => {
+                self.visual_anchor = Some(msg.id);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn cancel_visual(&mut self) {
+        self.visual_anchor = None;
+    }
+
+    /// Whether visual mode is active. It ends on its own once the anchor
+    /// message is no longer loaded.
+    pub fn is_visual(&self) -> bool {
+        self.visual_range().is_some()
+    }
+
+    /// Indices of the messages between the visual anchor and the cursor.
+    pub fn visual_range(&self) -> Option<RangeInclusive<usize>> {
+        let anchor_id = self.visual_anchor?;
+        let anchor = self.messages.iter().position(|m| m.id == anchor_id)?;
+        let cursor = self.selected_index?;
+        Some(anchor.min(cursor)..=anchor.max(cursor))
+    }
+
+    /// Messages an action applies to: the visual range, or just the
+    /// selected message outside visual mode.
+    pub fn selected_messages(&self) -> Vec<&Message> {
+        match self.visual_range() {
+            Some(range) => self.messages[range].iter().collect(),
+            None => self.selected_message().into_iter().collect(),
+        }
+    }
+
     /// Selects the next message (moves down in the list).
     ///
     /// Returns `true` if the selection changed, `false` if the cursor was
@@ -479,6 +680,19 @@ impl OpenChatState {
         moved
     }
 
+    /// Selects the loaded message with `message_id`.
+    ///
+    /// Returns `false` (leaving the selection untouched) when it is not loaded.
+    pub fn select_message_by_id(&mut self, message_id: i64) -> bool {
+        match self.messages.iter().position(|m| m.id == message_id) {
+            Some(idx) => {
+                self.selected_index = Some(idx);
+                true
+            }
+            None => false,
+        }
+    }
+
     pub fn select_previous(&mut self) -> bool {
         if self.messages.is_empty() {
             return false;
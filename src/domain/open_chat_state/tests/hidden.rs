@@ -0,0 +1,74 @@
+use super::*;
+
+fn ids(state: &OpenChatState) -> Vec<i64> {
+    state.messages().iter().map(|m| m.id).collect()
+}
+
+fn open_chat(chat_id: i64, messages: Vec<Message>) -> OpenChatState {
+    let mut state = OpenChatState::default();
+    state.set_loading(chat_id, "Chat".to_owned(), ChatType::Private);
+    state.set_ready(messages);
+    state
+}
+
+#[test]
+fn hide_messages_removes_and_returns_them() {
+    let mut state = open_chat(1, vec![message(1, "A"), message(2, "B"), message(3, "C")]);
+
+    let removed = state.hide_messages(&[2, 3]);
+
+    assert_eq!(removed.iter().map(|m| m.id).collect::<Vec<_>>(), vec![2, 3]);
+    assert_eq!(ids(&state), vec![1]);
+}
+
+#[test]
+fn hidden_messages_stay_hidden_on_refresh_and_reopen() {
+    let mut state = open_chat(1, vec![message(1, "A"), message(2, "B")]);
+    state.hide_messages(&[2]);
+
+    state.update_messages(vec![message(1, "A"), message(2, "B")]);
+    assert_eq!(ids(&state), vec![1]);
+
+    state.set_loading(5, "Other".to_owned(), ChatType::Private);
+    state.set_ready(vec![message(2, "Other chat")]);
+    assert_eq!(ids(&state), vec![2]);
+
+    state.set_loading(1, "Chat".to_owned(), ChatType::Private);
+    state.set_ready(vec![message(1, "A"), message(2, "B")]);
+    assert_eq!(ids(&state), vec![1]);
+}
+
+#[test]
+fn prepending_only_hidden_messages_does_not_end_history() {
+    let mut state = open_chat(1, vec![message(5, "E")]);
+    state.hide_messages(&[4]);
+
+    state.prepend_older_messages(vec![message(4, "D")]);
+
+    assert_eq!(ids(&state), vec![5]);
+    assert!(!state.all_messages_loaded());
+}
+
+#[test]
+fn restore_messages_reinserts_in_order_and_selects_them() {
+    let mut state = open_chat(1, vec![message(1, "A"), message(2, "B"), message(3, "C")]);
+    let removed = state.hide_messages(&[2]);
+    state.unhide_messages(1, &[2]);
+
+    state.restore_messages(1, removed);
+
+    assert_eq!(ids(&state), vec![1, 2, 3]);
+    assert_eq!(state.selected_message().unwrap().id, 2);
+}
+
+#[test]
+fn restore_messages_ignores_other_chat() {
+    let mut state = open_chat(1, vec![message(1, "A"), message(2, "B")]);
+    let removed = state.hide_messages(&[2]);
+    state.set_loading(5, "Other".to_owned(), ChatType::Private);
+    state.set_ready(vec![message(7, "X")]);
+
+    state.restore_messages(1, removed);
+
+    assert_eq!(ids(&state), vec![7]);
+}
@@ -267,27 +267,79 @@ fn remove_message_ignores_unknown_id() {
     assert_eq!(state.messages().len(), 2);
 }
 
-// ── Reaction count update tests ──
+// ── Reaction update tests ──
+
+fn fire(count: u32) -> Vec<crate::domain::message::MessageReaction> {
+    vec![crate::domain::message::MessageReaction {
+        emoji: "🔥".to_owned(),
+        count,
+        is_chosen: true,
+    }]
+}
 
 #[test]
-fn update_message_reaction_count_modifies_existing() {
+fn update_message_reactions_modifies_existing() {
     let mut state = OpenChatState::default();
     state.set_loading(1, "Chat".to_owned(), ChatType::Private);
     state.set_ready(vec![message(1, "A"), message(2, "B")]);
 
-    state.update_message_reaction_count(2, 7);
+    state.update_message_reactions(2, fire(7));
 
-    assert_eq!(state.messages()[0].reaction_count, 0);
-    assert_eq!(state.messages()[1].reaction_count, 7);
+    assert!(state.messages()[0].reactions.is_empty());
+    assert_eq!(state.messages()[1].reactions, fire(7));
 }
 
 #[test]
-fn update_message_reaction_count_ignores_unknown_id() {
+fn update_message_reactions_ignores_unknown_id() {
     let mut state = OpenChatState::default();
     state.set_loading(1, "Chat".to_owned(), ChatType::Private);
     state.set_ready(vec![message(1, "A")]);
 
-    state.update_message_reaction_count(999, 3);
+    state.update_message_reactions(999, fire(3));
+
+    assert!(state.messages()[0].reactions.is_empty());
+}
+
+// ── Read receipt tests ──
+
+#[test]
+fn apply_outbox_read_marks_outgoing_messages_read() {
+    let mut state = OpenChatState::default();
+    state.set_loading(1, "Chat".to_owned(), ChatType::Private);
+    let mut first = message(1, "A");
+    first.is_outgoing = true;
+    let mut second = message(2, "B");
+    second.is_outgoing = true;
+    state.set_ready(vec![first, second, message(3, "C")]);
+
+    state.apply_outbox_read(1);
+
+    assert_eq!(state.messages()[0].status, MessageStatus::Read);
+    assert_eq!(state.messages()[1].status, MessageStatus::Delivered);
+    assert_eq!(state.messages()[2].status, MessageStatus::Delivered);
+}
+
+#[test]
+fn reply_keyboard_comes_from_newest_message_carrying_one() {
+    let keyboard = |label: &str| crate::domain::message::ReplyKeyboard {
+        rows: vec![vec![label.to_owned()]],
+        one_time: false,
+        placeholder: String::new(),
+    };
+    let mut state = OpenChatState::default();
+    state.set_loading(1, "Bot".to_owned(), ChatType::Private);
+    assert!(state.reply_keyboard().is_none());
+
+    let mut first = message(1, "menu");
+    first.reply_keyboard = Some(keyboard("Old"));
+    let mut second = message(2, "menu again");
+    second.reply_keyboard = Some(keyboard("New"));
+    state.set_ready(vec![first, second, message(3, "plain")]);
+
+    let (message_id, active) = state.reply_keyboard().unwrap();
+    assert_eq!(message_id, 2);
+    assert_eq!(active.rows[0][0], "New");
 
-    assert_eq!(state.messages()[0].reaction_count, 0);
+    state.hide_reply_keyboard(2);
+    assert!(state.reply_keyboard().is_none());
 }
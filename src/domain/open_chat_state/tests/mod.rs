@@ -1,7 +1,9 @@
+mod hidden;
 mod messages;
 mod navigation;
 mod pagination;
 mod state_transitions;
+mod visual;
 
 use super::*;
 use crate::domain::chat::ChatType;
@@ -19,9 +21,11 @@ fn message(id: i64, text: &str) -> Message {
         call_info: None,
         reply_to: None,
         forward_info: None,
-        reaction_count: 0,
+        reactions: Vec::new(),
         links: Vec::new(),
         is_edited: false,
         is_service: false,
+        inline_keyboard: Vec::new(),
+        reply_keyboard: None,
     }
 }
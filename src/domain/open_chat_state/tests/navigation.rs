@@ -258,3 +258,23 @@ fn selected_message_follows_navigation() {
     assert_eq!(msg.id, 1);
     assert_eq!(msg.text, "A");
 }
+
+#[test]
+fn select_message_by_id_moves_to_loaded_message() {
+    let mut state = OpenChatState::default();
+    state.set_loading(1, "Chat".to_owned(), ChatType::Private);
+    state.set_ready(vec![message(1, "A"), message(2, "B"), message(3, "C")]);
+
+    assert!(state.select_message_by_id(1));
+    assert_eq!(state.selected_index(), Some(0));
+}
+
+#[test]
+fn select_message_by_id_keeps_selection_when_not_loaded() {
+    let mut state = OpenChatState::default();
+    state.set_loading(1, "Chat".to_owned(), ChatType::Private);
+    state.set_ready(vec![message(1, "A"), message(2, "B")]);
+
+    assert!(!state.select_message_by_id(42));
+    assert_eq!(state.selected_index(), Some(1));
+}
@@ -100,3 +100,74 @@ fn set_loading_resets_all_messages_loaded() {
     state.set_loading(2, "New".to_owned(), ChatType::Private);
     assert!(!state.all_messages_loaded());
 }
+
+// ── History window (jump to date) ──
+
+fn timed(id: i64, timestamp_ms: i64) -> Message {
+    let mut msg = message(id, "msg");
+    msg.timestamp_ms = timestamp_ms;
+    msg
+}
+
+#[test]
+fn show_history_window_selects_first_message_at_or_after_target() {
+    let mut state = ready_state_with_messages(vec![message(99, "latest")]);
+
+    state.show_history_window(vec![timed(1, 100), timed(2, 200), timed(3, 300)], 150);
+
+    assert!(state.is_history_window());
+    assert_eq!(state.selected_message().map(|m| m.id), Some(2));
+    assert_eq!(state.messages().len(), 3);
+    assert!(!state.all_messages_loaded());
+}
+
+#[test]
+fn show_history_window_falls_back_to_newest_when_target_is_after_window() {
+    let mut state = ready_state_with_messages(vec![]);
+
+    state.show_history_window(vec![timed(1, 100), timed(2, 200)], 500);
+
+    assert_eq!(state.selected_message().map(|m| m.id), Some(2));
+}
+
+#[test]
+fn leave_history_window_returns_to_loading() {
+    let mut state = ready_state_with_messages(vec![]);
+    state.show_history_window(vec![timed(1, 100)], 100);
+
+    state.leave_history_window();
+
+    assert!(!state.is_history_window());
+    assert!(state.messages().is_empty());
+    assert_eq!(state.ui_state(), OpenChatUiState::Loading);
+}
+
+#[test]
+fn set_loading_clears_history_window() {
+    let mut state = ready_state_with_messages(vec![]);
+    state.show_history_window(vec![timed(1, 100)], 100);
+
+    state.set_loading(2, "Other".to_owned(), ChatType::Private);
+
+    assert!(!state.is_history_window());
+}
+
+#[test]
+fn show_history_window_at_selects_message_by_id() {
+    let mut state = ready_state_with_messages(vec![message(99, "latest")]);
+
+    state.show_history_window_at(vec![message(1, "a"), message(2, "b"), message(3, "c")], 2);
+
+    assert!(state.is_history_window());
+    assert_eq!(state.selected_message().map(|m| m.id), Some(2));
+}
+
+#[test]
+fn set_ready_leaves_history_window() {
+    let mut state = ready_state_with_messages(vec![message(99, "latest")]);
+    state.show_history_window_at(vec![message(1, "a")], 1);
+
+    state.set_ready(vec![message(99, "latest")]);
+
+    assert!(!state.is_history_window());
+}
@@ -0,0 +1,92 @@
+use super::*;
+
+fn ready_state() -> OpenChatState {
+    let mut state = OpenChatState::default();
+    state.set_loading(1, "Chat".to_owned(), ChatType::Private);
+    state.set_ready(vec![
+        message(1, "A"),
+        message(2, "B"),
+        message(3, "C"),
+        message(4, "D"),
+    ]);
+    state
+}
+
+fn selected_ids(state: &OpenChatState) -> Vec<i64> {
+    state.selected_messages().iter().map(|m| m.id).collect()
+}
+
+#[test]
+fn without_visual_mode_only_the_cursor_message_is_selected() {
+    let state = ready_state();
+
+    assert!(!state.is_visual());
+    assert_eq!(selected_ids(&state), vec![4]);
+}
+
+#[test]
+fn visual_range_extends_in_both_directions() {
+    let mut state = ready_state();
+    state.set_selected_index_for_test(Some(1));
+    assert!(state.start_visual());
+
+    state.select_next();
+    state.select_next();
+    assert_eq!(state.visual_range(), Some(1..=3));
+    assert_eq!(selected_ids(&state), vec![2, 3, 4]);
+
+    for _ in 0..3 {
+        state.select_previous();
+    }
+    assert_eq!(state.visual_range(), Some(0..=1));
+    assert_eq!(selected_ids(&state), vec![1, 2]);
+}
+
+#[test]
+fn visual_anchor_follows_prepended_messages() {
+    let mut state = ready_state();
+    state.start_visual();
+    state.select_previous();
+
+    state.prepend_older_messages(vec![message(-1, "old"), message(0, "older")]);
+
+    assert_eq!(selected_ids(&state), vec![3, 4]);
+}
+
+#[test]
+fn visual_mode_ends_when_anchor_is_removed() {
+    let mut state = ready_state();
+    state.start_visual();
+    state.select_previous();
+
+    state.remove_message(4);
+
+    assert!(!state.is_visual());
+    assert_eq!(selected_ids(&state), vec![3]);
+}
+
+#[test]
+fn cancel_and_reload_clear_visual_mode() {
+    let mut state = ready_state();
+    state.start_visual();
+    state.cancel_visual();
+    assert!(!state.is_visual());
+
+    state.start_visual();
+    state.set_loading(2, "Other".to_owned(), ChatType::Private);
+    state.set_ready(vec![message(4, "same id, other chat")]);
+    assert!(!state.is_visual());
+}
+
+#[test]
+fn pending_message_cannot_start_visual_mode() {
+    let mut state = ready_state();
+    state.add_pending_message(
+        "sending".to_owned(),
+        crate::domain::message::MessageMedia::None,
+        None,
+    );
+
+    assert!(!state.start_visual());
+    assert!(!state.is_visual());
+}
@@ -0,0 +1,189 @@
+//! Quick switcher popup (`Ctrl+K`): recently opened chats and topics,
+//! newest first, narrowed by a fuzzy match on their titles.
+
+use super::{fuzzy::fuzzy_match, recent_chats::RecentChat, selectable_list::SelectableList};
+
+/// A recent chat matching the switcher query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuickSwitcherEntry {
+    pub chat: RecentChat,
+    /// Indices (in chars) of the display title characters matched by the query.
+    pub matches: Vec<usize>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct QuickSwitcherState {
+    query: String,
+    cursor_position: usize,
+    recent: Vec<RecentChat>,
+    entries: SelectableList<QuickSwitcherEntry>,
+}
+
+impl QuickSwitcherState {
+    /// Lists `recent` (newest first). When `skip_first` is set the second
+    /// entry starts selected, so `Ctrl+K` `Enter` returns to the chat
+    /// opened before the current one.
+    pub fn new(recent: Vec<RecentChat>, skip_first: bool) -> Self {
+        let mut state = Self {
+            recent,
+            ..Self::default()
+        };
+        state.refilter();
+        if skip_first {
+            state.entries.select_next();
+        }
+        state
+    }
+
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    pub fn insert_char(&mut self, ch: char) {
+        self.query.insert(self.cursor_position, ch);
+        self.cursor_position += ch.len_utf8();
+        self.refilter();
+    }
+
+    pub fn delete_char_before(&mut self) {
+        if self.cursor_position == 0 {
+            return;
+        }
+        let prev = self.query[..self.cursor_position]
+            .char_indices()
+            .next_back()
+            .map(|(i, _)| i)
+            .unwrap_or(0);
+        self.query.drain(prev..self.cursor_position);
+        self.cursor_position = prev;
+        self.refilter();
+    }
+
+    /// Matching entries, best match first; most recent first among equals.
+    pub fn entries(&self) -> &[QuickSwitcherEntry] {
+        self.entries.items()
+    }
+
+    pub fn selected_index(&self) -> Option<usize> {
+        self.entries.selected_index()
+    }
+
+    pub fn selected_chat(&self) -> Option<&RecentChat> {
+        self.entries.selected().map(|entry| &entry.chat)
+    }
+
+    pub fn select_next(&mut self) {
+        self.entries.select_next();
+    }
+
+    pub fn select_previous(&mut self) {
+        self.entries.select_previous();
+    }
+
+    fn refilter(&mut self) {
+        let mut ranked: Vec<(i32, QuickSwitcherEntry)> = self
+            .recent
+            .iter()
+            .filter_map(|chat| {
+                let found = fuzzy_match(&self.query, &chat.display_title())?;
+                Some((
+                    found.score,
+                    QuickSwitcherEntry {
+                        chat: chat.clone(),
+                        matches: found.positions,
+                    },
+                ))
+            })
+            .collect();
+        // Stable: equally ranked entries keep the most-recent-first order.
+        ranked.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+        self.entries
+            .replace(ranked.into_iter().map(|(_, entry)| entry).collect(), None);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::chat::ChatType;
+
+    fn recent() -> Vec<RecentChat> {
+        vec![
+            RecentChat::chat(1, "Alice", ChatType::Private),
+            RecentChat::topic(2, "Rust Forum", 10, "Async"),
+            RecentChat::chat(3, "Team chat", ChatType::Group),
+            RecentChat::chat(4, "Travel", ChatType::Group),
+        ]
+    }
+
+    fn titles(state: &QuickSwitcherState) -> Vec<String> {
+        state
+            .entries()
+            .iter()
+            .map(|e| e.chat.display_title())
+            .collect()
+    }
+
+    fn typed(query: &str) -> QuickSwitcherState {
+        let mut state = QuickSwitcherState::new(recent(), false);
+        for ch in query.chars() {
+            state.insert_char(ch);
+        }
+        state
+    }
+
+    #[test]
+    fn empty_query_lists_everything_most_recent_first() {
+        let state = QuickSwitcherState::new(recent(), false);
+
+        assert_eq!(
+            titles(&state),
+            vec!["Alice", "Rust Forum > Async", "Team chat", "Travel"]
+        );
+        assert_eq!(state.selected_index(), Some(0));
+    }
+
+    #[test]
+    fn skip_first_selects_the_previous_chat() {
+        let state = QuickSwitcherState::new(recent(), true);
+
+        assert_eq!(state.selected_chat().map(|c| c.chat_id), Some(2));
+    }
+
+    #[test]
+    fn query_filters_fuzzily_and_ranks_by_score() {
+        let state = typed("tc");
+
+        // "Team chat" matches at two word starts; "Rust Forum > Async"
+        // only matches scattered letters, and "Travel" has no "c".
+        assert_eq!(titles(&state), vec!["Team chat", "Rust Forum > Async"]);
+        assert_eq!(state.entries()[0].matches, vec![0, 5]);
+    }
+
+    #[test]
+    fn topics_match_on_their_name() {
+        let state = typed("async");
+
+        assert_eq!(titles(&state), vec!["Rust Forum > Async"]);
+        assert_eq!(state.selected_chat().and_then(|c| c.topic_id()), Some(10));
+    }
+
+    #[test]
+    fn no_match_leaves_nothing_selected() {
+        let state = typed("zzz");
+
+        assert!(state.entries().is_empty());
+        assert_eq!(state.selected_chat(), None);
+    }
+
+    #[test]
+    fn deleting_the_query_restores_all_entries() {
+        let mut state = typed("zz");
+        state.delete_char_before();
+        state.delete_char_before();
+        state.delete_char_before();
+
+        assert_eq!(state.query(), "");
+        assert_eq!(state.entries().len(), 4);
+    }
+}
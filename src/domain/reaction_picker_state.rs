@@ -1,47 +1,45 @@
+use super::emoji::EMOJI;
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AvailableReaction {
     pub emoji: String,
     pub needs_premium: bool,
     pub is_chosen: bool,
+    /// Recently used by the account; listed in a section of its own.
+    pub is_recent: bool,
 }
 
 impl AvailableReaction {
-    pub fn display_name(&self) -> &str {
-        allowed_reaction_name(&self.emoji).unwrap_or("")
+    /// Shortcode name of the emoji, or an empty string if it is not in the
+    /// emoji table. Variation selectors are ignored when matching.
+    pub fn display_name(&self) -> &'static str {
+        let emoji = strip_variation_selector(&self.emoji);
+        EMOJI
+            .iter()
+            .find(|e| strip_variation_selector(e.emoji) == emoji)
+            .map(|e| e.name)
+            .unwrap_or("")
     }
-}
 
-const ALLOWED_REACTIONS: &[(&str, &str)] = &[
-    ("👌", "ok"),
-    ("😁", "grin"),
-    ("👍", "thumbs_up"),
-    ("🔥", "fire"),
-    ("❤", "heart"),
-    ("😱", "shocked"),
-    ("🙏", "pray"),
-    ("👀", "eyes"),
-    ("🌚", "new_moon"),
-    ("👎", "thumbs_down"),
-    ("🤔", "thinking"),
-];
-
-pub fn allowed_reaction_name(emoji: &str) -> Option<&'static str> {
-    ALLOWED_REACTIONS
-        .iter()
-        .find(|(e, _)| *e == emoji)
-        .map(|(_, name)| *name)
+    fn matches(&self, query: &str) -> bool {
+        let name = self.display_name();
+        name.contains(query) || self.emoji == query
+    }
 }
 
-pub fn is_allowed_reaction(emoji: &str) -> bool {
-    ALLOWED_REACTIONS.iter().any(|(e, _)| *e == emoji)
+fn strip_variation_selector(emoji: &str) -> String {
+    emoji.chars().filter(|&c| c != '\u{fe0f}').collect()
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ReactionPickerData {
     pub items: Vec<AvailableReaction>,
+    /// Index into [`visible`](Self::visible).
     pub selected_index: usize,
     pub chat_id: i64,
     pub message_id: i64,
+    query: String,
+    searching: bool,
 }
 
 impl ReactionPickerData {
@@ -51,11 +49,57 @@ impl ReactionPickerData {
             selected_index: 0,
             chat_id,
             message_id,
+            query: String::new(),
+            searching: false,
         }
     }
 
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    /// Whether typed characters go to the search query.
+    pub fn is_searching(&self) -> bool {
+        self.searching
+    }
+
+    /// Reactions matching the query, in list order.
+    pub fn visible(&self) -> Vec<&AvailableReaction> {
+        let query = self.query.to_lowercase();
+        self.items
+            .iter()
+            .filter(|r| query.is_empty() || r.matches(&query))
+            .collect()
+    }
+
+    pub fn start_search(&mut self) {
+        self.searching = true;
+    }
+
+    /// Leaves search mode, keeping the current filter.
+    pub fn finish_search(&mut self) {
+        self.searching = false;
+    }
+
+    /// Leaves search mode and clears the filter.
+    pub fn cancel_search(&mut self) {
+        self.searching = false;
+        self.query.clear();
+        self.selected_index = 0;
+    }
+
+    pub fn insert_char(&mut self, ch: char) {
+        self.query.push(ch);
+        self.selected_index = 0;
+    }
+
+    pub fn delete_char_before(&mut self) {
+        self.query.pop();
+        self.selected_index = 0;
+    }
+
     pub fn select_next(&mut self) {
-        if !self.items.is_empty() && self.selected_index + 1 < self.items.len() {
+        if self.selected_index + 1 < self.visible().len() {
             self.selected_index += 1;
         }
     }
@@ -67,7 +111,7 @@ impl ReactionPickerData {
     }
 
     pub fn selected_reaction(&self) -> Option<&AvailableReaction> {
-        self.items.get(self.selected_index)
+        self.visible().get(self.selected_index).copied()
     }
 }
 
@@ -108,16 +152,19 @@ mod tests {
                 emoji: "👍".into(),
                 needs_premium: false,
                 is_chosen: false,
+                is_recent: false,
             },
             AvailableReaction {
                 emoji: "👎".into(),
                 needs_premium: false,
                 is_chosen: false,
+                is_recent: false,
             },
             AvailableReaction {
                 emoji: "❤".into(),
                 needs_premium: false,
                 is_chosen: false,
+                is_recent: false,
             },
         ]
     }
@@ -211,6 +258,7 @@ mod tests {
             emoji: "👍".into(),
             needs_premium: false,
             is_chosen: false,
+            is_recent: false,
         };
         assert_eq!(r.display_name(), "thumbs_up");
 
@@ -218,6 +266,7 @@ mod tests {
             emoji: "❤".into(),
             needs_premium: false,
             is_chosen: false,
+            is_recent: false,
         };
         assert_eq!(r.display_name(), "heart");
 
@@ -225,6 +274,7 @@ mod tests {
             emoji: "🔥".into(),
             needs_premium: false,
             is_chosen: false,
+            is_recent: false,
         };
         assert_eq!(r.display_name(), "fire");
 
@@ -232,32 +282,66 @@ mod tests {
             emoji: "👌".into(),
             needs_premium: false,
             is_chosen: false,
+            is_recent: false,
         };
-        assert_eq!(r.display_name(), "ok");
+        assert_eq!(r.display_name(), "ok_hand");
+    }
+
+    #[test]
+    fn display_name_ignores_variation_selector() {
+        let r = AvailableReaction {
+            emoji: "❤️".into(),
+            needs_premium: false,
+            is_chosen: false,
+            is_recent: false,
+        };
+        assert_eq!(r.display_name(), "heart");
     }
 
     #[test]
     fn display_name_returns_empty_for_unknown() {
         let r = AvailableReaction {
-            emoji: "🧪".into(),
+            emoji: "🫠🫠".into(),
             needs_premium: false,
             is_chosen: false,
+            is_recent: false,
         };
         assert_eq!(r.display_name(), "");
     }
 
     #[test]
-    fn is_allowed_filters_correctly() {
-        assert!(is_allowed_reaction("👍"));
-        assert!(is_allowed_reaction("❤"));
-        assert!(is_allowed_reaction("🤔"));
-        assert!(!is_allowed_reaction("🧪"));
-        assert!(!is_allowed_reaction("🤣"));
+    fn query_filters_by_name_and_resets_selection() {
+        let mut data = ReactionPickerData::new(sample_reactions(), 1, 2);
+        data.select_next();
+        data.start_search();
+        for ch in "thumbs".chars() {
+            data.insert_char(ch);
+        }
+
+        assert_eq!(data.selected_index, 0);
+        let visible: Vec<_> = data.visible().iter().map(|r| r.emoji.as_str()).collect();
+        assert_eq!(visible, vec!["👍", "👎"]);
+
+        data.select_next();
+        data.select_next();
+        assert_eq!(data.selected_reaction().unwrap().emoji, "👎");
     }
 
     #[test]
-    fn allowed_reactions_has_exactly_eleven_entries() {
-        assert_eq!(ALLOWED_REACTIONS.len(), 11);
+    fn finish_search_keeps_filter_and_cancel_clears_it() {
+        let mut data = ReactionPickerData::new(sample_reactions(), 1, 2);
+        data.start_search();
+        data.insert_char('h');
+        data.insert_char('e');
+        data.finish_search();
+
+        assert!(!data.is_searching());
+        assert_eq!(data.visible().len(), 1);
+
+        data.start_search();
+        data.cancel_search();
+        assert_eq!(data.query(), "");
+        assert_eq!(data.visible().len(), 3);
     }
 
     #[test]
@@ -267,11 +351,13 @@ mod tests {
                 emoji: "👍".into(),
                 needs_premium: false,
                 is_chosen: false,
+                is_recent: false,
             },
             AvailableReaction {
                 emoji: "🔥".into(),
                 needs_premium: false,
                 is_chosen: true,
+                is_recent: false,
             },
         ];
         let mut data = ReactionPickerData::new(items, 1, 2);
@@ -287,6 +373,7 @@ mod tests {
             emoji: "👍".into(),
             needs_premium: false,
             is_chosen: false,
+            is_recent: false,
         }];
         let mut data = ReactionPickerData::new(items, 1, 2);
         assert_eq!(data.selected_index, 0);
@@ -0,0 +1,225 @@
+//! Most recently opened chats and forum topics, newest first.
+//!
+//! Feeds the quick switcher (`Ctrl+K`) and the "previous chat" toggle.
+//! Forum topics are separate entries from each other; a forum itself is
+//! never recorded since opening it only shows its topic list.
+
+use super::chat::ChatType;
+
+/// How many entries are kept (and persisted).
+pub const MAX_RECENT_CHATS: usize = 50;
+
+/// The forum topic part of a [`RecentChat`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecentTopic {
+    pub topic_id: i32,
+    pub name: String,
+}
+
+/// A chat, or a forum topic, the user opened.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecentChat {
+    pub chat_id: i64,
+    /// Title of the chat (of the forum for topics).
+    pub title: String,
+    pub chat_type: ChatType,
+    pub topic: Option<RecentTopic>,
+}
+
+impl RecentChat {
+    pub fn chat(chat_id: i64, title: impl Into<String>, chat_type: ChatType) -> Self {
+        Self {
+            chat_id,
+            title: title.into(),
+            chat_type,
+            topic: None,
+        }
+    }
+
+    pub fn topic(
+        chat_id: i64,
+        forum_title: impl Into<String>,
+        topic_id: i32,
+        topic_name: impl Into<String>,
+    ) -> Self {
+        Self {
+            chat_id,
+            title: forum_title.into(),
+            chat_type: ChatType::Group,
+            topic: Some(RecentTopic {
+                topic_id,
+                name: topic_name.into(),
+            }),
+        }
+    }
+
+    pub fn topic_id(&self) -> Option<i32> {
+        self.topic.as_ref().map(|topic| topic.topic_id)
+    }
+
+    /// Whether both entries open the same chat or topic.
+    pub fn same_target(&self, other: &Self) -> bool {
+        self.chat_id == other.chat_id && self.topic_id() == other.topic_id()
+    }
+
+    /// `Forum > Topic` for topics, the chat title otherwise.
+    pub fn display_title(&self) -> String {
+        match &self.topic {
+            Some(topic) => format!("{} > {}", self.title, topic.name),
+            None => self.title.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RecentChats {
+    entries: Vec<RecentChat>,
+    /// Set when the entries changed since the last [`Self::take_unsaved`].
+    unsaved: bool,
+}
+
+impl RecentChats {
+    /// Restores saved entries, newest first, dropping duplicates and
+    /// anything past [`MAX_RECENT_CHATS`].
+    pub fn from_entries(entries: Vec<RecentChat>) -> Self {
+        let mut deduped: Vec<RecentChat> = Vec::with_capacity(entries.len());
+        for entry in entries {
+            if !deduped.iter().any(|e| e.same_target(&entry)) {
+                deduped.push(entry);
+            }
+        }
+        deduped.truncate(MAX_RECENT_CHATS);
+        Self {
+            entries: deduped,
+            unsaved: false,
+        }
+    }
+
+    pub fn entries(&self) -> &[RecentChat] {
+        &self.entries
+    }
+
+    /// Moves `entry` to the front, refreshing its title.
+    pub fn record(&mut self, entry: RecentChat) {
+        if self.entries.first() == Some(&entry) {
+            return;
+        }
+        self.entries.retain(|e| !e.same_target(&entry));
+        self.entries.insert(0, entry);
+        self.entries.truncate(MAX_RECENT_CHATS);
+        self.unsaved = true;
+    }
+
+    /// Forgets a chat and its topics, e.g. after leaving it.
+    pub fn remove_chat(&mut self, chat_id: i64) {
+        let before = self.entries.len();
+        self.entries.retain(|e| e.chat_id != chat_id);
+        if self.entries.len() != before {
+            self.unsaved = true;
+        }
+    }
+
+    /// Returns whether the entries changed since the last call.
+    pub fn take_unsaved(&mut self) -> bool {
+        std::mem::take(&mut self.unsaved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chat(chat_id: i64) -> RecentChat {
+        RecentChat::chat(chat_id, format!("Chat {chat_id}"), ChatType::Private)
+    }
+
+    fn ids(recent: &RecentChats) -> Vec<(i64, Option<i32>)> {
+        recent
+            .entries()
+            .iter()
+            .map(|e| (e.chat_id, e.topic_id()))
+            .collect()
+    }
+
+    #[test]
+    fn record_moves_entry_to_front() {
+        let mut recent = RecentChats::default();
+        recent.record(chat(1));
+        recent.record(chat(2));
+        recent.record(chat(1));
+
+        assert_eq!(ids(&recent), vec![(1, None), (2, None)]);
+    }
+
+    #[test]
+    fn topics_are_separate_entries() {
+        let mut recent = RecentChats::default();
+        recent.record(RecentChat::topic(5, "Forum", 1, "General"));
+        recent.record(RecentChat::topic(5, "Forum", 2, "Off-topic"));
+        recent.record(chat(7));
+
+        assert_eq!(ids(&recent), vec![(7, None), (5, Some(2)), (5, Some(1))]);
+        assert_eq!(recent.entries()[1].display_title(), "Forum > Off-topic");
+    }
+
+    #[test]
+    fn record_refreshes_title() {
+        let mut recent = RecentChats::default();
+        recent.record(chat(1));
+        recent.record(chat(2));
+        recent.record(RecentChat::chat(1, "Renamed", ChatType::Private));
+
+        assert_eq!(recent.entries()[0].title, "Renamed");
+        assert_eq!(recent.entries().len(), 2);
+    }
+
+    #[test]
+    fn keeps_at_most_max_entries() {
+        let mut recent = RecentChats::default();
+        for chat_id in 0..(MAX_RECENT_CHATS as i64 + 5) {
+            recent.record(chat(chat_id));
+        }
+
+        assert_eq!(recent.entries().len(), MAX_RECENT_CHATS);
+        assert_eq!(recent.entries()[0].chat_id, MAX_RECENT_CHATS as i64 + 4);
+    }
+
+    #[test]
+    fn from_entries_drops_duplicates_and_is_saved() {
+        let mut recent = RecentChats::from_entries(vec![chat(1), chat(2), chat(1)]);
+
+        assert_eq!(ids(&recent), vec![(1, None), (2, None)]);
+        assert!(!recent.take_unsaved());
+    }
+
+    #[test]
+    fn tracks_unsaved_changes() {
+        let mut recent = RecentChats::default();
+        recent.record(chat(1));
+        assert!(recent.take_unsaved());
+        assert!(!recent.take_unsaved());
+
+        recent.record(chat(1));
+        assert!(
+            !recent.take_unsaved(),
+            "re-opening the latest chat changes nothing"
+        );
+
+        recent.record(chat(2));
+        recent.remove_chat(1);
+        assert!(recent.take_unsaved());
+        assert_eq!(ids(&recent), vec![(2, None)]);
+    }
+
+    #[test]
+    fn remove_chat_drops_its_topics() {
+        let mut recent = RecentChats::default();
+        recent.record(RecentChat::topic(5, "Forum", 1, "General"));
+        recent.record(chat(7));
+        recent.record(RecentChat::topic(5, "Forum", 2, "Off-topic"));
+
+        recent.remove_chat(5);
+
+        assert_eq!(ids(&recent), vec![(7, None)]);
+    }
+}
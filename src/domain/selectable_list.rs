@@ -1,5 +1,5 @@
 //! Generic cursor-over-Vec used by left-panel state types (chat list,
-//! forum topic list).
+//! forum topic list, contacts).
 //!
 //! Owns the items and the selected index, and exposes cursor-only navigation
 //! (`select_next`, `select_previous`, `select_first`). Replacing the items is
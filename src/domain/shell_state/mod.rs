@@ -0,0 +1,327 @@
+use std::time::{Duration, Instant};
+
+use super::{
+    bot_command::BotCommandCache,
+    button_grid_state::{InlineButtonSelection, ReplyKeyboardSelection},
+    chat::ChatSummary,
+    chat_info_state::ChatInfoPopupState,
+    chat_list_state::ChatListState,
+    chat_manage_state::ChatConfirmState,
+    chat_member::MemberSearch,
+    chat_search_state::ChatSearchState,
+    command_popup_state::CommandPopupState,
+    completion_state::CompletionState,
+    contacts_state::ContactsState,
+    date_prompt_state::DatePromptState,
+    delete_confirm_state::DeleteConfirmState,
+    emoji_picker_state::EmojiPickerState,
+    events::ConnectivityStatus,
+    forum_topic_list_state::ForumTopicListState,
+    go_to_prompt_state::GoToPromptState,
+    link_hints_state::LinkHintsState,
+    link_target::ChatInvite,
+    message_cache::MessageCache,
+    message_info_state::MessageInfoPopupState,
+    message_input_state::MessageInputState,
+    new_chat_wizard_state::NewChatWizardState,
+    open_chat_state::OpenChatState,
+    quick_switcher_state::QuickSwitcherState,
+    reaction_picker_state::ReactionPickerState,
+};
+
+mod popups;
+#[cfg(test)]
+mod tests;
+
+const NOTIFICATION_TTL: Duration = Duration::from_secs(3);
+
+/// Represents which panel currently has focus for keyboard navigation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ActivePane {
+    /// The left panel showing the list of chats.
+    #[default]
+    ChatList,
+    /// The right panel showing messages for the selected chat.
+    Messages,
+    /// The message input field for composing messages.
+    MessageInput,
+}
+
+/// Messages picked for forwarding while the user chooses the target chat
+/// in the chat list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingForward {
+    pub from_chat_id: i64,
+    pub message_ids: Vec<i64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ShellState {
+    running: bool,
+    connectivity_status: ConnectivityStatus,
+    chat_list: ChatListState,
+    /// Topic list for the forum chat the user is currently browsing.
+    ///
+    /// `None` means the root chat list is shown in the left panel. When
+    /// `Some`, the root `chat_list` stays intact (selection, pagination,
+    /// chats) — leaving the forum (`leave_forum`) simply drops this field
+    /// and the root list reappears unchanged.
+    forum_topic_list: Option<ForumTopicListState>,
+    /// Contacts shown in the left panel instead of the chat list (or an
+    /// open forum's topics).
+    contacts: Option<ContactsState>,
+    open_chat: OpenChatState,
+    message_cache: MessageCache,
+    message_input: MessageInputState,
+    active_pane: ActivePane,
+    help_visible: bool,
+    command_popup: Option<CommandPopupState>,
+    /// Notification text, when it was set and how long it stays.
+    notification: Option<(String, Instant, Duration)>,
+    chat_info_popup: Option<ChatInfoPopupState>,
+    message_info_popup: Option<MessageInfoPopupState>,
+    reaction_picker: Option<ReactionPickerState>,
+    chat_search: Option<ChatSearchState>,
+    /// Recent-chats quick switcher.
+    quick_switcher: Option<QuickSwitcherState>,
+    /// "Jump to date" prompt shown over the messages panel.
+    date_prompt: Option<DatePromptState>,
+    /// "Go to chat" prompt for opening a chat by username, phone or name.
+    go_to_prompt: Option<GoToPromptState>,
+    /// Button-selection mode over a bot message's inline keyboard.
+    inline_button_selection: Option<InlineButtonSelection>,
+    /// Bot callback answer that must be acknowledged (`show_alert`).
+    alert: Option<String>,
+    /// Button-selection mode over the open chat's custom reply keyboard.
+    reply_keyboard_selection: Option<ReplyKeyboardSelection>,
+    /// Completion popup for the token under the input cursor.
+    completion: Option<CompletionState>,
+    bot_commands: BotCommandCache,
+    member_search: MemberSearch,
+    /// Emoji picker opened from the message input.
+    emoji_picker: Option<EmojiPickerState>,
+    pending_forward: Option<PendingForward>,
+    /// "Delete messages?" confirmation popup.
+    delete_confirm: Option<DeleteConfirmState>,
+    /// "Leave / delete / clear history?" confirmation for a chat.
+    chat_confirm: Option<ChatConfirmState>,
+    /// "Join chat?" confirmation for an opened invite link.
+    join_prompt: Option<ChatInvite>,
+    /// Numbered list of the links in a message, for picking one.
+    link_hints: Option<LinkHintsState>,
+    /// Wizard for creating a group, supergroup, forum or channel.
+    new_chat_wizard: Option<NewChatWizardState>,
+}
+
+impl Default for ShellState {
+    fn default() -> Self {
+        Self {
+            running: true,
+            connectivity_status: ConnectivityStatus::Connecting,
+            chat_list: ChatListState::default(),
+            forum_topic_list: None,
+            contacts: None,
+            open_chat: OpenChatState::default(),
+            message_cache: MessageCache::default(),
+            message_input: MessageInputState::default(),
+            active_pane: ActivePane::default(),
+            help_visible: false,
+            command_popup: None,
+            notification: None,
+            chat_info_popup: None,
+            message_info_popup: None,
+            reaction_picker: None,
+            chat_search: None,
+            quick_switcher: None,
+            date_prompt: None,
+            go_to_prompt: None,
+            inline_button_selection: None,
+            alert: None,
+            reply_keyboard_selection: None,
+            completion: None,
+            bot_commands: BotCommandCache::default(),
+            member_search: MemberSearch::default(),
+            emoji_picker: None,
+            pending_forward: None,
+            delete_confirm: None,
+            chat_confirm: None,
+            join_prompt: None,
+            link_hints: None,
+            new_chat_wizard: None,
+        }
+    }
+}
+
+impl ShellState {
+    /// Creates a state pre-populated with cached chat list data.
+    ///
+    /// If `chats` is non-empty, `ChatListState` starts as `Ready` immediately,
+    /// allowing the TUI to display cached chats on the very first frame.
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub fn with_initial_chat_list(chats: Vec<ChatSummary>) -> Self {
+        Self {
+            chat_list: ChatListState::with_initial_chats(chats),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a state with cached chat list data and custom cache limits.
+    pub fn with_cache_limits(
+        chats: Vec<ChatSummary>,
+        max_cached_chats: usize,
+        max_messages_per_chat: usize,
+    ) -> Self {
+        Self {
+            chat_list: ChatListState::with_initial_chats(chats),
+            message_cache: MessageCache::new(max_cached_chats, max_messages_per_chat),
+            ..Default::default()
+        }
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.running
+    }
+
+    pub fn stop(&mut self) {
+        self.running = false;
+    }
+
+    pub fn connectivity_status(&self) -> ConnectivityStatus {
+        self.connectivity_status
+    }
+
+    pub fn set_connectivity_status(&mut self, status: ConnectivityStatus) {
+        self.connectivity_status = status;
+    }
+
+    #[allow(dead_code)]
+    pub fn chat_list(&self) -> &ChatListState {
+        &self.chat_list
+    }
+
+    #[allow(dead_code)]
+    pub fn chat_list_mut(&mut self) -> &mut ChatListState {
+        &mut self.chat_list
+    }
+
+    /// Returns the active forum topic list, if any. `None` means the root
+    /// chat list is shown.
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub fn forum_topic_list(&self) -> Option<&ForumTopicListState> {
+        self.forum_topic_list.as_ref()
+    }
+
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub fn forum_topic_list_mut(&mut self) -> Option<&mut ForumTopicListState> {
+        self.forum_topic_list.as_mut()
+    }
+
+    /// Installs the topic-list panel for a forum chat in `Loading` state.
+    ///
+    /// Does not touch `chat_list` — the root list keeps its selection and
+    /// pagination so the user returns to the same view on `leave_forum`.
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub fn enter_forum(&mut self, parent_chat_id: i64, parent_chat_title: impl Into<String>) {
+        self.forum_topic_list = Some(ForumTopicListState::loading(
+            parent_chat_id,
+            parent_chat_title.into(),
+        ));
+    }
+
+    /// Drops the topic-list panel. The root chat list is untouched.
+    #[cfg_attr(not(test), allow(dead_code))]
+    pub fn leave_forum(&mut self) {
+        self.forum_topic_list = None;
+    }
+
+    pub fn contacts(&self) -> Option<&ContactsState> {
+        self.contacts.as_ref()
+    }
+
+    pub fn contacts_mut(&mut self) -> Option<&mut ContactsState> {
+        self.contacts.as_mut()
+    }
+
+    /// Shows the contacts panel in `Loading` state over the chat list.
+    pub fn open_contacts(&mut self) {
+        self.contacts = Some(ContactsState::loading());
+    }
+
+    pub fn close_contacts(&mut self) {
+        self.contacts = None;
+    }
+
+    pub fn open_chat(&self) -> &OpenChatState {
+        &self.open_chat
+    }
+
+    /// True when a forum topic is open and the cached topic summary marks it
+    /// as closed. Used to gate the message input — admins can still post but
+    /// we don't surface that capability in the TUI yet, so any open of input
+    /// is treated as read-only.
+    pub fn open_topic_is_closed(&self) -> bool {
+        let Some(topic_id) = self.open_chat.topic_id() else {
+            return false;
+        };
+        self.forum_topic_list
+            .as_ref()
+            .and_then(|list| list.find_topic(topic_id))
+            .is_some_and(|t| t.is_closed)
+    }
+
+    pub fn open_chat_mut(&mut self) -> &mut OpenChatState {
+        &mut self.open_chat
+    }
+
+    pub fn message_cache(&self) -> &MessageCache {
+        &self.message_cache
+    }
+
+    pub fn message_cache_mut(&mut self) -> &mut MessageCache {
+        &mut self.message_cache
+    }
+
+    pub fn active_pane(&self) -> ActivePane {
+        self.active_pane
+    }
+
+    pub fn set_active_pane(&mut self, pane: ActivePane) {
+        self.active_pane = pane;
+    }
+
+    pub fn message_input(&self) -> &MessageInputState {
+        &self.message_input
+    }
+
+    pub fn message_input_mut(&mut self) -> &mut MessageInputState {
+        &mut self.message_input
+    }
+
+    pub fn set_notification(&mut self, text: impl Into<String>) {
+        self.set_notification_for(text, NOTIFICATION_TTL);
+    }
+
+    /// Shows a notification for `ttl` instead of the default duration,
+    /// e.g. for as long as an action can be undone.
+    pub fn set_notification_for(&mut self, text: impl Into<String>, ttl: Duration) {
+        self.notification = Some((text.into(), Instant::now(), ttl));
+    }
+
+    /// Returns the notification text if it hasn't expired yet.
+    pub fn active_notification(&self) -> Option<&str> {
+        self.notification
+            .as_ref()
+            .and_then(|(text, created_at, ttl)| {
+                if created_at.elapsed() < *ttl {
+                    Some(text.as_str())
+                } else {
+                    None
+                }
+            })
+    }
+
+    #[cfg(test)]
+    pub fn set_notification_at(&mut self, text: impl Into<String>, at: Instant) {
+        self.notification = Some((text.into(), at, NOTIFICATION_TTL));
+    }
+}
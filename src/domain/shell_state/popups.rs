@@ -0,0 +1,354 @@
+use crate::domain::{
+    bot_command::BotCommandCache,
+    button_grid_state::{InlineButtonSelection, ReplyKeyboardSelection},
+    chat_info_state::ChatInfoPopupState,
+    chat_manage_state::ChatConfirmState,
+    chat_member::MemberSearch,
+    chat_search_state::ChatSearchState,
+    command_popup_state::CommandPopupState,
+    completion_state::CompletionState,
+    date_prompt_state::DatePromptState,
+    delete_confirm_state::DeleteConfirmState,
+    emoji_picker_state::EmojiPickerState,
+    go_to_prompt_state::GoToPromptState,
+    link_hints_state::LinkHintsState,
+    link_target::ChatInvite,
+    message_info_state::MessageInfoPopupState,
+    new_chat_wizard_state::NewChatWizardState,
+    quick_switcher_state::QuickSwitcherState,
+    reaction_picker_state::ReactionPickerState,
+};
+
+use super::{PendingForward, ShellState};
+
+/// Popups, prompts and other transient state layered over the panes.
+impl ShellState {
+    pub fn help_visible(&self) -> bool {
+        self.help_visible
+    }
+
+    pub fn show_help(&mut self) {
+        self.help_visible = true;
+    }
+
+    pub fn hide_help(&mut self) {
+        self.help_visible = false;
+    }
+
+    pub fn command_popup(&self) -> Option<&CommandPopupState> {
+        self.command_popup.as_ref()
+    }
+
+    pub fn command_popup_mut(&mut self) -> Option<&mut CommandPopupState> {
+        self.command_popup.as_mut()
+    }
+
+    pub fn open_command_popup(
+        &mut self,
+        title: impl Into<String>,
+        kind: crate::domain::command_popup_state::CommandPopupKind,
+    ) {
+        self.command_popup = Some(CommandPopupState::new(title, kind));
+    }
+
+    pub fn close_command_popup(&mut self) {
+        self.command_popup = None;
+    }
+
+    pub fn chat_info_popup(&self) -> Option<&ChatInfoPopupState> {
+        self.chat_info_popup.as_ref()
+    }
+
+    pub fn show_chat_info_loading(&mut self, chat_id: i64, title: impl Into<String>) {
+        self.chat_info_popup = Some(ChatInfoPopupState::Loading {
+            chat_id,
+            title: title.into(),
+        });
+    }
+
+    pub fn set_chat_info_loaded(&mut self, state: ChatInfoPopupState) {
+        self.chat_info_popup = Some(state);
+    }
+
+    pub fn close_chat_info_popup(&mut self) {
+        self.chat_info_popup = None;
+    }
+
+    pub fn message_info_popup(&self) -> Option<&MessageInfoPopupState> {
+        self.message_info_popup.as_ref()
+    }
+
+    pub fn show_message_info_loading(&mut self, chat_id: i64, message_id: i64) {
+        self.message_info_popup = Some(MessageInfoPopupState::Loading {
+            chat_id,
+            message_id,
+        });
+    }
+
+    pub fn set_message_info_loaded(&mut self, state: MessageInfoPopupState) {
+        self.message_info_popup = Some(state);
+    }
+
+    pub fn close_message_info_popup(&mut self) {
+        self.message_info_popup = None;
+    }
+
+    pub fn reaction_picker(&self) -> Option<&ReactionPickerState> {
+        self.reaction_picker.as_ref()
+    }
+
+    pub fn reaction_picker_mut(&mut self) -> Option<&mut ReactionPickerState> {
+        self.reaction_picker.as_mut()
+    }
+
+    pub fn show_reaction_picker_loading(&mut self, chat_id: i64, message_id: i64) {
+        self.reaction_picker = Some(ReactionPickerState::Loading {
+            chat_id,
+            message_id,
+        });
+    }
+
+    pub fn set_reaction_picker(&mut self, state: ReactionPickerState) {
+        self.reaction_picker = Some(state);
+    }
+
+    pub fn close_reaction_picker(&mut self) {
+        self.reaction_picker = None;
+    }
+
+    pub fn chat_search(&self) -> Option<&ChatSearchState> {
+        self.chat_search.as_ref()
+    }
+
+    pub fn chat_search_mut(&mut self) -> Option<&mut ChatSearchState> {
+        self.chat_search.as_mut()
+    }
+
+    pub fn open_chat_search(&mut self) {
+        self.chat_search = Some(ChatSearchState::new(self.chat_list.loaded_chats().to_vec()));
+    }
+
+    pub fn close_chat_search(&mut self) {
+        self.chat_search = None;
+    }
+
+    pub fn quick_switcher(&self) -> Option<&QuickSwitcherState> {
+        self.quick_switcher.as_ref()
+    }
+
+    pub fn quick_switcher_mut(&mut self) -> Option<&mut QuickSwitcherState> {
+        self.quick_switcher.as_mut()
+    }
+
+    pub fn open_quick_switcher(&mut self, switcher: QuickSwitcherState) {
+        self.quick_switcher = Some(switcher);
+    }
+
+    pub fn close_quick_switcher(&mut self) {
+        self.quick_switcher = None;
+    }
+
+    pub fn date_prompt(&self) -> Option<&DatePromptState> {
+        self.date_prompt.as_ref()
+    }
+
+    pub fn date_prompt_mut(&mut self) -> Option<&mut DatePromptState> {
+        self.date_prompt.as_mut()
+    }
+
+    pub fn open_date_prompt(&mut self) {
+        self.date_prompt = Some(DatePromptState::default());
+    }
+
+    pub fn close_date_prompt(&mut self) {
+        self.date_prompt = None;
+    }
+
+    pub fn go_to_prompt(&self) -> Option<&GoToPromptState> {
+        self.go_to_prompt.as_ref()
+    }
+
+    pub fn go_to_prompt_mut(&mut self) -> Option<&mut GoToPromptState> {
+        self.go_to_prompt.as_mut()
+    }
+
+    pub fn open_go_to_prompt(&mut self) {
+        self.go_to_prompt = Some(GoToPromptState::default());
+    }
+
+    pub fn close_go_to_prompt(&mut self) {
+        self.go_to_prompt = None;
+    }
+
+    pub fn inline_button_selection(&self) -> Option<&InlineButtonSelection> {
+        self.inline_button_selection.as_ref()
+    }
+
+    pub fn inline_button_selection_mut(&mut self) -> Option<&mut InlineButtonSelection> {
+        self.inline_button_selection.as_mut()
+    }
+
+    pub fn start_inline_button_selection(&mut self, selection: InlineButtonSelection) {
+        self.inline_button_selection = Some(selection);
+    }
+
+    pub fn stop_inline_button_selection(&mut self) {
+        self.inline_button_selection = None;
+    }
+
+    pub fn alert(&self) -> Option<&str> {
+        self.alert.as_deref()
+    }
+
+    pub fn show_alert(&mut self, text: impl Into<String>) {
+        self.alert = Some(text.into());
+    }
+
+    pub fn close_alert(&mut self) {
+        self.alert = None;
+    }
+
+    pub fn reply_keyboard_selection(&self) -> Option<&ReplyKeyboardSelection> {
+        self.reply_keyboard_selection.as_ref()
+    }
+
+    pub fn reply_keyboard_selection_mut(&mut self) -> Option<&mut ReplyKeyboardSelection> {
+        self.reply_keyboard_selection.as_mut()
+    }
+
+    pub fn start_reply_keyboard_selection(&mut self, selection: ReplyKeyboardSelection) {
+        self.reply_keyboard_selection = Some(selection);
+    }
+
+    pub fn stop_reply_keyboard_selection(&mut self) {
+        self.reply_keyboard_selection = None;
+    }
+
+    pub fn completion(&self) -> Option<&CompletionState> {
+        self.completion.as_ref()
+    }
+
+    pub fn completion_mut(&mut self) -> Option<&mut CompletionState> {
+        self.completion.as_mut()
+    }
+
+    pub fn set_completion(&mut self, completion: Option<CompletionState>) {
+        self.completion = completion;
+    }
+
+    pub fn bot_commands(&self) -> &BotCommandCache {
+        &self.bot_commands
+    }
+
+    pub fn bot_commands_mut(&mut self) -> &mut BotCommandCache {
+        &mut self.bot_commands
+    }
+
+    pub fn member_search(&self) -> &MemberSearch {
+        &self.member_search
+    }
+
+    pub fn member_search_mut(&mut self) -> &mut MemberSearch {
+        &mut self.member_search
+    }
+
+    pub fn emoji_picker(&self) -> Option<&EmojiPickerState> {
+        self.emoji_picker.as_ref()
+    }
+
+    pub fn emoji_picker_mut(&mut self) -> Option<&mut EmojiPickerState> {
+        self.emoji_picker.as_mut()
+    }
+
+    pub fn open_emoji_picker(&mut self) {
+        self.emoji_picker = Some(EmojiPickerState::default());
+    }
+
+    pub fn close_emoji_picker(&mut self) {
+        self.emoji_picker = None;
+    }
+
+    pub fn pending_forward(&self) -> Option<&PendingForward> {
+        self.pending_forward.as_ref()
+    }
+
+    pub fn set_pending_forward(&mut self, forward: PendingForward) {
+        self.pending_forward = Some(forward);
+    }
+
+    pub fn take_pending_forward(&mut self) -> Option<PendingForward> {
+        self.pending_forward.take()
+    }
+
+    pub fn delete_confirm(&self) -> Option<&DeleteConfirmState> {
+        self.delete_confirm.as_ref()
+    }
+
+    pub fn delete_confirm_mut(&mut self) -> Option<&mut DeleteConfirmState> {
+        self.delete_confirm.as_mut()
+    }
+
+    pub fn open_delete_confirm(&mut self, state: DeleteConfirmState) {
+        self.delete_confirm = Some(state);
+    }
+
+    pub fn close_delete_confirm(&mut self) -> Option<DeleteConfirmState> {
+        self.delete_confirm.take()
+    }
+
+    pub fn chat_confirm(&self) -> Option<&ChatConfirmState> {
+        self.chat_confirm.as_ref()
+    }
+
+    pub fn open_chat_confirm(&mut self, state: ChatConfirmState) {
+        self.chat_confirm = Some(state);
+    }
+
+    pub fn close_chat_confirm(&mut self) -> Option<ChatConfirmState> {
+        self.chat_confirm.take()
+    }
+
+    pub fn join_prompt(&self) -> Option<&ChatInvite> {
+        self.join_prompt.as_ref()
+    }
+
+    pub fn open_join_prompt(&mut self, invite: ChatInvite) {
+        self.join_prompt = Some(invite);
+    }
+
+    pub fn close_join_prompt(&mut self) -> Option<ChatInvite> {
+        self.join_prompt.take()
+    }
+
+    pub fn link_hints(&self) -> Option<&LinkHintsState> {
+        self.link_hints.as_ref()
+    }
+
+    pub fn link_hints_mut(&mut self) -> Option<&mut LinkHintsState> {
+        self.link_hints.as_mut()
+    }
+
+    pub fn open_link_hints(&mut self, state: LinkHintsState) {
+        self.link_hints = Some(state);
+    }
+
+    pub fn close_link_hints(&mut self) {
+        self.link_hints = None;
+    }
+
+    pub fn new_chat_wizard(&self) -> Option<&NewChatWizardState> {
+        self.new_chat_wizard.as_ref()
+    }
+
+    pub fn new_chat_wizard_mut(&mut self) -> Option<&mut NewChatWizardState> {
+        self.new_chat_wizard.as_mut()
+    }
+
+    pub fn open_new_chat_wizard(&mut self) {
+        self.new_chat_wizard = Some(NewChatWizardState::default());
+    }
+
+    pub fn close_new_chat_wizard(&mut self) {
+        self.new_chat_wizard = None;
+    }
+}
@@ -0,0 +1,456 @@
+use super::*;
+use crate::domain::chat::{ChatSummary, ChatType, OutgoingReadStatus};
+
+fn chat(id: i64, title: &str) -> ChatSummary {
+    ChatSummary {
+        chat_id: id,
+        title: title.to_owned(),
+        unread_count: 0,
+        last_message_preview: None,
+        last_message_unix_ms: None,
+        is_pinned: false,
+        chat_type: ChatType::Private,
+        last_message_sender: None,
+        is_online: None,
+        is_bot: false,
+        outgoing_status: OutgoingReadStatus::default(),
+        last_message_id: None,
+        unread_reaction_count: 0,
+        unread_mention_count: 0,
+        is_forum: false,
+        unread_topic_count: None,
+        is_muted: false,
+        is_marked_as_unread: false,
+        is_blocked: false,
+        username: None,
+    }
+}
+
+#[test]
+fn default_state_is_running() {
+    let state = ShellState::default();
+    assert!(state.is_running());
+}
+
+#[test]
+fn default_connectivity_is_connecting() {
+    let state = ShellState::default();
+    assert_eq!(state.connectivity_status(), ConnectivityStatus::Connecting);
+}
+
+#[test]
+fn default_active_pane_is_chat_list() {
+    let state = ShellState::default();
+    assert_eq!(state.active_pane(), ActivePane::ChatList);
+}
+
+#[test]
+fn stop_sets_running_to_false() {
+    let mut state = ShellState::default();
+    state.stop();
+    assert!(!state.is_running());
+}
+
+#[test]
+fn set_connectivity_status_updates_value() {
+    let mut state = ShellState::default();
+    state.set_connectivity_status(ConnectivityStatus::Connected);
+    assert_eq!(state.connectivity_status(), ConnectivityStatus::Connected);
+}
+
+#[test]
+fn set_active_pane_switches_pane() {
+    let mut state = ShellState::default();
+    state.set_active_pane(ActivePane::Messages);
+    assert_eq!(state.active_pane(), ActivePane::Messages);
+
+    state.set_active_pane(ActivePane::MessageInput);
+    assert_eq!(state.active_pane(), ActivePane::MessageInput);
+}
+
+#[test]
+fn with_initial_chat_list_empty_falls_back_to_default() {
+    let state = ShellState::with_initial_chat_list(vec![]);
+    assert!(state.is_running());
+    assert_eq!(state.connectivity_status(), ConnectivityStatus::Connecting);
+    assert_eq!(state.active_pane(), ActivePane::ChatList);
+}
+
+#[test]
+fn with_initial_chat_list_non_empty_populates_chats() {
+    let chats = vec![chat(1, "Alice"), chat(2, "Bob")];
+    let state = ShellState::with_initial_chat_list(chats);
+    assert!(state.is_running());
+    assert_eq!(state.chat_list().chats().len(), 2);
+}
+
+#[test]
+fn message_input_mut_allows_mutation() {
+    let mut state = ShellState::default();
+    state.message_input_mut().insert_char('a');
+    assert_eq!(state.message_input().text(), "a");
+}
+
+#[test]
+fn help_not_visible_by_default() {
+    let state = ShellState::default();
+    assert!(!state.help_visible());
+}
+
+#[test]
+fn show_help_makes_it_visible() {
+    let mut state = ShellState::default();
+    state.show_help();
+    assert!(state.help_visible());
+}
+
+#[test]
+fn hide_help_makes_it_invisible() {
+    let mut state = ShellState::default();
+    state.show_help();
+    state.hide_help();
+    assert!(!state.help_visible());
+}
+
+#[test]
+fn chat_info_popup_none_by_default() {
+    let state = ShellState::default();
+    assert!(state.chat_info_popup().is_none());
+}
+
+#[test]
+fn show_chat_info_loading_creates_popup() {
+    let mut state = ShellState::default();
+    state.show_chat_info_loading(1, "Alice");
+    assert!(state.chat_info_popup().is_some());
+    assert_eq!(state.chat_info_popup().unwrap().title(), "Alice");
+}
+
+#[test]
+fn close_chat_info_popup_clears_state() {
+    let mut state = ShellState::default();
+    state.show_chat_info_loading(1, "Alice");
+    state.close_chat_info_popup();
+    assert!(state.chat_info_popup().is_none());
+}
+
+#[test]
+fn set_chat_info_loaded_updates_popup() {
+    use crate::domain::chat_info_state::{ChatInfo, ChatInfoPopupState};
+    let mut state = ShellState::default();
+    state.show_chat_info_loading(1, "Alice");
+    state.set_chat_info_loaded(ChatInfoPopupState::Loaded(ChatInfo {
+        title: "Alice".into(),
+        chat_type: ChatType::Private,
+        status_line: "online".into(),
+        username: None,
+        description: Some("Hello world".into()),
+    }));
+    match state.chat_info_popup().unwrap() {
+        ChatInfoPopupState::Loaded(info) => {
+            assert_eq!(info.status_line, "online");
+            assert_eq!(info.description.as_deref(), Some("Hello world"));
+        }
+        _ => panic!("expected Loaded state"),
+    }
+}
+
+#[test]
+fn command_popup_none_by_default() {
+    let state = ShellState::default();
+    assert!(state.command_popup().is_none());
+}
+
+#[test]
+fn open_command_popup_creates_state() {
+    use crate::domain::command_popup_state::CommandPopupKind;
+    let mut state = ShellState::default();
+    state.open_command_popup("Recording", CommandPopupKind::Recording);
+    assert!(state.command_popup().is_some());
+    assert_eq!(state.command_popup().unwrap().title(), "Recording");
+}
+
+#[test]
+fn close_command_popup_clears_state() {
+    use crate::domain::command_popup_state::CommandPopupKind;
+    let mut state = ShellState::default();
+    state.open_command_popup("Recording", CommandPopupKind::Recording);
+    state.close_command_popup();
+    assert!(state.command_popup().is_none());
+}
+
+#[test]
+fn command_popup_mut_allows_mutation() {
+    use crate::domain::command_popup_state::CommandPopupKind;
+    let mut state = ShellState::default();
+    state.open_command_popup("Test", CommandPopupKind::Recording);
+    state
+        .command_popup_mut()
+        .unwrap()
+        .push_line("output".into());
+    assert_eq!(
+        state.command_popup().unwrap().visible_lines(20),
+        vec!["output"]
+    );
+}
+
+#[test]
+fn notification_none_by_default() {
+    let state = ShellState::default();
+    assert!(state.active_notification().is_none());
+}
+
+#[test]
+fn set_notification_makes_it_active() {
+    let mut state = ShellState::default();
+    state.set_notification("Copied to clipboard");
+    assert_eq!(state.active_notification(), Some("Copied to clipboard"));
+}
+
+#[test]
+fn notification_expires_after_ttl() {
+    let mut state = ShellState::default();
+    let expired = Instant::now() - Duration::from_secs(5);
+    state.set_notification_at("Old message", expired);
+    assert!(state.active_notification().is_none());
+}
+
+#[test]
+fn notification_with_custom_ttl_outlives_default() {
+    let mut state = ShellState::default();
+    state.set_notification_for("Deleted", Duration::from_secs(60));
+    let (_, created_at, ttl) = state.notification.as_mut().unwrap();
+    *created_at = Instant::now() - NOTIFICATION_TTL;
+    assert_eq!(*ttl, Duration::from_secs(60));
+    assert_eq!(state.active_notification(), Some("Deleted"));
+}
+
+#[test]
+fn fresh_notification_replaces_previous() {
+    let mut state = ShellState::default();
+    state.set_notification("First");
+    state.set_notification("Second");
+    assert_eq!(state.active_notification(), Some("Second"));
+}
+
+#[test]
+fn message_info_popup_none_by_default() {
+    let state = ShellState::default();
+    assert!(state.message_info_popup().is_none());
+}
+
+#[test]
+fn show_message_info_loading_creates_popup() {
+    let mut state = ShellState::default();
+    state.show_message_info_loading(1, 42);
+    assert!(state.message_info_popup().is_some());
+    assert_eq!(state.message_info_popup().unwrap().ids(), Some((1, 42)));
+}
+
+#[test]
+fn close_message_info_popup_clears_state() {
+    let mut state = ShellState::default();
+    state.show_message_info_loading(1, 42);
+    state.close_message_info_popup();
+    assert!(state.message_info_popup().is_none());
+}
+
+#[test]
+fn set_message_info_loaded_updates_popup() {
+    use crate::domain::message_info_state::{MessageInfo, MessageInfoPopupState};
+    let mut state = ShellState::default();
+    state.show_message_info_loading(1, 42);
+    state.set_message_info_loaded(MessageInfoPopupState::Loaded(MessageInfo {
+        reactions: vec![],
+        reaction_counts: vec![],
+        viewers: vec![],
+        read_date: None,
+        edit_date: Some(1700000000),
+    }));
+    match state.message_info_popup().unwrap() {
+        MessageInfoPopupState::Loaded(info) => {
+            assert_eq!(info.edit_date, Some(1700000000));
+        }
+        _ => panic!("expected Loaded state"),
+    }
+}
+
+#[test]
+fn reaction_picker_none_by_default() {
+    let state = ShellState::default();
+    assert!(state.reaction_picker().is_none());
+}
+
+#[test]
+fn show_reaction_picker_loading_creates_picker() {
+    let mut state = ShellState::default();
+    state.show_reaction_picker_loading(1, 42);
+    assert!(state.reaction_picker().is_some());
+    assert_eq!(state.reaction_picker().unwrap().ids(), Some((1, 42)));
+}
+
+#[test]
+fn close_reaction_picker_clears_state() {
+    let mut state = ShellState::default();
+    state.show_reaction_picker_loading(1, 42);
+    state.close_reaction_picker();
+    assert!(state.reaction_picker().is_none());
+}
+
+#[test]
+fn set_reaction_picker_updates_state() {
+    use crate::domain::reaction_picker_state::{
+        AvailableReaction, ReactionPickerData, ReactionPickerState,
+    };
+    let mut state = ShellState::default();
+    state.show_reaction_picker_loading(1, 42);
+    state.set_reaction_picker(ReactionPickerState::Ready(ReactionPickerData::new(
+        vec![AvailableReaction {
+            emoji: "👍".into(),
+            needs_premium: false,
+            is_chosen: false,
+            is_recent: false,
+        }],
+        1,
+        42,
+    )));
+    match state.reaction_picker().unwrap() {
+        ReactionPickerState::Ready(data) => {
+            assert_eq!(data.items.len(), 1);
+        }
+        _ => panic!("expected Ready state"),
+    }
+}
+
+#[test]
+fn reaction_picker_mut_allows_mutation() {
+    use crate::domain::reaction_picker_state::{
+        AvailableReaction, ReactionPickerData, ReactionPickerState,
+    };
+    let mut state = ShellState::default();
+    state.set_reaction_picker(ReactionPickerState::Ready(ReactionPickerData::new(
+        vec![
+            AvailableReaction {
+                emoji: "👍".into(),
+                needs_premium: false,
+                is_chosen: false,
+                is_recent: false,
+            },
+            AvailableReaction {
+                emoji: "❤".into(),
+                needs_premium: false,
+                is_chosen: false,
+                is_recent: false,
+            },
+        ],
+        1,
+        42,
+    )));
+    if let Some(data) = state.reaction_picker_mut().and_then(|p| p.data_mut()) {
+        data.select_next();
+    }
+    match state.reaction_picker().unwrap() {
+        ReactionPickerState::Ready(data) => {
+            assert_eq!(data.selected_index, 1);
+        }
+        _ => panic!("expected Ready state"),
+    }
+}
+
+#[test]
+fn forum_topic_list_none_by_default() {
+    let state = ShellState::default();
+    assert!(state.forum_topic_list().is_none());
+}
+
+#[test]
+fn enter_forum_installs_loading_topic_list() {
+    let mut state = ShellState::default();
+    state.enter_forum(100, "Topics");
+
+    let list = state.forum_topic_list().expect("topic list should exist");
+    assert_eq!(list.parent_chat_id(), 100);
+    assert_eq!(list.parent_chat_title(), "Topics");
+    assert_eq!(
+        list.ui_state(),
+        crate::domain::forum_topic_list_state::ForumTopicListUiState::Loading
+    );
+}
+
+#[test]
+fn enter_forum_preserves_root_chat_list_state() {
+    let chats = vec![chat(1, "Alice"), chat(2, "Bob")];
+    let mut state = ShellState::with_initial_chat_list(chats.clone());
+    state.chat_list_mut().select_next();
+    let prior_selection = state.chat_list().selected_chat().map(|c| c.chat_id);
+
+    state.enter_forum(100, "Forum");
+
+    assert_eq!(state.chat_list().chats(), chats.as_slice());
+    assert_eq!(
+        state.chat_list().selected_chat().map(|c| c.chat_id),
+        prior_selection
+    );
+}
+
+#[test]
+fn leave_forum_clears_topic_list_without_touching_root_chat_list() {
+    let chats = vec![chat(1, "Alice"), chat(2, "Bob")];
+    let mut state = ShellState::with_initial_chat_list(chats.clone());
+    state.chat_list_mut().select_next();
+    let prior_selection = state.chat_list().selected_chat().map(|c| c.chat_id);
+    state.enter_forum(100, "Forum");
+
+    state.leave_forum();
+
+    assert!(state.forum_topic_list().is_none());
+    assert_eq!(state.chat_list().chats(), chats.as_slice());
+    assert_eq!(
+        state.chat_list().selected_chat().map(|c| c.chat_id),
+        prior_selection
+    );
+}
+
+#[test]
+fn open_topic_is_closed_returns_false_when_no_topic_open() {
+    let state = ShellState::default();
+    assert!(!state.open_topic_is_closed());
+}
+
+#[test]
+fn open_topic_is_closed_reflects_topic_summary_flag() {
+    use crate::domain::forum_topic::ForumTopicSummary;
+    let mut closed = ForumTopicSummary {
+        chat_id: 100,
+        topic_id: 7,
+        name: "Backend".to_owned(),
+        is_general: false,
+        is_closed: true,
+        is_hidden: false,
+        is_pinned: false,
+        unread_count: 0,
+        last_message_preview: None,
+        last_message_unix_ms: None,
+        last_message_id: None,
+        order: 0,
+    };
+
+    let mut state = ShellState::default();
+    state.enter_forum(100, "Forum");
+    state
+        .forum_topic_list_mut()
+        .unwrap()
+        .set_ready(vec![closed.clone()]);
+    state
+        .open_chat_mut()
+        .set_loading_with_topic(100, Some(7), "x".to_owned(), ChatType::Group);
+
+    assert!(state.open_topic_is_closed());
+
+    closed.is_closed = false;
+    state
+        .forum_topic_list_mut()
+        .unwrap()
+        .set_ready(vec![closed]);
+    assert!(!state.open_topic_is_closed());
+}
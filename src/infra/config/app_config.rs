@@ -13,6 +13,8 @@ pub struct AppConfig {
     pub open: OpenConfig,
     pub download: DownloadConfig,
     pub keys: KeysConfig,
+    pub privacy: PrivacyConfig,
+    pub messages: MessagesConfig,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -180,6 +182,62 @@ pub fn parse_size(s: &str) -> Option<u64> {
     Some((num * multiplier as f64) as u64)
 }
 
+/// Privacy-related settings.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PrivacyConfig {
+    /// Broadcast "typing..." / "recording voice..." to chat members.
+    #[serde(default = "default_send_chat_actions")]
+    pub send_chat_actions: bool,
+}
+
+fn default_send_chat_actions() -> bool {
+    true
+}
+
+impl Default for PrivacyConfig {
+    fn default() -> Self {
+        Self {
+            send_chat_actions: default_send_chat_actions(),
+        }
+    }
+}
+
+/// Message action settings.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct MessagesConfig {
+    /// Ask before deleting messages and let the user choose between
+    /// "delete for me" and "delete for everyone".
+    #[serde(default = "default_confirm_delete")]
+    pub confirm_delete: bool,
+    /// Seconds a deletion (or leaving a chat) can be undone before it is
+    /// sent; 0 sends it immediately.
+    #[serde(default = "default_undo_seconds")]
+    pub undo_seconds: u64,
+}
+
+fn default_confirm_delete() -> bool {
+    true
+}
+
+fn default_undo_seconds() -> u64 {
+    5
+}
+
+impl MessagesConfig {
+    pub fn undo_grace(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.undo_seconds)
+    }
+}
+
+impl Default for MessagesConfig {
+    fn default() -> Self {
+        Self {
+            confirm_delete: default_confirm_delete(),
+            undo_seconds: default_undo_seconds(),
+        }
+    }
+}
+
 /// Configuration for opening message files (mailcap-style MIME → command mappings).
 ///
 /// Keys are MIME types or wildcard patterns (e.g. `"audio/ogg"`, `"audio/*"`).
@@ -1,8 +1,8 @@
 use serde::Deserialize;
 
 use crate::infra::config::{
-    AppConfig, CacheConfig, DownloadConfig, KeysConfig, LogConfig, OpenConfig, TelegramConfig,
-    VoiceConfig,
+    AppConfig, CacheConfig, DownloadConfig, KeysConfig, LogConfig, MessagesConfig, OpenConfig,
+    PrivacyConfig, TelegramConfig, VoiceConfig,
 };
 
 #[derive(Debug, Deserialize, Default)]
@@ -14,6 +14,8 @@ pub struct FileConfig {
     pub open: Option<FileOpenConfig>,
     pub download: Option<FileDownloadConfig>,
     pub keys: Option<FileKeysConfig>,
+    pub privacy: Option<FilePrivacyConfig>,
+    pub messages: Option<FileMessagesConfig>,
 }
 
 impl FileConfig {
@@ -45,6 +47,14 @@ impl FileConfig {
         if let Some(keys) = self.keys {
             keys.merge_into(&mut config.keys);
         }
+
+        if let Some(privacy) = self.privacy {
+            privacy.merge_into(&mut config.privacy);
+        }
+
+        if let Some(messages) = self.messages {
+            messages.merge_into(&mut config.messages);
+        }
     }
 }
 
@@ -159,6 +169,36 @@ impl FileKeysConfig {
     }
 }
 
+#[derive(Debug, Deserialize, Default)]
+pub struct FilePrivacyConfig {
+    pub send_chat_actions: Option<bool>,
+}
+
+impl FilePrivacyConfig {
+    fn merge_into(self, config: &mut PrivacyConfig) {
+        if let Some(send_chat_actions) = self.send_chat_actions {
+            config.send_chat_actions = send_chat_actions;
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Default)]
+pub struct FileMessagesConfig {
+    pub confirm_delete: Option<bool>,
+    pub undo_seconds: Option<u64>,
+}
+
+impl FileMessagesConfig {
+    fn merge_into(self, config: &mut MessagesConfig) {
+        if let Some(confirm_delete) = self.confirm_delete {
+            config.confirm_delete = confirm_delete;
+        }
+        if let Some(undo_seconds) = self.undo_seconds {
+            config.undo_seconds = undo_seconds;
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -237,6 +277,8 @@ level = "warn"
             open: None,
             download: None,
             keys: None,
+            privacy: None,
+            messages: None,
         };
 
         let mut config = AppConfig::default();
@@ -278,6 +320,8 @@ level = "warn"
             open: None,
             download: None,
             keys: None,
+            privacy: None,
+            messages: None,
         };
 
         let mut config = AppConfig::default();
@@ -303,6 +347,8 @@ level = "warn"
             open: None,
             download: None,
             keys: None,
+            privacy: None,
+            messages: None,
         };
 
         let mut config = AppConfig::default();
@@ -373,6 +419,8 @@ record_cmd = "sox -d {file_path}"
             open: None,
             download: None,
             keys: None,
+            privacy: None,
+            messages: None,
         };
         let mut config = AppConfig::default();
         file.merge_into(&mut config);
@@ -412,6 +460,8 @@ max_auto_download_size = "500KB"
             open: None,
             download: None,
             keys: None,
+            privacy: None,
+            messages: None,
         };
         let mut config = AppConfig::default();
         file.merge_into(&mut config);
@@ -455,9 +505,54 @@ quit = "Q"
             open: None,
             download: None,
             keys: None,
+            privacy: None,
+            messages: None,
         };
         let mut config = AppConfig::default();
         file.merge_into(&mut config);
         assert!(config.keys.overrides.is_empty());
     }
+
+    #[test]
+    fn privacy_config_merges_into_app_config() {
+        let toml = r#"
+[privacy]
+send_chat_actions = false
+"#;
+        let file: FileConfig = toml::from_str(toml).unwrap();
+        let mut config = AppConfig::default();
+        file.merge_into(&mut config);
+        assert!(!config.privacy.send_chat_actions);
+    }
+
+    #[test]
+    fn privacy_config_none_preserves_default() {
+        let file: FileConfig = toml::from_str("").unwrap();
+        let mut config = AppConfig::default();
+        file.merge_into(&mut config);
+        assert!(config.privacy.send_chat_actions);
+    }
+
+    #[test]
+    fn messages_config_merges_into_app_config() {
+        let toml = r#"
+[messages]
+confirm_delete = false
+undo_seconds = 0
+"#;
+        let file: FileConfig = toml::from_str(toml).unwrap();
+        let mut config = AppConfig::default();
+        file.merge_into(&mut config);
+        assert!(!config.messages.confirm_delete);
+        assert!(config.messages.undo_grace().is_zero());
+    }
+
+    #[test]
+    fn messages_config_none_preserves_default() {
+        let file: FileConfig = toml::from_str("").unwrap();
+        let mut config = AppConfig::default();
+        file.merge_into(&mut config);
+        assert!(config.messages.confirm_delete);
+        assert_eq!(config.messages.undo_seconds, 5);
+    }
 }
@@ -6,6 +6,6 @@ mod writer;
 
 pub use adapter::FileConfigAdapter;
 pub use app_config::{
-    AppConfig, CacheConfig, DownloadConfig, KeysConfig, LogConfig, OpenConfig, TelegramConfig,
-    VoiceConfig,
+    AppConfig, CacheConfig, DownloadConfig, KeysConfig, LogConfig, MessagesConfig, OpenConfig,
+    PrivacyConfig, TelegramConfig, VoiceConfig,
 };
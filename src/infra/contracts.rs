@@ -1,6 +1,6 @@
 use anyhow::Result;
 
-use crate::infra::config::AppConfig;
+use crate::{domain::recent_chats::RecentChat, infra::config::AppConfig};
 
 pub trait ConfigAdapter {
     fn load(&self) -> Result<AppConfig>;
@@ -9,6 +9,9 @@ pub trait ConfigAdapter {
 
 pub trait StorageAdapter {
     fn save_last_action(&mut self, action: &str) -> Result<()>;
+    /// Most recently opened chats, newest first; empty when none were saved.
+    fn load_recent_chats(&self) -> Result<Vec<RecentChat>>;
+    fn save_recent_chats(&mut self, chats: &[RecentChat]) -> Result<()>;
 }
 
 pub trait ExternalOpener {
@@ -54,6 +54,30 @@ pub enum AppError {
         #[source]
         source: io::Error,
     },
+    #[error("failed to read state file at {path}: {source}")]
+    StateRead {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+    #[error("failed to parse state file at {path}: {source}")]
+    StateParse {
+        path: PathBuf,
+        #[source]
+        source: toml::de::Error,
+    },
+    #[error("failed to serialize state file at {path}: {source}")]
+    StateSerialize {
+        path: PathBuf,
+        #[source]
+        source: toml::ser::Error,
+    },
+    #[error("failed to write state file at {path}: {source}")]
+    StateWrite {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
     #[error(transparent)]
     Other(#[from] anyhow::Error),
 }
@@ -0,0 +1,217 @@
+//! File-backed [`StorageAdapter`]: keeps the recent chats in a TOML file
+//! under the config directory.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    domain::{
+        chat::ChatType,
+        recent_chats::{RecentChat, RecentTopic},
+    },
+    infra::{contracts::StorageAdapter, error::AppError},
+};
+
+/// Without a file path nothing is persisted: the recent chats start empty
+/// and saves are dropped.
+#[derive(Debug, Clone, Default)]
+pub struct FileStorageAdapter {
+    recent_chats_file: Option<PathBuf>,
+    last_action: Option<String>,
+}
+
+impl FileStorageAdapter {
+    pub fn new(recent_chats_file: Option<PathBuf>) -> Self {
+        Self {
+            recent_chats_file,
+            last_action: None,
+        }
+    }
+}
+
+impl StorageAdapter for FileStorageAdapter {
+    fn save_last_action(&mut self, action: &str) -> Result<()> {
+        self.last_action = Some(action.to_owned());
+        Ok(())
+    }
+
+    fn load_recent_chats(&self) -> Result<Vec<RecentChat>> {
+        match &self.recent_chats_file {
+            Some(path) => Ok(read_recent_chats(path)?),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    fn save_recent_chats(&mut self, chats: &[RecentChat]) -> Result<()> {
+        match &self.recent_chats_file {
+            Some(path) => Ok(write_recent_chats(path, chats)?),
+            None => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct RecentChatsFile {
+    #[serde(default)]
+    chats: Vec<FileRecentChat>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct FileRecentChat {
+    chat_id: i64,
+    title: String,
+    #[serde(default)]
+    chat_type: FileChatType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    topic_id: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    topic_name: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum FileChatType {
+    #[default]
+    Private,
+    Group,
+    Channel,
+}
+
+impl From<&RecentChat> for FileRecentChat {
+    fn from(chat: &RecentChat) -> Self {
+        Self {
+            chat_id: chat.chat_id,
+            title: chat.title.clone(),
+            chat_type: match chat.chat_type {
+                ChatType::Private => FileChatType::Private,
+                ChatType::Group => FileChatType::Group,
+                ChatType::Channel => FileChatType::Channel,
+            },
+            topic_id: chat.topic_id(),
+            topic_name: chat.topic.as_ref().map(|topic| topic.name.clone()),
+        }
+    }
+}
+
+impl From<FileRecentChat> for RecentChat {
+    fn from(chat: FileRecentChat) -> Self {
+        Self {
+            chat_id: chat.chat_id,
+            title: chat.title,
+            chat_type: match chat.chat_type {
+                FileChatType::Private => ChatType::Private,
+                FileChatType::Group => ChatType::Group,
+                FileChatType::Channel => ChatType::Channel,
+            },
+            topic: chat.topic_id.map(|topic_id| RecentTopic {
+                topic_id,
+                name: chat.topic_name.unwrap_or_default(),
+            }),
+        }
+    }
+}
+
+fn read_recent_chats(path: &Path) -> Result<Vec<RecentChat>, AppError> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let raw = fs::read_to_string(path).map_err(|source| AppError::StateRead {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    let file: RecentChatsFile = toml::from_str(&raw).map_err(|source| AppError::StateParse {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    Ok(file.chats.into_iter().map(RecentChat::from).collect())
+}
+
+/// Writes through a temporary file so a crash mid-write keeps the old list.
+fn write_recent_chats(path: &Path, chats: &[RecentChat]) -> Result<(), AppError> {
+    let file = RecentChatsFile {
+        chats: chats.iter().map(FileRecentChat::from).collect(),
+    };
+    let raw = toml::to_string(&file).map_err(|source| AppError::StateSerialize {
+        path: path.to_path_buf(),
+        source,
+    })?;
+
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent).map_err(|source| AppError::StorageDirCreate {
+            path: parent.to_path_buf(),
+            source,
+        })?;
+    }
+    let tmp = path.with_extension("toml.tmp");
+    fs::write(&tmp, raw).map_err(|source| AppError::StateWrite {
+        path: tmp.clone(),
+        source,
+    })?;
+    fs::rename(&tmp, path).map_err(|source| AppError::StateWrite {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn adapter(dir: &tempfile::TempDir) -> FileStorageAdapter {
+        FileStorageAdapter::new(Some(dir.path().join("rtg").join("recent_chats.toml")))
+    }
+
+    #[test]
+    fn missing_file_loads_no_recent_chats() {
+        let dir = tempfile::tempdir().expect("temp dir");
+
+        let chats = adapter(&dir).load_recent_chats().expect("must load");
+
+        assert!(chats.is_empty());
+    }
+
+    #[test]
+    fn recent_chats_round_trip_through_the_file() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let chats = vec![
+            RecentChat::topic(-100, "Rust Forum", 7, "Async"),
+            RecentChat::chat(42, "Alice", ChatType::Private),
+            RecentChat::chat(-200, "News", ChatType::Channel),
+        ];
+
+        adapter(&dir).save_recent_chats(&chats).expect("must save");
+        let loaded = adapter(&dir).load_recent_chats().expect("must load");
+
+        assert_eq!(loaded, chats);
+    }
+
+    #[test]
+    fn malformed_file_is_an_error() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let storage = adapter(&dir);
+        fs::create_dir_all(dir.path().join("rtg")).expect("create dir");
+        fs::write(
+            dir.path().join("rtg").join("recent_chats.toml"),
+            "chats = 5",
+        )
+        .expect("write file");
+
+        assert!(storage.load_recent_chats().is_err());
+    }
+
+    #[test]
+    fn without_a_file_nothing_is_persisted() {
+        let mut storage = FileStorageAdapter::default();
+
+        storage
+            .save_recent_chats(&[RecentChat::chat(1, "Alice", ChatType::Private)])
+            .expect("must save");
+
+        assert!(storage.load_recent_chats().expect("must load").is_empty());
+    }
+}
@@ -67,6 +67,12 @@ impl StorageLayout {
         self.config_dir.join("tdlib.log")
     }
 
+    /// Returns the path for the most recently opened chats, kept between
+    /// runs for the quick switcher.
+    pub fn recent_chats_file(&self) -> PathBuf {
+        self.config_dir.join("recent_chats.toml")
+    }
+
     /// Checks whether a TDLib session (database) exists on disk.
     ///
     /// Returns `true` if the TDLib database directory exists and contains
@@ -134,6 +140,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn recent_chats_file_is_under_config_dir() {
+        let layout = StorageLayout {
+            config_dir: PathBuf::from("/tmp/test"),
+            cache_dir: PathBuf::from("/tmp/test/cache"),
+        };
+        assert_eq!(
+            layout.recent_chats_file(),
+            PathBuf::from("/tmp/test/recent_chats.toml")
+        );
+    }
+
     #[test]
     fn tdlib_session_exists_returns_false_for_missing_dir() {
         let layout = StorageLayout {
@@ -1,9 +1,14 @@
+#[cfg(test)]
 use anyhow::Result;
 
-use crate::infra::contracts::StorageAdapter;
-
 #[cfg(test)]
-use crate::infra::{config::AppConfig, contracts::ConfigAdapter};
+use crate::{
+    domain::recent_chats::RecentChat,
+    infra::{
+        config::AppConfig,
+        contracts::{ConfigAdapter, StorageAdapter},
+    },
+};
 
 #[cfg(test)]
 #[derive(Debug, Clone, Default)]
@@ -20,16 +25,28 @@ impl ConfigAdapter for StubConfigAdapter {
     }
 }
 
+#[cfg(test)]
 #[derive(Debug, Clone, Default)]
 pub struct StubStorageAdapter {
     pub last_action: Option<String>,
+    pub recent_chats: Vec<RecentChat>,
 }
 
+#[cfg(test)]
 impl StorageAdapter for StubStorageAdapter {
     fn save_last_action(&mut self, action: &str) -> Result<()> {
         self.last_action = Some(action.to_owned());
         Ok(())
     }
+
+    fn load_recent_chats(&self) -> Result<Vec<RecentChat>> {
+        Ok(self.recent_chats.clone())
+    }
+
+    fn save_recent_chats(&mut self, chats: &[RecentChat]) -> Result<()> {
+        self.recent_chats = chats.to_vec();
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -110,23 +110,35 @@ fn map_update(update: TdLibUpdate, mapper: &dyn MessageMapper) -> Option<ChatUpd
             message_ids,
         }),
         TdLibUpdate::MessageContentChanged { chat_id, .. }
+        | TdLibUpdate::MessageEdited { chat_id, .. }
+        | TdLibUpdate::ChatReplyMarkup { chat_id }
         | TdLibUpdate::ChatLastMessage { chat_id }
         | TdLibUpdate::ChatPosition { chat_id }
         | TdLibUpdate::ChatReadInbox { chat_id }
-        | TdLibUpdate::ChatReadOutbox { chat_id }
+        | TdLibUpdate::ChatNotificationSettings { chat_id }
+        | TdLibUpdate::ChatIsMarkedAsUnread { chat_id }
+        | TdLibUpdate::ChatBlockList { chat_id }
         | TdLibUpdate::MessageSendSucceeded { chat_id, .. }
         | TdLibUpdate::ChatUnreadReactionCount { chat_id }
+        | TdLibUpdate::ChatUnreadMentionCount { chat_id }
         | TdLibUpdate::SupergroupMetadataChanged { chat_id } => {
             Some(ChatUpdate::ChatMetadataChanged { chat_id })
         }
+        TdLibUpdate::ChatReadOutbox {
+            chat_id,
+            last_read_outbox_message_id,
+        } => Some(ChatUpdate::OutboxRead {
+            chat_id,
+            last_read_outbox_message_id,
+        }),
         TdLibUpdate::MessageInteractionInfoChanged {
             chat_id,
             message_id,
-            reaction_count,
+            reactions,
         } => Some(ChatUpdate::MessageReactionsChanged {
             chat_id,
             message_id,
-            reaction_count,
+            reactions,
         }),
         TdLibUpdate::FileUpdated {
             file_id,
@@ -181,6 +193,23 @@ fn map_update(update: TdLibUpdate, mapper: &dyn MessageMapper) -> Option<ChatUpd
             topic_id,
             unread_topic_count,
         }),
+        TdLibUpdate::ScopeNotificationSettings => Some(ChatUpdate::NotificationDefaultsChanged),
+        TdLibUpdate::ChatFolders {
+            folders,
+            main_position,
+        } => Some(ChatUpdate::ChatFoldersChanged {
+            folders,
+            main_position,
+        }),
+        TdLibUpdate::UnreadChatCount {
+            list,
+            unread_count,
+            total_count,
+        } => Some(ChatUpdate::UnreadChatCountChanged {
+            list,
+            unread_count,
+            total_count,
+        }),
     }
 }
 
@@ -266,10 +295,12 @@ impl MessageMapper for StubMessageMapper {
             call_info: None,
             reply_to: None,
             forward_info: None,
-            reaction_count: 0,
+            reactions: Vec::new(),
             links: Vec::new(),
             is_edited: raw.edit_date > 0,
             is_service: false,
+            inline_keyboard: Vec::new(),
+            reply_keyboard: None,
         }
     }
 }
@@ -482,13 +513,147 @@ mod tests {
         );
     }
 
+    #[test]
+    fn map_chat_unread_mention_count_to_metadata_changed() {
+        let mapper = StubMessageMapper;
+        let update = TdLibUpdate::ChatUnreadMentionCount { chat_id: 42 };
+
+        let result = map_update(update, &mapper);
+
+        assert!(
+            matches!(
+                result,
+                Some(ChatUpdate::ChatMetadataChanged { chat_id: 42 })
+            ),
+            "expected ChatMetadataChanged, got: {result:?}"
+        );
+    }
+
+    #[test]
+    fn map_chat_is_marked_as_unread_to_metadata_changed() {
+        let mapper = StubMessageMapper;
+        let update = TdLibUpdate::ChatIsMarkedAsUnread { chat_id: 42 };
+
+        let result = map_update(update, &mapper);
+
+        assert!(
+            matches!(
+                result,
+                Some(ChatUpdate::ChatMetadataChanged { chat_id: 42 })
+            ),
+            "expected ChatMetadataChanged, got: {result:?}"
+        );
+    }
+
+    #[test]
+    fn map_chat_block_list_to_metadata_changed() {
+        let mapper = StubMessageMapper;
+        let update = TdLibUpdate::ChatBlockList { chat_id: 42 };
+
+        let result = map_update(update, &mapper);
+
+        assert!(
+            matches!(
+                result,
+                Some(ChatUpdate::ChatMetadataChanged { chat_id: 42 })
+            ),
+            "expected ChatMetadataChanged, got: {result:?}"
+        );
+    }
+
+    #[test]
+    fn map_chat_notification_settings_to_metadata_changed() {
+        let mapper = StubMessageMapper;
+        let update = TdLibUpdate::ChatNotificationSettings { chat_id: 42 };
+
+        let result = map_update(update, &mapper);
+
+        assert!(
+            matches!(
+                result,
+                Some(ChatUpdate::ChatMetadataChanged { chat_id: 42 })
+            ),
+            "expected ChatMetadataChanged, got: {result:?}"
+        );
+    }
+
+    #[test]
+    fn map_scope_notification_settings_to_notification_defaults_changed() {
+        let mapper = StubMessageMapper;
+
+        let result = map_update(TdLibUpdate::ScopeNotificationSettings, &mapper);
+
+        assert_eq!(result, Some(ChatUpdate::NotificationDefaultsChanged));
+    }
+
+    #[test]
+    fn map_chat_reply_markup_to_metadata_changed() {
+        let mapper = StubMessageMapper;
+        let update = TdLibUpdate::ChatReplyMarkup { chat_id: 42 };
+
+        let result = map_update(update, &mapper);
+
+        assert!(
+            matches!(
+                result,
+                Some(ChatUpdate::ChatMetadataChanged { chat_id: 42 })
+            ),
+            "expected ChatMetadataChanged, got: {result:?}"
+        );
+    }
+
+    #[test]
+    fn map_message_edited_to_metadata_changed() {
+        let mapper = StubMessageMapper;
+        let update = TdLibUpdate::MessageEdited {
+            chat_id: 42,
+            message_id: 7,
+        };
+
+        let result = map_update(update, &mapper);
+
+        assert!(
+            matches!(
+                result,
+                Some(ChatUpdate::ChatMetadataChanged { chat_id: 42 })
+            ),
+            "expected ChatMetadataChanged, got: {result:?}"
+        );
+    }
+
+    #[test]
+    fn map_chat_read_outbox_to_outbox_read() {
+        let mapper = StubMessageMapper;
+        let update = TdLibUpdate::ChatReadOutbox {
+            chat_id: 42,
+            last_read_outbox_message_id: 300,
+        };
+
+        let result = map_update(update, &mapper);
+
+        assert!(
+            matches!(
+                result,
+                Some(ChatUpdate::OutboxRead {
+                    chat_id: 42,
+                    last_read_outbox_message_id: 300,
+                })
+            ),
+            "expected OutboxRead, got: {result:?}"
+        );
+    }
+
     #[test]
     fn map_message_interaction_info_to_reactions_changed() {
         let mapper = StubMessageMapper;
         let update = TdLibUpdate::MessageInteractionInfoChanged {
             chat_id: 10,
             message_id: 20,
-            reaction_count: 5,
+            reactions: vec![crate::domain::message::MessageReaction {
+                emoji: "👍".to_owned(),
+                count: 5,
+                is_chosen: false,
+            }],
         };
 
         let result = map_update(update, &mapper);
@@ -497,11 +662,11 @@ mod tests {
             Some(ChatUpdate::MessageReactionsChanged {
                 chat_id,
                 message_id,
-                reaction_count,
+                reactions,
             }) => {
                 assert_eq!(chat_id, 10);
                 assert_eq!(message_id, 20);
-                assert_eq!(reaction_count, 5);
+                assert_eq!(reactions[0].count, 5);
             }
             other => panic!("expected MessageReactionsChanged, got: {other:?}"),
         }
@@ -566,4 +731,52 @@ mod tests {
             "expected UserStatusChanged, got: {result:?}"
         );
     }
+
+    #[test]
+    fn map_chat_folders_to_chat_folders_changed() {
+        use crate::domain::chat_folder::ChatFolder;
+
+        let mapper = StubMessageMapper;
+        let folders = vec![ChatFolder {
+            id: 3,
+            title: "Work".to_owned(),
+        }];
+        let update = TdLibUpdate::ChatFolders {
+            folders: folders.clone(),
+            main_position: 1,
+        };
+
+        let result = map_update(update, &mapper);
+
+        assert_eq!(
+            result,
+            Some(ChatUpdate::ChatFoldersChanged {
+                folders,
+                main_position: 1,
+            })
+        );
+    }
+
+    #[test]
+    fn map_unread_chat_count_to_unread_chat_count_changed() {
+        use crate::domain::chat_folder::ChatListId;
+
+        let mapper = StubMessageMapper;
+        let update = TdLibUpdate::UnreadChatCount {
+            list: ChatListId::Folder(3),
+            unread_count: 4,
+            total_count: 10,
+        };
+
+        let result = map_update(update, &mapper);
+
+        assert_eq!(
+            result,
+            Some(ChatUpdate::UnreadChatCountChanged {
+                list: ChatListId::Folder(3),
+                unread_count: 4,
+                total_count: 10,
+            })
+        );
+    }
 }
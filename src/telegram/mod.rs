@@ -131,6 +131,7 @@ impl TelegramAdapter {
         let update_rx = backend
             .take_update_receiver()
             .ok_or(ChatUpdatesMonitorStartError::StartupRejected)?;
+        backend.seed_scope_notification_settings();
 
         let mapper = backend.create_message_mapper();
 
@@ -184,11 +185,20 @@ pub fn module_name() -> &'static str {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::chat::MuteDuration;
+    use crate::domain::chat_folder::ChatListId;
     use crate::usecases::{
-        chat_lifecycle::{ChatLifecycle, ChatLifecycleError, ChatReadMarker, MessageDeleter},
+        chat_lifecycle::{
+            ChatLifecycle, ChatLifecycleError, ChatListMover, ChatManager, ChatMuter, ChatPinner,
+            ChatReadMarker, MessageDeleter, MessageForwarder,
+        },
+        contacts::{ContactSource, ContactsError},
+        go_to_chat::{ChatLookupError, ChatLookupSource},
         guided_auth::TelegramAuthClient,
+        links::{LinkError, LinkSource},
         list_chats::{ListChatsSource, ListChatsSourceError},
         load_messages::{MessagesSource, MessagesSourceError},
+        new_chat::{ChatCreator, NewChatError},
         send_message::{MessageSender, SendMessageSourceError},
     };
 
@@ -258,7 +268,7 @@ mod tests {
         let adapter = TelegramAdapter::stub();
 
         let error = adapter
-            .list_chats(20, false)
+            .list_chats(ChatListId::Main, 20, false)
             .expect_err("stub adapter should fail");
 
         assert_eq!(error, ListChatsSourceError::Unavailable);
@@ -280,7 +290,7 @@ mod tests {
         let adapter = TelegramAdapter::stub();
 
         let error = adapter
-            .send_message(1, None, "hello", None)
+            .send_message(1, None, "hello", None, &[])
             .expect_err("stub adapter should fail");
 
         assert_eq!(error, SendMessageSourceError::Unavailable);
@@ -295,6 +305,92 @@ mod tests {
         assert_eq!(error, ChatLifecycleError::Unavailable);
     }
 
+    #[test]
+    fn set_chat_marked_as_unread_returns_unavailable_when_backend_is_not_configured() {
+        let adapter = TelegramAdapter::stub();
+
+        let error = adapter
+            .set_chat_marked_as_unread(1, true)
+            .expect_err("stub adapter should fail");
+
+        assert_eq!(error, ChatLifecycleError::Unavailable);
+    }
+
+    #[test]
+    fn set_chat_pinned_returns_unavailable_when_backend_is_not_configured() {
+        let adapter = TelegramAdapter::stub();
+
+        let error = adapter
+            .set_chat_pinned(1, ChatListId::Main, true)
+            .expect_err("stub adapter should fail");
+
+        assert_eq!(error, ChatLifecycleError::Unavailable);
+    }
+
+    #[test]
+    fn set_pinned_chats_returns_unavailable_when_backend_is_not_configured() {
+        let adapter = TelegramAdapter::stub();
+
+        let error = adapter
+            .set_pinned_chats(ChatListId::Main, vec![2, 1])
+            .expect_err("stub adapter should fail");
+
+        assert_eq!(error, ChatLifecycleError::Unavailable);
+    }
+
+    #[test]
+    fn leave_chat_returns_unavailable_when_backend_is_not_configured() {
+        let adapter = TelegramAdapter::stub();
+
+        let error = adapter.leave_chat(1).expect_err("stub adapter should fail");
+
+        assert_eq!(error, ChatLifecycleError::Unavailable);
+    }
+
+    #[test]
+    fn delete_chat_returns_unavailable_when_backend_is_not_configured() {
+        let adapter = TelegramAdapter::stub();
+
+        let error = adapter
+            .delete_chat(1)
+            .expect_err("stub adapter should fail");
+
+        assert_eq!(error, ChatLifecycleError::Unavailable);
+    }
+
+    #[test]
+    fn delete_chat_history_returns_unavailable_when_backend_is_not_configured() {
+        let adapter = TelegramAdapter::stub();
+
+        let error = adapter
+            .delete_chat_history(1, false, true)
+            .expect_err("stub adapter should fail");
+
+        assert_eq!(error, ChatLifecycleError::Unavailable);
+    }
+
+    #[test]
+    fn set_chat_blocked_returns_unavailable_when_backend_is_not_configured() {
+        let adapter = TelegramAdapter::stub();
+
+        let error = adapter
+            .set_chat_blocked(1, true)
+            .expect_err("stub adapter should fail");
+
+        assert_eq!(error, ChatLifecycleError::Unavailable);
+    }
+
+    #[test]
+    fn set_chat_mute_returns_unavailable_when_backend_is_not_configured() {
+        let adapter = TelegramAdapter::stub();
+
+        let error = adapter
+            .set_chat_mute(1, Some(MuteDuration::OneHour))
+            .expect_err("stub adapter should fail");
+
+        assert_eq!(error, ChatLifecycleError::Unavailable);
+    }
+
     #[test]
     fn close_chat_returns_unavailable_when_backend_is_not_configured() {
         let adapter = TelegramAdapter::stub();
@@ -325,4 +421,166 @@ mod tests {
 
         assert_eq!(error, ChatLifecycleError::Unavailable);
     }
+
+    #[test]
+    fn forward_messages_returns_unavailable_when_backend_is_not_configured() {
+        let adapter = TelegramAdapter::stub();
+
+        let error = adapter
+            .forward_messages(2, 1, vec![1, 2])
+            .expect_err("stub adapter should fail");
+
+        assert_eq!(error, ChatLifecycleError::Unavailable);
+    }
+
+    #[test]
+    fn add_chat_to_list_returns_unavailable_when_backend_is_not_configured() {
+        let adapter = TelegramAdapter::stub();
+
+        let error = adapter
+            .add_chat_to_list(1, ChatListId::Archive)
+            .expect_err("stub adapter should fail");
+
+        assert_eq!(error, ChatLifecycleError::Unavailable);
+    }
+
+    #[test]
+    fn delete_permissions_returns_unavailable_when_backend_is_not_configured() {
+        let adapter = TelegramAdapter::stub();
+
+        let error = adapter
+            .delete_permissions(1, &[1, 2])
+            .expect_err("stub adapter should fail");
+
+        assert_eq!(error, ChatLifecycleError::Unavailable);
+    }
+
+    #[test]
+    fn get_message_link_returns_unavailable_when_backend_is_not_configured() {
+        let adapter = TelegramAdapter::stub();
+
+        let error = adapter
+            .get_message_link(1, 2)
+            .expect_err("stub adapter should fail");
+
+        assert_eq!(error, LinkError::Unavailable);
+    }
+
+    #[test]
+    fn resolve_link_returns_unavailable_when_backend_is_not_configured() {
+        let adapter = TelegramAdapter::stub();
+
+        let error = adapter
+            .resolve_link("https://t.me/rustlang", 50)
+            .expect_err("stub adapter should fail");
+
+        assert_eq!(error, LinkError::Unavailable);
+    }
+
+    #[test]
+    fn join_chat_by_invite_link_returns_unavailable_when_backend_is_not_configured() {
+        let adapter = TelegramAdapter::stub();
+
+        let error = adapter
+            .join_chat_by_invite_link("https://t.me/+AbCdEf")
+            .expect_err("stub adapter should fail");
+
+        assert_eq!(error, LinkError::Unavailable);
+    }
+
+    #[test]
+    fn find_chat_returns_unavailable_when_backend_is_not_configured() {
+        let adapter = TelegramAdapter::stub();
+
+        let error = adapter
+            .find_chat(&crate::domain::go_to_prompt_state::ChatQuery::Username(
+                "durov".to_owned(),
+            ))
+            .expect_err("stub adapter should fail");
+
+        assert_eq!(error, ChatLookupError::Unavailable);
+    }
+
+    #[test]
+    fn search_chats_returns_unavailable_when_backend_is_not_configured() {
+        let adapter = TelegramAdapter::stub();
+
+        let error = adapter
+            .search_chats("rust", 20)
+            .expect_err("stub adapter should fail");
+
+        assert_eq!(error, ChatLookupError::Unavailable);
+    }
+
+    #[test]
+    fn search_chats_on_server_returns_unavailable_when_backend_is_not_configured() {
+        let adapter = TelegramAdapter::stub();
+
+        let error = adapter
+            .search_chats_on_server("rust", 20)
+            .expect_err("stub adapter should fail");
+
+        assert_eq!(error, ChatLookupError::Unavailable);
+    }
+
+    #[test]
+    fn list_contacts_returns_unavailable_when_backend_is_not_configured() {
+        let adapter = TelegramAdapter::stub();
+
+        let error = adapter
+            .list_contacts()
+            .expect_err("stub adapter should fail");
+
+        assert_eq!(error, ContactsError::Unavailable);
+    }
+
+    #[test]
+    fn add_contact_returns_unavailable_when_backend_is_not_configured() {
+        let adapter = TelegramAdapter::stub();
+
+        let error = adapter
+            .add_contact(1)
+            .expect_err("stub adapter should fail");
+
+        assert_eq!(error, ContactsError::Unavailable);
+    }
+
+    #[test]
+    fn remove_contact_returns_unavailable_when_backend_is_not_configured() {
+        let adapter = TelegramAdapter::stub();
+
+        let error = adapter
+            .remove_contact(1)
+            .expect_err("stub adapter should fail");
+
+        assert_eq!(error, ContactsError::Unavailable);
+    }
+
+    #[test]
+    fn contact_chat_returns_unavailable_when_backend_is_not_configured() {
+        let adapter = TelegramAdapter::stub();
+
+        let error = adapter
+            .contact_chat(1)
+            .expect_err("stub adapter should fail");
+
+        assert_eq!(error, ContactsError::Unavailable);
+    }
+
+    #[test]
+    fn create_chat_returns_unavailable_when_backend_is_not_configured() {
+        let adapter = TelegramAdapter::stub();
+        let request = crate::domain::new_chat_wizard_state::NewChatRequest {
+            kind: crate::domain::new_chat_wizard_state::NewChatKind::Supergroup,
+            title: "Ops".to_owned(),
+            description: String::new(),
+            member_ids: Vec::new(),
+        };
+
+        let error = adapter
+            .create_chat(&request)
+            .expect_err("stub adapter should fail");
+
+        assert_eq!(error, NewChatError::Unavailable);
+    }
 }
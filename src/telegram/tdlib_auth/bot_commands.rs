@@ -0,0 +1,96 @@
+use crate::domain::bot_command::BotCommand;
+use crate::usecases::bot_commands::BotCommandsError;
+
+use super::TdLibAuthBackend;
+
+impl TdLibAuthBackend {
+    /// Loads the commands of the bot in a private chat, or of every bot
+    /// member of a group. Channels and chats with people have none.
+    pub fn get_bot_commands(&self, chat_id: i64) -> Result<Vec<BotCommand>, BotCommandsError> {
+        let chat = self.client.get_chat(chat_id).map_err(|e| {
+            tracing::debug!(chat_id, error = ?e, "failed to get chat for bot commands");
+            BotCommandsError::Unavailable
+        })?;
+
+        let lists: Vec<Vec<tdlib_rs::types::BotCommand>> = match &chat.r#type {
+            tdlib_rs::enums::ChatType::Private(private) => {
+                let info = self
+                    .client
+                    .get_user_full_info(private.user_id)
+                    .map_err(|e| {
+                        tracing::debug!(chat_id, error = ?e, "failed to get bot info");
+                        BotCommandsError::Unavailable
+                    })?;
+                info.bot_info.map(|b| b.commands).into_iter().collect()
+            }
+            tdlib_rs::enums::ChatType::BasicGroup(bg) => self
+                .client
+                .get_basic_group_full_info(bg.basic_group_id)
+                .map_err(|e| {
+                    tracing::debug!(chat_id, error = ?e, "failed to get group bot commands");
+                    BotCommandsError::Unavailable
+                })?
+                .bot_commands
+                .into_iter()
+                .map(|b| b.commands)
+                .collect(),
+            tdlib_rs::enums::ChatType::Supergroup(sg) if !sg.is_channel => self
+                .client
+                .get_supergroup_full_info(sg.supergroup_id)
+                .map_err(|e| {
+                    tracing::debug!(chat_id, error = ?e, "failed to get group bot commands");
+                    BotCommandsError::Unavailable
+                })?
+                .bot_commands
+                .into_iter()
+                .map(|b| b.commands)
+                .collect(),
+            _ => Vec::new(),
+        };
+
+        Ok(merge_bot_commands(lists))
+    }
+}
+
+/// Flattens per-bot command lists, keeping the first description of a
+/// command that several bots declare.
+fn merge_bot_commands(lists: Vec<Vec<tdlib_rs::types::BotCommand>>) -> Vec<BotCommand> {
+    let mut merged: Vec<BotCommand> = Vec::new();
+    for command in lists.into_iter().flatten() {
+        if merged.iter().any(|c| c.command == command.command) {
+            continue;
+        }
+        merged.push(BotCommand {
+            command: command.command,
+            description: command.description,
+        });
+    }
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn td_command(command: &str, description: &str) -> tdlib_rs::types::BotCommand {
+        tdlib_rs::types::BotCommand {
+            command: command.to_owned(),
+            description: description.to_owned(),
+        }
+    }
+
+    #[test]
+    fn merge_keeps_first_declaration_of_duplicate_commands() {
+        let merged = merge_bot_commands(vec![
+            vec![td_command("start", "First bot"), td_command("help", "Help")],
+            vec![
+                td_command("start", "Second bot"),
+                td_command("stats", "Stats"),
+            ],
+        ]);
+
+        let names: Vec<&str> = merged.iter().map(|c| c.command.as_str()).collect();
+        assert_eq!(names, vec!["start", "help", "stats"]);
+        assert_eq!(merged[0].description, "First bot");
+    }
+}
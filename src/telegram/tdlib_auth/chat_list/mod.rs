@@ -1,8 +1,10 @@
 use tdlib_rs::types::Chat;
 
 use crate::domain::chat::ChatSummary;
+use crate::domain::chat_folder::ChatListId;
 use crate::telegram::tdlib_cache::TdLibCache;
 use crate::telegram::tdlib_client::TdLibError;
+use crate::telegram::tdlib_mappers::{self, is_pinned_in_list, map_chat_list_id};
 use crate::usecases::list_chats::ListChatsSourceError;
 
 use super::error_mapping::map_list_chats_error;
@@ -43,20 +45,23 @@ impl ChatDataResolver for crate::telegram::tdlib_client::TdLibClient {
 impl TdLibAuthBackend {
     /// Lists chat summaries from TDLib.
     ///
-    /// Fetches chats from the main chat list and maps them to domain `ChatSummary`.
+    /// Fetches chats from `list` (the main list or a chat folder) and maps them
+    /// to domain `ChatSummary`.
     /// When `force` is `true`, bypasses the in-memory cache and reads every chat
     /// directly from TDLib's SQLite via `get_chat()` — guarantees fresh data at
     /// the cost of ~1-2ms per chat.
     pub fn list_chat_summaries(
         &self,
+        list: ChatListId,
         limit: usize,
         force: bool,
     ) -> Result<(Vec<ChatSummary>, bool), ListChatsSourceError> {
         let limit_i32 = i32::try_from(limit).unwrap_or(i32::MAX);
 
+        let td_list = map_chat_list_id(list);
         let result = self
             .client
-            .get_chats(limit_i32)
+            .get_chats(td_list.clone(), limit_i32)
             .map_err(map_list_chats_error)?;
 
         let requested_count = result.chat_ids.len();
@@ -67,7 +72,16 @@ impl TdLibAuthBackend {
             "Fetched chat IDs from TDLib"
         );
 
-        let summaries = build_summaries_from_ids(&self.client, result.chat_ids, force);
+        let mut summaries = build_summaries_from_ids(&self.client, result.chat_ids, force);
+
+        // Summaries carry the main list's pin flags; folders pin separately.
+        if list != ChatListId::Main {
+            for summary in &mut summaries {
+                if let Some(chat) = self.client.cache().get_chat(summary.chat_id) {
+                    summary.is_pinned = is_pinned_in_list(&chat, &td_list);
+                }
+            }
+        }
 
         if requested_count > 0 && summaries.is_empty() {
             tracing::warn!(
@@ -134,12 +148,15 @@ pub(super) fn build_summaries_from_ids(
 
         let (sender_name, is_online, is_bot, is_forum) =
             resolve_chat_metadata(resolver, &chat, cache);
-        let mut summary = crate::telegram::tdlib_mappers::map_chat_to_summary(
+        let scope_settings =
+            cache.get_scope_notification_settings(&tdlib_mappers::notification_scope(&chat.r#type));
+        let mut summary = tdlib_mappers::map_chat_to_summary(
             &chat,
             sender_name,
             is_online,
             is_bot,
             is_forum,
+            scope_settings.as_ref(),
         );
         // TDLib's chat-level `unread_count` is unreliable for forums, so the
         // badge instead shows the number of topics with unread messages,
@@ -149,6 +166,7 @@ pub(super) fn build_summaries_from_ids(
         if is_forum {
             summary.unread_topic_count = cache.unread_forum_topic_count(chat_id);
         }
+        summary.username = chat_username(&chat, cache);
         summaries.push(summary);
     }
 
@@ -168,7 +186,6 @@ fn resolve_chat_metadata(
     cache: &TdLibCache,
 ) -> (Option<String>, Option<bool>, bool, bool) {
     use crate::domain::chat::ChatType;
-    use crate::telegram::tdlib_mappers;
 
     let chat_type = tdlib_mappers::map_chat_type(&chat.r#type);
 
@@ -209,6 +226,19 @@ fn resolve_chat_metadata(
     (sender_name, is_online, is_bot, is_forum)
 }
 
+/// Primary username of the chat, read from the cached user (private
+/// chats, resolved above) or supergroup (public groups and channels).
+fn chat_username(chat: &Chat, cache: &TdLibCache) -> Option<String> {
+    let usernames = match &chat.r#type {
+        tdlib_rs::enums::ChatType::Private(private) => cache.get_user(private.user_id)?.usernames,
+        tdlib_rs::enums::ChatType::Supergroup(sg) => {
+            cache.get_supergroup(sg.supergroup_id)?.usernames
+        }
+        _ => None,
+    };
+    usernames?.active_usernames.into_iter().next()
+}
+
 /// Resolves a user from cache, falling back to TDLib on miss.
 ///
 /// Populates the cache on successful TDLib fetch. Logs a warning
@@ -255,6 +255,42 @@ fn private_chat_resolves_user_from_cache() {
     );
 }
 
+fn usernames(username: &str) -> Option<tdlib_rs::types::Usernames> {
+    Some(tdlib_rs::types::Usernames {
+        active_usernames: vec![username.to_owned()],
+        disabled_usernames: Vec::new(),
+        editable_username: username.to_owned(),
+        collectible_usernames: Vec::new(),
+    })
+}
+
+#[test]
+fn private_chat_carries_username_of_user() {
+    let mut user = make_test_user(1, "Alice");
+    user.usernames = usernames("alice");
+    let resolver = FakeResolver::new()
+        .with_cached_chat(make_test_chat(1, "Alice"))
+        .with_cached_user(user);
+
+    let summaries = build_summaries_from_ids(&resolver, vec![1], NO_FORCE);
+
+    assert_eq!(summaries[0].username.as_deref(), Some("alice"));
+}
+
+#[test]
+fn supergroup_chat_carries_username_from_cache() {
+    use crate::telegram::tdlib_cache::tests::make_test_supergroup;
+
+    let resolver = FakeResolver::new().with_cached_chat(make_supergroup_chat(10, 100, "Rust"));
+    let mut supergroup = make_test_supergroup(100, false);
+    supergroup.usernames = usernames("rustlang");
+    resolver.cache.upsert_supergroup(supergroup);
+
+    let summaries = build_summaries_from_ids(&resolver, vec![10], NO_FORCE);
+
+    assert_eq!(summaries[0].username.as_deref(), Some("rustlang"));
+}
+
 #[test]
 fn private_chat_falls_back_to_get_user_on_cache_miss() {
     let resolver = FakeResolver::new()
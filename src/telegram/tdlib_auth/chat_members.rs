@@ -0,0 +1,78 @@
+use crate::domain::chat_member::ChatMember;
+use crate::telegram::tdlib_mappers;
+use crate::usecases::chat_members::ChatMembersError;
+
+use super::TdLibAuthBackend;
+
+impl TdLibAuthBackend {
+    /// Finds group members to mention. Supergroups are searched on the
+    /// server; basic groups return their full member list, filtered here.
+    pub fn search_chat_members(
+        &self,
+        chat_id: i64,
+        query: &str,
+        limit: usize,
+    ) -> Result<Vec<ChatMember>, ChatMembersError> {
+        let chat = self.client.get_chat(chat_id).map_err(|e| {
+            tracing::debug!(chat_id, error = ?e, "failed to get chat for member search");
+            ChatMembersError::Unavailable
+        })?;
+
+        let (td_members, filter_locally) = match &chat.r#type {
+            tdlib_rs::enums::ChatType::Supergroup(sg) if !sg.is_channel => {
+                let limit = i32::try_from(limit).unwrap_or(i32::MAX);
+                let members = self
+                    .client
+                    .search_chat_members(chat_id, query, limit)
+                    .map_err(|e| {
+                        tracing::debug!(chat_id, error = ?e, "failed to search chat members");
+                        ChatMembersError::Unavailable
+                    })?;
+                (members, false)
+            }
+            tdlib_rs::enums::ChatType::BasicGroup(bg) => {
+                let info = self
+                    .client
+                    .get_basic_group_full_info(bg.basic_group_id)
+                    .map_err(|e| {
+                        tracing::debug!(chat_id, error = ?e, "failed to get basic group members");
+                        ChatMembersError::Unavailable
+                    })?;
+                (info.members, true)
+            }
+            _ => return Ok(Vec::new()),
+        };
+
+        let cache = self.client.cache();
+        let members = td_members
+            .into_iter()
+            .filter_map(|member| match member.member_id {
+                tdlib_rs::enums::MessageSender::User(u) => Some(u.user_id),
+                tdlib_rs::enums::MessageSender::Chat(_) => None,
+            })
+            .filter_map(|user_id| {
+                cache
+                    .get_user(user_id)
+                    .or_else(|| self.client.get_user(user_id).ok())
+            })
+            .filter(|user| !matches!(user.r#type, tdlib_rs::enums::UserType::Deleted))
+            .map(|user| map_member(&user))
+            .filter(|member| !filter_locally || member.matches(query))
+            .take(limit)
+            .collect();
+
+        Ok(members)
+    }
+}
+
+fn map_member(user: &tdlib_rs::types::User) -> ChatMember {
+    ChatMember {
+        user_id: user.id,
+        display_name: tdlib_mappers::format_user_name(user),
+        username: user
+            .usernames
+            .as_ref()
+            .and_then(|u| u.active_usernames.first().cloned())
+            .unwrap_or_default(),
+    }
+}
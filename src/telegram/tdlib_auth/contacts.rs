@@ -0,0 +1,81 @@
+use crate::domain::chat::ChatSummary;
+use crate::domain::contact::Contact;
+use crate::telegram::tdlib_mappers;
+use crate::usecases::contacts::ContactsError;
+
+use super::chat_list::build_summaries_from_ids;
+use super::error_mapping::map_contacts_error;
+use super::TdLibAuthBackend;
+
+impl TdLibAuthBackend {
+    pub fn list_contacts(&self) -> Result<Vec<Contact>, ContactsError> {
+        let user_ids = self.client.get_contacts().map_err(map_contacts_error)?;
+
+        Ok(user_ids
+            .into_iter()
+            .filter_map(|user_id| self.contact_user(user_id))
+            .map(|user| map_contact(&user))
+            .collect())
+    }
+
+    pub fn add_contact(&self, chat_id: i64) -> Result<Contact, ContactsError> {
+        let chat = self.client.get_chat(chat_id).map_err(map_contacts_error)?;
+        let user_id =
+            tdlib_mappers::get_private_chat_user_id(&chat.r#type).ok_or(ContactsError::NotFound)?;
+        let user = self.client.get_user(user_id).map_err(map_contacts_error)?;
+        if matches!(user.r#type, tdlib_rs::enums::UserType::Bot(_)) {
+            return Err(ContactsError::NotFound);
+        }
+
+        self.client
+            .add_contact(user_id, user.first_name.clone(), user.last_name.clone())
+            .map_err(map_contacts_error)?;
+        Ok(map_contact(&user))
+    }
+
+    pub fn remove_contact(&self, user_id: i64) -> Result<(), ContactsError> {
+        self.client
+            .remove_contacts(vec![user_id])
+            .map_err(map_contacts_error)
+    }
+
+    pub fn contact_chat(&self, user_id: i64) -> Result<ChatSummary, ContactsError> {
+        let chat = self
+            .client
+            .create_private_chat(user_id)
+            .map_err(map_contacts_error)?;
+        build_summaries_from_ids(&self.client, vec![chat.id], false)
+            .pop()
+            .ok_or(ContactsError::NotFound)
+    }
+
+    /// Resolves a contact from the cache, falling back to TDLib.
+    fn contact_user(&self, user_id: i64) -> Option<tdlib_rs::types::User> {
+        if let Some(user) = self.client.cache().get_user(user_id) {
+            return Some(user);
+        }
+        match self.client.get_user(user_id) {
+            Ok(user) => {
+                self.client.cache().upsert_user(user.clone());
+                Some(user)
+            }
+            Err(e) => {
+                tracing::debug!(user_id, error = %e, "contact missing from TDLib");
+                None
+            }
+        }
+    }
+}
+
+fn map_contact(user: &tdlib_rs::types::User) -> Contact {
+    Contact {
+        user_id: user.id,
+        display_name: tdlib_mappers::format_user_name(user),
+        username: user
+            .usernames
+            .as_ref()
+            .and_then(|u| u.active_usernames.first().cloned())
+            .unwrap_or_default(),
+        is_online: tdlib_mappers::is_user_online(&user.status),
+    }
+}
@@ -1,8 +1,12 @@
+use crate::usecases::contacts::ContactsError;
 use crate::usecases::edit_message::EditMessageSourceError;
+use crate::usecases::go_to_chat::ChatLookupError;
 use crate::usecases::guided_auth::AuthBackendError;
+use crate::usecases::links::LinkError;
 use crate::usecases::list_chats::ListChatsSourceError;
 use crate::usecases::list_forum_topics::ListForumTopicsSourceError;
 use crate::usecases::load_messages::MessagesSourceError;
+use crate::usecases::new_chat::NewChatError;
 use crate::usecases::send_message::SendMessageSourceError;
 
 use super::super::tdlib_client::TdLibError;
@@ -212,6 +216,50 @@ pub(super) fn map_edit_message_error(error: TdLibError) -> EditMessageSourceErro
     EditMessageSourceError::Unavailable
 }
 
+/// Maps TDLib error to LinkError.
+///
+/// TDLib answers unknown usernames, expired invites and chats without
+/// message links with a 400 error; joining a chat that needs approval
+/// fails with `INVITE_REQUEST_SENT` once the request is sent.
+pub(super) fn map_link_error(error: TdLibError) -> LinkError {
+    match error {
+        TdLibError::Request { message, .. } if message.contains("INVITE_REQUEST_SENT") => {
+            LinkError::JoinRequestSent
+        }
+        TdLibError::Request { code: 400, .. } => LinkError::NotFound,
+        _ => LinkError::Unavailable,
+    }
+}
+
+/// Maps TDLib error from username, phone or contact lookups. TDLib answers
+/// 400 for unknown usernames and phone numbers.
+pub(super) fn map_chat_lookup_error(error: TdLibError) -> ChatLookupError {
+    match error {
+        TdLibError::Request { code: 400, .. } => ChatLookupError::NotFound,
+        _ => ChatLookupError::Unavailable,
+    }
+}
+
+/// Maps TDLib error from contact requests. TDLib answers 400 for users it
+/// does not know and for peers that can't be contacts.
+pub(super) fn map_contacts_error(error: TdLibError) -> ContactsError {
+    match error {
+        TdLibError::Request { code: 400, .. } => ContactsError::NotFound,
+        _ => ContactsError::Unavailable,
+    }
+}
+
+/// Maps TDLib error from chat creation. 400 covers invalid titles and
+/// members, 403 members whose privacy settings forbid inviting them.
+pub(super) fn map_new_chat_error(error: TdLibError) -> NewChatError {
+    match error {
+        TdLibError::Request {
+            code: 400 | 403, ..
+        } => NewChatError::Rejected,
+        _ => NewChatError::Unavailable,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -258,4 +306,60 @@ mod tests {
             ListForumTopicsSourceError::Unavailable
         );
     }
+
+    #[test]
+    fn link_error_bad_request_is_not_found() {
+        let err = request(400, "USERNAME_NOT_OCCUPIED");
+        assert_eq!(map_link_error(err), LinkError::NotFound);
+    }
+
+    #[test]
+    fn link_error_invite_request_sent_is_recognised() {
+        let err = request(400, "INVITE_REQUEST_SENT");
+        assert_eq!(map_link_error(err), LinkError::JoinRequestSent);
+    }
+
+    #[test]
+    fn link_error_other_failures_are_unavailable() {
+        let err = request(500, "Internal Server Error");
+        assert_eq!(map_link_error(err), LinkError::Unavailable);
+    }
+
+    #[test]
+    fn chat_lookup_error_bad_request_is_not_found() {
+        let err = request(400, "PHONE_NOT_FOUND");
+        assert_eq!(map_chat_lookup_error(err), ChatLookupError::NotFound);
+    }
+
+    #[test]
+    fn chat_lookup_error_other_failures_are_unavailable() {
+        let err = request(500, "Internal Server Error");
+        assert_eq!(map_chat_lookup_error(err), ChatLookupError::Unavailable);
+    }
+
+    #[test]
+    fn contacts_error_bad_request_is_not_found() {
+        let err = request(400, "USER_ID_INVALID");
+        assert_eq!(map_contacts_error(err), ContactsError::NotFound);
+    }
+
+    #[test]
+    fn contacts_error_other_failures_are_unavailable() {
+        let err = request(500, "Internal Server Error");
+        assert_eq!(map_contacts_error(err), ContactsError::Unavailable);
+    }
+
+    #[test]
+    fn new_chat_error_refusals_are_rejected() {
+        let err = request(400, "CHAT_TITLE_EMPTY");
+        assert_eq!(map_new_chat_error(err), NewChatError::Rejected);
+        let err = request(403, "USER_PRIVACY_RESTRICTED");
+        assert_eq!(map_new_chat_error(err), NewChatError::Rejected);
+    }
+
+    #[test]
+    fn new_chat_error_other_failures_are_unavailable() {
+        let err = request(500, "Internal Server Error");
+        assert_eq!(map_new_chat_error(err), NewChatError::Unavailable);
+    }
 }
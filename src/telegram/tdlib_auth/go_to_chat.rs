@@ -0,0 +1,88 @@
+use crate::domain::chat::ChatSummary;
+use crate::domain::go_to_prompt_state::ChatQuery;
+use crate::usecases::go_to_chat::ChatLookupError;
+
+use super::chat_list::build_summaries_from_ids;
+use super::error_mapping::map_chat_lookup_error;
+use super::TdLibAuthBackend;
+
+/// Contacts considered when matching a name: enough to tell a single
+/// match from an ambiguous one.
+const CONTACT_SEARCH_LIMIT: i32 = 2;
+
+impl TdLibAuthBackend {
+    /// Resolves a "go to chat" query. Users are reached through their
+    /// private chat, which `createPrivateChat` opens even if it never had
+    /// messages.
+    pub fn find_chat(&self, query: &ChatQuery) -> Result<ChatSummary, ChatLookupError> {
+        let chat_id = match query {
+            ChatQuery::Username(username) => self.public_chat_id(username)?,
+            ChatQuery::Phone(digits) => {
+                let user = self
+                    .client
+                    .search_user_by_phone_number(digits.clone())
+                    .map_err(map_chat_lookup_error)?;
+                self.private_chat_id(user.id)?
+            }
+            ChatQuery::Name(name) => {
+                let contacts = self
+                    .client
+                    .search_contacts(name.clone(), CONTACT_SEARCH_LIMIT)
+                    .map_err(map_chat_lookup_error)?;
+                match contacts.as_slice() {
+                    [user_id] => self.private_chat_id(*user_id)?,
+                    // `rustlang` typed without `@` is still worth a try.
+                    [] if query.is_username_like() => self.public_chat_id(name)?,
+                    [] => return Err(ChatLookupError::NotFound),
+                    _ => return Err(ChatLookupError::Ambiguous),
+                }
+            }
+        };
+
+        build_summaries_from_ids(&self.client, vec![chat_id], false)
+            .pop()
+            .ok_or(ChatLookupError::NotFound)
+    }
+
+    /// Chats matching `query` that TDLib knows locally.
+    pub fn search_chats(
+        &self,
+        query: &str,
+        limit: usize,
+    ) -> Result<Vec<ChatSummary>, ChatLookupError> {
+        let limit = i32::try_from(limit).unwrap_or(i32::MAX);
+        let chat_ids = self
+            .client
+            .search_chats(query.to_owned(), limit)
+            .map_err(map_chat_lookup_error)?;
+        Ok(build_summaries_from_ids(&self.client, chat_ids, false))
+    }
+
+    /// Public chats matching `query`, found by the server.
+    pub fn search_chats_on_server(
+        &self,
+        query: &str,
+        limit: usize,
+    ) -> Result<Vec<ChatSummary>, ChatLookupError> {
+        let limit = i32::try_from(limit).unwrap_or(i32::MAX);
+        let chat_ids = self
+            .client
+            .search_chats_on_server(query.to_owned(), limit)
+            .map_err(map_chat_lookup_error)?;
+        Ok(build_summaries_from_ids(&self.client, chat_ids, false))
+    }
+
+    fn public_chat_id(&self, username: &str) -> Result<i64, ChatLookupError> {
+        self.client
+            .search_public_chat(username.to_owned())
+            .map(|chat| chat.id)
+            .map_err(map_chat_lookup_error)
+    }
+
+    fn private_chat_id(&self, user_id: i64) -> Result<i64, ChatLookupError> {
+        self.client
+            .create_private_chat(user_id)
+            .map(|chat| chat.id)
+            .map_err(map_chat_lookup_error)
+    }
+}
@@ -0,0 +1,37 @@
+use crate::domain::message::CallbackAnswer;
+use crate::telegram::tdlib_client::TdLibError;
+use crate::usecases::inline_keyboard::{CallbackQuery, CallbackQueryError};
+
+use super::TdLibAuthBackend;
+
+impl TdLibAuthBackend {
+    pub fn get_callback_answer(
+        &self,
+        query: &CallbackQuery,
+    ) -> Result<CallbackAnswer, CallbackQueryError> {
+        let answer = self
+            .client
+            .get_callback_query_answer(query.chat_id, query.message_id, query.data.clone())
+            .map_err(|e| {
+                tracing::debug!(error = ?e, "get_callback_query_answer failed");
+                map_callback_query_error(&e)
+            })?;
+
+        Ok(CallbackAnswer {
+            text: answer.text,
+            show_alert: answer.show_alert,
+            url: answer.url,
+        })
+    }
+}
+
+/// Bots that do not answer in time surface as `BOT_RESPONSE_TIMEOUT`.
+fn map_callback_query_error(error: &TdLibError) -> CallbackQueryError {
+    match error {
+        TdLibError::Timeout { .. } => CallbackQueryError::Timeout,
+        TdLibError::Request { message, .. } if message.contains("TIMEOUT") => {
+            CallbackQueryError::Timeout
+        }
+        _ => CallbackQueryError::Unavailable,
+    }
+}
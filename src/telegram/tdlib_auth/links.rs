@@ -0,0 +1,121 @@
+use tdlib_rs::enums::InternalLinkType;
+
+use crate::domain::chat::ChatSummary;
+use crate::domain::link_target::{ChatInvite, LinkTarget};
+use crate::domain::message::UnreadMessageWindow;
+use crate::usecases::links::LinkError;
+use crate::usecases::load_messages::MessagesSourceError;
+
+use super::chat_list::build_summaries_from_ids;
+use super::error_mapping::map_link_error;
+use super::TdLibAuthBackend;
+
+impl TdLibAuthBackend {
+    pub fn get_message_link(&self, chat_id: i64, message_id: i64) -> Result<String, LinkError> {
+        self.client
+            .get_message_link(chat_id, message_id)
+            .map(|link| link.link)
+            .map_err(|e| {
+                tracing::debug!(chat_id, message_id, error = ?e, "getMessageLink failed");
+                map_link_error(e)
+            })
+    }
+
+    /// Classifies `url` with `getInternalLinkType` and resolves the chat,
+    /// message or invite it points to. Link kinds rtg has no UI for are
+    /// reported as [`LinkTarget::External`].
+    pub fn resolve_link(&self, url: &str, window_size: usize) -> Result<LinkTarget, LinkError> {
+        let link_type = self
+            .client
+            .get_internal_link_type(url.to_owned())
+            .map_err(map_link_error)?;
+
+        match link_type {
+            InternalLinkType::PublicChat(link) => self.resolve_username(link.chat_username),
+            InternalLinkType::BotStart(link) => self.resolve_username(link.bot_username),
+            InternalLinkType::Message(link) => self.resolve_message_link(link.url, window_size),
+            InternalLinkType::ChatInvite(link) => self.resolve_invite(link.invite_link),
+            other => {
+                tracing::debug!(?other, "unsupported internal link type");
+                Ok(LinkTarget::External)
+            }
+        }
+    }
+
+    pub fn join_chat_by_invite_link(&self, invite_link: &str) -> Result<ChatSummary, LinkError> {
+        let chat = self
+            .client
+            .join_chat_by_invite_link(invite_link.to_owned())
+            .map_err(map_link_error)?;
+        self.chat_summary(chat.id)
+    }
+
+    fn resolve_username(&self, username: String) -> Result<LinkTarget, LinkError> {
+        let chat = self
+            .client
+            .search_public_chat(username)
+            .map_err(map_link_error)?;
+        self.chat_summary(chat.id).map(LinkTarget::Chat)
+    }
+
+    fn resolve_message_link(
+        &self,
+        url: String,
+        window_size: usize,
+    ) -> Result<LinkTarget, LinkError> {
+        let info = self
+            .client
+            .get_message_link_info(url)
+            .map_err(map_link_error)?;
+        if info.chat_id == 0 {
+            return Err(LinkError::NotFound);
+        }
+
+        let chat = self.chat_summary(info.chat_id)?;
+        // Forum topics open from the topic list; a deleted message still
+        // opens its chat.
+        let Some(message) = info.message.filter(|_| !chat.is_forum) else {
+            return Ok(LinkTarget::Chat(chat));
+        };
+
+        let messages = self
+            .fetch_window_around(info.chat_id, None, message.id, window_size)
+            .map_err(|e| match e {
+                MessagesSourceError::ChatNotFound => LinkError::NotFound,
+                _ => LinkError::Unavailable,
+            })?;
+        Ok(LinkTarget::Message {
+            chat,
+            window: UnreadMessageWindow {
+                target_message_id: message.id,
+                messages,
+            },
+        })
+    }
+
+    fn resolve_invite(&self, invite_link: String) -> Result<LinkTarget, LinkError> {
+        let info = self
+            .client
+            .check_chat_invite_link(invite_link.clone())
+            .map_err(map_link_error)?;
+
+        // A known chat without a temporary preview means the user is
+        // already a member.
+        if info.chat_id != 0 && info.accessible_for == 0 {
+            return self.chat_summary(info.chat_id).map(LinkTarget::Chat);
+        }
+
+        Ok(LinkTarget::Invite(ChatInvite {
+            invite_link,
+            title: info.title,
+            member_count: u32::try_from(info.member_count).unwrap_or(0),
+            creates_join_request: info.creates_join_request,
+        }))
+    }
+
+    fn chat_summary(&self, chat_id: i64) -> Result<ChatSummary, LinkError> {
+        build_summaries_from_ids(&self.client, vec![chat_id], false)
+            .pop()
+            .ok_or(LinkError::NotFound)
+    }
+}
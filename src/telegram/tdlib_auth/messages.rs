@@ -1,6 +1,11 @@
 use std::collections::HashMap;
 
-use crate::domain::message::Message;
+use crate::domain::chat::{MuteDuration, UnreadJumpKind};
+use crate::domain::chat_action::ChatAction;
+use crate::domain::chat_folder::ChatListId;
+use crate::domain::delete_confirm_state::DeletePermissions;
+use crate::domain::message::{Message, UnreadMessageWindow};
+use crate::domain::message_input_state::TextMention;
 use crate::usecases::edit_message::EditMessageSourceError;
 use crate::usecases::load_messages::MessagesSourceError;
 use crate::usecases::send_message::SendMessageSourceError;
@@ -8,9 +13,12 @@ use crate::usecases::send_message::SendMessageSourceError;
 use super::error_mapping::{map_edit_message_error, map_messages_error, map_send_message_error};
 use super::TdLibAuthBackend;
 use crate::telegram::tdlib_cache::TdLibCache;
-use crate::telegram::tdlib_client::TdLibClient;
+use crate::telegram::tdlib_client::{TdLibClient, TdLibError};
 use crate::telegram::tdlib_mappers;
 
+/// Page size when walking unread mentions/reactions to find the oldest.
+const UNREAD_SEARCH_LIMIT: i32 = 100;
+
 impl TdLibAuthBackend {
     /// Lists messages from TDLib's local cache only.
     ///
@@ -37,37 +45,7 @@ impl TdLibAuthBackend {
             "fetched cached messages from TDLib"
         );
 
-        let cache = self.client.cache();
-        let resolve_user = |user_id: i64| {
-            cache
-                .get_user(user_id)
-                .map(|u| tdlib_mappers::format_user_name(&u))
-        };
-
-        let mut messages: Vec<Message> = td_messages
-            .iter()
-            .map(|msg| {
-                let sender_name = self.resolve_message_sender_name(msg);
-                let reply_to = self.resolve_reply_info(msg);
-                let forward_info = self.resolve_forward_info(msg);
-                tdlib_mappers::map_tdlib_message_to_domain(
-                    msg,
-                    sender_name,
-                    reply_to,
-                    forward_info,
-                    resolve_user,
-                )
-            })
-            .collect();
-
-        enrich_same_chat_reply_info(&td_messages, &mut messages, |chat_id, message_id| {
-            self.resolve_external_reply_target(chat_id, message_id)
-        });
-
-        // TDLib returns newest-first; UI expects chronological (oldest-first)
-        messages.reverse();
-
-        Ok(messages)
+        Ok(self.map_newest_first_page(&td_messages))
     }
 
     /// Lists messages from a chat or, when `topic_id` is `Some`, from a
@@ -88,6 +66,153 @@ impl TdLibAuthBackend {
         self.fetch_messages_paginated(chat_id, topic_id, limit, from_message_id)
     }
 
+    /// Returns a window of messages around the last message sent no later
+    /// than `unix_time` (seconds), in chronological order.
+    ///
+    /// The anchor comes from `getChatMessageByDate`; the window is then read
+    /// with a negative history offset so it spans both older and newer
+    /// messages. Returns an empty list when the chat has no message that old;
+    /// the caller then falls back to [`Self::list_oldest_messages`].
+    pub fn list_messages_around_date(
+        &self,
+        chat_id: i64,
+        topic_id: Option<i32>,
+        unix_time: i64,
+        limit: usize,
+    ) -> Result<Vec<Message>, MessagesSourceError> {
+        let date = i32::try_from(unix_time).unwrap_or(i32::MAX);
+        let anchor = match self.client.get_chat_message_by_date(chat_id, date) {
+            Ok(message) => message,
+            Err(TdLibError::Request { code: 404, .. }) => return Ok(Vec::new()),
+            Err(error) => return Err(map_messages_error(error)),
+        };
+
+        self.fetch_window_around(chat_id, topic_id, anchor.id, limit)
+    }
+
+    /// Returns up to `limit` of the chat's (or forum topic's) oldest
+    /// messages, in chronological order.
+    ///
+    /// History is read from message id 1, below every real id, with an
+    /// offset that takes the whole page from the newer side.
+    pub fn list_oldest_messages(
+        &self,
+        chat_id: i64,
+        topic_id: Option<i32>,
+        limit: usize,
+    ) -> Result<Vec<Message>, MessagesSourceError> {
+        const OLDEST_ANCHOR_ID: i64 = 1;
+
+        let limit = i32::try_from(limit).unwrap_or(i32::MAX).max(2);
+        // TDLib requires `-offset < limit`.
+        let offset = -(limit - 1);
+        let td_messages = match topic_id {
+            Some(tid) => self
+                .client
+                .get_forum_topic_history(chat_id, tid, OLDEST_ANCHOR_ID, offset, limit)
+                .map_err(map_messages_error)?,
+            None => self
+                .client
+                .get_chat_history(chat_id, OLDEST_ANCHOR_ID, offset, limit)
+                .map_err(map_messages_error)?,
+        };
+
+        tracing::debug!(
+            chat_id,
+            topic_id = ?topic_id,
+            count = td_messages.len(),
+            "fetched oldest messages"
+        );
+
+        Ok(self.map_newest_first_page(&td_messages))
+    }
+
+    /// Finds the oldest unread mention or reaction in a chat (or forum
+    /// topic) and returns a window of messages around it.
+    ///
+    /// Returns `None` when there is nothing unread of that kind.
+    pub fn list_messages_around_unread(
+        &self,
+        chat_id: i64,
+        topic_id: Option<i32>,
+        kind: UnreadJumpKind,
+        limit: usize,
+    ) -> Result<Option<UnreadMessageWindow>, MessagesSourceError> {
+        let filter = match kind {
+            UnreadJumpKind::Mention => tdlib_rs::enums::SearchMessagesFilter::UnreadMention,
+            UnreadJumpKind::Reaction => tdlib_rs::enums::SearchMessagesFilter::UnreadReaction,
+        };
+        // Hits are newest first; page to the end so the jump lands on the
+        // oldest one, like official clients.
+        let mut oldest = None;
+        let mut from_message_id = 0;
+        loop {
+            let page = self
+                .client
+                .search_chat_messages(
+                    chat_id,
+                    topic_id,
+                    filter.clone(),
+                    from_message_id,
+                    UNREAD_SEARCH_LIMIT,
+                )
+                .map_err(map_messages_error)?;
+            if let Some(last) = page.messages.last() {
+                oldest = Some(last.id);
+            }
+            if page.messages.is_empty()
+                || page.next_from_message_id == 0
+                || page.next_from_message_id == from_message_id
+            {
+                break;
+            }
+            from_message_id = page.next_from_message_id;
+        }
+        let Some(target_message_id) = oldest else {
+            return Ok(None);
+        };
+
+        let messages = self.fetch_window_around(chat_id, topic_id, target_message_id, limit)?;
+        Ok(Some(UnreadMessageWindow {
+            target_message_id,
+            messages,
+        }))
+    }
+
+    /// Reads up to `limit` messages centred on `anchor_id`, oldest first.
+    pub(super) fn fetch_window_around(
+        &self,
+        chat_id: i64,
+        topic_id: Option<i32>,
+        anchor_id: i64,
+        limit: usize,
+    ) -> Result<Vec<Message>, MessagesSourceError> {
+        let limit = i32::try_from(limit).unwrap_or(i32::MAX).max(2);
+        // Half of the window is newer than the anchor; TDLib requires
+        // `-offset < limit`.
+        let offset = -(limit / 2);
+        let td_messages = match topic_id {
+            Some(tid) => self
+                .client
+                .get_forum_topic_history(chat_id, tid, anchor_id, offset, limit)
+                .map_err(map_messages_error)?,
+            None => self
+                .client
+                .get_chat_history(chat_id, anchor_id, offset, limit)
+                .map_err(map_messages_error)?,
+        };
+
+        tracing::debug!(
+            chat_id,
+            topic_id = ?topic_id,
+            anchor_id,
+            count = td_messages.len(),
+            "fetched message window around anchor"
+        );
+
+        Ok(self.map_newest_first_page(&td_messages))
+    }
+
     /// Informs TDLib that the user has opened a chat.
     pub fn open_chat(&self, chat_id: i64) -> Result<(), MessagesSourceError> {
         self.client.open_chat(chat_id).map_err(map_messages_error)
@@ -98,6 +223,104 @@ impl TdLibAuthBackend {
         self.client.close_chat(chat_id).map_err(map_messages_error)
     }
 
+    /// Moves a chat into `list` (archives or unarchives it).
+    pub fn add_chat_to_list(
+        &self,
+        chat_id: i64,
+        list: ChatListId,
+    ) -> Result<(), MessagesSourceError> {
+        self.client
+            .add_chat_to_list(chat_id, tdlib_mappers::map_chat_list_id(list))
+            .map_err(map_messages_error)
+    }
+
+    /// Mutes the chat for `mute_for`, or unmutes it when `None`. The other
+    /// notification settings are kept as cached.
+    pub fn set_chat_mute(
+        &self,
+        chat_id: i64,
+        mute_for: Option<MuteDuration>,
+    ) -> Result<(), MessagesSourceError> {
+        let mut settings = self
+            .client
+            .cache()
+            .get_chat(chat_id)
+            .ok_or(MessagesSourceError::ChatNotFound)?
+            .notification_settings;
+        settings.use_default_mute_for = false;
+        settings.mute_for = mute_for.map_or(0, MuteDuration::seconds);
+
+        self.client
+            .set_chat_notification_settings(chat_id, settings)
+            .map_err(map_messages_error)
+    }
+
+    /// Caches the default notification settings of every scope, so chats
+    /// that follow them show the right mute state before TDLib pushes
+    /// `updateScopeNotificationSettings`.
+    pub fn seed_scope_notification_settings(&self) {
+        use tdlib_rs::enums::NotificationSettingsScope;
+
+        for scope in [
+            NotificationSettingsScope::PrivateChats,
+            NotificationSettingsScope::GroupChats,
+            NotificationSettingsScope::ChannelChats,
+        ] {
+            match self.client.get_scope_notification_settings(scope.clone()) {
+                Ok(settings) => self
+                    .client
+                    .cache()
+                    .update_scope_notification_settings(&scope, settings),
+                Err(error) => {
+                    tracing::warn!(?scope, error = %error, "failed to load scope notification settings")
+                }
+            }
+        }
+    }
+
+    /// Leaves a group or channel.
+    pub fn leave_chat(&self, chat_id: i64) -> Result<(), MessagesSourceError> {
+        self.client.leave_chat(chat_id).map_err(map_messages_error)
+    }
+
+    /// Deletes a chat for all its members.
+    pub fn delete_chat(&self, chat_id: i64) -> Result<(), MessagesSourceError> {
+        self.client.delete_chat(chat_id).map_err(map_messages_error)
+    }
+
+    /// Deletes the chat's messages; see [`TdLibClient::delete_chat_history`].
+    pub fn delete_chat_history(
+        &self,
+        chat_id: i64,
+        remove_from_chat_list: bool,
+        revoke: bool,
+    ) -> Result<(), MessagesSourceError> {
+        self.client
+            .delete_chat_history(chat_id, remove_from_chat_list, revoke)
+            .map_err(map_messages_error)
+    }
+
+    /// Blocks or unblocks the user of a private chat. Blocking uses the
+    /// main block list.
+    pub fn set_chat_blocked(&self, chat_id: i64, blocked: bool) -> Result<(), MessagesSourceError> {
+        let chat = self
+            .client
+            .cache()
+            .get_chat(chat_id)
+            .ok_or(MessagesSourceError::ChatNotFound)?;
+        let tdlib_rs::enums::ChatType::Private(private) = chat.r#type else {
+            return Err(MessagesSourceError::ChatNotFound);
+        };
+        let sender = tdlib_rs::enums::MessageSender::User(tdlib_rs::types::MessageSenderUser {
+            user_id: private.user_id,
+        });
+        let block_list = blocked.then_some(tdlib_rs::enums::BlockList::Main);
+
+        self.client
+            .set_message_sender_block_list(sender, block_list)
+            .map_err(map_messages_error)
+    }
+
     /// Triggers an asynchronous file download.
     pub fn download_file(&self, file_id: i32) -> Result<(), MessagesSourceError> {
         self.client
@@ -120,6 +343,62 @@ impl TdLibAuthBackend {
             .map_err(map_messages_error)
     }
 
+    /// Sets or clears the chat's "marked as unread" flag.
+    pub fn set_chat_marked_as_unread(
+        &self,
+        chat_id: i64,
+        marked: bool,
+    ) -> Result<(), MessagesSourceError> {
+        self.client
+            .toggle_chat_is_marked_as_unread(chat_id, marked)
+            .map_err(map_messages_error)
+    }
+
+    /// Pins or unpins a chat in `list`.
+    pub fn set_chat_pinned(
+        &self,
+        chat_id: i64,
+        list: ChatListId,
+        pinned: bool,
+    ) -> Result<(), MessagesSourceError> {
+        self.client
+            .toggle_chat_is_pinned(tdlib_mappers::map_chat_list_id(list), chat_id, pinned)
+            .map_err(map_messages_error)
+    }
+
+    /// Reorders the pinned chats of `list`.
+    pub fn set_pinned_chats(
+        &self,
+        list: ChatListId,
+        chat_ids: Vec<i64>,
+    ) -> Result<(), MessagesSourceError> {
+        self.client
+            .set_pinned_chats(tdlib_mappers::map_chat_list_id(list), chat_ids)
+            .map_err(map_messages_error)
+    }
+
+    /// Broadcasts a chat action to the chat or forum topic.
+    pub fn send_chat_action(
+        &self,
+        chat_id: i64,
+        topic_id: Option<i32>,
+        action: ChatAction,
+    ) -> Result<(), MessagesSourceError> {
+        let action = match action {
+            ChatAction::Typing => Some(tdlib_rs::enums::ChatAction::Typing),
+            ChatAction::RecordingVoiceNote => Some(tdlib_rs::enums::ChatAction::RecordingVoiceNote),
+            ChatAction::UploadingVoiceNote => {
+                Some(tdlib_rs::enums::ChatAction::UploadingVoiceNote(
+                    tdlib_rs::types::ChatActionUploadingVoiceNote { progress: 0 },
+                ))
+            }
+            ChatAction::Cancel => None,
+        };
+        self.client
+            .send_chat_action(chat_id, topic_id, action)
+            .map_err(map_messages_error)
+    }
+
     pub fn delete_messages(
         &self,
         chat_id: i64,
@@ -131,6 +410,40 @@ impl TdLibAuthBackend {
             .map_err(map_messages_error)
     }
 
+    /// Combines `getMessageProperties` of every message: an option is
+    /// allowed only when every message allows it.
+    pub fn delete_permissions(
+        &self,
+        chat_id: i64,
+        message_ids: &[i64],
+    ) -> Result<DeletePermissions, MessagesSourceError> {
+        let all = DeletePermissions {
+            for_self: true,
+            for_everyone: true,
+        };
+        message_ids.iter().try_fold(all, |acc, &message_id| {
+            let props = self
+                .client
+                .get_message_properties(chat_id, message_id)
+                .map_err(map_messages_error)?;
+            Ok(acc.intersect(DeletePermissions {
+                for_self: props.can_be_deleted_only_for_self,
+                for_everyone: props.can_be_deleted_for_all_users,
+            }))
+        })
+    }
+
+    pub fn forward_messages(
+        &self,
+        to_chat_id: i64,
+        from_chat_id: i64,
+        message_ids: Vec<i64>,
+    ) -> Result<(), MessagesSourceError> {
+        self.client
+            .forward_messages(to_chat_id, from_chat_id, message_ids)
+            .map_err(map_messages_error)
+    }
+
     /// Fetches up to `limit` messages using paginated `getChatHistory` or
     /// `getForumTopicHistory` calls (depending on `topic_id`).
     fn fetch_messages_paginated(
@@ -175,38 +488,7 @@ impl TdLibAuthBackend {
             "message pagination complete"
         );
 
-        // Convert to domain messages (accumulated is newest-first)
-        let cache = self.client.cache();
-        let resolve_user = |user_id: i64| {
-            cache
-                .get_user(user_id)
-                .map(|u| tdlib_mappers::format_user_name(&u))
-        };
-
-        let mut messages: Vec<Message> = td_messages
-            .iter()
-            .map(|msg| {
-                let sender_name = self.resolve_message_sender_name(msg);
-                let reply_to = self.resolve_reply_info(msg);
-                let forward_info = self.resolve_forward_info(msg);
-                tdlib_mappers::map_tdlib_message_to_domain(
-                    msg,
-                    sender_name,
-                    reply_to,
-                    forward_info,
-                    resolve_user,
-                )
-            })
-            .collect();
-
-        enrich_same_chat_reply_info(&td_messages, &mut messages, |chat_id, message_id| {
-            self.resolve_external_reply_target(chat_id, message_id)
-        });
-
-        // Reverse to get oldest first (UI expects chronological order)
-        messages.reverse();
-
-        Ok(messages)
+        Ok(self.map_newest_first_page(&td_messages))
     }
 
     /// Sends a text message to a chat or forum topic.
@@ -216,9 +498,11 @@ impl TdLibAuthBackend {
         topic_id: Option<i32>,
         text: &str,
         reply_to_message_id: Option<i64>,
+        mentions: &[TextMention],
     ) -> Result<(), SendMessageSourceError> {
+        let entities = tdlib_mappers::mention_entities(text, mentions);
         self.client
-            .send_message(chat_id, topic_id, text, reply_to_message_id)
+            .send_message(chat_id, topic_id, text, reply_to_message_id, entities)
             .map_err(map_send_message_error)?;
 
         tracing::debug!(
@@ -272,6 +556,51 @@ impl TdLibAuthBackend {
         Ok(())
     }
 
+    /// Maps a newest-first TDLib history page to domain messages in
+    /// chronological order (oldest first), as the UI expects.
+    fn map_newest_first_page(&self, td_messages: &[tdlib_rs::types::Message]) -> Vec<Message> {
+        let cache = self.client.cache();
+        let resolve_user = |user_id: i64| {
+            cache
+                .get_user(user_id)
+                .map(|u| tdlib_mappers::format_user_name(&u))
+        };
+
+        let mut messages: Vec<Message> = td_messages
+            .iter()
+            .map(|msg| {
+                let sender_name = self.resolve_message_sender_name(msg);
+                let reply_to = self.resolve_reply_info(msg);
+                let forward_info = self.resolve_forward_info(msg);
+                tdlib_mappers::map_tdlib_message_to_domain(
+                    msg,
+                    sender_name,
+                    reply_to,
+                    forward_info,
+                    resolve_user,
+                )
+            })
+            .collect();
+
+        enrich_same_chat_reply_info(td_messages, &mut messages, |chat_id, message_id| {
+            self.resolve_external_reply_target(chat_id, message_id)
+        });
+
+        for (raw, message) in td_messages.iter().zip(messages.iter_mut()) {
+            attach_inline_bot_username(raw, cache, message);
+        }
+
+        if let Some(chat) = td_messages.first().and_then(|m| cache.get_chat(m.chat_id)) {
+            for message in &mut messages {
+                message.apply_outbox_read(chat.last_read_outbox_message_id);
+                keep_active_reply_keyboard(&chat, message);
+            }
+        }
+
+        messages.reverse();
+        messages
+    }
+
     /// Resolves the sender name for a message.
     fn resolve_message_sender_name(&self, msg: &tdlib_rs::types::Message) -> String {
         resolve_sender_name(self.client.cache(), &self.client, msg)
@@ -498,6 +827,39 @@ fn extract_forward_info_from_cache(
     )
 }
 
+/// Drops the reply keyboard of messages that are no longer the chat's
+/// active reply markup (removed or replaced by a newer keyboard).
+fn keep_active_reply_keyboard(chat: &tdlib_rs::types::Chat, message: &mut Message) {
+    if message.id != chat.reply_markup_message_id {
+        message.reply_keyboard = None;
+    }
+}
+
+/// Re-maps the inline keyboard with the owning bot's username once it is
+/// known from the cache, so switch-inline buttons can prefill `@bot query`.
+fn attach_inline_bot_username(
+    msg: &tdlib_rs::types::Message,
+    cache: &TdLibCache,
+    message: &mut Message,
+) {
+    let has_switch_inline = message.inline_keyboard.iter().flatten().any(|button| {
+        matches!(
+            button.action,
+            crate::domain::message::InlineButtonAction::SwitchInline { .. }
+        )
+    });
+    if !has_switch_inline {
+        return;
+    }
+
+    let username = tdlib_mappers::inline_keyboard_bot_user_id(msg)
+        .and_then(|user_id| cache.get_user(user_id))
+        .and_then(|user| user.usernames)
+        .and_then(|u| u.active_usernames.into_iter().next());
+    message.inline_keyboard =
+        tdlib_mappers::extract_inline_keyboard(msg.reply_markup.as_ref(), username.as_deref());
+}
+
 /// Maps raw TDLib messages to domain `Message` types.
 ///
 /// Uses the shared TDLib cache for sender name resolution (fast path).
@@ -595,6 +957,13 @@ impl crate::telegram::chat_updates::MessageMapper for TdLibMessageMapper {
             },
         );
 
+        attach_inline_bot_username(raw, cache, &mut mapped);
+
+        if let Some(chat) = cache.get_chat(raw.chat_id) {
+            mapped.apply_outbox_read(chat.last_read_outbox_message_id);
+            keep_active_reply_keyboard(&chat, &mut mapped);
+        }
+
         if let (Some(reply), Some(tdlib_rs::enums::MessageReplyTo::Message(info))) =
             (mapped.reply_to.as_mut(), raw.reply_to.as_ref())
         {
@@ -8,12 +8,19 @@
 //! - WaitPassword → check_authentication_password
 //! - Ready → authorization complete
 
+mod bot_commands;
 mod chat_details;
 mod chat_list;
+mod chat_members;
+mod contacts;
 mod error_mapping;
 mod forum;
+mod go_to_chat;
+mod inline_keyboard;
+mod links;
 mod message_details;
 mod messages;
+mod new_chat;
 mod reactions;
 
 use std::time::Duration;
@@ -0,0 +1,77 @@
+use crate::domain::new_chat_wizard_state::{CreatedChat, NewChatKind, NewChatRequest};
+use crate::usecases::new_chat::NewChatError;
+
+use super::chat_list::build_summaries_from_ids;
+use super::error_mapping::map_new_chat_error;
+use super::TdLibAuthBackend;
+
+impl TdLibAuthBackend {
+    /// Creates the chat described by the wizard. Only the creation itself
+    /// can fail the request: once the chat exists, members that could not
+    /// be added and a description that could not be set are reported in
+    /// the result so the user still lands in the new chat.
+    pub fn create_chat(&self, request: &NewChatRequest) -> Result<CreatedChat, NewChatError> {
+        let mut members_not_added = 0;
+        let mut description_failed = false;
+        let chat_id = match request.kind {
+            NewChatKind::BasicGroup => {
+                let chat_id = self
+                    .client
+                    .create_new_basic_group_chat(request.member_ids.clone(), request.title.clone())
+                    .map_err(map_new_chat_error)?;
+                // Basic groups take no description at creation.
+                if !request.description.is_empty() {
+                    if let Err(error) = self
+                        .client
+                        .set_chat_description(chat_id, request.description.clone())
+                    {
+                        tracing::warn!(chat_id, error = %error, "failed to set new group description");
+                        description_failed = true;
+                    }
+                }
+                chat_id
+            }
+            NewChatKind::Supergroup | NewChatKind::Forum | NewChatKind::Channel => {
+                let chat_id = self
+                    .client
+                    .create_new_supergroup_chat(
+                        request.title.clone(),
+                        request.kind == NewChatKind::Forum,
+                        request.kind == NewChatKind::Channel,
+                        request.description.clone(),
+                    )
+                    .map_err(map_new_chat_error)?;
+                if !request.member_ids.is_empty() {
+                    members_not_added = match self
+                        .client
+                        .add_chat_members(chat_id, request.member_ids.clone())
+                    {
+                        Ok(0) => 0,
+                        Ok(failed) => {
+                            tracing::warn!(
+                                chat_id,
+                                failed,
+                                "some members were not added to the new chat"
+                            );
+                            failed
+                        }
+                        Err(error) => {
+                            tracing::warn!(chat_id, error = %error, "failed to add members to the new chat");
+                            request.member_ids.len()
+                        }
+                    };
+                }
+                chat_id
+            }
+        };
+
+        let chat = build_summaries_from_ids(&self.client, vec![chat_id], false)
+            .pop()
+            .ok_or(NewChatError::Unavailable)?;
+        Ok(CreatedChat {
+            chat,
+            members_not_added,
+            description_failed,
+        })
+    }
+}
@@ -1,4 +1,4 @@
-use crate::domain::reaction_picker_state::{is_allowed_reaction, AvailableReaction};
+use crate::domain::reaction_picker_state::AvailableReaction;
 use crate::usecases::message_reactions::{
     AddReactionQuery, AvailableReactionsQuery, ReactionError,
 };
@@ -36,21 +36,22 @@ impl TdLibAuthBackend {
             })
             .unwrap_or_default();
 
-        let mut reactions = Vec::new();
-        for r in available
+        // Recent reactions come first so the picker can show them as their
+        // own section; the rest keep TDLib's top-then-popular order.
+        let recent = available.recent_reactions.iter().map(|r| (r, true));
+        let others = available
             .top_reactions
             .iter()
-            .chain(available.recent_reactions.iter())
             .chain(available.popular_reactions.iter())
-        {
+            .map(|r| (r, false));
+
+        let mut reactions = Vec::new();
+        for (r, is_recent) in recent.chain(others) {
             let emoji = match &r.r#type {
                 tdlib_rs::enums::ReactionType::Emoji(e) => &e.emoji,
                 tdlib_rs::enums::ReactionType::CustomEmoji(_) => continue,
                 tdlib_rs::enums::ReactionType::Paid => continue,
             };
-            if !is_allowed_reaction(emoji) {
-                continue;
-            }
             if reactions
                 .iter()
                 .any(|existing: &AvailableReaction| existing.emoji == *emoji)
@@ -62,6 +63,7 @@ impl TdLibAuthBackend {
                 emoji: emoji.clone(),
                 needs_premium: r.needs_premium,
                 is_chosen,
+                is_recent,
             });
         }
 
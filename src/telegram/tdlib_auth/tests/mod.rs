@@ -188,10 +188,12 @@ mod reply_enrichment {
             call_info: None,
             reply_to,
             forward_info: None,
-            reaction_count: 0,
+            reactions: Vec::new(),
             links: Vec::new(),
             is_edited: false,
             is_service: false,
+            inline_keyboard: Vec::new(),
+            reply_keyboard: None,
         }
     }
 
@@ -318,10 +320,12 @@ fn reply_sender_name_for_outgoing_message_is_you() {
         call_info: None,
         reply_to: None,
         forward_info: None,
-        reaction_count: 0,
+        reactions: Vec::new(),
         links: Vec::new(),
         is_edited: false,
         is_service: false,
+        inline_keyboard: Vec::new(),
+        reply_keyboard: None,
     };
 
     assert_eq!(reply_sender_name_for_message(&message), "You");
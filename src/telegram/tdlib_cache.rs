@@ -12,7 +12,11 @@ use std::collections::HashMap;
 use std::sync::atomic::{AtomicI64, Ordering};
 use std::sync::{Arc, RwLock};
 
-use tdlib_rs::types::{Chat, ChatPosition, ForumTopic, Supergroup, User};
+use tdlib_rs::enums::NotificationSettingsScope;
+use tdlib_rs::types::{
+    Chat, ChatNotificationSettings, ChatPosition, ForumTopic, ScopeNotificationSettings,
+    Supergroup, User,
+};
 
 /// Thread-safe cache for TDLib objects populated by the update loop.
 ///
@@ -36,6 +40,18 @@ struct CacheInner {
     /// unseeded chats are dropped, since a partial picture would produce a
     /// wrong unread-topic count.
     forum_topics: HashMap<i64, HashMap<i32, TopicReadState>>,
+    /// Default notification settings of the private, group and channel
+    /// scopes, indexed by [`scope_index`]. Chats that follow the default
+    /// take their mute state from here.
+    scope_notification_settings: [Option<ScopeNotificationSettings>; 3],
+}
+
+fn scope_index(scope: &NotificationSettingsScope) -> usize {
+    match scope {
+        NotificationSettingsScope::PrivateChats => 0,
+        NotificationSettingsScope::GroupChats => 1,
+        NotificationSettingsScope::ChannelChats => 2,
+    }
 }
 
 /// Read state of a single forum topic, sufficient to derive "has unread".
@@ -180,6 +196,14 @@ impl TdLibCache {
         }
     }
 
+    /// Updates the message whose custom reply keyboard is active in a cached chat.
+    pub fn update_chat_reply_markup(&self, chat_id: i64, reply_markup_message_id: i64) {
+        let mut inner = self.inner.write().expect("cache write lock poisoned");
+        if let Some(chat) = inner.chats.get_mut(&chat_id) {
+            chat.reply_markup_message_id = reply_markup_message_id;
+        }
+    }
+
     /// Updates unread reaction count for a cached chat.
     pub fn update_chat_unread_reaction_count(&self, chat_id: i64, unread_reaction_count: i32) {
         let mut inner = self.inner.write().expect("cache write lock poisoned");
@@ -188,6 +212,67 @@ impl TdLibCache {
         }
     }
 
+    /// Updates unread mention count for a cached chat.
+    pub fn update_chat_unread_mention_count(&self, chat_id: i64, unread_mention_count: i32) {
+        let mut inner = self.inner.write().expect("cache write lock poisoned");
+        if let Some(chat) = inner.chats.get_mut(&chat_id) {
+            chat.unread_mention_count = unread_mention_count;
+        }
+    }
+
+    /// Updates the "marked as unread" flag for a cached chat.
+    pub fn update_chat_is_marked_as_unread(&self, chat_id: i64, is_marked_as_unread: bool) {
+        let mut inner = self.inner.write().expect("cache write lock poisoned");
+        if let Some(chat) = inner.chats.get_mut(&chat_id) {
+            chat.is_marked_as_unread = is_marked_as_unread;
+        }
+    }
+
+    /// Updates the block list of a cached chat (blocked or unblocked).
+    pub fn update_chat_block_list(
+        &self,
+        chat_id: i64,
+        block_list: Option<tdlib_rs::enums::BlockList>,
+    ) {
+        let mut inner = self.inner.write().expect("cache write lock poisoned");
+        if let Some(chat) = inner.chats.get_mut(&chat_id) {
+            chat.block_list = block_list;
+        }
+    }
+
+    /// Updates notification settings (mute state) for a cached chat.
+    pub fn update_chat_notification_settings(
+        &self,
+        chat_id: i64,
+        notification_settings: ChatNotificationSettings,
+    ) {
+        let mut inner = self.inner.write().expect("cache write lock poisoned");
+        if let Some(chat) = inner.chats.get_mut(&chat_id) {
+            chat.notification_settings = notification_settings;
+        }
+    }
+
+    /// Stores the default notification settings of a scope, from
+    /// `updateScopeNotificationSettings` or `getScopeNotificationSettings`.
+    pub fn update_scope_notification_settings(
+        &self,
+        scope: &NotificationSettingsScope,
+        notification_settings: ScopeNotificationSettings,
+    ) {
+        let mut inner = self.inner.write().expect("cache write lock poisoned");
+        inner.scope_notification_settings[scope_index(scope)] = Some(notification_settings);
+    }
+
+    /// Looks up the default notification settings of a scope. `None` until
+    /// TDLib reported them.
+    pub fn get_scope_notification_settings(
+        &self,
+        scope: &NotificationSettingsScope,
+    ) -> Option<ScopeNotificationSettings> {
+        let inner = self.inner.read().expect("cache read lock poisoned");
+        inner.scope_notification_settings[scope_index(scope)].clone()
+    }
+
     /// Looks up a chat by ID. Returns a clone.
     pub fn get_chat(&self, chat_id: i64) -> Option<Chat> {
         let inner = self.inner.read().expect("cache read lock poisoned");
@@ -591,6 +676,62 @@ pub(crate) mod tests {
         assert_eq!(cached.last_read_inbox_message_id, 100);
     }
 
+    #[test]
+    fn update_chat_is_marked_as_unread_modifies_flag() {
+        let cache = TdLibCache::new();
+        cache.upsert_chat(make_test_chat(1, "Chat"));
+
+        cache.update_chat_is_marked_as_unread(1, true);
+
+        let cached = cache.get_chat(1).expect("chat should be cached");
+        assert!(cached.is_marked_as_unread);
+    }
+
+    #[test]
+    fn update_chat_block_list_modifies_block_list() {
+        let cache = TdLibCache::new();
+        cache.upsert_chat(make_test_chat(1, "Chat"));
+
+        cache.update_chat_block_list(1, Some(tdlib_rs::enums::BlockList::Main));
+
+        let cached = cache.get_chat(1).expect("chat should be cached");
+        assert_eq!(cached.block_list, Some(tdlib_rs::enums::BlockList::Main));
+    }
+
+    #[test]
+    fn update_chat_notification_settings_modifies_mute_for() {
+        let cache = TdLibCache::new();
+        cache.upsert_chat(make_test_chat(1, "Chat"));
+        let mut settings = make_test_chat(1, "Chat").notification_settings;
+        settings.mute_for = 3600;
+
+        cache.update_chat_notification_settings(1, settings);
+
+        let cached = cache.get_chat(1).expect("chat should be cached");
+        assert_eq!(cached.notification_settings.mute_for, 3600);
+    }
+
+    #[test]
+    fn scope_notification_settings_are_kept_per_scope() {
+        let cache = TdLibCache::new();
+        let scope = NotificationSettingsScope::ChannelChats;
+        assert!(cache.get_scope_notification_settings(&scope).is_none());
+
+        cache.update_scope_notification_settings(
+            &scope,
+            ScopeNotificationSettings {
+                mute_for: 3600,
+                ..Default::default()
+            },
+        );
+
+        let cached = cache.get_scope_notification_settings(&scope);
+        assert_eq!(cached.map(|s| s.mute_for), Some(3600));
+        assert!(cache
+            .get_scope_notification_settings(&NotificationSettingsScope::GroupChats)
+            .is_none());
+    }
+
     #[test]
     fn update_chat_read_outbox_modifies_read_id() {
         let cache = TdLibCache::new();
@@ -602,6 +743,17 @@ pub(crate) mod tests {
         assert_eq!(cached.last_read_outbox_message_id, 200);
     }
 
+    #[test]
+    fn update_chat_reply_markup_modifies_message_id() {
+        let cache = TdLibCache::new();
+        cache.upsert_chat(make_test_chat(1, "Bot"));
+
+        cache.update_chat_reply_markup(1, 42);
+
+        let cached = cache.get_chat(1).expect("chat should be cached");
+        assert_eq!(cached.reply_markup_message_id, 42);
+    }
+
     #[test]
     fn update_user_status_modifies_cached_user() {
         let cache = TdLibCache::new();
@@ -0,0 +1,101 @@
+use super::types::TdLibError;
+use super::TdLibClient;
+
+impl TdLibClient {
+    /// Creates a basic group with the given members and returns its chat
+    /// id. Members TDLib could not add are logged, not reported as errors.
+    pub fn create_new_basic_group_chat(
+        &self,
+        user_ids: Vec<i64>,
+        title: String,
+    ) -> Result<i64, TdLibError> {
+        let client_id = self.client_id;
+
+        self.block_on_request("createNewBasicGroupChat", async {
+            let tdlib_rs::enums::CreatedBasicGroupChat::CreatedBasicGroupChat(created) =
+                tdlib_rs::functions::create_new_basic_group_chat(user_ids, title, 0, client_id)
+                    .await
+                    .map_err(|e| TdLibError::Request {
+                        code: e.code,
+                        message: e.message,
+                    })?;
+
+            let failed = created.failed_to_add_members.failed_to_add_members.len();
+            if failed > 0 {
+                tracing::warn!(
+                    chat_id = created.chat_id,
+                    failed,
+                    "some members were not added to the new group"
+                );
+            }
+            Ok(created.chat_id)
+        })
+    }
+
+    /// Creates a supergroup, forum or channel and returns its chat id.
+    pub fn create_new_supergroup_chat(
+        &self,
+        title: String,
+        is_forum: bool,
+        is_channel: bool,
+        description: String,
+    ) -> Result<i64, TdLibError> {
+        let client_id = self.client_id;
+
+        self.block_on_request("createNewSupergroupChat", async {
+            tdlib_rs::functions::create_new_supergroup_chat(
+                title,
+                is_forum,
+                is_channel,
+                description,
+                None,
+                0,
+                false,
+                client_id,
+            )
+            .await
+            .map(|tdlib_rs::enums::Chat::Chat(chat)| chat.id)
+            .map_err(|e| TdLibError::Request {
+                code: e.code,
+                message: e.message,
+            })
+        })
+    }
+
+    /// Adds users to a supergroup or channel. Returns how many could not
+    /// be added, e.g. because of their privacy settings.
+    pub fn add_chat_members(&self, chat_id: i64, user_ids: Vec<i64>) -> Result<usize, TdLibError> {
+        let client_id = self.client_id;
+
+        self.block_on_request("addChatMembers", async {
+            tdlib_rs::functions::add_chat_members(chat_id, user_ids, client_id)
+                .await
+                .map(
+                    |tdlib_rs::enums::FailedToAddMembers::FailedToAddMembers(failed)| {
+                        failed.failed_to_add_members.len()
+                    },
+                )
+                .map_err(|e| TdLibError::Request {
+                    code: e.code,
+                    message: e.message,
+                })
+        })
+    }
+
+    pub fn set_chat_description(
+        &self,
+        chat_id: i64,
+        description: String,
+    ) -> Result<(), TdLibError> {
+        let client_id = self.client_id;
+
+        self.block_on_request("setChatDescription", async {
+            tdlib_rs::functions::set_chat_description(chat_id, description, client_id)
+                .await
+                .map_err(|e| TdLibError::Request {
+                    code: e.code,
+                    message: e.message,
+                })
+        })
+    }
+}
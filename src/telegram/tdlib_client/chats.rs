@@ -9,14 +9,19 @@ pub struct GetChatsResult {
 impl TdLibClient {
     /// Gets list of chat IDs from TDLib.
     ///
-    /// Returns up to `limit` chat IDs from the main chat list, sorted by TDLib's order.
+    /// Returns up to `limit` chat IDs from `list` (the main list or a folder),
+    /// sorted by TDLib's order.
     /// First attempts `loadChats` to fetch from the server, then reads local
     /// cache via `getChats`. If `loadChats` fails (e.g. no network), we still
     /// try `getChats` to return whatever is available from TDLib's local
     /// SQLite database — this keeps the chat list usable in offline scenarios.
     ///
     /// `all_loaded` is `true` when TDLib signals there are no more chats to fetch.
-    pub fn get_chats(&self, limit: i32) -> Result<GetChatsResult, TdLibError> {
+    pub fn get_chats(
+        &self,
+        list: tdlib_rs::enums::ChatList,
+        limit: i32,
+    ) -> Result<GetChatsResult, TdLibError> {
         let client_id = self.client_id;
 
         self.block_on_request("getChats", async {
@@ -24,12 +29,8 @@ impl TdLibClient {
 
             // Try to load fresh chats from the server. Failures are non-fatal:
             // TDLib's local cache may still have chats from previous sessions.
-            if let Err(e) = tdlib_rs::functions::load_chats(
-                Some(tdlib_rs::enums::ChatList::Main),
-                limit,
-                client_id,
-            )
-            .await
+            if let Err(e) =
+                tdlib_rs::functions::load_chats(Some(list.clone()), limit, client_id).await
             {
                 if e.code == TDLIB_ERROR_ALL_CHATS_LOADED {
                     tracing::debug!("load_chats returned 404: all chats already loaded");
@@ -44,16 +45,12 @@ impl TdLibClient {
             }
 
             // Read whatever chat IDs are available (server-fresh or locally cached).
-            let chats = tdlib_rs::functions::get_chats(
-                Some(tdlib_rs::enums::ChatList::Main),
-                limit,
-                client_id,
-            )
-            .await
-            .map_err(|e| TdLibError::Request {
-                code: e.code,
-                message: e.message,
-            })?;
+            let chats = tdlib_rs::functions::get_chats(Some(list), limit, client_id)
+                .await
+                .map_err(|e| TdLibError::Request {
+                    code: e.code,
+                    message: e.message,
+                })?;
 
             match chats {
                 tdlib_rs::enums::Chats::Chats(c) => {
@@ -72,6 +69,41 @@ impl TdLibClient {
         })
     }
 
+    /// Searches chats known locally by title and username, including chats
+    /// not loaded into any chat list yet.
+    pub fn search_chats(&self, query: String, limit: i32) -> Result<Vec<i64>, TdLibError> {
+        let client_id = self.client_id;
+
+        self.block_on_request("searchChats", async {
+            tdlib_rs::functions::search_chats(query, limit, client_id)
+                .await
+                .map(|tdlib_rs::enums::Chats::Chats(chats)| chats.chat_ids)
+                .map_err(|e| TdLibError::Request {
+                    code: e.code,
+                    message: e.message,
+                })
+        })
+    }
+
+    /// Searches public chats by title and username on the server.
+    pub fn search_chats_on_server(
+        &self,
+        query: String,
+        limit: i32,
+    ) -> Result<Vec<i64>, TdLibError> {
+        let client_id = self.client_id;
+
+        self.block_on_request("searchChatsOnServer", async {
+            tdlib_rs::functions::search_chats_on_server(query, limit, client_id)
+                .await
+                .map(|tdlib_rs::enums::Chats::Chats(chats)| chats.chat_ids)
+                .map_err(|e| TdLibError::Request {
+                    code: e.code,
+                    message: e.message,
+                })
+        })
+    }
+
     /// Gets the first page of forum topics for a forum supergroup chat.
     ///
     /// Up to `limit` topics, ordered by TDLib's `order` (highest first).
@@ -211,6 +243,39 @@ impl TdLibClient {
         })
     }
 
+    /// Searches members of a group that can be mentioned.
+    pub fn search_chat_members(
+        &self,
+        chat_id: i64,
+        query: &str,
+        limit: i32,
+    ) -> Result<Vec<tdlib_rs::types::ChatMember>, TdLibError> {
+        let client_id = self.client_id;
+        let query = query.to_owned();
+        let filter = tdlib_rs::enums::ChatMembersFilter::Mention(
+            tdlib_rs::types::ChatMembersFilterMention { topic_id: None },
+        );
+
+        self.block_on_request("searchChatMembers", async {
+            let members = tdlib_rs::functions::search_chat_members(
+                chat_id,
+                query,
+                limit,
+                Some(filter),
+                client_id,
+            )
+            .await
+            .map_err(|e| TdLibError::Request {
+                code: e.code,
+                message: e.message,
+            })?;
+
+            match members {
+                tdlib_rs::enums::ChatMembers::ChatMembers(m) => Ok(m.members),
+            }
+        })
+    }
+
     /// Informs TDLib that the chat is opened by the user.
     ///
     /// Many useful activities depend on the chat being opened or closed
@@ -229,6 +294,35 @@ impl TdLibClient {
         })
     }
 
+    /// Broadcasts a chat action (typing, recording, ...) to the chat or
+    /// forum topic. `None` cancels the currently active action.
+    pub fn send_chat_action(
+        &self,
+        chat_id: i64,
+        topic_id: Option<i32>,
+        action: Option<tdlib_rs::enums::ChatAction>,
+    ) -> Result<(), TdLibError> {
+        let client_id = self.client_id;
+
+        self.block_on_request("sendChatAction", async {
+            tdlib_rs::functions::send_chat_action(
+                chat_id,
+                topic_id.map(|id| {
+                    tdlib_rs::enums::MessageTopic::Forum(tdlib_rs::types::MessageTopicForum {
+                        forum_topic_id: id,
+                    })
+                }),
+                action,
+                client_id,
+            )
+            .await
+            .map_err(|e| TdLibError::Request {
+                code: e.code,
+                message: e.message,
+            })
+        })
+    }
+
     /// Informs TDLib that messages are being viewed by the user.
     ///
     /// This marks messages as read and updates view counters.
@@ -292,4 +386,197 @@ impl TdLibClient {
                 })
         })
     }
+
+    /// Moves a chat to another chat list. For the archive this archives the
+    /// chat; for the main list it unarchives it.
+    pub fn add_chat_to_list(
+        &self,
+        chat_id: i64,
+        list: tdlib_rs::enums::ChatList,
+    ) -> Result<(), TdLibError> {
+        let client_id = self.client_id;
+
+        self.block_on_request("addChatToList", async {
+            tdlib_rs::functions::add_chat_to_list(chat_id, list, client_id)
+                .await
+                .map_err(|e| TdLibError::Request {
+                    code: e.code,
+                    message: e.message,
+                })
+        })
+    }
+
+    /// Replaces a chat's notification settings (used to mute and unmute).
+    pub fn set_chat_notification_settings(
+        &self,
+        chat_id: i64,
+        notification_settings: tdlib_rs::types::ChatNotificationSettings,
+    ) -> Result<(), TdLibError> {
+        let client_id = self.client_id;
+
+        self.block_on_request("setChatNotificationSettings", async {
+            tdlib_rs::functions::set_chat_notification_settings(
+                chat_id,
+                notification_settings,
+                client_id,
+            )
+            .await
+            .map_err(|e| TdLibError::Request {
+                code: e.code,
+                message: e.message,
+            })
+        })
+    }
+
+    /// Reads the default notification settings of a scope.
+    pub fn get_scope_notification_settings(
+        &self,
+        scope: tdlib_rs::enums::NotificationSettingsScope,
+    ) -> Result<tdlib_rs::types::ScopeNotificationSettings, TdLibError> {
+        let client_id = self.client_id;
+
+        self.block_on_request("getScopeNotificationSettings", async {
+            let settings = tdlib_rs::functions::get_scope_notification_settings(scope, client_id)
+                .await
+                .map_err(|e| TdLibError::Request {
+                    code: e.code,
+                    message: e.message,
+                })?;
+
+            match settings {
+                tdlib_rs::enums::ScopeNotificationSettings::ScopeNotificationSettings(s) => Ok(s),
+            }
+        })
+    }
+
+    /// Pins or unpins a chat in a chat list.
+    pub fn toggle_chat_is_pinned(
+        &self,
+        list: tdlib_rs::enums::ChatList,
+        chat_id: i64,
+        is_pinned: bool,
+    ) -> Result<(), TdLibError> {
+        let client_id = self.client_id;
+
+        self.block_on_request("toggleChatIsPinned", async {
+            tdlib_rs::functions::toggle_chat_is_pinned(list, chat_id, is_pinned, client_id)
+                .await
+                .map_err(|e| TdLibError::Request {
+                    code: e.code,
+                    message: e.message,
+                })
+        })
+    }
+
+    /// Replaces the order of the pinned chats in a chat list.
+    pub fn set_pinned_chats(
+        &self,
+        list: tdlib_rs::enums::ChatList,
+        chat_ids: Vec<i64>,
+    ) -> Result<(), TdLibError> {
+        let client_id = self.client_id;
+
+        self.block_on_request("setPinnedChats", async {
+            tdlib_rs::functions::set_pinned_chats(list, chat_ids, client_id)
+                .await
+                .map_err(|e| TdLibError::Request {
+                    code: e.code,
+                    message: e.message,
+                })
+        })
+    }
+
+    /// Sets or clears the chat's "marked as unread" flag.
+    pub fn toggle_chat_is_marked_as_unread(
+        &self,
+        chat_id: i64,
+        is_marked_as_unread: bool,
+    ) -> Result<(), TdLibError> {
+        let client_id = self.client_id;
+
+        self.block_on_request("toggleChatIsMarkedAsUnread", async {
+            tdlib_rs::functions::toggle_chat_is_marked_as_unread(
+                chat_id,
+                is_marked_as_unread,
+                client_id,
+            )
+            .await
+            .map_err(|e| TdLibError::Request {
+                code: e.code,
+                message: e.message,
+            })
+        })
+    }
+
+    /// Leaves a group or channel.
+    pub fn leave_chat(&self, chat_id: i64) -> Result<(), TdLibError> {
+        let client_id = self.client_id;
+
+        self.block_on_request("leaveChat", async {
+            tdlib_rs::functions::leave_chat(chat_id, client_id)
+                .await
+                .map_err(|e| TdLibError::Request {
+                    code: e.code,
+                    message: e.message,
+                })
+        })
+    }
+
+    /// Deletes a chat along with all its messages for all members.
+    pub fn delete_chat(&self, chat_id: i64) -> Result<(), TdLibError> {
+        let client_id = self.client_id;
+
+        self.block_on_request("deleteChat", async {
+            tdlib_rs::functions::delete_chat(chat_id, client_id)
+                .await
+                .map_err(|e| TdLibError::Request {
+                    code: e.code,
+                    message: e.message,
+                })
+        })
+    }
+
+    /// Deletes all messages in a chat, optionally removing the chat from
+    /// the chat lists and revoking the messages for the other side.
+    pub fn delete_chat_history(
+        &self,
+        chat_id: i64,
+        remove_from_chat_list: bool,
+        revoke: bool,
+    ) -> Result<(), TdLibError> {
+        let client_id = self.client_id;
+
+        self.block_on_request("deleteChatHistory", async {
+            tdlib_rs::functions::delete_chat_history(
+                chat_id,
+                remove_from_chat_list,
+                revoke,
+                client_id,
+            )
+            .await
+            .map_err(|e| TdLibError::Request {
+                code: e.code,
+                message: e.message,
+            })
+        })
+    }
+
+    /// Adds a message sender to a block list, or removes it from all block
+    /// lists when `block_list` is `None`.
+    pub fn set_message_sender_block_list(
+        &self,
+        sender_id: tdlib_rs::enums::MessageSender,
+        block_list: Option<tdlib_rs::enums::BlockList>,
+    ) -> Result<(), TdLibError> {
+        let client_id = self.client_id;
+
+        self.block_on_request("setMessageSenderBlockList", async {
+            tdlib_rs::functions::set_message_sender_block_list(sender_id, block_list, client_id)
+                .await
+                .map_err(|e| TdLibError::Request {
+                    code: e.code,
+                    message: e.message,
+                })
+        })
+    }
 }
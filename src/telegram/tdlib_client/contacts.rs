@@ -0,0 +1,106 @@
+use super::types::TdLibError;
+use super::TdLibClient;
+
+impl TdLibClient {
+    /// Returns the user ids of all contacts.
+    pub fn get_contacts(&self) -> Result<Vec<i64>, TdLibError> {
+        let client_id = self.client_id;
+
+        self.block_on_request("getContacts", async {
+            tdlib_rs::functions::get_contacts(client_id)
+                .await
+                .map(|tdlib_rs::enums::Users::Users(users)| users.user_ids)
+                .map_err(|e| TdLibError::Request {
+                    code: e.code,
+                    message: e.message,
+                })
+        })
+    }
+
+    /// Adds a user to the contacts under the given name, without sharing
+    /// the account's phone number.
+    pub fn add_contact(
+        &self,
+        user_id: i64,
+        first_name: String,
+        last_name: String,
+    ) -> Result<(), TdLibError> {
+        let client_id = self.client_id;
+        let contact = tdlib_rs::types::ImportedContact {
+            first_name,
+            last_name,
+            ..Default::default()
+        };
+
+        self.block_on_request("addContact", async {
+            tdlib_rs::functions::add_contact(user_id, contact, false, client_id)
+                .await
+                .map_err(|e| TdLibError::Request {
+                    code: e.code,
+                    message: e.message,
+                })
+        })
+    }
+
+    pub fn remove_contacts(&self, user_ids: Vec<i64>) -> Result<(), TdLibError> {
+        let client_id = self.client_id;
+
+        self.block_on_request("removeContacts", async {
+            tdlib_rs::functions::remove_contacts(user_ids, client_id)
+                .await
+                .map_err(|e| TdLibError::Request {
+                    code: e.code,
+                    message: e.message,
+                })
+        })
+    }
+
+    /// Searches the user's contacts by name or username.
+    pub fn search_contacts(&self, query: String, limit: i32) -> Result<Vec<i64>, TdLibError> {
+        let client_id = self.client_id;
+
+        self.block_on_request("searchContacts", async {
+            tdlib_rs::functions::search_contacts(query, limit, client_id)
+                .await
+                .map(|tdlib_rs::enums::Users::Users(users)| users.user_ids)
+                .map_err(|e| TdLibError::Request {
+                    code: e.code,
+                    message: e.message,
+                })
+        })
+    }
+
+    /// Finds a user by phone number, asking the server when the number is
+    /// not known locally.
+    pub fn search_user_by_phone_number(
+        &self,
+        phone_number: String,
+    ) -> Result<tdlib_rs::types::User, TdLibError> {
+        let client_id = self.client_id;
+
+        self.block_on_request("searchUserByPhoneNumber", async {
+            tdlib_rs::functions::search_user_by_phone_number(phone_number, false, client_id)
+                .await
+                .map(|tdlib_rs::enums::User::User(user)| user)
+                .map_err(|e| TdLibError::Request {
+                    code: e.code,
+                    message: e.message,
+                })
+        })
+    }
+
+    /// Returns the private chat with a user, creating it if needed.
+    pub fn create_private_chat(&self, user_id: i64) -> Result<tdlib_rs::types::Chat, TdLibError> {
+        let client_id = self.client_id;
+
+        self.block_on_request("createPrivateChat", async {
+            tdlib_rs::functions::create_private_chat(user_id, false, client_id)
+                .await
+                .map(|tdlib_rs::enums::Chat::Chat(chat)| chat)
+                .map_err(|e| TdLibError::Request {
+                    code: e.code,
+                    message: e.message,
+                })
+        })
+    }
+}
@@ -0,0 +1,112 @@
+use super::types::TdLibError;
+use super::TdLibClient;
+
+impl TdLibClient {
+    /// Returns an HTTPS link to a message in a supergroup or channel.
+    pub fn get_message_link(
+        &self,
+        chat_id: i64,
+        message_id: i64,
+    ) -> Result<tdlib_rs::types::MessageLink, TdLibError> {
+        let client_id = self.client_id;
+
+        self.block_on_request("getMessageLink", async {
+            tdlib_rs::functions::get_message_link(chat_id, message_id, 0, false, false, client_id)
+                .await
+                .map(|tdlib_rs::enums::MessageLink::MessageLink(link)| link)
+                .map_err(|e| TdLibError::Request {
+                    code: e.code,
+                    message: e.message,
+                })
+        })
+    }
+
+    /// Classifies a `t.me` / `tg://` link.
+    pub fn get_internal_link_type(
+        &self,
+        link: String,
+    ) -> Result<tdlib_rs::enums::InternalLinkType, TdLibError> {
+        let client_id = self.client_id;
+
+        self.block_on_request("getInternalLinkType", async {
+            tdlib_rs::functions::get_internal_link_type(link, client_id)
+                .await
+                .map_err(|e| TdLibError::Request {
+                    code: e.code,
+                    message: e.message,
+                })
+        })
+    }
+
+    /// Resolves a public chat by username.
+    pub fn search_public_chat(
+        &self,
+        username: String,
+    ) -> Result<tdlib_rs::types::Chat, TdLibError> {
+        let client_id = self.client_id;
+
+        self.block_on_request("searchPublicChat", async {
+            tdlib_rs::functions::search_public_chat(username, client_id)
+                .await
+                .map(|tdlib_rs::enums::Chat::Chat(chat)| chat)
+                .map_err(|e| TdLibError::Request {
+                    code: e.code,
+                    message: e.message,
+                })
+        })
+    }
+
+    /// Resolves a message link to its chat and message.
+    pub fn get_message_link_info(
+        &self,
+        url: String,
+    ) -> Result<tdlib_rs::types::MessageLinkInfo, TdLibError> {
+        let client_id = self.client_id;
+
+        self.block_on_request("getMessageLinkInfo", async {
+            tdlib_rs::functions::get_message_link_info(url, client_id)
+                .await
+                .map(|tdlib_rs::enums::MessageLinkInfo::MessageLinkInfo(info)| info)
+                .map_err(|e| TdLibError::Request {
+                    code: e.code,
+                    message: e.message,
+                })
+        })
+    }
+
+    /// Returns information about the chat behind an invite link.
+    pub fn check_chat_invite_link(
+        &self,
+        invite_link: String,
+    ) -> Result<tdlib_rs::types::ChatInviteLinkInfo, TdLibError> {
+        let client_id = self.client_id;
+
+        self.block_on_request("checkChatInviteLink", async {
+            tdlib_rs::functions::check_chat_invite_link(invite_link, client_id)
+                .await
+                .map(|tdlib_rs::enums::ChatInviteLinkInfo::ChatInviteLinkInfo(info)| info)
+                .map_err(|e| TdLibError::Request {
+                    code: e.code,
+                    message: e.message,
+                })
+        })
+    }
+
+    /// Joins a chat by invite link.
+    pub fn join_chat_by_invite_link(
+        &self,
+        invite_link: String,
+    ) -> Result<tdlib_rs::types::Chat, TdLibError> {
+        let client_id = self.client_id;
+
+        self.block_on_request("joinChatByInviteLink", async {
+            tdlib_rs::functions::join_chat_by_invite_link(invite_link, client_id)
+                .await
+                .map(|tdlib_rs::enums::Chat::Chat(chat)| chat)
+                .map_err(|e| TdLibError::Request {
+                    code: e.code,
+                    message: e.message,
+                })
+        })
+    }
+}
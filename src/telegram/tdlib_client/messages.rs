@@ -153,15 +153,13 @@ impl TdLibClient {
         topic_id: Option<i32>,
         text: &str,
         reply_to_message_id: Option<i64>,
+        entities: Vec<tdlib_rs::types::TextEntity>,
     ) -> Result<tdlib_rs::types::Message, TdLibError> {
         let client_id = self.client_id;
         let text = text.to_owned();
 
         self.block_on_request("sendMessage", async {
-            let formatted_text = tdlib_rs::types::FormattedText {
-                text,
-                entities: vec![],
-            };
+            let formatted_text = tdlib_rs::types::FormattedText { text, entities };
 
             let input_content = tdlib_rs::enums::InputMessageContent::InputMessageText(
                 tdlib_rs::types::InputMessageText {
@@ -401,6 +399,74 @@ impl TdLibClient {
         })
     }
 
+    /// Returns the last message sent in a chat no later than `date`
+    /// (Unix seconds). TDLib answers with a 404 error when there is none.
+    pub fn get_chat_message_by_date(
+        &self,
+        chat_id: i64,
+        date: i32,
+    ) -> Result<tdlib_rs::types::Message, TdLibError> {
+        let client_id = self.client_id;
+
+        self.block_on_request("getChatMessageByDate", async {
+            let msg = tdlib_rs::functions::get_chat_message_by_date(chat_id, date, client_id)
+                .await
+                .map_err(|e| TdLibError::Request {
+                    code: e.code,
+                    message: e.message,
+                })?;
+
+            match msg {
+                tdlib_rs::enums::Message::Message(m) => Ok(m),
+            }
+        })
+    }
+
+    /// Searches a chat (or forum topic) for messages matching `filter`.
+    ///
+    /// Returns one page of messages in reverse chronological order (newest
+    /// first), starting before `from_message_id` (0 for the last message in
+    /// the chat). The page's `next_from_message_id` is 0 when there are no
+    /// more results.
+    pub fn search_chat_messages(
+        &self,
+        chat_id: i64,
+        topic_id: Option<i32>,
+        filter: tdlib_rs::enums::SearchMessagesFilter,
+        from_message_id: i64,
+        limit: i32,
+    ) -> Result<tdlib_rs::types::FoundChatMessages, TdLibError> {
+        let client_id = self.client_id;
+        let topic = topic_id.map(|forum_topic_id| {
+            tdlib_rs::enums::MessageTopic::Forum(tdlib_rs::types::MessageTopicForum {
+                forum_topic_id,
+            })
+        });
+
+        self.block_on_request("searchChatMessages", async {
+            let found = tdlib_rs::functions::search_chat_messages(
+                chat_id,
+                topic,
+                String::new(),
+                None,
+                from_message_id,
+                0,
+                limit,
+                Some(filter),
+                client_id,
+            )
+            .await
+            .map_err(|e| TdLibError::Request {
+                code: e.code,
+                message: e.message,
+            })?;
+
+            match found {
+                tdlib_rs::enums::FoundChatMessages::FoundChatMessages(f) => Ok(f),
+            }
+        })
+    }
+
     pub fn get_message_available_reactions(
         &self,
         chat_id: i64,
@@ -473,6 +539,29 @@ impl TdLibClient {
         })
     }
 
+    /// Presses an inline keyboard callback button and waits for the bot's answer.
+    pub fn get_callback_query_answer(
+        &self,
+        chat_id: i64,
+        message_id: i64,
+        data: String,
+    ) -> Result<tdlib_rs::types::CallbackQueryAnswer, TdLibError> {
+        let client_id = self.client_id;
+        let payload = tdlib_rs::enums::CallbackQueryPayload::Data(
+            tdlib_rs::types::CallbackQueryPayloadData { data },
+        );
+
+        self.block_on_request("getCallbackQueryAnswer", async {
+            tdlib_rs::functions::get_callback_query_answer(chat_id, message_id, payload, client_id)
+                .await
+                .map(|tdlib_rs::enums::CallbackQueryAnswer::CallbackQueryAnswer(answer)| answer)
+                .map_err(|e| TdLibError::Request {
+                    code: e.code,
+                    message: e.message,
+                })
+        })
+    }
+
     /// Deletes messages from a chat.
     ///
     /// When `revoke` is true, the messages are deleted for all participants
@@ -494,4 +583,33 @@ impl TdLibClient {
                 })
         })
     }
+
+    /// Forwards messages to another chat, keeping the original author.
+    pub fn forward_messages(
+        &self,
+        to_chat_id: i64,
+        from_chat_id: i64,
+        message_ids: Vec<i64>,
+    ) -> Result<(), TdLibError> {
+        let client_id = self.client_id;
+
+        self.block_on_request("forwardMessages", async {
+            tdlib_rs::functions::forward_messages(
+                to_chat_id,
+                None,
+                from_chat_id,
+                message_ids,
+                None,
+                false,
+                false,
+                client_id,
+            )
+            .await
+            .map(|_| ())
+            .map_err(|e| TdLibError::Request {
+                code: e.code,
+                message: e.message,
+            })
+        })
+    }
 }
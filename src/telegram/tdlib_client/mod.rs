@@ -6,7 +6,10 @@
 //! - Proper shutdown handling
 
 mod auth;
+mod chat_creation;
 mod chats;
+mod contacts;
+mod links;
 mod log_setup;
 mod messages;
 mod request_guard;
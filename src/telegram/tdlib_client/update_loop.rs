@@ -6,7 +6,7 @@ use super::types::{AuthStateUpdate, UPDATE_POLL_INTERVAL};
 use super::TdLibClient;
 use crate::domain::events::ConnectivityStatus;
 use crate::telegram::tdlib_cache::TdLibCache;
-use crate::telegram::tdlib_mappers::sum_reaction_counts;
+use crate::telegram::tdlib_mappers::{map_message_reactions, map_td_chat_list};
 use crate::telegram::tdlib_updates::TdLibUpdate;
 
 impl TdLibClient {
@@ -20,6 +20,16 @@ impl TdLibClient {
         let _ = update_tx.send(TdLibUpdate::ChatUnreadReactionCount { chat_id });
     }
 
+    pub(super) fn publish_unread_mention_count(
+        update_tx: &mpsc::Sender<TdLibUpdate>,
+        cache: &TdLibCache,
+        chat_id: i64,
+        unread_mention_count: i32,
+    ) {
+        cache.update_chat_unread_mention_count(chat_id, unread_mention_count);
+        let _ = update_tx.send(TdLibUpdate::ChatUnreadMentionCount { chat_id });
+    }
+
     /// Background loop that receives and processes TDLib updates.
     ///
     /// This is a fully synchronous function that runs in a dedicated thread.
@@ -134,6 +144,12 @@ impl TdLibClient {
                                 new_content: Box::new(u.new_content),
                             });
                         }
+                        Update::MessageEdited(u) => {
+                            let _ = update_tx.send(TdLibUpdate::MessageEdited {
+                                chat_id: u.chat_id,
+                                message_id: u.message_id,
+                            });
+                        }
                         Update::DeleteMessages(u) => {
                             let _ = update_tx.send(TdLibUpdate::DeleteMessages {
                                 chat_id: u.chat_id,
@@ -171,8 +187,44 @@ impl TdLibClient {
                         }
                         Update::ChatReadOutbox(u) => {
                             cache.update_chat_read_outbox(u.chat_id, u.last_read_outbox_message_id);
+                            let _ = update_tx.send(TdLibUpdate::ChatReadOutbox {
+                                chat_id: u.chat_id,
+                                last_read_outbox_message_id: u.last_read_outbox_message_id,
+                            });
+                        }
+                        Update::ChatIsMarkedAsUnread(u) => {
+                            cache.update_chat_is_marked_as_unread(u.chat_id, u.is_marked_as_unread);
+                            let _ = update_tx
+                                .send(TdLibUpdate::ChatIsMarkedAsUnread { chat_id: u.chat_id });
+                        }
+
+                        Update::ChatBlockList(u) => {
+                            cache.update_chat_block_list(u.chat_id, u.block_list);
+                            let _ =
+                                update_tx.send(TdLibUpdate::ChatBlockList { chat_id: u.chat_id });
+                        }
+
+                        Update::ChatNotificationSettings(u) => {
+                            cache.update_chat_notification_settings(
+                                u.chat_id,
+                                u.notification_settings,
+                            );
+                            let _ = update_tx
+                                .send(TdLibUpdate::ChatNotificationSettings { chat_id: u.chat_id });
+                        }
+
+                        Update::ScopeNotificationSettings(u) => {
+                            cache.update_scope_notification_settings(
+                                &u.scope,
+                                u.notification_settings,
+                            );
+                            let _ = update_tx.send(TdLibUpdate::ScopeNotificationSettings);
+                        }
+
+                        Update::ChatReplyMarkup(u) => {
+                            cache.update_chat_reply_markup(u.chat_id, u.reply_markup_message_id);
                             let _ =
-                                update_tx.send(TdLibUpdate::ChatReadOutbox { chat_id: u.chat_id });
+                                update_tx.send(TdLibUpdate::ChatReplyMarkup { chat_id: u.chat_id });
                         }
 
                         // User status updates — write through to cache
@@ -191,11 +243,11 @@ impl TdLibClient {
                             );
                         }
                         Update::MessageInteractionInfo(u) => {
-                            let reaction_count = sum_reaction_counts(u.interaction_info.as_ref());
+                            let reactions = map_message_reactions(u.interaction_info.as_ref());
                             let _ = update_tx.send(TdLibUpdate::MessageInteractionInfoChanged {
                                 chat_id: u.chat_id,
                                 message_id: u.message_id,
-                                reaction_count,
+                                reactions,
                             });
                         }
                         Update::MessageUnreadReactions(u) => {
@@ -207,6 +259,24 @@ impl TdLibClient {
                             );
                         }
 
+                        // Mention updates
+                        Update::ChatUnreadMentionCount(u) => {
+                            Self::publish_unread_mention_count(
+                                &update_tx,
+                                &cache,
+                                u.chat_id,
+                                u.unread_mention_count,
+                            );
+                        }
+                        Update::MessageMentionRead(u) => {
+                            Self::publish_unread_mention_count(
+                                &update_tx,
+                                &cache,
+                                u.chat_id,
+                                u.unread_mention_count,
+                            );
+                        }
+
                         // Forum topic updates
                         Update::ForumTopicInfo(u) => {
                             let _ = update_tx.send(TdLibUpdate::ForumTopicInfoChanged {
@@ -240,6 +310,30 @@ impl TdLibClient {
                             });
                         }
 
+                        // Chat folders shown as tabs above the chat list
+                        Update::ChatFolders(u) => {
+                            let folders = u
+                                .chat_folders
+                                .into_iter()
+                                .map(|f| crate::domain::chat_folder::ChatFolder {
+                                    id: f.id,
+                                    title: f.name.text.text,
+                                })
+                                .collect();
+                            let _ = update_tx.send(TdLibUpdate::ChatFolders {
+                                folders,
+                                main_position: u.main_chat_list_position.max(0) as usize,
+                            });
+                        }
+
+                        Update::UnreadChatCount(u) => {
+                            let _ = update_tx.send(TdLibUpdate::UnreadChatCount {
+                                list: map_td_chat_list(&u.chat_list),
+                                unread_count: u.unread_unmuted_count.max(0) as u32,
+                                total_count: u.total_count.max(0) as u32,
+                            });
+                        }
+
                         // Chat action updates (typing indicators)
                         Update::ChatAction(u) => {
                             let sender_user_id = match u.sender_id {
@@ -1,7 +1,11 @@
+use tdlib_rs::enums::ChatList as TdChatList;
 use tdlib_rs::enums::ChatType as TdChatType;
+use tdlib_rs::enums::NotificationSettingsScope;
 use tdlib_rs::types::Chat as TdChat;
+use tdlib_rs::types::{ChatNotificationSettings, ScopeNotificationSettings};
 
 use crate::domain::chat::{ChatSummary, ChatType, OutgoingReadStatus};
+use crate::domain::chat_folder::ChatListId;
 
 use super::extract_message_preview;
 
@@ -14,18 +18,19 @@ use super::extract_message_preview;
 /// associated `Supergroup`, not on the chat itself, so the caller is expected
 /// to look it up via `TdLibCache::get_supergroup` for supergroup chats and
 /// pass `false` otherwise.
+///
+/// `scope_settings` are the defaults of the chat's [`notification_scope`],
+/// which decide whether a chat that follows them is muted.
 pub fn map_chat_to_summary(
     chat: &TdChat,
     sender_name: Option<String>,
     is_sender_online: Option<bool>,
     is_bot: bool,
     is_forum: bool,
+    scope_settings: Option<&ScopeNotificationSettings>,
 ) -> ChatSummary {
     let chat_type = map_chat_type(&chat.r#type);
-    let is_pinned = chat
-        .positions
-        .iter()
-        .any(|pos| matches!(&pos.list, tdlib_rs::enums::ChatList::Main) && pos.is_pinned);
+    let is_pinned = is_pinned_in_list(chat, &TdChatList::Main);
 
     let (last_message_preview, last_message_unix_ms, outgoing_status, last_message_id) =
         extract_last_message_info(chat, sender_name.is_some());
@@ -60,8 +65,64 @@ pub fn map_chat_to_summary(
         outgoing_status,
         last_message_id,
         unread_reaction_count: chat.unread_reaction_count.max(0) as u32,
+        unread_mention_count: chat.unread_mention_count.max(0) as u32,
         is_forum,
         unread_topic_count: None,
+        is_muted: is_chat_muted(&chat.notification_settings, scope_settings),
+        is_marked_as_unread: chat.is_marked_as_unread,
+        is_blocked: chat.block_list.is_some(),
+        username: None,
+    }
+}
+
+/// Whether the chat is muted, by its own settings or, when it follows the
+/// default, by its scope's. Unknown scope settings count as unmuted.
+pub fn is_chat_muted(
+    settings: &ChatNotificationSettings,
+    scope_settings: Option<&ScopeNotificationSettings>,
+) -> bool {
+    if settings.use_default_mute_for {
+        scope_settings.is_some_and(|scope| scope.mute_for > 0)
+    } else {
+        settings.mute_for > 0
+    }
+}
+
+/// The notification scope whose defaults apply to a chat of this type.
+pub fn notification_scope(td_type: &TdChatType) -> NotificationSettingsScope {
+    match td_type {
+        TdChatType::Private(_) | TdChatType::Secret(_) => NotificationSettingsScope::PrivateChats,
+        TdChatType::BasicGroup(_) => NotificationSettingsScope::GroupChats,
+        TdChatType::Supergroup(sg) if sg.is_channel => NotificationSettingsScope::ChannelChats,
+        TdChatType::Supergroup(_) => NotificationSettingsScope::GroupChats,
+    }
+}
+
+/// Whether the chat is pinned in the given chat list. Pins are per list, so
+/// a chat pinned in a folder is not necessarily pinned in the main list.
+pub fn is_pinned_in_list(chat: &TdChat, list: &TdChatList) -> bool {
+    chat.positions
+        .iter()
+        .any(|pos| &pos.list == list && pos.is_pinned)
+}
+
+/// Maps a domain chat list to the TDLib list it stands for.
+pub fn map_chat_list_id(list: ChatListId) -> TdChatList {
+    match list {
+        ChatListId::Main => TdChatList::Main,
+        ChatListId::Folder(chat_folder_id) => {
+            TdChatList::Folder(tdlib_rs::types::ChatListFolder { chat_folder_id })
+        }
+        ChatListId::Archive => TdChatList::Archive,
+    }
+}
+
+/// Maps a TDLib chat list to the domain one.
+pub fn map_td_chat_list(list: &TdChatList) -> ChatListId {
+    match list {
+        TdChatList::Main => ChatListId::Main,
+        TdChatList::Folder(folder) => ChatListId::Folder(folder.chat_folder_id),
+        TdChatList::Archive => ChatListId::Archive,
     }
 }
 
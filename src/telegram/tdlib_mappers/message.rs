@@ -1,9 +1,10 @@
 use tdlib_rs::enums::MessageContent;
 use tdlib_rs::types::Message as TdMessage;
 
-use crate::domain::message::{ForwardInfo, Message, MessageMedia, ReplyInfo};
+use crate::domain::message::{ForwardInfo, Message, MessageMedia, MessageReaction, ReplyInfo};
 
 use super::file_info::{extract_call_info, extract_file_info};
+use super::reply_markup::{extract_inline_keyboard, extract_reply_keyboard};
 use super::text_links::extract_content_links;
 
 /// Maps a TDLib Message to a domain Message.
@@ -25,7 +26,9 @@ pub fn map_tdlib_message_to_domain(
     let call_info = extract_call_info(&msg.content);
     let links = extract_content_links(&msg.content);
     let timestamp_ms = i64::from(msg.date) * 1000;
-    let reaction_count = extract_total_reaction_count(msg);
+    let reactions = map_message_reactions(msg.interaction_info.as_ref());
+    let inline_keyboard = extract_inline_keyboard(msg.reply_markup.as_ref(), None);
+    let reply_keyboard = extract_reply_keyboard(msg.reply_markup.as_ref());
 
     Message {
         id: msg.id,
@@ -39,10 +42,12 @@ pub fn map_tdlib_message_to_domain(
         call_info,
         reply_to,
         forward_info,
-        reaction_count,
+        reactions,
         links,
         is_edited: msg.edit_date > 0,
         is_service,
+        inline_keyboard,
+        reply_keyboard,
     }
 }
 
@@ -80,24 +85,30 @@ fn is_service_content(content: &MessageContent) -> bool {
     )
 }
 
-/// Sums `total_count` across all reaction types from interaction info.
-pub fn sum_reaction_counts(
+/// Maps the reactions from interaction info, skipping empty ones.
+/// Custom emoji reactions become `⭐` and paid reactions `💎`, as in the
+/// message info popup.
+pub fn map_message_reactions(
     interaction_info: Option<&tdlib_rs::types::MessageInteractionInfo>,
-) -> u32 {
-    interaction_info
-        .and_then(|info| info.reactions.as_ref())
-        .map(|reactions| {
-            reactions
-                .reactions
-                .iter()
-                .map(|r| r.total_count.max(0) as u32)
-                .sum()
-        })
-        .unwrap_or(0)
-}
+) -> Vec<MessageReaction> {
+    let Some(reactions) = interaction_info.and_then(|info| info.reactions.as_ref()) else {
+        return Vec::new();
+    };
 
-fn extract_total_reaction_count(msg: &TdMessage) -> u32 {
-    sum_reaction_counts(msg.interaction_info.as_ref())
+    reactions
+        .reactions
+        .iter()
+        .filter(|r| r.total_count > 0)
+        .map(|r| MessageReaction {
+            emoji: match &r.r#type {
+                tdlib_rs::enums::ReactionType::Emoji(e) => e.emoji.clone(),
+                tdlib_rs::enums::ReactionType::CustomEmoji(_) => "⭐".to_owned(),
+                tdlib_rs::enums::ReactionType::Paid => "💎".to_owned(),
+            },
+            count: r.total_count as u32,
+            is_chosen: r.is_chosen,
+        })
+        .collect()
 }
 
 /// Extracts reply information from a TDLib Message.
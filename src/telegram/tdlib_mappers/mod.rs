@@ -7,13 +7,17 @@ mod chat;
 mod file_info;
 mod forum_topic;
 mod message;
+mod reply_markup;
 mod text_links;
 mod user;
 
 // Re-exports consumed by sibling modules (`tdlib_auth`, `tdlib_client`, `chat_updates`)
 // via `super::tdlib_mappers::*` paths.
 #[allow(unused_imports)]
-pub use chat::{map_chat_to_summary, map_chat_type};
+pub use chat::{
+    is_chat_muted, is_pinned_in_list, map_chat_list_id, map_chat_to_summary, map_chat_type,
+    map_td_chat_list, notification_scope,
+};
 #[allow(unused_imports)]
 pub use file_info::extract_file_info;
 #[allow(unused_imports)]
@@ -21,8 +25,13 @@ pub use forum_topic::map_forum_topic_to_summary;
 #[allow(unused_imports)]
 pub use message::{
     extract_forward_info, extract_message_media, extract_message_preview, extract_message_text,
-    extract_reply_info, map_tdlib_message_to_domain, sum_reaction_counts,
+    extract_reply_info, map_message_reactions, map_tdlib_message_to_domain,
+};
+#[allow(unused_imports)]
+pub use reply_markup::{
+    extract_inline_keyboard, extract_reply_keyboard, inline_keyboard_bot_user_id,
 };
+pub use text_links::mention_entities;
 #[allow(unused_imports)]
 pub use user::{
     format_user_name, get_private_chat_user_id, get_sender_user_id, is_user_online,
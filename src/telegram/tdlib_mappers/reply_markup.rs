@@ -0,0 +1,96 @@
+use tdlib_rs::enums::{InlineKeyboardButtonType, KeyboardButtonType, ReplyMarkup};
+use tdlib_rs::types::Message as TdMessage;
+
+use crate::domain::message::{InlineButton, InlineButtonAction, ReplyKeyboard};
+
+/// Maps an inline keyboard reply markup into rows of domain buttons.
+///
+/// Other reply markup kinds (custom reply keyboards, force reply) are not
+/// attached to the message and yield an empty grid. `bot_username` is used
+/// for switch-inline buttons, which need the bot mention to be prefilled.
+pub fn extract_inline_keyboard(
+    reply_markup: Option<&ReplyMarkup>,
+    bot_username: Option<&str>,
+) -> Vec<Vec<InlineButton>> {
+    let Some(ReplyMarkup::InlineKeyboard(keyboard)) = reply_markup else {
+        return Vec::new();
+    };
+
+    keyboard
+        .rows
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|button| InlineButton {
+                    text: button.text.clone(),
+                    action: map_button_type(&button.r#type, bot_username),
+                })
+                .collect::<Vec<_>>()
+        })
+        .filter(|row| !row.is_empty())
+        .collect()
+}
+
+fn map_button_type(
+    button_type: &InlineKeyboardButtonType,
+    bot_username: Option<&str>,
+) -> InlineButtonAction {
+    match button_type {
+        InlineKeyboardButtonType::Callback(callback) => InlineButtonAction::Callback {
+            data: callback.data.clone(),
+        },
+        InlineKeyboardButtonType::Url(url) => InlineButtonAction::Url {
+            url: url.url.clone(),
+        },
+        InlineKeyboardButtonType::LoginUrl(login) => InlineButtonAction::Url {
+            url: login.url.clone(),
+        },
+        InlineKeyboardButtonType::SwitchInline(switch) => InlineButtonAction::SwitchInline {
+            query: switch.query.clone(),
+            bot_username: bot_username.map(str::to_owned),
+        },
+        _ => InlineButtonAction::Unsupported,
+    }
+}
+
+/// Maps a custom reply keyboard markup into a domain keyboard.
+///
+/// Only plain text buttons are kept: buttons that share contacts,
+/// locations, polls or open web apps cannot be sent from rtg.
+/// Returns `None` for other markup kinds or when no text button remains.
+pub fn extract_reply_keyboard(reply_markup: Option<&ReplyMarkup>) -> Option<ReplyKeyboard> {
+    let Some(ReplyMarkup::ShowKeyboard(keyboard)) = reply_markup else {
+        return None;
+    };
+
+    let rows: Vec<Vec<String>> = keyboard
+        .rows
+        .iter()
+        .map(|row| {
+            row.iter()
+                .filter(|button| matches!(button.r#type, KeyboardButtonType::Text))
+                .map(|button| button.text.clone())
+                .collect::<Vec<_>>()
+        })
+        .filter(|row| !row.is_empty())
+        .collect();
+
+    if rows.is_empty() {
+        return None;
+    }
+
+    Some(ReplyKeyboard {
+        rows,
+        one_time: keyboard.one_time,
+        placeholder: keyboard.input_field_placeholder.clone(),
+    })
+}
+
+/// Returns the bot that owns the message's inline keyboard: the inline bot
+/// the message was sent via, or the sending user otherwise.
+pub fn inline_keyboard_bot_user_id(msg: &TdMessage) -> Option<i64> {
+    if msg.via_bot_user_id != 0 {
+        return Some(msg.via_bot_user_id);
+    }
+    super::get_sender_user_id(&msg.sender_id)
+}
@@ -1,10 +1,16 @@
-use crate::telegram::tdlib_mappers::map_chat_to_summary;
+use tdlib_rs::enums::{ChatList as TdChatList, NotificationSettingsScope};
+use tdlib_rs::types::{ChatListFolder, ChatPosition, ScopeNotificationSettings};
+
+use crate::domain::chat_folder::ChatListId;
+use crate::telegram::tdlib_mappers::{
+    is_pinned_in_list, map_chat_list_id, map_chat_to_summary, map_td_chat_list, notification_scope,
+};
 
 #[test]
 fn chat_summary_uses_deleted_placeholder_when_title_empty() {
     let td_chat = crate::telegram::tdlib_cache::tests::make_test_chat(1, "");
 
-    let summary = map_chat_to_summary(&td_chat, None, None, false, false);
+    let summary = map_chat_to_summary(&td_chat, None, None, false, false, None);
     assert_eq!(summary.title, "Deleted");
 }
 
@@ -13,7 +19,7 @@ fn chat_summary_maps_unread_reaction_count() {
     let mut td_chat = crate::telegram::tdlib_cache::tests::make_test_chat(1, "Test");
     td_chat.unread_reaction_count = 5;
 
-    let summary = map_chat_to_summary(&td_chat, None, None, false, false);
+    let summary = map_chat_to_summary(&td_chat, None, None, false, false, None);
     assert_eq!(summary.unread_reaction_count, 5);
 }
 
@@ -21,7 +27,7 @@ fn chat_summary_maps_unread_reaction_count() {
 fn chat_summary_maps_zero_unread_reaction_count() {
     let td_chat = crate::telegram::tdlib_cache::tests::make_test_chat(1, "Test");
 
-    let summary = map_chat_to_summary(&td_chat, None, None, false, false);
+    let summary = map_chat_to_summary(&td_chat, None, None, false, false, None);
     assert_eq!(summary.unread_reaction_count, 0);
 }
 
@@ -29,9 +35,108 @@ fn chat_summary_maps_zero_unread_reaction_count() {
 fn chat_summary_carries_is_forum_flag() {
     let td_chat = crate::telegram::tdlib_cache::tests::make_test_chat(1, "Topics");
 
-    let with_topics = map_chat_to_summary(&td_chat, None, None, false, true);
-    let without_topics = map_chat_to_summary(&td_chat, None, None, false, false);
+    let with_topics = map_chat_to_summary(&td_chat, None, None, false, true, None);
+    let without_topics = map_chat_to_summary(&td_chat, None, None, false, false, None);
 
     assert!(with_topics.is_forum);
     assert!(!without_topics.is_forum);
 }
+
+#[test]
+fn chat_summary_is_muted_by_own_mute_for() {
+    let mut td_chat = crate::telegram::tdlib_cache::tests::make_test_chat(1, "Noisy");
+    assert!(!map_chat_to_summary(&td_chat, None, None, false, false, None).is_muted);
+
+    td_chat.notification_settings.mute_for = 3600;
+    assert!(map_chat_to_summary(&td_chat, None, None, false, false, None).is_muted);
+
+    td_chat.notification_settings.use_default_mute_for = true;
+    assert!(!map_chat_to_summary(&td_chat, None, None, false, false, None).is_muted);
+}
+
+#[test]
+fn chat_following_the_default_is_muted_by_its_scope() {
+    let mut td_chat = crate::telegram::tdlib_cache::tests::make_test_chat(1, "News");
+    td_chat.notification_settings.use_default_mute_for = true;
+    td_chat.notification_settings.mute_for = 0;
+    let muted_scope = ScopeNotificationSettings {
+        mute_for: i32::MAX,
+        ..Default::default()
+    };
+    let unmuted_scope = ScopeNotificationSettings::default();
+
+    let summary = map_chat_to_summary(&td_chat, None, None, false, false, Some(&muted_scope));
+    assert!(summary.is_muted);
+    let summary = map_chat_to_summary(&td_chat, None, None, false, false, Some(&unmuted_scope));
+    assert!(!summary.is_muted);
+
+    // A chat with its own setting ignores the scope.
+    td_chat.notification_settings.use_default_mute_for = false;
+    let summary = map_chat_to_summary(&td_chat, None, None, false, false, Some(&muted_scope));
+    assert!(!summary.is_muted);
+}
+
+#[test]
+fn notification_scope_follows_chat_type() {
+    use tdlib_rs::enums::ChatType;
+    use tdlib_rs::types::{ChatTypeBasicGroup, ChatTypePrivate, ChatTypeSupergroup};
+
+    let private = ChatType::Private(ChatTypePrivate { user_id: 1 });
+    let group = ChatType::BasicGroup(ChatTypeBasicGroup { basic_group_id: 2 });
+    let supergroup = ChatType::Supergroup(ChatTypeSupergroup {
+        supergroup_id: 3,
+        is_channel: false,
+    });
+    let channel = ChatType::Supergroup(ChatTypeSupergroup {
+        supergroup_id: 4,
+        is_channel: true,
+    });
+
+    assert_eq!(
+        notification_scope(&private),
+        NotificationSettingsScope::PrivateChats
+    );
+    assert_eq!(
+        notification_scope(&group),
+        NotificationSettingsScope::GroupChats
+    );
+    assert_eq!(
+        notification_scope(&supergroup),
+        NotificationSettingsScope::GroupChats
+    );
+    assert_eq!(
+        notification_scope(&channel),
+        NotificationSettingsScope::ChannelChats
+    );
+}
+
+#[test]
+fn pin_state_is_per_chat_list() {
+    let mut td_chat = crate::telegram::tdlib_cache::tests::make_test_chat(1, "Work");
+    let folder = TdChatList::Folder(ChatListFolder { chat_folder_id: 4 });
+    td_chat.positions = vec![
+        ChatPosition {
+            list: TdChatList::Main,
+            order: 10,
+            is_pinned: false,
+            source: None,
+        },
+        ChatPosition {
+            list: folder.clone(),
+            order: 20,
+            is_pinned: true,
+            source: None,
+        },
+    ];
+
+    assert!(is_pinned_in_list(&td_chat, &folder));
+    assert!(!is_pinned_in_list(&td_chat, &TdChatList::Main));
+    assert!(!map_chat_to_summary(&td_chat, None, None, false, false, None).is_pinned);
+}
+
+#[test]
+fn chat_list_ids_round_trip() {
+    for list in [ChatListId::Main, ChatListId::Folder(9), ChatListId::Archive] {
+        assert_eq!(map_td_chat_list(&map_chat_list_id(list)), list);
+    }
+}
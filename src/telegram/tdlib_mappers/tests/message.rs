@@ -1,6 +1,6 @@
 use tdlib_rs::enums::MessageContent;
 
-use crate::domain::message::MessageMedia;
+use crate::domain::message::{MessageMedia, MessageReaction};
 use crate::telegram::tdlib_mappers::{
     extract_forward_info, extract_message_media, extract_message_text, map_tdlib_message_to_domain,
 };
@@ -182,17 +182,17 @@ fn map_tdlib_message_includes_file_info() {
     assert_eq!(fi.local_path, Some("/tmp/v.ogg".to_owned()));
 }
 
-// ── reaction count tests ──
+// ── reaction tests ──
 
 #[test]
-fn message_without_interaction_info_has_zero_reactions() {
+fn message_without_interaction_info_has_no_reactions() {
     let td_msg = make_test_message(1, "Hello", false);
     let msg = map_tdlib_message_to_domain(&td_msg, "User".to_owned(), None, None, |_: i64| None);
-    assert_eq!(msg.reaction_count, 0);
+    assert!(msg.reactions.is_empty());
 }
 
 #[test]
-fn message_with_reactions_sums_total_counts() {
+fn message_with_reactions_keeps_per_emoji_counts() {
     let mut td_msg = make_test_message(1, "Hello", false);
     td_msg.interaction_info = Some(tdlib_rs::types::MessageInteractionInfo {
         view_count: 0,
@@ -218,7 +218,7 @@ fn message_with_reactions_sums_total_counts() {
                         },
                     ),
                     total_count: 1,
-                    is_chosen: false,
+                    is_chosen: true,
                     used_sender_id: None,
                     recent_sender_ids: vec![],
                 },
@@ -230,7 +230,21 @@ fn message_with_reactions_sums_total_counts() {
     });
 
     let msg = map_tdlib_message_to_domain(&td_msg, "User".to_owned(), None, None, |_: i64| None);
-    assert_eq!(msg.reaction_count, 3);
+    assert_eq!(
+        msg.reactions,
+        vec![
+            MessageReaction {
+                emoji: "\u{1f44d}".to_owned(),
+                count: 2,
+                is_chosen: false,
+            },
+            MessageReaction {
+                emoji: "\u{2764}".to_owned(),
+                count: 1,
+                is_chosen: true,
+            },
+        ]
+    );
 }
 
 // ── forward info extraction tests ──
@@ -2,6 +2,7 @@ mod chat;
 mod file_info;
 mod forum_topic;
 mod message;
+mod reply_markup;
 mod text_links;
 mod user;
 
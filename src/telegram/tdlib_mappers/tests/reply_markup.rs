@@ -0,0 +1,182 @@
+use tdlib_rs::enums::{
+    ButtonStyle, InlineKeyboardButtonType, KeyboardButtonType, ReplyMarkup, TargetChat,
+};
+use tdlib_rs::types::{
+    InlineKeyboardButton, InlineKeyboardButtonTypeCallback, InlineKeyboardButtonTypeSwitchInline,
+    InlineKeyboardButtonTypeUrl, KeyboardButton, ReplyMarkupInlineKeyboard,
+    ReplyMarkupShowKeyboard,
+};
+
+use crate::domain::message::{InlineButton, InlineButtonAction, ReplyKeyboard};
+use crate::telegram::tdlib_mappers::{
+    extract_inline_keyboard, extract_reply_keyboard, inline_keyboard_bot_user_id,
+    map_tdlib_message_to_domain,
+};
+
+use super::make_test_message;
+
+fn button(text: &str, r#type: InlineKeyboardButtonType) -> InlineKeyboardButton {
+    InlineKeyboardButton {
+        text: text.to_owned(),
+        icon_custom_emoji_id: 0,
+        style: ButtonStyle::Default,
+        r#type,
+    }
+}
+
+fn keyboard(rows: Vec<Vec<InlineKeyboardButton>>) -> ReplyMarkup {
+    ReplyMarkup::InlineKeyboard(ReplyMarkupInlineKeyboard { rows })
+}
+
+#[test]
+fn extract_inline_keyboard_returns_empty_without_markup() {
+    assert!(extract_inline_keyboard(None, None).is_empty());
+}
+
+#[test]
+fn extract_inline_keyboard_maps_rows_and_button_types() {
+    let markup = keyboard(vec![
+        vec![
+            button(
+                "Deploy",
+                InlineKeyboardButtonType::Callback(InlineKeyboardButtonTypeCallback {
+                    data: "deploy:42".to_owned(),
+                }),
+            ),
+            button(
+                "Logs",
+                InlineKeyboardButtonType::Url(InlineKeyboardButtonTypeUrl {
+                    url: "https://ci.example.com/42".to_owned(),
+                }),
+            ),
+        ],
+        vec![button("Pay", InlineKeyboardButtonType::Buy)],
+    ]);
+
+    let rows = extract_inline_keyboard(Some(&markup), None);
+
+    assert_eq!(
+        rows,
+        vec![
+            vec![
+                InlineButton {
+                    text: "Deploy".to_owned(),
+                    action: InlineButtonAction::Callback {
+                        data: "deploy:42".to_owned()
+                    },
+                },
+                InlineButton {
+                    text: "Logs".to_owned(),
+                    action: InlineButtonAction::Url {
+                        url: "https://ci.example.com/42".to_owned()
+                    },
+                },
+            ],
+            vec![InlineButton {
+                text: "Pay".to_owned(),
+                action: InlineButtonAction::Unsupported,
+            }],
+        ]
+    );
+}
+
+#[test]
+fn extract_inline_keyboard_attaches_bot_username_to_switch_inline() {
+    let markup = keyboard(vec![vec![button(
+        "Search",
+        InlineKeyboardButtonType::SwitchInline(InlineKeyboardButtonTypeSwitchInline {
+            query: "builds".to_owned(),
+            target_chat: TargetChat::Current,
+        }),
+    )]]);
+
+    let rows = extract_inline_keyboard(Some(&markup), Some("ci_bot"));
+
+    assert_eq!(
+        rows[0][0].action,
+        InlineButtonAction::SwitchInline {
+            query: "builds".to_owned(),
+            bot_username: Some("ci_bot".to_owned()),
+        }
+    );
+}
+
+#[test]
+fn map_message_includes_inline_keyboard() {
+    let mut msg = make_test_message(1, "Build finished", false);
+    msg.reply_markup = Some(keyboard(vec![vec![button(
+        "Retry",
+        InlineKeyboardButtonType::Callback(InlineKeyboardButtonTypeCallback {
+            data: "retry".to_owned(),
+        }),
+    )]]));
+
+    let mapped = map_tdlib_message_to_domain(&msg, "CI".to_owned(), None, None, |_| None);
+
+    assert_eq!(mapped.inline_keyboard.len(), 1);
+    assert_eq!(mapped.inline_keyboard[0][0].text, "Retry");
+}
+
+#[test]
+fn inline_keyboard_bot_prefers_via_bot() {
+    let mut msg = make_test_message(1, "result", false);
+    assert_eq!(inline_keyboard_bot_user_id(&msg), Some(1));
+
+    msg.via_bot_user_id = 77;
+    assert_eq!(inline_keyboard_bot_user_id(&msg), Some(77));
+}
+
+fn reply_button(text: &str, r#type: KeyboardButtonType) -> KeyboardButton {
+    KeyboardButton {
+        text: text.to_owned(),
+        icon_custom_emoji_id: 0,
+        style: ButtonStyle::Default,
+        r#type,
+    }
+}
+
+fn show_keyboard(rows: Vec<Vec<KeyboardButton>>) -> ReplyMarkup {
+    ReplyMarkup::ShowKeyboard(ReplyMarkupShowKeyboard {
+        rows,
+        is_persistent: false,
+        resize_keyboard: true,
+        one_time: true,
+        is_personal: false,
+        input_field_placeholder: "Pick one".to_owned(),
+    })
+}
+
+#[test]
+fn extract_reply_keyboard_keeps_text_buttons_only() {
+    let markup = show_keyboard(vec![
+        vec![
+            reply_button("Yes", KeyboardButtonType::Text),
+            reply_button("No", KeyboardButtonType::Text),
+        ],
+        vec![reply_button(
+            "Share phone",
+            KeyboardButtonType::RequestPhoneNumber,
+        )],
+    ]);
+
+    assert_eq!(
+        extract_reply_keyboard(Some(&markup)),
+        Some(ReplyKeyboard {
+            rows: vec![vec!["Yes".to_owned(), "No".to_owned()]],
+            one_time: true,
+            placeholder: "Pick one".to_owned(),
+        })
+    );
+}
+
+#[test]
+fn extract_reply_keyboard_ignores_other_markup() {
+    assert_eq!(extract_reply_keyboard(None), None);
+    assert_eq!(extract_reply_keyboard(Some(&keyboard(vec![]))), None);
+
+    let only_location = show_keyboard(vec![vec![reply_button(
+        "Send location",
+        KeyboardButtonType::RequestLocation,
+    )]]);
+    assert_eq!(extract_reply_keyboard(Some(&only_location)), None);
+}
@@ -1,7 +1,8 @@
 use tdlib_rs::enums::MessageContent;
 
+use crate::domain::message_input_state::TextMention;
 use crate::telegram::tdlib_mappers::text_links::{
-    extract_content_links, extract_text_links, utf16_offset_to_byte_offset,
+    extract_content_links, extract_text_links, mention_entities, utf16_offset_to_byte_offset,
 };
 
 use super::{make_formatted_text, make_text_url_entity, make_url_entity};
@@ -147,3 +148,32 @@ fn extract_text_links_with_emoji_prefix() {
     assert_eq!(links[0].length, 4);
     assert_eq!(links[0].url, "link");
 }
+
+#[test]
+fn mention_entities_use_utf16_offsets() {
+    let mentions = vec![TextMention {
+        offset: 2,
+        length: 3,
+        user_id: 42,
+    }];
+
+    let entities = mention_entities("🔥 Bob", &mentions);
+
+    assert_eq!(entities.len(), 1);
+    assert_eq!(entities[0].offset, 3);
+    assert_eq!(entities[0].length, 3);
+    assert!(matches!(
+        &entities[0].r#type,
+        tdlib_rs::enums::TextEntityType::MentionName(m) if m.user_id == 42
+    ));
+}
+
+#[test]
+fn mention_entities_skip_out_of_range_mentions() {
+    let mentions = vec![TextMention {
+        offset: 2,
+        length: 5,
+        user_id: 1,
+    }];
+    assert!(mention_entities("Bob", &mentions).is_empty());
+}
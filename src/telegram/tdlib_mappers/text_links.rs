@@ -1,6 +1,6 @@
 use tdlib_rs::enums::MessageContent;
 
-use crate::domain::message::TextLink;
+use crate::domain::{message::TextLink, message_input_state::TextMention};
 
 /// Converts a UTF-16 code-unit offset to a UTF-8 byte offset within `text`.
 ///
@@ -22,6 +22,32 @@ pub(super) fn utf16_offset_to_byte_offset(text: &str, utf16_offset: usize) -> Op
     None
 }
 
+/// Builds `mentionName` entities for an outgoing message, converting the
+/// domain's character offsets to TDLib's UTF-16 offsets.
+pub fn mention_entities(text: &str, mentions: &[TextMention]) -> Vec<tdlib_rs::types::TextEntity> {
+    let utf16_prefix: Vec<usize> = std::iter::once(0)
+        .chain(text.chars().scan(0, |pos, ch| {
+            *pos += ch.len_utf16();
+            Some(*pos)
+        }))
+        .collect();
+
+    mentions
+        .iter()
+        .filter_map(|m| {
+            let start = *utf16_prefix.get(m.offset)?;
+            let end = *utf16_prefix.get(m.offset + m.length)?;
+            Some(tdlib_rs::types::TextEntity {
+                offset: start as i32,
+                length: (end - start) as i32,
+                r#type: tdlib_rs::enums::TextEntityType::MentionName(
+                    tdlib_rs::types::TextEntityTypeMentionName { user_id: m.user_id },
+                ),
+            })
+        })
+        .collect()
+}
+
 /// Extracts URL-bearing text entities from a `FormattedText` into domain `TextLink`s.
 ///
 /// Handles `TextEntityTypeUrl` (URL visible in text) and `TextEntityTypeTextUrl`
@@ -35,6 +35,12 @@ pub enum TdLibUpdate {
         new_content: Box<MessageContent>,
     },
 
+    /// Message was edited (edit date or inline keyboard changed).
+    MessageEdited { chat_id: i64, message_id: i64 },
+
+    /// The chat's active custom reply keyboard changed or was removed.
+    ChatReplyMarkup { chat_id: i64 },
+
     /// Messages were deleted.
     DeleteMessages { chat_id: i64, message_ids: Vec<i64> },
 
@@ -44,11 +50,27 @@ pub enum TdLibUpdate {
     /// Chat position changed (affects chat list ordering).
     ChatPosition { chat_id: i64 },
 
+    /// The user marked the chat as unread or cleared the mark.
+    ChatIsMarkedAsUnread { chat_id: i64 },
+
+    /// The user of a private chat was blocked or unblocked.
+    ChatBlockList { chat_id: i64 },
+
+    /// Chat notification settings changed (affects the muted badge).
+    ChatNotificationSettings { chat_id: i64 },
+
+    /// Default notification settings of private chats, groups or channels
+    /// changed (affects the muted badge of chats that follow them).
+    ScopeNotificationSettings,
+
     /// Incoming messages were read (affects unread count).
     ChatReadInbox { chat_id: i64 },
 
     /// Outgoing messages were read (affects read receipts).
-    ChatReadOutbox { chat_id: i64 },
+    ChatReadOutbox {
+        chat_id: i64,
+        last_read_outbox_message_id: i64,
+    },
 
     /// User status changed (online/offline).
     UserStatus { user_id: i64 },
@@ -68,11 +90,14 @@ pub enum TdLibUpdate {
     /// Unread reaction count changed for a chat (affects chat list badge).
     ChatUnreadReactionCount { chat_id: i64 },
 
-    /// Message interaction info changed (reaction counts on a message).
+    /// Unread mention count changed for a chat (affects chat list badge).
+    ChatUnreadMentionCount { chat_id: i64 },
+
+    /// Message interaction info changed (reactions on a message).
     MessageInteractionInfoChanged {
         chat_id: i64,
         message_id: i64,
-        reaction_count: u32,
+        reactions: Vec<crate::domain::message::MessageReaction>,
     },
 
     /// File download progress or completion update.
@@ -103,6 +128,21 @@ pub enum TdLibUpdate {
     /// chat list should re-resolve this chat's metadata so the forum flag
     /// becomes current.
     SupergroupMetadataChanged { chat_id: i64 },
+
+    /// The user's chat folders were added, removed, renamed or reordered.
+    /// `main_position` is where the main list sits among them.
+    ChatFolders {
+        folders: Vec<crate::domain::chat_folder::ChatFolder>,
+        main_position: usize,
+    },
+
+    /// Chat counts of a chat list (main, folder or archive) changed.
+    /// `unread_count` counts unread unmuted chats.
+    UnreadChatCount {
+        list: crate::domain::chat_folder::ChatListId,
+        unread_count: u32,
+        total_count: u32,
+    },
 }
 
 impl TdLibUpdate {
@@ -113,19 +153,29 @@ impl TdLibUpdate {
             TdLibUpdate::NewChat { chat } => Some(chat.id),
             TdLibUpdate::NewMessage { chat_id, .. }
             | TdLibUpdate::MessageContentChanged { chat_id, .. }
+            | TdLibUpdate::MessageEdited { chat_id, .. }
+            | TdLibUpdate::ChatReplyMarkup { chat_id }
             | TdLibUpdate::DeleteMessages { chat_id, .. }
             | TdLibUpdate::ChatLastMessage { chat_id }
             | TdLibUpdate::ChatPosition { chat_id }
+            | TdLibUpdate::ChatIsMarkedAsUnread { chat_id }
+            | TdLibUpdate::ChatBlockList { chat_id }
+            | TdLibUpdate::ChatNotificationSettings { chat_id }
             | TdLibUpdate::ChatReadInbox { chat_id }
-            | TdLibUpdate::ChatReadOutbox { chat_id }
+            | TdLibUpdate::ChatReadOutbox { chat_id, .. }
             | TdLibUpdate::MessageSendSucceeded { chat_id, .. }
             | TdLibUpdate::ChatUnreadReactionCount { chat_id }
+            | TdLibUpdate::ChatUnreadMentionCount { chat_id }
             | TdLibUpdate::MessageInteractionInfoChanged { chat_id, .. } => Some(*chat_id),
             TdLibUpdate::ChatAction { chat_id, .. } => Some(*chat_id),
             TdLibUpdate::ForumTopicInfoChanged { chat_id, .. }
             | TdLibUpdate::ForumTopicChanged { chat_id, .. }
             | TdLibUpdate::SupergroupMetadataChanged { chat_id } => Some(*chat_id),
-            TdLibUpdate::UserStatus { .. } | TdLibUpdate::FileUpdated { .. } => None,
+            TdLibUpdate::UserStatus { .. }
+            | TdLibUpdate::FileUpdated { .. }
+            | TdLibUpdate::ScopeNotificationSettings
+            | TdLibUpdate::ChatFolders { .. }
+            | TdLibUpdate::UnreadChatCount { .. } => None,
         }
     }
 
@@ -135,20 +185,29 @@ impl TdLibUpdate {
             TdLibUpdate::NewChat { .. } => "new_chat",
             TdLibUpdate::NewMessage { .. } => "new_message",
             TdLibUpdate::MessageContentChanged { .. } => "message_content",
+            TdLibUpdate::MessageEdited { .. } => "message_edited",
+            TdLibUpdate::ChatReplyMarkup { .. } => "chat_reply_markup",
             TdLibUpdate::DeleteMessages { .. } => "delete_messages",
             TdLibUpdate::ChatLastMessage { .. } => "chat_last_message",
             TdLibUpdate::ChatPosition { .. } => "chat_position",
+            TdLibUpdate::ChatIsMarkedAsUnread { .. } => "chat_is_marked_as_unread",
+            TdLibUpdate::ChatBlockList { .. } => "chat_block_list",
+            TdLibUpdate::ChatNotificationSettings { .. } => "chat_notification_settings",
+            TdLibUpdate::ScopeNotificationSettings => "scope_notification_settings",
             TdLibUpdate::ChatReadInbox { .. } => "chat_read_inbox",
             TdLibUpdate::ChatReadOutbox { .. } => "chat_read_outbox",
             TdLibUpdate::UserStatus { .. } => "user_status",
             TdLibUpdate::MessageSendSucceeded { .. } => "message_send_succeeded",
             TdLibUpdate::ChatUnreadReactionCount { .. } => "chat_unread_reaction_count",
+            TdLibUpdate::ChatUnreadMentionCount { .. } => "chat_unread_mention_count",
             TdLibUpdate::MessageInteractionInfoChanged { .. } => "message_interaction_info_changed",
             TdLibUpdate::ChatAction { .. } => "chat_action",
             TdLibUpdate::FileUpdated { .. } => "file_updated",
             TdLibUpdate::ForumTopicInfoChanged { .. } => "forum_topic_info",
             TdLibUpdate::ForumTopicChanged { .. } => "forum_topic",
             TdLibUpdate::SupergroupMetadataChanged { .. } => "supergroup_metadata",
+            TdLibUpdate::ChatFolders { .. } => "chat_folders",
+            TdLibUpdate::UnreadChatCount { .. } => "unread_chat_count",
         }
     }
 }
@@ -254,4 +313,15 @@ mod tests {
         assert_eq!(update.chat_id(), Some(77));
         assert_eq!(update.kind(), "new_chat");
     }
+
+    #[test]
+    fn unread_chat_count_has_no_chat_id() {
+        let update = TdLibUpdate::UnreadChatCount {
+            list: crate::domain::chat_folder::ChatListId::Folder(2),
+            unread_count: 5,
+            total_count: 8,
+        };
+        assert_eq!(update.chat_id(), None);
+        assert_eq!(update.kind(), "unread_chat_count");
+    }
 }
@@ -0,0 +1,105 @@
+use crate::{
+    domain::{
+        chat::ChatSummary,
+        contact::Contact,
+        go_to_prompt_state::ChatQuery,
+        link_target::LinkTarget,
+        new_chat_wizard_state::{CreatedChat, NewChatRequest},
+    },
+    usecases::{
+        contacts::{ContactSource, ContactsError},
+        go_to_chat::{ChatLookupError, ChatLookupSource},
+        links::{LinkError, LinkSource},
+        new_chat::{ChatCreator, NewChatError},
+    },
+};
+
+use crate::telegram::TelegramAdapter;
+impl LinkSource for TelegramAdapter {
+    fn get_message_link(&self, chat_id: i64, message_id: i64) -> Result<String, LinkError> {
+        match self.tdlib_backend.as_ref() {
+            Some(backend) => backend.get_message_link(chat_id, message_id),
+            None => Err(LinkError::Unavailable),
+        }
+    }
+
+    fn resolve_link(&self, url: &str, window_size: usize) -> Result<LinkTarget, LinkError> {
+        match self.tdlib_backend.as_ref() {
+            Some(backend) => backend.resolve_link(url, window_size),
+            None => Err(LinkError::Unavailable),
+        }
+    }
+
+    fn join_chat_by_invite_link(&self, invite_link: &str) -> Result<ChatSummary, LinkError> {
+        match self.tdlib_backend.as_ref() {
+            Some(backend) => backend.join_chat_by_invite_link(invite_link),
+            None => Err(LinkError::Unavailable),
+        }
+    }
+}
+
+impl ChatLookupSource for TelegramAdapter {
+    fn find_chat(&self, query: &ChatQuery) -> Result<ChatSummary, ChatLookupError> {
+        match self.tdlib_backend.as_ref() {
+            Some(backend) => backend.find_chat(query),
+            None => Err(ChatLookupError::Unavailable),
+        }
+    }
+
+    fn search_chats(&self, query: &str, limit: usize) -> Result<Vec<ChatSummary>, ChatLookupError> {
+        match self.tdlib_backend.as_ref() {
+            Some(backend) => backend.search_chats(query, limit),
+            None => Err(ChatLookupError::Unavailable),
+        }
+    }
+
+    fn search_chats_on_server(
+        &self,
+        query: &str,
+        limit: usize,
+    ) -> Result<Vec<ChatSummary>, ChatLookupError> {
+        match self.tdlib_backend.as_ref() {
+            Some(backend) => backend.search_chats_on_server(query, limit),
+            None => Err(ChatLookupError::Unavailable),
+        }
+    }
+}
+
+impl ContactSource for TelegramAdapter {
+    fn list_contacts(&self) -> Result<Vec<Contact>, ContactsError> {
+        match self.tdlib_backend.as_ref() {
+            Some(backend) => backend.list_contacts(),
+            None => Err(ContactsError::Unavailable),
+        }
+    }
+
+    fn add_contact(&self, chat_id: i64) -> Result<Contact, ContactsError> {
+        match self.tdlib_backend.as_ref() {
+            Some(backend) => backend.add_contact(chat_id),
+            None => Err(ContactsError::Unavailable),
+        }
+    }
+
+    fn remove_contact(&self, user_id: i64) -> Result<(), ContactsError> {
+        match self.tdlib_backend.as_ref() {
+            Some(backend) => backend.remove_contact(user_id),
+            None => Err(ContactsError::Unavailable),
+        }
+    }
+
+    fn contact_chat(&self, user_id: i64) -> Result<ChatSummary, ContactsError> {
+        match self.tdlib_backend.as_ref() {
+            Some(backend) => backend.contact_chat(user_id),
+            None => Err(ContactsError::Unavailable),
+        }
+    }
+}
+
+impl ChatCreator for TelegramAdapter {
+    fn create_chat(&self, request: &NewChatRequest) -> Result<CreatedChat, NewChatError> {
+        match self.tdlib_backend.as_ref() {
+            Some(backend) => backend.create_chat(request),
+            None => Err(NewChatError::Unavailable),
+        }
+    }
+}
@@ -0,0 +1,120 @@
+use crate::usecases::{
+    chat_lifecycle::{ChatLifecycleError, ChatListMover, ChatManager, ChatMuter, ChatPinner},
+    load_messages::MessagesSourceError,
+};
+
+use crate::telegram::TelegramAdapter;
+impl ChatListMover for TelegramAdapter {
+    fn add_chat_to_list(
+        &self,
+        chat_id: i64,
+        list: crate::domain::chat_folder::ChatListId,
+    ) -> Result<(), ChatLifecycleError> {
+        match self.tdlib_backend.as_ref() {
+            Some(backend) => backend.add_chat_to_list(chat_id, list).map_err(|e| {
+                tracing::debug!(chat_id, error = ?e, "add_chat_to_list mapped to lifecycle error");
+                ChatLifecycleError::Unavailable
+            }),
+            None => Err(ChatLifecycleError::Unavailable),
+        }
+    }
+}
+
+impl ChatMuter for TelegramAdapter {
+    fn set_chat_mute(
+        &self,
+        chat_id: i64,
+        mute_for: Option<crate::domain::chat::MuteDuration>,
+    ) -> Result<(), ChatLifecycleError> {
+        match self.tdlib_backend.as_ref() {
+            Some(backend) => backend.set_chat_mute(chat_id, mute_for).map_err(|e| {
+                tracing::debug!(chat_id, error = ?e, "set_chat_mute mapped to lifecycle error");
+                ChatLifecycleError::Unavailable
+            }),
+            None => Err(ChatLifecycleError::Unavailable),
+        }
+    }
+}
+
+impl ChatPinner for TelegramAdapter {
+    fn set_chat_pinned(
+        &self,
+        chat_id: i64,
+        list: crate::domain::chat_folder::ChatListId,
+        pinned: bool,
+    ) -> Result<(), ChatLifecycleError> {
+        match self.tdlib_backend.as_ref() {
+            Some(backend) => backend.set_chat_pinned(chat_id, list, pinned).map_err(|e| {
+                tracing::debug!(chat_id, error = ?e, "set_chat_pinned mapped to lifecycle error");
+                ChatLifecycleError::Unavailable
+            }),
+            None => Err(ChatLifecycleError::Unavailable),
+        }
+    }
+
+    fn set_pinned_chats(
+        &self,
+        list: crate::domain::chat_folder::ChatListId,
+        chat_ids: Vec<i64>,
+    ) -> Result<(), ChatLifecycleError> {
+        match self.tdlib_backend.as_ref() {
+            Some(backend) => backend.set_pinned_chats(list, chat_ids).map_err(|e| {
+                tracing::debug!(error = ?e, "set_pinned_chats mapped to lifecycle error");
+                ChatLifecycleError::Unavailable
+            }),
+            None => Err(ChatLifecycleError::Unavailable),
+        }
+    }
+}
+
+impl ChatManager for TelegramAdapter {
+    fn leave_chat(&self, chat_id: i64) -> Result<(), ChatLifecycleError> {
+        match self.tdlib_backend.as_ref() {
+            Some(backend) => backend.leave_chat(chat_id).map_err(|e| {
+                tracing::debug!(chat_id, error = ?e, "leave_chat mapped to lifecycle error");
+                ChatLifecycleError::Unavailable
+            }),
+            None => Err(ChatLifecycleError::Unavailable),
+        }
+    }
+
+    fn delete_chat(&self, chat_id: i64) -> Result<(), ChatLifecycleError> {
+        match self.tdlib_backend.as_ref() {
+            Some(backend) => backend.delete_chat(chat_id).map_err(|e| {
+                tracing::debug!(chat_id, error = ?e, "delete_chat mapped to lifecycle error");
+                ChatLifecycleError::Unavailable
+            }),
+            None => Err(ChatLifecycleError::Unavailable),
+        }
+    }
+
+    fn delete_chat_history(
+        &self,
+        chat_id: i64,
+        remove_from_chat_list: bool,
+        revoke: bool,
+    ) -> Result<(), ChatLifecycleError> {
+        match self.tdlib_backend.as_ref() {
+            Some(backend) => backend
+                .delete_chat_history(chat_id, remove_from_chat_list, revoke)
+                .map_err(|e| {
+                    tracing::debug!(chat_id, error = ?e, "delete_chat_history mapped to lifecycle error");
+                    ChatLifecycleError::Unavailable
+                }),
+            None => Err(ChatLifecycleError::Unavailable),
+        }
+    }
+
+    fn set_chat_blocked(&self, chat_id: i64, blocked: bool) -> Result<(), ChatLifecycleError> {
+        match self.tdlib_backend.as_ref() {
+            Some(backend) => backend.set_chat_blocked(chat_id, blocked).map_err(|e| {
+                tracing::debug!(chat_id, error = ?e, "set_chat_blocked mapped to lifecycle error");
+                match e {
+                    MessagesSourceError::ChatNotFound => ChatLifecycleError::ChatNotFound,
+                    _ => ChatLifecycleError::Unavailable,
+                }
+            }),
+            None => Err(ChatLifecycleError::Unavailable),
+        }
+    }
+}
@@ -0,0 +1,177 @@
+use crate::{
+    domain::{bot_command::BotCommand, chat_action::ChatAction, chat_member::ChatMember},
+    usecases::{
+        bot_commands::{BotCommandsError, BotCommandsSource},
+        chat_lifecycle::{
+            ChatActionSender, ChatLifecycle, ChatLifecycleError, ChatReadMarker, FileDownloader,
+        },
+        chat_members::{ChatMemberSource, ChatMembersError},
+        chat_subtitle::{ChatInfoQuery, ChatSubtitleError, ChatSubtitleQuery, ChatSubtitleSource},
+        list_chats::{ListChatsSource, ListChatsSourceError},
+        list_forum_topics::{ForumTopicsSource, ListForumTopicsSourceError},
+    },
+};
+
+use crate::telegram::TelegramAdapter;
+impl ListChatsSource for TelegramAdapter {
+    fn list_chats(
+        &self,
+        list: crate::domain::chat_folder::ChatListId,
+        limit: usize,
+        force: bool,
+    ) -> Result<(Vec<crate::domain::chat::ChatSummary>, bool), ListChatsSourceError> {
+        match self.tdlib_backend.as_ref() {
+            Some(backend) => backend.list_chat_summaries(list, limit, force),
+            None => Err(ListChatsSourceError::Unavailable),
+        }
+    }
+}
+
+impl ForumTopicsSource for TelegramAdapter {
+    fn list_forum_topics(
+        &self,
+        chat_id: i64,
+        limit: usize,
+    ) -> Result<Vec<crate::domain::forum_topic::ForumTopicSummary>, ListForumTopicsSourceError>
+    {
+        match self.tdlib_backend.as_ref() {
+            Some(backend) => backend.list_forum_topic_summaries(chat_id, limit),
+            None => Err(ListForumTopicsSourceError::Unavailable),
+        }
+    }
+}
+
+impl ChatLifecycle for TelegramAdapter {
+    fn open_chat(&self, chat_id: i64) -> Result<(), ChatLifecycleError> {
+        match self.tdlib_backend.as_ref() {
+            Some(backend) => backend.open_chat(chat_id).map_err(|e| {
+                tracing::debug!(chat_id, error = ?e, "open_chat mapped to lifecycle error");
+                ChatLifecycleError::Unavailable
+            }),
+            None => Err(ChatLifecycleError::Unavailable),
+        }
+    }
+
+    fn close_chat(&self, chat_id: i64) -> Result<(), ChatLifecycleError> {
+        match self.tdlib_backend.as_ref() {
+            Some(backend) => backend.close_chat(chat_id).map_err(|e| {
+                tracing::debug!(chat_id, error = ?e, "close_chat mapped to lifecycle error");
+                ChatLifecycleError::Unavailable
+            }),
+            None => Err(ChatLifecycleError::Unavailable),
+        }
+    }
+}
+
+impl FileDownloader for TelegramAdapter {
+    fn download_file(&self, file_id: i32) -> Result<(), ChatLifecycleError> {
+        match self.tdlib_backend.as_ref() {
+            Some(backend) => backend.download_file(file_id).map_err(|e| {
+                tracing::debug!(file_id, error = ?e, "download_file mapped to lifecycle error");
+                ChatLifecycleError::Unavailable
+            }),
+            None => Err(ChatLifecycleError::Unavailable),
+        }
+    }
+}
+
+impl ChatReadMarker for TelegramAdapter {
+    fn mark_messages_read(
+        &self,
+        chat_id: i64,
+        topic_id: Option<i32>,
+        message_ids: Vec<i64>,
+    ) -> Result<(), ChatLifecycleError> {
+        match self.tdlib_backend.as_ref() {
+            Some(backend) => backend
+                .view_messages(chat_id, topic_id, message_ids)
+                .map_err(|e| {
+                    tracing::debug!(chat_id, error = ?e, "view_messages mapped to lifecycle error");
+                    ChatLifecycleError::Unavailable
+                }),
+            None => Err(ChatLifecycleError::Unavailable),
+        }
+    }
+
+    fn set_chat_marked_as_unread(
+        &self,
+        chat_id: i64,
+        marked: bool,
+    ) -> Result<(), ChatLifecycleError> {
+        match self.tdlib_backend.as_ref() {
+            Some(backend) => backend
+                .set_chat_marked_as_unread(chat_id, marked)
+                .map_err(|e| {
+                    tracing::debug!(chat_id, error = ?e, "set_chat_marked_as_unread mapped to lifecycle error");
+                    ChatLifecycleError::Unavailable
+                }),
+            None => Err(ChatLifecycleError::Unavailable),
+        }
+    }
+}
+
+impl ChatActionSender for TelegramAdapter {
+    fn send_chat_action(
+        &self,
+        chat_id: i64,
+        topic_id: Option<i32>,
+        action: ChatAction,
+    ) -> Result<(), ChatLifecycleError> {
+        match self.tdlib_backend.as_ref() {
+            Some(backend) => backend
+                .send_chat_action(chat_id, topic_id, action)
+                .map_err(|e| {
+                    tracing::debug!(chat_id, error = ?e, "send_chat_action mapped to lifecycle error");
+                    ChatLifecycleError::Unavailable
+                }),
+            None => Err(ChatLifecycleError::Unavailable),
+        }
+    }
+}
+
+impl ChatSubtitleSource for TelegramAdapter {
+    fn resolve_chat_subtitle(
+        &self,
+        query: &ChatSubtitleQuery,
+    ) -> Result<crate::domain::chat_subtitle::ChatSubtitle, ChatSubtitleError> {
+        match self.tdlib_backend.as_ref() {
+            Some(backend) => Ok(backend.resolve_subtitle(query.chat_id)),
+            None => Err(ChatSubtitleError::Unavailable),
+        }
+    }
+
+    fn resolve_chat_info(
+        &self,
+        query: &ChatInfoQuery,
+    ) -> Result<crate::domain::chat_info_state::ChatInfo, ChatSubtitleError> {
+        match self.tdlib_backend.as_ref() {
+            Some(backend) => {
+                Ok(backend.resolve_chat_info(query.chat_id, query.chat_type, query.title.clone()))
+            }
+            None => Err(ChatSubtitleError::Unavailable),
+        }
+    }
+}
+
+impl BotCommandsSource for TelegramAdapter {
+    fn get_bot_commands(&self, chat_id: i64) -> Result<Vec<BotCommand>, BotCommandsError> {
+        match self.tdlib_backend.as_ref() {
+            Some(backend) => backend.get_bot_commands(chat_id),
+            None => Err(BotCommandsError::Unavailable),
+        }
+    }
+}
+
+impl ChatMemberSource for TelegramAdapter {
+    fn search_chat_members(
+        &self,
+        chat_id: i64,
+        query: &str,
+        limit: usize,
+    ) -> Result<Vec<ChatMember>, ChatMembersError> {
+        match self.tdlib_backend.as_ref() {
+            Some(backend) => backend.search_chat_members(chat_id, query, limit),
+            None => Err(ChatMembersError::Unavailable),
+        }
+    }
+}
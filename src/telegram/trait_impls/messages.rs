@@ -1,14 +1,17 @@
 use crate::{
-    domain::message::Message,
+    domain::{
+        chat::UnreadJumpKind,
+        delete_confirm_state::DeletePermissions,
+        message::{CallbackAnswer, Message, UnreadMessageWindow},
+        message_input_state::TextMention,
+    },
     usecases::{
-        chat_lifecycle::{
-            ChatLifecycle, ChatLifecycleError, ChatReadMarker, FileDownloader, MessageDeleter,
-        },
-        chat_subtitle::{ChatInfoQuery, ChatSubtitleError, ChatSubtitleQuery, ChatSubtitleSource},
+        chat_lifecycle::{ChatLifecycleError, MessageDeleter, MessageForwarder},
         edit_message::{EditMessageSourceError, MessageEditor},
-        list_chats::{ListChatsSource, ListChatsSourceError},
-        list_forum_topics::{ForumTopicsSource, ListForumTopicsSourceError},
-        load_messages::{CachedMessagesSource, MessagesSource, MessagesSourceError},
+        inline_keyboard::{CallbackQuery, CallbackQueryError, CallbackQuerySource},
+        load_messages::{
+            CachedMessagesSource, MessageWindowSource, MessagesSource, MessagesSourceError,
+        },
         message_info::{MessageInfoError, MessageInfoQuery, MessageInfoSource},
         message_reactions::{
             AddReactionQuery, AvailableReactionsQuery, ReactionError, ReactionSource,
@@ -18,45 +21,57 @@ use crate::{
     },
 };
 
-use super::TelegramAdapter;
-
-impl ListChatsSource for TelegramAdapter {
-    fn list_chats(
+use crate::telegram::TelegramAdapter;
+impl MessagesSource for TelegramAdapter {
+    fn list_messages(
         &self,
+        chat_id: i64,
+        topic_id: Option<i32>,
         limit: usize,
-        force: bool,
-    ) -> Result<(Vec<crate::domain::chat::ChatSummary>, bool), ListChatsSourceError> {
+        from_message_id: i64,
+    ) -> Result<Vec<Message>, MessagesSourceError> {
         match self.tdlib_backend.as_ref() {
-            Some(backend) => backend.list_chat_summaries(limit, force),
-            None => Err(ListChatsSourceError::Unavailable),
+            Some(backend) => backend.list_messages(chat_id, topic_id, limit, from_message_id),
+            None => Err(MessagesSourceError::Unavailable),
         }
     }
 }
 
-impl ForumTopicsSource for TelegramAdapter {
-    fn list_forum_topics(
+impl MessageWindowSource for TelegramAdapter {
+    fn list_messages_around_date(
         &self,
         chat_id: i64,
+        topic_id: Option<i32>,
+        unix_time: i64,
         limit: usize,
-    ) -> Result<Vec<crate::domain::forum_topic::ForumTopicSummary>, ListForumTopicsSourceError>
-    {
+    ) -> Result<Vec<Message>, MessagesSourceError> {
         match self.tdlib_backend.as_ref() {
-            Some(backend) => backend.list_forum_topic_summaries(chat_id, limit),
-            None => Err(ListForumTopicsSourceError::Unavailable),
+            Some(backend) => backend.list_messages_around_date(chat_id, topic_id, unix_time, limit),
+            None => Err(MessagesSourceError::Unavailable),
         }
     }
-}
 
-impl MessagesSource for TelegramAdapter {
-    fn list_messages(
+    fn list_oldest_messages(
         &self,
         chat_id: i64,
         topic_id: Option<i32>,
         limit: usize,
-        from_message_id: i64,
     ) -> Result<Vec<Message>, MessagesSourceError> {
         match self.tdlib_backend.as_ref() {
-            Some(backend) => backend.list_messages(chat_id, topic_id, limit, from_message_id),
+            Some(backend) => backend.list_oldest_messages(chat_id, topic_id, limit),
+            None => Err(MessagesSourceError::Unavailable),
+        }
+    }
+
+    fn list_messages_around_unread(
+        &self,
+        chat_id: i64,
+        topic_id: Option<i32>,
+        kind: UnreadJumpKind,
+        limit: usize,
+    ) -> Result<Option<UnreadMessageWindow>, MessagesSourceError> {
+        match self.tdlib_backend.as_ref() {
+            Some(backend) => backend.list_messages_around_unread(chat_id, topic_id, kind, limit),
             None => Err(MessagesSourceError::Unavailable),
         }
     }
@@ -82,9 +97,12 @@ impl MessageSender for TelegramAdapter {
         topic_id: Option<i32>,
         text: &str,
         reply_to_message_id: Option<i64>,
+        mentions: &[TextMention],
     ) -> Result<(), SendMessageSourceError> {
         match self.tdlib_backend.as_ref() {
-            Some(backend) => backend.send_message(chat_id, topic_id, text, reply_to_message_id),
+            Some(backend) => {
+                backend.send_message(chat_id, topic_id, text, reply_to_message_id, mentions)
+            }
             None => Err(SendMessageSourceError::Unavailable),
         }
     }
@@ -122,33 +140,30 @@ impl VoiceNoteSender for TelegramAdapter {
     }
 }
 
-impl ChatLifecycle for TelegramAdapter {
-    fn open_chat(&self, chat_id: i64) -> Result<(), ChatLifecycleError> {
-        match self.tdlib_backend.as_ref() {
-            Some(backend) => backend.open_chat(chat_id).map_err(|e| {
-                tracing::debug!(chat_id, error = ?e, "open_chat mapped to lifecycle error");
-                ChatLifecycleError::Unavailable
-            }),
-            None => Err(ChatLifecycleError::Unavailable),
-        }
-    }
-
-    fn close_chat(&self, chat_id: i64) -> Result<(), ChatLifecycleError> {
+impl MessageDeleter for TelegramAdapter {
+    fn delete_messages(
+        &self,
+        chat_id: i64,
+        message_ids: Vec<i64>,
+        revoke: bool,
+    ) -> Result<(), ChatLifecycleError> {
         match self.tdlib_backend.as_ref() {
-            Some(backend) => backend.close_chat(chat_id).map_err(|e| {
-                tracing::debug!(chat_id, error = ?e, "close_chat mapped to lifecycle error");
+            Some(backend) => backend.delete_messages(chat_id, message_ids, revoke).map_err(|e| {
+                tracing::debug!(chat_id, error = ?e, "delete_messages mapped to lifecycle error");
                 ChatLifecycleError::Unavailable
             }),
             None => Err(ChatLifecycleError::Unavailable),
         }
     }
-}
 
-impl FileDownloader for TelegramAdapter {
-    fn download_file(&self, file_id: i32) -> Result<(), ChatLifecycleError> {
+    fn delete_permissions(
+        &self,
+        chat_id: i64,
+        message_ids: &[i64],
+    ) -> Result<DeletePermissions, ChatLifecycleError> {
         match self.tdlib_backend.as_ref() {
-            Some(backend) => backend.download_file(file_id).map_err(|e| {
-                tracing::debug!(file_id, error = ?e, "download_file mapped to lifecycle error");
+            Some(backend) => backend.delete_permissions(chat_id, message_ids).map_err(|e| {
+                tracing::debug!(chat_id, error = ?e, "delete_permissions mapped to lifecycle error");
                 ChatLifecycleError::Unavailable
             }),
             None => Err(ChatLifecycleError::Unavailable),
@@ -156,18 +171,18 @@ impl FileDownloader for TelegramAdapter {
     }
 }
 
-impl ChatReadMarker for TelegramAdapter {
-    fn mark_messages_read(
+impl MessageForwarder for TelegramAdapter {
+    fn forward_messages(
         &self,
-        chat_id: i64,
-        topic_id: Option<i32>,
+        to_chat_id: i64,
+        from_chat_id: i64,
         message_ids: Vec<i64>,
     ) -> Result<(), ChatLifecycleError> {
         match self.tdlib_backend.as_ref() {
             Some(backend) => backend
-                .view_messages(chat_id, topic_id, message_ids)
+                .forward_messages(to_chat_id, from_chat_id, message_ids)
                 .map_err(|e| {
-                    tracing::debug!(chat_id, error = ?e, "view_messages mapped to lifecycle error");
+                    tracing::debug!(to_chat_id, error = ?e, "forward_messages mapped to lifecycle error");
                     ChatLifecycleError::Unavailable
                 }),
             None => Err(ChatLifecycleError::Unavailable),
@@ -175,47 +190,6 @@ impl ChatReadMarker for TelegramAdapter {
     }
 }
 
-impl MessageDeleter for TelegramAdapter {
-    fn delete_messages(
-        &self,
-        chat_id: i64,
-        message_ids: Vec<i64>,
-        revoke: bool,
-    ) -> Result<(), ChatLifecycleError> {
-        match self.tdlib_backend.as_ref() {
-            Some(backend) => backend.delete_messages(chat_id, message_ids, revoke).map_err(|e| {
-                tracing::debug!(chat_id, error = ?e, "delete_messages mapped to lifecycle error");
-                ChatLifecycleError::Unavailable
-            }),
-            None => Err(ChatLifecycleError::Unavailable),
-        }
-    }
-}
-
-impl ChatSubtitleSource for TelegramAdapter {
-    fn resolve_chat_subtitle(
-        &self,
-        query: &ChatSubtitleQuery,
-    ) -> Result<crate::domain::chat_subtitle::ChatSubtitle, ChatSubtitleError> {
-        match self.tdlib_backend.as_ref() {
-            Some(backend) => Ok(backend.resolve_subtitle(query.chat_id)),
-            None => Err(ChatSubtitleError::Unavailable),
-        }
-    }
-
-    fn resolve_chat_info(
-        &self,
-        query: &ChatInfoQuery,
-    ) -> Result<crate::domain::chat_info_state::ChatInfo, ChatSubtitleError> {
-        match self.tdlib_backend.as_ref() {
-            Some(backend) => {
-                Ok(backend.resolve_chat_info(query.chat_id, query.chat_type, query.title.clone()))
-            }
-            None => Err(ChatSubtitleError::Unavailable),
-        }
-    }
-}
-
 impl MessageInfoSource for TelegramAdapter {
     fn resolve_message_info(
         &self,
@@ -253,3 +227,15 @@ impl ReactionSource for TelegramAdapter {
         }
     }
 }
+
+impl CallbackQuerySource for TelegramAdapter {
+    fn get_callback_answer(
+        &self,
+        query: &CallbackQuery,
+    ) -> Result<CallbackAnswer, CallbackQueryError> {
+        match self.tdlib_backend.as_ref() {
+            Some(backend) => backend.get_callback_answer(query),
+            None => Err(CallbackQueryError::Unavailable),
+        }
+    }
+}
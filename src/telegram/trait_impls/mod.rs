@@ -0,0 +1,7 @@
+//! Implementations of the use case ports for [`TelegramAdapter`](super::TelegramAdapter),
+//! one file per feature area.
+
+mod chat_lookup;
+mod chat_management;
+mod chats;
+mod messages;
@@ -0,0 +1,34 @@
+use ratatui::{
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Padding, Paragraph, Wrap},
+    Frame,
+};
+
+use super::{popup_utils, styles};
+
+/// Renders a bot alert (callback answer with `show_alert`) that stays
+/// until dismissed.
+pub fn render_alert_popup(frame: &mut Frame<'_>, area: Rect, text: &str) {
+    let popup_area = popup_utils::centered_rect(area, 50, 30);
+
+    frame.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(" Alert ")
+        .borders(Borders::ALL)
+        .border_style(styles::help_popup_border_style())
+        .padding(Padding::horizontal(1));
+
+    let mut lines: Vec<Line<'_>> = text.lines().map(Line::from).collect();
+    lines.push(Line::default());
+    lines.push(Line::from(Span::styled(
+        "Press Enter, q or Esc to close",
+        styles::help_popup_footer_style(),
+    )));
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .wrap(Wrap { trim: false });
+    frame.render_widget(paragraph, popup_area);
+}
@@ -0,0 +1,143 @@
+use ratatui::{
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Padding, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::domain::chat_manage_state::ChatConfirmState;
+
+use super::{popup_utils, styles};
+
+pub fn render_chat_confirm_popup(frame: &mut Frame<'_>, area: Rect, state: &ChatConfirmState) {
+    let popup_area = popup_utils::centered_rect(area, 50, 35);
+
+    frame.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(format!(" {} ", state.heading()))
+        .borders(Borders::ALL)
+        .border_style(styles::command_popup_border_style())
+        .padding(Padding::horizontal(1));
+
+    let paragraph = Paragraph::new(build_lines(state))
+        .block(block)
+        .wrap(Wrap { trim: false });
+    frame.render_widget(paragraph, popup_area);
+}
+
+fn build_lines(state: &ChatConfirmState) -> Vec<Line<'static>> {
+    let mut lines = vec![
+        Line::from(Span::styled(
+            state.question(),
+            styles::help_popup_action_style(),
+        )),
+        Line::default(),
+        Line::from(Span::styled(
+            state.explanation(),
+            styles::help_popup_footer_style(),
+        )),
+        Line::default(),
+    ];
+
+    let options = state.options();
+    if options.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "Nothing can be done here",
+            styles::command_popup_error_style(),
+        )));
+    }
+    for option in options {
+        lines.push(Line::from(vec![
+            Span::styled(format!("{}  ", option.key), styles::help_popup_key_style()),
+            Span::styled(option.label, styles::help_popup_action_style()),
+        ]));
+    }
+
+    lines.push(Line::default());
+    lines.push(Line::from(Span::styled(
+        "n, q or Esc to cancel",
+        styles::help_popup_footer_style(),
+    )));
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{
+        chat::{ChatSummary, ChatType},
+        chat_manage_state::ChatConfirmKind,
+    };
+
+    fn text(lines: &[Line<'_>]) -> String {
+        lines
+            .iter()
+            .map(|l| {
+                l.spans
+                    .iter()
+                    .map(|s| s.content.as_ref())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn summary(chat_type: ChatType) -> ChatSummary {
+        ChatSummary {
+            chat_id: 1,
+            title: "Rust News".to_owned(),
+            unread_count: 0,
+            last_message_preview: None,
+            last_message_unix_ms: None,
+            is_pinned: false,
+            chat_type,
+            last_message_sender: None,
+            is_online: None,
+            is_bot: false,
+            outgoing_status: Default::default(),
+            last_message_id: None,
+            unread_reaction_count: 0,
+            unread_mention_count: 0,
+            is_forum: false,
+            unread_topic_count: None,
+            is_muted: false,
+            is_marked_as_unread: false,
+            is_blocked: false,
+            username: None,
+        }
+    }
+
+    #[test]
+    fn leaving_a_channel_explains_the_consequences() {
+        let state = ChatConfirmState::new(&summary(ChatType::Channel), ChatConfirmKind::Remove);
+
+        let rendered = text(&build_lines(&state));
+
+        assert!(rendered.contains("Leave the channel Rust News?"));
+        assert!(rendered.contains("stop receiving posts"));
+        assert!(rendered.contains("y  Leave channel"));
+        assert!(rendered.contains("n, q or Esc to cancel"));
+    }
+
+    #[test]
+    fn clearing_a_private_chat_offers_both_options() {
+        let state =
+            ChatConfirmState::new(&summary(ChatType::Private), ChatConfirmKind::ClearHistory);
+
+        let rendered = text(&build_lines(&state));
+
+        assert!(rendered.contains("m  Clear just for me"));
+        assert!(rendered.contains("e  Clear for both"));
+    }
+
+    #[test]
+    fn says_so_when_nothing_is_allowed() {
+        let state =
+            ChatConfirmState::new(&summary(ChatType::Channel), ChatConfirmKind::ClearHistory);
+
+        let rendered = text(&build_lines(&state));
+
+        assert!(rendered.contains("Nothing can be done here"));
+    }
+}
@@ -12,6 +12,8 @@ mod rendering;
 #[cfg(test)]
 mod tests;
 
+use std::ops::RangeInclusive;
+
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
@@ -59,6 +61,8 @@ pub struct ChatMessageList<'a> {
     items: Vec<ChatMessageListItem<'a>>,
     block: Option<Block<'a>>,
     highlight_style: Style,
+    /// Items styled as part of a range selection.
+    marked: Option<(RangeInclusive<usize>, Style)>,
     scroll_padding: usize,
 }
 
@@ -95,6 +99,7 @@ impl<'a> ChatMessageList<'a> {
             items: items.into_iter().map(Into::into).collect(),
             block: None,
             highlight_style: Style::default(),
+            marked: None,
             scroll_padding: 0,
         }
     }
@@ -109,6 +114,11 @@ impl<'a> ChatMessageList<'a> {
         self
     }
 
+    pub fn marked(mut self, items: RangeInclusive<usize>, style: Style) -> Self {
+        self.marked = Some((items, style));
+        self
+    }
+
     pub fn scroll_padding(mut self, padding: usize) -> Self {
         self.scroll_padding = padding;
         self
@@ -158,6 +168,7 @@ impl<'a> StatefulWidget for ChatMessageList<'a> {
             buf,
             state.selected,
             self.highlight_style,
+            self.marked.as_ref(),
         );
 
         state.offset = scroll;
@@ -1,3 +1,5 @@
+use std::ops::RangeInclusive;
+
 use ratatui::{buffer::Buffer, layout::Rect, style::Style};
 
 use crate::domain::open_chat_state::ScrollOffset;
@@ -11,6 +13,8 @@ struct RenderCtx {
 }
 
 /// Renders items into the viewport, clipping the first item's top lines as needed.
+///
+/// Items in `marked` get its style; the selected item's highlight wins.
 pub(super) fn render_items(
     items: &[ChatMessageListItem<'_>],
     offset: ScrollOffset,
@@ -18,6 +22,7 @@ pub(super) fn render_items(
     buf: &mut Buffer,
     selected: Option<usize>,
     highlight_style: Style,
+    marked: Option<&(RangeInclusive<usize>, Style)>,
 ) {
     let ctx = RenderCtx {
         area,
@@ -41,9 +46,15 @@ pub(super) fn render_items(
         }
 
         let lines_to_render = visible_lines_in_item.min(viewport_height - y);
-        let is_selected = selected == Some(item_idx);
+        let line_style = if selected == Some(item_idx) {
+            Some(ctx.highlight_style)
+        } else {
+            marked
+                .filter(|(range, _)| range.contains(&item_idx))
+                .map(|(_, style)| *style)
+        };
 
-        render_item_lines(item, skip_lines, lines_to_render, y, buf, is_selected, &ctx);
+        render_item_lines(item, skip_lines, lines_to_render, y, buf, line_style, &ctx);
 
         y += lines_to_render;
         item_idx += 1;
@@ -60,7 +71,7 @@ fn render_item_lines(
     lines_to_render: usize,
     y_offset: usize,
     buf: &mut Buffer,
-    is_selected: bool,
+    line_style: Option<Style>,
     ctx: &RenderCtx,
 ) {
     let area = ctx.area;
@@ -103,8 +114,8 @@ fn render_item_lines(
 
         // Apply highlight AFTER content so it overrides text colors (matches
         // ratatui List behavior: selected row gets uniform fg/bg).
-        if is_selected {
-            buf.set_style(line_area, ctx.highlight_style);
+        if let Some(style) = line_style {
+            buf.set_style(line_area, style);
         }
     }
 }
@@ -119,3 +119,28 @@ fn highlight_style_overrides_span_colors() {
     assert_eq!(cell_w.fg, Color::Black);
     assert_eq!(cell_w.bg, Color::Gray);
 }
+
+#[test]
+fn marked_items_are_styled_and_selection_wins() {
+    use ratatui::style::Color;
+
+    let texts: Vec<Text<'_>> = ["one", "two", "three"]
+        .into_iter()
+        .map(|t| Text::from(vec![Line::raw(t)]))
+        .collect();
+    let highlight = Style::default().bg(Color::Gray);
+    let marked = Style::default().bg(Color::Blue);
+    let widget = ChatMessageList::new(texts)
+        .highlight_style(highlight)
+        .marked(0..=1, marked);
+
+    let area = Rect::new(0, 0, 10, 3);
+    let mut buf = Buffer::empty(area);
+    let mut state = ChatMessageListState::new(ScrollOffset::ZERO, Some(1));
+
+    widget.render(area, &mut buf, &mut state);
+
+    assert_eq!(buf[(0, 0)].bg, Color::Blue);
+    assert_eq!(buf[(0, 1)].bg, Color::Gray);
+    assert_eq!(buf[(0, 2)].bg, Color::Reset);
+}
@@ -1,33 +1,36 @@
 use ratatui::{
     layout::Rect,
+    style::Style,
     text::{Line, Span},
     widgets::{Block, Borders, Clear, Padding, Paragraph},
     Frame,
 };
 use unicode_width::UnicodeWidthStr;
 
-use crate::domain::chat_search_state::ChatSearchState;
+use crate::domain::chat_search_state::{ChatSearchResult, ChatSearchState};
 
 use super::{popup_utils, styles};
 
+/// Rows above the results: the query line and a blank line.
+const HEADER_ROWS: usize = 2;
+
 pub fn render_chat_search_popup(frame: &mut Frame<'_>, area: Rect, state: &ChatSearchState) {
-    let centered = popup_utils::centered_rect(area, 50, 70);
-    let popup_area = Rect::new(centered.x, centered.y, centered.width, 3.min(area.height));
+    let popup_area = popup_utils::centered_rect(area, 50, 70);
 
     frame.render_widget(Clear, popup_area);
 
     let block = Block::default()
         .title(" Search ")
+        .title_bottom(Span::styled(
+            " ↑/↓ select · Enter open · Esc close ",
+            styles::help_popup_footer_style(),
+        ))
         .borders(Borders::ALL)
         .border_style(styles::help_popup_border_style())
         .padding(Padding::horizontal(1));
 
-    let line = Line::from(vec![
-        Span::styled("/", styles::help_popup_key_style()),
-        Span::styled(state.query().to_owned(), styles::help_popup_action_style()),
-    ]);
-
-    let paragraph = Paragraph::new(line).block(block);
+    let rows = usize::from(popup_area.height.saturating_sub(2));
+    let paragraph = Paragraph::new(build_lines(state, rows)).block(block);
     frame.render_widget(paragraph, popup_area);
 
     let cursor_x = popup_area.x + 2 + 1 + UnicodeWidthStr::width(state.query()) as u16;
@@ -36,3 +39,195 @@ pub fn render_chat_search_popup(frame: &mut Frame<'_>, area: Rect, state: &ChatS
         frame.set_cursor_position((cursor_x, cursor_y));
     }
 }
+
+/// Builds at most `rows` lines: the query, then the results scrolled so
+/// the selected one is visible.
+fn build_lines(state: &ChatSearchState, rows: usize) -> Vec<Line<'static>> {
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("/", styles::help_popup_key_style()),
+            Span::styled(state.query().to_owned(), styles::help_popup_action_style()),
+        ]),
+        Line::default(),
+    ];
+
+    let results = state.results();
+    if results.is_empty() {
+        let hint = if state.query().is_empty() {
+            "Type to search all chats by name or @username"
+        } else if state.is_searching() {
+            "Searching..."
+        } else {
+            "No chats found"
+        };
+        lines.push(Line::from(Span::styled(
+            hint,
+            styles::help_popup_footer_style(),
+        )));
+        return lines;
+    }
+
+    let result_rows = rows.saturating_sub(HEADER_ROWS).max(1);
+    let selected = state.selected_index().unwrap_or(0);
+    let first = (selected + 1).saturating_sub(result_rows);
+    lines.extend(
+        results
+            .iter()
+            .enumerate()
+            .skip(first)
+            .take(result_rows)
+            .map(|(idx, result)| result_line(result, idx == selected)),
+    );
+    lines
+}
+
+fn result_line(result: &ChatSearchResult, selected: bool) -> Line<'static> {
+    let marker = if selected { "> " } else { "  " };
+    let mut spans = vec![Span::styled(marker, styles::help_popup_key_style())];
+    spans.extend(highlighted_spans(
+        &result.chat.title,
+        &result.title_matches,
+        styles::chat_name_style(),
+    ));
+    if let Some(username) = &result.chat.username {
+        spans.push(Span::styled("  @", styles::completion_detail_style()));
+        spans.extend(highlighted_spans(
+            username,
+            &result.username_matches,
+            styles::completion_detail_style(),
+        ));
+    }
+    Line::from(spans)
+}
+
+/// Splits `text` into spans, styling the chars at `matches` as matches.
+pub(super) fn highlighted_spans(text: &str, matches: &[usize], style: Style) -> Vec<Span<'static>> {
+    let mut spans: Vec<Span<'static>> = Vec::new();
+    let mut run = String::new();
+    let mut run_matched = false;
+
+    for (index, ch) in text.chars().enumerate() {
+        let matched = matches.contains(&index);
+        if matched != run_matched && !run.is_empty() {
+            spans.push(run_span(std::mem::take(&mut run), run_matched, style));
+        }
+        run_matched = matched;
+        run.push(ch);
+    }
+    if !run.is_empty() {
+        spans.push(run_span(run, run_matched, style));
+    }
+    spans
+}
+
+fn run_span(text: String, matched: bool, style: Style) -> Span<'static> {
+    if matched {
+        Span::styled(text, style.patch(styles::search_match_style()))
+    } else {
+        Span::styled(text, style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::chat::{ChatSummary, ChatType};
+
+    fn text(lines: &[Line<'_>]) -> String {
+        lines
+            .iter()
+            .map(|l| {
+                l.spans
+                    .iter()
+                    .map(|s| s.content.as_ref())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn chat(chat_id: i64, title: &str, username: Option<&str>) -> ChatSummary {
+        ChatSummary {
+            chat_id,
+            title: title.to_owned(),
+            unread_count: 0,
+            last_message_preview: None,
+            last_message_unix_ms: None,
+            is_pinned: false,
+            chat_type: ChatType::Group,
+            last_message_sender: None,
+            is_online: None,
+            is_bot: false,
+            outgoing_status: Default::default(),
+            last_message_id: None,
+            unread_reaction_count: 0,
+            unread_mention_count: 0,
+            is_forum: false,
+            unread_topic_count: None,
+            is_muted: false,
+            is_marked_as_unread: false,
+            is_blocked: false,
+            username: username.map(str::to_owned),
+        }
+    }
+
+    fn search(chats: Vec<ChatSummary>, query: &str) -> ChatSearchState {
+        let mut state = ChatSearchState::new(chats);
+        for ch in query.chars() {
+            state.insert_char(ch);
+        }
+        state
+    }
+
+    #[test]
+    fn lists_results_with_usernames_and_marks_selection() {
+        let state = search(
+            vec![
+                chat(1, "Rust News", Some("rustnews")),
+                chat(2, "Trust fund", None),
+            ],
+            "rust",
+        );
+
+        let rendered = text(&build_lines(&state, 10));
+
+        assert!(rendered.starts_with("/rust\n"));
+        assert!(rendered.contains("> Rust News  @rustnews"));
+        assert!(rendered.contains("  Trust fund"));
+    }
+
+    #[test]
+    fn matched_characters_are_highlighted() {
+        let spans = highlighted_spans("Rust News", &[0, 5], styles::chat_name_style());
+
+        let contents: Vec<&str> = spans.iter().map(|s| s.content.as_ref()).collect();
+        assert_eq!(contents, vec!["R", "ust ", "N", "ews"]);
+        assert_eq!(spans[0].style.fg, styles::search_match_style().fg);
+        assert_eq!(spans[1].style, styles::chat_name_style());
+    }
+
+    #[test]
+    fn says_so_when_nothing_matches() {
+        let mut state = search(vec![chat(1, "Rust News", None)], "zz");
+        state.begin_request();
+        state.store_found("zz".to_owned(), Vec::new(), true);
+
+        assert!(text(&build_lines(&state, 10)).contains("No chats found"));
+    }
+
+    #[test]
+    fn scrolls_to_keep_selection_visible() {
+        let chats = (1..=10)
+            .map(|i| chat(i, &format!("Chat {i}"), None))
+            .collect();
+        let mut state = search(chats, "chat");
+        for _ in 0..6 {
+            state.select_next();
+        }
+
+        let lines = build_lines(&state, 5);
+
+        assert_eq!(lines.len(), 5);
+        assert!(text(&lines).contains("> Chat 7"));
+    }
+}
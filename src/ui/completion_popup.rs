@@ -0,0 +1,124 @@
+use ratatui::{
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::domain::completion_state::CompletionState;
+
+use super::styles;
+
+const MAX_VISIBLE_ITEMS: usize = 8;
+
+/// Renders completion candidates in a box right above `input_area`,
+/// clipped to `bounds`.
+pub fn render_completion_popup(
+    frame: &mut Frame<'_>,
+    input_area: Rect,
+    bounds: Rect,
+    state: &CompletionState,
+) {
+    let visible = state.items().len().min(MAX_VISIBLE_ITEMS) as u16;
+    let height = (visible + 2).min(input_area.y.saturating_sub(bounds.y));
+    if height < 3 {
+        return;
+    }
+    let popup_area = Rect {
+        x: input_area.x,
+        y: input_area.y - height,
+        width: input_area.width,
+        height,
+    };
+
+    frame.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(styles::help_popup_border_style())
+        .title_bottom(Span::styled(
+            " Tab complete ",
+            styles::help_popup_footer_style(),
+        ));
+
+    let paragraph = Paragraph::new(build_lines(state, usize::from(height - 2))).block(block);
+    frame.render_widget(paragraph, popup_area);
+}
+
+/// Builds at most `rows` lines, scrolled so the selected item is visible.
+fn build_lines(state: &CompletionState, rows: usize) -> Vec<Line<'static>> {
+    let first = (state.selected_index() + 1).saturating_sub(rows);
+
+    state
+        .items()
+        .iter()
+        .enumerate()
+        .skip(first)
+        .take(rows)
+        .map(|(idx, item)| {
+            if idx == state.selected_index() {
+                let text = if item.detail.is_empty() {
+                    format!(" {} ", item.label)
+                } else {
+                    format!(" {}  {} ", item.label, item.detail)
+                };
+                return Line::from(Span::styled(text, styles::completion_selected_style()));
+            }
+
+            let mut spans = vec![Span::styled(
+                format!(" {}", item.label),
+                styles::input_text_style(),
+            )];
+            if !item.detail.is_empty() {
+                spans.push(Span::styled(
+                    format!("  {}", item.detail),
+                    styles::completion_detail_style(),
+                ));
+            }
+            Line::from(spans)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::completion_state::CompletionItem;
+
+    fn state(count: usize) -> CompletionState {
+        let items = (0..count)
+            .map(|i| CompletionItem {
+                label: format!("/cmd{i}"),
+                detail: format!("Command {i}"),
+                insert_text: format!("/cmd{i} "),
+                mention_user_id: None,
+            })
+            .collect();
+        CompletionState::new(0, items).unwrap()
+    }
+
+    fn line_text(line: &Line<'_>) -> String {
+        line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    #[test]
+    fn lines_show_label_and_detail() {
+        let lines = build_lines(&state(2), 8);
+
+        assert_eq!(lines.len(), 2);
+        assert_eq!(line_text(&lines[0]), " /cmd0  Command 0 ");
+        assert_eq!(lines[0].spans[0].style, styles::completion_selected_style());
+        assert_eq!(line_text(&lines[1]), " /cmd1  Command 1");
+    }
+
+    #[test]
+    fn long_list_scrolls_to_selection() {
+        let mut s = state(5);
+        s.select_previous();
+
+        let lines = build_lines(&s, 3);
+
+        assert_eq!(lines.len(), 3);
+        assert!(line_text(&lines[2]).starts_with(" /cmd4"));
+    }
+}
@@ -0,0 +1,144 @@
+use ratatui::{
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Padding, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::domain::delete_confirm_state::DeleteConfirmState;
+
+use super::{popup_utils, styles};
+
+pub fn render_delete_confirm_popup(frame: &mut Frame<'_>, area: Rect, state: &DeleteConfirmState) {
+    let popup_area = popup_utils::centered_rect(area, 50, 30);
+
+    frame.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(" Delete ")
+        .borders(Borders::ALL)
+        .border_style(styles::command_popup_border_style())
+        .padding(Padding::horizontal(1));
+
+    let paragraph = Paragraph::new(build_lines(state))
+        .block(block)
+        .wrap(Wrap { trim: false });
+    frame.render_widget(paragraph, popup_area);
+}
+
+fn build_lines(state: &DeleteConfirmState) -> Vec<Line<'static>> {
+    let count = state.message_ids().len();
+    let question = if count == 1 {
+        "Delete this message?".to_owned()
+    } else {
+        format!("Delete {count} messages?")
+    };
+    let mut lines = vec![
+        Line::from(Span::styled(question, styles::help_popup_action_style())),
+        Line::default(),
+    ];
+
+    if state.is_loading() {
+        lines.push(Line::from(Span::styled(
+            "Checking permissions...",
+            styles::help_popup_footer_style(),
+        )));
+    } else if state.cannot_delete() {
+        lines.push(Line::from(Span::styled(
+            "You can't delete this here",
+            styles::command_popup_error_style(),
+        )));
+    } else {
+        if state.is_unknown() {
+            lines.push(Line::from(Span::styled(
+                "Couldn't check permissions",
+                styles::command_popup_error_style(),
+            )));
+        }
+        if state.can_delete_for_me() {
+            lines.push(option_line("m", "Delete for me"));
+        }
+        if state.can_delete_for_everyone() {
+            lines.push(option_line("e", "Delete for everyone"));
+        } else {
+            lines.push(Line::from(Span::styled(
+                "Can't be deleted for everyone",
+                styles::help_popup_footer_style(),
+            )));
+        }
+    }
+
+    lines.push(Line::default());
+    lines.push(Line::from(Span::styled(
+        "n, q or Esc to cancel",
+        styles::help_popup_footer_style(),
+    )));
+    lines
+}
+
+fn option_line(key: &'static str, label: &'static str) -> Line<'static> {
+    Line::from(vec![
+        Span::styled(format!("{key}  "), styles::help_popup_key_style()),
+        Span::styled(label, styles::help_popup_action_style()),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::delete_confirm_state::DeletePermissions;
+
+    fn text(lines: &[Line<'_>]) -> String {
+        lines
+            .iter()
+            .map(|l| {
+                l.spans
+                    .iter()
+                    .map(|s| s.content.as_ref())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn shows_loading_until_permissions_arrive() {
+        let state = DeleteConfirmState::new(1, vec![10, 11]);
+
+        let rendered = text(&build_lines(&state));
+
+        assert!(rendered.contains("Delete 2 messages?"));
+        assert!(rendered.contains("Checking permissions"));
+        assert!(!rendered.contains("Delete for me"));
+    }
+
+    #[test]
+    fn offers_only_allowed_options() {
+        let mut state = DeleteConfirmState::new(1, vec![10]);
+        state.resolve(Some(DeletePermissions {
+            for_self: true,
+            for_everyone: false,
+        }));
+
+        let rendered = text(&build_lines(&state));
+
+        assert!(rendered.contains("Delete this message?"));
+        assert!(rendered.contains("m  Delete for me"));
+        assert!(!rendered.contains("e  Delete for everyone"));
+        assert!(rendered.contains("Can't be deleted for everyone"));
+    }
+
+    #[test]
+    fn says_so_when_nothing_is_allowed() {
+        let mut state = DeleteConfirmState::new(1, vec![10]);
+        state.resolve(Some(DeletePermissions {
+            for_self: false,
+            for_everyone: false,
+        }));
+
+        let rendered = text(&build_lines(&state));
+
+        assert!(rendered.contains("You can't delete this here"));
+        assert!(!rendered.contains("Delete for me"));
+    }
+}
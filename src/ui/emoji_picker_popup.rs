@@ -0,0 +1,173 @@
+use ratatui::{
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Padding, Paragraph},
+    Frame,
+};
+
+use crate::domain::{
+    emoji::EmojiCategory,
+    emoji_picker_state::{EmojiPickerState, EMOJI_GRID_COLUMNS},
+};
+
+use super::{popup_utils, styles};
+
+const PLACEHOLDER: &str = "type to search";
+
+/// Lines around the grid: query, tabs, blank line, shortcode, footer.
+const CHROME_LINES: usize = 5;
+
+pub fn render_emoji_picker(frame: &mut Frame<'_>, area: Rect, state: &EmojiPickerState) {
+    let popup_area = popup_utils::centered_rect(area, 60, 60);
+
+    frame.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(" Emoji ")
+        .borders(Borders::ALL)
+        .border_style(styles::help_popup_border_style())
+        .padding(Padding::horizontal(1));
+
+    let inner_height = popup_area.height.saturating_sub(2) as usize;
+    let grid_rows = inner_height.saturating_sub(CHROME_LINES).max(1);
+    let paragraph = Paragraph::new(build_lines(state, grid_rows)).block(block);
+    frame.render_widget(paragraph, popup_area);
+}
+
+fn build_lines(state: &EmojiPickerState, grid_rows: usize) -> Vec<Line<'static>> {
+    let query = if state.query().is_empty() {
+        Span::styled(PLACEHOLDER, styles::input_placeholder_style())
+    } else {
+        Span::styled(state.query().to_owned(), styles::help_popup_action_style())
+    };
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("> ", styles::help_popup_key_style()),
+            query,
+        ]),
+        category_tabs(state.category()),
+        Line::from(""),
+    ];
+
+    if state.visible().is_empty() {
+        lines.push(Line::from(Span::styled(
+            "No emoji found",
+            styles::completion_detail_style(),
+        )));
+    } else {
+        let selected_row = state.selected_index() / EMOJI_GRID_COLUMNS;
+        let first_row = (selected_row + 1).saturating_sub(grid_rows);
+        let cells = state
+            .visible()
+            .chunks(EMOJI_GRID_COLUMNS)
+            .enumerate()
+            .skip(first_row)
+            .take(grid_rows);
+        for (row, emoji_row) in cells {
+            let spans = emoji_row
+                .iter()
+                .enumerate()
+                .map(|(col, emoji)| {
+                    let style = if row * EMOJI_GRID_COLUMNS + col == state.selected_index() {
+                        styles::completion_selected_style()
+                    } else {
+                        styles::help_popup_action_style()
+                    };
+                    Span::styled(format!(" {} ", emoji.emoji), style)
+                })
+                .collect::<Vec<_>>();
+            lines.push(Line::from(spans));
+        }
+    }
+
+    let shortcode = state
+        .selected_emoji()
+        .map(|e| format!(":{}:", e.name))
+        .unwrap_or_default();
+    lines.push(Line::from(Span::styled(
+        shortcode,
+        styles::completion_detail_style(),
+    )));
+    lines.push(Line::from(Span::styled(
+        "Tab category, arrows move, Enter insert, Esc close",
+        styles::help_popup_footer_style(),
+    )));
+    lines
+}
+
+/// Category titles with the shown one highlighted; a single
+/// "Search results" label while a query is typed.
+fn category_tabs(current: Option<EmojiCategory>) -> Line<'static> {
+    let Some(current) = current else {
+        return Line::from(Span::styled(
+            "Search results",
+            styles::help_popup_key_style(),
+        ));
+    };
+
+    let spans = EmojiCategory::ALL
+        .iter()
+        .flat_map(|&category| {
+            let style = if category == current {
+                styles::completion_selected_style()
+            } else {
+                styles::completion_detail_style()
+            };
+            [
+                Span::styled(format!(" {} ", category.title()), style),
+                Span::raw(" "),
+            ]
+        })
+        .collect::<Vec<_>>();
+    Line::from(spans)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line_text(line: &Line<'_>) -> String {
+        line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    #[test]
+    fn shows_tabs_grid_and_selected_shortcode() {
+        let state = EmojiPickerState::default();
+
+        let lines = build_lines(&state, 3);
+
+        assert!(line_text(&lines[0]).contains(PLACEHOLDER));
+        assert!(line_text(&lines[1]).contains("Smileys"));
+        assert!(line_text(&lines[1]).contains("Symbols"));
+        assert_eq!(lines[3].spans.len(), EMOJI_GRID_COLUMNS);
+        assert_eq!(lines.len(), 3 + 3 + 2);
+        assert_eq!(line_text(&lines[6]), ":grinning:");
+    }
+
+    #[test]
+    fn grid_scrolls_to_keep_selection_visible() {
+        let mut state = EmojiPickerState::default();
+        for _ in 0..4 {
+            state.move_down();
+        }
+
+        let lines = build_lines(&state, 2);
+
+        let selected = state.selected_emoji().unwrap().emoji;
+        assert!(line_text(&lines[4]).starts_with(&format!(" {selected} ")));
+    }
+
+    #[test]
+    fn search_without_results_says_so() {
+        let mut state = EmojiPickerState::default();
+        for ch in "zzqx".chars() {
+            state.insert_char(ch);
+        }
+
+        let lines = build_lines(&state, 3);
+
+        assert!(line_text(&lines[0]).contains("zzqx"));
+        assert_eq!(line_text(&lines[1]), "Search results");
+        assert_eq!(line_text(&lines[3]), "No emoji found");
+    }
+}
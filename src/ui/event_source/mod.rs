@@ -252,10 +252,11 @@ fn map_key_event(key: KeyEvent) -> Option<AppEvent> {
     }
 
     if let KeyCode::Char(ch) = key.code {
-        return Some(AppEvent::InputKey(KeyInput::new(
-            ch.to_string(),
-            key.modifiers.contains(KeyModifiers::CONTROL),
-        )));
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        // Legacy terminals send Ctrl+^ as Ctrl+6; report it the same way
+        // when the terminal reports the `^` itself.
+        let ch = if ctrl && ch == '^' { '6' } else { ch };
+        return Some(AppEvent::InputKey(KeyInput::new(ch.to_string(), ctrl)));
     }
 
     let special_key = match key.code {
@@ -268,6 +269,10 @@ fn map_key_event(key: KeyEvent) -> Option<AppEvent> {
         KeyCode::Right => Some("right"),
         KeyCode::Home => Some("home"),
         KeyCode::End => Some("end"),
+        KeyCode::Up => Some("up"),
+        KeyCode::Down => Some("down"),
+        KeyCode::Tab => Some("tab"),
+        KeyCode::BackTab => Some("backtab"),
         _ => None,
     };
 
@@ -40,6 +40,10 @@ fn special_keys_produce_named_input() {
         (KeyCode::Right, "right"),
         (KeyCode::Home, "home"),
         (KeyCode::End, "end"),
+        (KeyCode::Up, "up"),
+        (KeyCode::Down, "down"),
+        (KeyCode::Tab, "tab"),
+        (KeyCode::BackTab, "backtab"),
     ];
     for (code, expected) in cases {
         let event = map_key_event(KeyEvent::new(code, KeyModifiers::NONE));
@@ -67,6 +71,12 @@ fn ctrl_o_produces_ctrl_input_key() {
     assert_eq!(event, Some(AppEvent::InputKey(KeyInput::new("o", true))));
 }
 
+#[test]
+fn ctrl_caret_produces_ctrl_6() {
+    let event = map_key_event(KeyEvent::new(KeyCode::Char('^'), KeyModifiers::CONTROL));
+    assert_eq!(event, Some(AppEvent::InputKey(KeyInput::new("6", true))));
+}
+
 #[test]
 fn unknown_special_key_returns_none() {
     let event = map_key_event(KeyEvent::new(KeyCode::F(1), KeyModifiers::NONE));
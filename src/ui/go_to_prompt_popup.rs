@@ -0,0 +1,46 @@
+use ratatui::{
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Padding, Paragraph},
+    Frame,
+};
+use unicode_width::UnicodeWidthStr;
+
+use crate::domain::go_to_prompt_state::GoToPromptState;
+
+use super::{popup_utils, styles};
+
+const PLACEHOLDER: &str = "@username, +phone or contact name";
+
+pub fn render_go_to_prompt_popup(frame: &mut Frame<'_>, area: Rect, state: &GoToPromptState) {
+    let centered = popup_utils::centered_rect(area, 50, 70);
+    let popup_area = Rect::new(centered.x, centered.y, centered.width, 3.min(area.height));
+
+    frame.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(" Go to chat ")
+        .borders(Borders::ALL)
+        .border_style(styles::help_popup_border_style())
+        .padding(Padding::horizontal(1));
+
+    let input = if state.input().is_empty() {
+        Span::styled(PLACEHOLDER, styles::input_placeholder_style())
+    } else {
+        Span::styled(state.input().to_owned(), styles::help_popup_action_style())
+    };
+    let line = Line::from(vec![
+        Span::styled("> ", styles::help_popup_key_style()),
+        input,
+    ]);
+
+    let paragraph = Paragraph::new(line).block(block);
+    frame.render_widget(paragraph, popup_area);
+
+    let before_cursor = &state.input()[..state.cursor_position()];
+    let cursor_x = popup_area.x + 2 + 2 + UnicodeWidthStr::width(before_cursor) as u16;
+    let cursor_y = popup_area.y + 1;
+    if cursor_x < popup_area.right() && cursor_y < popup_area.bottom() {
+        frame.set_cursor_position((cursor_x, cursor_y));
+    }
+}
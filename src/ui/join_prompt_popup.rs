@@ -0,0 +1,106 @@
+use ratatui::{
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Padding, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::domain::link_target::ChatInvite;
+
+use super::{popup_utils, styles};
+
+pub fn render_join_prompt_popup(frame: &mut Frame<'_>, area: Rect, invite: &ChatInvite) {
+    let popup_area = popup_utils::centered_rect(area, 50, 30);
+
+    frame.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(" Invite link ")
+        .borders(Borders::ALL)
+        .border_style(styles::command_popup_border_style())
+        .padding(Padding::horizontal(1));
+
+    let paragraph = Paragraph::new(build_lines(invite))
+        .block(block)
+        .wrap(Wrap { trim: false });
+    frame.render_widget(paragraph, popup_area);
+}
+
+fn build_lines(invite: &ChatInvite) -> Vec<Line<'static>> {
+    let question = if invite.creates_join_request {
+        format!("Request to join {}?", invite.title)
+    } else {
+        format!("Join {}?", invite.title)
+    };
+    let members = if invite.member_count == 1 {
+        "1 member".to_owned()
+    } else {
+        format!("{} members", invite.member_count)
+    };
+
+    vec![
+        Line::from(Span::styled(question, styles::help_popup_action_style())),
+        Line::from(Span::styled(members, styles::help_popup_footer_style())),
+        Line::default(),
+        Line::from(vec![
+            Span::styled("y  ", styles::help_popup_key_style()),
+            Span::styled(
+                if invite.creates_join_request {
+                    "Send request"
+                } else {
+                    "Join"
+                },
+                styles::help_popup_action_style(),
+            ),
+        ]),
+        Line::default(),
+        Line::from(Span::styled(
+            "n, q or Esc to cancel",
+            styles::help_popup_footer_style(),
+        )),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(lines: &[Line<'_>]) -> String {
+        lines
+            .iter()
+            .map(|l| {
+                l.spans
+                    .iter()
+                    .map(|s| s.content.as_ref())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn invite(creates_join_request: bool) -> ChatInvite {
+        ChatInvite {
+            invite_link: "https://t.me/+AbCdEf".to_owned(),
+            title: "Rust".to_owned(),
+            member_count: 1200,
+            creates_join_request,
+        }
+    }
+
+    #[test]
+    fn asks_to_join_with_member_count() {
+        let rendered = text(&build_lines(&invite(false)));
+
+        assert!(rendered.contains("Join Rust?"));
+        assert!(rendered.contains("1200 members"));
+        assert!(rendered.contains("y  Join"));
+    }
+
+    #[test]
+    fn approval_links_offer_a_join_request() {
+        let rendered = text(&build_lines(&invite(true)));
+
+        assert!(rendered.contains("Request to join Rust?"));
+        assert!(rendered.contains("y  Send request"));
+    }
+}
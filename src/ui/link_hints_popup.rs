@@ -0,0 +1,145 @@
+use ratatui::{
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Padding, Paragraph},
+    Frame,
+};
+
+use crate::domain::link_hints_state::{hint_label, LinkHintsState};
+
+use super::{popup_utils, styles};
+
+const FOOTER: &str = "1-9 open, Ctrl+number or y copies, j/k + Enter, q/Esc close";
+
+pub fn render_link_hints_popup(frame: &mut Frame<'_>, area: Rect, state: &LinkHintsState) {
+    let popup_area = popup_utils::centered_rect(area, 70, 50);
+
+    frame.render_widget(Clear, popup_area);
+
+    let title = if state.is_copy_mode() {
+        " Copy link "
+    } else {
+        " Links "
+    };
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(styles::help_popup_border_style())
+        .padding(Padding::horizontal(1));
+
+    // Keep the selected link in view when there are more than fit.
+    let visible = popup_area.height.saturating_sub(4) as usize;
+    let scroll = (state.selected_index() + 1).saturating_sub(visible) as u16;
+
+    let paragraph = Paragraph::new(build_lines(state))
+        .block(block)
+        .scroll((scroll, 0));
+    frame.render_widget(paragraph, popup_area);
+}
+
+fn build_lines(state: &LinkHintsState) -> Vec<Line<'static>> {
+    let mut lines: Vec<Line<'static>> = state
+        .urls()
+        .iter()
+        .enumerate()
+        .map(|(idx, link)| {
+            let hint = hint_label(idx).map_or(' ', |c| c);
+            let mut spans = vec![Span::styled(
+                format!("{hint}  "),
+                styles::help_popup_key_style(),
+            )];
+            if link.anchor != link.url {
+                spans.push(Span::styled(
+                    format!("{}  ", link.anchor),
+                    styles::help_popup_action_style(),
+                ));
+            }
+            spans.push(Span::styled(link.url.clone(), styles::message_link_style()));
+
+            let line = Line::from(spans);
+            if idx == state.selected_index() {
+                line.style(styles::completion_selected_style())
+            } else {
+                line
+            }
+        })
+        .collect();
+
+    lines.push(Line::default());
+    lines.push(Line::from(Span::styled(
+        FOOTER,
+        styles::help_popup_footer_style(),
+    )));
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::message::MessageUrl;
+
+    use super::*;
+
+    fn text(lines: &[Line<'_>]) -> Vec<String> {
+        lines
+            .iter()
+            .map(|l| {
+                l.spans
+                    .iter()
+                    .map(|s| s.content.as_ref())
+                    .collect::<String>()
+            })
+            .collect()
+    }
+
+    fn link(url: &str, anchor: &str) -> MessageUrl {
+        MessageUrl {
+            url: url.to_owned(),
+            anchor: anchor.to_owned(),
+        }
+    }
+
+    #[test]
+    fn lists_numbered_links_with_anchor_text() {
+        let state = LinkHintsState::new(vec![
+            link("https://docs.rs", "the docs"),
+            link("https://crates.io", "https://crates.io"),
+        ]);
+
+        let rendered = text(&build_lines(&state));
+
+        assert_eq!(rendered[0], "1  the docs  https://docs.rs");
+        assert_eq!(rendered[1], "2  https://crates.io");
+        assert_eq!(rendered.last().map(String::as_str), Some(FOOTER));
+    }
+
+    #[test]
+    fn links_past_the_ninth_have_no_hint() {
+        let state = LinkHintsState::new(
+            (1..=10)
+                .map(|i| {
+                    let url = format!("https://example.com/{i}");
+                    link(&url, &url)
+                })
+                .collect(),
+        );
+
+        let rendered = text(&build_lines(&state));
+
+        assert!(rendered[8].starts_with("9  "));
+        assert!(rendered[9].starts_with("   https://example.com/10"));
+    }
+
+    #[test]
+    fn selected_link_is_highlighted() {
+        let mut state = LinkHintsState::new(vec![
+            link("https://a.com", "https://a.com"),
+            link("https://b.com", "https://b.com"),
+        ]);
+        state.select_next();
+
+        let lines = build_lines(&state);
+
+        assert_eq!(lines[1].style, styles::completion_selected_style());
+        assert_ne!(lines[0].style, styles::completion_selected_style());
+    }
+}
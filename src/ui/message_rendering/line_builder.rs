@@ -5,7 +5,7 @@
 
 use ratatui::text::{Line, Span};
 
-use crate::domain::message::{ForwardInfo, MessageStatus, ReplyInfo, TextLink};
+use crate::domain::message::{ForwardInfo, MessageReaction, MessageStatus, ReplyInfo, TextLink};
 use crate::ui::styles;
 
 use super::content_spans::build_content_line_spans_linked;
@@ -27,7 +27,7 @@ pub(super) fn build_message_lines(
     file_meta: Option<&str>,
     reply_info: Option<&ReplyInfo>,
     forward_info: Option<&ForwardInfo>,
-    reaction_count: u32,
+    reactions: &[MessageReaction],
     links: &[TextLink],
     max_width: usize,
     is_edited: bool,
@@ -111,16 +111,27 @@ pub(super) fn build_message_lines(
         append_file_meta_to_media_line(&mut lines, meta);
     }
 
-    if reaction_count > 0 {
-        append_reaction_indicator(&mut lines, reaction_count);
+    if let Some(last_line) = lines.last_mut() {
+        last_line.spans.extend(reaction_spans(reactions));
     }
 
-    // Append sending status indicator on the same line as the last content line
-    if status == MessageStatus::Sending {
+    // Append delivery status on the same line as the last content line:
+    // "sending..." for pending messages, ticks for outgoing ones.
+    let status_span = match status {
+        MessageStatus::Sending => {
+            Some(Span::styled(" sending...", styles::message_sending_style()))
+        }
+        MessageStatus::Delivered if is_outgoing => {
+            Some(Span::styled(" ✓", styles::message_sent_tick_style()))
+        }
+        MessageStatus::Read if is_outgoing => {
+            Some(Span::styled(" ✓✓", styles::message_read_tick_style()))
+        }
+        MessageStatus::Delivered | MessageStatus::Read => None,
+    };
+    if let Some(span) = status_span {
         if let Some(last_line) = lines.last_mut() {
-            last_line
-                .spans
-                .push(Span::styled(" sending...", styles::message_sending_style()));
+            last_line.spans.push(span);
         }
     }
 
@@ -136,6 +147,33 @@ pub(super) fn build_message_lines(
     lines
 }
 
+/// Builds one line per inline keyboard row: `[ Approve ] [ Reject ]`.
+///
+/// `selected` highlights a button while button-selection mode is active.
+pub(super) fn build_inline_keyboard_lines(
+    rows: &[Vec<String>],
+    selected: Option<(usize, usize)>,
+) -> Vec<Line<'static>> {
+    rows.iter()
+        .enumerate()
+        .map(|(row_idx, row)| {
+            let mut spans = vec![Span::raw(MESSAGE_INDENT.to_owned())];
+            for (col_idx, label) in row.iter().enumerate() {
+                if col_idx > 0 {
+                    spans.push(Span::raw(" "));
+                }
+                let style = if selected == Some((row_idx, col_idx)) {
+                    styles::inline_button_selected_style()
+                } else {
+                    styles::inline_button_style()
+                };
+                spans.push(Span::styled(format!("[ {label} ]"), style));
+            }
+            Line::from(spans)
+        })
+        .collect()
+}
+
 fn build_indented_content_lines(
     lines: &mut Vec<Line<'static>>,
     content: &str,
@@ -224,17 +262,23 @@ fn build_grouped_message_lines(
     }
 }
 
-fn append_reaction_indicator(lines: &mut [Line<'static>], reaction_count: u32) {
-    if let Some(last_line) = lines.last_mut() {
-        let badge = if reaction_count == 1 {
-            " [♡]".to_owned()
+/// Per-emoji reaction counts (`👍 3  🔥 1`) appended after the content;
+/// the user's own reactions are highlighted.
+pub(super) fn reaction_spans(reactions: &[MessageReaction]) -> Vec<Span<'static>> {
+    let mut spans = Vec::with_capacity(reactions.len() * 2);
+    for (i, reaction) in reactions.iter().enumerate() {
+        spans.push(Span::raw(if i == 0 { " " } else { "  " }));
+        let style = if reaction.is_chosen {
+            styles::message_own_reaction_style()
         } else {
-            format!(" [♡×{}]", reaction_count)
+            styles::message_reaction_style()
         };
-        last_line
-            .spans
-            .push(Span::styled(badge, styles::message_reaction_style()));
+        spans.push(Span::styled(
+            format!("{} {}", reaction.emoji, reaction.count),
+            style,
+        ));
     }
+    spans
 }
 
 fn append_file_meta_to_media_line(lines: &mut [Line<'static>], meta: &str) {
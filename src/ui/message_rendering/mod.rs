@@ -18,18 +18,18 @@ use ratatui::{
 };
 
 use crate::domain::message::{
-    ForwardInfo, Message, MessageMedia, MessageStatus, ReplyInfo, TextLink,
+    ForwardInfo, Message, MessageMedia, MessageReaction, MessageStatus, ReplyInfo, TextLink,
 };
 
 use super::styles;
 
-use line_builder::build_message_lines;
+use line_builder::{build_inline_keyboard_lines, build_message_lines, reaction_spans};
 use text_utils::{effective_sender_name, format_date, format_time, timestamp_to_date};
 
 /// Represents a visual element in the messages list.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum MessageListElement {
-    /// Date separator line (e.g., "——— 14 Feb 2026 ———").
+    /// Date separator line (e.g., "——— Mon 14 Sep ———").
     DateSeparator(String),
     /// A message with optional sender display.
     Message {
@@ -41,20 +41,32 @@ pub enum MessageListElement {
         status: MessageStatus,
         /// File metadata line (e.g. "download=yes, size=15.5KB, duration=0:03").
         file_meta: Option<String>,
-        /// Reply preview: sender name and text of the replied-to message.
-        reply_info: Option<ReplyInfo>,
+        /// Reply preview: sender name and text of the replied-to message;
+        /// boxed to keep the element small.
+        reply_info: Option<Box<ReplyInfo>>,
         forward_info: Option<ForwardInfo>,
-        /// Total number of reactions on this message.
-        reaction_count: u32,
+        /// Reactions on this message by emoji.
+        reactions: Vec<MessageReaction>,
         /// Hyperlinks embedded in the message text (byte offsets into `Message::text`).
         links: Vec<TextLink>,
         /// Whether the message has been edited.
         is_edited: bool,
         /// Whether this is a service/system message.
         is_service: bool,
+        /// Bot inline keyboard shown under the message; boxed because
+        /// most messages have none.
+        inline_keyboard: Option<Box<InlineKeyboardView>>,
     },
 }
 
+/// Button labels of a bot's inline keyboard, row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InlineKeyboardView {
+    pub rows: Vec<Vec<String>>,
+    /// `(row, column)` of the highlighted button in button-selection mode.
+    pub selected: Option<(usize, usize)>,
+}
+
 /// Builds a list of visual elements from messages.
 ///
 /// Groups consecutive messages from the same sender and inserts date separators.
@@ -63,13 +75,16 @@ pub fn build_message_list_elements(messages: &[Message]) -> Vec<MessageListEleme
     let mut prev_date: Option<chrono::NaiveDate> = None;
     let mut prev_sender: Option<&str> = None;
     let mut prev_time: Option<String> = None;
+    let today = chrono::Local::now().date_naive();
 
     for message in messages {
         let msg_date = timestamp_to_date(message.timestamp_ms);
 
         // Insert date separator if date changed
         if prev_date != Some(msg_date) {
-            elements.push(MessageListElement::DateSeparator(format_date(msg_date)));
+            elements.push(MessageListElement::DateSeparator(format_date(
+                msg_date, today,
+            )));
             prev_sender = None; // Reset sender grouping on date change
             prev_time = None;
         }
@@ -88,10 +103,11 @@ pub fn build_message_list_elements(messages: &[Message]) -> Vec<MessageListEleme
                 file_meta: None,
                 reply_info: None,
                 forward_info: None,
-                reaction_count: message.reaction_count,
+                reactions: message.reactions.clone(),
                 links: Vec::new(),
                 is_edited: false,
                 is_service: true,
+                inline_keyboard: None,
             });
             prev_sender = None;
             prev_time = None;
@@ -133,12 +149,13 @@ pub fn build_message_list_elements(messages: &[Message]) -> Vec<MessageListEleme
             content: message.display_content(),
             status: message.status,
             file_meta,
-            reply_info: message.reply_to.clone(),
+            reply_info: message.reply_to.clone().map(Box::new),
             forward_info: message.forward_info.clone(),
-            reaction_count: message.reaction_count,
+            reactions: message.reactions.clone(),
             links: message.links.clone(),
             is_edited: message.is_edited,
             is_service: false,
+            inline_keyboard: build_inline_keyboard_view(message),
         });
 
         prev_date = Some(msg_date);
@@ -149,6 +166,21 @@ pub fn build_message_list_elements(messages: &[Message]) -> Vec<MessageListEleme
     elements
 }
 
+fn build_inline_keyboard_view(message: &Message) -> Option<Box<InlineKeyboardView>> {
+    if message.inline_keyboard.is_empty() {
+        return None;
+    }
+    let rows = message
+        .inline_keyboard
+        .iter()
+        .map(|row| row.iter().map(|button| button.text.clone()).collect())
+        .collect();
+    Some(Box::new(InlineKeyboardView {
+        rows,
+        selected: None,
+    }))
+}
+
 /// Converts a message index to the corresponding element index in the list.
 ///
 /// Since the element list contains both messages and date separators,
@@ -200,24 +232,18 @@ pub fn element_to_text(
             file_meta: _,
             reply_info: _,
             forward_info: _,
-            reaction_count,
+            reactions,
             links: _,
             is_edited: _,
             is_service: true,
+            ..
         } => {
             let text = match sender.as_deref() {
                 Some(name) => format!("{} {}", name, content),
                 None => content.clone(),
             };
             let mut spans = vec![Span::styled(text, styles::service_message_style())];
-            if *reaction_count > 0 {
-                let badge = if *reaction_count == 1 {
-                    " [♡]".to_owned()
-                } else {
-                    format!(" [♡×{}]", reaction_count)
-                };
-                spans.push(Span::styled(badge, styles::message_reaction_style()));
-            }
+            spans.extend(reaction_spans(reactions));
             let line = Line::from(spans).alignment(Alignment::Center);
             ratatui::text::Text::from(vec![line])
         }
@@ -231,12 +257,13 @@ pub fn element_to_text(
             file_meta,
             reply_info,
             forward_info,
-            reaction_count,
+            reactions,
             links,
             is_edited,
             is_service: _,
+            inline_keyboard,
         } => {
-            let lines = build_message_lines(
+            let mut lines = build_message_lines(
                 time,
                 *show_time,
                 sender.as_deref(),
@@ -244,14 +271,20 @@ pub fn element_to_text(
                 content,
                 *status,
                 file_meta.as_deref(),
-                reply_info.as_ref(),
+                reply_info.as_deref(),
                 forward_info.as_ref(),
-                *reaction_count,
+                reactions,
                 links,
                 max_width,
                 *is_edited,
                 false,
             );
+            if let Some(keyboard) = inline_keyboard {
+                lines.extend(build_inline_keyboard_lines(
+                    &keyboard.rows,
+                    keyboard.selected,
+                ));
+            }
             ratatui::text::Text::from(lines)
         }
     }
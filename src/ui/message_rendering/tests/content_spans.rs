@@ -97,7 +97,7 @@ fn message_with_text_url_entity_renders_underlined() {
         call_info: None,
         reply_to: None,
         forward_info: None,
-        reaction_count: 0,
+        reactions: Vec::new(),
         links: vec![TextLink {
             offset: 0,
             length: 10,
@@ -105,6 +105,8 @@ fn message_with_text_url_entity_renders_underlined() {
         }],
         is_edited: false,
         is_service: false,
+        inline_keyboard: Vec::new(),
+        reply_keyboard: None,
     }];
 
     let elements = build_message_list_elements(&messages);
@@ -345,12 +345,34 @@ fn message_index_to_element_index_returns_none_for_empty_elements() {
 // ── format_date ──
 
 #[test]
-fn format_date_produces_correct_format() {
-    let date = chrono::NaiveDate::from_ymd_opt(2026, 2, 14).unwrap();
+fn format_date_uses_weekday_and_omits_current_year() {
+    let date = chrono::NaiveDate::from_ymd_opt(2026, 9, 14).unwrap();
+    let today = chrono::NaiveDate::from_ymd_opt(2026, 9, 20).unwrap();
 
-    let formatted = crate::ui::message_rendering::text_utils::format_date(date);
+    let formatted = crate::ui::message_rendering::text_utils::format_date(date, today);
 
-    assert_eq!(formatted, "14 Feb 2026");
+    assert_eq!(formatted, "Mon 14 Sep");
+}
+
+#[test]
+fn format_date_includes_year_for_older_dates() {
+    let date = chrono::NaiveDate::from_ymd_opt(2025, 2, 14).unwrap();
+    let today = chrono::NaiveDate::from_ymd_opt(2026, 9, 20).unwrap();
+
+    let formatted = crate::ui::message_rendering::text_utils::format_date(date, today);
+
+    assert_eq!(formatted, "Fri 14 Feb 2025");
+}
+
+#[test]
+fn format_date_labels_today_and_yesterday() {
+    use crate::ui::message_rendering::text_utils::format_date;
+
+    let today = chrono::NaiveDate::from_ymd_opt(2026, 1, 1).unwrap();
+    let yesterday = chrono::NaiveDate::from_ymd_opt(2025, 12, 31).unwrap();
+
+    assert_eq!(format_date(today, today), "Today");
+    assert_eq!(format_date(yesterday, today), "Yesterday");
 }
 
 #[test]
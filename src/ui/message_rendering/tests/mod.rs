@@ -26,10 +26,12 @@ fn msg(id: i64, sender: &str, text: &str, ts_ms: i64, outgoing: bool) -> Message
         call_info: None,
         reply_to: None,
         forward_info: None,
-        reaction_count: 0,
+        reactions: Vec::new(),
         links: Vec::new(),
         is_edited: false,
         is_service: false,
+        inline_keyboard: Vec::new(),
+        reply_keyboard: None,
     }
 }
 
@@ -46,10 +48,12 @@ fn msg_with_media(id: i64, sender: &str, text: &str, ts_ms: i64, media: MessageM
         call_info: None,
         reply_to: None,
         forward_info: None,
-        reaction_count: 0,
+        reactions: Vec::new(),
         links: Vec::new(),
         is_edited: false,
         is_service: false,
+        inline_keyboard: Vec::new(),
+        reply_keyboard: None,
     }
 }
 
@@ -77,10 +81,12 @@ fn msg_with_reply(
             is_outgoing: false,
         }),
         forward_info: None,
-        reaction_count: 0,
+        reactions: Vec::new(),
         links: Vec::new(),
         is_edited: false,
         is_service: false,
+        inline_keyboard: Vec::new(),
+        reply_keyboard: None,
     }
 }
 
@@ -105,9 +111,11 @@ fn msg_with_forward(
         forward_info: Some(ForwardInfo {
             sender_name: forward_sender.to_owned(),
         }),
-        reaction_count: 0,
+        reactions: Vec::new(),
         links: Vec::new(),
         is_edited: false,
         is_service: false,
+        inline_keyboard: Vec::new(),
+        reply_keyboard: None,
     }
 }
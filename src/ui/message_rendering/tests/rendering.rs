@@ -1,4 +1,5 @@
 use super::*;
+use crate::domain::message::MessageReaction;
 use crate::ui::message_rendering::{
     build_message_list_elements, element_to_text, MessageListElement,
 };
@@ -19,10 +20,12 @@ fn sending_status_on_same_line_as_content() {
         call_info: None,
         reply_to: None,
         forward_info: None,
-        reaction_count: 0,
+        reactions: Vec::new(),
         links: Vec::new(),
         is_edited: false,
         is_service: false,
+        inline_keyboard: Vec::new(),
+        reply_keyboard: None,
     }];
 
     let elements = build_message_list_elements(&messages);
@@ -65,10 +68,12 @@ fn delivered_message_has_no_sending_indicator() {
         call_info: None,
         reply_to: None,
         forward_info: None,
-        reaction_count: 0,
+        reactions: Vec::new(),
         links: Vec::new(),
         is_edited: false,
         is_service: false,
+        inline_keyboard: Vec::new(),
+        reply_keyboard: None,
     }];
 
     let elements = build_message_list_elements(&messages);
@@ -83,6 +88,42 @@ fn delivered_message_has_no_sending_indicator() {
     assert!(!all_text.contains("sending..."));
 }
 
+// ── read ticks ──
+
+fn last_line_text(message: Message) -> String {
+    let elements = build_message_list_elements(&[message]);
+    let msg_text = element_to_text(&elements[1], 80);
+    msg_text
+        .lines
+        .last()
+        .unwrap()
+        .spans
+        .iter()
+        .map(|s| s.content.as_ref())
+        .collect()
+}
+
+#[test]
+fn delivered_outgoing_message_shows_single_tick() {
+    let text = last_line_text(msg(1, "Me", "Hello", FEB_14_2026_10AM, true));
+    assert!(text.ends_with("Hello ✓"), "got: '{}'", text);
+}
+
+#[test]
+fn read_outgoing_message_shows_double_tick() {
+    let mut message = msg(1, "Me", "Hello", FEB_14_2026_10AM, true);
+    message.status = MessageStatus::Read;
+
+    let text = last_line_text(message);
+    assert!(text.ends_with("Hello ✓✓"), "got: '{}'", text);
+}
+
+#[test]
+fn incoming_message_shows_no_ticks() {
+    let text = last_line_text(msg(1, "User", "Hello", FEB_14_2026_10AM, false));
+    assert!(!text.contains('✓'), "got: '{}'", text);
+}
+
 // ── edited indicator ──
 
 #[test]
@@ -99,10 +140,12 @@ fn edited_message_shows_edited_indicator() {
         call_info: None,
         reply_to: None,
         forward_info: None,
-        reaction_count: 0,
+        reactions: Vec::new(),
         links: Vec::new(),
         is_edited: true,
         is_service: false,
+        inline_keyboard: Vec::new(),
+        reply_keyboard: None,
     }];
 
     let elements = build_message_list_elements(&messages);
@@ -134,10 +177,12 @@ fn non_edited_message_has_no_edited_indicator() {
         call_info: None,
         reply_to: None,
         forward_info: None,
-        reaction_count: 0,
+        reactions: Vec::new(),
         links: Vec::new(),
         is_edited: false,
         is_service: false,
+        inline_keyboard: Vec::new(),
+        reply_keyboard: None,
     }];
 
     let elements = build_message_list_elements(&messages);
@@ -168,10 +213,12 @@ fn edited_indicator_on_same_line_as_content() {
         call_info: None,
         reply_to: None,
         forward_info: None,
-        reaction_count: 0,
+        reactions: Vec::new(),
         links: Vec::new(),
         is_edited: true,
         is_service: false,
+        inline_keyboard: Vec::new(),
+        reply_keyboard: None,
     }];
 
     let elements = build_message_list_elements(&messages);
@@ -291,10 +338,12 @@ fn voice_message_shows_file_metadata() {
         call_info: None,
         reply_to: None,
         forward_info: None,
-        reaction_count: 0,
+        reactions: Vec::new(),
         links: Vec::new(),
         is_edited: false,
         is_service: false,
+        inline_keyboard: Vec::new(),
+        reply_keyboard: None,
     }];
 
     let elements = build_message_list_elements(&messages);
@@ -348,10 +397,12 @@ fn document_message_shows_file_name_and_extension() {
         call_info: None,
         reply_to: None,
         forward_info: None,
-        reaction_count: 0,
+        reactions: Vec::new(),
         links: Vec::new(),
         is_edited: false,
         is_service: false,
+        inline_keyboard: Vec::new(),
+        reply_keyboard: None,
     }];
 
     let elements = build_message_list_elements(&messages);
@@ -415,10 +466,12 @@ fn document_without_extension_does_not_break_layout() {
             call_info: None,
             reply_to: None,
             forward_info: None,
-            reaction_count: 0,
+            reactions: Vec::new(),
             links: Vec::new(),
             is_edited: false,
             is_service: false,
+            inline_keyboard: Vec::new(),
+            reply_keyboard: None,
         }
     }
 
@@ -464,24 +517,22 @@ fn text_message_has_no_file_metadata() {
 
 // ── reactions ──
 
+fn reactions(counts: &[(&str, u32, bool)]) -> Vec<MessageReaction> {
+    counts
+        .iter()
+        .map(|&(emoji, count, is_chosen)| MessageReaction {
+            emoji: emoji.to_owned(),
+            count,
+            is_chosen,
+        })
+        .collect()
+}
+
 #[test]
-fn message_with_multiple_reactions_shows_count() {
+fn message_with_reactions_shows_per_emoji_counts() {
     let messages = vec![Message {
-        id: 1,
-        sender_name: "Alice".to_owned(),
-        text: "Hello".to_owned(),
-        timestamp_ms: FEB_14_2026_10AM,
-        is_outgoing: false,
-        media: MessageMedia::None,
-        status: MessageStatus::Delivered,
-        file_info: None,
-        call_info: None,
-        reply_to: None,
-        forward_info: None,
-        reaction_count: 3,
-        links: Vec::new(),
-        is_edited: false,
-        is_service: false,
+        reactions: reactions(&[("👍", 3, false), ("🔥", 1, false)]),
+        ..msg(1, "Alice", "Hello", FEB_14_2026_10AM, false)
     }];
 
     let elements = build_message_list_elements(&messages);
@@ -492,39 +543,24 @@ fn message_with_multiple_reactions_shows_count() {
         .flat_map(|l| l.spans.iter().map(|s| s.content.as_ref()))
         .collect();
 
-    assert!(all_text.contains("[♡×3]"));
+    assert!(all_text.ends_with("Hello 👍 3  🔥 1"), "got: '{all_text}'");
 }
 
 #[test]
-fn message_with_single_reaction_shows_heart_without_count() {
+fn own_reaction_is_highlighted() {
     let messages = vec![Message {
-        id: 1,
-        sender_name: "Alice".to_owned(),
-        text: "Hello".to_owned(),
-        timestamp_ms: FEB_14_2026_10AM,
-        is_outgoing: false,
-        media: MessageMedia::None,
-        status: MessageStatus::Delivered,
-        file_info: None,
-        call_info: None,
-        reply_to: None,
-        forward_info: None,
-        reaction_count: 1,
-        links: Vec::new(),
-        is_edited: false,
-        is_service: false,
+        reactions: reactions(&[("👍", 2, true), ("🔥", 1, false)]),
+        ..msg(1, "Alice", "Hello", FEB_14_2026_10AM, false)
     }];
 
     let elements = build_message_list_elements(&messages);
     let msg_text = element_to_text(&elements[1], 80);
-    let all_text: String = msg_text
-        .lines
-        .iter()
-        .flat_map(|l| l.spans.iter().map(|s| s.content.as_ref()))
-        .collect();
+    let spans = &msg_text.lines.last().unwrap().spans;
 
-    assert!(all_text.contains("[♡]"));
-    assert!(!all_text.contains("[♡×1]"));
+    let own = spans.iter().find(|s| s.content == "👍 2").unwrap();
+    let other = spans.iter().find(|s| s.content == "🔥 1").unwrap();
+    assert_eq!(own.style, crate::ui::styles::message_own_reaction_style());
+    assert_eq!(other.style, crate::ui::styles::message_reaction_style());
 }
 
 #[test]
@@ -541,10 +577,12 @@ fn service_message_renders_centered_with_sender_inline() {
         call_info: None,
         reply_to: None,
         forward_info: None,
-        reaction_count: 0,
+        reactions: Vec::new(),
         links: Vec::new(),
         is_edited: false,
         is_service: true,
+        inline_keyboard: Vec::new(),
+        reply_keyboard: None,
     }];
 
     let elements = build_message_list_elements(&messages);
@@ -580,10 +618,12 @@ fn service_message_breaks_sender_grouping() {
             call_info: None,
             reply_to: None,
             forward_info: None,
-            reaction_count: 0,
+            reactions: Vec::new(),
             links: Vec::new(),
             is_edited: false,
             is_service: true,
+            inline_keyboard: Vec::new(),
+            reply_keyboard: None,
         },
         msg(3, "Alice", "World", FEB_14_2026_10AM, false),
     ];
@@ -621,10 +661,12 @@ fn service_message_shows_reaction_badge() {
         call_info: None,
         reply_to: None,
         forward_info: None,
-        reaction_count: 3,
+        reactions: reactions(&[("❤", 3, false)]),
         links: Vec::new(),
         is_edited: false,
         is_service: true,
+        inline_keyboard: Vec::new(),
+        reply_keyboard: None,
     }];
 
     let elements = build_message_list_elements(&messages);
@@ -636,7 +678,7 @@ fn service_message_shows_reaction_badge() {
         .flat_map(|l| l.spans.iter().map(|s| s.content.as_ref()))
         .collect();
     assert!(
-        all_text.contains("[♡×3]"),
+        all_text.contains("❤ 3"),
         "Service message should show reaction badge, got: '{}'",
         all_text
     );
@@ -656,10 +698,12 @@ fn service_message_without_reactions_has_no_badge() {
         call_info: None,
         reply_to: None,
         forward_info: None,
-        reaction_count: 0,
+        reactions: Vec::new(),
         links: Vec::new(),
         is_edited: false,
         is_service: true,
+        inline_keyboard: Vec::new(),
+        reply_keyboard: None,
     }];
 
     let elements = build_message_list_elements(&messages);
@@ -671,7 +715,78 @@ fn service_message_without_reactions_has_no_badge() {
         .flat_map(|l| l.spans.iter().map(|s| s.content.as_ref()))
         .collect();
     assert!(
-        !all_text.contains("♡"),
+        !all_text.contains(char::is_numeric),
         "Service message without reactions should have no badge"
     );
 }
+
+// ── inline keyboard ──
+
+fn bot_message_with_keyboard() -> Message {
+    use crate::domain::message::{InlineButton, InlineButtonAction};
+
+    let button = |text: &str| InlineButton {
+        text: text.to_owned(),
+        action: InlineButtonAction::Callback {
+            data: text.to_owned(),
+        },
+    };
+    let mut message = msg(1, "CI", "Build finished", FEB_14_2026_10AM, false);
+    message.inline_keyboard = vec![
+        vec![button("Deploy"), button("Retry")],
+        vec![button("Logs")],
+    ];
+    message
+}
+
+#[test]
+fn inline_keyboard_renders_one_line_per_row() {
+    let elements = build_message_list_elements(&[bot_message_with_keyboard()]);
+    let msg_text = element_to_text(&elements[1], 80);
+
+    let lines: Vec<String> = msg_text
+        .lines
+        .iter()
+        .map(|l| l.spans.iter().map(|s| s.content.as_ref()).collect())
+        .collect();
+
+    // Header + content + two keyboard rows.
+    assert_eq!(lines.len(), 4);
+    assert!(
+        lines[2].contains("[ Deploy ] [ Retry ]"),
+        "got: {:?}",
+        lines
+    );
+    assert!(lines[3].contains("[ Logs ]"), "got: {:?}", lines);
+}
+
+#[test]
+fn selected_inline_button_is_highlighted() {
+    let mut elements = build_message_list_elements(&[bot_message_with_keyboard()]);
+    if let MessageListElement::Message {
+        inline_keyboard: Some(keyboard),
+        ..
+    } = &mut elements[1]
+    {
+        keyboard.selected = Some((0, 1));
+    }
+
+    let msg_text = element_to_text(&elements[1], 80);
+    let row = &msg_text.lines[2];
+    let retry = row
+        .spans
+        .iter()
+        .find(|s| s.content.contains("Retry"))
+        .unwrap();
+    let deploy = row
+        .spans
+        .iter()
+        .find(|s| s.content.contains("Deploy"))
+        .unwrap();
+
+    assert_eq!(
+        retry.style,
+        crate::ui::styles::inline_button_selected_style()
+    );
+    assert_eq!(deploy.style, crate::ui::styles::inline_button_style());
+}
@@ -132,10 +132,12 @@ fn grouped_message_with_reply_shows_reply_line() {
                 is_outgoing: false,
             }),
             forward_info: None,
-            reaction_count: 0,
+            reactions: Vec::new(),
             links: Vec::new(),
             is_edited: false,
             is_service: false,
+            inline_keyboard: Vec::new(),
+            reply_keyboard: None,
         },
     ];
 
@@ -3,7 +3,7 @@
 //! Pure helpers with no ratatui dependency: wrapping, date/time formatting,
 //! sender name resolution.
 
-use chrono::{Local, TimeZone};
+use chrono::{Datelike, Local, TimeZone};
 
 use crate::domain::message::Message;
 
@@ -64,8 +64,20 @@ pub(super) fn timestamp_to_date(timestamp_ms: i64) -> chrono::NaiveDate {
     }
 }
 
-pub(super) fn format_date(date: chrono::NaiveDate) -> String {
-    date.format("%-d %b %Y").to_string()
+/// Formats a date separator label relative to `today`.
+///
+/// "Today" and "Yesterday" for the last two days, "Mon 14 Sep" within the
+/// current year and "Mon 14 Sep 2025" for older dates.
+pub(super) fn format_date(date: chrono::NaiveDate, today: chrono::NaiveDate) -> String {
+    if date == today {
+        "Today".to_owned()
+    } else if today.pred_opt() == Some(date) {
+        "Yesterday".to_owned()
+    } else if date.year() == today.year() {
+        date.format("%a %-d %b").to_string()
+    } else {
+        date.format("%a %-d %b %Y").to_string()
+    }
 }
 
 pub(super) fn format_time(timestamp_ms: i64) -> String {
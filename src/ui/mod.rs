@@ -1,16 +1,28 @@
 //! UI layer: rendering and interaction entry points (CLI/TUI).
 
+mod alert_popup;
+mod chat_confirm_popup;
 mod chat_info_popup;
 pub(crate) mod chat_message_list;
 mod chat_search_popup;
 mod command_popup;
+mod completion_popup;
+mod date_prompt_popup;
+mod delete_confirm_popup;
+mod emoji_picker_popup;
 mod event_source;
+mod go_to_prompt_popup;
 mod help_popup;
+mod join_prompt_popup;
+mod link_hints_popup;
 mod message_info_popup;
 mod message_input;
 mod message_rendering;
+mod new_chat_wizard_popup;
 mod popup_utils;
+mod quick_switcher_popup;
 mod reaction_picker_popup;
+mod reply_keyboard;
 pub mod shell;
 mod styles;
 mod terminal;
@@ -0,0 +1,250 @@
+use ratatui::{
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Padding, Paragraph},
+    Frame,
+};
+use unicode_width::UnicodeWidthStr;
+
+use crate::domain::new_chat_wizard_state::{NewChatKind, NewChatStep, NewChatWizardState};
+
+use super::{popup_utils, styles};
+
+/// Lines above the step's content: step counter and a blank line.
+const HEADER_LINES: usize = 2;
+
+pub fn render_new_chat_wizard_popup(frame: &mut Frame<'_>, area: Rect, state: &NewChatWizardState) {
+    let popup_area = popup_utils::centered_rect(area, 60, 60);
+
+    frame.render_widget(Clear, popup_area);
+
+    let title = match state.step() {
+        NewChatStep::Kind => " New chat ".to_owned(),
+        _ => format!(" New {} ", state.kind().label().to_lowercase()),
+    };
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(styles::help_popup_border_style())
+        .padding(Padding::horizontal(1));
+
+    // Keep the member under the cursor in view when the list is long.
+    let scroll = if state.step() == NewChatStep::Members {
+        let visible = popup_area.height.saturating_sub(5) as usize;
+        let cursor_line = HEADER_LINES + 1 + state.member_cursor();
+        (cursor_line + 1).saturating_sub(visible) as u16
+    } else {
+        0
+    };
+
+    let paragraph = Paragraph::new(build_lines(state))
+        .block(block)
+        .scroll((scroll, 0));
+    frame.render_widget(paragraph, popup_area);
+
+    let input = match state.step() {
+        NewChatStep::Title => state.title(),
+        NewChatStep::Description => state.description(),
+        _ => return,
+    };
+    let cursor_x = popup_area.x + 2 + 2 + UnicodeWidthStr::width(input) as u16;
+    let cursor_y = popup_area.y + 1 + HEADER_LINES as u16 + 1;
+    if cursor_x < popup_area.right() && cursor_y < popup_area.bottom() {
+        frame.set_cursor_position((cursor_x, cursor_y));
+    }
+}
+
+fn build_lines(state: &NewChatWizardState) -> Vec<Line<'static>> {
+    let (number, name) = match state.step() {
+        NewChatStep::Kind => (1, "Kind"),
+        NewChatStep::Title => (2, "Title"),
+        NewChatStep::Description => (3, "Description"),
+        NewChatStep::Members => (4, "Members"),
+    };
+    let mut lines = vec![
+        Line::from(Span::styled(
+            format!("Step {number}/4: {name}"),
+            styles::help_popup_footer_style(),
+        )),
+        Line::default(),
+    ];
+
+    let footer = match state.step() {
+        NewChatStep::Kind => {
+            for kind in NewChatKind::ALL {
+                lines.push(kind_line(kind, kind == state.kind()));
+            }
+            "j/k choose, Enter next, Esc cancel"
+        }
+        NewChatStep::Title => {
+            lines.push(label_line("Title"));
+            lines.push(input_line(state.title()));
+            "Enter next, Esc back"
+        }
+        NewChatStep::Description => {
+            lines.push(label_line("Description (optional)"));
+            lines.push(input_line(state.description()));
+            "Enter next, Esc back"
+        }
+        NewChatStep::Members => {
+            push_member_lines(&mut lines, state);
+            "Type to filter, Up/Down move, Space pick, Enter create, Esc back"
+        }
+    };
+
+    lines.push(Line::default());
+    lines.push(Line::from(Span::styled(
+        footer,
+        styles::help_popup_footer_style(),
+    )));
+    lines
+}
+
+fn kind_line(kind: NewChatKind, selected: bool) -> Line<'static> {
+    let marker = if selected { "> " } else { "  " };
+    let line = Line::from(vec![
+        Span::styled(marker, styles::help_popup_key_style()),
+        Span::styled(kind.label(), styles::help_popup_action_style()),
+    ]);
+    if selected {
+        line.style(styles::completion_selected_style())
+    } else {
+        line
+    }
+}
+
+fn label_line(label: impl Into<String>) -> Line<'static> {
+    Line::from(Span::styled(label.into(), styles::help_popup_key_style()))
+}
+
+fn input_line(text: &str) -> Line<'static> {
+    Line::from(vec![
+        Span::styled("> ", styles::help_popup_key_style()),
+        Span::styled(text.to_owned(), styles::help_popup_action_style()),
+    ])
+}
+
+fn push_member_lines(lines: &mut Vec<Line<'static>>, state: &NewChatWizardState) {
+    let mut header = format!("Members ({} picked)", state.member_count());
+    if !state.member_filter().is_empty() {
+        header.push_str(&format!("  /{}", state.member_filter()));
+    }
+    lines.push(label_line(header));
+
+    if !state.contacts_loaded() {
+        lines.push(Line::from(Span::styled(
+            "Loading contacts...",
+            styles::help_popup_footer_style(),
+        )));
+        return;
+    }
+
+    let members = state.visible_members();
+    if members.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "No matching contacts",
+            styles::help_popup_footer_style(),
+        )));
+        return;
+    }
+
+    for (idx, contact) in members.into_iter().enumerate() {
+        let check = if state.is_member(contact.user_id) {
+            "[x] "
+        } else {
+            "[ ] "
+        };
+        let mut spans = vec![
+            Span::styled(check, styles::help_popup_key_style()),
+            Span::styled(contact.display_name.clone(), styles::chat_name_style()),
+        ];
+        if !contact.username.is_empty() {
+            spans.push(Span::styled(
+                format!(" @{}", contact.username),
+                styles::chat_preview_style(),
+            ));
+        }
+        let line = Line::from(spans);
+        lines.push(if idx == state.member_cursor() {
+            line.style(styles::completion_selected_style())
+        } else {
+            line
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::contact::Contact;
+
+    use super::*;
+
+    fn text(lines: &[Line<'_>]) -> Vec<String> {
+        lines
+            .iter()
+            .map(|l| {
+                l.spans
+                    .iter()
+                    .map(|s| s.content.as_ref())
+                    .collect::<String>()
+            })
+            .collect()
+    }
+
+    fn contact(user_id: i64, name: &str, username: &str) -> Contact {
+        Contact {
+            user_id,
+            display_name: name.to_owned(),
+            username: username.to_owned(),
+            is_online: false,
+        }
+    }
+
+    #[test]
+    fn kind_step_lists_all_kinds_with_selection() {
+        let mut state = NewChatWizardState::default();
+        state.select_next_kind();
+
+        let lines = build_lines(&state);
+        let rendered = text(&lines);
+
+        assert_eq!(rendered[0], "Step 1/4: Kind");
+        assert_eq!(rendered[2], "  Group");
+        assert_eq!(rendered[3], "> Supergroup");
+        assert_eq!(lines[3].style, styles::completion_selected_style());
+    }
+
+    #[test]
+    fn title_step_shows_typed_title() {
+        let mut state = NewChatWizardState::default();
+        state.advance();
+        for ch in "Ops".chars() {
+            state.push_char(ch);
+        }
+
+        let rendered = text(&build_lines(&state));
+
+        assert_eq!(rendered[2], "Title");
+        assert_eq!(rendered[3], "> Ops");
+    }
+
+    #[test]
+    fn members_step_marks_picked_contacts() {
+        let mut state = NewChatWizardState::default();
+        state.advance();
+        state.push_char('x');
+        state.advance();
+        state.advance();
+
+        let rendered = text(&build_lines(&state));
+        assert_eq!(rendered[3], "Loading contacts...");
+
+        state.set_contacts(vec![contact(1, "Bob", ""), contact(2, "Alice", "alice")]);
+        state.toggle_member();
+        let rendered = text(&build_lines(&state));
+
+        assert_eq!(rendered[2], "Members (1 picked)");
+        assert_eq!(rendered[3], "[x] Alice @alice");
+        assert_eq!(rendered[4], "[ ] Bob");
+    }
+}
@@ -0,0 +1,146 @@
+use ratatui::{
+    layout::Rect,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Padding, Paragraph},
+    Frame,
+};
+use unicode_width::UnicodeWidthStr;
+
+use crate::domain::quick_switcher_state::{QuickSwitcherEntry, QuickSwitcherState};
+
+use super::{chat_search_popup::highlighted_spans, popup_utils, styles};
+
+/// Rows above the entries: the query line and a blank line.
+const HEADER_ROWS: usize = 2;
+
+pub fn render_quick_switcher_popup(frame: &mut Frame<'_>, area: Rect, state: &QuickSwitcherState) {
+    let popup_area = popup_utils::centered_rect(area, 50, 60);
+
+    frame.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(" Recent chats ")
+        .title_bottom(Span::styled(
+            " ↑/↓ select · Enter open · Esc close ",
+            styles::help_popup_footer_style(),
+        ))
+        .borders(Borders::ALL)
+        .border_style(styles::help_popup_border_style())
+        .padding(Padding::horizontal(1));
+
+    let rows = usize::from(popup_area.height.saturating_sub(2));
+    let paragraph = Paragraph::new(build_lines(state, rows)).block(block);
+    frame.render_widget(paragraph, popup_area);
+
+    let cursor_x = popup_area.x + 2 + 2 + UnicodeWidthStr::width(state.query()) as u16;
+    let cursor_y = popup_area.y + 1;
+    if cursor_x < popup_area.right() && cursor_y < popup_area.bottom() {
+        frame.set_cursor_position((cursor_x, cursor_y));
+    }
+}
+
+/// Builds at most `rows` lines: the query, then the entries scrolled so
+/// the selected one is visible.
+fn build_lines(state: &QuickSwitcherState, rows: usize) -> Vec<Line<'static>> {
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("» ", styles::help_popup_key_style()),
+            Span::styled(state.query().to_owned(), styles::help_popup_action_style()),
+        ]),
+        Line::default(),
+    ];
+
+    let entries = state.entries();
+    if entries.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "No recent chats match",
+            styles::help_popup_footer_style(),
+        )));
+        return lines;
+    }
+
+    let entry_rows = rows.saturating_sub(HEADER_ROWS).max(1);
+    let selected = state.selected_index().unwrap_or(0);
+    let first = (selected + 1).saturating_sub(entry_rows);
+    lines.extend(
+        entries
+            .iter()
+            .enumerate()
+            .skip(first)
+            .take(entry_rows)
+            .map(|(idx, entry)| entry_line(entry, idx == selected)),
+    );
+    lines
+}
+
+fn entry_line(entry: &QuickSwitcherEntry, selected: bool) -> Line<'static> {
+    let marker = if selected { "> " } else { "  " };
+    let mut spans = vec![Span::styled(marker, styles::help_popup_key_style())];
+    spans.extend(highlighted_spans(
+        &entry.chat.display_title(),
+        &entry.matches,
+        styles::chat_name_style(),
+    ));
+    Line::from(spans)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{chat::ChatType, recent_chats::RecentChat};
+
+    fn text(lines: &[Line<'_>]) -> String {
+        lines
+            .iter()
+            .map(|l| {
+                l.spans
+                    .iter()
+                    .map(|s| s.content.as_ref())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn recent() -> Vec<RecentChat> {
+        vec![
+            RecentChat::chat(1, "Alice", ChatType::Private),
+            RecentChat::topic(2, "Rust Forum", 10, "Async"),
+        ]
+    }
+
+    #[test]
+    fn lists_chats_and_topics_and_marks_selection() {
+        let state = QuickSwitcherState::new(recent(), true);
+
+        let rendered = text(&build_lines(&state, 10));
+
+        assert!(rendered.starts_with("» \n"));
+        assert!(rendered.contains("  Alice"));
+        assert!(rendered.contains("> Rust Forum > Async"));
+    }
+
+    #[test]
+    fn says_so_when_nothing_matches() {
+        let mut state = QuickSwitcherState::new(recent(), false);
+        state.insert_char('z');
+
+        assert!(text(&build_lines(&state, 10)).contains("No recent chats match"));
+    }
+
+    #[test]
+    fn scrolls_to_keep_selection_visible() {
+        let recent = (1..=10)
+            .map(|i| RecentChat::chat(i, format!("Chat {i}"), ChatType::Group))
+            .collect();
+        let mut state = QuickSwitcherState::new(recent, false);
+        for _ in 0..6 {
+            state.select_next();
+        }
+
+        let lines = build_lines(&state, 5);
+
+        assert_eq!(lines.len(), 5);
+        assert!(text(&lines).contains("> Chat 7"));
+    }
+}
@@ -6,10 +6,15 @@ use ratatui::{
     Frame,
 };
 
-use crate::domain::reaction_picker_state::ReactionPickerState;
+use crate::domain::reaction_picker_state::{
+    AvailableReaction, ReactionPickerData, ReactionPickerState,
+};
 
 use super::{popup_utils, styles};
 
+/// Lines around the reaction list: search line, blank line and footer.
+const CHROME_LINES: usize = 4;
+
 pub fn render_reaction_picker(frame: &mut Frame<'_>, area: Rect, state: &ReactionPickerState) {
     let popup_area = popup_utils::centered_rect(area, 50, 60);
 
@@ -21,12 +26,14 @@ pub fn render_reaction_picker(frame: &mut Frame<'_>, area: Rect, state: &Reactio
         .border_style(styles::chat_info_popup_border_style())
         .padding(Padding::new(2, 2, 1, 1));
 
-    let lines = build_lines(state);
+    let inner_height = popup_area.height.saturating_sub(4) as usize;
+    let list_rows = inner_height.saturating_sub(CHROME_LINES).max(1);
+    let lines = build_lines(state, list_rows);
     let paragraph = Paragraph::new(lines).block(block);
     frame.render_widget(paragraph, popup_area);
 }
 
-fn build_lines(state: &ReactionPickerState) -> Vec<Line<'static>> {
+fn build_lines(state: &ReactionPickerState, list_rows: usize) -> Vec<Line<'static>> {
     match state {
         ReactionPickerState::Loading { .. } => {
             vec![Line::from(Span::styled(
@@ -41,33 +48,27 @@ fn build_lines(state: &ReactionPickerState) -> Vec<Line<'static>> {
             ))]
         }
         ReactionPickerState::Ready(data) => {
-            let mut lines: Vec<Line<'static>> = data
-                .items
-                .iter()
-                .enumerate()
-                .map(|(i, r)| {
-                    let style = if i == data.selected_index {
-                        Style::default()
-                            .fg(Color::Black)
-                            .bg(Color::White)
-                            .add_modifier(Modifier::BOLD)
-                    } else {
-                        styles::chat_info_popup_value_style()
-                    };
-                    let name = r.display_name();
-                    let suffix = if r.is_chosen { " +1" } else { "" };
-                    let label = if name.is_empty() {
-                        format!("  {}{}", r.emoji, suffix)
-                    } else {
-                        format!("  {}  {}{}", r.emoji, name, suffix)
-                    };
-                    Line::from(Span::styled(label, style))
-                })
-                .collect();
+            let mut lines = Vec::new();
+            if data.is_searching() || !data.query().is_empty() {
+                lines.push(Line::from(vec![
+                    Span::styled("/", styles::help_popup_key_style()),
+                    Span::styled(data.query().to_owned(), styles::help_popup_action_style()),
+                ]));
+            }
+
+            let (list, selected_line) = list_lines(data);
+            if list.is_empty() {
+                lines.push(Line::from(Span::styled(
+                    "No reactions found",
+                    styles::completion_detail_style(),
+                )));
+            }
+            let first = (selected_line + 1).saturating_sub(list_rows);
+            lines.extend(list.into_iter().skip(first).take(list_rows));
 
             lines.push(Line::from(""));
             lines.push(Line::from(Span::styled(
-                "j/k navigate, Enter select",
+                "j/k navigate, / search, Enter react (again to remove)",
                 styles::help_popup_footer_style(),
             )));
             lines.push(Line::from(Span::styled(
@@ -79,10 +80,55 @@ fn build_lines(state: &ReactionPickerState) -> Vec<Line<'static>> {
     }
 }
 
+/// Reaction lines with "Recent" / "All reactions" headers when there are
+/// recent reactions and no filter, plus the line index of the selection.
+fn list_lines(data: &ReactionPickerData) -> (Vec<Line<'static>>, usize) {
+    let visible = data.visible();
+    let sections = data.query().is_empty() && visible.iter().any(|r| r.is_recent);
+
+    let mut lines = Vec::new();
+    let mut selected_line = 0;
+    for (i, r) in visible.iter().enumerate() {
+        if sections && i == 0 {
+            lines.push(section_header("Recent"));
+        }
+        if sections && !r.is_recent && (i == 0 || visible[i - 1].is_recent) {
+            lines.push(section_header("All reactions"));
+        }
+        if i == data.selected_index {
+            selected_line = lines.len();
+        }
+        lines.push(reaction_line(r, i == data.selected_index));
+    }
+    (lines, selected_line)
+}
+
+fn section_header(title: &'static str) -> Line<'static> {
+    Line::from(Span::styled(title, styles::chat_info_popup_label_style()))
+}
+
+fn reaction_line(r: &AvailableReaction, selected: bool) -> Line<'static> {
+    let style = if selected {
+        Style::default()
+            .fg(Color::Black)
+            .bg(Color::White)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        styles::chat_info_popup_value_style()
+    };
+    let name = r.display_name();
+    let suffix = if r.is_chosen { " +1" } else { "" };
+    let label = if name.is_empty() {
+        format!("  {}{}", r.emoji, suffix)
+    } else {
+        format!("  {}  {}{}", r.emoji, name, suffix)
+    };
+    Line::from(Span::styled(label, style))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::reaction_picker_state::{AvailableReaction, ReactionPickerData};
 
     #[test]
     fn loading_state_shows_loading() {
@@ -90,7 +136,7 @@ mod tests {
             chat_id: 1,
             message_id: 2,
         };
-        let lines = build_lines(&state);
+        let lines = build_lines(&state, 20);
         assert_eq!(lines.len(), 1);
         assert!(lines[0].spans[0].content.contains("Loading"));
     }
@@ -98,7 +144,7 @@ mod tests {
     #[test]
     fn error_state_shows_error() {
         let state = ReactionPickerState::Error;
-        let lines = build_lines(&state);
+        let lines = build_lines(&state, 20);
         assert_eq!(lines.len(), 1);
         assert!(lines[0].spans[0].content.contains("Failed"));
     }
@@ -110,15 +156,17 @@ mod tests {
                 emoji: "👍".into(),
                 needs_premium: false,
                 is_chosen: false,
+                is_recent: false,
             },
             AvailableReaction {
                 emoji: "❤".into(),
                 needs_premium: false,
                 is_chosen: false,
+                is_recent: false,
             },
         ];
         let state = ReactionPickerState::Ready(ReactionPickerData::new(reactions, 1, 2));
-        let lines = build_lines(&state);
+        let lines = build_lines(&state, 20);
         assert_eq!(lines.len(), 5);
         assert!(lines[0].spans[0].content.contains("👍"));
         assert!(lines[0].spans[0].content.contains("thumbs_up"));
@@ -137,15 +185,17 @@ mod tests {
                 emoji: "🔥".into(),
                 needs_premium: false,
                 is_chosen: true,
+                is_recent: false,
             },
             AvailableReaction {
                 emoji: "❤".into(),
                 needs_premium: false,
                 is_chosen: false,
+                is_recent: false,
             },
         ];
         let state = ReactionPickerState::Ready(ReactionPickerData::new(reactions, 1, 2));
-        let lines = build_lines(&state);
+        let lines = build_lines(&state, 20);
         assert!(lines[0].spans[0].content.contains("🔥"));
         assert!(lines[0].spans[0].content.contains("fire +1"));
         assert!(lines[1].spans[0].content.contains("❤"));
@@ -159,10 +209,81 @@ mod tests {
             emoji: "🧪".into(),
             needs_premium: false,
             is_chosen: true,
+            is_recent: false,
         }];
         let state = ReactionPickerState::Ready(ReactionPickerData::new(reactions, 1, 2));
-        let lines = build_lines(&state);
+        let lines = build_lines(&state, 20);
         assert!(lines[0].spans[0].content.contains("🧪"));
         assert!(lines[0].spans[0].content.contains("+1"));
     }
+
+    fn reaction(emoji: &str, is_recent: bool) -> AvailableReaction {
+        AvailableReaction {
+            emoji: emoji.into(),
+            needs_premium: false,
+            is_chosen: false,
+            is_recent,
+        }
+    }
+
+    fn text(line: &Line<'_>) -> String {
+        line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    #[test]
+    fn recent_reactions_get_their_own_section() {
+        let reactions = vec![
+            reaction("🔥", true),
+            reaction("👍", false),
+            reaction("🤔", false),
+        ];
+        let state = ReactionPickerState::Ready(ReactionPickerData::new(reactions, 1, 2));
+        let lines = build_lines(&state, 20);
+        assert_eq!(text(&lines[0]), "Recent");
+        assert!(text(&lines[1]).contains("fire"));
+        assert_eq!(text(&lines[2]), "All reactions");
+        assert!(text(&lines[3]).contains("thumbs_up"));
+        assert!(text(&lines[4]).contains("thinking"));
+    }
+
+    #[test]
+    fn search_shows_query_and_hides_sections() {
+        let reactions = vec![reaction("🔥", true), reaction("👍", false)];
+        let mut data = ReactionPickerData::new(reactions, 1, 2);
+        data.start_search();
+        data.insert_char('t');
+        data.insert_char('h');
+        let state = ReactionPickerState::Ready(data);
+        let lines = build_lines(&state, 20);
+        assert_eq!(text(&lines[0]), "/th");
+        assert!(text(&lines[1]).contains("thumbs_up"));
+        assert_eq!(text(&lines[2]), "");
+    }
+
+    #[test]
+    fn empty_search_result_says_so() {
+        let mut data = ReactionPickerData::new(vec![reaction("👍", false)], 1, 2);
+        data.start_search();
+        data.insert_char('z');
+        let state = ReactionPickerState::Ready(data);
+        let lines = build_lines(&state, 20);
+        assert_eq!(text(&lines[1]), "No reactions found");
+    }
+
+    #[test]
+    fn long_list_scrolls_to_selection() {
+        let reactions = ["👍", "👎", "🔥", "🤔", "👀"]
+            .iter()
+            .map(|e| reaction(e, false))
+            .collect();
+        let mut data = ReactionPickerData::new(reactions, 1, 2);
+        for _ in 0..4 {
+            data.select_next();
+        }
+        let state = ReactionPickerState::Ready(data);
+        let lines = build_lines(&state, 2);
+        assert!(text(&lines[0]).contains("thinking"));
+        assert!(text(&lines[1]).contains("eyes"));
+        assert_eq!(lines.len(), 2 + 3);
+    }
 }
@@ -0,0 +1,103 @@
+use ratatui::{
+    layout::Rect,
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+use crate::domain::message::ReplyKeyboard;
+
+use super::styles;
+
+/// Keyboards taller than this are cut off; the selection still reaches
+/// every row, the hidden ones just are not drawn.
+const MAX_VISIBLE_ROWS: usize = 4;
+
+/// Number of lines the keyboard takes above the message input.
+pub fn reply_keyboard_height(keyboard: &ReplyKeyboard) -> u16 {
+    keyboard.rows.len().min(MAX_VISIBLE_ROWS) as u16
+}
+
+/// Renders a bot's custom reply keyboard between the messages and the input.
+pub fn render_reply_keyboard(
+    frame: &mut Frame<'_>,
+    area: Rect,
+    keyboard: &ReplyKeyboard,
+    selected: Option<(usize, usize)>,
+) {
+    let lines = build_lines(keyboard, selected);
+    frame.render_widget(Paragraph::new(lines), area);
+}
+
+/// Keeps the selected row visible when the keyboard is cut off.
+fn build_lines(keyboard: &ReplyKeyboard, selected: Option<(usize, usize)>) -> Vec<Line<'static>> {
+    let first_row = selected
+        .map(|(row, _)| (row + 1).saturating_sub(MAX_VISIBLE_ROWS))
+        .unwrap_or(0);
+
+    keyboard
+        .rows
+        .iter()
+        .enumerate()
+        .skip(first_row)
+        .take(MAX_VISIBLE_ROWS)
+        .map(|(row_idx, row)| {
+            let mut spans = Vec::new();
+            for (col_idx, label) in row.iter().enumerate() {
+                if col_idx > 0 {
+                    spans.push(Span::raw(" "));
+                }
+                let style = if selected == Some((row_idx, col_idx)) {
+                    styles::inline_button_selected_style()
+                } else {
+                    styles::inline_button_style()
+                };
+                spans.push(Span::styled(format!("[ {label} ]"), style));
+            }
+            Line::from(spans)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keyboard(rows: &[&[&str]]) -> ReplyKeyboard {
+        ReplyKeyboard {
+            rows: rows
+                .iter()
+                .map(|row| row.iter().map(|s| (*s).to_owned()).collect())
+                .collect(),
+            one_time: false,
+            placeholder: String::new(),
+        }
+    }
+
+    fn line_text(line: &Line<'_>) -> String {
+        line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    #[test]
+    fn renders_rows_of_bracketed_buttons() {
+        let lines = build_lines(&keyboard(&[&["Yes", "No"], &["Cancel"]]), Some((0, 1)));
+
+        assert_eq!(line_text(&lines[0]), "[ Yes ] [ No ]");
+        assert_eq!(line_text(&lines[1]), "[ Cancel ]");
+        assert_eq!(
+            lines[0].spans[2].style,
+            styles::inline_button_selected_style()
+        );
+    }
+
+    #[test]
+    fn tall_keyboard_scrolls_to_selected_row() {
+        let kb = keyboard(&[&["1"], &["2"], &["3"], &["4"], &["5"], &["6"]]);
+        assert_eq!(reply_keyboard_height(&kb), 4);
+
+        let lines = build_lines(&kb, Some((5, 0)));
+
+        assert_eq!(lines.len(), 4);
+        assert_eq!(line_text(&lines[3]), "[ 6 ]");
+    }
+}
@@ -31,7 +31,16 @@ pub fn start(
 
     while orchestrator.state().is_running() {
         let help_entries: Vec<HelpEntry> = if orchestrator.state().help_visible() {
-            let context = match orchestrator.state().active_pane() {
+            let state = orchestrator.state();
+            let context = match state.active_pane() {
+                crate::domain::shell_state::ActivePane::ChatList if state.contacts().is_some() => {
+                    KeyContext::Contacts
+                }
+                crate::domain::shell_state::ActivePane::ChatList
+                    if state.forum_topic_list().is_some() =>
+                {
+                    KeyContext::ForumTopicList
+                }
                 crate::domain::shell_state::ActivePane::ChatList => KeyContext::ChatList,
                 _ => KeyContext::Messages,
             };
@@ -19,11 +19,35 @@ pub fn unread_count_style() -> Style {
     Style::default().fg(Color::Green)
 }
 
+/// Style for the unread count badge of a muted chat (dimmed).
+pub fn muted_unread_count_style() -> Style {
+    Style::default().fg(Color::DarkGray)
+}
+
+/// Style for the "[blocked]" marker of a chat whose user is blocked.
+pub fn blocked_marker_style() -> Style {
+    Style::default().fg(Color::Red)
+}
+
+/// Style for the characters of a chat search result matched by the query.
+pub fn search_match_style() -> Style {
+    Style::default()
+        .fg(Color::Yellow)
+        .add_modifier(Modifier::BOLD)
+}
+
 /// Style for unread reaction badge in the chat list (pink heart icon).
 pub fn reaction_badge_style() -> Style {
     Style::default().fg(Color::LightRed)
 }
 
+/// Style for unread mention badge in the chat list (`@` icon).
+pub fn mention_badge_style() -> Style {
+    Style::default()
+        .fg(Color::LightBlue)
+        .add_modifier(Modifier::BOLD)
+}
+
 /// Style for section headers like "-- Pinned --".
 pub fn section_header_style() -> Style {
     Style::default().fg(Color::DarkGray)
@@ -58,3 +82,15 @@ pub fn outgoing_read_style() -> Style {
 pub fn group_sender_style() -> Style {
     Style::default().fg(Color::Cyan)
 }
+
+/// Style for inactive chat folder tabs (dimmed).
+pub fn folder_tab_style() -> Style {
+    Style::default().fg(Color::DarkGray)
+}
+
+/// Style for the chat folder tab being shown (bold, underlined).
+pub fn active_folder_tab_style() -> Style {
+    Style::default()
+        .fg(Color::White)
+        .add_modifier(Modifier::BOLD | Modifier::UNDERLINED)
+}
@@ -1,6 +1,6 @@
 //! Style definitions for the message input area.
 
-use ratatui::style::{Color, Style};
+use ratatui::style::{Color, Modifier, Style};
 
 /// Style for the input prompt symbol (>).
 pub fn input_prompt_style() -> Style {
@@ -16,3 +16,16 @@ pub fn input_text_style() -> Style {
 pub fn input_placeholder_style() -> Style {
     Style::default().fg(Color::DarkGray)
 }
+
+/// Style for the highlighted candidate in the completion popup.
+pub fn completion_selected_style() -> Style {
+    Style::default()
+        .fg(Color::Black)
+        .bg(Color::White)
+        .add_modifier(Modifier::BOLD)
+}
+
+/// Style for candidate details (e.g. bot command descriptions).
+pub fn completion_detail_style() -> Style {
+    Style::default().fg(Color::DarkGray)
+}
@@ -51,16 +51,46 @@ pub fn message_sending_style() -> Style {
     Style::default().fg(Color::DarkGray)
 }
 
+/// Style for the single tick on outgoing messages not yet read by the peer.
+pub fn message_sent_tick_style() -> Style {
+    Style::default().fg(Color::DarkGray)
+}
+
+/// Style for the double tick on outgoing messages read by the peer.
+pub fn message_read_tick_style() -> Style {
+    Style::default().fg(Color::LightGreen)
+}
+
+/// Style for inline keyboard buttons under bot messages.
+pub fn inline_button_style() -> Style {
+    Style::default().fg(Color::Cyan)
+}
+
+/// Style for the highlighted inline keyboard button in button-selection mode.
+pub fn inline_button_selected_style() -> Style {
+    Style::default()
+        .fg(Color::Black)
+        .bg(Color::Cyan)
+        .add_modifier(Modifier::BOLD)
+}
+
 /// Style for the "edited" indicator on edited messages.
 pub fn message_edited_style() -> Style {
     Style::default().fg(Color::DarkGray)
 }
 
-/// Style for reaction count on individual messages.
+/// Style for reaction counts on individual messages.
 pub fn message_reaction_style() -> Style {
     Style::default().fg(Color::LightRed)
 }
 
+/// Style for reactions the current user added.
+pub fn message_own_reaction_style() -> Style {
+    Style::default()
+        .fg(Color::LightRed)
+        .add_modifier(Modifier::BOLD | Modifier::REVERSED)
+}
+
 /// Style for service/system message text (member added, photo changed, etc.).
 pub fn service_message_style() -> Style {
     Style::default().fg(Color::DarkGray)
@@ -16,6 +16,12 @@ pub fn highlight_style() -> Style {
     Style::default().fg(Color::Black).bg(Color::Gray)
 }
 
+/// Style for items inside a visual (range) selection, other than the one
+/// under the cursor.
+pub fn visual_selection_style() -> Style {
+    Style::default().fg(Color::Black).bg(Color::DarkGray)
+}
+
 /// Style for the panel title when the panel is active.
 pub fn active_title_style() -> Style {
     Style::default()
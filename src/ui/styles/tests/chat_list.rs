@@ -15,6 +15,25 @@ fn unread_count_style_is_green() {
     assert_eq!(style.fg, Some(Color::Green));
 }
 
+#[test]
+fn muted_unread_count_style_is_dark_gray() {
+    let style = muted_unread_count_style();
+    assert_eq!(style.fg, Some(Color::DarkGray));
+}
+
+#[test]
+fn blocked_marker_style_is_red() {
+    let style = blocked_marker_style();
+    assert_eq!(style.fg, Some(Color::Red));
+}
+
+#[test]
+fn search_match_style_is_bold_yellow() {
+    let style = search_match_style();
+    assert_eq!(style.fg, Some(Color::Yellow));
+    assert!(style.add_modifier.contains(Modifier::BOLD));
+}
+
 #[test]
 fn online_indicator_style_is_green() {
     let style = online_indicator_style();
@@ -19,3 +19,10 @@ fn input_placeholder_style_is_dark_gray() {
     let style = input_placeholder_style();
     assert_eq!(style.fg, Some(Color::DarkGray));
 }
+
+#[test]
+fn completion_selected_style_is_highlighted() {
+    let style = completion_selected_style();
+    assert_eq!(style.bg, Some(Color::White));
+    assert_eq!(completion_detail_style().fg, Some(Color::DarkGray));
+}
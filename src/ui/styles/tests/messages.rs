@@ -106,6 +106,13 @@ fn message_reaction_style_is_light_red() {
     assert_eq!(style.fg, Some(Color::LightRed));
 }
 
+#[test]
+fn message_own_reaction_style_is_reversed() {
+    let style = message_own_reaction_style();
+    assert_eq!(style.fg, Some(Color::LightRed));
+    assert!(style.add_modifier.contains(Modifier::REVERSED));
+}
+
 #[test]
 fn reply_bar_style_is_light_blue() {
     let style = reply_bar_style();
@@ -171,3 +178,22 @@ fn message_sending_style_is_dark_gray() {
     let style = message_sending_style();
     assert_eq!(style.fg, Some(Color::DarkGray));
 }
+
+#[test]
+fn message_sent_tick_style_is_dark_gray() {
+    let style = message_sent_tick_style();
+    assert_eq!(style.fg, Some(Color::DarkGray));
+}
+
+#[test]
+fn message_read_tick_style_is_light_green() {
+    let style = message_read_tick_style();
+    assert_eq!(style.fg, Some(Color::LightGreen));
+}
+
+#[test]
+fn inline_button_selected_style_stands_out() {
+    let style = inline_button_selected_style();
+    assert_eq!(style.bg, Some(Color::Cyan));
+    assert_ne!(style, inline_button_style());
+}
@@ -9,6 +9,13 @@ fn highlight_style_is_gray_bg_black_fg() {
     assert_eq!(style.bg, Some(Color::Gray));
 }
 
+#[test]
+fn visual_selection_style_differs_from_highlight() {
+    let style = visual_selection_style();
+    assert_eq!(style.bg, Some(Color::DarkGray));
+    assert_ne!(style, highlight_style());
+}
+
 #[test]
 fn active_title_style_is_green_bold() {
     let style = active_title_style();
@@ -0,0 +1,79 @@
+use ratatui::text::{Line, Span};
+use unicode_width::UnicodeWidthStr;
+
+use crate::domain::chat_folder::{ChatListId, ChatListTab};
+
+use super::styles;
+
+const TAB_SEPARATOR: &str = "  ";
+
+struct TabSegment {
+    title: String,
+    badge: String,
+    is_active: bool,
+}
+
+impl TabSegment {
+    fn width(&self) -> usize {
+        self.title.width() + self.badge.width()
+    }
+}
+
+/// One line of folder tabs with unread badges. When the tabs do not fit,
+/// leading tabs are dropped so the active one stays visible.
+pub(super) fn folder_tabs_line(
+    tabs: &[ChatListTab],
+    active: ChatListId,
+    width: usize,
+) -> Line<'static> {
+    let segments: Vec<TabSegment> = tabs
+        .iter()
+        .map(|tab| TabSegment {
+            title: tab.title.clone(),
+            badge: if tab.unread_count > 0 {
+                format!(" {}", tab.unread_count)
+            } else {
+                String::new()
+            },
+            is_active: tab.list == active,
+        })
+        .collect();
+    let active_index = segments.iter().position(|s| s.is_active).unwrap_or(0);
+
+    let span_width = |range: &[TabSegment]| -> usize {
+        range.iter().map(TabSegment::width).sum::<usize>()
+            + TAB_SEPARATOR.width() * range.len().saturating_sub(1)
+    };
+    let mut start = 0;
+    while start < active_index && span_width(&segments[start..=active_index]) > width {
+        start += 1;
+    }
+
+    let mut spans = Vec::new();
+    let mut used = 0;
+    for segment in &segments[start..] {
+        let separator = if spans.is_empty() { "" } else { TAB_SEPARATOR };
+        let needed = separator.width() + segment.width();
+        if used + needed > width && !segment.is_active {
+            break;
+        }
+        used += needed;
+        if !separator.is_empty() {
+            spans.push(Span::raw(separator));
+        }
+        let title_style = if segment.is_active {
+            styles::active_folder_tab_style()
+        } else {
+            styles::folder_tab_style()
+        };
+        spans.push(Span::styled(segment.title.clone(), title_style));
+        if !segment.badge.is_empty() {
+            spans.push(Span::styled(
+                segment.badge.clone(),
+                styles::unread_count_style(),
+            ));
+        }
+    }
+
+    Line::from(spans)
+}
@@ -1,5 +1,5 @@
 use ratatui::{
-    layout::{Alignment, Rect},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::Style,
     text::{Line, Span},
     widgets::{Block, List, ListItem, ListState, Padding, Paragraph},
@@ -8,12 +8,17 @@ use ratatui::{
 
 use crate::domain::{
     chat::ChatSummary,
+    chat_folder::ChatListId,
+    chat_list_filter::ChatListFilters,
     chat_list_state::ChatListUiState,
+    contacts_state::{ContactsState, ContactsUiState},
     forum_topic_list_state::ForumTopicListUiState,
     shell_state::{ActivePane, ShellState},
 };
 
+use super::chat_folder_tabs::folder_tabs_line;
 use super::chat_list_item::chat_list_item_line;
+use super::contact_list_item::contact_list_item_line;
 use super::forum_topic_list_item::forum_topic_list_item_line;
 use super::{panel_title_style, styles};
 
@@ -26,38 +31,90 @@ pub(super) fn render_chat_list_panel(
     let is_active = active_pane == ActivePane::ChatList;
     let title_style = panel_title_style(is_active);
 
+    if let Some(contacts) = state.contacts() {
+        render_contacts_panel(frame, area, contacts, is_active, title_style);
+        return;
+    }
+
     if let Some(forum_list) = state.forum_topic_list() {
         render_forum_topic_list_panel(frame, area, forum_list, is_active, title_style);
         return;
     }
 
     let chat_list = state.chat_list();
+    let in_archive = chat_list.active_list() == ChatListId::Archive;
+    let panel_name = if in_archive { "Archive" } else { "Chats" };
+    let area = if chat_list.has_folders() && !in_archive {
+        let [tabs_area, list_area] = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Min(0)])
+            .areas(area);
+        let tabs_width = tabs_area.width.saturating_sub(2) as usize;
+        let tabs = Paragraph::new(folder_tabs_line(
+            &chat_list.tabs(),
+            chat_list.active_list(),
+            tabs_width,
+        ))
+        .block(Block::new().padding(Padding::horizontal(1)));
+        frame.render_widget(tabs, tabs_area);
+        list_area
+    } else {
+        area
+    };
+
     match chat_list.ui_state() {
         ChatListUiState::Loading => {
-            render_chat_list_message(frame, area, "Chats", "Loading chats...", title_style)
+            render_chat_list_message(frame, area, panel_name, "Loading chats...", title_style)
         }
+        ChatListUiState::Empty if in_archive => render_chat_list_message(
+            frame,
+            area,
+            panel_name,
+            "No archived chats. Press h to go back.",
+            title_style,
+        ),
         ChatListUiState::Empty => render_chat_list_message(
             frame,
             area,
-            "Chats",
+            panel_name,
             "No chats yet. Press refresh to try again.",
             title_style,
         ),
         ChatListUiState::Error => render_chat_list_message(
             frame,
             area,
-            "Chats",
+            panel_name,
             "Failed to load chats. Check connection and retry.",
             title_style,
         ),
+        ChatListUiState::Ready
+            if chat_list.chats().is_empty() && !chat_list.shows_archive_row() =>
+        {
+            let message = if chat_list.all_chats_loaded() {
+                "No chats match the filters. Press f0 to clear them."
+            } else {
+                "Looking for matching chats..."
+            };
+            let title = chat_list_title(panel_name, 0, chat_list.filters());
+            render_chat_list_message(frame, area, &title, message, title_style)
+        }
         ChatListUiState::Ready => {
             let chats = chat_list.chats();
             let inner_width = area.width.saturating_sub(2) as usize;
-            let layout = ChatListLayout::new(chats);
+            let mut layout = ChatListLayout::new(chats);
+            if chat_list.shows_archive_row() {
+                layout = layout.with_archive_row(chat_list.unread_count(ChatListId::Archive));
+            }
             let items = layout.build_items(chats, inner_width);
             let chat_count = chats.len();
 
-            let title = format!("Chats ({})", chat_count);
+            let title = match state.pending_forward() {
+                Some(forward) => format!(
+                    "Forward {} message(s) to (Esc to cancel)",
+                    forward.message_ids.len()
+                ),
+                None => chat_list_title(panel_name, chat_count, chat_list.filters()),
+            };
             let highlight = if is_active {
                 styles::highlight_style()
             } else {
@@ -74,12 +131,14 @@ pub(super) fn render_chat_list_panel(
                 )
                 .highlight_style(highlight);
 
-            let visual_index = if is_active {
+            let visual_index = if !is_active {
+                None
+            } else if chat_list.archive_row_selected() {
+                Some(0)
+            } else {
                 chat_list
                     .selected_index()
                     .map(|idx| layout.visual_index(idx))
-            } else {
-                None
             };
 
             let mut list_state = ListState::default();
@@ -89,6 +148,20 @@ pub(super) fn render_chat_list_panel(
     }
 }
 
+/// "Chats (12)", followed by the filters that are on, e.g.
+/// "Chats (3) [unread DMs]".
+pub(super) fn chat_list_title(
+    panel_name: &str,
+    chat_count: usize,
+    filters: &ChatListFilters,
+) -> String {
+    if filters.is_empty() {
+        format!("{} ({})", panel_name, chat_count)
+    } else {
+        format!("{} ({}) [{}]", panel_name, chat_count, filters.indicator())
+    }
+}
+
 fn render_chat_list_message(
     frame: &mut Frame<'_>,
     area: Rect,
@@ -108,12 +181,23 @@ fn render_chat_list_message(
 
 pub(super) struct ChatListLayout {
     pub pinned_count: usize,
+    /// Unread count shown on the collapsed "Archive" row, when there is one.
+    pub archive_row: Option<u32>,
 }
 
 impl ChatListLayout {
     pub fn new(chats: &[ChatSummary]) -> Self {
         let pinned_count = chats.iter().filter(|c| c.is_pinned).count();
-        Self { pinned_count }
+        Self {
+            pinned_count,
+            archive_row: None,
+        }
+    }
+
+    /// Adds the collapsed "Archive" row above the sections.
+    pub fn with_archive_row(mut self, unread_count: u32) -> Self {
+        self.archive_row = Some(unread_count);
+        self
     }
 
     pub fn has_pinned(&self) -> bool {
@@ -125,6 +209,10 @@ impl ChatListLayout {
 
         let mut items = Vec::new();
 
+        if let Some(unread_count) = self.archive_row {
+            items.push(ListItem::new(archive_row_line(unread_count)));
+        }
+
         if self.has_pinned() {
             items.push(section_header_item("Pinned"));
             for chat in &pinned {
@@ -143,15 +231,27 @@ impl ChatListLayout {
     }
 
     pub fn visual_index(&self, chat_index: usize) -> usize {
+        let archive_rows = usize::from(self.archive_row.is_some());
         if chat_index < self.pinned_count {
-            chat_index + 1
+            archive_rows + chat_index + 1
         } else {
             let headers = if self.has_pinned() { 2 } else { 1 };
-            chat_index + headers
+            archive_rows + chat_index + headers
         }
     }
 }
 
+fn archive_row_line(unread_count: u32) -> Line<'static> {
+    let mut spans = vec![Span::styled("Archive", styles::chat_name_style())];
+    if unread_count > 0 {
+        spans.push(Span::styled(
+            format!(" ({unread_count} unread)"),
+            styles::unread_count_style(),
+        ));
+    }
+    Line::from(spans)
+}
+
 fn section_header_item(title: &str) -> ListItem<'static> {
     let line = Line::from(vec![Span::styled(
         format!("-- {} --", title),
@@ -218,3 +318,74 @@ pub(super) fn render_forum_topic_list_panel(
         }
     }
 }
+
+pub(super) fn render_contacts_panel(
+    frame: &mut Frame<'_>,
+    area: Rect,
+    contacts: &ContactsState,
+    is_active: bool,
+    title_style: Style,
+) {
+    match contacts.ui_state() {
+        ContactsUiState::Loading => {
+            render_chat_list_message(frame, area, "Contacts", "Loading contacts...", title_style)
+        }
+        ContactsUiState::Empty => {
+            render_chat_list_message(frame, area, "Contacts", "No contacts yet.", title_style)
+        }
+        ContactsUiState::Error => render_chat_list_message(
+            frame,
+            area,
+            "Contacts",
+            "Failed to load contacts. Press R to retry.",
+            title_style,
+        ),
+        ContactsUiState::Ready => {
+            let visible = contacts.contacts();
+            let title = contacts_panel_title(contacts);
+            if visible.is_empty() {
+                render_chat_list_message(frame, area, &title, "No matching contacts", title_style);
+                return;
+            }
+
+            let inner_width = area.width.saturating_sub(2) as usize;
+            let items: Vec<ListItem<'static>> = visible
+                .iter()
+                .map(|contact| ListItem::new(contact_list_item_line(contact, inner_width)))
+                .collect();
+            let highlight = if is_active {
+                styles::highlight_style()
+            } else {
+                Style::default()
+            };
+
+            let list = List::new(items)
+                .block(
+                    Block::new()
+                        .title(title)
+                        .title_style(title_style)
+                        .title_alignment(Alignment::Center)
+                        .padding(Padding::horizontal(1)),
+                )
+                .highlight_style(highlight);
+
+            let mut list_state = ListState::default();
+            if is_active {
+                list_state.select(contacts.selected_index());
+            }
+            frame.render_stateful_widget(list, area, &mut list_state);
+        }
+    }
+}
+
+fn contacts_panel_title(contacts: &ContactsState) -> String {
+    if contacts.filter().is_empty() && !contacts.is_filtering() {
+        return format!("Contacts ({})", contacts.total_count());
+    }
+    format!(
+        "Contacts ({}/{}) /{}",
+        contacts.contacts().len(),
+        contacts.total_count(),
+        contacts.filter()
+    )
+}
@@ -43,6 +43,8 @@ pub(super) fn chat_list_item_line(chat: &ChatSummary, width: usize) -> Line<'sta
     };
     let unread_badge = if badge_count > 0 {
         format!(" [{}]", badge_count)
+    } else if chat.is_marked_as_unread {
+        " [\u{2022}]".to_owned() // unread dot
     } else {
         String::new()
     };
@@ -53,6 +55,12 @@ pub(super) fn chat_list_item_line(chat: &ChatSummary, width: usize) -> Line<'sta
         ""
     };
 
+    let mention_badge = if chat.unread_mention_count > 0 {
+        " [@]"
+    } else {
+        ""
+    };
+
     let online_indicator =
         if chat.chat_type == ChatType::Private && !chat.is_bot && chat.is_online == Some(true) {
             " \u{2022}" // bullet
@@ -63,6 +71,7 @@ pub(super) fn chat_list_item_line(chat: &ChatSummary, width: usize) -> Line<'sta
     let fixed_prefix_width = 5 + 3; // timestamp (5) + " | " (3)
     let suffix_width = outgoing_suffix_width
         + reaction_badge.width()
+        + mention_badge.width()
         + unread_badge.width()
         + online_indicator.width();
 
@@ -119,8 +128,20 @@ pub(super) fn chat_list_item_line(chat: &ChatSummary, width: usize) -> Line<'sta
         ));
     }
 
+    if !mention_badge.is_empty() {
+        spans.push(Span::styled(
+            mention_badge.to_owned(),
+            styles::mention_badge_style(),
+        ));
+    }
+
     if !unread_badge.is_empty() {
-        spans.push(Span::styled(unread_badge, styles::unread_count_style()));
+        let badge_style = if chat.is_muted {
+            styles::muted_unread_count_style()
+        } else {
+            styles::unread_count_style()
+        };
+        spans.push(Span::styled(unread_badge, badge_style));
     }
 
     Line::from(spans)
@@ -136,6 +157,13 @@ fn build_preview_prefix_segments(chat: &ChatSummary) -> Vec<PrefixSegment> {
 
     let mut segments = Vec::new();
 
+    if chat.is_blocked {
+        segments.push(PrefixSegment {
+            text: "[blocked] ".to_owned(),
+            style: styles::blocked_marker_style(),
+        });
+    }
+
     if chat.chat_type == ChatType::Group {
         if let Some(ref sender) = chat.last_message_sender {
             segments.push(PrefixSegment {
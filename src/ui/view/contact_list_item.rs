@@ -0,0 +1,41 @@
+use ratatui::text::{Line, Span};
+use unicode_width::UnicodeWidthStr;
+
+use crate::domain::contact::Contact;
+
+use super::styles;
+use super::text_utils::truncate_to_display_width;
+
+pub(super) fn contact_list_item_line(contact: &Contact, width: usize) -> Line<'static> {
+    // Same marker as online private chats in the chat list.
+    let online_indicator = if contact.is_online { " \u{2022}" } else { "" };
+    let username = if contact.username.is_empty() {
+        String::new()
+    } else {
+        format!(" @{}", contact.username)
+    };
+
+    // The name yields space to the online marker; the username is dropped
+    // before the name is truncated.
+    let name_budget = width.saturating_sub(online_indicator.width());
+    let (name, _) = truncate_to_display_width(&contact.display_name, name_budget);
+    let username_budget = name_budget.saturating_sub(name.width());
+    let username = if username.width() <= username_budget {
+        username
+    } else {
+        String::new()
+    };
+
+    let mut spans = vec![Span::styled(name, styles::chat_name_style())];
+    if !username.is_empty() {
+        spans.push(Span::styled(username, styles::chat_preview_style()));
+    }
+    if !online_indicator.is_empty() {
+        spans.push(Span::styled(
+            online_indicator,
+            styles::online_indicator_style(),
+        ));
+    }
+
+    Line::from(spans)
+}
@@ -1,3 +1,5 @@
+use std::ops::RangeInclusive;
+
 use ratatui::{
     layout::{Alignment, Rect},
     style::{Color, Modifier, Style},
@@ -14,6 +16,7 @@ use crate::domain::{
 use crate::ui::chat_message_list::{ChatMessageList, ChatMessageListState};
 use crate::ui::message_rendering::{
     build_message_list_elements, element_to_text, message_index_to_element_index,
+    MessageListElement,
 };
 use crate::ui::styles;
 
@@ -71,13 +74,15 @@ pub(super) fn render_messages_panel(
                 let panel = Paragraph::new("No messages in this chat").block(block());
                 frame.render_widget(panel, area);
             } else {
-                let elements = build_message_list_elements(messages);
+                let mut elements = build_message_list_elements(messages);
 
                 let element_index = state
                     .open_chat()
                     .selected_index()
                     .and_then(|msg_idx| message_index_to_element_index(&elements, msg_idx));
 
+                mark_selected_inline_button(state, &mut elements, element_index);
+
                 let highlight = if is_active {
                     styles::highlight_style()
                 } else {
@@ -93,10 +98,13 @@ pub(super) fn render_messages_panel(
                     .map(|e| element_to_text(e, content_width))
                     .collect();
 
-                let list = ChatMessageList::new(texts)
+                let mut list = ChatMessageList::new(texts)
                     .block(block())
                     .highlight_style(highlight)
                     .scroll_padding(SCROLL_MARGIN);
+                if let Some(range) = visual_element_range(state, &elements) {
+                    list = list.marked(range, styles::visual_selection_style());
+                }
 
                 let scroll_offset = state.open_chat().scroll_offset();
                 let mut list_state = ChatMessageListState::new(scroll_offset, element_index);
@@ -113,6 +121,40 @@ pub(super) fn render_messages_panel(
     }
 }
 
+/// Element indices covered by the visual selection, including any date
+/// separators between the selected messages.
+fn visual_element_range(
+    state: &ShellState,
+    elements: &[MessageListElement],
+) -> Option<RangeInclusive<usize>> {
+    let range = state.open_chat().visual_range()?;
+    let start = message_index_to_element_index(elements, *range.start())?;
+    let end = message_index_to_element_index(elements, *range.end())?;
+    Some(start..=end)
+}
+
+/// Highlights the selected inline keyboard button when button-selection
+/// mode is active on the selected message.
+fn mark_selected_inline_button(
+    state: &ShellState,
+    elements: &mut [MessageListElement],
+    element_index: Option<usize>,
+) {
+    let Some(selection) = state.inline_button_selection() else {
+        return;
+    };
+    if state.open_chat().selected_message().map(|m| m.id) != Some(selection.message_id()) {
+        return;
+    }
+    if let Some(MessageListElement::Message {
+        inline_keyboard: Some(keyboard),
+        ..
+    }) = element_index.and_then(|idx| elements.get_mut(idx))
+    {
+        keyboard.selected = Some(selection.position());
+    }
+}
+
 /// ANSI Shadow rendering of "RTG". All lines share the same display width so
 /// centered alignment keeps the block shape intact.
 pub(super) const RTG_LOGO_LINES: [&str; 6] = [
@@ -165,6 +207,13 @@ pub(super) fn open_chat_title(
 
     let name = open_chat.chat_title().to_owned();
 
+    if let Some(range) = open_chat.visual_range() {
+        return Line::from(Span::styled(
+            format!("{} \u{00b7} VISUAL ({})", name, range.count()),
+            title_style,
+        ));
+    }
+
     if open_chat.is_refreshing() {
         return Line::from(Span::styled(
             format!("{} \u{00b7} updating...", name),
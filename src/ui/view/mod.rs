@@ -1,5 +1,7 @@
+mod chat_folder_tabs;
 mod chat_list;
 mod chat_list_item;
+mod contact_list_item;
 mod forum_topic_list_item;
 mod messages_panel;
 mod status_line;
@@ -15,16 +17,28 @@ use ratatui::{
 
 use crate::domain::{chat::ChatType, keymap::HelpEntry, shell_state::ShellState};
 
+use super::alert_popup;
+use super::chat_confirm_popup;
 use super::chat_info_popup;
 use super::chat_search_popup;
 use super::command_popup;
+use super::completion_popup;
+use super::date_prompt_popup;
+use super::delete_confirm_popup;
+use super::emoji_picker_popup;
+use super::go_to_prompt_popup;
 use super::help_popup;
+use super::join_prompt_popup;
+use super::link_hints_popup;
 use super::message_info_popup;
 use super::message_input::{
     render_message_input, reply_preview_height, CHANNEL_READONLY_PLACEHOLDER, PLACEHOLDER_TEXT,
     TOPIC_CLOSED_PLACEHOLDER,
 };
+use super::new_chat_wizard_popup;
+use super::quick_switcher_popup;
 use super::reaction_picker_popup;
+use super::reply_keyboard::{render_reply_keyboard, reply_keyboard_height};
 use super::styles;
 
 pub fn render(frame: &mut Frame<'_>, state: &mut ShellState, help_entries: &[HelpEntry]) {
@@ -59,24 +73,42 @@ pub fn render(frame: &mut Frame<'_>, state: &mut ShellState, help_entries: &[Hel
         )
         .saturating_add(reply_preview_height(state.message_input()));
 
-        // Split right panel into messages area, horizontal separator, and input field
-        let [messages_area, input_separator_area, input_area] = Layout::default()
+        let is_channel = state.open_chat().chat_type() == ChatType::Channel;
+        let keyboard_height = match state.open_chat().reply_keyboard() {
+            Some((_, keyboard)) if !is_channel => reply_keyboard_height(keyboard),
+            _ => 0,
+        };
+
+        // Split right panel into messages area, horizontal separator,
+        // the bot's reply keyboard (if any) and input field
+        let [messages_area, input_separator_area, keyboard_area, input_area] = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
                 Constraint::Min(1),
                 Constraint::Length(1),
+                Constraint::Length(keyboard_height),
                 Constraint::Length(input_height),
             ])
             .areas(messages_with_input_area);
 
         messages_panel::render_messages_panel(frame, messages_area, state, active_pane);
         render_horizontal_separator(frame, input_separator_area);
-        let placeholder = if state.open_chat().chat_type() == ChatType::Channel {
+
+        let mut bot_placeholder = None;
+        if keyboard_height > 0 {
+            if let Some((_, keyboard)) = state.open_chat().reply_keyboard() {
+                let selected = state.reply_keyboard_selection().map(|s| s.position());
+                render_reply_keyboard(frame, keyboard_area, keyboard, selected);
+                bot_placeholder = Some(keyboard.placeholder.as_str()).filter(|p| !p.is_empty());
+            }
+        }
+
+        let placeholder = if is_channel {
             CHANNEL_READONLY_PLACEHOLDER
         } else if state.open_topic_is_closed() {
             TOPIC_CLOSED_PLACEHOLDER
         } else {
-            PLACEHOLDER_TEXT
+            bot_placeholder.unwrap_or(PLACEHOLDER_TEXT)
         };
         render_message_input(
             frame,
@@ -85,6 +117,15 @@ pub fn render(frame: &mut Frame<'_>, state: &mut ShellState, help_entries: &[Hel
             active_pane,
             placeholder,
         );
+
+        if let Some(completion) = state.completion() {
+            completion_popup::render_completion_popup(
+                frame,
+                input_area,
+                messages_with_input_area,
+                completion,
+            );
+        }
     } else {
         // No chat open: hide the input field entirely and give the whole right
         // panel to the (empty) messages panel.
@@ -119,6 +160,46 @@ pub fn render(frame: &mut Frame<'_>, state: &mut ShellState, help_entries: &[Hel
     if let Some(search_state) = state.chat_search() {
         chat_search_popup::render_chat_search_popup(frame, frame.area(), search_state);
     }
+
+    if let Some(switcher) = state.quick_switcher() {
+        quick_switcher_popup::render_quick_switcher_popup(frame, frame.area(), switcher);
+    }
+
+    if let Some(prompt_state) = state.date_prompt() {
+        date_prompt_popup::render_date_prompt_popup(frame, frame.area(), prompt_state);
+    }
+
+    if let Some(prompt_state) = state.go_to_prompt() {
+        go_to_prompt_popup::render_go_to_prompt_popup(frame, frame.area(), prompt_state);
+    }
+
+    if let Some(wizard) = state.new_chat_wizard() {
+        new_chat_wizard_popup::render_new_chat_wizard_popup(frame, frame.area(), wizard);
+    }
+
+    if let Some(picker_state) = state.emoji_picker() {
+        emoji_picker_popup::render_emoji_picker(frame, frame.area(), picker_state);
+    }
+
+    if let Some(confirm_state) = state.delete_confirm() {
+        delete_confirm_popup::render_delete_confirm_popup(frame, frame.area(), confirm_state);
+    }
+
+    if let Some(confirm_state) = state.chat_confirm() {
+        chat_confirm_popup::render_chat_confirm_popup(frame, frame.area(), confirm_state);
+    }
+
+    if let Some(invite) = state.join_prompt() {
+        join_prompt_popup::render_join_prompt_popup(frame, frame.area(), invite);
+    }
+
+    if let Some(hints) = state.link_hints() {
+        link_hints_popup::render_link_hints_popup(frame, frame.area(), hints);
+    }
+
+    if let Some(text) = state.alert() {
+        alert_popup::render_alert_popup(frame, frame.area(), text);
+    }
 }
 
 /// Renders a vertical separator line between panels.
@@ -0,0 +1,48 @@
+use crate::domain::chat_folder::{ChatListId, ChatListTab};
+use crate::ui::styles;
+use crate::ui::view::chat_folder_tabs;
+
+use super::line_to_string;
+
+fn tab(list: ChatListId, title: &str, unread_count: u32) -> ChatListTab {
+    ChatListTab {
+        list,
+        title: title.to_owned(),
+        unread_count,
+    }
+}
+
+fn sample_tabs() -> Vec<ChatListTab> {
+    vec![
+        tab(ChatListId::Main, "All chats", 12),
+        tab(ChatListId::Folder(1), "Work", 0),
+        tab(ChatListId::Folder(2), "Bots", 3),
+    ]
+}
+
+#[test]
+fn renders_tabs_with_unread_badges() {
+    let line = chat_folder_tabs::folder_tabs_line(&sample_tabs(), ChatListId::Folder(1), 60);
+
+    assert_eq!(line_to_string(&line), "All chats 12  Work  Bots 3");
+}
+
+#[test]
+fn highlights_active_tab() {
+    let line = chat_folder_tabs::folder_tabs_line(&sample_tabs(), ChatListId::Folder(1), 60);
+
+    let work = line
+        .spans
+        .iter()
+        .find(|span| span.content == "Work")
+        .expect("active tab span");
+    assert_eq!(work.style, styles::active_folder_tab_style());
+    assert_eq!(line.spans[0].style, styles::folder_tab_style());
+}
+
+#[test]
+fn narrow_row_keeps_active_tab_visible() {
+    let line = chat_folder_tabs::folder_tabs_line(&sample_tabs(), ChatListId::Folder(2), 14);
+
+    assert_eq!(line_to_string(&line), "Work  Bots 3");
+}
@@ -1,10 +1,17 @@
 use ratatui::{backend::TestBackend, style::Style, Terminal};
 
 use super::{
-    super::chat_list::{render_forum_topic_list_panel, ChatListLayout},
+    super::chat_list::{
+        chat_list_title, render_contacts_panel, render_forum_topic_list_panel, ChatListLayout,
+    },
     chat, chat_with_pinned,
 };
-use crate::domain::forum_topic_list_state::ForumTopicListState;
+use crate::domain::{
+    chat_list_filter::{ChatListFilter, ChatListFilters},
+    contact::Contact,
+    contacts_state::ContactsState,
+    forum_topic_list_state::ForumTopicListState,
+};
 
 const TEST_WIDTH: usize = 50;
 
@@ -50,6 +57,29 @@ fn compute_visual_index_with_no_pinned() {
     assert_eq!(layout.visual_index(1), 2);
 }
 
+#[test]
+fn chat_list_title_shows_active_filters() {
+    let mut filters = ChatListFilters::default();
+    assert_eq!(chat_list_title("Chats", 12, &filters), "Chats (12)");
+
+    filters.toggle(ChatListFilter::Private);
+    filters.toggle(ChatListFilter::Unread);
+    assert_eq!(
+        chat_list_title("Chats", 3, &filters),
+        "Chats (3) [unread DMs]"
+    );
+}
+
+#[test]
+fn archive_row_comes_before_sections() {
+    let chats = vec![chat(1, "Chat1", 0, None)];
+    let layout = ChatListLayout::new(&chats).with_archive_row(3);
+    let items = layout.build_items(&chats, TEST_WIDTH);
+
+    assert_eq!(items.len(), 3);
+    assert_eq!(layout.visual_index(0), 2);
+}
+
 #[test]
 fn compute_visual_index_with_all_pinned() {
     let chats = vec![
@@ -126,3 +156,46 @@ fn forum_panel_loading_state_shows_forum_title_not_chats() {
         "panel title in Loading state must not fall back to 'Chats', got: {rendered}"
     );
 }
+
+#[test]
+fn contacts_panel_title_shows_filter_and_match_count() {
+    let backend = TestBackend::new(40, 5);
+    let mut terminal = Terminal::new(backend).unwrap();
+
+    let mut contacts = ContactsState::loading();
+    contacts.set_ready(vec![
+        Contact {
+            user_id: 1,
+            display_name: "Alice".to_owned(),
+            username: String::new(),
+            is_online: false,
+        },
+        Contact {
+            user_id: 2,
+            display_name: "Bob".to_owned(),
+            username: String::new(),
+            is_online: false,
+        },
+    ]);
+    contacts.start_filtering();
+    contacts.push_filter_char('b');
+
+    terminal
+        .draw(|frame| {
+            let area = frame.area();
+            render_contacts_panel(frame, area, &contacts, true, Style::default());
+        })
+        .unwrap();
+
+    let buffer = terminal.backend().buffer().clone();
+    let mut rendered = String::new();
+    for y in 0..buffer.area.height {
+        for x in 0..buffer.area.width {
+            rendered.push_str(buffer[(x, y)].symbol());
+        }
+    }
+
+    assert!(rendered.contains("Contacts (1/2) /b"), "got: {rendered}");
+    assert!(rendered.contains("Bob"));
+    assert!(!rendered.contains("Alice"));
+}
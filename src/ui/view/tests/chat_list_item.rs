@@ -1,6 +1,7 @@
 use unicode_width::UnicodeWidthStr;
 
 use crate::domain::chat::{ChatSummary, ChatType, OutgoingReadStatus};
+use crate::ui::styles;
 
 use super::{
     super::chat_list_item, channel_chat_outgoing, chat, group_chat, group_chat_outgoing,
@@ -38,6 +39,54 @@ fn chat_list_item_omits_counter_when_zero() {
     assert!(!text.contains("[]"));
 }
 
+#[test]
+fn muted_chat_has_dimmed_unread_counter() {
+    let mut c = chat(1, "Noisy channel", 12, Some("Hello"));
+    let loud = chat_list_item::chat_list_item_line(&c, TEST_WIDTH);
+    c.is_muted = true;
+    let muted = chat_list_item::chat_list_item_line(&c, TEST_WIDTH);
+
+    let badge_style = |line: &ratatui::text::Line<'_>| {
+        line.spans
+            .iter()
+            .find(|span| span.content.contains("[12]"))
+            .map(|span| span.style)
+    };
+    assert_eq!(badge_style(&loud), Some(styles::unread_count_style()));
+    assert_eq!(
+        badge_style(&muted),
+        Some(styles::muted_unread_count_style())
+    );
+}
+
+#[test]
+fn chat_marked_as_unread_shows_dot_without_unread_messages() {
+    let mut c = chat(1, "To-do", 0, Some("Hello"));
+    c.is_marked_as_unread = true;
+
+    let text = line_to_string(&chat_list_item::chat_list_item_line(&c, TEST_WIDTH));
+    assert!(text.ends_with("[\u{2022}]"), "got '{text}'");
+
+    c.unread_count = 4;
+    let text = line_to_string(&chat_list_item::chat_list_item_line(&c, TEST_WIDTH));
+    assert!(text.ends_with("[4]"), "got '{text}'");
+}
+
+#[test]
+fn blocked_chat_shows_marker_before_preview() {
+    let mut c = chat(1, "Spammer", 0, Some("Buy now"));
+    c.is_blocked = true;
+
+    let line = chat_list_item::chat_list_item_line(&c, TEST_WIDTH);
+    let text = line_to_string(&line);
+
+    assert!(text.contains("Spammer [blocked] Buy now"), "got '{text}'");
+    assert!(line
+        .spans
+        .iter()
+        .any(|span| span.content == "[blocked] " && span.style == styles::blocked_marker_style()));
+}
+
 #[test]
 fn forum_chat_badge_shows_unread_topic_count_not_message_count() {
     let mut c = chat(1, "Forum", 42, Some("Hello"));
@@ -217,8 +266,13 @@ fn channel_does_not_render_sender_prefix() {
         outgoing_status: OutgoingReadStatus::default(),
         last_message_id: None,
         unread_reaction_count: 0,
+        unread_mention_count: 0,
         is_forum: false,
         unread_topic_count: None,
+        is_muted: false,
+        is_marked_as_unread: false,
+        is_blocked: false,
+        username: None,
     };
 
     let line = chat_list_item::chat_list_item_line(&c, TEST_WIDTH);
@@ -393,8 +447,13 @@ fn chat_with_unread_and_online_shows_both() {
         outgoing_status: OutgoingReadStatus::default(),
         last_message_id: None,
         unread_reaction_count: 0,
+        unread_mention_count: 0,
         is_forum: false,
         unread_topic_count: None,
+        is_muted: false,
+        is_marked_as_unread: false,
+        is_blocked: false,
+        username: None,
     };
 
     let line = chat_list_item::chat_list_item_line(&c, 70);
@@ -420,8 +479,13 @@ fn bot_chat_online_does_not_show_online_indicator() {
         outgoing_status: OutgoingReadStatus::default(),
         last_message_id: None,
         unread_reaction_count: 0,
+        unread_mention_count: 0,
         is_forum: false,
         unread_topic_count: None,
+        is_muted: false,
+        is_marked_as_unread: false,
+        is_blocked: false,
+        username: None,
     };
 
     let line = chat_list_item::chat_list_item_line(&c, 70);
@@ -451,8 +515,13 @@ fn chat_with_unread_reactions_shows_heart_badge() {
         outgoing_status: OutgoingReadStatus::default(),
         last_message_id: None,
         unread_reaction_count: 2,
+        unread_mention_count: 0,
         is_forum: false,
         unread_topic_count: None,
+        is_muted: false,
+        is_marked_as_unread: false,
+        is_blocked: false,
+        username: None,
     };
 
     let line = chat_list_item::chat_list_item_line(&c, 70);
@@ -492,8 +561,13 @@ fn chat_with_reactions_and_unread_shows_both_badges() {
         outgoing_status: OutgoingReadStatus::default(),
         last_message_id: None,
         unread_reaction_count: 1,
+        unread_mention_count: 0,
         is_forum: false,
         unread_topic_count: None,
+        is_muted: false,
+        is_marked_as_unread: false,
+        is_blocked: false,
+        username: None,
     };
 
     let line = chat_list_item::chat_list_item_line(&c, 80);
@@ -505,3 +579,25 @@ fn chat_with_reactions_and_unread_shows_both_badges() {
     );
     assert!(text.contains("[3]"), "expected unread badge in: {text}");
 }
+
+#[test]
+fn chat_with_unread_mentions_shows_at_badge_before_count() {
+    let mut c = chat(1, "Team", 5, Some("ping"));
+    c.unread_mention_count = 2;
+
+    let line = chat_list_item::chat_list_item_line(&c, 80);
+    let text = line_to_string(&line);
+
+    let mention = text.find("[@]").expect("expected mention badge");
+    let count = text.find("[5]").expect("expected unread badge");
+    assert!(mention < count, "mention badge must precede count: {text}");
+}
+
+#[test]
+fn chat_without_unread_mentions_has_no_at_badge() {
+    let c = chat(1, "Team", 5, Some("ping"));
+    let line = chat_list_item::chat_list_item_line(&c, 80);
+    let text = line_to_string(&line);
+
+    assert!(!text.contains("[@]"), "unexpected mention badge: {text}");
+}
@@ -0,0 +1,40 @@
+use crate::domain::contact::Contact;
+use crate::ui::view::contact_list_item;
+
+use super::line_to_string;
+
+fn contact(name: &str, username: &str, is_online: bool) -> Contact {
+    Contact {
+        user_id: 1,
+        display_name: name.to_owned(),
+        username: username.to_owned(),
+        is_online,
+    }
+}
+
+#[test]
+fn renders_name_username_and_online_marker() {
+    let c = contact("Alice Smith", "alice", true);
+
+    let line = contact_list_item::contact_list_item_line(&c, 40);
+
+    assert_eq!(line_to_string(&line), "Alice Smith @alice \u{2022}");
+}
+
+#[test]
+fn omits_missing_username_and_offline_marker() {
+    let c = contact("Bob", "", false);
+
+    let line = contact_list_item::contact_list_item_line(&c, 40);
+
+    assert_eq!(line_to_string(&line), "Bob");
+}
+
+#[test]
+fn drops_username_when_row_is_narrow() {
+    let c = contact("Alice Smith", "alice_in_wonderland", false);
+
+    let line = contact_list_item::contact_list_item_line(&c, 16);
+
+    assert_eq!(line_to_string(&line), "Alice Smith");
+}
@@ -44,10 +44,12 @@ fn make_message() -> crate::domain::message::Message {
         call_info: None,
         reply_to: None,
         forward_info: None,
-        reaction_count: 0,
+        reactions: Vec::new(),
         links: Vec::new(),
         is_edited: false,
         is_service: false,
+        inline_keyboard: Vec::new(),
+        reply_keyboard: None,
     }
 }
 
@@ -222,3 +224,26 @@ fn empty_placeholder_includes_logo_version_and_slogan() {
         .any(|t| *t == format!("v{}", env!("CARGO_PKG_VERSION"))));
     assert!(texts.iter().any(|t| t == messages_panel::RTG_SLOGAN));
 }
+
+#[test]
+fn open_chat_title_shows_visual_selection_size() {
+    let mut state = ShellState::default();
+    state.open_chat_mut().set_loading(
+        1,
+        "General".to_owned(),
+        crate::domain::chat::ChatType::Private,
+    );
+    let second = crate::domain::message::Message {
+        id: 2,
+        ..make_message()
+    };
+    state
+        .open_chat_mut()
+        .set_ready(vec![make_message(), second]);
+    state.open_chat_mut().start_visual();
+    state.open_chat_mut().select_previous();
+
+    let title = messages_panel::open_chat_title(state.open_chat(), true);
+
+    assert_eq!(title_to_string(&title), "General \u{00b7} VISUAL (2)");
+}
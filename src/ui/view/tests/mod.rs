@@ -1,5 +1,7 @@
+mod chat_folder_tabs;
 mod chat_list;
 mod chat_list_item;
+mod contact_list_item;
 mod forum_topic_list_item;
 mod messages_panel;
 mod status_line;
@@ -43,8 +45,13 @@ pub(super) fn chat_with_pinned(
         outgoing_status: OutgoingReadStatus::default(),
         last_message_id: None,
         unread_reaction_count: 0,
+        unread_mention_count: 0,
         is_forum: false,
         unread_topic_count: None,
+        is_muted: false,
+        is_marked_as_unread: false,
+        is_blocked: false,
+        username: None,
     }
 }
 
@@ -68,8 +75,13 @@ pub(super) fn group_chat(
         outgoing_status: OutgoingReadStatus::default(),
         last_message_id: None,
         unread_reaction_count: 0,
+        unread_mention_count: 0,
         is_forum: false,
         unread_topic_count: None,
+        is_muted: false,
+        is_marked_as_unread: false,
+        is_blocked: false,
+        username: None,
     }
 }
 
@@ -94,8 +106,13 @@ pub(super) fn group_chat_outgoing(
         outgoing_status: OutgoingReadStatus::Outgoing { is_read },
         last_message_id: None,
         unread_reaction_count: 0,
+        unread_mention_count: 0,
         is_forum: false,
         unread_topic_count: None,
+        is_muted: false,
+        is_marked_as_unread: false,
+        is_blocked: false,
+        username: None,
     }
 }
 
@@ -119,8 +136,13 @@ pub(super) fn private_chat_online(
         outgoing_status: OutgoingReadStatus::default(),
         last_message_id: None,
         unread_reaction_count: 0,
+        unread_mention_count: 0,
         is_forum: false,
         unread_topic_count: None,
+        is_muted: false,
+        is_marked_as_unread: false,
+        is_blocked: false,
+        username: None,
     }
 }
 
@@ -144,8 +166,13 @@ pub(super) fn private_chat_outgoing(
         outgoing_status: OutgoingReadStatus::Outgoing { is_read },
         last_message_id: None,
         unread_reaction_count: 0,
+        unread_mention_count: 0,
         is_forum: false,
         unread_topic_count: None,
+        is_muted: false,
+        is_marked_as_unread: false,
+        is_blocked: false,
+        username: None,
     }
 }
 
@@ -169,7 +196,12 @@ pub(super) fn channel_chat_outgoing(
         outgoing_status: OutgoingReadStatus::Outgoing { is_read },
         last_message_id: None,
         unread_reaction_count: 0,
+        unread_mention_count: 0,
         is_forum: false,
         unread_topic_count: None,
+        is_muted: false,
+        is_marked_as_unread: false,
+        is_blocked: false,
+        username: None,
     }
 }
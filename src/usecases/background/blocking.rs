@@ -0,0 +1,32 @@
+use std::{sync::mpsc, time::Duration};
+
+use crate::domain::events::BackgroundError;
+
+/// Runs `task` on a background thread and waits at most `timeout` for its
+/// result, so a stalled request cannot hold up quitting.
+pub(super) fn run_blocking(
+    thread_name: &str,
+    timeout: Duration,
+    task: impl FnOnce() -> Result<(), BackgroundError> + Send + 'static,
+) -> Result<(), BackgroundError> {
+    let (done_tx, done_rx) = mpsc::channel();
+
+    std::thread::Builder::new()
+        .name(thread_name.into())
+        .spawn(move || {
+            let _ = done_tx.send(task());
+        })
+        .map_err(|error| {
+            tracing::error!(error = %error, thread_name, "failed to spawn background thread");
+            BackgroundError::new("THREAD_SPAWN_FAILED")
+        })?;
+
+    done_rx.recv_timeout(timeout).unwrap_or_else(|_| {
+        tracing::warn!(
+            thread_name,
+            ?timeout,
+            "background task did not finish in time"
+        );
+        Err(BackgroundError::new("TIMED_OUT"))
+    })
+}
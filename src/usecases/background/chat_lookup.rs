@@ -0,0 +1,136 @@
+use std::sync::{mpsc::Sender, Arc};
+
+use crate::{
+    domain::{
+        events::{BackgroundError, BackgroundTaskResult},
+        go_to_prompt_state::ChatQuery,
+        new_chat_wizard_state::NewChatRequest,
+    },
+    usecases::{
+        go_to_chat::{searches_server, ChatLookupError, ChatLookupSource, CHAT_SEARCH_LIMIT},
+        new_chat::{ChatCreator, NewChatError},
+    },
+};
+
+pub(super) fn dispatch_find_chat<S: ChatLookupSource + Send + Sync + 'static>(
+    source: &Arc<S>,
+    tx: &Sender<BackgroundTaskResult>,
+    query: ChatQuery,
+) {
+    let source = Arc::clone(source);
+    let tx = tx.clone();
+    let tx_fallback = tx.clone();
+    let fallback_query = query.clone();
+
+    let spawn_result = std::thread::Builder::new()
+        .name("rtg-bg-find-chat".into())
+        .spawn(move || {
+            tracing::debug!(?query, "background: looking up chat");
+            let result = source.find_chat(&query).map_err(|e| match e {
+                ChatLookupError::NotFound => BackgroundError::new("CHAT_NOT_FOUND"),
+                ChatLookupError::Ambiguous => BackgroundError::new("CHAT_AMBIGUOUS"),
+                ChatLookupError::Unavailable => BackgroundError::new("CHAT_LOOKUP_UNAVAILABLE"),
+            });
+
+            let _ = tx.send(BackgroundTaskResult::ChatFound { query, result });
+        });
+
+    if let Err(error) = spawn_result {
+        tracing::error!(error = %error, "failed to spawn chat lookup background thread");
+        let _ = tx_fallback.send(BackgroundTaskResult::ChatFound {
+            query: fallback_query,
+            result: Err(BackgroundError::new("THREAD_SPAWN_FAILED")),
+        });
+    }
+}
+
+pub(super) fn dispatch_search_chats<S: ChatLookupSource + Send + Sync + 'static>(
+    source: &Arc<S>,
+    tx: &Sender<BackgroundTaskResult>,
+    query: String,
+) {
+    let source = Arc::clone(source);
+    let tx = tx.clone();
+    let tx_fallback = tx.clone();
+    let fallback_query = query.clone();
+
+    let spawn_result = std::thread::Builder::new()
+        .name("rtg-bg-search-chats".into())
+        .spawn(move || {
+            let map_error = |e| match e {
+                ChatLookupError::NotFound | ChatLookupError::Ambiguous => {
+                    BackgroundError::new("CHAT_SEARCH_FAILED")
+                }
+                ChatLookupError::Unavailable => BackgroundError::new("CHAT_SEARCH_UNAVAILABLE"),
+            };
+
+            tracing::debug!(%query, "background: searching chats");
+            let result = source
+                .search_chats(&query, CHAT_SEARCH_LIMIT)
+                .map_err(map_error);
+            let _ = tx.send(BackgroundTaskResult::ChatsSearched {
+                query: query.clone(),
+                on_server: false,
+                result,
+            });
+            if !searches_server(&query) {
+                return;
+            }
+
+            let result = source
+                .search_chats_on_server(&query, CHAT_SEARCH_LIMIT)
+                .map_err(map_error);
+            let _ = tx.send(BackgroundTaskResult::ChatsSearched {
+                query,
+                on_server: true,
+                result,
+            });
+        });
+
+    if let Err(error) = spawn_result {
+        tracing::error!(error = %error, "failed to spawn chat search background thread");
+        let _ = tx_fallback.send(BackgroundTaskResult::ChatsSearched {
+            query: fallback_query,
+            on_server: true,
+            result: Err(BackgroundError::new("THREAD_SPAWN_FAILED")),
+        });
+    }
+}
+
+pub(super) fn dispatch_create_chat<S: ChatCreator + Send + Sync + 'static>(
+    source: &Arc<S>,
+    tx: &Sender<BackgroundTaskResult>,
+    request: NewChatRequest,
+) {
+    let source = Arc::clone(source);
+    let tx = tx.clone();
+    let tx_fallback = tx.clone();
+    let fallback_title = request.title.clone();
+
+    let spawn_result = std::thread::Builder::new()
+        .name("rtg-bg-create-chat".into())
+        .spawn(move || {
+            tracing::debug!(
+                kind = ?request.kind,
+                members = request.member_ids.len(),
+                "background: creating chat"
+            );
+            let result = source.create_chat(&request).map_err(|e| match e {
+                NewChatError::Rejected => BackgroundError::new("CHAT_CREATION_REJECTED"),
+                NewChatError::Unavailable => BackgroundError::new("CHAT_CREATION_UNAVAILABLE"),
+            });
+
+            let _ = tx.send(BackgroundTaskResult::ChatCreated {
+                title: request.title,
+                result,
+            });
+        });
+
+    if let Err(error) = spawn_result {
+        tracing::error!(error = %error, "failed to spawn create chat background thread");
+        let _ = tx_fallback.send(BackgroundTaskResult::ChatCreated {
+            title: fallback_title,
+            result: Err(BackgroundError::new("THREAD_SPAWN_FAILED")),
+        });
+    }
+}
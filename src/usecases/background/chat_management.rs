@@ -0,0 +1,261 @@
+use std::{
+    sync::{mpsc::Sender, Arc},
+    time::Duration,
+};
+
+use crate::{
+    domain::{
+        chat::MuteDuration,
+        chat_folder::ChatListId,
+        chat_manage_state::ChatManageOp,
+        events::{BackgroundError, BackgroundTaskResult},
+    },
+    usecases::chat_lifecycle::{ChatListMover, ChatManager, ChatMuter, ChatPinner, ChatReadMarker},
+};
+
+use super::blocking::run_blocking;
+pub(super) fn dispatch_move_chat_to_list<L: ChatListMover + Send + Sync + 'static>(
+    lifecycle: &Arc<L>,
+    tx: &Sender<BackgroundTaskResult>,
+    chat_id: i64,
+    list: ChatListId,
+) {
+    let lifecycle = Arc::clone(lifecycle);
+    let tx = tx.clone();
+    let tx_fallback = tx.clone();
+
+    let spawn_result = std::thread::Builder::new()
+        .name("rtg-bg-move-chat".into())
+        .spawn(move || {
+            tracing::debug!(chat_id, ?list, "background: moving chat to list");
+            let result = lifecycle.add_chat_to_list(chat_id, list).map_err(|e| {
+                tracing::warn!(chat_id, ?list, error = ?e, "addChatToList failed");
+                BackgroundError::new("CHAT_MOVE_FAILED")
+            });
+            let _ = tx.send(BackgroundTaskResult::ChatMovedToList {
+                chat_id,
+                list,
+                result,
+            });
+        });
+
+    if let Err(error) = spawn_result {
+        tracing::error!(error = %error, "failed to spawn move chat background thread");
+        let _ = tx_fallback.send(BackgroundTaskResult::ChatMovedToList {
+            chat_id,
+            list,
+            result: Err(BackgroundError::new("THREAD_SPAWN_FAILED")),
+        });
+    }
+}
+
+pub(super) fn dispatch_set_chat_mute<L: ChatMuter + Send + Sync + 'static>(
+    lifecycle: &Arc<L>,
+    tx: &Sender<BackgroundTaskResult>,
+    chat_id: i64,
+    mute_for: Option<MuteDuration>,
+) {
+    let lifecycle = Arc::clone(lifecycle);
+    let tx = tx.clone();
+    let tx_fallback = tx.clone();
+    let muted = mute_for.is_some();
+
+    let spawn_result = std::thread::Builder::new()
+        .name("rtg-bg-mute-chat".into())
+        .spawn(move || {
+            tracing::debug!(chat_id, ?mute_for, "background: setting chat mute");
+            let result = lifecycle.set_chat_mute(chat_id, mute_for).map_err(|e| {
+                tracing::warn!(chat_id, error = ?e, "setChatNotificationSettings failed");
+                BackgroundError::new("CHAT_MUTE_FAILED")
+            });
+            let _ = tx.send(BackgroundTaskResult::ChatMuteChanged {
+                chat_id,
+                muted,
+                result,
+            });
+        });
+
+    if let Err(error) = spawn_result {
+        tracing::error!(error = %error, "failed to spawn mute chat background thread");
+        let _ = tx_fallback.send(BackgroundTaskResult::ChatMuteChanged {
+            chat_id,
+            muted,
+            result: Err(BackgroundError::new("THREAD_SPAWN_FAILED")),
+        });
+    }
+}
+
+pub(super) fn dispatch_set_chat_marked_as_unread<L: ChatReadMarker + Send + Sync + 'static>(
+    lifecycle: &Arc<L>,
+    tx: &Sender<BackgroundTaskResult>,
+    chat_id: i64,
+    marked: bool,
+) {
+    let lifecycle = Arc::clone(lifecycle);
+    let tx = tx.clone();
+    let tx_fallback = tx.clone();
+
+    let spawn_result = std::thread::Builder::new()
+        .name("rtg-bg-mark-unread".into())
+        .spawn(move || {
+            tracing::debug!(chat_id, marked, "background: setting chat unread mark");
+            let result = lifecycle
+                .set_chat_marked_as_unread(chat_id, marked)
+                .map_err(|e| {
+                    tracing::warn!(chat_id, error = ?e, "toggleChatIsMarkedAsUnread failed");
+                    BackgroundError::new("CHAT_MARK_UNREAD_FAILED")
+                });
+            let _ = tx.send(BackgroundTaskResult::ChatMarkedAsUnread {
+                chat_id,
+                marked,
+                result,
+            });
+        });
+
+    if let Err(error) = spawn_result {
+        tracing::error!(error = %error, "failed to spawn mark unread background thread");
+        let _ = tx_fallback.send(BackgroundTaskResult::ChatMarkedAsUnread {
+            chat_id,
+            marked,
+            result: Err(BackgroundError::new("THREAD_SPAWN_FAILED")),
+        });
+    }
+}
+
+pub(super) fn dispatch_set_chat_pinned<L: ChatPinner + Send + Sync + 'static>(
+    lifecycle: &Arc<L>,
+    tx: &Sender<BackgroundTaskResult>,
+    chat_id: i64,
+    list: ChatListId,
+    pinned: bool,
+) {
+    let lifecycle = Arc::clone(lifecycle);
+    let tx = tx.clone();
+    let tx_fallback = tx.clone();
+
+    let spawn_result = std::thread::Builder::new()
+        .name("rtg-bg-pin-chat".into())
+        .spawn(move || {
+            tracing::debug!(chat_id, ?list, pinned, "background: toggling chat pin");
+            let result = lifecycle
+                .set_chat_pinned(chat_id, list, pinned)
+                .map_err(|e| {
+                    tracing::warn!(chat_id, error = ?e, "toggleChatIsPinned failed");
+                    BackgroundError::new("CHAT_PIN_FAILED")
+                });
+            let _ = tx.send(BackgroundTaskResult::ChatPinToggled {
+                chat_id,
+                pinned,
+                result,
+            });
+        });
+
+    if let Err(error) = spawn_result {
+        tracing::error!(error = %error, "failed to spawn pin chat background thread");
+        let _ = tx_fallback.send(BackgroundTaskResult::ChatPinToggled {
+            chat_id,
+            pinned,
+            result: Err(BackgroundError::new("THREAD_SPAWN_FAILED")),
+        });
+    }
+}
+
+pub(super) fn dispatch_set_pinned_chats<L: ChatPinner + Send + Sync + 'static>(
+    lifecycle: &Arc<L>,
+    tx: &Sender<BackgroundTaskResult>,
+    list: ChatListId,
+    chat_ids: Vec<i64>,
+) {
+    let lifecycle = Arc::clone(lifecycle);
+    let tx = tx.clone();
+    let tx_fallback = tx.clone();
+
+    let spawn_result = std::thread::Builder::new()
+        .name("rtg-bg-reorder-pins".into())
+        .spawn(move || {
+            tracing::debug!(
+                ?list,
+                count = chat_ids.len(),
+                "background: reordering pinned chats"
+            );
+            let result = lifecycle.set_pinned_chats(list, chat_ids).map_err(|e| {
+                tracing::warn!(?list, error = ?e, "setPinnedChats failed");
+                BackgroundError::new("PINNED_CHATS_REORDER_FAILED")
+            });
+            let _ = tx.send(BackgroundTaskResult::PinnedChatsReordered { list, result });
+        });
+
+    if let Err(error) = spawn_result {
+        tracing::error!(error = %error, "failed to spawn reorder pins background thread");
+        let _ = tx_fallback.send(BackgroundTaskResult::PinnedChatsReordered {
+            list,
+            result: Err(BackgroundError::new("THREAD_SPAWN_FAILED")),
+        });
+    }
+}
+
+pub(super) fn dispatch_manage_chat<L: ChatManager + Send + Sync + 'static>(
+    lifecycle: &Arc<L>,
+    tx: &Sender<BackgroundTaskResult>,
+    chat_id: i64,
+    op: ChatManageOp,
+) {
+    let lifecycle = Arc::clone(lifecycle);
+    let tx = tx.clone();
+    let tx_fallback = tx.clone();
+
+    let spawn_result = std::thread::Builder::new()
+        .name("rtg-bg-manage-chat".into())
+        .spawn(move || {
+            let result = manage_chat(lifecycle.as_ref(), chat_id, op);
+            let _ = tx.send(BackgroundTaskResult::ChatManaged {
+                chat_id,
+                op,
+                result,
+            });
+        });
+
+    if let Err(error) = spawn_result {
+        tracing::error!(error = %error, "failed to spawn manage chat background thread");
+        let _ = tx_fallback.send(BackgroundTaskResult::ChatManaged {
+            chat_id,
+            op,
+            result: Err(BackgroundError::new("THREAD_SPAWN_FAILED")),
+        });
+    }
+}
+
+/// Manages a chat on a background thread and waits at most `timeout` for
+/// it to finish.
+pub(super) fn manage_chat_blocking<L: ChatManager + Send + Sync + 'static>(
+    lifecycle: &Arc<L>,
+    chat_id: i64,
+    op: ChatManageOp,
+    timeout: Duration,
+) -> Result<(), BackgroundError> {
+    let lifecycle = Arc::clone(lifecycle);
+    run_blocking("rtg-bg-manage-chat", timeout, move || {
+        manage_chat(lifecycle.as_ref(), chat_id, op)
+    })
+}
+
+fn manage_chat<L: ChatManager>(
+    lifecycle: &L,
+    chat_id: i64,
+    op: ChatManageOp,
+) -> Result<(), BackgroundError> {
+    tracing::debug!(chat_id, ?op, "background: managing chat");
+    match op {
+        ChatManageOp::Leave => lifecycle.leave_chat(chat_id),
+        ChatManageOp::Delete => lifecycle.delete_chat(chat_id),
+        ChatManageOp::DeleteForMe => lifecycle.delete_chat_history(chat_id, true, false),
+        ChatManageOp::ClearHistory { for_everyone } => {
+            lifecycle.delete_chat_history(chat_id, false, for_everyone)
+        }
+        ChatManageOp::SetBlocked(blocked) => lifecycle.set_chat_blocked(chat_id, blocked),
+    }
+    .map_err(|e| {
+        tracing::warn!(chat_id, ?op, error = ?e, "chat management request failed");
+        BackgroundError::new("CHAT_MANAGE_FAILED")
+    })
+}
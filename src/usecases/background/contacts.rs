@@ -0,0 +1,125 @@
+use std::sync::{mpsc::Sender, Arc};
+
+use crate::{
+    domain::events::{BackgroundError, BackgroundTaskResult},
+    usecases::contacts::{ContactSource, ContactsError},
+};
+
+pub(super) fn dispatch_load_contacts<S: ContactSource + Send + Sync + 'static>(
+    source: &Arc<S>,
+    tx: &Sender<BackgroundTaskResult>,
+) {
+    let source = Arc::clone(source);
+    let tx = tx.clone();
+    let tx_fallback = tx.clone();
+
+    let spawn_result = std::thread::Builder::new()
+        .name("rtg-bg-contacts".into())
+        .spawn(move || {
+            tracing::debug!("background: loading contacts");
+            let result = source.list_contacts().map_err(|e| map_contacts_error(&e));
+
+            let _ = tx.send(BackgroundTaskResult::ContactsLoaded { result });
+        });
+
+    if let Err(error) = spawn_result {
+        tracing::error!(error = %error, "failed to spawn contacts background thread");
+        let _ = tx_fallback.send(BackgroundTaskResult::ContactsLoaded {
+            result: Err(BackgroundError::new("THREAD_SPAWN_FAILED")),
+        });
+    }
+}
+
+pub(super) fn dispatch_add_contact<S: ContactSource + Send + Sync + 'static>(
+    source: &Arc<S>,
+    tx: &Sender<BackgroundTaskResult>,
+    chat_id: i64,
+) {
+    let source = Arc::clone(source);
+    let tx = tx.clone();
+    let tx_fallback = tx.clone();
+
+    let spawn_result = std::thread::Builder::new()
+        .name("rtg-bg-add-contact".into())
+        .spawn(move || {
+            tracing::debug!(chat_id, "background: adding contact");
+            let result = source
+                .add_contact(chat_id)
+                .map_err(|e| map_contacts_error(&e));
+
+            let _ = tx.send(BackgroundTaskResult::ContactAdded { chat_id, result });
+        });
+
+    if let Err(error) = spawn_result {
+        tracing::error!(error = %error, "failed to spawn add contact background thread");
+        let _ = tx_fallback.send(BackgroundTaskResult::ContactAdded {
+            chat_id,
+            result: Err(BackgroundError::new("THREAD_SPAWN_FAILED")),
+        });
+    }
+}
+
+pub(super) fn dispatch_remove_contact<S: ContactSource + Send + Sync + 'static>(
+    source: &Arc<S>,
+    tx: &Sender<BackgroundTaskResult>,
+    user_id: i64,
+) {
+    let source = Arc::clone(source);
+    let tx = tx.clone();
+    let tx_fallback = tx.clone();
+
+    let spawn_result = std::thread::Builder::new()
+        .name("rtg-bg-remove-contact".into())
+        .spawn(move || {
+            tracing::debug!(user_id, "background: removing contact");
+            let result = source
+                .remove_contact(user_id)
+                .map_err(|e| map_contacts_error(&e));
+
+            let _ = tx.send(BackgroundTaskResult::ContactRemoved { user_id, result });
+        });
+
+    if let Err(error) = spawn_result {
+        tracing::error!(error = %error, "failed to spawn remove contact background thread");
+        let _ = tx_fallback.send(BackgroundTaskResult::ContactRemoved {
+            user_id,
+            result: Err(BackgroundError::new("THREAD_SPAWN_FAILED")),
+        });
+    }
+}
+
+pub(super) fn dispatch_open_contact_chat<S: ContactSource + Send + Sync + 'static>(
+    source: &Arc<S>,
+    tx: &Sender<BackgroundTaskResult>,
+    user_id: i64,
+) {
+    let source = Arc::clone(source);
+    let tx = tx.clone();
+    let tx_fallback = tx.clone();
+
+    let spawn_result = std::thread::Builder::new()
+        .name("rtg-bg-contact-chat".into())
+        .spawn(move || {
+            tracing::debug!(user_id, "background: opening chat with contact");
+            let result = source
+                .contact_chat(user_id)
+                .map_err(|e| map_contacts_error(&e));
+
+            let _ = tx.send(BackgroundTaskResult::ContactChatLoaded { user_id, result });
+        });
+
+    if let Err(error) = spawn_result {
+        tracing::error!(error = %error, "failed to spawn contact chat background thread");
+        let _ = tx_fallback.send(BackgroundTaskResult::ContactChatLoaded {
+            user_id,
+            result: Err(BackgroundError::new("THREAD_SPAWN_FAILED")),
+        });
+    }
+}
+
+fn map_contacts_error(error: &ContactsError) -> BackgroundError {
+    match error {
+        ContactsError::NotFound => BackgroundError::new("CONTACT_NOT_FOUND"),
+        ContactsError::Unavailable => BackgroundError::new("CONTACTS_UNAVAILABLE"),
+    }
+}
@@ -1,22 +1,26 @@
 use std::sync::{mpsc::Sender, Arc};
 
 use crate::{
-    domain::events::{BackgroundError, BackgroundTaskResult},
+    domain::{
+        chat_action::ChatAction,
+        chat_folder::ChatListId,
+        events::{BackgroundError, BackgroundTaskResult},
+    },
     usecases::{
-        chat_lifecycle::{ChatLifecycle, ChatReadMarker, FileDownloader, MessageDeleter},
+        bot_commands::{BotCommandsError, BotCommandsSource},
+        chat_lifecycle::{ChatActionSender, ChatLifecycle, ChatReadMarker, FileDownloader},
+        chat_members::{ChatMemberSource, ChatMembersError, MEMBER_SEARCH_LIMIT},
         chat_subtitle::{ChatInfoQuery, ChatSubtitleQuery, ChatSubtitleSource},
         list_chats::{list_chats, ListChatsQuery, ListChatsSource},
         list_forum_topics::{list_forum_topics, ForumTopicsSource, ListForumTopicsQuery},
-        message_info::{MessageInfoQuery, MessageInfoSource},
-        message_reactions::{AddReactionQuery, AvailableReactionsQuery, ReactionSource},
     },
 };
 
 use super::error_mapping::{map_list_chats_error, map_list_forum_topics_error};
-
 pub(super) fn dispatch_chat_list<C: ListChatsSource + Send + Sync + 'static>(
     source: &Arc<C>,
     tx: &Sender<BackgroundTaskResult>,
+    list: ChatListId,
     force: bool,
     limit: usize,
 ) {
@@ -27,12 +31,13 @@ pub(super) fn dispatch_chat_list<C: ListChatsSource + Send + Sync + 'static>(
     let spawn_result = std::thread::Builder::new()
         .name("rtg-bg-chat-list".into())
         .spawn(move || {
-            tracing::debug!(force, limit, "background: fetching chat list");
-            let query = ListChatsQuery { force, limit };
+            tracing::debug!(?list, force, limit, "background: fetching chat list");
+            let query = ListChatsQuery { list, force, limit };
             let result = list_chats(source.as_ref(), query);
             match result {
                 Ok(output) => {
                     let _ = tx.send(BackgroundTaskResult::ChatListLoaded {
+                        list,
                         result: Ok(output.chats),
                         all_loaded: output.all_loaded,
                     });
@@ -40,6 +45,7 @@ pub(super) fn dispatch_chat_list<C: ListChatsSource + Send + Sync + 'static>(
                 Err(error) => {
                     tracing::warn!(error = ?error, "background: chat list fetch failed");
                     let _ = tx.send(BackgroundTaskResult::ChatListLoaded {
+                        list,
                         result: Err(BackgroundError::new(map_list_chats_error(&error))),
                         all_loaded: false,
                     });
@@ -50,6 +56,7 @@ pub(super) fn dispatch_chat_list<C: ListChatsSource + Send + Sync + 'static>(
     if let Err(error) = spawn_result {
         tracing::error!(error = %error, "failed to spawn chat list background thread");
         let _ = tx_fallback.send(BackgroundTaskResult::ChatListLoaded {
+            list,
             result: Err(BackgroundError::new("THREAD_SPAWN_FAILED")),
             all_loaded: false,
         });
@@ -209,6 +216,32 @@ pub(super) fn dispatch_mark_as_read<L: ChatReadMarker + Send + Sync + 'static>(
     }
 }
 
+pub(super) fn dispatch_send_chat_action<L: ChatActionSender + Send + Sync + 'static>(
+    lifecycle: &Arc<L>,
+    chat_id: i64,
+    topic_id: Option<i32>,
+    action: ChatAction,
+) {
+    let lifecycle = Arc::clone(lifecycle);
+
+    if let Err(error) = std::thread::Builder::new()
+        .name("rtg-bg-chat-action".into())
+        .spawn(move || {
+            tracing::debug!(
+                chat_id,
+                ?topic_id,
+                ?action,
+                "background: sending chat action"
+            );
+            if let Err(e) = lifecycle.send_chat_action(chat_id, topic_id, action) {
+                tracing::debug!(chat_id, error = ?e, "background: sendChatAction failed");
+            }
+        })
+    {
+        tracing::error!(error = %error, "failed to spawn chat action background thread");
+    }
+}
+
 pub(super) fn dispatch_mark_chat_as_read<
     L: ChatLifecycle + ChatReadMarker + Send + Sync + 'static,
 >(
@@ -238,30 +271,6 @@ pub(super) fn dispatch_mark_chat_as_read<
     }
 }
 
-pub(super) fn dispatch_delete_message<L: MessageDeleter + Send + Sync + 'static>(
-    lifecycle: &Arc<L>,
-    chat_id: i64,
-    message_id: i64,
-) {
-    let lifecycle = Arc::clone(lifecycle);
-
-    if let Err(error) = std::thread::Builder::new()
-        .name("rtg-bg-delete-msg".into())
-        .spawn(move || {
-            tracing::debug!(chat_id, message_id, "background: deleting message");
-            let ids = vec![message_id];
-            if let Err(e) = lifecycle.delete_messages(chat_id, ids.clone(), true) {
-                tracing::debug!(chat_id, message_id, error = ?e, "revoke delete failed, trying self-only");
-                if let Err(e2) = lifecycle.delete_messages(chat_id, ids, false) {
-                    tracing::warn!(chat_id, message_id, error = ?e2, "self-only delete also failed");
-                }
-            }
-        })
-    {
-        tracing::error!(error = %error, "failed to spawn delete message background thread");
-    }
-}
-
 pub(super) fn dispatch_chat_subtitle<S: ChatSubtitleSource + Send + Sync + 'static>(
     source: &Arc<S>,
     tx: &Sender<BackgroundTaskResult>,
@@ -335,130 +344,71 @@ pub(super) fn dispatch_download_file<L: FileDownloader + Send + Sync + 'static>(
     }
 }
 
-pub(super) fn dispatch_message_info<S: MessageInfoSource + Send + Sync + 'static>(
+pub(super) fn dispatch_load_bot_commands<S: BotCommandsSource + Send + Sync + 'static>(
     source: &Arc<S>,
     tx: &Sender<BackgroundTaskResult>,
-    query: MessageInfoQuery,
+    chat_id: i64,
 ) {
     let source = Arc::clone(source);
     let tx = tx.clone();
     let tx_fallback = tx.clone();
-    let chat_id = query.chat_id;
-    let message_id = query.message_id;
 
     let spawn_result = std::thread::Builder::new()
-        .name("rtg-bg-msg-info".into())
+        .name("rtg-bg-bot-commands".into())
         .spawn(move || {
-            tracing::debug!(chat_id, message_id, "background: resolving message info");
-            let result = source
-                .resolve_message_info(&query)
-                .map_err(|_| BackgroundError::new("MESSAGE_INFO_UNAVAILABLE"));
-
-            let _ = tx.send(BackgroundTaskResult::MessageInfoLoaded {
-                chat_id,
-                message_id,
-                result,
+            tracing::debug!(chat_id, "background: loading bot commands");
+            let result = source.get_bot_commands(chat_id).map_err(|e| match e {
+                BotCommandsError::Unavailable => BackgroundError::new("BOT_COMMANDS_UNAVAILABLE"),
             });
+
+            let _ = tx.send(BackgroundTaskResult::BotCommandsLoaded { chat_id, result });
         });
 
     if let Err(error) = spawn_result {
-        tracing::error!(error = %error, "failed to spawn message info background thread");
-        let _ = tx_fallback.send(BackgroundTaskResult::MessageInfoLoaded {
+        tracing::error!(error = %error, "failed to spawn bot commands background thread");
+        let _ = tx_fallback.send(BackgroundTaskResult::BotCommandsLoaded {
             chat_id,
-            message_id,
             result: Err(BackgroundError::new("THREAD_SPAWN_FAILED")),
         });
     }
 }
 
-pub(super) fn dispatch_available_reactions<S: ReactionSource + Send + Sync + 'static>(
+pub(super) fn dispatch_search_chat_members<S: ChatMemberSource + Send + Sync + 'static>(
     source: &Arc<S>,
     tx: &Sender<BackgroundTaskResult>,
-    query: AvailableReactionsQuery,
+    chat_id: i64,
+    query: String,
 ) {
     let source = Arc::clone(source);
     let tx = tx.clone();
     let tx_fallback = tx.clone();
-    let chat_id = query.chat_id;
-    let message_id = query.message_id;
+    let fallback_query = query.clone();
 
     let spawn_result = std::thread::Builder::new()
-        .name("rtg-bg-reactions".into())
+        .name("rtg-bg-chat-members".into())
         .spawn(move || {
-            tracing::debug!(
-                chat_id,
-                message_id,
-                "background: loading available reactions"
-            );
+            tracing::debug!(chat_id, "background: searching chat members");
             let result = source
-                .get_available_reactions(&query)
-                .map_err(|_| BackgroundError::new("REACTIONS_UNAVAILABLE"));
+                .search_chat_members(chat_id, &query, MEMBER_SEARCH_LIMIT)
+                .map_err(|e| match e {
+                    ChatMembersError::Unavailable => {
+                        BackgroundError::new("CHAT_MEMBERS_UNAVAILABLE")
+                    }
+                });
 
-            let _ = tx.send(BackgroundTaskResult::AvailableReactionsLoaded {
+            let _ = tx.send(BackgroundTaskResult::ChatMembersLoaded {
                 chat_id,
-                message_id,
+                query,
                 result,
             });
         });
 
     if let Err(error) = spawn_result {
-        tracing::error!(error = %error, "failed to spawn reactions background thread");
-        let _ = tx_fallback.send(BackgroundTaskResult::AvailableReactionsLoaded {
+        tracing::error!(error = %error, "failed to spawn chat members background thread");
+        let _ = tx_fallback.send(BackgroundTaskResult::ChatMembersLoaded {
             chat_id,
-            message_id,
+            query: fallback_query,
             result: Err(BackgroundError::new("THREAD_SPAWN_FAILED")),
         });
     }
 }
-
-pub(super) fn dispatch_add_reaction<S: ReactionSource + Send + Sync + 'static>(
-    source: &Arc<S>,
-    chat_id: i64,
-    message_id: i64,
-    emoji: String,
-) {
-    let source = Arc::clone(source);
-
-    if let Err(error) = std::thread::Builder::new()
-        .name("rtg-bg-add-reaction".into())
-        .spawn(move || {
-            tracing::debug!(chat_id, message_id, ?emoji, "background: adding reaction");
-            let query = AddReactionQuery {
-                chat_id,
-                message_id,
-                emoji,
-            };
-            if let Err(e) = source.add_reaction(&query) {
-                tracing::warn!(chat_id, message_id, error = ?e, "background: add reaction failed");
-            }
-        })
-    {
-        tracing::error!(error = %error, "failed to spawn add reaction background thread");
-    }
-}
-
-pub(super) fn dispatch_remove_reaction<S: ReactionSource + Send + Sync + 'static>(
-    source: &Arc<S>,
-    chat_id: i64,
-    message_id: i64,
-    emoji: String,
-) {
-    let source = Arc::clone(source);
-
-    if let Err(error) = std::thread::Builder::new()
-        .name("rtg-bg-remove-reaction".into())
-        .spawn(move || {
-            tracing::debug!(chat_id, message_id, ?emoji, "background: removing reaction");
-            let query = AddReactionQuery {
-                chat_id,
-                message_id,
-                emoji,
-            };
-            if let Err(e) = source.remove_reaction(&query) {
-                tracing::warn!(chat_id, message_id, error = ?e, "background: remove reaction failed");
-            }
-        })
-    {
-        tracing::error!(error = %error, "failed to spawn remove reaction background thread");
-    }
-}
@@ -0,0 +1,112 @@
+use std::sync::{mpsc::Sender, Arc};
+
+use crate::{
+    domain::events::{BackgroundError, BackgroundTaskResult},
+    usecases::links::{LinkError, LinkSource, LINKED_MESSAGE_WINDOW},
+};
+
+pub(super) fn dispatch_message_link<S: LinkSource + Send + Sync + 'static>(
+    source: &Arc<S>,
+    tx: &Sender<BackgroundTaskResult>,
+    chat_id: i64,
+    message_id: i64,
+) {
+    let source = Arc::clone(source);
+    let tx = tx.clone();
+    let tx_fallback = tx.clone();
+
+    let spawn_result = std::thread::Builder::new()
+        .name("rtg-bg-message-link".into())
+        .spawn(move || {
+            tracing::debug!(chat_id, message_id, "background: loading message link");
+            let result = source
+                .get_message_link(chat_id, message_id)
+                .map_err(|e| map_link_error(&e));
+
+            let _ = tx.send(BackgroundTaskResult::MessageLinkLoaded {
+                chat_id,
+                message_id,
+                result,
+            });
+        });
+
+    if let Err(error) = spawn_result {
+        tracing::error!(error = %error, "failed to spawn message link background thread");
+        let _ = tx_fallback.send(BackgroundTaskResult::MessageLinkLoaded {
+            chat_id,
+            message_id,
+            result: Err(BackgroundError::new("THREAD_SPAWN_FAILED")),
+        });
+    }
+}
+
+pub(super) fn dispatch_resolve_link<S: LinkSource + Send + Sync + 'static>(
+    source: &Arc<S>,
+    tx: &Sender<BackgroundTaskResult>,
+    url: String,
+) {
+    let source = Arc::clone(source);
+    let tx = tx.clone();
+    let tx_fallback = tx.clone();
+    let fallback_url = url.clone();
+
+    let spawn_result = std::thread::Builder::new()
+        .name("rtg-bg-resolve-link".into())
+        .spawn(move || {
+            tracing::debug!(%url, "background: resolving Telegram link");
+            let result = source
+                .resolve_link(&url, LINKED_MESSAGE_WINDOW)
+                .map_err(|e| map_link_error(&e));
+
+            let _ = tx.send(BackgroundTaskResult::LinkResolved { url, result });
+        });
+
+    if let Err(error) = spawn_result {
+        tracing::error!(error = %error, "failed to spawn link resolution background thread");
+        let _ = tx_fallback.send(BackgroundTaskResult::LinkResolved {
+            url: fallback_url,
+            result: Err(BackgroundError::new("THREAD_SPAWN_FAILED")),
+        });
+    }
+}
+
+pub(super) fn dispatch_join_chat<S: LinkSource + Send + Sync + 'static>(
+    source: &Arc<S>,
+    tx: &Sender<BackgroundTaskResult>,
+    invite_link: String,
+) {
+    let source = Arc::clone(source);
+    let tx = tx.clone();
+    let tx_fallback = tx.clone();
+    let fallback_link = invite_link.clone();
+
+    let spawn_result = std::thread::Builder::new()
+        .name("rtg-bg-join-chat".into())
+        .spawn(move || {
+            tracing::debug!("background: joining chat by invite link");
+            let result = source
+                .join_chat_by_invite_link(&invite_link)
+                .map_err(|e| map_link_error(&e));
+
+            let _ = tx.send(BackgroundTaskResult::ChatJoined {
+                invite_link,
+                result,
+            });
+        });
+
+    if let Err(error) = spawn_result {
+        tracing::error!(error = %error, "failed to spawn join chat background thread");
+        let _ = tx_fallback.send(BackgroundTaskResult::ChatJoined {
+            invite_link: fallback_link,
+            result: Err(BackgroundError::new("THREAD_SPAWN_FAILED")),
+        });
+    }
+}
+
+fn map_link_error(error: &LinkError) -> BackgroundError {
+    match error {
+        LinkError::NotFound => BackgroundError::new("LINK_NOT_FOUND"),
+        LinkError::JoinRequestSent => BackgroundError::new("JOIN_REQUEST_SENT"),
+        LinkError::Unavailable => BackgroundError::new("LINK_UNAVAILABLE"),
+    }
+}
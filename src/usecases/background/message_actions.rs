@@ -0,0 +1,342 @@
+use std::{
+    sync::{mpsc::Sender, Arc},
+    time::Duration,
+};
+
+use crate::{
+    domain::{
+        delete_confirm_state::DeleteScope,
+        events::{BackgroundError, BackgroundTaskResult},
+    },
+    usecases::{
+        chat_lifecycle::{MessageDeleter, MessageForwarder},
+        inline_keyboard::{CallbackQuery, CallbackQueryError, CallbackQuerySource},
+        message_info::{MessageInfoQuery, MessageInfoSource},
+        message_reactions::{AddReactionQuery, AvailableReactionsQuery, ReactionSource},
+    },
+};
+
+use super::blocking::run_blocking;
+pub(super) fn dispatch_delete_messages<L: MessageDeleter + Send + Sync + 'static>(
+    lifecycle: &Arc<L>,
+    tx: &Sender<BackgroundTaskResult>,
+    chat_id: i64,
+    message_ids: Vec<i64>,
+    scope: DeleteScope,
+) {
+    let lifecycle = Arc::clone(lifecycle);
+    let tx = tx.clone();
+    let tx_fallback = tx.clone();
+    let fallback_ids = message_ids.clone();
+
+    let spawn_result = std::thread::Builder::new()
+        .name("rtg-bg-delete-msg".into())
+        .spawn(move || {
+            let result = delete_messages(lifecycle.as_ref(), chat_id, message_ids.clone(), scope);
+            let _ = tx.send(BackgroundTaskResult::MessagesDeleted {
+                chat_id,
+                message_ids,
+                result,
+            });
+        });
+
+    if let Err(error) = spawn_result {
+        tracing::error!(error = %error, "failed to spawn delete message background thread");
+        let _ = tx_fallback.send(BackgroundTaskResult::MessagesDeleted {
+            chat_id,
+            message_ids: fallback_ids,
+            result: Err(BackgroundError::new("THREAD_SPAWN_FAILED")),
+        });
+    }
+}
+
+/// Deletes messages on a background thread and waits at most `timeout`
+/// for it to finish.
+pub(super) fn delete_messages_blocking<L: MessageDeleter + Send + Sync + 'static>(
+    lifecycle: &Arc<L>,
+    chat_id: i64,
+    message_ids: Vec<i64>,
+    scope: DeleteScope,
+    timeout: Duration,
+) -> Result<(), BackgroundError> {
+    let lifecycle = Arc::clone(lifecycle);
+    run_blocking("rtg-bg-delete-msg", timeout, move || {
+        delete_messages(lifecycle.as_ref(), chat_id, message_ids, scope)
+    })
+}
+
+fn delete_messages<L: MessageDeleter>(
+    lifecycle: &L,
+    chat_id: i64,
+    message_ids: Vec<i64>,
+    scope: DeleteScope,
+) -> Result<(), BackgroundError> {
+    let count = message_ids.len();
+    tracing::debug!(chat_id, count, ?scope, "background: deleting messages");
+    let result = match scope {
+        DeleteScope::ForMe => lifecycle.delete_messages(chat_id, message_ids, false),
+        DeleteScope::ForEveryone => lifecycle.delete_messages(chat_id, message_ids, true),
+        DeleteScope::Auto => lifecycle
+            .delete_messages(chat_id, message_ids.clone(), true)
+            .or_else(|e| {
+                tracing::debug!(chat_id, count, error = ?e, "revoke delete failed, trying self-only");
+                lifecycle.delete_messages(chat_id, message_ids, false)
+            }),
+    };
+    result.map_err(|e| {
+        tracing::warn!(chat_id, count, ?scope, error = ?e, "delete messages failed");
+        BackgroundError::new("DELETE_MESSAGES_FAILED")
+    })
+}
+
+pub(super) fn dispatch_delete_permissions<L: MessageDeleter + Send + Sync + 'static>(
+    lifecycle: &Arc<L>,
+    tx: &Sender<BackgroundTaskResult>,
+    chat_id: i64,
+    message_ids: Vec<i64>,
+) {
+    let lifecycle = Arc::clone(lifecycle);
+    let tx = tx.clone();
+    let tx_fallback = tx.clone();
+    let fallback_ids = message_ids.clone();
+
+    let spawn_result = std::thread::Builder::new()
+        .name("rtg-bg-delete-perms".into())
+        .spawn(move || {
+            tracing::debug!(
+                chat_id,
+                count = message_ids.len(),
+                "background: resolving delete permissions"
+            );
+            let result = lifecycle
+                .delete_permissions(chat_id, &message_ids)
+                .map_err(|_| BackgroundError::new("DELETE_PERMISSIONS_UNAVAILABLE"));
+
+            let _ = tx.send(BackgroundTaskResult::DeletePermissionsLoaded {
+                chat_id,
+                message_ids,
+                result,
+            });
+        });
+
+    if let Err(error) = spawn_result {
+        tracing::error!(error = %error, "failed to spawn delete permissions background thread");
+        let _ = tx_fallback.send(BackgroundTaskResult::DeletePermissionsLoaded {
+            chat_id,
+            message_ids: fallback_ids,
+            result: Err(BackgroundError::new("THREAD_SPAWN_FAILED")),
+        });
+    }
+}
+
+pub(super) fn dispatch_forward_messages<L: MessageForwarder + Send + Sync + 'static>(
+    lifecycle: &Arc<L>,
+    tx: &Sender<BackgroundTaskResult>,
+    to_chat_id: i64,
+    from_chat_id: i64,
+    message_ids: Vec<i64>,
+) {
+    let lifecycle = Arc::clone(lifecycle);
+    let tx = tx.clone();
+    let tx_fallback = tx.clone();
+    let count = message_ids.len();
+
+    let spawn_result = std::thread::Builder::new()
+        .name("rtg-bg-forward-msgs".into())
+        .spawn(move || {
+            tracing::debug!(
+                to_chat_id,
+                from_chat_id,
+                count,
+                "background: forwarding messages"
+            );
+            let result = lifecycle
+                .forward_messages(to_chat_id, from_chat_id, message_ids)
+                .map_err(|e| {
+                    tracing::warn!(to_chat_id, from_chat_id, count, error = ?e, "forward failed");
+                    BackgroundError::new("FORWARD_MESSAGES_FAILED")
+                });
+
+            let _ = tx.send(BackgroundTaskResult::MessagesForwarded {
+                to_chat_id,
+                count,
+                result,
+            });
+        });
+
+    if let Err(error) = spawn_result {
+        tracing::error!(error = %error, "failed to spawn forward messages background thread");
+        let _ = tx_fallback.send(BackgroundTaskResult::MessagesForwarded {
+            to_chat_id,
+            count,
+            result: Err(BackgroundError::new("THREAD_SPAWN_FAILED")),
+        });
+    }
+}
+
+pub(super) fn dispatch_message_info<S: MessageInfoSource + Send + Sync + 'static>(
+    source: &Arc<S>,
+    tx: &Sender<BackgroundTaskResult>,
+    query: MessageInfoQuery,
+) {
+    let source = Arc::clone(source);
+    let tx = tx.clone();
+    let tx_fallback = tx.clone();
+    let chat_id = query.chat_id;
+    let message_id = query.message_id;
+
+    let spawn_result = std::thread::Builder::new()
+        .name("rtg-bg-msg-info".into())
+        .spawn(move || {
+            tracing::debug!(chat_id, message_id, "background: resolving message info");
+            let result = source
+                .resolve_message_info(&query)
+                .map_err(|_| BackgroundError::new("MESSAGE_INFO_UNAVAILABLE"));
+
+            let _ = tx.send(BackgroundTaskResult::MessageInfoLoaded {
+                chat_id,
+                message_id,
+                result,
+            });
+        });
+
+    if let Err(error) = spawn_result {
+        tracing::error!(error = %error, "failed to spawn message info background thread");
+        let _ = tx_fallback.send(BackgroundTaskResult::MessageInfoLoaded {
+            chat_id,
+            message_id,
+            result: Err(BackgroundError::new("THREAD_SPAWN_FAILED")),
+        });
+    }
+}
+
+pub(super) fn dispatch_available_reactions<S: ReactionSource + Send + Sync + 'static>(
+    source: &Arc<S>,
+    tx: &Sender<BackgroundTaskResult>,
+    query: AvailableReactionsQuery,
+) {
+    let source = Arc::clone(source);
+    let tx = tx.clone();
+    let tx_fallback = tx.clone();
+    let chat_id = query.chat_id;
+    let message_id = query.message_id;
+
+    let spawn_result = std::thread::Builder::new()
+        .name("rtg-bg-reactions".into())
+        .spawn(move || {
+            tracing::debug!(
+                chat_id,
+                message_id,
+                "background: loading available reactions"
+            );
+            let result = source
+                .get_available_reactions(&query)
+                .map_err(|_| BackgroundError::new("REACTIONS_UNAVAILABLE"));
+
+            let _ = tx.send(BackgroundTaskResult::AvailableReactionsLoaded {
+                chat_id,
+                message_id,
+                result,
+            });
+        });
+
+    if let Err(error) = spawn_result {
+        tracing::error!(error = %error, "failed to spawn reactions background thread");
+        let _ = tx_fallback.send(BackgroundTaskResult::AvailableReactionsLoaded {
+            chat_id,
+            message_id,
+            result: Err(BackgroundError::new("THREAD_SPAWN_FAILED")),
+        });
+    }
+}
+
+pub(super) fn dispatch_callback_query<S: CallbackQuerySource + Send + Sync + 'static>(
+    source: &Arc<S>,
+    tx: &Sender<BackgroundTaskResult>,
+    query: CallbackQuery,
+) {
+    let source = Arc::clone(source);
+    let tx = tx.clone();
+    let tx_fallback = tx.clone();
+    let chat_id = query.chat_id;
+    let message_id = query.message_id;
+
+    let spawn_result = std::thread::Builder::new()
+        .name("rtg-bg-callback-query".into())
+        .spawn(move || {
+            tracing::debug!(chat_id, message_id, "background: pressing callback button");
+            let result = source.get_callback_answer(&query).map_err(|e| match e {
+                CallbackQueryError::Timeout => BackgroundError::new("CALLBACK_QUERY_TIMEOUT"),
+                CallbackQueryError::Unavailable => {
+                    BackgroundError::new("CALLBACK_QUERY_UNAVAILABLE")
+                }
+            });
+
+            let _ = tx.send(BackgroundTaskResult::CallbackAnswerLoaded {
+                chat_id,
+                message_id,
+                result,
+            });
+        });
+
+    if let Err(error) = spawn_result {
+        tracing::error!(error = %error, "failed to spawn callback query background thread");
+        let _ = tx_fallback.send(BackgroundTaskResult::CallbackAnswerLoaded {
+            chat_id,
+            message_id,
+            result: Err(BackgroundError::new("THREAD_SPAWN_FAILED")),
+        });
+    }
+}
+
+pub(super) fn dispatch_add_reaction<S: ReactionSource + Send + Sync + 'static>(
+    source: &Arc<S>,
+    chat_id: i64,
+    message_id: i64,
+    emoji: String,
+) {
+    let source = Arc::clone(source);
+
+    if let Err(error) = std::thread::Builder::new()
+        .name("rtg-bg-add-reaction".into())
+        .spawn(move || {
+            tracing::debug!(chat_id, message_id, ?emoji, "background: adding reaction");
+            let query = AddReactionQuery {
+                chat_id,
+                message_id,
+                emoji,
+            };
+            if let Err(e) = source.add_reaction(&query) {
+                tracing::warn!(chat_id, message_id, error = ?e, "background: add reaction failed");
+            }
+        })
+    {
+        tracing::error!(error = %error, "failed to spawn add reaction background thread");
+    }
+}
+
+pub(super) fn dispatch_remove_reaction<S: ReactionSource + Send + Sync + 'static>(
+    source: &Arc<S>,
+    chat_id: i64,
+    message_id: i64,
+    emoji: String,
+) {
+    let source = Arc::clone(source);
+
+    if let Err(error) = std::thread::Builder::new()
+        .name("rtg-bg-remove-reaction".into())
+        .spawn(move || {
+            tracing::debug!(chat_id, message_id, ?emoji, "background: removing reaction");
+            let query = AddReactionQuery {
+                chat_id,
+                message_id,
+                emoji,
+            };
+            if let Err(e) = source.remove_reaction(&query) {
+                tracing::warn!(chat_id, message_id, error = ?e, "background: remove reaction failed");
+            }
+        })
+    {
+        tracing::error!(error = %error, "failed to spawn remove reaction background thread");
+    }
+}
@@ -1,10 +1,17 @@
 use std::sync::{mpsc::Sender, Arc};
 
 use crate::{
-    domain::events::{BackgroundError, BackgroundTaskResult},
+    domain::{
+        chat::UnreadJumpKind,
+        events::{BackgroundError, BackgroundTaskResult},
+        message_input_state::TextMention,
+    },
     usecases::{
         edit_message::{edit_message, EditMessageCommand, MessageEditor},
-        load_messages::{load_messages, LoadMessagesQuery, MessagesSource},
+        load_messages::{
+            load_messages, load_messages_around_date, load_messages_around_unread,
+            LoadMessagesQuery, MessageWindowSource, MessagesSource,
+        },
         send_message::{send_message, MessageSender, SendMessageCommand},
         send_voice::VoiceNoteSender,
     },
@@ -104,6 +111,99 @@ pub(super) fn dispatch_load_older_messages<M: MessagesSource + Send + Sync + 'st
     }
 }
 
+pub(super) fn dispatch_load_messages_around_date<M: MessageWindowSource + Send + Sync + 'static>(
+    source: &Arc<M>,
+    tx: &Sender<BackgroundTaskResult>,
+    chat_id: i64,
+    topic_id: Option<i32>,
+    unix_time: i64,
+) {
+    let source = Arc::clone(source);
+    let tx = tx.clone();
+    let tx_fallback = tx.clone();
+    let target_unix_ms = unix_time.saturating_mul(1000);
+
+    let spawn_result = std::thread::Builder::new()
+        .name("rtg-bg-jump-date".into())
+        .spawn(move || {
+            tracing::debug!(
+                chat_id,
+                ?topic_id,
+                unix_time,
+                "background: fetching messages around date"
+            );
+            let result = load_messages_around_date(source.as_ref(), chat_id, topic_id, unix_time)
+                .map(|output| output.messages)
+                .map_err(|error| {
+                    tracing::warn!(chat_id, error = ?error, "background: jump to date failed");
+                    BackgroundError::new(map_load_messages_error(&error))
+                });
+
+            let _ = tx.send(BackgroundTaskResult::MessagesAroundDateLoaded {
+                chat_id,
+                topic_id,
+                target_unix_ms,
+                result,
+            });
+        });
+
+    if let Err(error) = spawn_result {
+        tracing::error!(error = %error, "failed to spawn jump to date background thread");
+        let _ = tx_fallback.send(BackgroundTaskResult::MessagesAroundDateLoaded {
+            chat_id,
+            topic_id,
+            target_unix_ms,
+            result: Err(BackgroundError::new("THREAD_SPAWN_FAILED")),
+        });
+    }
+}
+
+pub(super) fn dispatch_load_unread_window<M: MessageWindowSource + Send + Sync + 'static>(
+    source: &Arc<M>,
+    tx: &Sender<BackgroundTaskResult>,
+    chat_id: i64,
+    topic_id: Option<i32>,
+    kind: UnreadJumpKind,
+) {
+    let source = Arc::clone(source);
+    let tx = tx.clone();
+    let tx_fallback = tx.clone();
+
+    let spawn_result = std::thread::Builder::new()
+        .name("rtg-bg-jump-unread".into())
+        .spawn(move || {
+            tracing::debug!(
+                chat_id,
+                ?topic_id,
+                ?kind,
+                "background: fetching messages around unread"
+            );
+            let result = load_messages_around_unread(source.as_ref(), chat_id, topic_id, kind)
+                .map_err(|error| {
+                    tracing::warn!(chat_id, error = ?error, "background: jump to unread failed");
+                    BackgroundError::new(map_load_messages_error(&error))
+                });
+
+            let _ = tx.send(BackgroundTaskResult::UnreadWindowLoaded {
+                chat_id,
+                topic_id,
+                kind,
+                result,
+            });
+        });
+
+    if let Err(error) = spawn_result {
+        tracing::error!(error = %error, "failed to spawn jump to unread background thread");
+        let _ = tx_fallback.send(BackgroundTaskResult::UnreadWindowLoaded {
+            chat_id,
+            topic_id,
+            kind,
+            result: Err(BackgroundError::new("THREAD_SPAWN_FAILED")),
+        });
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 pub(super) fn dispatch_send_message<
     MS: MessageSender + Send + Sync + 'static,
     M: MessagesSource + Send + Sync + 'static,
@@ -115,6 +215,7 @@ pub(super) fn dispatch_send_message<
     topic_id: Option<i32>,
     text: String,
     reply_to_message_id: Option<i64>,
+    mentions: Vec<TextMention>,
 ) {
     let sender = Arc::clone(sender);
     let messages_source = Arc::clone(messages_source);
@@ -132,6 +233,7 @@ pub(super) fn dispatch_send_message<
                 topic_id,
                 text,
                 reply_to_message_id,
+                mentions,
             };
             let send_result = send_message(sender.as_ref(), command).map_err(|error| {
                 tracing::warn!(chat_id, error = ?error, "background: send message failed");
@@ -3,24 +3,53 @@
 //! Provides the [`TaskDispatcher`] trait and a thread-based implementation
 //! that moves blocking Telegram API calls off the UI thread.
 
+mod blocking;
+mod chat_lookup;
+mod chat_management;
+mod contacts;
 mod error_mapping;
 mod file_ops;
 mod lifecycle;
+mod links;
+mod message_actions;
 mod messaging;
 
-use std::sync::{mpsc::Sender, Arc};
+use std::{
+    sync::{mpsc::Sender, Arc},
+    time::Duration,
+};
 
-use crate::domain::events::BackgroundTaskResult;
+use crate::domain::{
+    chat::{MuteDuration, UnreadJumpKind},
+    chat_action::ChatAction,
+    chat_folder::ChatListId,
+    chat_manage_state::ChatManageOp,
+    delete_confirm_state::DeleteScope,
+    events::{BackgroundError, BackgroundTaskResult},
+    go_to_prompt_state::ChatQuery,
+    message_input_state::TextMention,
+    new_chat_wizard_state::NewChatRequest,
+};
 
 use super::{
-    chat_lifecycle::{ChatLifecycle, ChatReadMarker, FileDownloader, MessageDeleter},
+    bot_commands::BotCommandsSource,
+    chat_lifecycle::{
+        ChatActionSender, ChatLifecycle, ChatListMover, ChatManager, ChatMuter, ChatPinner,
+        ChatReadMarker, FileDownloader, MessageDeleter, MessageForwarder,
+    },
+    chat_members::ChatMemberSource,
     chat_subtitle::{ChatInfoQuery, ChatSubtitleQuery, ChatSubtitleSource},
+    contacts::ContactSource,
     edit_message::MessageEditor,
+    go_to_chat::ChatLookupSource,
+    inline_keyboard::{CallbackQuery, CallbackQuerySource},
+    links::LinkSource,
     list_chats::ListChatsSource,
     list_forum_topics::ForumTopicsSource,
-    load_messages::MessagesSource,
+    load_messages::{MessageWindowSource, MessagesSource},
     message_info::{MessageInfoQuery, MessageInfoSource},
     message_reactions::{AvailableReactionsQuery, ReactionSource},
+    new_chat::ChatCreator,
     send_message::MessageSender,
     send_voice::VoiceNoteSender,
 };
@@ -29,12 +58,14 @@ use super::{
 ///
 /// Implementations must be non-blocking: they enqueue work and return immediately.
 /// Results are delivered asynchronously via the background result channel.
+/// The `*_blocking` methods used on quit are the only exception.
 ///
 /// Lifecycle operations (`dispatch_open_chat`, `dispatch_close_chat`,
 /// `dispatch_mark_as_read`) are fire-and-forget: errors are logged
 /// but do not produce `BackgroundTaskResult`.
 pub trait TaskDispatcher {
-    fn dispatch_chat_list(&self, force: bool, limit: usize);
+    /// Fetches the first `limit` chats of `list` (main list or a folder).
+    fn dispatch_chat_list(&self, list: ChatListId, force: bool, limit: usize);
     /// Fetches the topic list for a forum supergroup chat in the background.
     fn dispatch_load_forum_topics(&self, chat_id: i64);
     /// Resolves unread-topic counts for forum chats whose chat-list badge is
@@ -50,12 +81,29 @@ pub trait TaskDispatcher {
         topic_id: Option<i32>,
         from_message_id: i64,
     );
+    /// Loads a history window around `unix_time` (seconds) for "jump to
+    /// date". Results arrive as `MessagesAroundDateLoaded`.
+    fn dispatch_load_messages_around_date(
+        &self,
+        chat_id: i64,
+        topic_id: Option<i32>,
+        unix_time: i64,
+    );
+    /// Loads a history window around the oldest unread mention or reaction.
+    /// Results arrive as `UnreadWindowLoaded`.
+    fn dispatch_load_unread_window(
+        &self,
+        chat_id: i64,
+        topic_id: Option<i32>,
+        kind: UnreadJumpKind,
+    );
     fn dispatch_send_message(
         &self,
         chat_id: i64,
         topic_id: Option<i32>,
         text: String,
         reply_to_message_id: Option<i64>,
+        mentions: Vec<TextMention>,
     );
     fn dispatch_edit_message(&self, chat_id: i64, message_id: i64, text: String);
 
@@ -74,16 +122,39 @@ pub trait TaskDispatcher {
     /// to mark the chat as read without loading its messages.
     fn dispatch_mark_chat_as_read(&self, chat_id: i64, last_message_id: i64);
 
+    /// Broadcasts a chat action such as "typing" (fire-and-forget).
+    fn dispatch_send_chat_action(&self, chat_id: i64, topic_id: Option<i32>, action: ChatAction);
+
     /// Prefetches messages for a chat the user is hovering in the chat list.
     /// Results go into `MessageCache` only (not `OpenChatState`).
     /// `topic_id` scopes the prefetch to a forum topic when `Some`.
     fn dispatch_prefetch_messages(&self, chat_id: i64, topic_id: Option<i32>);
 
-    /// Deletes a message from a chat (fire-and-forget).
+    /// Deletes messages from a chat in one request.
     ///
-    /// Tries `revoke=true` first (delete for everyone), falls back to
-    /// `revoke=false` (delete for self only) if that fails.
-    fn dispatch_delete_message(&self, chat_id: i64, message_id: i64);
+    /// With [`DeleteScope::Auto`] tries `revoke=true` first (delete for
+    /// everyone), falling back to `revoke=false` (delete for self only)
+    /// if that fails. The result arrives as `MessagesDeleted`.
+    fn dispatch_delete_messages(&self, chat_id: i64, message_ids: Vec<i64>, scope: DeleteScope);
+
+    /// Like [`dispatch_delete_messages`](Self::dispatch_delete_messages),
+    /// but blocks until the deletion finishes or `timeout` passes. Only
+    /// for quitting, when a background result would never be handled.
+    fn delete_messages_blocking(
+        &self,
+        chat_id: i64,
+        message_ids: Vec<i64>,
+        scope: DeleteScope,
+        timeout: Duration,
+    ) -> Result<(), BackgroundError>;
+
+    /// Resolves who the messages can be deleted for in the background.
+    /// The result arrives as `DeletePermissionsLoaded`.
+    fn dispatch_delete_permissions(&self, chat_id: i64, message_ids: Vec<i64>);
+
+    /// Forwards messages to another chat.
+    /// The result arrives as `MessagesForwarded`.
+    fn dispatch_forward_messages(&self, to_chat_id: i64, from_chat_id: i64, message_ids: Vec<i64>);
 
     /// Resolves the chat subtitle (user status, member count, etc.) in the background.
     fn dispatch_chat_subtitle(&self, query: ChatSubtitleQuery);
@@ -122,6 +193,89 @@ pub trait TaskDispatcher {
 
     /// Removes a reaction from a message (fire-and-forget).
     fn dispatch_remove_reaction(&self, chat_id: i64, message_id: i64, emoji: String);
+
+    /// Presses an inline keyboard callback button in the background.
+    /// The bot's answer arrives as `CallbackAnswerLoaded`.
+    fn dispatch_callback_query(&self, query: CallbackQuery);
+
+    /// Loads the bot commands available in a chat for `/` completion.
+    /// Result arrives as `BotCommandsLoaded`.
+    fn dispatch_load_bot_commands(&self, chat_id: i64);
+
+    /// Searches group members for `@` completion.
+    /// Result arrives as `ChatMembersLoaded`.
+    fn dispatch_search_chat_members(&self, chat_id: i64, query: String);
+
+    /// Loads the `t.me` link of a message for copying.
+    /// Result arrives as `MessageLinkLoaded`.
+    fn dispatch_message_link(&self, chat_id: i64, message_id: i64);
+
+    /// Resolves a `t.me` / `tg://` link to the chat or message it points to.
+    /// Result arrives as `LinkResolved`.
+    fn dispatch_resolve_link(&self, url: String);
+
+    /// Joins a chat by invite link. Result arrives as `ChatJoined`.
+    fn dispatch_join_chat(&self, invite_link: String);
+
+    /// Finds the chat for a username, phone number or contact name.
+    /// Result arrives as `ChatFound`.
+    fn dispatch_find_chat(&self, query: ChatQuery);
+
+    /// Searches chats by title and username, first locally and then, for
+    /// queries of at least `MIN_SERVER_SEARCH_CHARS`, on the server.
+    /// Results arrive as `ChatsSearched`, once per scope searched.
+    fn dispatch_search_chats(&self, query: String);
+
+    /// Loads the contact list. Result arrives as `ContactsLoaded`.
+    fn dispatch_load_contacts(&self);
+
+    /// Adds the peer of a private chat as a contact.
+    /// Result arrives as `ContactAdded`.
+    fn dispatch_add_contact(&self, chat_id: i64);
+
+    /// Removes a contact. Result arrives as `ContactRemoved`.
+    fn dispatch_remove_contact(&self, user_id: i64);
+
+    /// Opens (creating if needed) the private chat with a contact.
+    /// Result arrives as `ContactChatLoaded`.
+    fn dispatch_open_contact_chat(&self, user_id: i64);
+
+    /// Creates a group, supergroup, forum or channel.
+    /// Result arrives as `ChatCreated`.
+    fn dispatch_create_chat(&self, request: NewChatRequest);
+
+    /// Moves a chat to another chat list (archive or main list).
+    /// Result arrives as `ChatMovedToList`.
+    fn dispatch_move_chat_to_list(&self, chat_id: i64, list: ChatListId);
+
+    /// Mutes a chat for `mute_for`, or unmutes it when `None`.
+    /// Result arrives as `ChatMuteChanged`.
+    fn dispatch_set_chat_mute(&self, chat_id: i64, mute_for: Option<MuteDuration>);
+
+    /// Sets or clears a chat's "marked as unread" flag.
+    /// Result arrives as `ChatMarkedAsUnread`.
+    fn dispatch_set_chat_marked_as_unread(&self, chat_id: i64, marked: bool);
+
+    /// Pins or unpins a chat in `list`. Result arrives as `ChatPinToggled`.
+    fn dispatch_set_chat_pinned(&self, chat_id: i64, list: ChatListId, pinned: bool);
+
+    /// Reorders the pinned chats of `list`.
+    /// Result arrives as `PinnedChatsReordered`.
+    fn dispatch_set_pinned_chats(&self, list: ChatListId, chat_ids: Vec<i64>);
+
+    /// Leaves, deletes or clears a chat, or blocks or unblocks its user.
+    /// Result arrives as `ChatManaged`.
+    fn dispatch_manage_chat(&self, chat_id: i64, op: ChatManageOp);
+
+    /// Like [`dispatch_manage_chat`](Self::dispatch_manage_chat), but
+    /// blocks until the request finishes or `timeout` passes. Only for
+    /// quitting.
+    fn manage_chat_blocking(
+        &self,
+        chat_id: i64,
+        op: ChatManageOp,
+        timeout: Duration,
+    ) -> Result<(), BackgroundError>;
 }
 
 /// Thread-based dispatcher that runs blocking API calls on background OS threads.
@@ -132,10 +286,34 @@ pub trait TaskDispatcher {
 pub struct ThreadTaskDispatcher<C, M, MS, L, S>
 where
     C: ListChatsSource + ForumTopicsSource + Send + Sync + 'static,
-    M: MessagesSource + Send + Sync + 'static,
+    M: MessagesSource + MessageWindowSource + Send + Sync + 'static,
     MS: MessageSender + MessageEditor + VoiceNoteSender + Send + Sync + 'static,
-    L: ChatLifecycle + ChatReadMarker + MessageDeleter + FileDownloader + Send + Sync + 'static,
-    S: ChatSubtitleSource + MessageInfoSource + ReactionSource + Send + Sync + 'static,
+    L: ChatLifecycle
+        + ChatReadMarker
+        + ChatActionSender
+        + MessageDeleter
+        + MessageForwarder
+        + FileDownloader
+        + ChatListMover
+        + ChatMuter
+        + ChatPinner
+        + ChatManager
+        + Send
+        + Sync
+        + 'static,
+    S: ChatSubtitleSource
+        + MessageInfoSource
+        + ReactionSource
+        + CallbackQuerySource
+        + BotCommandsSource
+        + ChatMemberSource
+        + LinkSource
+        + ChatLookupSource
+        + ContactSource
+        + ChatCreator
+        + Send
+        + Sync
+        + 'static,
 {
     chats_source: Arc<C>,
     messages_source: Arc<M>,
@@ -148,10 +326,34 @@ where
 impl<C, M, MS, L, S> ThreadTaskDispatcher<C, M, MS, L, S>
 where
     C: ListChatsSource + ForumTopicsSource + Send + Sync + 'static,
-    M: MessagesSource + Send + Sync + 'static,
+    M: MessagesSource + MessageWindowSource + Send + Sync + 'static,
     MS: MessageSender + MessageEditor + VoiceNoteSender + Send + Sync + 'static,
-    L: ChatLifecycle + ChatReadMarker + MessageDeleter + FileDownloader + Send + Sync + 'static,
-    S: ChatSubtitleSource + MessageInfoSource + ReactionSource + Send + Sync + 'static,
+    L: ChatLifecycle
+        + ChatReadMarker
+        + ChatActionSender
+        + MessageDeleter
+        + MessageForwarder
+        + FileDownloader
+        + ChatListMover
+        + ChatMuter
+        + ChatPinner
+        + ChatManager
+        + Send
+        + Sync
+        + 'static,
+    S: ChatSubtitleSource
+        + MessageInfoSource
+        + ReactionSource
+        + CallbackQuerySource
+        + BotCommandsSource
+        + ChatMemberSource
+        + LinkSource
+        + ChatLookupSource
+        + ContactSource
+        + ChatCreator
+        + Send
+        + Sync
+        + 'static,
 {
     pub fn new(
         chats_source: Arc<C>,
@@ -175,13 +377,37 @@ where
 impl<C, M, MS, L, S> TaskDispatcher for ThreadTaskDispatcher<C, M, MS, L, S>
 where
     C: ListChatsSource + ForumTopicsSource + Send + Sync + 'static,
-    M: MessagesSource + Send + Sync + 'static,
+    M: MessagesSource + MessageWindowSource + Send + Sync + 'static,
     MS: MessageSender + MessageEditor + VoiceNoteSender + Send + Sync + 'static,
-    L: ChatLifecycle + ChatReadMarker + MessageDeleter + FileDownloader + Send + Sync + 'static,
-    S: ChatSubtitleSource + MessageInfoSource + ReactionSource + Send + Sync + 'static,
+    L: ChatLifecycle
+        + ChatReadMarker
+        + ChatActionSender
+        + MessageDeleter
+        + MessageForwarder
+        + FileDownloader
+        + ChatListMover
+        + ChatMuter
+        + ChatPinner
+        + ChatManager
+        + Send
+        + Sync
+        + 'static,
+    S: ChatSubtitleSource
+        + MessageInfoSource
+        + ReactionSource
+        + CallbackQuerySource
+        + BotCommandsSource
+        + ChatMemberSource
+        + LinkSource
+        + ChatLookupSource
+        + ContactSource
+        + ChatCreator
+        + Send
+        + Sync
+        + 'static,
 {
-    fn dispatch_chat_list(&self, force: bool, limit: usize) {
-        lifecycle::dispatch_chat_list(&self.chats_source, &self.result_tx, force, limit);
+    fn dispatch_chat_list(&self, list: ChatListId, force: bool, limit: usize) {
+        lifecycle::dispatch_chat_list(&self.chats_source, &self.result_tx, list, force, limit);
     }
 
     fn dispatch_load_forum_topics(&self, chat_id: i64) {
@@ -216,12 +442,43 @@ where
         );
     }
 
+    fn dispatch_load_messages_around_date(
+        &self,
+        chat_id: i64,
+        topic_id: Option<i32>,
+        unix_time: i64,
+    ) {
+        messaging::dispatch_load_messages_around_date(
+            &self.messages_source,
+            &self.result_tx,
+            chat_id,
+            topic_id,
+            unix_time,
+        );
+    }
+
+    fn dispatch_load_unread_window(
+        &self,
+        chat_id: i64,
+        topic_id: Option<i32>,
+        kind: UnreadJumpKind,
+    ) {
+        messaging::dispatch_load_unread_window(
+            &self.messages_source,
+            &self.result_tx,
+            chat_id,
+            topic_id,
+            kind,
+        );
+    }
+
     fn dispatch_send_message(
         &self,
         chat_id: i64,
         topic_id: Option<i32>,
         text: String,
         reply_to_message_id: Option<i64>,
+        mentions: Vec<TextMention>,
     ) {
         messaging::dispatch_send_message(
             &self.message_sender,
@@ -231,6 +488,7 @@ where
             topic_id,
             text,
             reply_to_message_id,
+            mentions,
         );
     }
 
@@ -260,6 +518,10 @@ where
         lifecycle::dispatch_mark_chat_as_read(&self.lifecycle, chat_id, last_message_id);
     }
 
+    fn dispatch_send_chat_action(&self, chat_id: i64, topic_id: Option<i32>, action: ChatAction) {
+        lifecycle::dispatch_send_chat_action(&self.lifecycle, chat_id, topic_id, action);
+    }
+
     fn dispatch_prefetch_messages(&self, chat_id: i64, topic_id: Option<i32>) {
         messaging::dispatch_prefetch_messages(
             &self.messages_source,
@@ -269,8 +531,49 @@ where
         );
     }
 
-    fn dispatch_delete_message(&self, chat_id: i64, message_id: i64) {
-        lifecycle::dispatch_delete_message(&self.lifecycle, chat_id, message_id);
+    fn dispatch_delete_messages(&self, chat_id: i64, message_ids: Vec<i64>, scope: DeleteScope) {
+        message_actions::dispatch_delete_messages(
+            &self.lifecycle,
+            &self.result_tx,
+            chat_id,
+            message_ids,
+            scope,
+        );
+    }
+
+    fn delete_messages_blocking(
+        &self,
+        chat_id: i64,
+        message_ids: Vec<i64>,
+        scope: DeleteScope,
+        timeout: Duration,
+    ) -> Result<(), BackgroundError> {
+        message_actions::delete_messages_blocking(
+            &self.lifecycle,
+            chat_id,
+            message_ids,
+            scope,
+            timeout,
+        )
+    }
+
+    fn dispatch_delete_permissions(&self, chat_id: i64, message_ids: Vec<i64>) {
+        message_actions::dispatch_delete_permissions(
+            &self.lifecycle,
+            &self.result_tx,
+            chat_id,
+            message_ids,
+        );
+    }
+
+    fn dispatch_forward_messages(&self, to_chat_id: i64, from_chat_id: i64, message_ids: Vec<i64>) {
+        message_actions::dispatch_forward_messages(
+            &self.lifecycle,
+            &self.result_tx,
+            to_chat_id,
+            from_chat_id,
+            message_ids,
+        );
     }
 
     fn dispatch_chat_subtitle(&self, query: ChatSubtitleQuery) {
@@ -305,19 +608,144 @@ where
     }
 
     fn dispatch_message_info(&self, query: MessageInfoQuery) {
-        lifecycle::dispatch_message_info(&self.subtitle_source, &self.result_tx, query);
+        message_actions::dispatch_message_info(&self.subtitle_source, &self.result_tx, query);
     }
 
     fn dispatch_available_reactions(&self, query: AvailableReactionsQuery) {
-        lifecycle::dispatch_available_reactions(&self.subtitle_source, &self.result_tx, query);
+        message_actions::dispatch_available_reactions(
+            &self.subtitle_source,
+            &self.result_tx,
+            query,
+        );
     }
 
     fn dispatch_add_reaction(&self, chat_id: i64, message_id: i64, emoji: String) {
-        lifecycle::dispatch_add_reaction(&self.subtitle_source, chat_id, message_id, emoji);
+        message_actions::dispatch_add_reaction(&self.subtitle_source, chat_id, message_id, emoji);
     }
 
     fn dispatch_remove_reaction(&self, chat_id: i64, message_id: i64, emoji: String) {
-        lifecycle::dispatch_remove_reaction(&self.subtitle_source, chat_id, message_id, emoji);
+        message_actions::dispatch_remove_reaction(
+            &self.subtitle_source,
+            chat_id,
+            message_id,
+            emoji,
+        );
+    }
+
+    fn dispatch_callback_query(&self, query: CallbackQuery) {
+        message_actions::dispatch_callback_query(&self.subtitle_source, &self.result_tx, query);
+    }
+
+    fn dispatch_load_bot_commands(&self, chat_id: i64) {
+        lifecycle::dispatch_load_bot_commands(&self.subtitle_source, &self.result_tx, chat_id);
+    }
+
+    fn dispatch_search_chat_members(&self, chat_id: i64, query: String) {
+        lifecycle::dispatch_search_chat_members(
+            &self.subtitle_source,
+            &self.result_tx,
+            chat_id,
+            query,
+        );
+    }
+
+    fn dispatch_message_link(&self, chat_id: i64, message_id: i64) {
+        links::dispatch_message_link(&self.subtitle_source, &self.result_tx, chat_id, message_id);
+    }
+
+    fn dispatch_resolve_link(&self, url: String) {
+        links::dispatch_resolve_link(&self.subtitle_source, &self.result_tx, url);
+    }
+
+    fn dispatch_join_chat(&self, invite_link: String) {
+        links::dispatch_join_chat(&self.subtitle_source, &self.result_tx, invite_link);
+    }
+
+    fn dispatch_find_chat(&self, query: ChatQuery) {
+        chat_lookup::dispatch_find_chat(&self.subtitle_source, &self.result_tx, query);
+    }
+
+    fn dispatch_search_chats(&self, query: String) {
+        chat_lookup::dispatch_search_chats(&self.subtitle_source, &self.result_tx, query);
+    }
+
+    fn dispatch_load_contacts(&self) {
+        contacts::dispatch_load_contacts(&self.subtitle_source, &self.result_tx);
+    }
+
+    fn dispatch_add_contact(&self, chat_id: i64) {
+        contacts::dispatch_add_contact(&self.subtitle_source, &self.result_tx, chat_id);
+    }
+
+    fn dispatch_remove_contact(&self, user_id: i64) {
+        contacts::dispatch_remove_contact(&self.subtitle_source, &self.result_tx, user_id);
+    }
+
+    fn dispatch_open_contact_chat(&self, user_id: i64) {
+        contacts::dispatch_open_contact_chat(&self.subtitle_source, &self.result_tx, user_id);
+    }
+
+    fn dispatch_create_chat(&self, request: NewChatRequest) {
+        chat_lookup::dispatch_create_chat(&self.subtitle_source, &self.result_tx, request);
+    }
+
+    fn dispatch_move_chat_to_list(&self, chat_id: i64, list: ChatListId) {
+        chat_management::dispatch_move_chat_to_list(
+            &self.lifecycle,
+            &self.result_tx,
+            chat_id,
+            list,
+        );
+    }
+
+    fn dispatch_set_chat_mute(&self, chat_id: i64, mute_for: Option<MuteDuration>) {
+        chat_management::dispatch_set_chat_mute(
+            &self.lifecycle,
+            &self.result_tx,
+            chat_id,
+            mute_for,
+        );
+    }
+
+    fn dispatch_set_chat_marked_as_unread(&self, chat_id: i64, marked: bool) {
+        chat_management::dispatch_set_chat_marked_as_unread(
+            &self.lifecycle,
+            &self.result_tx,
+            chat_id,
+            marked,
+        );
+    }
+
+    fn dispatch_set_chat_pinned(&self, chat_id: i64, list: ChatListId, pinned: bool) {
+        chat_management::dispatch_set_chat_pinned(
+            &self.lifecycle,
+            &self.result_tx,
+            chat_id,
+            list,
+            pinned,
+        );
+    }
+
+    fn dispatch_set_pinned_chats(&self, list: ChatListId, chat_ids: Vec<i64>) {
+        chat_management::dispatch_set_pinned_chats(
+            &self.lifecycle,
+            &self.result_tx,
+            list,
+            chat_ids,
+        );
+    }
+
+    fn dispatch_manage_chat(&self, chat_id: i64, op: ChatManageOp) {
+        chat_management::dispatch_manage_chat(&self.lifecycle, &self.result_tx, chat_id, op);
+    }
+
+    fn manage_chat_blocking(
+        &self,
+        chat_id: i64,
+        op: ChatManageOp,
+        timeout: Duration,
+    ) -> Result<(), BackgroundError> {
+        chat_management::manage_chat_blocking(&self.lifecycle, chat_id, op, timeout)
     }
 }
 
@@ -3,10 +3,19 @@ mod error_mapping;
 use std::sync::mpsc::{self, Sender};
 
 use crate::{
-    domain::events::BackgroundTaskResult,
+    domain::{
+        chat::MuteDuration,
+        chat_folder::ChatListId,
+        chat_manage_state::ChatManageOp,
+        delete_confirm_state::DeleteScope,
+        events::{BackgroundError, BackgroundTaskResult},
+        go_to_prompt_state::ChatQuery,
+        new_chat_wizard_state::NewChatRequest,
+    },
     usecases::{
         background::TaskDispatcher,
         chat_subtitle::{ChatInfoQuery, ChatSubtitleQuery},
+        inline_keyboard::CallbackQuery,
         message_info::MessageInfoQuery,
         message_reactions::AvailableReactionsQuery,
     },
@@ -31,7 +40,7 @@ impl StubTaskDispatcher {
 }
 
 impl TaskDispatcher for StubTaskDispatcher {
-    fn dispatch_chat_list(&self, _force: bool, _limit: usize) {}
+    fn dispatch_chat_list(&self, _list: ChatListId, _force: bool, _limit: usize) {}
 
     fn dispatch_load_forum_topics(&self, _chat_id: i64) {}
 
@@ -47,12 +56,29 @@ impl TaskDispatcher for StubTaskDispatcher {
     ) {
     }
 
+    fn dispatch_load_messages_around_date(
+        &self,
+        _chat_id: i64,
+        _topic_id: Option<i32>,
+        _unix_time: i64,
+    ) {
+    }
+
+    fn dispatch_load_unread_window(
+        &self,
+        _chat_id: i64,
+        _topic_id: Option<i32>,
+        _kind: crate::domain::chat::UnreadJumpKind,
+    ) {
+    }
+
     fn dispatch_send_message(
         &self,
         _chat_id: i64,
         _topic_id: Option<i32>,
         _text: String,
         _reply_to_message_id: Option<i64>,
+        _mentions: Vec<crate::domain::message_input_state::TextMention>,
     ) {
     }
 
@@ -67,9 +93,38 @@ impl TaskDispatcher for StubTaskDispatcher {
 
     fn dispatch_mark_chat_as_read(&self, _chat_id: i64, _last_message_id: i64) {}
 
+    fn dispatch_send_chat_action(
+        &self,
+        _chat_id: i64,
+        _topic_id: Option<i32>,
+        _action: crate::domain::chat_action::ChatAction,
+    ) {
+    }
+
     fn dispatch_prefetch_messages(&self, _chat_id: i64, _topic_id: Option<i32>) {}
 
-    fn dispatch_delete_message(&self, _chat_id: i64, _message_id: i64) {}
+    fn dispatch_delete_messages(&self, _chat_id: i64, _message_ids: Vec<i64>, _scope: DeleteScope) {
+    }
+
+    fn delete_messages_blocking(
+        &self,
+        _chat_id: i64,
+        _message_ids: Vec<i64>,
+        _scope: DeleteScope,
+        _timeout: std::time::Duration,
+    ) -> Result<(), BackgroundError> {
+        Ok(())
+    }
+
+    fn dispatch_delete_permissions(&self, _chat_id: i64, _message_ids: Vec<i64>) {}
+
+    fn dispatch_forward_messages(
+        &self,
+        _to_chat_id: i64,
+        _from_chat_id: i64,
+        _message_ids: Vec<i64>,
+    ) {
+    }
 
     fn dispatch_chat_subtitle(&self, _query: ChatSubtitleQuery) {}
 
@@ -90,6 +145,53 @@ impl TaskDispatcher for StubTaskDispatcher {
     fn dispatch_add_reaction(&self, _chat_id: i64, _message_id: i64, _emoji: String) {}
 
     fn dispatch_remove_reaction(&self, _chat_id: i64, _message_id: i64, _emoji: String) {}
+
+    fn dispatch_callback_query(&self, _query: CallbackQuery) {}
+
+    fn dispatch_load_bot_commands(&self, _chat_id: i64) {}
+
+    fn dispatch_search_chat_members(&self, _chat_id: i64, _query: String) {}
+
+    fn dispatch_message_link(&self, _chat_id: i64, _message_id: i64) {}
+
+    fn dispatch_resolve_link(&self, _url: String) {}
+
+    fn dispatch_join_chat(&self, _invite_link: String) {}
+
+    fn dispatch_find_chat(&self, _query: ChatQuery) {}
+
+    fn dispatch_search_chats(&self, _query: String) {}
+
+    fn dispatch_load_contacts(&self) {}
+
+    fn dispatch_add_contact(&self, _chat_id: i64) {}
+
+    fn dispatch_remove_contact(&self, _user_id: i64) {}
+
+    fn dispatch_open_contact_chat(&self, _user_id: i64) {}
+
+    fn dispatch_create_chat(&self, _request: NewChatRequest) {}
+
+    fn dispatch_move_chat_to_list(&self, _chat_id: i64, _list: ChatListId) {}
+
+    fn dispatch_set_chat_mute(&self, _chat_id: i64, _mute_for: Option<MuteDuration>) {}
+
+    fn dispatch_set_chat_marked_as_unread(&self, _chat_id: i64, _marked: bool) {}
+
+    fn dispatch_set_chat_pinned(&self, _chat_id: i64, _list: ChatListId, _pinned: bool) {}
+
+    fn dispatch_set_pinned_chats(&self, _list: ChatListId, _chat_ids: Vec<i64>) {}
+
+    fn dispatch_manage_chat(&self, _chat_id: i64, _op: ChatManageOp) {}
+
+    fn manage_chat_blocking(
+        &self,
+        _chat_id: i64,
+        _op: ChatManageOp,
+        _timeout: std::time::Duration,
+    ) -> Result<(), BackgroundError> {
+        Ok(())
+    }
 }
 
 // ── dispatch_forum_unread_counts: the badge warm-up sweep ──
@@ -14,7 +14,8 @@ use crate::{
         contracts::ConfigAdapter,
         error::AppError,
         opener::BrowserOpener,
-        stubs::StubStorageAdapter,
+        storage::FileStorageAdapter,
+        storage_layout::StorageLayout,
     },
     telegram::{
         ChatUpdatesMonitorStartError, ConnectivityMonitorStartError, TelegramAdapter,
@@ -146,10 +147,18 @@ fn compose_shell_with_factory(
         None
     };
 
+    // Without a config dir the recent chats are kept for this run only.
+    let recent_chats_file = StorageLayout::resolve()
+        .map(|layout| layout.recent_chats_file())
+        .inspect_err(|error| {
+            tracing::warn!(error = %error, "recent chats will not be saved");
+        })
+        .ok();
+
     ShellComposition {
         event_source,
         orchestrator: Box::new(DefaultShellOrchestrator::new_with_initial_state(
-            StubStorageAdapter::default(),
+            FileStorageAdapter::new(recent_chats_file),
             BrowserOpener,
             dispatcher,
             initial_state,
@@ -159,6 +168,9 @@ fn compose_shell_with_factory(
             context.config.open.handlers.clone(),
             context.config.download.max_auto_download_bytes(),
             context.config.keys.overrides.clone(),
+            context.config.privacy.send_chat_actions,
+            context.config.messages.confirm_delete,
+            context.config.messages.undo_grace(),
         )),
         _connectivity_monitor: connectivity_monitor,
         _chat_updates_monitor: chat_updates_monitor,
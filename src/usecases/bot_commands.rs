@@ -0,0 +1,18 @@
+use crate::domain::bot_command::BotCommand;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BotCommandsError {
+    Unavailable,
+}
+
+pub trait BotCommandsSource: Send + Sync {
+    /// Returns the commands of the bots in a chat: the bot itself in a
+    /// private chat, every member bot in a group. Empty for other chats.
+    fn get_bot_commands(&self, chat_id: i64) -> Result<Vec<BotCommand>, BotCommandsError>;
+}
+
+impl<T: BotCommandsSource> BotCommandsSource for std::sync::Arc<T> {
+    fn get_bot_commands(&self, chat_id: i64) -> Result<Vec<BotCommand>, BotCommandsError> {
+        (**self).get_bot_commands(chat_id)
+    }
+}
@@ -1,8 +1,15 @@
-//! Chat lifecycle management: open/close and mark-as-read.
+//! Chat lifecycle management: open/close, mark-as-read, chat actions,
+//! moving chats between chat lists, muting, pinning, and leaving,
+//! deleting, clearing or blocking chats.
 //!
 //! Provides traits for TDLib chat lifecycle operations that are
 //! managed by the orchestrator rather than individual use cases.
 
+use crate::domain::{
+    chat::MuteDuration, chat_action::ChatAction, chat_folder::ChatListId,
+    delete_confirm_state::DeletePermissions,
+};
+
 /// Error type for chat lifecycle operations.
 ///
 /// These are best-effort operations — failures are logged but do not
@@ -37,6 +44,24 @@ pub trait MessageDeleter: Send + Sync {
         message_ids: Vec<i64>,
         revoke: bool,
     ) -> Result<(), ChatLifecycleError>;
+
+    /// Who the messages can be deleted for; an option is allowed only
+    /// when it is allowed for every message.
+    fn delete_permissions(
+        &self,
+        chat_id: i64,
+        message_ids: &[i64],
+    ) -> Result<DeletePermissions, ChatLifecycleError>;
+}
+
+/// Forwards messages from one chat to another via TDLib.
+pub trait MessageForwarder: Send + Sync {
+    fn forward_messages(
+        &self,
+        to_chat_id: i64,
+        from_chat_id: i64,
+        message_ids: Vec<i64>,
+    ) -> Result<(), ChatLifecycleError>;
 }
 
 /// Triggers an asynchronous file download in TDLib.
@@ -62,6 +87,80 @@ pub trait ChatReadMarker: Send + Sync {
         topic_id: Option<i32>,
         message_ids: Vec<i64>,
     ) -> Result<(), ChatLifecycleError>;
+
+    /// Sets or clears the user's "marked as unread" flag on a chat.
+    fn set_chat_marked_as_unread(
+        &self,
+        chat_id: i64,
+        marked: bool,
+    ) -> Result<(), ChatLifecycleError>;
+}
+
+/// Broadcasts the user's current activity ("typing...", "recording
+/// voice...") to the other members of a chat.
+pub trait ChatActionSender: Send + Sync {
+    /// Sends `action` to the chat, or to a forum topic when `topic_id` is
+    /// `Some`. [`ChatAction::Cancel`] clears the active action.
+    fn send_chat_action(
+        &self,
+        chat_id: i64,
+        topic_id: Option<i32>,
+        action: ChatAction,
+    ) -> Result<(), ChatLifecycleError>;
+}
+
+/// Moves a chat to another chat list, e.g. into or out of the archive.
+pub trait ChatListMover: Send + Sync {
+    fn add_chat_to_list(&self, chat_id: i64, list: ChatListId) -> Result<(), ChatLifecycleError>;
+}
+
+/// Mutes or unmutes a chat's notifications.
+pub trait ChatMuter: Send + Sync {
+    /// Mutes the chat for `mute_for`; `None` unmutes it.
+    fn set_chat_mute(
+        &self,
+        chat_id: i64,
+        mute_for: Option<MuteDuration>,
+    ) -> Result<(), ChatLifecycleError>;
+}
+
+/// Pins chats in a chat list and orders the pinned ones.
+pub trait ChatPinner: Send + Sync {
+    fn set_chat_pinned(
+        &self,
+        chat_id: i64,
+        list: ChatListId,
+        pinned: bool,
+    ) -> Result<(), ChatLifecycleError>;
+
+    /// Replaces the order of the pinned chats of `list`.
+    fn set_pinned_chats(
+        &self,
+        list: ChatListId,
+        chat_ids: Vec<i64>,
+    ) -> Result<(), ChatLifecycleError>;
+}
+
+/// Leaves, deletes, clears and blocks chats.
+pub trait ChatManager: Send + Sync {
+    /// Leaves a group or channel.
+    fn leave_chat(&self, chat_id: i64) -> Result<(), ChatLifecycleError>;
+
+    /// Deletes a chat for all its members.
+    fn delete_chat(&self, chat_id: i64) -> Result<(), ChatLifecycleError>;
+
+    /// Deletes all messages of a chat. `remove_from_chat_list` also drops
+    /// the chat from the chat lists; `revoke` deletes the messages for the
+    /// other side where allowed.
+    fn delete_chat_history(
+        &self,
+        chat_id: i64,
+        remove_from_chat_list: bool,
+        revoke: bool,
+    ) -> Result<(), ChatLifecycleError>;
+
+    /// Blocks or unblocks the user of a private chat.
+    fn set_chat_blocked(&self, chat_id: i64, blocked: bool) -> Result<(), ChatLifecycleError>;
 }
 
 #[cfg(test)]
@@ -90,6 +189,14 @@ mod tests {
         ) -> Result<(), ChatLifecycleError> {
             Ok(())
         }
+
+        fn set_chat_marked_as_unread(
+            &self,
+            _chat_id: i64,
+            _marked: bool,
+        ) -> Result<(), ChatLifecycleError> {
+            Ok(())
+        }
     }
 
     struct StubDeleter;
@@ -103,6 +210,117 @@ mod tests {
         ) -> Result<(), ChatLifecycleError> {
             Ok(())
         }
+
+        fn delete_permissions(
+            &self,
+            _chat_id: i64,
+            _message_ids: &[i64],
+        ) -> Result<DeletePermissions, ChatLifecycleError> {
+            Ok(DeletePermissions {
+                for_self: true,
+                for_everyone: false,
+            })
+        }
+    }
+
+    struct StubForwarder;
+
+    impl MessageForwarder for StubForwarder {
+        fn forward_messages(
+            &self,
+            _to_chat_id: i64,
+            _from_chat_id: i64,
+            _message_ids: Vec<i64>,
+        ) -> Result<(), ChatLifecycleError> {
+            Ok(())
+        }
+    }
+
+    struct StubActionSender;
+
+    impl ChatActionSender for StubActionSender {
+        fn send_chat_action(
+            &self,
+            _chat_id: i64,
+            _topic_id: Option<i32>,
+            _action: ChatAction,
+        ) -> Result<(), ChatLifecycleError> {
+            Ok(())
+        }
+    }
+
+    struct StubMover;
+
+    impl ChatListMover for StubMover {
+        fn add_chat_to_list(
+            &self,
+            _chat_id: i64,
+            _list: ChatListId,
+        ) -> Result<(), ChatLifecycleError> {
+            Ok(())
+        }
+    }
+
+    struct StubMuter;
+
+    impl ChatMuter for StubMuter {
+        fn set_chat_mute(
+            &self,
+            _chat_id: i64,
+            _mute_for: Option<MuteDuration>,
+        ) -> Result<(), ChatLifecycleError> {
+            Ok(())
+        }
+    }
+
+    struct StubPinner;
+
+    impl ChatPinner for StubPinner {
+        fn set_chat_pinned(
+            &self,
+            _chat_id: i64,
+            _list: ChatListId,
+            _pinned: bool,
+        ) -> Result<(), ChatLifecycleError> {
+            Ok(())
+        }
+
+        fn set_pinned_chats(
+            &self,
+            _list: ChatListId,
+            _chat_ids: Vec<i64>,
+        ) -> Result<(), ChatLifecycleError> {
+            Ok(())
+        }
+    }
+
+    struct StubManager;
+
+    impl ChatManager for StubManager {
+        fn leave_chat(&self, _chat_id: i64) -> Result<(), ChatLifecycleError> {
+            Ok(())
+        }
+
+        fn delete_chat(&self, _chat_id: i64) -> Result<(), ChatLifecycleError> {
+            Ok(())
+        }
+
+        fn delete_chat_history(
+            &self,
+            _chat_id: i64,
+            _remove_from_chat_list: bool,
+            _revoke: bool,
+        ) -> Result<(), ChatLifecycleError> {
+            Ok(())
+        }
+
+        fn set_chat_blocked(
+            &self,
+            _chat_id: i64,
+            _blocked: bool,
+        ) -> Result<(), ChatLifecycleError> {
+            Ok(())
+        }
     }
 
     #[test]
@@ -116,6 +334,7 @@ mod tests {
     fn stub_read_marker_succeeds() {
         let marker = StubReadMarker;
         assert!(marker.mark_messages_read(1, None, vec![1, 2, 3]).is_ok());
+        assert!(marker.set_chat_marked_as_unread(1, true).is_ok());
     }
 
     #[test]
@@ -124,4 +343,51 @@ mod tests {
         assert!(deleter.delete_messages(1, vec![1, 2], true).is_ok());
         assert!(deleter.delete_messages(1, vec![1, 2], false).is_ok());
     }
+
+    #[test]
+    fn stub_forwarder_succeeds() {
+        let forwarder = StubForwarder;
+        assert!(forwarder.forward_messages(2, 1, vec![1, 2]).is_ok());
+    }
+
+    #[test]
+    fn stub_action_sender_succeeds() {
+        let sender = StubActionSender;
+        assert!(sender.send_chat_action(1, None, ChatAction::Typing).is_ok());
+        assert!(sender
+            .send_chat_action(1, Some(2), ChatAction::Cancel)
+            .is_ok());
+    }
+
+    #[test]
+    fn stub_mover_succeeds() {
+        let mover = StubMover;
+        assert!(mover.add_chat_to_list(1, ChatListId::Archive).is_ok());
+        assert!(mover.add_chat_to_list(1, ChatListId::Main).is_ok());
+    }
+
+    #[test]
+    fn stub_muter_succeeds() {
+        let muter = StubMuter;
+        assert!(muter.set_chat_mute(1, Some(MuteDuration::Forever)).is_ok());
+        assert!(muter.set_chat_mute(1, None).is_ok());
+    }
+
+    #[test]
+    fn stub_pinner_succeeds() {
+        let pinner = StubPinner;
+        assert!(pinner.set_chat_pinned(1, ChatListId::Main, true).is_ok());
+        assert!(pinner
+            .set_pinned_chats(ChatListId::Main, vec![2, 1])
+            .is_ok());
+    }
+
+    #[test]
+    fn stub_manager_succeeds() {
+        let manager = StubManager;
+        assert!(manager.leave_chat(1).is_ok());
+        assert!(manager.delete_chat(1).is_ok());
+        assert!(manager.delete_chat_history(1, true, false).is_ok());
+        assert!(manager.set_chat_blocked(1, true).is_ok());
+    }
 }
@@ -0,0 +1,31 @@
+use crate::domain::chat_member::ChatMember;
+
+/// Maximum number of members fetched per `@` query.
+pub const MEMBER_SEARCH_LIMIT: usize = 50;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChatMembersError {
+    Unavailable,
+}
+
+pub trait ChatMemberSource: Send + Sync {
+    /// Finds members of a group whose name or username matches `query`
+    /// (all members, up to `limit`, for an empty query).
+    fn search_chat_members(
+        &self,
+        chat_id: i64,
+        query: &str,
+        limit: usize,
+    ) -> Result<Vec<ChatMember>, ChatMembersError>;
+}
+
+impl<T: ChatMemberSource> ChatMemberSource for std::sync::Arc<T> {
+    fn search_chat_members(
+        &self,
+        chat_id: i64,
+        query: &str,
+        limit: usize,
+    ) -> Result<Vec<ChatMember>, ChatMembersError> {
+        (**self).search_chat_members(chat_id, query, limit)
+    }
+}
@@ -0,0 +1,39 @@
+use crate::domain::{chat::ChatSummary, contact::Contact};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ContactsError {
+    /// The chat is not a private chat with a user, or the user is gone.
+    NotFound,
+    Unavailable,
+}
+
+pub trait ContactSource: Send + Sync {
+    fn list_contacts(&self) -> Result<Vec<Contact>, ContactsError>;
+
+    /// Adds the peer of a private chat to the contacts, under the name
+    /// they have on Telegram.
+    fn add_contact(&self, chat_id: i64) -> Result<Contact, ContactsError>;
+
+    fn remove_contact(&self, user_id: i64) -> Result<(), ContactsError>;
+
+    /// Returns the private chat with a contact, creating it if needed.
+    fn contact_chat(&self, user_id: i64) -> Result<ChatSummary, ContactsError>;
+}
+
+impl<T: ContactSource> ContactSource for std::sync::Arc<T> {
+    fn list_contacts(&self) -> Result<Vec<Contact>, ContactsError> {
+        (**self).list_contacts()
+    }
+
+    fn add_contact(&self, chat_id: i64) -> Result<Contact, ContactsError> {
+        (**self).add_contact(chat_id)
+    }
+
+    fn remove_contact(&self, user_id: i64) -> Result<(), ContactsError> {
+        (**self).remove_contact(user_id)
+    }
+
+    fn contact_chat(&self, user_id: i64) -> Result<ChatSummary, ContactsError> {
+        (**self).contact_chat(user_id)
+    }
+}
@@ -0,0 +1,57 @@
+use crate::domain::{chat::ChatSummary, go_to_prompt_state::ChatQuery};
+
+/// Chats asked from each chat search scope (local, server).
+pub const CHAT_SEARCH_LIMIT: usize = 50;
+
+/// Shorter queries are only searched locally: public chats matching one
+/// or two characters are noise and cost a server round trip.
+pub const MIN_SERVER_SEARCH_CHARS: usize = 3;
+
+/// Whether a chat search for `query` also asks the server.
+pub fn searches_server(query: &str) -> bool {
+    query.chars().count() >= MIN_SERVER_SEARCH_CHARS
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChatLookupError {
+    /// No public chat, user or contact matches the query.
+    NotFound,
+    /// Several contacts match a name, so there is no single chat to open.
+    Ambiguous,
+    Unavailable,
+}
+
+pub trait ChatLookupSource: Send + Sync {
+    /// Finds the chat for a username, phone number or contact name,
+    /// creating the private chat with a user when there is none yet.
+    fn find_chat(&self, query: &ChatQuery) -> Result<ChatSummary, ChatLookupError>;
+
+    /// Chats whose title or username matches `query` among the chats
+    /// known locally, including ones not loaded into the chat list.
+    fn search_chats(&self, query: &str, limit: usize) -> Result<Vec<ChatSummary>, ChatLookupError>;
+
+    /// Public chats and channels matching `query`, asked from the server.
+    fn search_chats_on_server(
+        &self,
+        query: &str,
+        limit: usize,
+    ) -> Result<Vec<ChatSummary>, ChatLookupError>;
+}
+
+impl<T: ChatLookupSource> ChatLookupSource for std::sync::Arc<T> {
+    fn find_chat(&self, query: &ChatQuery) -> Result<ChatSummary, ChatLookupError> {
+        (**self).find_chat(query)
+    }
+
+    fn search_chats(&self, query: &str, limit: usize) -> Result<Vec<ChatSummary>, ChatLookupError> {
+        (**self).search_chats(query, limit)
+    }
+
+    fn search_chats_on_server(
+        &self,
+        query: &str,
+        limit: usize,
+    ) -> Result<Vec<ChatSummary>, ChatLookupError> {
+        (**self).search_chats_on_server(query, limit)
+    }
+}
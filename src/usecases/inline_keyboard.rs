@@ -0,0 +1,32 @@
+use crate::domain::message::CallbackAnswer;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallbackQuery {
+    pub chat_id: i64,
+    pub message_id: i64,
+    pub data: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CallbackQueryError {
+    /// The bot did not answer in time.
+    Timeout,
+    Unavailable,
+}
+
+pub trait CallbackQuerySource: Send + Sync {
+    /// Presses a callback button and waits for the bot's answer.
+    fn get_callback_answer(
+        &self,
+        query: &CallbackQuery,
+    ) -> Result<CallbackAnswer, CallbackQueryError>;
+}
+
+impl<T: CallbackQuerySource> CallbackQuerySource for std::sync::Arc<T> {
+    fn get_callback_answer(
+        &self,
+        query: &CallbackQuery,
+    ) -> Result<CallbackAnswer, CallbackQueryError> {
+        (**self).get_callback_answer(query)
+    }
+}
@@ -0,0 +1,41 @@
+use crate::domain::{chat::ChatSummary, link_target::LinkTarget};
+
+/// Number of messages loaded around a linked message.
+pub const LINKED_MESSAGE_WINDOW: usize = 50;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LinkError {
+    /// Telegram rejected the request: no such chat or message, an expired
+    /// invite, or a chat whose messages have no links.
+    NotFound,
+    /// The invite link needs admin approval; a join request was sent.
+    JoinRequestSent,
+    Unavailable,
+}
+
+pub trait LinkSource: Send + Sync {
+    /// Returns the `t.me` link to a message in a supergroup or channel.
+    fn get_message_link(&self, chat_id: i64, message_id: i64) -> Result<String, LinkError>;
+
+    /// Classifies a Telegram link and resolves the chat or message it
+    /// points to. `window_size` is the number of messages loaded around a
+    /// linked message.
+    fn resolve_link(&self, url: &str, window_size: usize) -> Result<LinkTarget, LinkError>;
+
+    /// Joins a chat by invite link and returns the joined chat.
+    fn join_chat_by_invite_link(&self, invite_link: &str) -> Result<ChatSummary, LinkError>;
+}
+
+impl<T: LinkSource> LinkSource for std::sync::Arc<T> {
+    fn get_message_link(&self, chat_id: i64, message_id: i64) -> Result<String, LinkError> {
+        (**self).get_message_link(chat_id, message_id)
+    }
+
+    fn resolve_link(&self, url: &str, window_size: usize) -> Result<LinkTarget, LinkError> {
+        (**self).resolve_link(url, window_size)
+    }
+
+    fn join_chat_by_invite_link(&self, invite_link: &str) -> Result<ChatSummary, LinkError> {
+        (**self).join_chat_by_invite_link(invite_link)
+    }
+}
@@ -1,10 +1,12 @@
-use crate::domain::chat::ChatSummary;
+use crate::domain::{chat::ChatSummary, chat_folder::ChatListId};
 
 pub const DEFAULT_CHAT_PAGE_SIZE: usize = 50;
 
 #[cfg_attr(not(test), allow(dead_code))]
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ListChatsQuery {
+    /// Main list or one of the user's chat folders.
+    pub list: ChatListId,
     pub limit: usize,
     /// When `true`, bypasses the in-memory cache and reads directly from
     /// TDLib's SQLite — guarantees fresh data for user-initiated refreshes.
@@ -14,6 +16,7 @@ pub struct ListChatsQuery {
 impl Default for ListChatsQuery {
     fn default() -> Self {
         Self {
+            list: ChatListId::Main,
             limit: DEFAULT_CHAT_PAGE_SIZE,
             force: false,
         }
@@ -49,6 +52,7 @@ pub enum ListChatsSourceError {
 pub trait ListChatsSource {
     fn list_chats(
         &self,
+        list: ChatListId,
         limit: usize,
         force: bool,
     ) -> Result<(Vec<ChatSummary>, bool), ListChatsSourceError>;
@@ -60,10 +64,11 @@ where
 {
     fn list_chats(
         &self,
+        list: ChatListId,
         limit: usize,
         force: bool,
     ) -> Result<(Vec<ChatSummary>, bool), ListChatsSourceError> {
-        (*self).list_chats(limit, force)
+        (*self).list_chats(list, limit, force)
     }
 }
 
@@ -73,10 +78,11 @@ where
 {
     fn list_chats(
         &self,
+        list: ChatListId,
         limit: usize,
         force: bool,
     ) -> Result<(Vec<ChatSummary>, bool), ListChatsSourceError> {
-        (**self).list_chats(limit, force)
+        (**self).list_chats(list, limit, force)
     }
 }
 
@@ -95,7 +101,7 @@ pub fn list_chats(
 ) -> Result<ListChatsOutput, ListChatsError> {
     let limit = query.normalized_limit();
     let (chats, all_loaded) = source
-        .list_chats(limit, query.force)
+        .list_chats(query.list, limit, query.force)
         .map_err(map_source_error)?;
 
     Ok(ListChatsOutput { chats, all_loaded })
@@ -119,6 +125,7 @@ mod tests {
     struct StubSource {
         result: Result<Vec<ChatSummary>, ListChatsSourceError>,
         captured_limit: std::sync::Mutex<Option<usize>>,
+        captured_list: std::sync::Mutex<Option<ChatListId>>,
     }
 
     impl StubSource {
@@ -126,6 +133,7 @@ mod tests {
             Self {
                 result,
                 captured_limit: std::sync::Mutex::new(None),
+                captured_list: std::sync::Mutex::new(None),
             }
         }
     }
@@ -133,9 +141,11 @@ mod tests {
     impl ListChatsSource for StubSource {
         fn list_chats(
             &self,
+            list: ChatListId,
             limit: usize,
             _force: bool,
         ) -> Result<(Vec<ChatSummary>, bool), ListChatsSourceError> {
+            *self.captured_list.lock().expect("list lock") = Some(list);
             *self.captured_limit.lock().expect("limit lock") = Some(limit);
             self.result.clone().map(|chats| (chats, false))
         }
@@ -156,8 +166,13 @@ mod tests {
             outgoing_status: OutgoingReadStatus::default(),
             last_message_id: None,
             unread_reaction_count: 0,
+            unread_mention_count: 0,
             is_forum: false,
             unread_topic_count: None,
+            is_muted: false,
+            is_marked_as_unread: false,
+            is_blocked: false,
+            username: None,
         }
     }
 
@@ -196,6 +211,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn passes_folder_list_through() {
+        let source = StubSource::with_result(Ok(vec![]));
+
+        let _ = list_chats(
+            &source,
+            ListChatsQuery {
+                list: ChatListId::Folder(7),
+                ..ListChatsQuery::default()
+            },
+        )
+        .expect("list should succeed");
+
+        assert_eq!(
+            *source.captured_list.lock().expect("list lock"),
+            Some(ChatListId::Folder(7))
+        );
+    }
+
     #[test]
     fn keeps_source_payload_without_mutation() {
         let chats = vec![sample_chat()];
@@ -1,4 +1,7 @@
-use crate::domain::message::Message;
+use crate::domain::{
+    chat::UnreadJumpKind,
+    message::{Message, UnreadMessageWindow},
+};
 
 const DEFAULT_MESSAGES_PAGE_SIZE: usize = 50;
 const MAX_MESSAGES_PAGE_SIZE: usize = 200;
@@ -158,6 +161,74 @@ where
     }
 }
 
+/// Source of history windows detached from the latest page (jump to date,
+/// jump to unread mention/reaction).
+pub trait MessageWindowSource: Send + Sync {
+    /// Lists up to `limit` messages around the last message sent no later
+    /// than `unix_time` (seconds), in chronological order (oldest first).
+    /// Returns an empty list when the chat has no message that old.
+    fn list_messages_around_date(
+        &self,
+        chat_id: i64,
+        topic_id: Option<i32>,
+        unix_time: i64,
+        limit: usize,
+    ) -> Result<Vec<Message>, MessagesSourceError>;
+
+    /// Lists up to `limit` of the chat's oldest messages, in chronological
+    /// order (oldest first).
+    fn list_oldest_messages(
+        &self,
+        chat_id: i64,
+        topic_id: Option<i32>,
+        limit: usize,
+    ) -> Result<Vec<Message>, MessagesSourceError>;
+
+    /// Finds the oldest unread message of `kind` and lists up to `limit`
+    /// messages around it. Returns `None` when nothing of that kind is unread.
+    fn list_messages_around_unread(
+        &self,
+        chat_id: i64,
+        topic_id: Option<i32>,
+        kind: UnreadJumpKind,
+        limit: usize,
+    ) -> Result<Option<UnreadMessageWindow>, MessagesSourceError>;
+}
+
+impl<T> MessageWindowSource for std::sync::Arc<T>
+where
+    T: MessageWindowSource + ?Sized,
+{
+    fn list_messages_around_date(
+        &self,
+        chat_id: i64,
+        topic_id: Option<i32>,
+        unix_time: i64,
+        limit: usize,
+    ) -> Result<Vec<Message>, MessagesSourceError> {
+        (**self).list_messages_around_date(chat_id, topic_id, unix_time, limit)
+    }
+
+    fn list_oldest_messages(
+        &self,
+        chat_id: i64,
+        topic_id: Option<i32>,
+        limit: usize,
+    ) -> Result<Vec<Message>, MessagesSourceError> {
+        (**self).list_oldest_messages(chat_id, topic_id, limit)
+    }
+
+    fn list_messages_around_unread(
+        &self,
+        chat_id: i64,
+        topic_id: Option<i32>,
+        kind: UnreadJumpKind,
+        limit: usize,
+    ) -> Result<Option<UnreadMessageWindow>, MessagesSourceError> {
+        (**self).list_messages_around_unread(chat_id, topic_id, kind, limit)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum LoadMessagesError {
     Unauthorized,
@@ -177,6 +248,42 @@ pub fn load_messages(
     Ok(LoadMessagesOutput { messages })
 }
 
+/// Loads a default-sized history window around `unix_time` (seconds).
+///
+/// When nothing is older than `unix_time` the whole chat is newer, so the
+/// window starts at the chat's oldest messages instead — e.g. when jumping
+/// to the day the chat began. Empty only when the chat has no messages.
+pub fn load_messages_around_date(
+    source: &dyn MessageWindowSource,
+    chat_id: i64,
+    topic_id: Option<i32>,
+    unix_time: i64,
+) -> Result<LoadMessagesOutput, LoadMessagesError> {
+    let mut messages = source
+        .list_messages_around_date(chat_id, topic_id, unix_time, DEFAULT_MESSAGES_PAGE_SIZE)
+        .map_err(map_source_error)?;
+    if messages.is_empty() {
+        messages = source
+            .list_oldest_messages(chat_id, topic_id, DEFAULT_MESSAGES_PAGE_SIZE)
+            .map_err(map_source_error)?;
+    }
+
+    Ok(LoadMessagesOutput { messages })
+}
+
+/// Loads a default-sized history window around the oldest unread message
+/// of `kind`. Returns `None` when there is nothing to jump to.
+pub fn load_messages_around_unread(
+    source: &dyn MessageWindowSource,
+    chat_id: i64,
+    topic_id: Option<i32>,
+    kind: UnreadJumpKind,
+) -> Result<Option<UnreadMessageWindow>, LoadMessagesError> {
+    source
+        .list_messages_around_unread(chat_id, topic_id, kind, DEFAULT_MESSAGES_PAGE_SIZE)
+        .map_err(map_source_error)
+}
+
 fn map_source_error(error: MessagesSourceError) -> LoadMessagesError {
     match error {
         MessagesSourceError::Unauthorized => LoadMessagesError::Unauthorized,
@@ -195,6 +302,8 @@ mod tests {
         captured_topic_id: std::sync::Mutex<Option<Option<i32>>>,
         captured_limit: std::sync::Mutex<Option<usize>>,
         captured_from_message_id: std::sync::Mutex<Option<i64>>,
+        /// Returned by `list_oldest_messages`.
+        oldest: Vec<Message>,
     }
 
     impl StubSource {
@@ -205,6 +314,7 @@ mod tests {
                 captured_topic_id: std::sync::Mutex::new(None),
                 captured_limit: std::sync::Mutex::new(None),
                 captured_from_message_id: std::sync::Mutex::new(None),
+                oldest: Vec::new(),
             }
         }
     }
@@ -241,10 +351,12 @@ mod tests {
             call_info: None,
             reply_to: None,
             forward_info: None,
-            reaction_count: 0,
+            reactions: Vec::new(),
             links: Vec::new(),
             is_edited: false,
             is_service: false,
+            inline_keyboard: Vec::new(),
+            reply_keyboard: None,
         }
     }
 
@@ -381,4 +493,122 @@ mod tests {
 
         assert_eq!(err, LoadMessagesError::ChatNotFound);
     }
+
+    impl MessageWindowSource for StubSource {
+        fn list_messages_around_date(
+            &self,
+            chat_id: i64,
+            topic_id: Option<i32>,
+            unix_time: i64,
+            limit: usize,
+        ) -> Result<Vec<Message>, MessagesSourceError> {
+            *self.captured_chat_id.lock().expect("chat_id lock") = Some(chat_id);
+            *self.captured_topic_id.lock().expect("topic_id lock") = Some(topic_id);
+            *self.captured_limit.lock().expect("limit lock") = Some(limit);
+            *self
+                .captured_from_message_id
+                .lock()
+                .expect("from_message_id lock") = Some(unix_time);
+            self.result.clone()
+        }
+
+        fn list_oldest_messages(
+            &self,
+            _chat_id: i64,
+            _topic_id: Option<i32>,
+            _limit: usize,
+        ) -> Result<Vec<Message>, MessagesSourceError> {
+            Ok(self.oldest.clone())
+        }
+
+        fn list_messages_around_unread(
+            &self,
+            chat_id: i64,
+            topic_id: Option<i32>,
+            _kind: UnreadJumpKind,
+            limit: usize,
+        ) -> Result<Option<UnreadMessageWindow>, MessagesSourceError> {
+            *self.captured_chat_id.lock().expect("chat_id lock") = Some(chat_id);
+            *self.captured_topic_id.lock().expect("topic_id lock") = Some(topic_id);
+            *self.captured_limit.lock().expect("limit lock") = Some(limit);
+            self.result.clone().map(|messages| {
+                messages.last().map(|target| UnreadMessageWindow {
+                    target_message_id: target.id,
+                    messages: messages.clone(),
+                })
+            })
+        }
+    }
+
+    #[test]
+    fn around_unread_passes_scope_and_default_limit() {
+        let source = StubSource::with_result(Ok(vec![sample_message()]));
+
+        let window = load_messages_around_unread(&source, 42, None, UnreadJumpKind::Mention)
+            .expect("load should succeed")
+            .expect("window expected");
+
+        assert_eq!(window.messages.len(), 1);
+        assert_eq!(*source.captured_limit.lock().expect("lock"), Some(50));
+    }
+
+    #[test]
+    fn around_unread_returns_none_when_nothing_unread() {
+        let source = StubSource::with_result(Ok(Vec::new()));
+
+        let window = load_messages_around_unread(&source, 42, None, UnreadJumpKind::Reaction)
+            .expect("load should succeed");
+
+        assert_eq!(window, None);
+    }
+
+    #[test]
+    fn around_date_passes_scope_and_default_limit() {
+        let source = StubSource::with_result(Ok(vec![sample_message()]));
+
+        let output = load_messages_around_date(&source, 42, Some(7), 1_780_000_000)
+            .expect("load should succeed");
+
+        assert_eq!(output.messages.len(), 1);
+        assert_eq!(
+            *source.captured_topic_id.lock().expect("lock"),
+            Some(Some(7))
+        );
+        assert_eq!(*source.captured_limit.lock().expect("lock"), Some(50));
+        assert_eq!(
+            *source.captured_from_message_id.lock().expect("lock"),
+            Some(1_780_000_000)
+        );
+    }
+
+    #[test]
+    fn around_date_on_the_first_day_starts_at_the_oldest_messages() {
+        // Nothing is older than the start of the day the chat began.
+        let mut source = StubSource::with_result(Ok(Vec::new()));
+        source.oldest = vec![sample_message()];
+
+        let output = load_messages_around_date(&source, 42, None, 1_780_000_000)
+            .expect("load should succeed");
+
+        assert_eq!(output.messages, vec![sample_message()]);
+    }
+
+    #[test]
+    fn around_date_is_empty_only_for_an_empty_chat() {
+        let source = StubSource::with_result(Ok(Vec::new()));
+
+        let output = load_messages_around_date(&source, 42, None, 1_780_000_000)
+            .expect("load should succeed");
+
+        assert!(output.messages.is_empty());
+    }
+
+    #[test]
+    fn around_date_maps_source_error() {
+        let source = StubSource::with_result(Err(MessagesSourceError::Unavailable));
+
+        let err = load_messages_around_date(&source, 1, None, 0).expect_err("must fail");
+
+        assert_eq!(err, LoadMessagesError::TemporarilyUnavailable);
+    }
 }
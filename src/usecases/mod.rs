@@ -2,18 +2,25 @@
 
 pub mod background;
 pub mod bootstrap;
+pub mod bot_commands;
 pub mod chat_lifecycle;
+pub mod chat_members;
 pub mod chat_subtitle;
+pub mod contacts;
 pub mod context;
 pub mod contracts;
 pub mod edit_message;
+pub mod go_to_chat;
 pub mod guided_auth;
+pub mod inline_keyboard;
+pub mod links;
 pub mod list_chats;
 pub mod list_forum_topics;
 pub mod load_messages;
 pub mod logout;
 pub mod message_info;
 pub mod message_reactions;
+pub mod new_chat;
 pub mod pty;
 pub mod send_message;
 pub mod send_voice;
@@ -0,0 +1,22 @@
+use crate::domain::new_chat_wizard_state::{CreatedChat, NewChatRequest};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NewChatError {
+    /// Telegram refused the request, e.g. an invalid title or members
+    /// whose privacy settings forbid adding them.
+    Rejected,
+    Unavailable,
+}
+
+pub trait ChatCreator: Send + Sync {
+    /// Creates the chat, adds the members and sets the description.
+    /// Members or a description Telegram refuses do not fail the request;
+    /// they are counted in the returned [`CreatedChat`].
+    fn create_chat(&self, request: &NewChatRequest) -> Result<CreatedChat, NewChatError>;
+}
+
+impl<T: ChatCreator> ChatCreator for std::sync::Arc<T> {
+    fn create_chat(&self, request: &NewChatRequest) -> Result<CreatedChat, NewChatError> {
+        (**self).create_chat(request)
+    }
+}
@@ -3,6 +3,8 @@
 //! This module provides the `MessageSender` trait and `send_message` function
 //! for sending text messages through the Telegram API.
 
+use crate::domain::message_input_state::TextMention;
+
 /// Maximum allowed message length in characters (Telegram protocol limit).
 pub const MAX_MESSAGE_LENGTH: usize = 4096;
 
@@ -15,6 +17,8 @@ pub struct SendMessageCommand {
     pub text: String,
     /// If set, the message is a reply to this message ID.
     pub reply_to_message_id: Option<i64>,
+    /// Mentions by name, as character offsets into `text`.
+    pub mentions: Vec<TextMention>,
 }
 
 /// Errors that can occur at the source level (Telegram API).
@@ -52,6 +56,7 @@ pub trait MessageSender {
         topic_id: Option<i32>,
         text: &str,
         reply_to_message_id: Option<i64>,
+        mentions: &[TextMention],
     ) -> Result<(), SendMessageSourceError>;
 }
 
@@ -62,8 +67,9 @@ impl<T: MessageSender + ?Sized> MessageSender for &T {
         topic_id: Option<i32>,
         text: &str,
         reply_to_message_id: Option<i64>,
+        mentions: &[TextMention],
     ) -> Result<(), SendMessageSourceError> {
-        (*self).send_message(chat_id, topic_id, text, reply_to_message_id)
+        (*self).send_message(chat_id, topic_id, text, reply_to_message_id, mentions)
     }
 }
 
@@ -74,8 +80,9 @@ impl<T: MessageSender + ?Sized> MessageSender for std::sync::Arc<T> {
         topic_id: Option<i32>,
         text: &str,
         reply_to_message_id: Option<i64>,
+        mentions: &[TextMention],
     ) -> Result<(), SendMessageSourceError> {
-        (**self).send_message(chat_id, topic_id, text, reply_to_message_id)
+        (**self).send_message(chat_id, topic_id, text, reply_to_message_id, mentions)
     }
 }
 
@@ -101,16 +108,30 @@ pub fn send_message(
     if text.is_empty() {
         return Err(SendMessageError::EmptyMessage);
     }
-    if text.chars().count() > MAX_MESSAGE_LENGTH {
+    let text_len = text.chars().count();
+    if text_len > MAX_MESSAGE_LENGTH {
         return Err(SendMessageError::MessageTooLong);
     }
 
+    // Mention offsets refer to the untrimmed text.
+    let leading = command.text.chars().count() - command.text.trim_start().chars().count();
+    let mentions: Vec<TextMention> = command
+        .mentions
+        .into_iter()
+        .filter(|m| m.offset >= leading && m.offset - leading + m.length <= text_len)
+        .map(|m| TextMention {
+            offset: m.offset - leading,
+            ..m
+        })
+        .collect();
+
     sender
         .send_message(
             command.chat_id,
             command.topic_id,
             text,
             command.reply_to_message_id,
+            &mentions,
         )
         .map_err(map_source_error)
 }
@@ -132,6 +153,7 @@ mod tests {
         result: Result<(), SendMessageSourceError>,
         captured_chat_id: RefCell<Option<i64>>,
         captured_text: RefCell<Option<String>>,
+        captured_mentions: RefCell<Vec<TextMention>>,
     }
 
     impl StubSender {
@@ -140,6 +162,7 @@ mod tests {
                 result,
                 captured_chat_id: RefCell::new(None),
                 captured_text: RefCell::new(None),
+                captured_mentions: RefCell::new(Vec::new()),
             }
         }
     }
@@ -151,9 +174,11 @@ mod tests {
             _topic_id: Option<i32>,
             text: &str,
             _reply_to_message_id: Option<i64>,
+            mentions: &[TextMention],
         ) -> Result<(), SendMessageSourceError> {
             *self.captured_chat_id.borrow_mut() = Some(chat_id);
             *self.captured_text.borrow_mut() = Some(text.to_owned());
+            *self.captured_mentions.borrow_mut() = mentions.to_vec();
             self.result.clone()
         }
     }
@@ -169,6 +194,7 @@ mod tests {
                 topic_id: None,
                 text: String::new(),
                 reply_to_message_id: None,
+                mentions: Vec::new(),
             },
         );
 
@@ -188,6 +214,7 @@ mod tests {
                 topic_id: None,
                 text: too_long,
                 reply_to_message_id: None,
+                mentions: Vec::new(),
             },
         );
 
@@ -207,6 +234,7 @@ mod tests {
                 topic_id: None,
                 text: exact,
                 reply_to_message_id: None,
+                mentions: Vec::new(),
             },
         );
 
@@ -226,6 +254,7 @@ mod tests {
                 topic_id: None,
                 text,
                 reply_to_message_id: None,
+                mentions: Vec::new(),
             },
         );
 
@@ -243,6 +272,7 @@ mod tests {
                 topic_id: None,
                 text: "   \n\t  ".to_owned(),
                 reply_to_message_id: None,
+                mentions: Vec::new(),
             },
         );
 
@@ -260,6 +290,7 @@ mod tests {
                 topic_id: None,
                 text: "  hello world  ".to_owned(),
                 reply_to_message_id: None,
+                mentions: Vec::new(),
             },
         );
 
@@ -280,6 +311,7 @@ mod tests {
                 topic_id: None,
                 text: "test".to_owned(),
                 reply_to_message_id: None,
+                mentions: Vec::new(),
             },
         );
 
@@ -297,6 +329,7 @@ mod tests {
                 topic_id: None,
                 text: "hello".to_owned(),
                 reply_to_message_id: None,
+                mentions: Vec::new(),
             },
         );
 
@@ -314,6 +347,7 @@ mod tests {
                 topic_id: None,
                 text: "hello".to_owned(),
                 reply_to_message_id: None,
+                mentions: Vec::new(),
             },
         );
 
@@ -331,6 +365,7 @@ mod tests {
                 topic_id: None,
                 text: "hello".to_owned(),
                 reply_to_message_id: None,
+                mentions: Vec::new(),
             },
         );
 
@@ -348,9 +383,41 @@ mod tests {
                 topic_id: None,
                 text: "hello".to_owned(),
                 reply_to_message_id: None,
+                mentions: Vec::new(),
             },
         );
 
         assert_eq!(result, Err(SendMessageError::TemporarilyUnavailable));
     }
+
+    #[test]
+    fn mention_offsets_follow_trimmed_text() {
+        let sender = StubSender::with_result(Ok(()));
+
+        let result = send_message(
+            &sender,
+            SendMessageCommand {
+                chat_id: 1,
+                topic_id: None,
+                text: "  hi Bob ".to_owned(),
+                reply_to_message_id: None,
+                mentions: vec![TextMention {
+                    offset: 5,
+                    length: 3,
+                    user_id: 7,
+                }],
+            },
+        );
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(sender.captured_text.borrow().as_deref(), Some("hi Bob"));
+        assert_eq!(
+            *sender.captured_mentions.borrow(),
+            vec![TextMention {
+                offset: 3,
+                length: 3,
+                user_id: 7,
+            }]
+        );
+    }
 }
@@ -6,18 +6,35 @@ use crate::{
     usecases::background::TaskDispatcher,
 };
 
-use super::{chat_open, OrchestratorCtx};
+use super::{
+    chat_list, chat_open, chat_search, completion, contacts, go_to_chat, inline_buttons,
+    jump_to_date, links, message_actions, new_chat, pending_ops, unread_jump, OrchestratorCtx,
+};
 
 pub(super) fn handle_background_result<D: TaskDispatcher>(
     ctx: &mut OrchestratorCtx<'_, D>,
     result: BackgroundTaskResult,
 ) {
     match result {
-        BackgroundTaskResult::ChatListLoaded { result, all_loaded } => {
+        BackgroundTaskResult::ChatListLoaded {
+            list,
+            result,
+            all_loaded,
+        } => {
             *ctx.chat_list_in_flight = false;
             let was_pending = std::mem::take(ctx.chat_list_refresh_pending);
             let pending_force = std::mem::take(ctx.chat_list_pending_force);
 
+            // The user switched folders while this fetch ran; the pending
+            // re-dispatch below loads the list now shown.
+            if list != ctx.state.chat_list().active_list() {
+                tracing::debug!(?list, "background: discarding stale chat list result");
+                if was_pending {
+                    super::chat_list::dispatch_chat_list_refresh(ctx, pending_force);
+                }
+                return;
+            }
+
             // When a re-dispatch is about to happen, defer the user notification
             // to the next completion — the pending result will be more up-to-date.
             let user_requested = if was_pending {
@@ -69,6 +86,7 @@ pub(super) fn handle_background_result<D: TaskDispatcher>(
                 );
                 super::chat_list::dispatch_chat_list_refresh(ctx, pending_force);
             }
+            super::chat_list::load_more_matching_chats(ctx);
         }
         BackgroundTaskResult::ForumUnreadCountsLoaded { counts } => {
             *ctx.forum_warmup_in_flight = false;
@@ -147,6 +165,13 @@ pub(super) fn handle_background_result<D: TaskDispatcher>(
                 return;
             }
 
+            // A refresh that raced with a jump must not yank the user back
+            // from the history window to the latest page.
+            if ctx.state.open_chat().is_history_window() {
+                tracing::debug!(chat_id, "background: open chat shows a history window");
+                return;
+            }
+
             match result {
                 Ok(messages) => {
                     tracing::debug!(
@@ -205,6 +230,24 @@ pub(super) fn handle_background_result<D: TaskDispatcher>(
                 }
             }
         }
+        BackgroundTaskResult::MessagesAroundDateLoaded {
+            chat_id,
+            topic_id,
+            target_unix_ms,
+            result,
+        } => jump_to_date::handle_messages_around_date_loaded(
+            ctx,
+            chat_id,
+            topic_id,
+            target_unix_ms,
+            result,
+        ),
+        BackgroundTaskResult::UnreadWindowLoaded {
+            chat_id,
+            topic_id,
+            kind,
+            result,
+        } => unread_jump::handle_unread_window_loaded(ctx, chat_id, topic_id, kind, result),
         BackgroundTaskResult::MessageSent {
             chat_id,
             original_text,
@@ -384,6 +427,32 @@ pub(super) fn handle_background_result<D: TaskDispatcher>(
                 }
             }
         }
+        BackgroundTaskResult::MessagesDeleted {
+            chat_id,
+            message_ids,
+            result,
+        } => pending_ops::handle_messages_deleted(ctx, chat_id, &message_ids, result),
+        BackgroundTaskResult::MessagesForwarded {
+            to_chat_id,
+            count,
+            result,
+        } => message_actions::handle_messages_forwarded(ctx, to_chat_id, count, result),
+        BackgroundTaskResult::DeletePermissionsLoaded {
+            chat_id,
+            message_ids,
+            result,
+        } => {
+            if let Some(confirm) = ctx
+                .state
+                .delete_confirm_mut()
+                .filter(|c| c.chat_id() == chat_id && c.message_ids() == message_ids)
+            {
+                if let Err(ref e) = result {
+                    tracing::debug!(chat_id, code = e.code, "delete permissions unavailable");
+                }
+                confirm.resolve(result.ok());
+            }
+        }
         BackgroundTaskResult::MessageInfoLoaded {
             chat_id,
             message_id,
@@ -412,6 +481,85 @@ pub(super) fn handle_background_result<D: TaskDispatcher>(
                 }
             }
         }
+        BackgroundTaskResult::CallbackAnswerLoaded {
+            chat_id,
+            message_id,
+            result,
+        } => inline_buttons::handle_callback_answer_loaded(ctx, chat_id, message_id, result),
+        BackgroundTaskResult::MessageLinkLoaded {
+            chat_id,
+            message_id,
+            result,
+        } => links::handle_message_link_loaded(ctx, chat_id, message_id, result),
+        BackgroundTaskResult::LinkResolved { url, result } => {
+            links::handle_link_resolved(ctx, url, result)
+        }
+        BackgroundTaskResult::ChatJoined { result, .. } => links::handle_chat_joined(ctx, result),
+        BackgroundTaskResult::ContactsLoaded { result } => {
+            contacts::handle_contacts_loaded(ctx, result)
+        }
+        BackgroundTaskResult::ContactAdded { chat_id, result } => {
+            contacts::handle_contact_added(ctx, chat_id, result)
+        }
+        BackgroundTaskResult::ContactRemoved { user_id, result } => {
+            contacts::handle_contact_removed(ctx, user_id, result)
+        }
+        BackgroundTaskResult::ContactChatLoaded { user_id, result } => {
+            contacts::handle_contact_chat_loaded(ctx, user_id, result)
+        }
+        BackgroundTaskResult::ChatFound { query, result } => {
+            go_to_chat::handle_chat_found(ctx, &query, result)
+        }
+        BackgroundTaskResult::ChatsSearched {
+            query,
+            on_server,
+            result,
+        } => chat_search::handle_chats_searched(ctx, query, on_server, result),
+        BackgroundTaskResult::ChatMovedToList {
+            chat_id,
+            list,
+            result,
+        } => chat_list::handle_chat_moved_to_list(ctx, chat_id, list, result),
+        BackgroundTaskResult::ChatMuteChanged {
+            chat_id,
+            muted,
+            result,
+        } => chat_list::handle_chat_mute_changed(ctx, chat_id, muted, result),
+        BackgroundTaskResult::ChatMarkedAsUnread { marked, result, .. } => {
+            let message = if marked {
+                "Failed to mark chat as unread"
+            } else {
+                "Failed to clear unread mark"
+            };
+            chat_list::handle_chat_list_edit_failed(ctx, result, message)
+        }
+        BackgroundTaskResult::ChatPinToggled { pinned, result, .. } => {
+            let message = if pinned {
+                "Failed to pin chat"
+            } else {
+                "Failed to unpin chat"
+            };
+            chat_list::handle_chat_list_edit_failed(ctx, result, message)
+        }
+        BackgroundTaskResult::PinnedChatsReordered { result, .. } => {
+            chat_list::handle_chat_list_edit_failed(ctx, result, "Failed to reorder pinned chats")
+        }
+        BackgroundTaskResult::ChatManaged {
+            chat_id,
+            op,
+            result,
+        } => chat_list::handle_chat_managed(ctx, chat_id, op, result),
+        BackgroundTaskResult::ChatCreated { title, result } => {
+            new_chat::handle_chat_created(ctx, title, result)
+        }
+        BackgroundTaskResult::BotCommandsLoaded { chat_id, result } => {
+            completion::handle_bot_commands_loaded(ctx, chat_id, result)
+        }
+        BackgroundTaskResult::ChatMembersLoaded {
+            chat_id,
+            query,
+            result,
+        } => completion::handle_chat_members_loaded(ctx, chat_id, query, result),
         BackgroundTaskResult::AvailableReactionsLoaded {
             chat_id,
             message_id,
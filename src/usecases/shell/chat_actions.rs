@@ -0,0 +1,52 @@
+use std::time::Instant;
+
+use crate::{domain::chat_action::ChatAction, usecases::background::TaskDispatcher};
+
+use super::OrchestratorCtx;
+
+/// Broadcasts `action` in the open chat. Throttled so that an ongoing
+/// activity is only re-sent every few seconds; no-op when chat actions
+/// are disabled in the config.
+pub(super) fn send_chat_action<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    action: ChatAction,
+) {
+    if !ctx.send_chat_actions {
+        return;
+    }
+    let Some(chat_id) = ctx.state.open_chat().chat_id() else {
+        return;
+    };
+    let topic_id = ctx.state.open_chat().topic_id();
+
+    if ctx
+        .chat_action_throttle
+        .begin(chat_id, topic_id, action, Instant::now())
+    {
+        ctx.dispatcher
+            .dispatch_send_chat_action(chat_id, topic_id, action);
+    }
+}
+
+/// Cancels the active chat action, if one was broadcast.
+pub(super) fn cancel_chat_action<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>) {
+    if let Some((chat_id, topic_id)) = ctx.chat_action_throttle.finish() {
+        ctx.dispatcher
+            .dispatch_send_chat_action(chat_id, topic_id, ChatAction::Cancel);
+    }
+}
+
+/// Updates the typing action after the message input text changed:
+/// typing while there is text, cancelled once the input is cleared.
+/// Editing an existing message is not announced.
+pub(super) fn handle_input_changed<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>) {
+    if ctx.state.message_input().editing().is_some() {
+        return;
+    }
+
+    if ctx.state.message_input().text().is_empty() {
+        cancel_chat_action(ctx);
+    } else {
+        send_chat_action(ctx, ChatAction::Typing);
+    }
+}
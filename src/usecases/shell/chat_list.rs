@@ -1,6 +1,20 @@
-use crate::{domain::chat_list_state::ChatListUiState, usecases::background::TaskDispatcher};
+use crate::{
+    domain::{
+        chat::{ChatType, MuteDuration},
+        chat_folder::ChatListId,
+        chat_list_filter::ChatListFilter,
+        chat_list_state::ChatListUiState,
+        chat_manage_state::{ChatConfirmKind, ChatConfirmState, ChatManageOp},
+        events::BackgroundError,
+    },
+    usecases::background::TaskDispatcher,
+};
 
-use super::OrchestratorCtx;
+use super::{
+    chat_open,
+    pending_ops::{self, PendingOperation},
+    OrchestratorCtx,
+};
 
 pub(super) fn dispatch_chat_list_refresh<D: TaskDispatcher>(
     ctx: &mut OrchestratorCtx<'_, D>,
@@ -22,14 +36,358 @@ pub(super) fn dispatch_chat_list_refresh<D: TaskDispatcher>(
     // after error, or empty state).  When the list is already visible
     // (Ready), keep showing stale data while the background fetch runs —
     // this prevents the "blink" where the chat list is momentarily replaced
-    // by a loading indicator on every Telegram update.
-    if ctx.state.chat_list().ui_state() != ChatListUiState::Ready {
+    // by a loading indicator on every Telegram update.  A list that is
+    // already Loading (e.g. just switched to a folder) keeps its restored
+    // pagination.
+    if !matches!(
+        ctx.state.chat_list().ui_state(),
+        ChatListUiState::Ready | ChatListUiState::Loading
+    ) {
         ctx.state.chat_list_mut().set_loading();
     }
 
     *ctx.chat_list_in_flight = true;
+    let list = ctx.state.chat_list().active_list();
     let limit = ctx.state.chat_list().total_limit();
-    ctx.dispatcher.dispatch_chat_list(force, limit);
+    ctx.dispatcher.dispatch_chat_list(list, force, limit);
+}
+
+/// Switches to the next (or previous) chat folder tab and loads it.
+pub(super) fn cycle_chat_folder<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    forward: bool,
+) {
+    if !ctx.state.chat_list_mut().cycle_list(forward) {
+        return;
+    }
+    dispatch_chat_list_refresh(ctx, false);
+}
+
+/// Drills into the archived chats from the "Archive" row.
+pub(super) fn open_archive<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>) {
+    ctx.state.chat_list_mut().open_archive();
+    dispatch_chat_list_refresh(ctx, false);
+}
+
+pub(super) fn close_archive<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>) {
+    if !ctx.state.chat_list_mut().close_archive() {
+        return;
+    }
+    dispatch_chat_list_refresh(ctx, false);
+}
+
+/// Turns a chat list filter on or off. The selected chat stays selected
+/// when it still matches.
+pub(super) fn toggle_chat_filter<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    filter: ChatListFilter,
+) {
+    ctx.state.chat_list_mut().toggle_filter(filter);
+    let indicator = ctx.state.chat_list().filters().indicator();
+    if indicator.is_empty() {
+        ctx.state.set_notification("Chat filters cleared");
+    } else {
+        ctx.state.set_notification(format!("Showing {indicator}"));
+    }
+    load_more_matching_chats(ctx);
+}
+
+pub(super) fn clear_chat_filters<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>) {
+    if ctx.state.chat_list_mut().clear_filters() {
+        ctx.state.set_notification("Chat filters cleared");
+    } else {
+        ctx.state.set_notification("No chat filters are on");
+    }
+}
+
+/// Fetches the next page when filters leave too few matching chats to
+/// scroll through, so matches further down the list still show up.
+pub(super) fn load_more_matching_chats<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>) {
+    let chat_list = ctx.state.chat_list();
+    if chat_list.filters().is_empty() || !chat_list.needs_more_chats() || *ctx.chat_list_in_flight {
+        return;
+    }
+    ctx.state.chat_list_mut().request_more_chats();
+    dispatch_chat_list_refresh(ctx, false);
+}
+
+/// Archives (`list` is `Archive`) or unarchives (`Main`) the selected chat.
+/// The chat leaves the shown list right away; TDLib's position updates
+/// settle the rest.
+pub(super) fn move_selected_chat<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    list: ChatListId,
+) {
+    let Some(chat) = ctx.state.chat_list().selected_chat() else {
+        return;
+    };
+    let (chat_id, title) = (chat.chat_id, chat.title.clone());
+    let active = ctx.state.chat_list().active_list();
+
+    let archiving = list == ChatListId::Archive;
+    if archiving && active == ChatListId::Archive {
+        ctx.state.set_notification("Chat is already archived");
+        return;
+    }
+    if !archiving && active != ChatListId::Archive {
+        ctx.state.set_notification("Chat is not archived");
+        return;
+    }
+
+    ctx.dispatcher.dispatch_move_chat_to_list(chat_id, list);
+    // Folders may keep archived chats, so only the main list and the
+    // archive drop the chat optimistically.
+    if !matches!(active, ChatListId::Folder(_)) {
+        ctx.state.chat_list_mut().remove_chat(chat_id);
+    }
+    let verb = if archiving { "Archived" } else { "Unarchived" };
+    ctx.state.set_notification(format!("{verb} {title}"));
+}
+
+/// Mutes the selected chat for `mute_for`, or unmutes it when `None`.
+pub(super) fn mute_selected_chat<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    mute_for: Option<MuteDuration>,
+) {
+    let Some(chat) = ctx.state.chat_list().selected_chat() else {
+        return;
+    };
+    let (chat_id, title, is_muted) = (chat.chat_id, chat.title.clone(), chat.is_muted);
+
+    if mute_for.is_none() && !is_muted {
+        ctx.state.set_notification("Chat is not muted");
+        return;
+    }
+
+    ctx.dispatcher.dispatch_set_chat_mute(chat_id, mute_for);
+    ctx.state
+        .chat_list_mut()
+        .set_chat_muted(chat_id, mute_for.is_some());
+    match mute_for {
+        Some(duration) => ctx
+            .state
+            .set_notification(format!("Muted {title} {}", duration.label())),
+        None => ctx.state.set_notification(format!("Unmuted {title}")),
+    }
+}
+
+/// Pins or unpins the selected chat in the shown list. TDLib's position
+/// update moves it into or out of the pinned section.
+pub(super) fn toggle_selected_chat_pin<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>) {
+    let Some(chat) = ctx.state.chat_list().selected_chat() else {
+        return;
+    };
+    let (chat_id, title, pinned) = (chat.chat_id, chat.title.clone(), !chat.is_pinned);
+    let list = ctx.state.chat_list().active_list();
+
+    ctx.dispatcher
+        .dispatch_set_chat_pinned(chat_id, list, pinned);
+    let verb = if pinned { "Pinned" } else { "Unpinned" };
+    ctx.state.set_notification(format!("{verb} {title}"));
+}
+
+/// Moves the selected pinned chat one place up or down among the pinned
+/// chats and sends the new order.
+pub(super) fn move_selected_pinned_chat<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    up: bool,
+) {
+    let Some(is_pinned) = ctx.state.chat_list().selected_chat().map(|c| c.is_pinned) else {
+        return;
+    };
+    if !is_pinned {
+        ctx.state.set_notification("Only pinned chats can be moved");
+        return;
+    }
+    if let Some(chat_ids) = ctx.state.chat_list_mut().move_selected_pinned(up) {
+        let list = ctx.state.chat_list().active_list();
+        ctx.dispatcher.dispatch_set_pinned_chats(list, chat_ids);
+    }
+}
+
+/// Marks the selected chat as unread, or clears the mark when it is set.
+pub(super) fn toggle_selected_chat_unread_mark<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+) {
+    let Some(chat) = ctx.state.chat_list().selected_chat() else {
+        return;
+    };
+    let (chat_id, title, marked) = (chat.chat_id, chat.title.clone(), !chat.is_marked_as_unread);
+
+    ctx.dispatcher
+        .dispatch_set_chat_marked_as_unread(chat_id, marked);
+    ctx.state
+        .chat_list_mut()
+        .set_chat_marked_as_unread(chat_id, marked);
+    if marked {
+        ctx.state
+            .set_notification(format!("Marked {title} as unread"));
+    } else {
+        ctx.state
+            .set_notification(format!("Cleared unread mark on {title}"));
+    }
+}
+
+/// Opens the confirmation popup for leaving (groups, channels) or deleting
+/// (private chats) the selected chat, or for clearing its history.
+pub(super) fn open_chat_confirm<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    kind: ChatConfirmKind,
+) {
+    let Some(chat) = ctx.state.chat_list().selected_chat() else {
+        return;
+    };
+    let confirm = ChatConfirmState::new(chat, kind);
+    ctx.state.open_chat_confirm(confirm);
+}
+
+/// Keys of the chat confirmation popup: the option keys it lists, and
+/// `n` / `q` / `Esc` to cancel.
+pub(super) fn handle_chat_confirm_key<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    key: &str,
+) {
+    let Some(confirm) = ctx.state.chat_confirm() else {
+        return;
+    };
+    if matches!(key, "n" | "q" | "esc") {
+        ctx.state.close_chat_confirm();
+        return;
+    }
+    let Some(op) = confirm.op_for_key(key) else {
+        return;
+    };
+    if let Some(confirm) = ctx.state.close_chat_confirm() {
+        leave_or_clear_chat(ctx, confirm.chat_id(), confirm.title().to_owned(), op);
+    }
+}
+
+/// Queues `op` for the undo grace period. A left or deleted chat leaves
+/// the list right away and is closed if it is open; undo brings it back.
+fn leave_or_clear_chat<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    chat_id: i64,
+    title: String,
+    op: ChatManageOp,
+) {
+    if op.removes_chat() {
+        ctx.state.chat_list_mut().hide_chat(chat_id);
+        if ctx.state.open_chat().chat_id() == Some(chat_id) {
+            chat_open::close_tdlib_chat(ctx);
+            ctx.state.open_chat_mut().clear();
+        }
+    }
+    pending_ops::schedule(ctx, PendingOperation::ManageChat { chat_id, title, op });
+}
+
+/// Blocks the user of the selected private chat, or unblocks them.
+pub(super) fn toggle_selected_chat_block<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>) {
+    let Some(chat) = ctx.state.chat_list().selected_chat() else {
+        return;
+    };
+    if chat.chat_type != ChatType::Private {
+        ctx.state
+            .set_notification("Only users in private chats can be blocked");
+        return;
+    }
+    let (chat_id, title, blocked) = (chat.chat_id, chat.title.clone(), !chat.is_blocked);
+
+    let op = ChatManageOp::SetBlocked(blocked);
+    ctx.state.chat_list_mut().set_chat_blocked(chat_id, blocked);
+    ctx.dispatcher.dispatch_manage_chat(chat_id, op);
+    ctx.state.set_notification(op.done_message(&title));
+}
+
+/// A left or deleted chat is also forgotten by the recent chats. On
+/// failure the optimistic change (removed chat, block flag) is undone by
+/// reloading the list.
+pub(super) fn handle_chat_managed<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    chat_id: i64,
+    op: ChatManageOp,
+    result: Result<(), BackgroundError>,
+) {
+    if op.removes_chat() {
+        ctx.state.chat_list_mut().unhide_chat(chat_id);
+        if result.is_ok() {
+            ctx.recent_chats.remove_chat(chat_id);
+        }
+    }
+    if let Err(error) = result {
+        tracing::warn!(
+            chat_id,
+            ?op,
+            code = error.code,
+            "background: chat management failed"
+        );
+        ctx.state.set_notification(op.failure_message());
+        dispatch_chat_list_refresh(ctx, true);
+    }
+}
+
+/// Failures of the chat list edits above (unread mark, pin, pin order)
+/// undo the optimistic change by reloading the list.
+pub(super) fn handle_chat_list_edit_failed<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    result: Result<(), BackgroundError>,
+    message: &str,
+) {
+    if let Err(error) = result {
+        tracing::warn!(
+            code = error.code,
+            message,
+            "background: chat list edit failed"
+        );
+        ctx.state.set_notification(message);
+        dispatch_chat_list_refresh(ctx, true);
+    }
+}
+
+/// On failure the optimistic mute flag is undone by reloading the list.
+pub(super) fn handle_chat_mute_changed<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    chat_id: i64,
+    muted: bool,
+    result: Result<(), BackgroundError>,
+) {
+    if let Err(error) = result {
+        tracing::warn!(
+            chat_id,
+            code = error.code,
+            "background: set chat mute failed"
+        );
+        let message = if muted {
+            "Failed to mute chat"
+        } else {
+            "Failed to unmute chat"
+        };
+        ctx.state.set_notification(message);
+        dispatch_chat_list_refresh(ctx, true);
+    }
+}
+
+/// Success needs no handling: TDLib's chat position updates move the chat.
+/// On failure the optimistic removal is undone by reloading the list.
+pub(super) fn handle_chat_moved_to_list<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    chat_id: i64,
+    list: ChatListId,
+    result: Result<(), BackgroundError>,
+) {
+    if let Err(error) = result {
+        tracing::warn!(
+            chat_id,
+            code = error.code,
+            "background: move chat to list failed"
+        );
+        let message = if list == ChatListId::Archive {
+            "Failed to archive chat"
+        } else {
+            "Failed to unarchive chat"
+        };
+        ctx.state.set_notification(message);
+        dispatch_chat_list_refresh(ctx, true);
+    }
 }
 
 pub(super) fn mark_selected_chat_as_read<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>) {
@@ -37,6 +395,19 @@ pub(super) fn mark_selected_chat_as_read<D: TaskDispatcher>(ctx: &mut Orchestrat
         return;
     };
 
+    // Reading a chat also clears a manual "marked as unread".
+    if chat.is_marked_as_unread {
+        let chat_id = chat.chat_id;
+        ctx.dispatcher
+            .dispatch_set_chat_marked_as_unread(chat_id, false);
+        ctx.state
+            .chat_list_mut()
+            .set_chat_marked_as_unread(chat_id, false);
+    }
+
+    let Some(chat) = ctx.state.chat_list().selected_chat() else {
+        return;
+    };
     if chat.unread_count == 0 {
         return;
     }
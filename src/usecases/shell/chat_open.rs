@@ -1,7 +1,8 @@
 use crate::{
     domain::{
-        chat::ChatType,
+        chat::{ChatSummary, ChatType},
         open_chat_state::{MessageSource, OpenChatUiState},
+        recent_chats::RecentChat,
         shell_state::ActivePane,
     },
     usecases::{background::TaskDispatcher, chat_subtitle::ChatSubtitleQuery},
@@ -14,19 +15,35 @@ pub(super) fn open_selected_chat<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_
         return;
     };
 
-    let chat_id = selected.chat_id;
-    let chat_title = selected.title.clone();
-    let chat_type = selected.chat_type;
-    let is_forum = selected.is_forum;
+    let chat = selected.clone();
+    open_chat(ctx, &chat);
+}
 
+/// Opens `chat`, whether or not it is in the chat list (e.g. a chat
+/// reached through a `t.me` link).
+pub(super) fn open_chat<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>, chat: &ChatSummary) {
     // Forum chats route into the topic-list panel rather than directly into
     // the messages view. The active pane stays ChatList (left panel just
     // renders topics instead of chats) — see ui/view/chat_list.rs.
-    if is_forum {
-        super::forum::enter_forum(ctx, chat_id, chat_title);
+    if chat.is_forum {
+        super::forum::enter_forum(ctx, chat.chat_id, chat.title.clone());
         return;
     }
 
+    open_chat_messages(ctx, chat.chat_id, chat.title.clone(), chat.chat_type);
+}
+
+/// Opens a non-forum chat in the messages pane and records it as the most
+/// recent chat.
+pub(super) fn open_chat_messages<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    chat_id: i64,
+    chat_title: String,
+    chat_type: ChatType,
+) {
+    ctx.recent_chats
+        .record(RecentChat::chat(chat_id, chat_title.clone(), chat_type));
+
     // Non-forum chats focus the messages pane.
     ctx.state.set_active_pane(ActivePane::Messages);
 
@@ -0,0 +1,86 @@
+//! Chat search popup (`/` in the chat list): ranks loaded chats as the
+//! user types and asks TDLib for the chats the list has not paged in yet,
+//! once the user pauses typing.
+
+use std::time::Duration;
+
+use crate::{
+    domain::{chat::ChatSummary, events::BackgroundError},
+    usecases::{background::TaskDispatcher, go_to_chat::searches_server},
+};
+
+use super::{links, OrchestratorCtx};
+
+/// How long the query must stay unchanged before TDLib is asked.
+const SEARCH_DEBOUNCE: Duration = Duration::from_millis(300);
+
+pub(super) fn open_chat_search<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>) {
+    ctx.state.open_chat_search();
+}
+
+pub(super) fn handle_chat_search_key<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    key: &str,
+) {
+    let Some(search) = ctx.state.chat_search_mut() else {
+        return;
+    };
+
+    match key {
+        "esc" => ctx.state.close_chat_search(),
+        "enter" => {
+            let chat = search.selected_chat().cloned();
+            ctx.state.close_chat_search();
+            if let Some(chat) = chat {
+                links::open_linked_chat(ctx, &chat);
+            }
+        }
+        "down" | "tab" => search.select_next(),
+        "up" | "backtab" => search.select_previous(),
+        "backspace" => search.delete_char_before(),
+        k if k.chars().count() == 1 => {
+            let ch = k.chars().next().unwrap();
+            search.insert_char(ch);
+        }
+        _ => {}
+    }
+}
+
+/// Called on every tick: asks TDLib for chats matching the query once the
+/// user paused typing, unless that search already ran.
+pub(super) fn search_when_typing_paused<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>) {
+    let Some(search) = ctx.state.chat_search_mut() else {
+        return;
+    };
+    if search.begin_request_after(SEARCH_DEBOUNCE) {
+        ctx.dispatcher
+            .dispatch_search_chats(search.query().to_owned());
+    }
+}
+
+pub(super) fn handle_chats_searched<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    query: String,
+    on_server: bool,
+    result: Result<Vec<ChatSummary>, BackgroundError>,
+) {
+    let chats = result.unwrap_or_else(|error| {
+        tracing::warn!(
+            on_server,
+            code = error.code,
+            "background: chat search failed"
+        );
+        Vec::new()
+    });
+    tracing::debug!(
+        on_server,
+        count = chats.len(),
+        "background: chat search results"
+    );
+    let Some(search) = ctx.state.chat_search_mut() else {
+        return;
+    };
+    // Short queries get no server batch, so the local one is the last.
+    let done = on_server || !searches_server(&query);
+    search.store_found(query, chats, done);
+}
@@ -9,7 +9,9 @@ use super::{chat_list, OrchestratorCtx};
 ///
 /// - `NewMessage`: inserts into `MessageCache` for any chat (warm cache passively)
 /// - `MessagesDeleted`: removes from `MessageCache`
-/// - `ChatMetadataChanged`: triggers chat list refresh
+/// - `ChatMetadataChanged` / `NotificationDefaultsChanged`: trigger chat list refresh
+/// - `OutboxRead`: flips read ticks in place (cache and open chat)
+/// - `ChatFoldersChanged` / `UnreadChatCountChanged`: update the folder tabs
 ///
 /// For the currently open chat, also dispatches a message refresh.
 pub(super) fn handle_chat_updates<D: TaskDispatcher>(
@@ -53,20 +55,34 @@ pub(super) fn handle_chat_updates<D: TaskDispatcher>(
                     reload_chat_ids.push(chat_id);
                 }
             }
+            ChatUpdate::OutboxRead {
+                chat_id,
+                last_read_outbox_message_id,
+            } => {
+                ctx.state
+                    .message_cache_mut()
+                    .apply_outbox_read(chat_id, last_read_outbox_message_id);
+                if ctx.state.open_chat().chat_id() == Some(chat_id) {
+                    ctx.state
+                        .open_chat_mut()
+                        .apply_outbox_read(last_read_outbox_message_id);
+                }
+                should_refresh_chat_list = true;
+            }
             ChatUpdate::MessageReactionsChanged {
                 chat_id,
                 message_id,
-                reaction_count,
+                reactions,
             } => {
-                ctx.state.message_cache_mut().update_reaction_count(
+                ctx.state.message_cache_mut().update_reactions(
                     chat_id,
                     message_id,
-                    reaction_count,
+                    reactions.clone(),
                 );
                 if ctx.state.open_chat().chat_id() == Some(chat_id) {
                     ctx.state
                         .open_chat_mut()
-                        .update_message_reaction_count(message_id, reaction_count);
+                        .update_message_reactions(message_id, reactions);
                 }
             }
             ChatUpdate::UserStatusChanged { user_id } => {
@@ -146,6 +162,28 @@ pub(super) fn handle_chat_updates<D: TaskDispatcher>(
                     ctx.dispatcher.dispatch_load_forum_topics(chat_id);
                 }
             }
+            ChatUpdate::NotificationDefaultsChanged => should_refresh_chat_list = true,
+            ChatUpdate::ChatFoldersChanged {
+                folders,
+                main_position,
+            } => {
+                // A deleted active folder falls back to the main list, which
+                // then needs loading.
+                let switched = ctx
+                    .state
+                    .chat_list_mut()
+                    .set_folders(folders, main_position);
+                should_refresh_chat_list |= switched;
+            }
+            ChatUpdate::UnreadChatCountChanged {
+                list,
+                unread_count,
+                total_count,
+            } => {
+                ctx.state
+                    .chat_list_mut()
+                    .set_chat_counts(list, unread_count, total_count);
+            }
         }
     }
 
@@ -300,6 +338,12 @@ pub(super) fn maybe_refresh_open_chat_messages<D: TaskDispatcher>(
         return;
     }
 
+    // The history window stays put until the user jumps back to the latest
+    // messages; a refresh would replace it with the newest page.
+    if ctx.state.open_chat().is_history_window() {
+        return;
+    }
+
     if !affected_chat_ids.contains(&open_id) {
         return;
     }
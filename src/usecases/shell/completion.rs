@@ -0,0 +1,192 @@
+use crate::{
+    domain::{
+        bot_command::{command_completions, BotCommand},
+        chat::ChatType,
+        chat_member::{mention_completions, ChatMember},
+        completion_state::{
+            find_completion_token, CompletionKind, CompletionState, CompletionToken,
+        },
+        emoji::shortcode_completions,
+        events::BackgroundError,
+        shell_state::ActivePane,
+    },
+    usecases::background::TaskDispatcher,
+};
+
+use super::{chat_actions, OrchestratorCtx};
+
+/// Handles popup navigation while completions are shown.
+/// Returns `true` if the key was consumed.
+pub(super) fn handle_completion_key<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    key: &str,
+) -> bool {
+    let Some(completion) = ctx.state.completion_mut() else {
+        return false;
+    };
+
+    match key {
+        "tab" => accept(ctx),
+        "down" => completion.select_next(),
+        "up" | "backtab" => completion.select_previous(),
+        "esc" => ctx.state.set_completion(None),
+        _ => return false,
+    }
+    true
+}
+
+/// Replaces the token being typed with the selected candidate.
+fn accept<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>) {
+    let Some(completion) = ctx.state.completion() else {
+        return;
+    };
+    let start = completion.token_start();
+    let item = completion.selected_item().clone();
+
+    let input = ctx.state.message_input_mut();
+    input.replace_before_cursor(start, &item.insert_text);
+    if let Some(user_id) = item.mention_user_id {
+        input.add_mention(user_id, item.insert_text.trim_end());
+    }
+    ctx.state.set_completion(None);
+    chat_actions::handle_input_changed(ctx);
+}
+
+/// Recomputes the completion popup for the token under the input cursor.
+pub(super) fn refresh<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>) {
+    let completion = if ctx.state.active_pane() == ActivePane::MessageInput
+        && ctx.state.message_input().editing().is_none()
+    {
+        let input = ctx.state.message_input();
+        find_completion_token(input.text(), input.cursor_position()).and_then(|token| {
+            match token.kind {
+                CompletionKind::BotCommand => bot_command_completion(ctx, &token),
+                CompletionKind::Mention => mention_completion(ctx, &token),
+                CompletionKind::Emoji => {
+                    CompletionState::new(token.start, shortcode_completions(&token.query))
+                }
+            }
+        })
+    } else {
+        None
+    };
+    ctx.state.set_completion(completion);
+}
+
+/// Commands of the open chat's bots matching the token. The first `/`
+/// in a chat triggers loading them; the popup appears once they arrive.
+fn bot_command_completion<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    token: &CompletionToken,
+) -> Option<CompletionState> {
+    let chat_id = ctx.state.open_chat().chat_id()?;
+    if !chat_may_have_bots(ctx, chat_id) {
+        return None;
+    }
+
+    if ctx.state.bot_commands_mut().begin_request(chat_id) {
+        ctx.dispatcher.dispatch_load_bot_commands(chat_id);
+        return None;
+    }
+
+    let commands = ctx.state.bot_commands().get(chat_id)?;
+    CompletionState::new(token.start, command_completions(commands, &token.query))
+}
+
+/// Members of the open group matching the token, people who wrote
+/// recently first. Each new query is searched in the background; until
+/// it completes the previous results are filtered locally.
+fn mention_completion<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    token: &CompletionToken,
+) -> Option<CompletionState> {
+    let chat_id = ctx.state.open_chat().chat_id()?;
+    if ctx.state.open_chat().chat_type() != ChatType::Group {
+        return None;
+    }
+
+    if ctx
+        .state
+        .member_search_mut()
+        .begin_request(chat_id, &token.query)
+    {
+        ctx.dispatcher
+            .dispatch_search_chat_members(chat_id, token.query.clone());
+    }
+
+    let recent_senders: Vec<&str> = ctx
+        .state
+        .open_chat()
+        .messages()
+        .iter()
+        .rev()
+        .filter(|m| !m.is_outgoing)
+        .map(|m| m.sender_name.as_str())
+        .collect();
+    let items = mention_completions(
+        ctx.state.member_search().members(chat_id),
+        &token.query,
+        &recent_senders,
+    );
+    CompletionState::new(token.start, items)
+}
+
+/// Private chats with bots and groups; channels never take commands.
+fn chat_may_have_bots<D: TaskDispatcher>(ctx: &OrchestratorCtx<'_, D>, chat_id: i64) -> bool {
+    match ctx.state.open_chat().chat_type() {
+        ChatType::Group => true,
+        ChatType::Channel => false,
+        ChatType::Private => ctx
+            .state
+            .chat_list()
+            .chats()
+            .iter()
+            .any(|c| c.chat_id == chat_id && c.is_bot),
+    }
+}
+
+pub(super) fn handle_bot_commands_loaded<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    chat_id: i64,
+    result: Result<Vec<BotCommand>, BackgroundError>,
+) {
+    let commands = result.unwrap_or_else(|error| {
+        tracing::warn!(chat_id, code = error.code, "failed to load bot commands");
+        Vec::new()
+    });
+    tracing::debug!(
+        chat_id,
+        command_count = commands.len(),
+        "background: bot commands loaded"
+    );
+    ctx.state.bot_commands_mut().store(chat_id, commands);
+
+    if ctx.state.open_chat().chat_id() == Some(chat_id) {
+        refresh(ctx);
+    }
+}
+
+pub(super) fn handle_chat_members_loaded<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    chat_id: i64,
+    query: String,
+    result: Result<Vec<ChatMember>, BackgroundError>,
+) {
+    let members = match result {
+        Ok(members) => members,
+        Err(error) => {
+            tracing::warn!(chat_id, code = error.code, "failed to search chat members");
+            Vec::new()
+        }
+    };
+    tracing::debug!(
+        chat_id,
+        member_count = members.len(),
+        "background: chat members loaded"
+    );
+    ctx.state.member_search_mut().store(chat_id, query, members);
+
+    if ctx.state.open_chat().chat_id() == Some(chat_id) {
+        refresh(ctx);
+    }
+}
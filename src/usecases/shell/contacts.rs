@@ -0,0 +1,167 @@
+//! Contacts panel: browse, filter, open, add and remove contacts.
+//!
+//! The panel replaces the chat list in the left pane while it is open,
+//! like the forum topic list. Removing a contact drops it from the panel
+//! right away; a failed removal reloads the list.
+
+use crate::{
+    domain::{
+        chat::{ChatSummary, ChatType},
+        contact::Contact,
+        events::BackgroundError,
+    },
+    usecases::background::TaskDispatcher,
+};
+
+use super::{links, OrchestratorCtx};
+
+pub(super) fn show_contacts<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>) {
+    ctx.state.open_contacts();
+    ctx.dispatcher.dispatch_load_contacts();
+}
+
+pub(super) fn reload_contacts<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>) {
+    let Some(contacts) = ctx.state.contacts_mut() else {
+        return;
+    };
+    contacts.set_loading();
+    ctx.dispatcher.dispatch_load_contacts();
+}
+
+/// Handles a key while the user types into the contacts filter.
+pub(super) fn handle_filter_key<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>, key: &str) {
+    let Some(contacts) = ctx.state.contacts_mut() else {
+        return;
+    };
+
+    match key {
+        "esc" => contacts.clear_filter(),
+        "enter" => contacts.finish_filtering(),
+        "backspace" => contacts.pop_filter_char(),
+        k if k.chars().count() == 1 => {
+            let ch = k.chars().next().unwrap();
+            contacts.push_filter_char(ch);
+        }
+        _ => {}
+    }
+}
+
+pub(super) fn open_selected_contact<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>) {
+    let Some(contact) = ctx.state.contacts().and_then(|c| c.selected_contact()) else {
+        return;
+    };
+    ctx.dispatcher.dispatch_open_contact_chat(contact.user_id);
+}
+
+pub(super) fn remove_selected_contact<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>) {
+    let Some(contacts) = ctx.state.contacts_mut() else {
+        return;
+    };
+    let Some(user_id) = contacts.selected_contact().map(|c| c.user_id) else {
+        return;
+    };
+    let Some(removed) = contacts.remove(user_id) else {
+        return;
+    };
+
+    ctx.state
+        .set_notification(format!("Removed {} from contacts", removed.display_name));
+    ctx.dispatcher.dispatch_remove_contact(user_id);
+}
+
+/// Adds the peer of a private chat: the selected chat in the chat list,
+/// or the open chat in the messages pane.
+pub(super) fn add_contact_from_chat<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    chat_id: i64,
+    chat_type: ChatType,
+    title: &str,
+) {
+    if chat_type != ChatType::Private {
+        ctx.state
+            .set_notification("Only people can be added to contacts");
+        return;
+    }
+    ctx.state
+        .set_notification(format!("Adding {title} to contacts..."));
+    ctx.dispatcher.dispatch_add_contact(chat_id);
+}
+
+pub(super) fn handle_contacts_loaded<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    result: Result<Vec<Contact>, BackgroundError>,
+) {
+    if let Err(error) = &result {
+        tracing::warn!(code = error.code, "background: loading contacts failed");
+    }
+
+    // The new chat wizard picks members from the same list.
+    if let Some(wizard) = ctx.state.new_chat_wizard_mut() {
+        wizard.set_contacts(result.clone().unwrap_or_default());
+    }
+
+    let Some(contacts) = ctx.state.contacts_mut() else {
+        return;
+    };
+    match result {
+        Ok(list) => contacts.set_ready(list),
+        Err(_) => contacts.set_error(),
+    }
+}
+
+pub(super) fn handle_contact_added<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    chat_id: i64,
+    result: Result<Contact, BackgroundError>,
+) {
+    match result {
+        Ok(contact) => {
+            ctx.state
+                .set_notification(format!("Added {} to contacts", contact.display_name));
+            reload_contacts(ctx);
+        }
+        Err(error) => {
+            tracing::warn!(chat_id, code = error.code, "background: add contact failed");
+            ctx.state
+                .set_notification(if error.code == "CONTACT_NOT_FOUND" {
+                    "This chat can't be added to contacts"
+                } else {
+                    "Failed to add contact"
+                });
+        }
+    }
+}
+
+pub(super) fn handle_contact_removed<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    user_id: i64,
+    result: Result<(), BackgroundError>,
+) {
+    if let Err(error) = result {
+        tracing::warn!(
+            user_id,
+            code = error.code,
+            "background: remove contact failed"
+        );
+        ctx.state.set_notification("Failed to remove contact");
+        reload_contacts(ctx);
+    }
+}
+
+pub(super) fn handle_contact_chat_loaded<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    user_id: i64,
+    result: Result<ChatSummary, BackgroundError>,
+) {
+    match result {
+        Ok(chat) => links::open_linked_chat(ctx, &chat),
+        Err(error) => {
+            tracing::warn!(
+                user_id,
+                code = error.code,
+                "background: opening contact chat failed"
+            );
+            ctx.state.set_notification("Failed to open chat");
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use crate::usecases::background::TaskDispatcher;
+
+use super::{chat_actions, completion, OrchestratorCtx};
+
+/// Opens the emoji picker over the message input. Editing a message is
+/// allowed; the picked emoji goes wherever the cursor is.
+pub(super) fn open<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>) {
+    ctx.state.set_completion(None);
+    ctx.state.open_emoji_picker();
+}
+
+pub(super) fn handle_picker_key<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>, key: &str) {
+    let Some(picker) = ctx.state.emoji_picker_mut() else {
+        return;
+    };
+
+    match key {
+        "esc" => ctx.state.close_emoji_picker(),
+        "enter" => {
+            let selected = picker.selected_emoji();
+            ctx.state.close_emoji_picker();
+            if let Some(emoji) = selected {
+                insert(ctx, emoji.emoji);
+            }
+        }
+        "tab" => picker.next_category(),
+        "backtab" => picker.previous_category(),
+        "left" => picker.move_left(),
+        "right" => picker.move_right(),
+        "up" => picker.move_up(),
+        "down" => picker.move_down(),
+        "backspace" => picker.delete_char_before(),
+        k if k.chars().count() == 1 => {
+            let ch = k.chars().next().unwrap();
+            picker.insert_char(ch);
+        }
+        _ => {}
+    }
+}
+
+fn insert<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>, emoji: &str) {
+    let input = ctx.state.message_input_mut();
+    let cursor = input.cursor_position();
+    input.replace_before_cursor(cursor, emoji);
+    chat_actions::handle_input_changed(ctx);
+    completion::refresh(ctx);
+}
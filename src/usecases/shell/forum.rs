@@ -1,6 +1,7 @@
 use crate::domain::{
     chat::ChatType,
     open_chat_state::{MessageSource, OpenChatUiState},
+    recent_chats::RecentChat,
     shell_state::ActivePane,
 };
 
@@ -79,12 +80,42 @@ pub(super) fn open_selected_topic<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'
     };
 
     let chat_id = forum_list.parent_chat_id();
+    let forum_title = forum_list.parent_chat_title().to_owned();
     let topic_id = topic.topic_id;
+    let topic_name = topic.name.clone();
     let topic_unread = topic.unread_count;
-    let title = format!("{} > {}", forum_list.parent_chat_title(), topic.name);
+    open_topic(
+        ctx,
+        chat_id,
+        forum_title,
+        topic_id,
+        topic_name,
+        topic_unread,
+    );
+}
+
+/// Opens a topic of the forum shown in the topic list panel and records it
+/// as the most recent chat. `topic_unread` is the topic's unread count as
+/// far as the caller knows.
+pub(super) fn open_topic<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    chat_id: i64,
+    forum_title: String,
+    topic_id: i32,
+    topic_name: String,
+    topic_unread: u32,
+) {
+    let title = format!("{forum_title} > {topic_name}");
 
     tracing::debug!(chat_id, topic_id, %title, "opening forum topic");
 
+    ctx.recent_chats.record(RecentChat::topic(
+        chat_id,
+        forum_title,
+        topic_id,
+        topic_name,
+    ));
+
     // If the same topic is already open and Ready, just switch focus.
     if ctx.state.open_chat().chat_id() == Some(chat_id)
         && ctx.state.open_chat().topic_id() == Some(topic_id)
@@ -0,0 +1,60 @@
+//! "Go to chat" prompt: opens a chat by `@username`, phone number or
+//! contact name, including chats that are not in the loaded chat list.
+
+use crate::{
+    domain::{chat::ChatSummary, events::BackgroundError, go_to_prompt_state::ChatQuery},
+    usecases::background::TaskDispatcher,
+};
+
+use super::{links, OrchestratorCtx};
+
+pub(super) fn handle_go_to_prompt_key<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    key: &str,
+) {
+    let Some(prompt) = ctx.state.go_to_prompt_mut() else {
+        return;
+    };
+
+    match key {
+        "esc" => ctx.state.close_go_to_prompt(),
+        "enter" => {
+            let input = prompt.input().to_owned();
+            let Some(query) = ChatQuery::parse(&input) else {
+                return;
+            };
+            ctx.state.close_go_to_prompt();
+            ctx.state.set_notification(format!("Looking up {query}..."));
+            ctx.dispatcher.dispatch_find_chat(query);
+        }
+        "backspace" => prompt.delete_char_before(),
+        k if k.chars().count() == 1 => {
+            let ch = k.chars().next().unwrap();
+            prompt.insert_char(ch);
+        }
+        _ => {}
+    }
+}
+
+pub(super) fn handle_chat_found<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    query: &ChatQuery,
+    result: Result<ChatSummary, BackgroundError>,
+) {
+    match result {
+        Ok(chat) => {
+            tracing::debug!(chat_id = chat.chat_id, %query, "opening looked-up chat");
+            links::open_linked_chat(ctx, &chat);
+        }
+        Err(error) => {
+            tracing::warn!(%query, code = error.code, "background: chat lookup failed");
+            ctx.state.set_notification(match error.code {
+                "CHAT_NOT_FOUND" => format!("No chat found for {query}"),
+                "CHAT_AMBIGUOUS" => {
+                    format!("Several contacts match {query}, type more of the name")
+                }
+                _ => "Failed to look up chat".to_owned(),
+            });
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use crate::{
+    domain::{
+        button_grid_state::InlineButtonSelection,
+        events::BackgroundError,
+        message::{CallbackAnswer, InlineButtonAction},
+        shell_state::ActivePane,
+    },
+    usecases::{background::TaskDispatcher, inline_keyboard::CallbackQuery},
+};
+
+use super::{links, OrchestratorCtx};
+
+/// Enters button-selection mode on the selected message's inline keyboard.
+pub(super) fn start_selection<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>) {
+    let Some(msg) = ctx.state.open_chat().selected_message() else {
+        return;
+    };
+
+    match InlineButtonSelection::new(msg.id, msg.inline_keyboard.clone()) {
+        Some(selection) => ctx.state.start_inline_button_selection(selection),
+        None => ctx.state.set_notification("No buttons on this message"),
+    }
+}
+
+pub(super) fn handle_selection_key<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>, key: &str) {
+    let Some(selection) = ctx.state.inline_button_selection_mut() else {
+        return;
+    };
+
+    match key {
+        "h" | "left" => selection.move_left(),
+        "l" | "right" => selection.move_right(),
+        "k" | "up" => selection.move_up(),
+        "j" | "down" => selection.move_down(),
+        "enter" => {
+            let message_id = selection.message_id();
+            let action = selection.selected_action().clone();
+            ctx.state.stop_inline_button_selection();
+            press_button(ctx, message_id, action);
+        }
+        "esc" | "q" | "b" => ctx.state.stop_inline_button_selection(),
+        _ => {}
+    }
+}
+
+fn press_button<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    message_id: i64,
+    action: InlineButtonAction,
+) {
+    let Some(chat_id) = ctx.state.open_chat().chat_id() else {
+        return;
+    };
+
+    match action {
+        InlineButtonAction::Callback { data } => {
+            tracing::debug!(chat_id, message_id, "pressing inline callback button");
+            ctx.dispatcher.dispatch_callback_query(CallbackQuery {
+                chat_id,
+                message_id,
+                data,
+            });
+        }
+        InlineButtonAction::Url { url } => {
+            if let Err(error) = links::open_url(ctx, &url) {
+                tracing::warn!(%url, error = %error, "failed to open inline button URL");
+                ctx.state.set_notification("Failed to open link");
+            }
+        }
+        action @ InlineButtonAction::SwitchInline { .. } => {
+            if ctx.state.open_chat().chat_type() == crate::domain::chat::ChatType::Channel {
+                ctx.state.set_notification("Channel is read-only");
+                return;
+            }
+            let text = action.switch_inline_text().unwrap_or_default();
+            ctx.state.message_input_mut().set_text(&text);
+            ctx.state.set_active_pane(ActivePane::MessageInput);
+        }
+        InlineButtonAction::Unsupported => {
+            ctx.state.set_notification("This button is not supported");
+        }
+    }
+}
+
+pub(super) fn handle_callback_answer_loaded<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    chat_id: i64,
+    message_id: i64,
+    result: Result<CallbackAnswer, BackgroundError>,
+) {
+    if ctx.state.open_chat().chat_id() != Some(chat_id) {
+        tracing::debug!(chat_id, message_id, "discarding stale callback answer");
+        return;
+    }
+
+    match result {
+        Ok(answer) => {
+            if !answer.url.is_empty() {
+                if let Err(error) = links::open_url(ctx, &answer.url) {
+                    tracing::warn!(url = %answer.url, error = %error, "failed to open callback URL");
+                }
+            }
+            if answer.text.is_empty() {
+                return;
+            }
+            if answer.show_alert {
+                ctx.state.show_alert(answer.text);
+            } else {
+                ctx.state.set_notification(answer.text);
+            }
+        }
+        Err(error) => {
+            tracing::warn!(
+                chat_id,
+                message_id,
+                code = error.code,
+                "callback button press failed"
+            );
+            let text = if error.code == "CALLBACK_QUERY_TIMEOUT" {
+                "Bot did not respond"
+            } else {
+                "Button press failed"
+            };
+            ctx.state.set_notification(text);
+        }
+    }
+}
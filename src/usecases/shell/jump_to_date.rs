@@ -0,0 +1,136 @@
+use chrono::{NaiveDate, NaiveTime, TimeZone};
+
+use crate::{
+    domain::{
+        date_prompt_state::parse_jump_date, events::BackgroundError, message::Message,
+        open_chat_state::MessageSource,
+    },
+    usecases::background::TaskDispatcher,
+};
+
+use super::OrchestratorCtx;
+
+pub(super) fn handle_date_prompt_key<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    key: &str,
+) {
+    let Some(prompt) = ctx.state.date_prompt_mut() else {
+        return;
+    };
+
+    match key {
+        "esc" => ctx.state.close_date_prompt(),
+        "enter" => {
+            let input = prompt.input().to_owned();
+            ctx.state.close_date_prompt();
+            submit_jump(ctx, &input);
+        }
+        "backspace" => prompt.delete_char_before(),
+        k if k.chars().count() == 1 => {
+            let ch = k.chars().next().unwrap();
+            prompt.insert_char(ch);
+        }
+        _ => {}
+    }
+}
+
+fn submit_jump<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>, input: &str) {
+    let Some(chat_id) = ctx.state.open_chat().chat_id() else {
+        return;
+    };
+
+    let today = chrono::Local::now().date_naive();
+    let Some(date) = parse_jump_date(input, today) else {
+        ctx.state
+            .set_notification(format!("Unrecognized date: {}", input.trim()));
+        return;
+    };
+
+    let topic_id = ctx.state.open_chat().topic_id();
+    tracing::debug!(chat_id, ?topic_id, %date, "jumping to date");
+    ctx.dispatcher
+        .dispatch_load_messages_around_date(chat_id, topic_id, start_of_local_day(date));
+}
+
+/// Unix timestamp of local midnight at the start of `date`.
+///
+/// Falls back to UTC midnight when local midnight does not exist
+/// (DST transitions at 00:00 in some zones).
+fn start_of_local_day(date: NaiveDate) -> i64 {
+    let midnight = date.and_time(NaiveTime::MIN);
+    chrono::Local
+        .from_local_datetime(&midnight)
+        .earliest()
+        .map(|dt| dt.timestamp())
+        .unwrap_or_else(|| midnight.and_utc().timestamp())
+}
+
+pub(super) fn handle_messages_around_date_loaded<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    chat_id: i64,
+    topic_id: Option<i32>,
+    target_unix_ms: i64,
+    result: Result<Vec<Message>, BackgroundError>,
+) {
+    if ctx.state.open_chat().chat_id() != Some(chat_id)
+        || ctx.state.open_chat().topic_id() != topic_id
+    {
+        tracing::debug!(
+            chat_id,
+            ?topic_id,
+            "background: discarding stale jump-to-date result"
+        );
+        return;
+    }
+
+    match result {
+        Ok(messages) if messages.is_empty() => {
+            ctx.state
+                .set_notification("No messages on or after that date");
+        }
+        Ok(messages) => {
+            tracing::debug!(
+                chat_id,
+                message_count = messages.len(),
+                "background: history window loaded"
+            );
+            ctx.state
+                .open_chat_mut()
+                .show_history_window(messages, target_unix_ms);
+        }
+        Err(error) => {
+            tracing::warn!(
+                chat_id,
+                code = error.code,
+                "background: jump to date failed"
+            );
+            ctx.state.set_notification("Jump to date failed");
+        }
+    }
+}
+
+/// Leaves the history window and reloads the latest page of the open chat.
+pub(super) fn return_to_latest<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>) {
+    let Some(chat_id) = ctx.state.open_chat().chat_id() else {
+        return;
+    };
+    let topic_id = ctx.state.open_chat().topic_id();
+
+    ctx.state.open_chat_mut().leave_history_window();
+
+    // The per-chat cache still holds the latest page — show it right away
+    // while the live reload is in flight.
+    if topic_id.is_none() {
+        if let Some(cached) = ctx.state.message_cache_mut().get(chat_id) {
+            let messages = cached.to_vec();
+            ctx.state.open_chat_mut().set_ready(messages);
+            ctx.state.open_chat_mut().set_refreshing(true);
+            ctx.state
+                .open_chat_mut()
+                .set_message_source(MessageSource::Cache);
+        }
+    }
+
+    *ctx.messages_refresh_in_flight = true;
+    ctx.dispatcher.dispatch_load_messages(chat_id, topic_id);
+}
@@ -1,11 +1,23 @@
 use anyhow::Result;
 
 use crate::{
-    domain::{chat::ChatType, events::ConnectivityStatus, keymap::Action, shell_state::ActivePane},
+    domain::{
+        chat::{ChatType, MuteDuration, UnreadJumpKind},
+        chat_folder::ChatListId,
+        chat_list_filter::ChatListFilter,
+        chat_manage_state::ChatConfirmKind,
+        events::ConnectivityStatus,
+        keymap::Action,
+        shell_state::ActivePane,
+    },
     usecases::background::TaskDispatcher,
 };
 
-use super::{chat_list, chat_open, forum, message_actions, voice, OrchestratorCtx};
+use super::{
+    chat_list, chat_open, chat_search, contacts, forum, inline_buttons, jump_to_date, links,
+    message_actions, new_chat, pending_ops, recent_chats, reply_keyboard, unread_jump, voice,
+    OrchestratorCtx,
+};
 
 pub(super) fn dispatch_chat_list_action<D: TaskDispatcher>(
     ctx: &mut OrchestratorCtx<'_, D>,
@@ -40,8 +52,53 @@ pub(super) fn dispatch_chat_list_action<D: TaskDispatcher>(
         },
         Action::MarkChatAsRead => chat_list::mark_selected_chat_as_read(ctx),
         Action::ShowChatInfo => chat_list::show_chat_info_popup(ctx),
-        Action::SearchChats => ctx.state.open_chat_search(),
+        Action::SearchChats => chat_search::open_chat_search(ctx),
+        Action::ShowContacts => contacts::show_contacts(ctx),
+        Action::NewChat => new_chat::open_new_chat_wizard(ctx),
+        Action::NextFolder => chat_list::cycle_chat_folder(ctx, true),
+        Action::PreviousFolder => chat_list::cycle_chat_folder(ctx, false),
+        Action::ArchiveChat => chat_list::move_selected_chat(ctx, ChatListId::Archive),
+        Action::UnarchiveChat => chat_list::move_selected_chat(ctx, ChatListId::Main),
+        Action::CloseArchive => chat_list::close_archive(ctx),
+        Action::MuteChatOneHour => chat_list::mute_selected_chat(ctx, Some(MuteDuration::OneHour)),
+        Action::MuteChatEightHours => {
+            chat_list::mute_selected_chat(ctx, Some(MuteDuration::EightHours))
+        }
+        Action::MuteChatTwoDays => chat_list::mute_selected_chat(ctx, Some(MuteDuration::TwoDays)),
+        Action::MuteChatForever => chat_list::mute_selected_chat(ctx, Some(MuteDuration::Forever)),
+        Action::UnmuteChat => chat_list::mute_selected_chat(ctx, None),
+        Action::TogglePinChat => chat_list::toggle_selected_chat_pin(ctx),
+        Action::MovePinnedChatUp => chat_list::move_selected_pinned_chat(ctx, true),
+        Action::MovePinnedChatDown => chat_list::move_selected_pinned_chat(ctx, false),
+        Action::MarkChatAsUnread => chat_list::toggle_selected_chat_unread_mark(ctx),
+        Action::LeaveOrDeleteChat => chat_list::open_chat_confirm(ctx, ChatConfirmKind::Remove),
+        Action::ClearChatHistory => {
+            chat_list::open_chat_confirm(ctx, ChatConfirmKind::ClearHistory)
+        }
+        Action::ToggleBlockUser => chat_list::toggle_selected_chat_block(ctx),
+        Action::FilterUnreadChats => chat_list::toggle_chat_filter(ctx, ChatListFilter::Unread),
+        Action::FilterPrivateChats => chat_list::toggle_chat_filter(ctx, ChatListFilter::Private),
+        Action::FilterGroupChats => chat_list::toggle_chat_filter(ctx, ChatListFilter::Groups),
+        Action::FilterChannelChats => chat_list::toggle_chat_filter(ctx, ChatListFilter::Channels),
+        Action::FilterBotChats => chat_list::toggle_chat_filter(ctx, ChatListFilter::Bots),
+        Action::FilterHideMutedChats => {
+            chat_list::toggle_chat_filter(ctx, ChatListFilter::HideMuted)
+        }
+        Action::ClearChatFilters => chat_list::clear_chat_filters(ctx),
+        Action::OpenChat if ctx.state.chat_list().archive_row_selected() => {
+            chat_list::open_archive(ctx);
+        }
+        Action::AddContact => {
+            if let Some(chat) = ctx.state.chat_list().selected_chat() {
+                let (chat_id, chat_type, title) =
+                    (chat.chat_id, chat.chat_type, chat.title.clone());
+                contacts::add_contact_from_chat(ctx, chat_id, chat_type, &title);
+            }
+        }
         Action::OpenChat if ctx.state.chat_list().selected_chat().is_some() => {
+            if ctx.state.pending_forward().is_some() {
+                message_actions::finish_forward(ctx);
+            }
             // Pane management lives inside open_selected_chat — forum chats
             // need the ChatList pane to stay active, regular chats switch to
             // Messages. Setting it here first would briefly show the wrong
@@ -50,12 +107,19 @@ pub(super) fn dispatch_chat_list_action<D: TaskDispatcher>(
             return Ok(true);
         }
         Action::Quit => {
+            pending_ops::commit_all(ctx);
             chat_open::close_tdlib_chat(ctx);
             ctx.state.stop();
         }
         Action::ShowHelp => {
             ctx.state.show_help();
         }
+        Action::Undo => {
+            pending_ops::undo_latest(ctx);
+        }
+        Action::GoToChat => ctx.state.open_go_to_prompt(),
+        Action::QuickSwitchChat => recent_chats::open_quick_switcher(ctx),
+        Action::PreviousChat => recent_chats::switch_to_previous_chat(ctx),
         _ => {}
     }
     Ok(false)
@@ -68,7 +132,10 @@ pub(super) fn dispatch_messages_action<D: TaskDispatcher>(
     match action {
         Action::ScrollNextMessage => {
             let moved = ctx.state.open_chat_mut().select_next();
-            if !moved && !ctx.state.open_chat().messages().is_empty() {
+            if !moved && ctx.state.open_chat().is_history_window() {
+                ctx.state
+                    .set_notification("End of loaded history, press G for latest");
+            } else if !moved && !ctx.state.open_chat().messages().is_empty() {
                 ctx.state.set_notification("End of messages");
             }
         }
@@ -80,7 +147,36 @@ pub(super) fn dispatch_messages_action<D: TaskDispatcher>(
             maybe_load_older_messages(ctx);
         }
         Action::ScrollToLastMessage => {
-            ctx.state.open_chat_mut().select_last();
+            if ctx.state.open_chat().is_history_window() {
+                jump_to_date::return_to_latest(ctx);
+            } else {
+                ctx.state.open_chat_mut().select_last();
+            }
+        }
+        Action::JumpToDate if ctx.state.open_chat().is_open() => {
+            ctx.state.open_date_prompt();
+        }
+        Action::AddContact => {
+            let open_chat = ctx.state.open_chat();
+            if let Some(chat_id) = open_chat.chat_id() {
+                let (chat_type, title) = (open_chat.chat_type(), open_chat.chat_title().to_owned());
+                contacts::add_contact_from_chat(ctx, chat_id, chat_type, &title);
+            }
+        }
+        Action::SelectInlineButton if ctx.state.open_chat().is_open() => {
+            inline_buttons::start_selection(ctx);
+        }
+        Action::SelectReplyKeyboardButton if ctx.state.open_chat().is_open() => {
+            reply_keyboard::start_selection(ctx);
+        }
+        Action::NextMention => {
+            unread_jump::jump_to_next_unread(ctx, UnreadJumpKind::Mention);
+        }
+        Action::NextUnreadReaction => {
+            unread_jump::jump_to_next_unread(ctx, UnreadJumpKind::Reaction);
+        }
+        Action::BackToChatList if ctx.state.open_chat().is_visual() => {
+            ctx.state.open_chat_mut().cancel_visual();
         }
         Action::BackToChatList => {
             // A topic-open state implies an active forum_topic_list panel —
@@ -106,16 +202,16 @@ pub(super) fn dispatch_messages_action<D: TaskDispatcher>(
                 ctx.state.set_active_pane(ActivePane::MessageInput);
             }
         }
-        Action::CopyMessage => {
-            if let Some(msg) = ctx.state.open_chat().selected_message() {
-                let text = msg.display_content();
-                if let Ok(mut clipboard) = arboard::Clipboard::new() {
-                    if clipboard.set_text(text).is_ok() {
-                        ctx.state.set_notification("Copied to clipboard");
-                    }
-                }
+        Action::CopyMessage => message_actions::copy_selected_messages(ctx),
+        Action::CopyMessageLink => links::copy_message_link(ctx),
+        Action::ToggleVisualMode if ctx.state.open_chat().is_open() => {
+            if ctx.state.open_chat().is_visual() {
+                ctx.state.open_chat_mut().cancel_visual();
+            } else {
+                ctx.state.open_chat_mut().start_visual();
             }
         }
+        Action::ForwardMessage => message_actions::start_forward(ctx),
         Action::OpenLink => message_actions::open_message_url(ctx)?,
         Action::OpenMessage if ctx.state.open_chat().is_open() => {
             message_actions::open_selected_message(ctx);
@@ -133,7 +229,7 @@ pub(super) fn dispatch_messages_action<D: TaskDispatcher>(
             download_selected_message_file(ctx);
         }
         Action::SaveFile => {
-            message_actions::save_selected_message_file(ctx);
+            message_actions::save_selected_message_files(ctx);
         }
         Action::ReplyToMessage => {
             message_actions::reply_to_selected_message(ctx);
@@ -142,15 +238,22 @@ pub(super) fn dispatch_messages_action<D: TaskDispatcher>(
             message_actions::edit_selected_message(ctx);
         }
         Action::DeleteMessage => {
-            message_actions::delete_selected_message(ctx);
+            message_actions::delete_selected_messages(ctx);
         }
         Action::Quit => {
+            pending_ops::commit_all(ctx);
             chat_open::close_tdlib_chat(ctx);
             ctx.state.stop();
         }
         Action::ShowHelp => {
             ctx.state.show_help();
         }
+        Action::Undo => {
+            pending_ops::undo_latest(ctx);
+        }
+        Action::GoToChat => ctx.state.open_go_to_prompt(),
+        Action::QuickSwitchChat => recent_chats::open_quick_switcher(ctx),
+        Action::PreviousChat => recent_chats::switch_to_previous_chat(ctx),
         _ => {}
     }
     Ok(())
@@ -292,13 +395,67 @@ pub(super) fn dispatch_forum_topic_list_action<D: TaskDispatcher>(
             forum::reload_topics(ctx);
         }
         Action::Quit => {
+            pending_ops::commit_all(ctx);
             chat_open::close_tdlib_chat(ctx);
             ctx.state.stop();
         }
         Action::ShowHelp => {
             ctx.state.show_help();
         }
+        Action::Undo => {
+            pending_ops::undo_latest(ctx);
+        }
+        Action::GoToChat => ctx.state.open_go_to_prompt(),
+        Action::QuickSwitchChat => recent_chats::open_quick_switcher(ctx),
+        Action::PreviousChat => recent_chats::switch_to_previous_chat(ctx),
         _ => {}
     }
     Ok(())
 }
+
+pub(super) fn dispatch_contacts_action<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    action: Action,
+) {
+    match action {
+        Action::SelectNextContact => {
+            if let Some(list) = ctx.state.contacts_mut() {
+                list.select_next();
+            }
+        }
+        Action::SelectPreviousContact => {
+            if let Some(list) = ctx.state.contacts_mut() {
+                list.select_previous();
+            }
+        }
+        Action::SelectFirstContact => {
+            if let Some(list) = ctx.state.contacts_mut() {
+                list.select_first();
+            }
+        }
+        Action::OpenContactChat => contacts::open_selected_contact(ctx),
+        Action::FilterContacts => {
+            if let Some(list) = ctx.state.contacts_mut() {
+                list.start_filtering();
+            }
+        }
+        Action::RemoveContact => contacts::remove_selected_contact(ctx),
+        Action::ReloadContacts => contacts::reload_contacts(ctx),
+        Action::CloseContacts => ctx.state.close_contacts(),
+        Action::Quit => {
+            pending_ops::commit_all(ctx);
+            chat_open::close_tdlib_chat(ctx);
+            ctx.state.stop();
+        }
+        Action::ShowHelp => {
+            ctx.state.show_help();
+        }
+        Action::Undo => {
+            pending_ops::undo_latest(ctx);
+        }
+        Action::GoToChat => ctx.state.open_go_to_prompt(),
+        Action::QuickSwitchChat => recent_chats::open_quick_switcher(ctx),
+        Action::PreviousChat => recent_chats::switch_to_previous_chat(ctx),
+        _ => {}
+    }
+}
@@ -0,0 +1,231 @@
+//! Message links and Telegram links opened inside rtg.
+//!
+//! `t.me` / `tg://` links are resolved in the background and open the chat
+//! or message they point to; invite links ask before joining. Anything
+//! rtg has no UI for goes to the external opener like other URLs.
+
+use anyhow::Result;
+
+use crate::{
+    domain::{
+        chat::{ChatSummary, ChatType},
+        events::{BackgroundError, KeyInput},
+        link_target::LinkTarget,
+        message::is_telegram_link,
+    },
+    usecases::background::TaskDispatcher,
+};
+
+use super::{chat_open, OrchestratorCtx};
+
+/// Requests the link of the selected message for copying.
+pub(super) fn copy_message_link<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>) {
+    let open_chat = ctx.state.open_chat();
+    let (Some(chat_id), Some(message)) = (open_chat.chat_id(), open_chat.selected_message()) else {
+        return;
+    };
+    if open_chat.chat_type() == ChatType::Private {
+        ctx.state
+            .set_notification("Message links exist only in groups and channels");
+        return;
+    }
+
+    let message_id = message.id;
+    ctx.dispatcher.dispatch_message_link(chat_id, message_id);
+}
+
+pub(super) fn handle_message_link_loaded<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    chat_id: i64,
+    message_id: i64,
+    result: Result<String, BackgroundError>,
+) {
+    match result {
+        Ok(link) => {
+            let copied = arboard::Clipboard::new()
+                .and_then(|mut clipboard| clipboard.set_text(link))
+                .is_ok();
+            ctx.state.set_notification(if copied {
+                "Message link copied"
+            } else {
+                "Failed to copy message link"
+            });
+        }
+        Err(error) => {
+            tracing::warn!(
+                chat_id,
+                message_id,
+                code = error.code,
+                "background: message link failed"
+            );
+            ctx.state
+                .set_notification(if error.code == "LINK_NOT_FOUND" {
+                    "This message has no link"
+                } else {
+                    "Failed to get message link"
+                });
+        }
+    }
+}
+
+/// Opens `url`: Telegram links are resolved inside rtg, everything else
+/// goes to the external opener.
+pub(super) fn open_url<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    url: &str,
+) -> Result<()> {
+    if is_telegram_link(url) {
+        tracing::debug!(%url, "resolving Telegram link");
+        ctx.state.set_notification("Opening link...");
+        ctx.dispatcher.dispatch_resolve_link(url.to_owned());
+        return Ok(());
+    }
+    ctx.opener.open(url)
+}
+
+pub(super) fn handle_link_resolved<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    url: String,
+    result: Result<LinkTarget, BackgroundError>,
+) {
+    match result {
+        Ok(LinkTarget::Chat(chat)) => open_linked_chat(ctx, &chat),
+        Ok(LinkTarget::Message { chat, window }) => {
+            open_linked_chat(ctx, &chat);
+            if ctx.state.open_chat().chat_id() != Some(chat.chat_id) {
+                return;
+            }
+            let target_id = window.target_message_id;
+            // The linked message may already be on the loaded page.
+            if !ctx.state.open_chat_mut().select_message_by_id(target_id) {
+                ctx.state
+                    .open_chat_mut()
+                    .show_history_window_at(window.messages, target_id);
+            }
+        }
+        Ok(LinkTarget::Invite(invite)) => ctx.state.open_join_prompt(invite),
+        Ok(LinkTarget::External) => {
+            if let Err(error) = ctx.opener.open(&url) {
+                tracing::warn!(%url, error = %error, "failed to open link");
+                ctx.state.set_notification("Failed to open link");
+            }
+        }
+        Err(error) => {
+            tracing::warn!(%url, code = error.code, "background: link resolution failed");
+            ctx.state
+                .set_notification(if error.code == "LINK_NOT_FOUND" {
+                    "Link is invalid or expired"
+                } else {
+                    "Failed to open link"
+                });
+        }
+    }
+}
+
+/// Handles a key while the link hints popup is open. A digit opens the
+/// link with that hint, or copies it with Ctrl or in copy mode.
+pub(super) fn handle_link_hints_key<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    key: &KeyInput,
+) {
+    let Some(hints) = ctx.state.link_hints_mut() else {
+        return;
+    };
+    let picked = match key.key.as_str() {
+        "j" | "down" => {
+            hints.select_next();
+            return;
+        }
+        "k" | "up" => {
+            hints.select_previous();
+            return;
+        }
+        "y" => {
+            hints.toggle_copy_mode();
+            return;
+        }
+        "q" | "esc" => {
+            ctx.state.close_link_hints();
+            return;
+        }
+        "enter" => hints.selected(),
+        digit => hints.by_hint(digit),
+    };
+    let Some(url) = picked.map(|u| u.url.clone()) else {
+        return;
+    };
+    let copy = key.ctrl || hints.is_copy_mode();
+    ctx.state.close_link_hints();
+
+    if copy {
+        let copied = arboard::Clipboard::new()
+            .and_then(|mut clipboard| clipboard.set_text(url))
+            .is_ok();
+        ctx.state.set_notification(if copied {
+            "Link copied"
+        } else {
+            "Failed to copy link"
+        });
+    } else if let Err(error) = open_url(ctx, &url) {
+        tracing::warn!(%url, error = %error, "failed to open link");
+        ctx.state.set_notification("Failed to open link");
+    }
+}
+
+/// Handles a key while the "join chat?" prompt is open.
+pub(super) fn handle_join_prompt_key<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    key: &str,
+) {
+    match key {
+        "y" | "enter" => {
+            let Some(invite) = ctx.state.close_join_prompt() else {
+                return;
+            };
+            ctx.state
+                .set_notification(format!("Joining {}...", invite.title));
+            ctx.dispatcher.dispatch_join_chat(invite.invite_link);
+        }
+        "n" | "q" | "esc" => {
+            ctx.state.close_join_prompt();
+        }
+        _ => {}
+    }
+}
+
+pub(super) fn handle_chat_joined<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    result: Result<ChatSummary, BackgroundError>,
+) {
+    match result {
+        Ok(chat) => {
+            ctx.state.set_notification(format!("Joined {}", chat.title));
+            open_linked_chat(ctx, &chat);
+        }
+        Err(error) if error.code == "JOIN_REQUEST_SENT" => {
+            ctx.state.set_notification("Join request sent");
+        }
+        Err(error) => {
+            tracing::warn!(code = error.code, "background: join chat failed");
+            ctx.state
+                .set_notification(if error.code == "LINK_NOT_FOUND" {
+                    "Invite link is invalid or expired"
+                } else {
+                    "Failed to join chat"
+                });
+        }
+    }
+}
+
+/// Opens a chat reached through a link or lookup, leaving any open forum first and
+/// highlighting the chat in the list when it is there.
+pub(super) fn open_linked_chat<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    chat: &ChatSummary,
+) {
+    if ctx.state.forum_topic_list().is_some() {
+        ctx.state.leave_forum();
+    }
+    ctx.state.chat_list_mut().select_chat(chat.chat_id);
+    chat_open::open_chat(ctx, chat);
+}
@@ -1,8 +1,18 @@
 use anyhow::Result;
 
-use crate::usecases::background::TaskDispatcher;
-
-use super::OrchestratorCtx;
+use crate::{
+    domain::{
+        delete_confirm_state::{DeleteConfirmState, DeleteScope},
+        events::BackgroundError,
+        message::FileInfo,
+    },
+    usecases::background::TaskDispatcher,
+};
+
+use super::{
+    pending_ops::{self, PendingOperation},
+    OrchestratorCtx,
+};
 
 /// Opens the currently selected message using the configured handler.
 ///
@@ -79,23 +89,194 @@ pub(super) fn open_selected_message<D: TaskDispatcher>(ctx: &mut OrchestratorCtx
     }
 }
 
-pub(super) fn delete_selected_message<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>) {
+/// Deletes the selected messages (the visual range, or the message under
+/// the cursor). With confirmation enabled this opens the confirm popup and
+/// resolves in the background who the messages can be deleted for;
+/// otherwise they are deleted right away, for everyone where allowed.
+pub(super) fn delete_selected_messages<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>) {
     let Some(chat_id) = ctx.state.open_chat().chat_id() else {
         return;
     };
-    let Some(msg) = ctx.state.open_chat().selected_message() else {
+    // Pending messages have id=0, skip
+    let message_ids = selected_message_ids(ctx);
+    if message_ids.is_empty() {
+        return;
+    }
+
+    if ctx.confirm_delete {
+        ctx.state
+            .open_delete_confirm(DeleteConfirmState::new(chat_id, message_ids.clone()));
+        ctx.dispatcher
+            .dispatch_delete_permissions(chat_id, message_ids);
+    } else {
+        delete_messages(ctx, chat_id, message_ids, DeleteScope::Auto);
+    }
+}
+
+/// Keys of the delete confirmation popup: `m` deletes for me, `e` for
+/// everyone (each only when allowed), `n` / `q` / `Esc` cancel.
+pub(super) fn handle_delete_confirm_key<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    key: &str,
+) {
+    let Some(confirm) = ctx.state.delete_confirm() else {
+        return;
+    };
+    let scope = match key {
+        "m" if confirm.can_delete_for_me() => DeleteScope::ForMe,
+        "e" if confirm.can_delete_for_everyone() => DeleteScope::ForEveryone,
+        "n" | "q" | "esc" => {
+            ctx.state.close_delete_confirm();
+            return;
+        }
+        _ => return,
+    };
+
+    if let Some(confirm) = ctx.state.close_delete_confirm() {
+        delete_messages(
+            ctx,
+            confirm.chat_id(),
+            confirm.message_ids().to_vec(),
+            scope,
+        );
+    }
+}
+
+/// Hides the messages and queues their deletion for the undo grace period.
+fn delete_messages<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    chat_id: i64,
+    message_ids: Vec<i64>,
+    scope: DeleteScope,
+) {
+    let open_chat = ctx.state.open_chat_mut();
+    open_chat.cancel_visual();
+    let removed = open_chat.hide_messages(&message_ids);
+    pending_ops::schedule(
+        ctx,
+        PendingOperation::DeleteMessages {
+            chat_id,
+            message_ids,
+            scope,
+            removed,
+        },
+    );
+}
+
+/// Copies the selected message to the clipboard; a visual range is copied
+/// as a transcript with time and sender prefixes.
+pub(super) fn copy_selected_messages<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>) {
+    use crate::domain::message::format_transcript;
+
+    let open_chat = ctx.state.open_chat();
+    let (text, count) = if open_chat.is_visual() {
+        let messages = open_chat.selected_messages();
+        (format_transcript(&messages), messages.len())
+    } else {
+        match open_chat.selected_message() {
+            Some(msg) => (msg.display_content(), 1),
+            None => return,
+        }
+    };
+
+    if let Ok(mut clipboard) = arboard::Clipboard::new() {
+        if clipboard.set_text(text).is_ok() {
+            if count == 1 {
+                ctx.state.set_notification("Copied to clipboard");
+            } else {
+                ctx.state
+                    .set_notification(format!("Copied {count} messages to clipboard"));
+            }
+        }
+    }
+    ctx.state.open_chat_mut().cancel_visual();
+}
+
+/// Remembers the selected messages for forwarding and moves focus to the
+/// chat list, where opening a chat picks it as the target.
+pub(super) fn start_forward<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>) {
+    use crate::domain::shell_state::{ActivePane, PendingForward};
+
+    let Some(from_chat_id) = ctx.state.open_chat().chat_id() else {
         return;
     };
-    let message_id = msg.id;
-    if message_id == 0 {
-        return; // Pending messages have id=0, skip
+    let message_ids = selected_message_ids(ctx);
+    if message_ids.is_empty() {
+        return;
     }
 
-    // Optimistically remove from UI
-    ctx.state.open_chat_mut().remove_message(message_id);
-    ctx.state.set_notification("Message deleted");
-    // Dispatch background deletion (fire-and-forget)
-    ctx.dispatcher.dispatch_delete_message(chat_id, message_id);
+    ctx.state.open_chat_mut().cancel_visual();
+    ctx.state.set_pending_forward(PendingForward {
+        from_chat_id,
+        message_ids,
+    });
+    ctx.state.set_active_pane(ActivePane::ChatList);
+}
+
+/// Forwards the pending messages to the chat selected in the chat list.
+pub(super) fn finish_forward<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>) {
+    let Some(to_chat_id) = ctx.state.chat_list().selected_chat().map(|c| c.chat_id) else {
+        return;
+    };
+    let Some(forward) = ctx.state.take_pending_forward() else {
+        return;
+    };
+
+    ctx.state.set_notification(format!(
+        "Forwarding {}...",
+        pending_ops::messages_label(forward.message_ids.len())
+    ));
+    ctx.dispatcher
+        .dispatch_forward_messages(to_chat_id, forward.from_chat_id, forward.message_ids);
+}
+
+/// Reports how a forward started by [`finish_forward`] ended.
+pub(super) fn handle_messages_forwarded<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    to_chat_id: i64,
+    count: usize,
+    result: Result<(), BackgroundError>,
+) {
+    match result {
+        Ok(()) => ctx.state.set_notification(count_label(count, "forwarded")),
+        Err(error) => {
+            tracing::warn!(
+                to_chat_id,
+                code = error.code,
+                "background: forward messages failed"
+            );
+            ctx.state.set_notification(format!(
+                "Failed to forward {}",
+                pending_ops::messages_label(count)
+            ));
+        }
+    }
+}
+
+pub(super) fn cancel_forward<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>) {
+    if ctx.state.take_pending_forward().is_some() {
+        ctx.state.set_notification("Forward cancelled");
+    }
+}
+
+/// Ids of the selected messages that exist on the server.
+fn selected_message_ids<D: TaskDispatcher>(ctx: &OrchestratorCtx<'_, D>) -> Vec<i64> {
+    ctx.state
+        .open_chat()
+        .selected_messages()
+        .iter()
+        .map(|m| m.id)
+        .filter(|&id| id != 0)
+        .collect()
+}
+
+/// "Message deleted" / "3 messages deleted".
+fn count_label(count: usize, verb: &str) -> String {
+    if count == 1 {
+        format!("Message {verb}")
+    } else {
+        format!("{count} messages {verb}")
+    }
 }
 
 pub(super) fn reply_to_selected_message<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>) {
@@ -165,68 +346,102 @@ pub(super) fn edit_selected_message<D: TaskDispatcher>(ctx: &mut OrchestratorCtx
     ctx.state.set_active_pane(ActivePane::MessageInput);
 }
 
-pub(super) fn save_selected_message_file<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>) {
-    use crate::domain::message::DownloadStatus;
-
+/// Saves the files of the selected messages to the downloads folder,
+/// downloading them first when needed.
+pub(super) fn save_selected_message_files<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>) {
     let Some(chat_id) = ctx.state.open_chat().chat_id() else {
         return;
     };
 
-    let Some(msg) = ctx.state.open_chat().selected_message() else {
-        return;
-    };
+    let files: Vec<(i64, FileInfo)> = ctx
+        .state
+        .open_chat()
+        .selected_messages()
+        .iter()
+        .filter_map(|m| m.file_info.clone().map(|fi| (m.id, fi)))
+        .collect();
+    let is_visual = ctx.state.open_chat().is_visual();
+    ctx.state.open_chat_mut().cancel_visual();
+
+    match files.as_slice() {
+        [] if is_visual => ctx.state.set_notification("No files in selection"),
+        [] => {}
+        [(message_id, fi)] => {
+            if let Some(notification) = save_message_file(ctx, chat_id, *message_id, fi) {
+                ctx.state.set_notification(notification);
+            }
+        }
+        _ => {
+            let started = files
+                .iter()
+                .filter(|(message_id, fi)| {
+                    save_message_file(ctx, chat_id, *message_id, fi)
+                        != Some("Save already in progress")
+                })
+                .count();
+            ctx.state
+                .set_notification(format!("Saving {started} files..."));
+        }
+    }
+}
 
-    let Some(ref fi) = msg.file_info else {
-        return;
-    };
+/// Starts saving one file; returns the notification describing what
+/// happened.
+fn save_message_file<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    chat_id: i64,
+    message_id: i64,
+    fi: &FileInfo,
+) -> Option<&'static str> {
+    use crate::domain::message::DownloadStatus;
 
     match fi.download_status {
         DownloadStatus::Completed => {
             if ctx.pending_saves.contains(&fi.file_id) {
-                ctx.state.set_notification("Save already in progress");
-                return;
+                return Some("Save already in progress");
             }
-            let Some(ref local_path) = fi.local_path else {
-                return;
-            };
+            let local_path = fi.local_path.as_ref()?;
             ctx.pending_saves.insert(fi.file_id);
             ctx.dispatcher
                 .dispatch_save_file(fi.file_id, local_path.clone(), fi.file_name.clone());
-            ctx.state.set_notification("Saving file...");
+            Some("Saving file...")
         }
         DownloadStatus::NotStarted => {
             let file_id = fi.file_id;
-            let message_id = msg.id;
 
             if ctx.active_downloads.contains_key(&file_id) {
                 ctx.pending_saves.insert(file_id);
-                ctx.state.set_notification("Will save after download...");
-                return;
+                return Some("Will save after download...");
             }
 
             tracing::info!(file_id, chat_id, message_id, "download + save triggered");
             ctx.active_downloads.insert(file_id, (chat_id, message_id));
             ctx.pending_saves.insert(file_id);
             ctx.dispatcher.dispatch_download_file(file_id);
-            ctx.state
-                .set_notification("Downloading, will save when done...");
+            Some("Downloading, will save when done...")
         }
         DownloadStatus::Downloading { .. } => {
             ctx.pending_saves.insert(fi.file_id);
-            ctx.state.set_notification("Will save after download...");
+            Some("Will save after download...")
         }
     }
 }
 
+/// Opens the link of the selected message; when it has several, shows
+/// them as numbered hints to pick from.
 pub(super) fn open_message_url<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>) -> Result<()> {
-    use crate::domain::message::extract_first_url;
+    use crate::domain::{link_hints_state::LinkHintsState, message::extract_urls};
 
     let Some(msg) = ctx.state.open_chat().selected_message() else {
         return Ok(());
     };
-    let text = msg.display_content();
-    if let Some(url) = extract_first_url(&text, &msg.links) {
-        ctx.opener.open(&url)?;
+    let mut urls = extract_urls(&msg.text, &msg.links);
+    match urls.len() {
+        0 => Ok(()),
+        1 => super::links::open_url(ctx, &urls.remove(0).url),
+        _ => {
+            ctx.state.open_link_hints(LinkHintsState::new(urls));
+            Ok(())
+        }
     }
-    Ok(())
 }
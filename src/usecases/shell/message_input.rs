@@ -1,11 +1,23 @@
-use crate::{domain::shell_state::ActivePane, usecases::background::TaskDispatcher};
+use crate::{
+    domain::{
+        message_input_state::{ReplyContext, TextMention},
+        shell_state::ActivePane,
+    },
+    usecases::background::TaskDispatcher,
+};
 
-use super::OrchestratorCtx;
+use super::{chat_actions, completion, OrchestratorCtx};
 
 pub(super) fn handle_message_input_key<D: TaskDispatcher>(
     ctx: &mut OrchestratorCtx<'_, D>,
     key: &str,
 ) {
+    if completion::handle_completion_key(ctx, key) {
+        return;
+    }
+
+    let text_before = ctx.state.message_input().text().to_owned();
+
     match key {
         "esc" => {
             if ctx.state.message_input().editing().is_some() {
@@ -39,6 +51,12 @@ pub(super) fn handle_message_input_key<D: TaskDispatcher>(
         }
         _ => {}
     }
+
+    if ctx.state.message_input().text() != text_before {
+        chat_actions::handle_input_changed(ctx);
+    }
+
+    completion::refresh(ctx);
 }
 
 fn try_edit_message<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>) {
@@ -77,14 +95,31 @@ fn try_send_message<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>) {
         return;
     }
 
+    if ctx.state.open_chat().chat_id().is_none() {
+        return;
+    }
+
+    // Extract reply context and mentions before clearing input
+    let reply_context = ctx.state.message_input_mut().take_reply_to();
+    let mentions = ctx.state.message_input().text_mentions();
+    ctx.state.message_input_mut().clear();
+    send_text(ctx, text, reply_context, mentions);
+}
+
+/// Shows `text` as a pending message in the open chat and dispatches
+/// the send. `text` must not be blank.
+pub(super) fn send_text<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    text: String,
+    reply_context: Option<ReplyContext>,
+    mentions: Vec<TextMention>,
+) {
     let Some(chat_id) = ctx.state.open_chat().chat_id() else {
         return;
     };
 
     tracing::debug!(chat_id, "dispatching send message to background");
 
-    // Extract reply context before clearing input
-    let reply_context = ctx.state.message_input_mut().take_reply_to();
     let reply_to_message_id = reply_context.as_ref().map(|r| r.message_id);
     let pending_reply_info = reply_context.map(|r| crate::domain::message::ReplyInfo {
         sender_name: r.sender_name.clone(),
@@ -92,14 +127,16 @@ fn try_send_message<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>) {
         text: r.text,
     });
 
-    // Optimistically clear the input and show the message immediately
-    ctx.state.message_input_mut().clear();
+    // Sending a message ends the typing action on Telegram's side.
+    ctx.chat_action_throttle.reset();
+
+    // Optimistically show the message immediately
     ctx.state.open_chat_mut().add_pending_message(
-        trimmed.to_owned(),
+        text.trim().to_owned(),
         crate::domain::message::MessageMedia::None,
         pending_reply_info,
     );
     let topic_id = ctx.state.open_chat().topic_id();
     ctx.dispatcher
-        .dispatch_send_message(chat_id, topic_id, text.clone(), reply_to_message_id);
+        .dispatch_send_message(chat_id, topic_id, text, reply_to_message_id, mentions);
 }
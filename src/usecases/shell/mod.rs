@@ -1,11 +1,25 @@
 mod background_results;
+mod chat_actions;
 mod chat_list;
 mod chat_open;
+mod chat_search;
 mod chat_updates;
+mod completion;
+mod contacts;
+mod emoji_picker;
 mod forum;
+mod go_to_chat;
+mod inline_buttons;
+mod jump_to_date;
 mod key_dispatch;
+mod links;
 mod message_actions;
 mod message_input;
+mod new_chat;
+mod pending_ops;
+mod recent_chats;
+mod reply_keyboard;
+mod unread_jump;
 mod voice;
 
 use std::sync::Arc;
@@ -14,10 +28,12 @@ use anyhow::Result;
 
 use crate::{
     domain::{
+        chat_action::ChatActionThrottle,
         chat_list_state::ChatListUiState,
         events::AppEvent,
-        keymap::{KeyContext, Keymap, ResolveResult},
+        keymap::{Action, KeyContext, Keymap, ResolveResult},
         message_cache::DEFAULT_MIN_DISPLAY_MESSAGES,
+        recent_chats::RecentChats,
         shell_state::{ActivePane, ShellState},
     },
     infra::contracts::{ExternalOpener, StorageAdapter},
@@ -65,6 +81,12 @@ pub(super) struct OrchestratorCtx<'a, D: TaskDispatcher> {
     pub cache_source: &'a Option<Arc<dyn CachedMessagesSource>>,
     pub open_handlers: &'a std::collections::HashMap<String, String>,
     pub opener: &'a dyn crate::infra::contracts::ExternalOpener,
+    pub send_chat_actions: bool,
+    pub chat_action_throttle: &'a mut ChatActionThrottle,
+    pub confirm_delete: bool,
+    pub pending_ops: &'a mut pending_ops::PendingOperations,
+    pub undo_grace: std::time::Duration,
+    pub recent_chats: &'a mut RecentChats,
 }
 
 pub struct DefaultShellOrchestrator<S, O, D>
@@ -130,6 +152,19 @@ where
     max_auto_download_bytes: u64,
     /// File IDs pending save-to-downloads after download completes.
     pending_saves: std::collections::HashSet<i32>,
+    /// Whether typing/recording actions are broadcast to peers (from config).
+    send_chat_actions: bool,
+    /// Throttles outgoing chat actions and tracks the active one.
+    chat_action_throttle: ChatActionThrottle,
+    /// Whether `dd` asks for confirmation before deleting (from config).
+    confirm_delete: bool,
+    /// Destructive actions waiting for their undo grace period to end.
+    pending_ops: pending_ops::PendingOperations,
+    /// How long destructive actions can be undone (from config).
+    undo_grace: std::time::Duration,
+    /// Chats and topics in most-recently-opened order, saved through
+    /// `storage` on the next Tick after they change.
+    recent_chats: RecentChats,
 }
 
 impl<S, O, D> DefaultShellOrchestrator<S, O, D>
@@ -140,6 +175,7 @@ where
 {
     #[cfg_attr(not(test), allow(dead_code))]
     pub fn new(storage: S, opener: O, dispatcher: D) -> Self {
+        let recent_chats = recent_chats::load(&storage);
         Self {
             state: ShellState::default(),
             storage,
@@ -166,6 +202,12 @@ where
             active_downloads: std::collections::HashMap::new(),
             max_auto_download_bytes: 10_000_000,
             pending_saves: std::collections::HashSet::new(),
+            send_chat_actions: true,
+            chat_action_throttle: ChatActionThrottle::default(),
+            confirm_delete: true,
+            pending_ops: pending_ops::PendingOperations::default(),
+            undo_grace: pending_ops::DEFAULT_UNDO_GRACE,
+            recent_chats,
         }
     }
 
@@ -189,8 +231,12 @@ where
         open_handlers: std::collections::HashMap<String, String>,
         max_auto_download_bytes: u64,
         key_overrides: std::collections::HashMap<String, String>,
+        send_chat_actions: bool,
+        confirm_delete: bool,
+        undo_grace: std::time::Duration,
     ) -> Self {
         let initial_refresh_needed = initial_state.chat_list().ui_state() == ChatListUiState::Ready;
+        let recent_chats = recent_chats::load(&storage);
         Self {
             state: initial_state,
             storage,
@@ -221,6 +267,12 @@ where
             active_downloads: std::collections::HashMap::new(),
             max_auto_download_bytes,
             pending_saves: std::collections::HashSet::new(),
+            send_chat_actions,
+            chat_action_throttle: ChatActionThrottle::default(),
+            confirm_delete,
+            pending_ops: pending_ops::PendingOperations::default(),
+            undo_grace,
+            recent_chats,
         }
     }
 
@@ -248,6 +300,12 @@ where
             open_handlers: &self.open_handlers,
             opener: &self.opener,
             pending_saves: &mut self.pending_saves,
+            send_chat_actions: self.send_chat_actions,
+            chat_action_throttle: &mut self.chat_action_throttle,
+            confirm_delete: self.confirm_delete,
+            pending_ops: &mut self.pending_ops,
+            undo_grace: self.undo_grace,
+            recent_chats: &mut self.recent_chats,
         }
     }
 
@@ -273,7 +331,33 @@ where
     fn handle_reaction_picker_key(&mut self, key: &str) {
         use crate::domain::reaction_picker_state::ReactionPickerState;
 
+        if let Some(data) = self
+            .state
+            .reaction_picker_mut()
+            .and_then(|p| p.data_mut())
+            .filter(|data| data.is_searching())
+        {
+            match key {
+                "esc" => data.cancel_search(),
+                "enter" => data.finish_search(),
+                "backspace" => data.delete_char_before(),
+                "down" => data.select_next(),
+                "up" => data.select_previous(),
+                k if k.chars().count() == 1 => {
+                    let ch = k.chars().next().unwrap();
+                    data.insert_char(ch);
+                }
+                _ => {}
+            }
+            return;
+        }
+
         match key {
+            "/" => {
+                if let Some(data) = self.state.reaction_picker_mut().and_then(|p| p.data_mut()) {
+                    data.start_search();
+                }
+            }
             "j" => {
                 if let Some(data) = self.state.reaction_picker_mut().and_then(|p| p.data_mut()) {
                     data.select_next();
@@ -351,10 +435,16 @@ where
                     chat_list::dispatch_chat_list_refresh(&mut self.as_ctx(), false);
                 }
                 self.state.open_chat_mut().typing_state_mut().expire_stale();
+                voice::refresh_recording_action(&mut self.as_ctx());
+                chat_search::search_when_typing_paused(&mut self.as_ctx());
+                pending_ops::commit_due(&mut self.as_ctx());
+                recent_chats::save_if_changed(&mut self.recent_chats, &mut self.storage);
                 self.storage.save_last_action("tick")?;
             }
             AppEvent::QuitRequested => {
+                pending_ops::commit_all(&mut self.as_ctx());
                 chat_open::close_tdlib_chat(&mut self.as_ctx());
+                recent_chats::save_if_changed(&mut self.recent_chats, &mut self.storage);
                 self.state.stop();
             }
             AppEvent::CommandOutputLine { text, replace_last } => {
@@ -371,29 +461,59 @@ where
                 voice::handle_command_exited(&mut self.as_ctx(), success);
             }
             AppEvent::InputKey(key) => {
-                if self.state.chat_search().is_some() {
+                if self.state.alert().is_some() {
                     match key.key.as_str() {
-                        "esc" | "enter" => self.state.close_chat_search(),
-                        "backspace" => {
-                            let search = self.state.chat_search_mut().unwrap();
-                            search.delete_char_before();
-                            let q = search.query().to_owned();
-                            if !q.is_empty() {
-                                self.state.chat_list_mut().select_by_query(&q);
-                            }
-                        }
-                        k if k.chars().count() == 1 => {
-                            let ch = k.chars().next().unwrap();
-                            let search = self.state.chat_search_mut().unwrap();
-                            search.insert_char(ch);
-                            let q = search.query().to_owned();
-                            self.state.chat_list_mut().select_by_query(&q);
-                        }
+                        "q" | "esc" | "enter" => self.state.close_alert(),
                         _ => {}
                     }
                     return Ok(());
                 }
 
+                if self.state.delete_confirm().is_some() {
+                    message_actions::handle_delete_confirm_key(&mut self.as_ctx(), &key.key);
+                    return Ok(());
+                }
+
+                if self.state.chat_confirm().is_some() {
+                    chat_list::handle_chat_confirm_key(&mut self.as_ctx(), &key.key);
+                    return Ok(());
+                }
+
+                if self.state.join_prompt().is_some() {
+                    links::handle_join_prompt_key(&mut self.as_ctx(), &key.key);
+                    return Ok(());
+                }
+
+                if self.state.go_to_prompt().is_some() {
+                    go_to_chat::handle_go_to_prompt_key(&mut self.as_ctx(), &key.key);
+                    return Ok(());
+                }
+
+                if self.state.new_chat_wizard().is_some() {
+                    new_chat::handle_new_chat_wizard_key(&mut self.as_ctx(), &key.key);
+                    return Ok(());
+                }
+
+                if self.state.link_hints().is_some() {
+                    links::handle_link_hints_key(&mut self.as_ctx(), &key);
+                    return Ok(());
+                }
+
+                if self.state.chat_search().is_some() {
+                    chat_search::handle_chat_search_key(&mut self.as_ctx(), &key.key);
+                    return Ok(());
+                }
+
+                if self.state.quick_switcher().is_some() {
+                    recent_chats::handle_quick_switcher_key(&mut self.as_ctx(), &key);
+                    return Ok(());
+                }
+
+                if self.state.date_prompt().is_some() {
+                    jump_to_date::handle_date_prompt_key(&mut self.as_ctx(), &key.key);
+                    return Ok(());
+                }
+
                 if self.state.command_popup().is_some() {
                     voice::handle_command_popup_key(&mut self.as_ctx(), &key.key);
                     return Ok(());
@@ -428,12 +548,52 @@ where
                     return Ok(());
                 }
 
+                if self.state.inline_button_selection().is_some() {
+                    inline_buttons::handle_selection_key(&mut self.as_ctx(), &key.key);
+                    return Ok(());
+                }
+
+                if self.state.reply_keyboard_selection().is_some() {
+                    reply_keyboard::handle_selection_key(&mut self.as_ctx(), &key.key);
+                    return Ok(());
+                }
+
+                if self.state.emoji_picker().is_some() {
+                    emoji_picker::handle_picker_key(&mut self.as_ctx(), &key.key);
+                    return Ok(());
+                }
+
                 if self.state.active_pane() == ActivePane::MessageInput {
+                    if key.ctrl {
+                        if let ResolveResult::Action(Action::InsertEmoji) =
+                            self.keymap
+                                .resolve(&key.key, true, KeyContext::MessageInput)
+                        {
+                            emoji_picker::open(&mut self.as_ctx());
+                            return Ok(());
+                        }
+                    }
                     message_input::handle_message_input_key(&mut self.as_ctx(), &key.key);
                     return Ok(());
                 }
 
+                if key.key == "esc"
+                    && self.state.active_pane() == ActivePane::ChatList
+                    && self.state.pending_forward().is_some()
+                {
+                    message_actions::cancel_forward(&mut self.as_ctx());
+                    return Ok(());
+                }
+
+                if self.state.active_pane() == ActivePane::ChatList
+                    && self.state.contacts().is_some_and(|c| c.is_filtering())
+                {
+                    contacts::handle_filter_key(&mut self.as_ctx(), &key.key);
+                    return Ok(());
+                }
+
                 let context = match self.state.active_pane() {
+                    ActivePane::ChatList if self.state.contacts().is_some() => KeyContext::Contacts,
                     ActivePane::ChatList if self.state.forum_topic_list().is_some() => {
                         KeyContext::ForumTopicList
                     }
@@ -448,11 +608,17 @@ where
                         KeyContext::ForumTopicList => {
                             self.handle_forum_topic_list_action(action)?
                         }
+                        KeyContext::Contacts => {
+                            key_dispatch::dispatch_contacts_action(&mut self.as_ctx(), action)
+                        }
                         KeyContext::Messages => self.handle_messages_action(action)?,
-                        KeyContext::Global => {}
+                        KeyContext::MessageInput | KeyContext::Global => {}
                     },
                     ResolveResult::Pending | ResolveResult::Unmatched => {}
                 }
+                if !self.state.is_running() {
+                    recent_chats::save_if_changed(&mut self.recent_chats, &mut self.storage);
+                }
             }
             AppEvent::ConnectivityChanged(status) => {
                 self.state.set_connectivity_status(status);
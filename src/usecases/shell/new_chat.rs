@@ -0,0 +1,107 @@
+//! "New chat" wizard: creates a group, supergroup, forum or channel and
+//! opens it once Telegram confirms.
+//!
+//! Members are picked from contacts, loaded when the wizard opens through
+//! the same request the contacts pane uses.
+
+use crate::{
+    domain::{
+        events::BackgroundError,
+        new_chat_wizard_state::{CreatedChat, NewChatStep, NewChatWizardState},
+    },
+    usecases::background::TaskDispatcher,
+};
+
+use super::{links, OrchestratorCtx};
+
+pub(super) fn open_new_chat_wizard<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>) {
+    ctx.state.open_new_chat_wizard();
+    ctx.dispatcher.dispatch_load_contacts();
+}
+
+pub(super) fn handle_new_chat_wizard_key<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    key: &str,
+) {
+    let Some(wizard) = ctx.state.new_chat_wizard_mut() else {
+        return;
+    };
+
+    match (wizard.step(), key) {
+        (_, "esc") => {
+            let stepped_back = wizard.back();
+            if !stepped_back {
+                ctx.state.close_new_chat_wizard();
+            }
+        }
+        (NewChatStep::Kind, "q") => ctx.state.close_new_chat_wizard(),
+        (NewChatStep::Kind, "j" | "down") => wizard.select_next_kind(),
+        (NewChatStep::Kind, "k" | "up") => wizard.select_previous_kind(),
+        (NewChatStep::Members, "down") => wizard.select_next_member(),
+        (NewChatStep::Members, "up") => wizard.select_previous_member(),
+        (NewChatStep::Members, " ") => wizard.toggle_member(),
+        (NewChatStep::Members, "enter") => submit(ctx),
+        (_, "enter") => {
+            wizard.advance();
+        }
+        (_, "backspace") => wizard.pop_char(),
+        (_, k) if k.chars().count() == 1 => {
+            let ch = k.chars().next().unwrap();
+            wizard.push_char(ch);
+        }
+        _ => {}
+    }
+}
+
+fn submit<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>) {
+    let Some(request) = ctx.state.new_chat_wizard().map(NewChatWizardState::request) else {
+        return;
+    };
+    if request.kind.requires_members() && request.member_ids.is_empty() {
+        ctx.state
+            .set_notification("Pick at least one member for a group");
+        return;
+    }
+
+    ctx.state.close_new_chat_wizard();
+    ctx.state
+        .set_notification(format!("Creating {}...", request.title));
+    ctx.dispatcher.dispatch_create_chat(request);
+}
+
+pub(super) fn handle_chat_created<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    title: String,
+    result: Result<CreatedChat, BackgroundError>,
+) {
+    match result {
+        Ok(created) => {
+            links::open_linked_chat(ctx, &created.chat);
+            ctx.state
+                .set_notification(created_notification(&title, &created));
+        }
+        Err(error) => {
+            tracing::warn!(code = error.code, "background: creating chat failed");
+            ctx.state
+                .set_notification(if error.code == "CHAT_CREATION_REJECTED" {
+                    format!("Telegram refused to create {title}")
+                } else {
+                    format!("Failed to create {title}")
+                });
+        }
+    }
+}
+
+/// "Created War room", plus whatever Telegram did not apply.
+fn created_notification(title: &str, created: &CreatedChat) -> String {
+    let mut notification = format!("Created {title}");
+    match created.members_not_added {
+        0 => {}
+        1 => notification.push_str(", 1 member could not be added"),
+        n => notification.push_str(&format!(", {n} members could not be added")),
+    }
+    if created.description_failed {
+        notification.push_str(", description not set");
+    }
+    notification
+}
@@ -0,0 +1,274 @@
+//! Destructive actions held back for an undo grace period.
+//!
+//! Instead of being dispatched right away, an operation is applied to the
+//! UI optimistically and queued; its network call is made once the grace
+//! period ends (or on quit). `u` rolls back the newest queued operation
+//! without any network call.
+
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    domain::{
+        chat_manage_state::ChatManageOp, delete_confirm_state::DeleteScope,
+        events::BackgroundError, message::Message,
+    },
+    usecases::background::TaskDispatcher,
+};
+
+use super::{chat_list, OrchestratorCtx};
+
+/// Grace period used when the orchestrator is built without config.
+pub(super) const DEFAULT_UNDO_GRACE: Duration = Duration::from_secs(5);
+
+/// How long quitting waits for each queued operation to reach the server.
+const QUIT_COMMIT_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// A destructive action waiting for its grace period to end.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum PendingOperation {
+    DeleteMessages {
+        chat_id: i64,
+        message_ids: Vec<i64>,
+        scope: DeleteScope,
+        /// The hidden messages, put back on undo.
+        removed: Vec<Message>,
+    },
+    /// Leaving, deleting or clearing the history of a chat.
+    ManageChat {
+        chat_id: i64,
+        title: String,
+        op: ChatManageOp,
+    },
+}
+
+impl PendingOperation {
+    /// What was done, e.g. "Deleted 3 messages".
+    fn describe(&self) -> String {
+        match self {
+            Self::DeleteMessages {
+                message_ids, scope, ..
+            } => {
+                let suffix = match scope {
+                    DeleteScope::ForMe => " for you",
+                    DeleteScope::ForEveryone => " for everyone",
+                    DeleteScope::Auto => "",
+                };
+                format!("Deleted {}{suffix}", messages_label(message_ids.len()))
+            }
+            Self::ManageChat { title, op, .. } => op.done_message(title),
+        }
+    }
+
+    /// Makes the network call the operation stands for.
+    fn commit<D: TaskDispatcher>(self, ctx: &mut OrchestratorCtx<'_, D>) {
+        match self {
+            // The messages stay hidden until `MessagesDeleted` arrives so a
+            // refresh racing the deletion does not bring them back.
+            Self::DeleteMessages {
+                chat_id,
+                message_ids,
+                scope,
+                ..
+            } => {
+                ctx.dispatcher
+                    .dispatch_delete_messages(chat_id, message_ids, scope);
+            }
+            // A left chat stays hidden until `ChatManaged` arrives.
+            Self::ManageChat { chat_id, op, .. } => {
+                ctx.dispatcher.dispatch_manage_chat(chat_id, op)
+            }
+        }
+    }
+
+    /// Makes the network call and waits for it, since no background
+    /// result is handled once the shell stops.
+    fn commit_before_quit<D: TaskDispatcher>(self, ctx: &mut OrchestratorCtx<'_, D>) {
+        match self {
+            Self::DeleteMessages {
+                chat_id,
+                message_ids,
+                scope,
+                ..
+            } => {
+                let count = message_ids.len();
+                if let Err(error) = ctx.dispatcher.delete_messages_blocking(
+                    chat_id,
+                    message_ids,
+                    scope,
+                    QUIT_COMMIT_TIMEOUT,
+                ) {
+                    tracing::warn!(chat_id, count, code = error.code, "delete on quit failed");
+                }
+            }
+            Self::ManageChat { chat_id, op, .. } => {
+                match ctx
+                    .dispatcher
+                    .manage_chat_blocking(chat_id, op, QUIT_COMMIT_TIMEOUT)
+                {
+                    Ok(()) if op.removes_chat() => ctx.recent_chats.remove_chat(chat_id),
+                    Ok(()) => {}
+                    Err(error) => {
+                        tracing::warn!(
+                            chat_id,
+                            ?op,
+                            code = error.code,
+                            "chat management on quit failed"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    /// Rolls back the optimistic UI change.
+    fn undo<D: TaskDispatcher>(self, ctx: &mut OrchestratorCtx<'_, D>) -> String {
+        match self {
+            Self::DeleteMessages {
+                chat_id,
+                message_ids,
+                removed,
+                ..
+            } => {
+                let open_chat = ctx.state.open_chat_mut();
+                open_chat.unhide_messages(chat_id, &message_ids);
+                open_chat.restore_messages(chat_id, removed);
+                format!("Restored {}", messages_label(message_ids.len()))
+            }
+            Self::ManageChat {
+                chat_id, title, op, ..
+            } => {
+                if op.removes_chat() {
+                    ctx.state.chat_list_mut().unhide_chat(chat_id);
+                    chat_list::dispatch_chat_list_refresh(ctx, true);
+                    format!("Restored {title}")
+                } else {
+                    format!("Kept the history of {title}")
+                }
+            }
+        }
+    }
+}
+
+pub(super) fn messages_label(count: usize) -> String {
+    if count == 1 {
+        "1 message".to_owned()
+    } else {
+        format!("{count} messages")
+    }
+}
+
+/// Queue of operations in the order they were made, each with the time
+/// its grace period ends.
+#[derive(Debug, Default)]
+pub struct PendingOperations {
+    queue: VecDeque<(Instant, PendingOperation)>,
+}
+
+impl PendingOperations {
+    fn push(&mut self, due: Instant, operation: PendingOperation) {
+        self.queue.push_back((due, operation));
+    }
+
+    fn take_due(&mut self, now: Instant) -> Vec<PendingOperation> {
+        let mut due = Vec::new();
+        while self.queue.front().is_some_and(|(at, _)| *at <= now) {
+            due.extend(self.queue.pop_front().map(|(_, op)| op));
+        }
+        due
+    }
+
+    fn take_latest(&mut self) -> Option<PendingOperation> {
+        self.queue.pop_back().map(|(_, op)| op)
+    }
+
+    fn take_all(&mut self) -> Vec<PendingOperation> {
+        self.queue.drain(..).map(|(_, op)| op).collect()
+    }
+
+    /// Ends the grace period of every queued operation.
+    #[cfg(test)]
+    pub(super) fn expire_all(&mut self) {
+        let now = Instant::now();
+        for (due, _) in &mut self.queue {
+            *due = now;
+        }
+    }
+}
+
+/// Queues `operation` for the configured grace period and offers undo in
+/// the status line; commits it right away when the grace period is zero.
+pub(super) fn schedule<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    operation: PendingOperation,
+) {
+    let description = operation.describe();
+    if ctx.undo_grace.is_zero() {
+        operation.commit(ctx);
+        ctx.state.set_notification(description);
+        return;
+    }
+
+    ctx.pending_ops
+        .push(Instant::now() + ctx.undo_grace, operation);
+    ctx.state
+        .set_notification_for(format!("{description} — press u to undo"), ctx.undo_grace);
+}
+
+/// Commits operations whose grace period has ended.
+pub(super) fn commit_due<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>) {
+    for operation in ctx.pending_ops.take_due(Instant::now()) {
+        operation.commit(ctx);
+    }
+}
+
+/// Commits every queued operation before quitting, waiting for each one.
+pub(super) fn commit_all<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>) {
+    for operation in ctx.pending_ops.take_all() {
+        operation.commit_before_quit(ctx);
+    }
+}
+
+/// A committed deletion finished: its messages no longer need hiding. On
+/// failure they are shown again by reloading the open chat.
+pub(super) fn handle_messages_deleted<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    chat_id: i64,
+    message_ids: &[i64],
+    result: Result<(), BackgroundError>,
+) {
+    ctx.state
+        .open_chat_mut()
+        .unhide_messages(chat_id, message_ids);
+    let Err(error) = result else {
+        return;
+    };
+
+    tracing::warn!(
+        chat_id,
+        code = error.code,
+        "background: delete messages failed"
+    );
+    ctx.state.set_notification(format!(
+        "Failed to delete {}",
+        messages_label(message_ids.len())
+    ));
+    if ctx.state.open_chat().chat_id() == Some(chat_id) {
+        *ctx.messages_refresh_in_flight = true;
+        let topic_id = ctx.state.open_chat().topic_id();
+        ctx.dispatcher.dispatch_load_messages(chat_id, topic_id);
+    }
+}
+
+/// Rolls back the newest queued operation.
+pub(super) fn undo_latest<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>) {
+    match ctx.pending_ops.take_latest() {
+        Some(operation) => {
+            let message = operation.undo(ctx);
+            ctx.state.set_notification(message);
+        }
+        None => ctx.state.set_notification("Nothing to undo"),
+    }
+}
@@ -0,0 +1,148 @@
+//! Recent chats: the quick switcher (`Ctrl+K`) and the "previous chat"
+//! toggle (`Ctrl+^`), both over the chats and forum topics in the order
+//! they were last opened.
+
+use crate::{
+    domain::{
+        events::KeyInput,
+        quick_switcher_state::QuickSwitcherState,
+        recent_chats::{RecentChat, RecentChats},
+    },
+    infra::contracts::StorageAdapter,
+    usecases::background::TaskDispatcher,
+};
+
+use super::{chat_open, forum, OrchestratorCtx};
+
+/// Restores the recent chats saved by the previous run.
+pub(super) fn load<S: StorageAdapter>(storage: &S) -> RecentChats {
+    match storage.load_recent_chats() {
+        Ok(entries) => RecentChats::from_entries(entries),
+        Err(error) => {
+            tracing::warn!(error = %error, "failed to load recent chats");
+            RecentChats::default()
+        }
+    }
+}
+
+/// Saves the recent chats when they changed. A failed save is logged and
+/// retried on the next change.
+pub(super) fn save_if_changed<S: StorageAdapter>(recent: &mut RecentChats, storage: &mut S) {
+    if !recent.take_unsaved() {
+        return;
+    }
+    if let Err(error) = storage.save_recent_chats(recent.entries()) {
+        tracing::warn!(error = %error, "failed to save recent chats");
+    }
+}
+
+pub(super) fn open_quick_switcher<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>) {
+    let entries = ctx.recent_chats.entries();
+    let Some(latest) = entries.first() else {
+        ctx.state.set_notification("No recent chats yet");
+        return;
+    };
+    let skip_first = is_open(ctx, latest);
+    ctx.state
+        .open_quick_switcher(QuickSwitcherState::new(entries.to_vec(), skip_first));
+}
+
+pub(super) fn handle_quick_switcher_key<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    key: &KeyInput,
+) {
+    let Some(switcher) = ctx.state.quick_switcher_mut() else {
+        return;
+    };
+
+    match (key.key.as_str(), key.ctrl) {
+        ("esc", _) => ctx.state.close_quick_switcher(),
+        ("enter", _) => {
+            let entry = switcher.selected_chat().cloned();
+            ctx.state.close_quick_switcher();
+            if let Some(entry) = entry {
+                open_recent_chat(ctx, &entry);
+            }
+        }
+        ("down" | "tab", _) | ("k" | "n", true) => switcher.select_next(),
+        ("up" | "backtab", _) | ("p", true) => switcher.select_previous(),
+        ("backspace", _) => switcher.delete_char_before(),
+        (k, false) if k.chars().count() == 1 => {
+            let ch = k.chars().next().unwrap();
+            switcher.insert_char(ch);
+        }
+        _ => {}
+    }
+}
+
+/// Opens the most recent chat other than the open one, so repeated use
+/// toggles between the last two chats.
+pub(super) fn switch_to_previous_chat<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>) {
+    let previous = ctx
+        .recent_chats
+        .entries()
+        .iter()
+        .find(|entry| !is_open(ctx, entry))
+        .cloned();
+    match previous {
+        Some(entry) => open_recent_chat(ctx, &entry),
+        None => ctx.state.set_notification("No previous chat"),
+    }
+}
+
+/// Whether `entry` is the chat (or topic) shown in the messages pane.
+fn is_open<D: TaskDispatcher>(ctx: &OrchestratorCtx<'_, D>, entry: &RecentChat) -> bool {
+    let open_chat = ctx.state.open_chat();
+    open_chat.chat_id() == Some(entry.chat_id) && open_chat.topic_id() == entry.topic_id()
+}
+
+/// Opens `entry` straight in the messages pane. A topic also shows its
+/// forum's topic list, as if the user had picked it there.
+fn open_recent_chat<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>, entry: &RecentChat) {
+    let chat_id = entry.chat_id;
+    let Some(topic) = &entry.topic else {
+        if ctx.state.forum_topic_list().is_some() {
+            ctx.state.leave_forum();
+        }
+        ctx.state.chat_list_mut().select_chat(chat_id);
+        // Prefer the chat list's copy: the title may have changed since.
+        let (title, chat_type) = ctx
+            .state
+            .chat_list()
+            .loaded_chats()
+            .iter()
+            .find(|chat| chat.chat_id == chat_id)
+            .map_or((entry.title.clone(), entry.chat_type), |chat| {
+                (chat.title.clone(), chat.chat_type)
+            });
+        chat_open::open_chat_messages(ctx, chat_id, title, chat_type);
+        return;
+    };
+
+    let in_forum = ctx
+        .state
+        .forum_topic_list()
+        .is_some_and(|forum| forum.parent_chat_id() == chat_id);
+    if !in_forum {
+        if ctx.state.forum_topic_list().is_some() {
+            ctx.state.leave_forum();
+        }
+        ctx.state.chat_list_mut().select_chat(chat_id);
+        forum::enter_forum(ctx, chat_id, entry.title.clone());
+    }
+    // Topics of a forum entered just now are not loaded yet; the next
+    // chat-list refresh corrects the forum's badge in that case.
+    let topic_unread = ctx
+        .state
+        .forum_topic_list()
+        .and_then(|forum| forum.topics().iter().find(|t| t.topic_id == topic.topic_id))
+        .map_or(0, |t| t.unread_count);
+    forum::open_topic(
+        ctx,
+        chat_id,
+        entry.title.clone(),
+        topic.topic_id,
+        topic.name.clone(),
+        topic_unread,
+    );
+}
@@ -0,0 +1,68 @@
+use crate::{
+    domain::{button_grid_state::ReplyKeyboardSelection, chat::ChatType},
+    usecases::background::TaskDispatcher,
+};
+
+use super::{message_input, OrchestratorCtx};
+
+/// Enters button-selection mode on the open chat's custom reply keyboard.
+pub(super) fn start_selection<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>) {
+    if ctx.state.open_chat().chat_type() == ChatType::Channel {
+        return;
+    }
+
+    let selection = ctx
+        .state
+        .open_chat()
+        .reply_keyboard()
+        .and_then(|(message_id, keyboard)| {
+            ReplyKeyboardSelection::new(message_id, keyboard.rows.clone())
+        });
+
+    match selection {
+        Some(selection) => ctx.state.start_reply_keyboard_selection(selection),
+        None => ctx.state.set_notification("No reply keyboard in this chat"),
+    }
+}
+
+pub(super) fn handle_selection_key<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>, key: &str) {
+    let Some(selection) = ctx.state.reply_keyboard_selection_mut() else {
+        return;
+    };
+
+    match key {
+        "h" | "left" => selection.move_left(),
+        "l" | "right" => selection.move_right(),
+        "k" | "up" => selection.move_up(),
+        "j" | "down" => selection.move_down(),
+        "enter" => {
+            let message_id = selection.message_id();
+            let text = selection.selected().clone();
+            ctx.state.stop_reply_keyboard_selection();
+            press_button(ctx, message_id, text);
+        }
+        "esc" | "q" | "B" => ctx.state.stop_reply_keyboard_selection(),
+        _ => {}
+    }
+}
+
+/// Sends the button text as a regular message, replying to the message
+/// being composed a reply to, if any.
+fn press_button<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    message_id: i64,
+    text: String,
+) {
+    let one_time = ctx
+        .state
+        .open_chat()
+        .reply_keyboard()
+        .is_some_and(|(id, keyboard)| id == message_id && keyboard.one_time);
+
+    let reply_context = ctx.state.message_input_mut().take_reply_to();
+    message_input::send_text(ctx, text, reply_context, Vec::new());
+
+    if one_time {
+        ctx.state.open_chat_mut().hide_reply_keyboard(message_id);
+    }
+}
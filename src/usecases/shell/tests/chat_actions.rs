@@ -0,0 +1,120 @@
+use super::*;
+
+fn type_keys(o: &mut TestOrchestrator, keys: &[&str]) {
+    for key in keys {
+        o.handle_event(AppEvent::InputKey(KeyInput::new(*key, false)))
+            .unwrap();
+    }
+}
+
+#[test]
+fn typing_in_input_sends_throttled_typing_action() {
+    let mut o = orchestrator_with_open_chat(vec![chat(1, "General")], 1, vec![message(1, "Hello")]);
+
+    type_keys(&mut o, &["i", "h", "e", "y"]);
+
+    assert_eq!(
+        o.dispatcher.chat_actions(),
+        vec![(1, None, ChatAction::Typing)]
+    );
+}
+
+#[test]
+fn clearing_input_cancels_typing_action() {
+    let mut o = orchestrator_with_open_chat(vec![chat(1, "General")], 1, vec![message(1, "Hello")]);
+
+    type_keys(&mut o, &["i", "h", "i", "backspace", "backspace"]);
+
+    assert_eq!(
+        o.dispatcher.chat_actions(),
+        vec![(1, None, ChatAction::Typing), (1, None, ChatAction::Cancel)]
+    );
+}
+
+#[test]
+fn sending_message_does_not_send_cancel() {
+    let mut o = orchestrator_with_open_chat(vec![chat(1, "General")], 1, vec![message(1, "Hello")]);
+
+    type_keys(&mut o, &["i", "h", "i", "enter"]);
+
+    assert_eq!(o.dispatcher.send_dispatch_count(), 1);
+    assert_eq!(
+        o.dispatcher.chat_actions(),
+        vec![(1, None, ChatAction::Typing)]
+    );
+}
+
+#[test]
+fn cursor_movement_does_not_send_actions() {
+    let mut o = orchestrator_with_open_chat(vec![chat(1, "General")], 1, vec![message(1, "Hello")]);
+
+    type_keys(&mut o, &["i", "left", "right", "home", "end"]);
+
+    assert!(o.dispatcher.chat_actions().is_empty());
+}
+
+#[test]
+fn disabled_chat_actions_send_nothing() {
+    let mut o = orchestrator_with_open_chat(vec![chat(1, "General")], 1, vec![message(1, "Hello")]);
+    o.send_chat_actions = false;
+
+    type_keys(&mut o, &["i", "h", "backspace"]);
+
+    assert!(o.dispatcher.chat_actions().is_empty());
+}
+
+#[test]
+fn tick_during_recording_sends_recording_action() {
+    let mut o = orchestrator_with_open_chat(vec![chat(1, "General")], 1, vec![message(1, "Hello")]);
+    o.state.open_command_popup(
+        "Recording Voice",
+        crate::domain::command_popup_state::CommandPopupKind::Recording,
+    );
+
+    o.handle_event(AppEvent::Tick).unwrap();
+    o.handle_event(AppEvent::Tick).unwrap();
+
+    assert_eq!(
+        o.dispatcher.chat_actions(),
+        vec![(1, None, ChatAction::RecordingVoiceNote)]
+    );
+}
+
+#[test]
+fn recording_exit_cancels_recording_action() {
+    let mut o = orchestrator_with_open_chat(vec![chat(1, "General")], 1, vec![message(1, "Hello")]);
+    o.state.open_command_popup(
+        "Recording Voice",
+        crate::domain::command_popup_state::CommandPopupKind::Recording,
+    );
+    o.handle_event(AppEvent::Tick).unwrap();
+
+    o.handle_event(AppEvent::CommandExited { success: true })
+        .unwrap();
+
+    assert_eq!(
+        o.dispatcher.chat_actions(),
+        vec![
+            (1, None, ChatAction::RecordingVoiceNote),
+            (1, None, ChatAction::Cancel)
+        ]
+    );
+}
+
+#[test]
+fn sending_voice_announces_upload() {
+    let mut o = orchestrator_with_open_chat(vec![chat(1, "General")], 1, vec![message(1, "Hello")]);
+
+    let tmp = std::env::temp_dir().join("rtg_test_chat_action_upload.oga");
+    std::fs::write(&tmp, b"fake audio").unwrap();
+    o.recording_file_path = Some(tmp.to_str().unwrap().to_owned());
+
+    o.send_voice_recording();
+
+    assert_eq!(
+        o.dispatcher.chat_actions(),
+        vec![(1, None, ChatAction::UploadingVoiceNote)]
+    );
+
+    let _ = std::fs::remove_file(&tmp);
+}
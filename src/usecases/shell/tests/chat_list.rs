@@ -32,6 +32,7 @@ fn chat_list_loaded_error_sets_error_state() {
     let mut o = make_orchestrator();
     o.handle_event(AppEvent::BackgroundTaskCompleted(
         BackgroundTaskResult::ChatListLoaded {
+            list: ChatListId::Main,
             result: Err(BackgroundError::new("CHAT_LIST_UNAVAILABLE")),
             all_loaded: false,
         },
@@ -57,6 +58,7 @@ fn chat_list_reload_preserves_selection_by_current_chat_id() {
     // (e.g. chat 3 got a new message and moved to the top)
     o.handle_event(AppEvent::BackgroundTaskCompleted(
         BackgroundTaskResult::ChatListLoaded {
+            list: ChatListId::Main,
             result: Ok(vec![chat(3, "Ops"), chat(1, "General"), chat(2, "Backend")]),
             all_loaded: false,
         },
@@ -106,6 +108,7 @@ fn chat_list_reload_cursor_follows_current_selection_not_dispatch_time() {
     // Background result arrives with reordered chats
     o.handle_event(AppEvent::BackgroundTaskCompleted(
         BackgroundTaskResult::ChatListLoaded {
+            list: ChatListId::Main,
             result: Ok(vec![
                 chat(5, "Epsilon"),
                 chat(1, "Alpha"),
@@ -142,6 +145,7 @@ fn chat_list_reload_falls_back_when_selected_chat_disappears() {
     // Background refresh arrives without chat 3
     o.handle_event(AppEvent::BackgroundTaskCompleted(
         BackgroundTaskResult::ChatListLoaded {
+            list: ChatListId::Main,
             result: Ok(vec![chat(1, "Alpha"), chat(2, "Beta")]),
             all_loaded: false,
         },
@@ -248,6 +252,7 @@ fn user_refresh_shows_notification_on_failure() {
     // Inject a failure
     o.handle_event(AppEvent::BackgroundTaskCompleted(
         BackgroundTaskResult::ChatListLoaded {
+            list: ChatListId::Main,
             result: Err(BackgroundError::new("CHAT_LIST_UNAVAILABLE")),
             all_loaded: false,
         },
@@ -382,8 +387,13 @@ fn chat_with_unread(
         outgoing_status: OutgoingReadStatus::default(),
         last_message_id: last_msg_id,
         unread_reaction_count: 0,
+        unread_mention_count: 0,
         is_forum: false,
         unread_topic_count: None,
+        is_muted: false,
+        is_marked_as_unread: false,
+        is_blocked: false,
+        username: None,
     }
 }
 
@@ -529,6 +539,7 @@ fn pending_flag_cleared_on_error_result_and_re_dispatches() {
     // Error result still triggers re-dispatch
     o.handle_event(AppEvent::BackgroundTaskCompleted(
         BackgroundTaskResult::ChatListLoaded {
+            list: ChatListId::Main,
             result: Err(BackgroundError::new("FAIL")),
             all_loaded: false,
         },
@@ -771,3 +782,600 @@ fn forum_unread_counts_loaded_patches_badges() {
         .collect();
     assert_eq!(badges, vec![Some(3), Some(0)]);
 }
+
+fn work_folder_update() -> AppEvent {
+    AppEvent::ChatUpdateReceived {
+        updates: vec![ChatUpdate::ChatFoldersChanged {
+            folders: vec![crate::domain::chat_folder::ChatFolder {
+                id: 3,
+                title: "Work".to_owned(),
+            }],
+            main_position: 0,
+        }],
+    }
+}
+
+#[test]
+fn bracket_switches_to_next_folder_and_loads_it() {
+    let mut o = orchestrator_with_chats(vec![chat(1, "General")]);
+    o.handle_event(work_folder_update()).unwrap();
+    assert_eq!(o.dispatcher.chat_list_dispatch_count(), 0);
+
+    o.handle_event(AppEvent::InputKey(KeyInput::new("]", false)))
+        .unwrap();
+
+    assert_eq!(o.state().chat_list().active_list(), ChatListId::Folder(3));
+    assert_eq!(o.state().chat_list().ui_state(), ChatListUiState::Loading);
+    assert_eq!(o.dispatcher.last_chat_list(), Some(ChatListId::Folder(3)));
+
+    o.handle_event(AppEvent::InputKey(KeyInput::new("[", false)))
+        .unwrap();
+    assert_eq!(o.state().chat_list().active_list(), ChatListId::Main);
+}
+
+#[test]
+fn bracket_without_folders_does_nothing() {
+    let mut o = orchestrator_with_chats(vec![chat(1, "General")]);
+
+    o.handle_event(AppEvent::InputKey(KeyInput::new("]", false)))
+        .unwrap();
+
+    assert_eq!(o.state().chat_list().ui_state(), ChatListUiState::Ready);
+    assert_eq!(o.dispatcher.chat_list_dispatch_count(), 0);
+}
+
+#[test]
+fn result_for_previous_folder_is_discarded_and_pending_load_runs() {
+    let mut o = orchestrator_with_chats(vec![chat(1, "General")]);
+    o.handle_event(work_folder_update()).unwrap();
+    o.handle_event(AppEvent::ChatUpdateReceived {
+        updates: vec![ChatUpdate::ChatMetadataChanged { chat_id: 1 }],
+    })
+    .unwrap();
+    o.handle_event(AppEvent::InputKey(KeyInput::new("]", false)))
+        .unwrap();
+    assert_eq!(o.dispatcher.chat_list_dispatch_count(), 1);
+
+    o.handle_event(AppEvent::BackgroundTaskCompleted(
+        BackgroundTaskResult::ChatListLoaded {
+            list: ChatListId::Main,
+            result: Ok(vec![chat(1, "General")]),
+            all_loaded: true,
+        },
+    ))
+    .unwrap();
+
+    assert_eq!(o.state().chat_list().ui_state(), ChatListUiState::Loading);
+    assert_eq!(o.dispatcher.chat_list_dispatch_count(), 2);
+    assert_eq!(o.dispatcher.last_chat_list(), Some(ChatListId::Folder(3)));
+}
+
+#[test]
+fn deleting_active_folder_reloads_main_list() {
+    let mut o = orchestrator_with_chats(vec![chat(1, "General")]);
+    o.handle_event(work_folder_update()).unwrap();
+    o.handle_event(AppEvent::InputKey(KeyInput::new("]", false)))
+        .unwrap();
+    o.handle_event(AppEvent::BackgroundTaskCompleted(
+        BackgroundTaskResult::ChatListLoaded {
+            list: ChatListId::Folder(3),
+            result: Ok(vec![chat(7, "Standup")]),
+            all_loaded: true,
+        },
+    ))
+    .unwrap();
+
+    o.handle_event(AppEvent::ChatUpdateReceived {
+        updates: vec![ChatUpdate::ChatFoldersChanged {
+            folders: Vec::new(),
+            main_position: 0,
+        }],
+    })
+    .unwrap();
+
+    assert_eq!(o.state().chat_list().active_list(), ChatListId::Main);
+    assert_eq!(o.dispatcher.last_chat_list(), Some(ChatListId::Main));
+}
+
+#[test]
+fn unread_chat_count_update_sets_tab_badge() {
+    let mut o = orchestrator_with_chats(vec![chat(1, "General")]);
+    o.handle_event(work_folder_update()).unwrap();
+
+    o.handle_event(AppEvent::ChatUpdateReceived {
+        updates: vec![ChatUpdate::UnreadChatCountChanged {
+            list: ChatListId::Folder(3),
+            unread_count: 2,
+            total_count: 6,
+        }],
+    })
+    .unwrap();
+
+    assert_eq!(o.state().chat_list().unread_count(ChatListId::Folder(3)), 2);
+    assert_eq!(o.dispatcher.chat_list_dispatch_count(), 0);
+}
+
+fn archive_counts_update(unread_count: u32, total_count: u32) -> AppEvent {
+    AppEvent::ChatUpdateReceived {
+        updates: vec![ChatUpdate::UnreadChatCountChanged {
+            list: ChatListId::Archive,
+            unread_count,
+            total_count,
+        }],
+    }
+}
+
+#[test]
+fn enter_on_archive_row_opens_archive_and_h_returns() {
+    let mut o = orchestrator_with_chats(vec![chat(1, "General")]);
+    o.handle_event(archive_counts_update(3, 5)).unwrap();
+
+    o.handle_event(AppEvent::InputKey(KeyInput::new("k", false)))
+        .unwrap();
+    assert!(o.state().chat_list().archive_row_selected());
+    o.handle_event(AppEvent::InputKey(KeyInput::new("enter", false)))
+        .unwrap();
+
+    assert_eq!(o.state().chat_list().active_list(), ChatListId::Archive);
+    assert_eq!(o.state().active_pane(), ActivePane::ChatList);
+    assert_eq!(o.dispatcher.last_chat_list(), Some(ChatListId::Archive));
+    o.handle_event(AppEvent::BackgroundTaskCompleted(
+        BackgroundTaskResult::ChatListLoaded {
+            list: ChatListId::Archive,
+            result: Ok(vec![chat(7, "Old news")]),
+            all_loaded: true,
+        },
+    ))
+    .unwrap();
+
+    o.handle_event(AppEvent::InputKey(KeyInput::new("h", false)))
+        .unwrap();
+    assert_eq!(o.state().chat_list().active_list(), ChatListId::Main);
+    assert_eq!(o.dispatcher.last_chat_list(), Some(ChatListId::Main));
+}
+
+#[test]
+fn a_archives_selected_chat_and_drops_it_from_main_list() {
+    let mut o = orchestrator_with_chats(vec![chat(1, "General"), chat(2, "Random")]);
+
+    o.handle_event(AppEvent::InputKey(KeyInput::new("a", false)))
+        .unwrap();
+
+    assert_eq!(o.dispatcher.chat_moves(), vec![(1, ChatListId::Archive)]);
+    assert_eq!(o.state().chat_list().chats().len(), 1);
+    assert_eq!(
+        o.state().chat_list().selected_chat().map(|c| c.chat_id),
+        Some(2)
+    );
+    assert_eq!(o.state().active_notification(), Some("Archived General"));
+}
+
+#[test]
+fn unarchive_outside_archive_is_refused() {
+    let mut o = orchestrator_with_chats(vec![chat(1, "General")]);
+
+    o.handle_event(AppEvent::InputKey(KeyInput::new("U", false)))
+        .unwrap();
+
+    assert!(o.dispatcher.chat_moves().is_empty());
+    assert_eq!(
+        o.state().active_notification(),
+        Some("Chat is not archived")
+    );
+}
+
+#[test]
+fn failed_archive_reloads_chat_list() {
+    let mut o = orchestrator_with_chats(vec![chat(1, "General"), chat(2, "Random")]);
+    o.handle_event(AppEvent::InputKey(KeyInput::new("a", false)))
+        .unwrap();
+
+    o.handle_event(AppEvent::BackgroundTaskCompleted(
+        BackgroundTaskResult::ChatMovedToList {
+            chat_id: 1,
+            list: ChatListId::Archive,
+            result: Err(BackgroundError::new("CHAT_MOVE_FAILED")),
+        },
+    ))
+    .unwrap();
+
+    assert_eq!(
+        o.state().active_notification(),
+        Some("Failed to archive chat")
+    );
+    assert_eq!(o.dispatcher.chat_list_dispatch_count(), 1);
+    assert_eq!(o.dispatcher.last_chat_list_force(), Some(true));
+}
+
+#[test]
+fn m_8_mutes_selected_chat_for_eight_hours() {
+    let mut o = orchestrator_with_chats(vec![chat(1, "News")]);
+
+    o.handle_event(AppEvent::InputKey(KeyInput::new("m", false)))
+        .unwrap();
+    o.handle_event(AppEvent::InputKey(KeyInput::new("8", false)))
+        .unwrap();
+
+    assert_eq!(
+        o.dispatcher.chat_mutes(),
+        vec![(1, Some(MuteDuration::EightHours))]
+    );
+    assert!(o.state().chat_list().chats()[0].is_muted);
+    assert_eq!(
+        o.state().active_notification(),
+        Some("Muted News for 8 hours")
+    );
+}
+
+#[test]
+fn unmute_of_unmuted_chat_is_refused() {
+    let mut o = orchestrator_with_chats(vec![chat(1, "News")]);
+
+    o.handle_event(AppEvent::InputKey(KeyInput::new("M", false)))
+        .unwrap();
+
+    assert!(o.dispatcher.chat_mutes().is_empty());
+    assert_eq!(o.state().active_notification(), Some("Chat is not muted"));
+}
+
+#[test]
+fn failed_mute_reloads_chat_list() {
+    let mut o = orchestrator_with_chats(vec![chat(1, "News")]);
+
+    o.handle_event(AppEvent::BackgroundTaskCompleted(
+        BackgroundTaskResult::ChatMuteChanged {
+            chat_id: 1,
+            muted: true,
+            result: Err(BackgroundError::new("CHAT_MUTE_FAILED")),
+        },
+    ))
+    .unwrap();
+
+    assert_eq!(o.state().active_notification(), Some("Failed to mute chat"));
+    assert_eq!(o.dispatcher.chat_list_dispatch_count(), 1);
+}
+
+fn pinned_chat(chat_id: i64, title: &str) -> ChatSummary {
+    let mut c = chat(chat_id, title);
+    c.is_pinned = true;
+    c
+}
+
+#[test]
+fn p_toggles_pin_of_selected_chat() {
+    let mut o = orchestrator_with_chats(vec![pinned_chat(1, "Inbox"), chat(2, "News")]);
+
+    o.handle_event(AppEvent::InputKey(KeyInput::new("p", false)))
+        .unwrap();
+    o.handle_event(AppEvent::InputKey(KeyInput::new("j", false)))
+        .unwrap();
+    o.handle_event(AppEvent::InputKey(KeyInput::new("p", false)))
+        .unwrap();
+
+    assert_eq!(
+        o.dispatcher.chat_pins(),
+        vec![(1, ChatListId::Main, false), (2, ChatListId::Main, true)]
+    );
+    assert_eq!(o.state().active_notification(), Some("Pinned News"));
+}
+
+#[test]
+fn shift_j_moves_pinned_chat_down_and_sends_order() {
+    let mut o = orchestrator_with_chats(vec![
+        pinned_chat(1, "Inbox"),
+        pinned_chat(2, "Family"),
+        chat(3, "News"),
+    ]);
+
+    o.handle_event(AppEvent::InputKey(KeyInput::new("J", false)))
+        .unwrap();
+    o.handle_event(AppEvent::InputKey(KeyInput::new("J", false)))
+        .unwrap();
+
+    assert_eq!(
+        o.dispatcher.pin_orders(),
+        vec![(ChatListId::Main, vec![2, 1])]
+    );
+    assert_eq!(
+        o.state().chat_list().selected_chat().map(|c| c.chat_id),
+        Some(1)
+    );
+}
+
+#[test]
+fn bang_toggles_unread_mark_and_read_clears_it() {
+    let mut o = orchestrator_with_chats(vec![chat(1, "To-do")]);
+
+    o.handle_event(AppEvent::InputKey(KeyInput::new("!", false)))
+        .unwrap();
+    assert!(o.state().chat_list().chats()[0].is_marked_as_unread);
+    assert_eq!(
+        o.state().active_notification(),
+        Some("Marked To-do as unread")
+    );
+
+    o.handle_event(AppEvent::InputKey(KeyInput::new("r", false)))
+        .unwrap();
+    assert!(!o.state().chat_list().chats()[0].is_marked_as_unread);
+    assert_eq!(o.dispatcher.unread_marks(), vec![(1, true), (1, false)]);
+}
+
+#[test]
+fn failed_pin_reloads_chat_list() {
+    let mut o = orchestrator_with_chats(vec![chat(1, "News")]);
+
+    o.handle_event(AppEvent::BackgroundTaskCompleted(
+        BackgroundTaskResult::ChatPinToggled {
+            chat_id: 1,
+            pinned: true,
+            result: Err(BackgroundError::new("CHAT_PIN_FAILED")),
+        },
+    ))
+    .unwrap();
+
+    assert_eq!(o.state().active_notification(), Some("Failed to pin chat"));
+    assert_eq!(o.dispatcher.chat_list_dispatch_count(), 1);
+}
+
+// ── Leave / delete / clear history / block ──
+
+fn group(chat_id: i64, title: &str) -> ChatSummary {
+    let mut c = chat(chat_id, title);
+    c.chat_type = crate::domain::chat::ChatType::Group;
+    c
+}
+
+fn press(o: &mut TestOrchestrator, key: &str) {
+    o.handle_event(AppEvent::InputKey(KeyInput::new(key, false)))
+        .unwrap();
+}
+
+/// Ends the undo grace period of queued operations.
+fn commit_pending(o: &mut TestOrchestrator) {
+    o.pending_ops.expire_all();
+    o.handle_event(AppEvent::Tick).unwrap();
+}
+
+#[test]
+fn dd_on_group_confirms_and_leaves() {
+    let mut o = orchestrator_with_chats(vec![group(1, "Ops"), chat(2, "Alice")]);
+
+    press(&mut o, "d");
+    press(&mut o, "d");
+    let confirm = o.state().chat_confirm().expect("confirm popup");
+    assert_eq!(confirm.question(), "Leave the group Ops?");
+
+    // Keys the popup does not offer are ignored.
+    press(&mut o, "e");
+    assert!(o.state().chat_confirm().is_some());
+    press(&mut o, "y");
+
+    assert!(o.state().chat_confirm().is_none());
+    assert_eq!(o.state().chat_list().chats().len(), 1);
+    assert_eq!(
+        o.state().active_notification(),
+        Some("Left Ops — press u to undo")
+    );
+    assert!(o.dispatcher.chat_manage_ops().is_empty());
+
+    commit_pending(&mut o);
+    assert_eq!(
+        o.dispatcher.chat_manage_ops(),
+        vec![(1, ChatManageOp::Leave)]
+    );
+}
+
+#[test]
+fn u_brings_back_a_left_chat_without_network_call() {
+    let mut o = orchestrator_with_chats(vec![group(1, "Ops"), chat(2, "Alice")]);
+    press(&mut o, "d");
+    press(&mut o, "d");
+    press(&mut o, "y");
+
+    // A refresh during the grace period keeps the chat hidden.
+    inject_chat_list(&mut o, vec![group(1, "Ops"), chat(2, "Alice")]);
+    assert_eq!(o.state().chat_list().chats().len(), 1);
+
+    let refreshes = o.dispatcher.chat_list_dispatch_count();
+    press(&mut o, "u");
+    assert_eq!(o.state().active_notification(), Some("Restored Ops"));
+    assert_eq!(o.dispatcher.chat_list_dispatch_count(), refreshes + 1);
+    inject_chat_list(&mut o, vec![group(1, "Ops"), chat(2, "Alice")]);
+    assert_eq!(o.state().chat_list().chats().len(), 2);
+
+    commit_pending(&mut o);
+    assert!(o.dispatcher.chat_manage_ops().is_empty());
+}
+
+#[test]
+fn quit_waits_for_a_pending_leave() {
+    let mut o = orchestrator_with_chats(vec![group(1, "Ops")]);
+    press(&mut o, "d");
+    press(&mut o, "d");
+    press(&mut o, "y");
+
+    o.handle_event(AppEvent::QuitRequested).unwrap();
+
+    assert!(o.dispatcher.chat_manage_ops().is_empty());
+    assert_eq!(
+        o.dispatcher.blocking_chat_manage_ops(),
+        vec![(1, ChatManageOp::Leave)]
+    );
+}
+
+#[test]
+fn deleting_open_private_chat_closes_it() {
+    let mut o = orchestrator_with_open_chat(vec![chat(1, "Alice")], 1, vec![message(1, "Hi")]);
+    press(&mut o, "h");
+
+    press(&mut o, "d");
+    press(&mut o, "d");
+    press(&mut o, "e");
+
+    assert_eq!(o.state().open_chat().chat_id(), None);
+    assert_eq!(
+        o.state().active_notification(),
+        Some("Deleted Alice — press u to undo")
+    );
+    commit_pending(&mut o);
+    assert_eq!(
+        o.dispatcher.chat_manage_ops(),
+        vec![(1, ChatManageOp::Delete)]
+    );
+}
+
+#[test]
+fn esc_cancels_clear_history_confirmation() {
+    let mut o = orchestrator_with_chats(vec![chat(1, "Alice")]);
+
+    press(&mut o, "X");
+    assert!(o.state().chat_confirm().is_some());
+    press(&mut o, "esc");
+
+    assert!(o.state().chat_confirm().is_none());
+    assert!(o.dispatcher.chat_manage_ops().is_empty());
+}
+
+#[test]
+fn clear_history_for_me_keeps_chat_in_list() {
+    let mut o = orchestrator_with_chats(vec![chat(1, "Alice")]);
+
+    press(&mut o, "X");
+    press(&mut o, "m");
+    commit_pending(&mut o);
+
+    assert_eq!(
+        o.dispatcher.chat_manage_ops(),
+        vec![(
+            1,
+            ChatManageOp::ClearHistory {
+                for_everyone: false
+            }
+        )]
+    );
+    assert_eq!(o.state().chat_list().chats().len(), 1);
+}
+
+#[test]
+fn b_toggles_block_of_private_chat_only() {
+    let mut o = orchestrator_with_chats(vec![chat(1, "Spammer"), group(2, "Ops")]);
+
+    press(&mut o, "B");
+    assert!(o.state().chat_list().chats()[0].is_blocked);
+    assert_eq!(o.state().active_notification(), Some("Blocked Spammer"));
+
+    press(&mut o, "j");
+    press(&mut o, "B");
+    assert_eq!(
+        o.state().active_notification(),
+        Some("Only users in private chats can be blocked")
+    );
+    assert_eq!(
+        o.dispatcher.chat_manage_ops(),
+        vec![(1, ChatManageOp::SetBlocked(true))]
+    );
+}
+
+#[test]
+fn failed_leave_reloads_chat_list() {
+    let mut o = orchestrator_with_chats(vec![group(1, "Ops")]);
+
+    o.handle_event(AppEvent::BackgroundTaskCompleted(
+        BackgroundTaskResult::ChatManaged {
+            chat_id: 1,
+            op: ChatManageOp::Leave,
+            result: Err(BackgroundError::new("CHAT_MANAGE_FAILED")),
+        },
+    ))
+    .unwrap();
+
+    assert_eq!(
+        o.state().active_notification(),
+        Some("Failed to leave chat")
+    );
+    assert_eq!(o.dispatcher.chat_list_dispatch_count(), 1);
+}
+
+// ── Filters ──
+
+#[test]
+fn f_keys_filter_unread_direct_messages() {
+    let mut o = orchestrator_with_chats(vec![
+        chat_with_unread(1, "Alice", 3, Some(10)),
+        group(2, "Ops"),
+        chat(3, "Bob"),
+        chat_with_unread(4, "Carol", 1, Some(11)),
+    ]);
+
+    press(&mut o, "f");
+    press(&mut o, "u");
+    press(&mut o, "f");
+    press(&mut o, "p");
+
+    let ids: Vec<i64> = o
+        .state()
+        .chat_list()
+        .chats()
+        .iter()
+        .map(|c| c.chat_id)
+        .collect();
+    assert_eq!(ids, vec![1, 4]);
+    assert_eq!(o.state().active_notification(), Some("Showing unread DMs"));
+
+    press(&mut o, "f");
+    press(&mut o, "0");
+    assert_eq!(o.state().chat_list().chats().len(), 4);
+    assert_eq!(
+        o.state().active_notification(),
+        Some("Chat filters cleared")
+    );
+}
+
+#[test]
+fn filter_with_few_matches_loads_more_chats_until_all_loaded() {
+    let mut o = orchestrator_with_chats(vec![chat(1, "Alice"), group(2, "Ops")]);
+    let limit = o.state().chat_list().total_limit();
+
+    press(&mut o, "f");
+    press(&mut o, "u");
+    assert!(o.state().chat_list().chats().is_empty());
+    assert_eq!(o.dispatcher.chat_list_dispatch_count(), 1);
+    assert!(o.state().chat_list().total_limit() > limit);
+
+    // Still no match in the next page: keep paging.
+    inject_chat_list(
+        &mut o,
+        vec![chat(1, "Alice"), group(2, "Ops"), chat(3, "Bob")],
+    );
+    assert_eq!(o.dispatcher.chat_list_dispatch_count(), 2);
+
+    o.handle_event(AppEvent::BackgroundTaskCompleted(
+        BackgroundTaskResult::ChatListLoaded {
+            list: ChatListId::Main,
+            result: Ok(vec![
+                chat(1, "Alice"),
+                chat_with_unread(5, "Dan", 1, Some(3)),
+            ]),
+            all_loaded: true,
+        },
+    ))
+    .unwrap();
+    assert_eq!(o.dispatcher.chat_list_dispatch_count(), 2);
+    assert_eq!(
+        o.state().chat_list().selected_chat().map(|c| c.chat_id),
+        Some(5)
+    );
+}
+
+#[test]
+fn clearing_filters_when_none_is_on_says_so() {
+    let mut o = orchestrator_with_chats(vec![chat(1, "Alice")]);
+
+    press(&mut o, "f");
+    press(&mut o, "0");
+
+    assert_eq!(
+        o.state().active_notification(),
+        Some("No chat filters are on")
+    );
+}
@@ -0,0 +1,119 @@
+use super::*;
+
+fn press(o: &mut TestOrchestrator, key: &str) {
+    o.handle_event(AppEvent::InputKey(KeyInput::new(key, false)))
+        .unwrap();
+}
+
+fn type_text(o: &mut TestOrchestrator, text: &str) {
+    for ch in text.chars() {
+        press(o, &ch.to_string());
+    }
+}
+
+fn searched(o: &mut TestOrchestrator, query: &str, on_server: bool, chats: Vec<ChatSummary>) {
+    o.handle_event(AppEvent::BackgroundTaskCompleted(
+        BackgroundTaskResult::ChatsSearched {
+            query: query.to_owned(),
+            on_server,
+            result: Ok(chats),
+        },
+    ))
+    .unwrap();
+}
+
+/// Ends the typing pause and lets the next tick run the search.
+fn pause_typing(o: &mut TestOrchestrator) {
+    o.state
+        .chat_search_mut()
+        .expect("search open")
+        .expire_typing_pause();
+    o.handle_event(AppEvent::Tick).unwrap();
+}
+
+fn result_ids(o: &TestOrchestrator) -> Vec<i64> {
+    o.state()
+        .chat_search()
+        .expect("search open")
+        .results()
+        .iter()
+        .map(|r| r.chat.chat_id)
+        .collect()
+}
+
+#[test]
+fn typing_ranks_loaded_chats_and_searches_tdlib() {
+    let mut o = orchestrator_with_chats(vec![
+        chat(1, "Vasya"),
+        chat(2, "Alice Smith"),
+        chat(3, "Bob"),
+    ]);
+    press(&mut o, "/");
+
+    type_text(&mut o, "as");
+
+    assert_eq!(result_ids(&o), vec![2, 1]);
+    o.handle_event(AppEvent::Tick).unwrap();
+    assert!(o.dispatcher.chat_searches().is_empty(), "still typing");
+
+    pause_typing(&mut o);
+    o.handle_event(AppEvent::Tick).unwrap();
+    assert_eq!(o.dispatcher.chat_searches(), vec!["as".to_owned()]);
+}
+
+#[test]
+fn local_results_end_the_search_for_short_queries() {
+    let mut o = orchestrator_with_chats(vec![chat(1, "Alice")]);
+    press(&mut o, "/");
+    type_text(&mut o, "al");
+    pause_typing(&mut o);
+
+    searched(&mut o, "al", false, vec![chat(5, "Alan")]);
+
+    let search = o.state().chat_search().expect("search open");
+    assert!(!search.is_searching());
+    assert_eq!(result_ids(&o), vec![1, 5]);
+}
+
+#[test]
+fn enter_opens_chat_found_beyond_loaded_list() {
+    let mut o = orchestrator_with_chats(vec![chat(1, "Alice")]);
+    press(&mut o, "/");
+    type_text(&mut o, "arch");
+    pause_typing(&mut o);
+
+    searched(&mut o, "arch", false, vec![chat(42, "Archive of 2019")]);
+    searched(&mut o, "arch", true, Vec::new());
+    assert_eq!(result_ids(&o), vec![42]);
+    press(&mut o, "enter");
+
+    assert!(o.state().chat_search().is_none());
+    assert_eq!(o.state().open_chat().chat_id(), Some(42));
+}
+
+#[test]
+fn down_selects_next_result_and_search_keys_do_not_trigger_bindings() {
+    let mut o = orchestrator_with_chats(vec![chat(1, "Anna"), chat(2, "Ann")]);
+    press(&mut o, "/");
+    type_text(&mut o, "q");
+    press(&mut o, "backspace");
+    type_text(&mut o, "an");
+
+    press(&mut o, "down");
+    press(&mut o, "enter");
+
+    assert!(o.state().is_running());
+    assert_eq!(o.state().open_chat().chat_id(), Some(2));
+}
+
+#[test]
+fn results_after_closing_are_ignored() {
+    let mut o = orchestrator_with_chats(vec![chat(1, "Alice")]);
+    press(&mut o, "/");
+    type_text(&mut o, "al");
+    press(&mut o, "esc");
+
+    searched(&mut o, "al", true, vec![chat(5, "Alan")]);
+
+    assert!(o.state().chat_search().is_none());
+}
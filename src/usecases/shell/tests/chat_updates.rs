@@ -257,8 +257,13 @@ fn group_chat(chat_id: i64, title: &str) -> ChatSummary {
         outgoing_status: OutgoingReadStatus::default(),
         last_message_id: None,
         unread_reaction_count: 0,
+        unread_mention_count: 0,
         is_forum: false,
         unread_topic_count: None,
+        is_muted: false,
+        is_marked_as_unread: false,
+        is_blocked: false,
+        username: None,
     }
 }
 
@@ -321,3 +326,61 @@ fn user_status_changed_refreshes_chat_list() {
 
     assert_eq!(o.dispatcher.chat_list_dispatch_count(), before + 1);
 }
+
+#[test]
+fn notification_defaults_changed_refreshes_chat_list() {
+    let mut o = orchestrator_with_chats(vec![chat(1, "News")]);
+
+    let before = o.dispatcher.chat_list_dispatch_count();
+
+    o.handle_event(AppEvent::ChatUpdateReceived {
+        updates: vec![ChatUpdate::NotificationDefaultsChanged],
+    })
+    .unwrap();
+
+    assert_eq!(o.dispatcher.chat_list_dispatch_count(), before + 1);
+}
+
+// ── Read receipt tests ──
+
+#[test]
+fn outbox_read_marks_open_chat_messages_without_refetch() {
+    let mut sent = message(1, "Hello");
+    sent.is_outgoing = true;
+    let mut o = orchestrator_with_open_chat(vec![chat(1, "General")], 1, vec![sent]);
+    let before = o.dispatcher.messages_dispatch_count();
+
+    o.handle_event(AppEvent::ChatUpdateReceived {
+        updates: vec![ChatUpdate::OutboxRead {
+            chat_id: 1,
+            last_read_outbox_message_id: 1,
+        }],
+    })
+    .unwrap();
+
+    assert_eq!(
+        o.state().open_chat().messages()[0].status,
+        crate::domain::message::MessageStatus::Read
+    );
+    assert_eq!(o.dispatcher.messages_dispatch_count(), before);
+}
+
+#[test]
+fn outbox_read_for_other_chat_leaves_open_chat_untouched() {
+    let mut sent = message(1, "Hello");
+    sent.is_outgoing = true;
+    let mut o = orchestrator_with_open_chat(vec![chat(1, "General")], 1, vec![sent]);
+
+    o.handle_event(AppEvent::ChatUpdateReceived {
+        updates: vec![ChatUpdate::OutboxRead {
+            chat_id: 2,
+            last_read_outbox_message_id: 1,
+        }],
+    })
+    .unwrap();
+
+    assert_eq!(
+        o.state().open_chat().messages()[0].status,
+        crate::domain::message::MessageStatus::Delivered
+    );
+}
@@ -0,0 +1,217 @@
+use super::*;
+use crate::domain::bot_command::BotCommand;
+use crate::domain::chat_member::ChatMember;
+use crate::domain::message_input_state::TextMention;
+
+fn press(o: &mut TestOrchestrator, keys: &[&str]) {
+    for key in keys {
+        o.handle_event(AppEvent::InputKey(KeyInput::new(*key, false)))
+            .unwrap();
+    }
+}
+
+fn bot_chat(chat_id: i64, title: &str) -> ChatSummary {
+    ChatSummary {
+        is_bot: true,
+        ..chat(chat_id, title)
+    }
+}
+
+fn commands_loaded(chat_id: i64) -> AppEvent {
+    AppEvent::BackgroundTaskCompleted(BackgroundTaskResult::BotCommandsLoaded {
+        chat_id,
+        result: Ok(vec![
+            BotCommand {
+                command: "start".to_owned(),
+                description: "Start the bot".to_owned(),
+            },
+            BotCommand {
+                command: "stats".to_owned(),
+                description: "Show stats".to_owned(),
+            },
+            BotCommand {
+                command: "help".to_owned(),
+                description: "Show help".to_owned(),
+            },
+        ]),
+    })
+}
+
+fn labels(o: &TestOrchestrator) -> Vec<String> {
+    o.state
+        .completion()
+        .map(|c| c.items().iter().map(|i| i.label.clone()).collect())
+        .unwrap_or_default()
+}
+
+#[test]
+fn slash_in_bot_chat_loads_commands_once() {
+    let mut o = orchestrator_with_open_chat(vec![bot_chat(1, "Bot")], 1, vec![message(1, "hi")]);
+
+    press(&mut o, &["i", "/", "s"]);
+
+    assert_eq!(o.dispatcher.bot_commands_requests(), vec![1]);
+    assert!(o.state.completion().is_none());
+}
+
+#[test]
+fn loaded_commands_are_filtered_by_typed_prefix() {
+    let mut o = orchestrator_with_open_chat(vec![bot_chat(1, "Bot")], 1, vec![message(1, "hi")]);
+    press(&mut o, &["i", "/"]);
+    o.handle_event(commands_loaded(1)).unwrap();
+
+    assert_eq!(labels(&o), vec!["/start", "/stats", "/help"]);
+
+    press(&mut o, &["s", "t", "a", "r"]);
+    assert_eq!(labels(&o), vec!["/start"]);
+}
+
+#[test]
+fn tab_accepts_selected_command() {
+    let mut o = orchestrator_with_open_chat(vec![bot_chat(1, "Bot")], 1, vec![message(1, "hi")]);
+    press(&mut o, &["i", "/"]);
+    o.handle_event(commands_loaded(1)).unwrap();
+
+    press(&mut o, &["s", "down", "tab"]);
+
+    assert_eq!(o.state.message_input().text(), "/stats ");
+    assert!(o.state.completion().is_none());
+    assert_eq!(o.state.active_pane(), ActivePane::MessageInput);
+}
+
+#[test]
+fn esc_closes_popup_but_keeps_input_focused() {
+    let mut o = orchestrator_with_open_chat(vec![bot_chat(1, "Bot")], 1, vec![message(1, "hi")]);
+    press(&mut o, &["i", "/"]);
+    o.handle_event(commands_loaded(1)).unwrap();
+
+    press(&mut o, &["esc"]);
+
+    assert!(o.state.completion().is_none());
+    assert_eq!(o.state.active_pane(), ActivePane::MessageInput);
+    assert_eq!(o.state.message_input().text(), "/");
+}
+
+#[test]
+fn slash_in_chat_with_person_offers_nothing() {
+    let mut o = orchestrator_with_open_chat(vec![chat(1, "Alice")], 1, vec![message(1, "hi")]);
+
+    press(&mut o, &["i", "/"]);
+
+    assert!(o.dispatcher.bot_commands_requests().is_empty());
+    assert!(o.state.completion().is_none());
+}
+
+fn group_chat(chat_id: i64, title: &str) -> ChatSummary {
+    ChatSummary {
+        chat_type: ChatType::Group,
+        ..chat(chat_id, title)
+    }
+}
+
+fn members_loaded(chat_id: i64, query: &str) -> AppEvent {
+    AppEvent::BackgroundTaskCompleted(BackgroundTaskResult::ChatMembersLoaded {
+        chat_id,
+        query: query.to_owned(),
+        result: Ok(vec![
+            ChatMember {
+                user_id: 10,
+                display_name: "Alice Smith".to_owned(),
+                username: "alice".to_owned(),
+            },
+            ChatMember {
+                user_id: 20,
+                display_name: "Bob".to_owned(),
+                username: String::new(),
+            },
+        ]),
+    })
+}
+
+fn sent_by(id: i64, sender: &str) -> Message {
+    Message {
+        sender_name: sender.to_owned(),
+        ..message(id, "hi")
+    }
+}
+
+#[test]
+fn at_sign_in_group_searches_members() {
+    let mut o = orchestrator_with_open_chat(vec![group_chat(1, "Team")], 1, vec![message(1, "hi")]);
+
+    press(&mut o, &["i", "@", "a"]);
+
+    assert_eq!(
+        o.dispatcher.member_searches(),
+        vec![(1, String::new()), (1, "a".to_owned())]
+    );
+}
+
+#[test]
+fn loaded_members_are_ranked_by_recent_senders() {
+    let mut o = orchestrator_with_open_chat(
+        vec![group_chat(1, "Team")],
+        1,
+        vec![sent_by(1, "Alice Smith"), sent_by(2, "Bob")],
+    );
+    press(&mut o, &["i", "@"]);
+    o.handle_event(members_loaded(1, "")).unwrap();
+
+    assert_eq!(labels(&o), vec!["Bob", "Alice Smith"]);
+}
+
+#[test]
+fn accepting_member_without_username_sends_mention_entity() {
+    let mut o = orchestrator_with_open_chat(vec![group_chat(1, "Team")], 1, vec![message(1, "hi")]);
+    press(&mut o, &["i", "@", "b"]);
+    o.handle_event(members_loaded(1, "b")).unwrap();
+
+    press(&mut o, &["tab"]);
+    assert_eq!(o.state.message_input().text(), "Bob ");
+
+    press(&mut o, &["h", "i", "enter"]);
+    assert_eq!(
+        o.dispatcher.last_send_mentions(),
+        vec![TextMention {
+            offset: 0,
+            length: 3,
+            user_id: 20,
+        }]
+    );
+}
+
+#[test]
+fn accepting_member_with_username_inserts_handle() {
+    let mut o = orchestrator_with_open_chat(vec![group_chat(1, "Team")], 1, vec![message(1, "hi")]);
+    press(&mut o, &["i", "h", "e", "y", " ", "@", "a"]);
+    o.handle_event(members_loaded(1, "a")).unwrap();
+
+    press(&mut o, &["tab"]);
+
+    assert_eq!(o.state.message_input().text(), "hey @alice ");
+    assert!(o.state.completion().is_none());
+}
+
+#[test]
+fn at_sign_in_private_chat_offers_nothing() {
+    let mut o = orchestrator_with_open_chat(vec![chat(1, "Alice")], 1, vec![message(1, "hi")]);
+
+    press(&mut o, &["i", "@"]);
+
+    assert!(o.dispatcher.member_searches().is_empty());
+    assert!(o.state.completion().is_none());
+}
+
+#[test]
+fn colon_shortcode_offers_emoji_in_any_chat() {
+    let mut o = orchestrator_with_open_chat(vec![chat(1, "Alice")], 1, vec![message(1, "hi")]);
+
+    press(&mut o, &["i", "o", "k", " ", ":", "f"]);
+    assert!(o.state.completion().is_none());
+
+    press(&mut o, &["i", "r"]);
+    assert_eq!(labels(&o)[0], "🔥 :fire:");
+
+    press(&mut o, &["tab"]);
+    assert_eq!(o.state.message_input().text(), "ok 🔥");
+}
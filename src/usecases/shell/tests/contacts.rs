@@ -0,0 +1,188 @@
+use crate::domain::{contact::Contact, contacts_state::ContactsUiState};
+
+use super::*;
+
+fn press(o: &mut TestOrchestrator, key: &str) {
+    o.handle_event(AppEvent::InputKey(KeyInput::new(key, false)))
+        .unwrap();
+}
+
+fn contact(user_id: i64, name: &str) -> Contact {
+    Contact {
+        user_id,
+        display_name: name.to_owned(),
+        username: String::new(),
+        is_online: false,
+    }
+}
+
+fn complete(o: &mut TestOrchestrator, result: BackgroundTaskResult) {
+    o.handle_event(AppEvent::BackgroundTaskCompleted(result))
+        .unwrap();
+}
+
+fn orchestrator_with_contacts(contacts: Vec<Contact>) -> TestOrchestrator {
+    let mut o = orchestrator_with_chats(vec![chat(1, "Alice")]);
+    press(&mut o, "C");
+    complete(
+        &mut o,
+        BackgroundTaskResult::ContactsLoaded {
+            result: Ok(contacts),
+        },
+    );
+    o
+}
+
+fn selected_name(o: &TestOrchestrator) -> Option<String> {
+    o.state()
+        .contacts()
+        .and_then(|c| c.selected_contact())
+        .map(|c| c.display_name.clone())
+}
+
+#[test]
+fn c_opens_contacts_and_loads_them() {
+    let mut o = orchestrator_with_chats(vec![chat(1, "Alice")]);
+
+    press(&mut o, "C");
+
+    let contacts = o.state().contacts().expect("contacts pane is open");
+    assert_eq!(contacts.ui_state(), ContactsUiState::Loading);
+    assert_eq!(o.dispatcher.contacts_load_count(), 1);
+}
+
+#[test]
+fn loaded_contacts_are_listed_by_name() {
+    let o = orchestrator_with_contacts(vec![contact(20, "Bob"), contact(10, "Alice")]);
+
+    let contacts = o.state().contacts().unwrap();
+    assert_eq!(contacts.ui_state(), ContactsUiState::Ready);
+    assert_eq!(selected_name(&o).as_deref(), Some("Alice"));
+}
+
+#[test]
+fn filter_keys_narrow_the_list_without_triggering_bindings() {
+    let mut o = orchestrator_with_contacts(vec![
+        contact(10, "Alice"),
+        contact(20, "Bob"),
+        contact(30, "Bella"),
+    ]);
+
+    press(&mut o, "/");
+    press(&mut o, "b");
+    press(&mut o, "q");
+    press(&mut o, "backspace");
+    press(&mut o, "enter");
+    press(&mut o, "j");
+
+    assert!(o.state().is_running());
+    let contacts = o.state().contacts().unwrap();
+    assert_eq!(contacts.filter(), "b");
+    assert_eq!(contacts.contacts().len(), 2);
+    assert_eq!(selected_name(&o).as_deref(), Some("Bob"));
+}
+
+#[test]
+fn esc_while_filtering_clears_filter() {
+    let mut o = orchestrator_with_contacts(vec![contact(10, "Alice"), contact(20, "Bob")]);
+
+    press(&mut o, "/");
+    press(&mut o, "b");
+    press(&mut o, "esc");
+
+    let contacts = o.state().contacts().unwrap();
+    assert_eq!(contacts.filter(), "");
+    assert_eq!(contacts.contacts().len(), 2);
+}
+
+#[test]
+fn enter_opens_chat_with_selected_contact() {
+    let mut o = orchestrator_with_contacts(vec![contact(10, "Alice"), contact(20, "Bob")]);
+
+    press(&mut o, "j");
+    press(&mut o, "enter");
+    assert_eq!(o.dispatcher.contact_chats(), vec![20]);
+
+    complete(
+        &mut o,
+        BackgroundTaskResult::ContactChatLoaded {
+            user_id: 20,
+            result: Ok(chat(2, "Bob")),
+        },
+    );
+
+    assert_eq!(o.state().open_chat().chat_id(), Some(2));
+    assert_eq!(o.state().active_pane(), ActivePane::Messages);
+}
+
+#[test]
+fn dd_removes_selected_contact() {
+    let mut o = orchestrator_with_contacts(vec![contact(10, "Alice"), contact(20, "Bob")]);
+
+    press(&mut o, "d");
+    press(&mut o, "d");
+
+    assert_eq!(o.dispatcher.contact_removals(), vec![10]);
+    assert_eq!(selected_name(&o).as_deref(), Some("Bob"));
+    assert_eq!(
+        o.state().active_notification(),
+        Some("Removed Alice from contacts")
+    );
+}
+
+#[test]
+fn failed_removal_reloads_contacts() {
+    let mut o = orchestrator_with_contacts(vec![contact(10, "Alice")]);
+
+    press(&mut o, "d");
+    press(&mut o, "d");
+    complete(
+        &mut o,
+        BackgroundTaskResult::ContactRemoved {
+            user_id: 10,
+            result: Err(BackgroundError::new("CONTACTS_UNAVAILABLE")),
+        },
+    );
+
+    assert_eq!(o.dispatcher.contacts_load_count(), 2);
+    assert_eq!(
+        o.state().active_notification(),
+        Some("Failed to remove contact")
+    );
+}
+
+#[test]
+fn a_adds_selected_private_chat_as_contact() {
+    let mut o = orchestrator_with_chats(vec![chat(1, "Alice")]);
+
+    press(&mut o, "A");
+
+    assert_eq!(o.dispatcher.contact_adds(), vec![1]);
+    assert_eq!(
+        o.state().active_notification(),
+        Some("Adding Alice to contacts...")
+    );
+}
+
+#[test]
+fn a_on_group_chat_is_refused() {
+    let mut o = orchestrator_with_chats(vec![forum_chat(1, "Rustaceans")]);
+
+    press(&mut o, "A");
+
+    assert!(o.dispatcher.contact_adds().is_empty());
+    assert_eq!(
+        o.state().active_notification(),
+        Some("Only people can be added to contacts")
+    );
+}
+
+#[test]
+fn h_closes_contacts() {
+    let mut o = orchestrator_with_contacts(vec![contact(10, "Alice")]);
+
+    press(&mut o, "h");
+
+    assert!(o.state().contacts().is_none());
+    assert_eq!(o.state().chat_list().selected_index(), Some(0));
+}
@@ -0,0 +1,64 @@
+use super::*;
+
+fn press(o: &mut TestOrchestrator, keys: &[&str]) {
+    for key in keys {
+        o.handle_event(AppEvent::InputKey(KeyInput::new(*key, false)))
+            .unwrap();
+    }
+}
+
+fn press_ctrl(o: &mut TestOrchestrator, key: &str) {
+    o.handle_event(AppEvent::InputKey(KeyInput::new(key, true)))
+        .unwrap();
+}
+
+#[test]
+fn ctrl_e_in_input_opens_picker() {
+    let mut o = orchestrator_with_open_chat(vec![chat(1, "Alice")], 1, vec![message(1, "hi")]);
+    press(&mut o, &["i"]);
+
+    press_ctrl(&mut o, "e");
+
+    assert!(o.state.emoji_picker().is_some());
+    assert!(o.state.message_input().text().is_empty());
+}
+
+#[test]
+fn typed_keys_search_instead_of_editing_input() {
+    let mut o = orchestrator_with_open_chat(vec![chat(1, "Alice")], 1, vec![message(1, "hi")]);
+    press(&mut o, &["i"]);
+    press_ctrl(&mut o, "e");
+
+    press(&mut o, &["r", "o", "c", "k"]);
+
+    let picker = o.state.emoji_picker().unwrap();
+    assert_eq!(picker.query(), "rock");
+    assert_eq!(picker.selected_emoji().unwrap().emoji, "🚀");
+    assert!(o.state.message_input().text().is_empty());
+}
+
+#[test]
+fn enter_inserts_selected_emoji_at_cursor() {
+    let mut o = orchestrator_with_open_chat(vec![chat(1, "Alice")], 1, vec![message(1, "hi")]);
+    press(&mut o, &["i", "a", "b", "left"]);
+    press_ctrl(&mut o, "e");
+
+    press(&mut o, &["f", "i", "r", "e", "enter"]);
+
+    assert!(o.state.emoji_picker().is_none());
+    assert_eq!(o.state.message_input().text(), "a🔥b");
+    assert_eq!(o.state.active_pane(), ActivePane::MessageInput);
+}
+
+#[test]
+fn esc_closes_picker_without_inserting() {
+    let mut o = orchestrator_with_open_chat(vec![chat(1, "Alice")], 1, vec![message(1, "hi")]);
+    press(&mut o, &["i"]);
+    press_ctrl(&mut o, "e");
+
+    press(&mut o, &["tab", "right", "esc"]);
+
+    assert!(o.state.emoji_picker().is_none());
+    assert!(o.state.message_input().text().is_empty());
+    assert_eq!(o.state.active_pane(), ActivePane::MessageInput);
+}
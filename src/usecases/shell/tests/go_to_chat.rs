@@ -0,0 +1,135 @@
+use crate::domain::go_to_prompt_state::ChatQuery;
+
+use super::*;
+
+fn press(o: &mut TestOrchestrator, key: &str) {
+    o.handle_event(AppEvent::InputKey(KeyInput::new(key, false)))
+        .unwrap();
+}
+
+fn type_text(o: &mut TestOrchestrator, text: &str) {
+    for ch in text.chars() {
+        press(o, &ch.to_string());
+    }
+}
+
+fn found(o: &mut TestOrchestrator, query: ChatQuery, result: Result<ChatSummary, BackgroundError>) {
+    o.handle_event(AppEvent::BackgroundTaskCompleted(
+        BackgroundTaskResult::ChatFound { query, result },
+    ))
+    .unwrap();
+}
+
+#[test]
+fn at_opens_go_to_prompt_from_messages() {
+    let mut o = orchestrator_with_open_chat(vec![chat(1, "Alice")], 1, vec![message(10, "a")]);
+
+    press(&mut o, "@");
+
+    assert!(o.state().go_to_prompt().is_some());
+}
+
+#[test]
+fn enter_looks_up_typed_username() {
+    let mut o = orchestrator_with_chats(vec![chat(1, "Alice")]);
+    press(&mut o, "@");
+
+    type_text(&mut o, "@durov");
+    press(&mut o, "enter");
+
+    assert!(o.state().go_to_prompt().is_none());
+    assert_eq!(
+        o.dispatcher.chat_lookups(),
+        vec![ChatQuery::Username("durov".to_owned())]
+    );
+    assert_eq!(
+        o.state().active_notification(),
+        Some("Looking up @durov...")
+    );
+}
+
+#[test]
+fn prompt_keys_do_not_trigger_bindings() {
+    let mut o = orchestrator_with_chats(vec![chat(1, "Alice")]);
+    press(&mut o, "@");
+
+    type_text(&mut o, "+1 555 0100");
+    press(&mut o, "enter");
+
+    assert!(o.state().is_running());
+    assert_eq!(
+        o.dispatcher.chat_lookups(),
+        vec![ChatQuery::Phone("15550100".to_owned())]
+    );
+}
+
+#[test]
+fn blank_prompt_stays_open_on_enter() {
+    let mut o = orchestrator_with_chats(vec![chat(1, "Alice")]);
+    press(&mut o, "@");
+
+    press(&mut o, "enter");
+
+    assert!(o.state().go_to_prompt().is_some());
+    assert!(o.dispatcher.chat_lookups().is_empty());
+}
+
+#[test]
+fn esc_closes_go_to_prompt() {
+    let mut o = orchestrator_with_chats(vec![chat(1, "Alice")]);
+    press(&mut o, "@");
+
+    press(&mut o, "esc");
+
+    assert!(o.state().go_to_prompt().is_none());
+    assert!(o.dispatcher.chat_lookups().is_empty());
+}
+
+#[test]
+fn found_chat_opens_even_when_not_in_list() {
+    let mut o = orchestrator_with_chats(vec![chat(1, "Alice")]);
+
+    found(
+        &mut o,
+        ChatQuery::Name("Carol".to_owned()),
+        Ok(chat(42, "Carol")),
+    );
+
+    assert_eq!(o.state().open_chat().chat_id(), Some(42));
+    assert_eq!(o.state().active_pane(), ActivePane::Messages);
+    assert_eq!(o.dispatcher.last_load_messages(), Some((42, None)));
+}
+
+#[test]
+fn ambiguous_name_is_reported() {
+    let mut o = orchestrator_with_chats(vec![chat(1, "Alice")]);
+
+    found(
+        &mut o,
+        ChatQuery::Name("Alex".to_owned()),
+        Err(BackgroundError::new("CHAT_AMBIGUOUS")),
+    );
+
+    assert!(!o.state().open_chat().is_open());
+    assert_eq!(
+        o.state().active_notification(),
+        Some("Several contacts match Alex, type more of the name")
+    );
+}
+
+#[test]
+fn unknown_query_is_reported() {
+    let mut o = orchestrator_with_chats(vec![chat(1, "Alice")]);
+
+    found(
+        &mut o,
+        ChatQuery::Username("nobody_here".to_owned()),
+        Err(BackgroundError::new("CHAT_NOT_FOUND")),
+    );
+
+    assert!(!o.state().open_chat().is_open());
+    assert_eq!(
+        o.state().active_notification(),
+        Some("No chat found for @nobody_here")
+    );
+}
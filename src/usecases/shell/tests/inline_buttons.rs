@@ -0,0 +1,192 @@
+use super::*;
+use crate::domain::message::{CallbackAnswer, InlineButton, InlineButtonAction};
+use crate::usecases::inline_keyboard::CallbackQuery;
+
+fn press(o: &mut TestOrchestrator, keys: &[&str]) {
+    for key in keys {
+        o.handle_event(AppEvent::InputKey(KeyInput::new(*key, false)))
+            .unwrap();
+    }
+}
+
+fn bot_message(id: i64) -> Message {
+    let mut msg = message(id, "Build #42 finished");
+    msg.inline_keyboard = vec![
+        vec![
+            InlineButton {
+                text: "Deploy".to_owned(),
+                action: InlineButtonAction::Callback {
+                    data: "deploy:42".to_owned(),
+                },
+            },
+            InlineButton {
+                text: "Logs".to_owned(),
+                action: InlineButtonAction::Url {
+                    url: "https://ci.example.com/42".to_owned(),
+                },
+            },
+        ],
+        vec![InlineButton {
+            text: "Search".to_owned(),
+            action: InlineButtonAction::SwitchInline {
+                query: "builds".to_owned(),
+                bot_username: Some("ci_bot".to_owned()),
+            },
+        }],
+    ];
+    msg
+}
+
+fn answer_loaded(chat_id: i64, result: Result<CallbackAnswer, BackgroundError>) -> AppEvent {
+    AppEvent::BackgroundTaskCompleted(BackgroundTaskResult::CallbackAnswerLoaded {
+        chat_id,
+        message_id: 10,
+        result,
+    })
+}
+
+fn answer(text: &str, show_alert: bool) -> CallbackAnswer {
+    CallbackAnswer {
+        text: text.to_owned(),
+        show_alert,
+        url: String::new(),
+    }
+}
+
+#[test]
+fn b_without_buttons_shows_notification() {
+    let mut o = orchestrator_with_open_chat(vec![chat(1, "CI")], 1, vec![message(10, "hi")]);
+
+    press(&mut o, &["b"]);
+
+    assert!(o.state().inline_button_selection().is_none());
+    assert_eq!(
+        o.state().active_notification(),
+        Some("No buttons on this message")
+    );
+}
+
+#[test]
+fn enter_on_callback_button_dispatches_callback_query() {
+    let mut o = orchestrator_with_open_chat(vec![chat(1, "CI")], 1, vec![bot_message(10)]);
+
+    press(&mut o, &["b", "enter"]);
+
+    assert!(o.state().inline_button_selection().is_none());
+    assert_eq!(
+        o.dispatcher.callback_queries(),
+        vec![CallbackQuery {
+            chat_id: 1,
+            message_id: 10,
+            data: "deploy:42".to_owned(),
+        }]
+    );
+}
+
+#[test]
+fn url_button_opens_via_external_opener() {
+    let mut o = orchestrator_with_open_chat(vec![chat(1, "CI")], 1, vec![bot_message(10)]);
+
+    press(&mut o, &["b", "l", "enter"]);
+
+    assert_eq!(o.opener.opened_urls(), vec!["https://ci.example.com/42"]);
+    assert!(o.dispatcher.callback_queries().is_empty());
+}
+
+#[test]
+fn switch_inline_button_prefills_input() {
+    let mut o = orchestrator_with_open_chat(vec![chat(1, "CI")], 1, vec![bot_message(10)]);
+
+    press(&mut o, &["b", "j", "enter"]);
+
+    assert_eq!(o.state().message_input().text(), "@ci_bot builds");
+    assert_eq!(o.state().active_pane(), ActivePane::MessageInput);
+}
+
+#[test]
+fn esc_leaves_selection_without_pressing() {
+    let mut o = orchestrator_with_open_chat(vec![chat(1, "CI")], 1, vec![bot_message(10)]);
+
+    press(&mut o, &["b", "esc"]);
+
+    assert!(o.state().inline_button_selection().is_none());
+    assert!(o.dispatcher.callback_queries().is_empty());
+    assert!(o.state().is_running());
+}
+
+#[test]
+fn callback_answer_is_shown_as_notification() {
+    let mut o = orchestrator_with_open_chat(vec![chat(1, "CI")], 1, vec![bot_message(10)]);
+
+    o.handle_event(answer_loaded(1, Ok(answer("Deploying...", false))))
+        .unwrap();
+
+    assert_eq!(o.state().active_notification(), Some("Deploying..."));
+    assert!(o.state().alert().is_none());
+}
+
+#[test]
+fn callback_alert_is_shown_until_dismissed() {
+    let mut o = orchestrator_with_open_chat(vec![chat(1, "CI")], 1, vec![bot_message(10)]);
+
+    o.handle_event(answer_loaded(1, Ok(answer("Access denied", true))))
+        .unwrap();
+    assert_eq!(o.state().alert(), Some("Access denied"));
+
+    // Keys are swallowed by the alert until it is closed.
+    press(&mut o, &["b"]);
+    assert!(o.state().inline_button_selection().is_none());
+
+    press(&mut o, &["enter"]);
+    assert!(o.state().alert().is_none());
+}
+
+#[test]
+fn callback_answer_url_is_opened() {
+    let mut o = orchestrator_with_open_chat(vec![chat(1, "CI")], 1, vec![bot_message(10)]);
+
+    let mut with_url = answer("", false);
+    with_url.url = "https://ci.example.com/game".to_owned();
+    o.handle_event(answer_loaded(1, Ok(with_url))).unwrap();
+
+    assert_eq!(o.opener.opened_urls(), vec!["https://ci.example.com/game"]);
+}
+
+#[test]
+fn callback_answer_telegram_link_is_resolved_in_app() {
+    let mut o = orchestrator_with_open_chat(vec![chat(1, "CI")], 1, vec![bot_message(10)]);
+
+    let mut with_url = answer("", false);
+    with_url.url = "https://t.me/ci_bot?game=x".to_owned();
+    o.handle_event(answer_loaded(1, Ok(with_url))).unwrap();
+
+    assert!(o.opener.opened_urls().is_empty());
+    assert_eq!(
+        o.dispatcher.link_resolutions(),
+        vec!["https://t.me/ci_bot?game=x"]
+    );
+}
+
+#[test]
+fn callback_timeout_shows_notification() {
+    let mut o = orchestrator_with_open_chat(vec![chat(1, "CI")], 1, vec![bot_message(10)]);
+
+    o.handle_event(answer_loaded(
+        1,
+        Err(BackgroundError::new("CALLBACK_QUERY_TIMEOUT")),
+    ))
+    .unwrap();
+
+    assert_eq!(o.state().active_notification(), Some("Bot did not respond"));
+}
+
+#[test]
+fn callback_answer_for_other_chat_is_ignored() {
+    let mut o = orchestrator_with_open_chat(vec![chat(1, "CI")], 1, vec![bot_message(10)]);
+
+    o.handle_event(answer_loaded(2, Ok(answer("Stale", true))))
+        .unwrap();
+
+    assert!(o.state().alert().is_none());
+    assert!(o.state().active_notification().is_none());
+}
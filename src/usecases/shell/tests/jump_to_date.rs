@@ -0,0 +1,180 @@
+use super::*;
+
+fn press(o: &mut TestOrchestrator, key: &str) {
+    o.handle_event(AppEvent::InputKey(KeyInput::new(key, false)))
+        .unwrap();
+}
+
+fn type_text(o: &mut TestOrchestrator, text: &str) {
+    for ch in text.chars() {
+        press(o, &ch.to_string());
+    }
+}
+
+fn timed_message(id: i64, timestamp_ms: i64) -> Message {
+    let mut m = message(id, &format!("msg {id}"));
+    m.timestamp_ms = timestamp_ms;
+    m
+}
+
+fn inject_window(o: &mut TestOrchestrator, chat_id: i64, messages: Vec<Message>, target: i64) {
+    o.handle_event(AppEvent::BackgroundTaskCompleted(
+        BackgroundTaskResult::MessagesAroundDateLoaded {
+            chat_id,
+            topic_id: None,
+            target_unix_ms: target,
+            result: Ok(messages),
+        },
+    ))
+    .unwrap();
+}
+
+#[test]
+fn g_d_opens_date_prompt() {
+    let mut o = orchestrator_with_open_chat(vec![chat(1, "Chat")], 1, vec![message(1, "a")]);
+
+    press(&mut o, "g");
+    press(&mut o, "d");
+
+    assert!(o.state().date_prompt().is_some());
+}
+
+#[test]
+fn esc_closes_prompt_without_dispatch() {
+    let mut o = orchestrator_with_open_chat(vec![chat(1, "Chat")], 1, vec![message(1, "a")]);
+    press(&mut o, "g");
+    press(&mut o, "d");
+
+    type_text(&mut o, "-3d");
+    press(&mut o, "esc");
+
+    assert!(o.state().date_prompt().is_none());
+    assert_eq!(o.dispatcher.last_messages_around_date(), None);
+}
+
+#[test]
+fn enter_dispatches_load_around_date() {
+    let mut o = orchestrator_with_open_chat(vec![chat(1, "Chat")], 1, vec![message(1, "a")]);
+    press(&mut o, "g");
+    press(&mut o, "d");
+
+    type_text(&mut o, "2024-03-01");
+    press(&mut o, "enter");
+
+    assert!(o.state().date_prompt().is_none());
+    let (chat_id, topic_id, unix_time) = o.dispatcher.last_messages_around_date().unwrap();
+    assert_eq!((chat_id, topic_id), (1, None));
+    // Local midnight of 2024-03-01 is within a day of UTC midnight.
+    let utc_midnight = 1_709_251_200;
+    assert!((unix_time - utc_midnight).abs() <= 14 * 3600);
+}
+
+#[test]
+fn unrecognized_date_shows_notification() {
+    let mut o = orchestrator_with_open_chat(vec![chat(1, "Chat")], 1, vec![message(1, "a")]);
+    press(&mut o, "g");
+    press(&mut o, "d");
+
+    type_text(&mut o, "soon");
+    press(&mut o, "enter");
+
+    assert_eq!(o.dispatcher.last_messages_around_date(), None);
+    assert_eq!(
+        o.state().active_notification(),
+        Some("Unrecognized date: soon")
+    );
+}
+
+#[test]
+fn window_result_selects_first_message_of_target_day() {
+    let mut o = orchestrator_with_open_chat(vec![chat(1, "Chat")], 1, vec![message(9, "latest")]);
+
+    inject_window(
+        &mut o,
+        1,
+        vec![
+            timed_message(1, 1_000),
+            timed_message(2, 5_000),
+            timed_message(3, 9_000),
+        ],
+        4_000,
+    );
+
+    let open_chat = o.state().open_chat();
+    assert!(open_chat.is_history_window());
+    assert_eq!(open_chat.selected_message().map(|m| m.id), Some(2));
+}
+
+#[test]
+fn empty_window_result_keeps_messages_and_notifies() {
+    let mut o = orchestrator_with_open_chat(vec![chat(1, "Chat")], 1, vec![message(9, "latest")]);
+
+    inject_window(&mut o, 1, Vec::new(), 4_000);
+
+    assert!(!o.state().open_chat().is_history_window());
+    assert_eq!(o.state().open_chat().messages().len(), 1);
+    assert_eq!(
+        o.state().active_notification(),
+        Some("No messages on or after that date")
+    );
+}
+
+#[test]
+fn window_result_for_other_chat_is_discarded() {
+    let mut o = orchestrator_with_open_chat(vec![chat(1, "Chat")], 1, vec![message(9, "latest")]);
+
+    inject_window(&mut o, 2, vec![timed_message(1, 1_000)], 0);
+
+    assert!(!o.state().open_chat().is_history_window());
+}
+
+#[test]
+fn updates_do_not_refresh_history_window() {
+    let mut o = orchestrator_with_open_chat(vec![chat(1, "Chat")], 1, vec![message(9, "latest")]);
+    inject_window(&mut o, 1, vec![timed_message(1, 1_000)], 0);
+    let dispatched_before = o.dispatcher.messages_dispatch_count();
+
+    o.handle_event(AppEvent::ChatUpdateReceived {
+        updates: vec![ChatUpdate::ChatMetadataChanged { chat_id: 1 }],
+    })
+    .unwrap();
+
+    assert_eq!(o.dispatcher.messages_dispatch_count(), dispatched_before);
+    assert!(o.state().open_chat().is_history_window());
+}
+
+#[test]
+fn late_latest_page_does_not_replace_history_window() {
+    let mut o = orchestrator_with_open_chat(vec![chat(1, "Chat")], 1, vec![message(9, "latest")]);
+    inject_window(&mut o, 1, vec![timed_message(1, 1_000)], 0);
+
+    inject_messages(&mut o, 1, vec![message(9, "latest"), message(10, "newer")]);
+
+    assert!(o.state().open_chat().is_history_window());
+    assert_eq!(o.state().open_chat().messages()[0].id, 1);
+}
+
+#[test]
+fn shift_g_in_window_reloads_latest_page() {
+    let mut o = orchestrator_with_open_chat(vec![chat(1, "Chat")], 1, vec![message(9, "latest")]);
+    inject_window(&mut o, 1, vec![timed_message(1, 1_000)], 0);
+    let dispatched_before = o.dispatcher.messages_dispatch_count();
+
+    press(&mut o, "G");
+
+    assert!(!o.state().open_chat().is_history_window());
+    assert_eq!(
+        o.dispatcher.messages_dispatch_count(),
+        dispatched_before + 1
+    );
+    assert_eq!(o.dispatcher.last_load_messages(), Some((1, None)));
+
+    // The cached latest page is shown while the reload is in flight.
+    assert_eq!(
+        o.state().open_chat().selected_message().map(|m| m.id),
+        Some(9)
+    );
+
+    inject_messages(&mut o, 1, vec![message(9, "latest"), message(10, "newer")]);
+    assert_eq!(o.state().open_chat().messages().len(), 2);
+}
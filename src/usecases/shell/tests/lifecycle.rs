@@ -37,6 +37,7 @@ fn integration_smoke_happy_path_startup_load_navigate_and_open_chat() {
     // Simulate result
     o.handle_event(AppEvent::BackgroundTaskCompleted(
         BackgroundTaskResult::ChatListLoaded {
+            list: ChatListId::Main,
             result: Ok(vec![chat(1, "General"), chat(2, "Backend"), chat(3, "Ops")]),
             all_loaded: false,
         },
@@ -72,6 +73,7 @@ fn integration_smoke_fallback_error_then_empty_list() {
     // Error result
     o.handle_event(AppEvent::BackgroundTaskCompleted(
         BackgroundTaskResult::ChatListLoaded {
+            list: ChatListId::Main,
             result: Err(BackgroundError::new("CHAT_LIST_UNAVAILABLE")),
             all_loaded: false,
         },
@@ -85,6 +87,7 @@ fn integration_smoke_fallback_error_then_empty_list() {
     // Empty list result
     o.handle_event(AppEvent::BackgroundTaskCompleted(
         BackgroundTaskResult::ChatListLoaded {
+            list: ChatListId::Main,
             result: Ok(vec![]),
             all_loaded: false,
         },
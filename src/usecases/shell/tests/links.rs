@@ -0,0 +1,343 @@
+use crate::domain::{
+    link_target::{ChatInvite, LinkTarget},
+    message::UnreadMessageWindow,
+};
+
+use super::*;
+
+fn press(o: &mut TestOrchestrator, key: &str) {
+    o.handle_event(AppEvent::InputKey(KeyInput::new(key, false)))
+        .unwrap();
+}
+
+fn resolve(o: &mut TestOrchestrator, url: &str, result: Result<LinkTarget, BackgroundError>) {
+    o.handle_event(AppEvent::BackgroundTaskCompleted(
+        BackgroundTaskResult::LinkResolved {
+            url: url.to_owned(),
+            result,
+        },
+    ))
+    .unwrap();
+}
+
+fn invite() -> ChatInvite {
+    ChatInvite {
+        invite_link: "https://t.me/+AbCdEf".to_owned(),
+        title: "Rust".to_owned(),
+        member_count: 10,
+        creates_join_request: false,
+    }
+}
+
+fn joined(o: &mut TestOrchestrator, result: Result<ChatSummary, BackgroundError>) {
+    o.handle_event(AppEvent::BackgroundTaskCompleted(
+        BackgroundTaskResult::ChatJoined {
+            invite_link: "https://t.me/+AbCdEf".to_owned(),
+            result,
+        },
+    ))
+    .unwrap();
+}
+
+#[test]
+fn o_resolves_telegram_links_instead_of_opening_browser() {
+    let mut o = orchestrator_with_open_chat(
+        vec![chat(1, "Chat")],
+        1,
+        vec![message(10, "Join https://t.me/rustlang today")],
+    );
+
+    press(&mut o, "o");
+
+    assert_eq!(
+        o.dispatcher.link_resolutions(),
+        vec!["https://t.me/rustlang"]
+    );
+    assert!(o.opener.opened_urls().is_empty());
+}
+
+#[test]
+fn shift_y_requests_link_of_selected_message() {
+    let mut o =
+        orchestrator_with_open_chat(vec![channel_chat(1, "News")], 1, vec![message(10, "a")]);
+
+    press(&mut o, "Y");
+
+    assert_eq!(o.dispatcher.message_link_requests(), vec![(1, 10)]);
+}
+
+#[test]
+fn shift_y_in_private_chat_explains_there_is_no_link() {
+    let mut o = orchestrator_with_open_chat(vec![chat(1, "Alice")], 1, vec![message(10, "a")]);
+
+    press(&mut o, "Y");
+
+    assert!(o.dispatcher.message_link_requests().is_empty());
+    assert_eq!(
+        o.state().active_notification(),
+        Some("Message links exist only in groups and channels")
+    );
+}
+
+#[test]
+fn missing_message_link_is_reported() {
+    let mut o = make_orchestrator();
+
+    o.handle_event(AppEvent::BackgroundTaskCompleted(
+        BackgroundTaskResult::MessageLinkLoaded {
+            chat_id: 1,
+            message_id: 10,
+            result: Err(BackgroundError::new("LINK_NOT_FOUND")),
+        },
+    ))
+    .unwrap();
+
+    assert_eq!(
+        o.state().active_notification(),
+        Some("This message has no link")
+    );
+}
+
+#[test]
+fn chat_link_opens_chat_and_highlights_it_in_list() {
+    let mut o = orchestrator_with_open_chat(
+        vec![chat(1, "Alice"), chat(2, "Bob")],
+        1,
+        vec![message(10, "a")],
+    );
+
+    resolve(
+        &mut o,
+        "https://t.me/bob",
+        Ok(LinkTarget::Chat(chat(2, "Bob"))),
+    );
+
+    assert_eq!(o.state().open_chat().chat_id(), Some(2));
+    assert_eq!(o.state().chat_list().selected_index(), Some(1));
+    assert_eq!(o.state().active_pane(), ActivePane::Messages);
+    assert_eq!(o.dispatcher.last_load_messages(), Some((2, None)));
+}
+
+#[test]
+fn chat_link_opens_chat_missing_from_list() {
+    let mut o = orchestrator_with_chats(vec![chat(1, "Alice")]);
+
+    resolve(
+        &mut o,
+        "https://t.me/rustlang",
+        Ok(LinkTarget::Chat(channel_chat(99, "Rust"))),
+    );
+
+    assert_eq!(o.state().open_chat().chat_id(), Some(99));
+    assert_eq!(o.state().open_chat().chat_title(), "Rust");
+    assert_eq!(o.state().chat_list().selected_index(), Some(0));
+}
+
+#[test]
+fn message_link_shows_history_around_linked_message() {
+    let mut o = orchestrator_with_chats(vec![chat(1, "Alice")]);
+
+    resolve(
+        &mut o,
+        "https://t.me/rustlang/6",
+        Ok(LinkTarget::Message {
+            chat: channel_chat(2, "Rust"),
+            window: UnreadMessageWindow {
+                target_message_id: 6,
+                messages: vec![message(5, "a"), message(6, "b"), message(7, "c")],
+            },
+        }),
+    );
+
+    let open_chat = o.state().open_chat();
+    assert_eq!(open_chat.chat_id(), Some(2));
+    assert!(open_chat.is_history_window());
+    assert_eq!(open_chat.selected_message().map(|m| m.id), Some(6));
+}
+
+#[test]
+fn message_link_into_open_chat_selects_loaded_message() {
+    let mut o = orchestrator_with_open_chat(
+        vec![channel_chat(1, "Rust")],
+        1,
+        vec![message(1, "a"), message(2, "b"), message(3, "c")],
+    );
+
+    resolve(
+        &mut o,
+        "https://t.me/rustlang/2",
+        Ok(LinkTarget::Message {
+            chat: channel_chat(1, "Rust"),
+            window: UnreadMessageWindow {
+                target_message_id: 2,
+                messages: vec![message(2, "b")],
+            },
+        }),
+    );
+
+    let open_chat = o.state().open_chat();
+    assert!(!open_chat.is_history_window());
+    assert_eq!(open_chat.messages().len(), 3);
+    assert_eq!(open_chat.selected_message().map(|m| m.id), Some(2));
+}
+
+#[test]
+fn invite_link_asks_before_joining() {
+    let mut o = orchestrator_with_chats(vec![chat(1, "Alice")]);
+
+    resolve(
+        &mut o,
+        "https://t.me/+AbCdEf",
+        Ok(LinkTarget::Invite(invite())),
+    );
+
+    assert_eq!(o.state().join_prompt(), Some(&invite()));
+    assert!(o.dispatcher.joins().is_empty());
+
+    press(&mut o, "y");
+
+    assert!(o.state().join_prompt().is_none());
+    assert_eq!(o.dispatcher.joins(), vec!["https://t.me/+AbCdEf"]);
+}
+
+#[test]
+fn esc_cancels_join_prompt() {
+    let mut o = orchestrator_with_chats(vec![chat(1, "Alice")]);
+    resolve(
+        &mut o,
+        "https://t.me/+AbCdEf",
+        Ok(LinkTarget::Invite(invite())),
+    );
+
+    press(&mut o, "esc");
+
+    assert!(o.state().join_prompt().is_none());
+    assert!(o.dispatcher.joins().is_empty());
+}
+
+#[test]
+fn joined_chat_is_opened() {
+    let mut o = orchestrator_with_chats(vec![chat(1, "Alice")]);
+
+    joined(&mut o, Ok(channel_chat(5, "Rust")));
+
+    assert_eq!(o.state().open_chat().chat_id(), Some(5));
+    assert_eq!(o.state().active_notification(), Some("Joined Rust"));
+}
+
+#[test]
+fn join_request_is_reported() {
+    let mut o = orchestrator_with_chats(vec![chat(1, "Alice")]);
+
+    joined(&mut o, Err(BackgroundError::new("JOIN_REQUEST_SENT")));
+
+    assert!(!o.state().open_chat().is_open());
+    assert_eq!(o.state().active_notification(), Some("Join request sent"));
+}
+
+#[test]
+fn unsupported_link_goes_to_browser() {
+    let mut o = orchestrator_with_chats(vec![chat(1, "Alice")]);
+
+    resolve(
+        &mut o,
+        "https://t.me/addstickers/rust",
+        Ok(LinkTarget::External),
+    );
+
+    assert_eq!(
+        o.opener.opened_urls(),
+        vec!["https://t.me/addstickers/rust"]
+    );
+}
+
+#[test]
+fn expired_link_is_reported() {
+    let mut o = orchestrator_with_chats(vec![chat(1, "Alice")]);
+
+    resolve(
+        &mut o,
+        "https://t.me/+Expired",
+        Err(BackgroundError::new("LINK_NOT_FOUND")),
+    );
+
+    assert!(!o.state().open_chat().is_open());
+    assert_eq!(
+        o.state().active_notification(),
+        Some("Link is invalid or expired")
+    );
+}
+
+fn orchestrator_with_links() -> TestOrchestrator {
+    orchestrator_with_open_chat(
+        vec![chat(1, "Chat")],
+        1,
+        vec![message(
+            10,
+            "See https://docs.rs and https://crates.io or https://t.me/rustlang",
+        )],
+    )
+}
+
+#[test]
+fn digit_in_link_hints_opens_that_link() {
+    let mut o = orchestrator_with_links();
+    press(&mut o, "o");
+
+    press(&mut o, "2");
+
+    assert!(o.state().link_hints().is_none());
+    assert_eq!(o.opener.opened_urls(), vec!["https://crates.io"]);
+}
+
+#[test]
+fn telegram_link_picked_from_hints_is_resolved() {
+    let mut o = orchestrator_with_links();
+    press(&mut o, "o");
+
+    press(&mut o, "j");
+    press(&mut o, "j");
+    press(&mut o, "enter");
+
+    assert_eq!(
+        o.dispatcher.link_resolutions(),
+        vec!["https://t.me/rustlang"]
+    );
+    assert!(o.opener.opened_urls().is_empty());
+}
+
+#[test]
+fn ctrl_digit_in_link_hints_copies_instead_of_opening() {
+    let mut o = orchestrator_with_links();
+    press(&mut o, "o");
+
+    o.handle_event(AppEvent::InputKey(KeyInput::new("1", true)))
+        .unwrap();
+
+    assert!(o.state().link_hints().is_none());
+    assert!(o.opener.opened_urls().is_empty());
+    assert!(o.state().active_notification().is_some());
+}
+
+#[test]
+fn y_switches_link_hints_to_copy_mode() {
+    let mut o = orchestrator_with_links();
+    press(&mut o, "o");
+
+    press(&mut o, "y");
+    assert!(o.state().link_hints().unwrap().is_copy_mode());
+    press(&mut o, "1");
+
+    assert!(o.opener.opened_urls().is_empty());
+}
+
+#[test]
+fn esc_closes_link_hints() {
+    let mut o = orchestrator_with_links();
+    press(&mut o, "o");
+
+    press(&mut o, "esc");
+
+    assert!(o.state().link_hints().is_none());
+    assert!(o.opener.opened_urls().is_empty());
+}
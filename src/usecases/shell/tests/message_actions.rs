@@ -1,14 +1,17 @@
 use super::*;
+use crate::domain::delete_confirm_state::DeletePermissions;
 
 // ── dd (delete message) tests ──
 
 #[test]
-fn dd_deletes_selected_message() {
+fn dd_deletes_selected_message_when_confirmation_is_disabled() {
     let mut o = orchestrator_with_open_chat(
         vec![chat(1, "Chat")],
         1,
         vec![message(10, "hello"), message(20, "world")],
     );
+    o.confirm_delete = false;
+    o.undo_grace = std::time::Duration::ZERO;
 
     // Select last message (20) — default after open
     assert_eq!(o.state().open_chat().selected_message().unwrap().id, 20);
@@ -25,10 +28,136 @@ fn dd_deletes_selected_message() {
 
     // Dispatch should have been called
     assert_eq!(o.dispatcher.delete_dispatch_count(), 1);
-    assert_eq!(o.dispatcher.last_delete(), Some((1, 20)));
+    assert_eq!(
+        o.dispatcher.last_delete(),
+        Some((1, vec![20], DeleteScope::Auto))
+    );
+    assert!(o.state().delete_confirm().is_none());
 
     // Notification should be set
-    assert_eq!(o.state().active_notification(), Some("Message deleted"));
+    assert_eq!(o.state().active_notification(), Some("Deleted 1 message"));
+}
+
+fn press(o: &mut TestOrchestrator, keys: &[&str]) {
+    for key in keys {
+        o.handle_event(AppEvent::InputKey(KeyInput::new(*key, false)))
+            .unwrap();
+    }
+}
+
+fn resolve_permissions(o: &mut TestOrchestrator, message_ids: Vec<i64>, for_everyone: bool) {
+    o.handle_event(AppEvent::BackgroundTaskCompleted(
+        BackgroundTaskResult::DeletePermissionsLoaded {
+            chat_id: 1,
+            message_ids,
+            result: Ok(DeletePermissions {
+                for_self: true,
+                for_everyone,
+            }),
+        },
+    ))
+    .unwrap();
+}
+
+#[test]
+fn dd_asks_for_confirmation_and_queries_permissions() {
+    let mut o = orchestrator_with_open_chat(
+        vec![chat(1, "Chat")],
+        1,
+        vec![message(10, "hello"), message(20, "world")],
+    );
+
+    press(&mut o, &["d", "d"]);
+
+    let confirm = o.state().delete_confirm().expect("confirm popup");
+    assert_eq!(confirm.message_ids(), &[20]);
+    assert!(confirm.is_loading());
+    assert_eq!(
+        o.dispatcher.delete_permission_queries(),
+        vec![(1, vec![20])]
+    );
+    assert_eq!(o.dispatcher.delete_dispatch_count(), 0);
+    assert_eq!(o.state().open_chat().messages().len(), 2);
+}
+
+#[test]
+fn confirm_delete_for_everyone() {
+    let mut o = orchestrator_with_open_chat(
+        vec![chat(1, "Chat")],
+        1,
+        vec![message(10, "hello"), message(20, "world")],
+    );
+    o.undo_grace = std::time::Duration::ZERO;
+    press(&mut o, &["d", "d"]);
+    resolve_permissions(&mut o, vec![20], true);
+
+    press(&mut o, &["e"]);
+
+    assert!(o.state().delete_confirm().is_none());
+    assert_eq!(
+        o.dispatcher.last_delete(),
+        Some((1, vec![20], DeleteScope::ForEveryone))
+    );
+    assert_eq!(o.state().open_chat().messages().len(), 1);
+    assert_eq!(
+        o.state().active_notification(),
+        Some("Deleted 1 message for everyone")
+    );
+}
+
+#[test]
+fn delete_for_everyone_is_ignored_when_not_allowed() {
+    let mut o = orchestrator_with_open_chat(
+        vec![chat(1, "Chat")],
+        1,
+        vec![message(10, "hello"), message(20, "world")],
+    );
+    o.undo_grace = std::time::Duration::ZERO;
+    press(&mut o, &["d", "d"]);
+    resolve_permissions(&mut o, vec![20], false);
+
+    press(&mut o, &["e"]);
+    assert!(o.state().delete_confirm().is_some());
+    assert_eq!(o.dispatcher.delete_dispatch_count(), 0);
+
+    press(&mut o, &["m"]);
+    assert_eq!(
+        o.dispatcher.last_delete(),
+        Some((1, vec![20], DeleteScope::ForMe))
+    );
+}
+
+#[test]
+fn options_are_ignored_until_permissions_arrive() {
+    let mut o = orchestrator_with_open_chat(vec![chat(1, "Chat")], 1, vec![message(10, "hello")]);
+
+    press(&mut o, &["d", "d", "m", "e"]);
+
+    assert!(o.state().delete_confirm().is_some());
+    assert_eq!(o.dispatcher.delete_dispatch_count(), 0);
+}
+
+#[test]
+fn esc_cancels_delete_confirmation() {
+    let mut o = orchestrator_with_open_chat(vec![chat(1, "Chat")], 1, vec![message(10, "hello")]);
+    press(&mut o, &["d", "d"]);
+    resolve_permissions(&mut o, vec![10], true);
+
+    press(&mut o, &["esc"]);
+
+    assert!(o.state().delete_confirm().is_none());
+    assert_eq!(o.dispatcher.delete_dispatch_count(), 0);
+    assert_eq!(o.state().open_chat().messages().len(), 1);
+}
+
+#[test]
+fn stale_permissions_do_not_update_popup() {
+    let mut o = orchestrator_with_open_chat(vec![chat(1, "Chat")], 1, vec![message(10, "hello")]);
+    press(&mut o, &["d", "d"]);
+
+    resolve_permissions(&mut o, vec![99], true);
+
+    assert!(o.state().delete_confirm().unwrap().is_loading());
 }
 
 #[test]
@@ -120,7 +249,7 @@ fn o_does_nothing_when_no_url() {
 }
 
 #[test]
-fn o_opens_first_url_when_multiple() {
+fn o_offers_link_hints_when_multiple() {
     let mut o = orchestrator_with_open_chat(
         vec![chat(1, "Chat")],
         1,
@@ -133,7 +262,16 @@ fn o_opens_first_url_when_multiple() {
     o.handle_event(AppEvent::InputKey(KeyInput::new("o", false)))
         .unwrap();
 
-    assert_eq!(o.opener.opened_urls(), vec!["https://first.com"]);
+    assert!(o.opener.opened_urls().is_empty());
+    let urls: Vec<_> = o
+        .state()
+        .link_hints()
+        .unwrap()
+        .urls()
+        .iter()
+        .map(|u| u.url.as_str())
+        .collect();
+    assert_eq!(urls, vec!["https://first.com", "https://second.com"]);
 }
 
 #[test]
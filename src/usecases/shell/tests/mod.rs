@@ -1,17 +1,32 @@
+mod chat_actions;
 mod chat_info;
 mod chat_list;
 mod chat_open;
+mod chat_search;
 mod chat_updates;
+mod completion;
+mod contacts;
+mod emoji_picker;
 mod forum;
+mod go_to_chat;
 mod help_popup;
+mod inline_buttons;
+mod jump_to_date;
 mod lifecycle;
+mod links;
 mod message_actions;
 mod message_cache;
 mod message_info;
 mod message_input;
 mod message_pagination;
+mod new_chat;
 mod playback;
 mod reaction_picker;
+mod recent_chats;
+mod reply_keyboard;
+mod undo;
+mod unread_jump;
+mod visual_mode;
 mod voice;
 
 use std::cell::RefCell;
@@ -19,10 +34,16 @@ use std::cell::RefCell;
 use super::*;
 use crate::{
     domain::{
-        chat::ChatSummary,
+        chat::{ChatSummary, MuteDuration},
+        chat_action::ChatAction,
+        chat_folder::ChatListId,
         chat_list_state::ChatListUiState,
+        chat_manage_state::ChatManageOp,
+        delete_confirm_state::DeleteScope,
         events::{AppEvent, BackgroundError, BackgroundTaskResult, ConnectivityStatus, KeyInput},
+        go_to_prompt_state::ChatQuery,
         message::Message,
+        new_chat_wizard_state::NewChatRequest,
         open_chat_state::OpenChatUiState,
         shell_state::ShellState,
     },
@@ -67,8 +88,13 @@ fn chat(chat_id: i64, title: &str) -> ChatSummary {
         outgoing_status: OutgoingReadStatus::default(),
         last_message_id: None,
         unread_reaction_count: 0,
+        unread_mention_count: 0,
         is_forum: false,
         unread_topic_count: None,
+        is_muted: false,
+        is_marked_as_unread: false,
+        is_blocked: false,
+        username: None,
     }
 }
 
@@ -85,10 +111,12 @@ fn message(id: i64, text: &str) -> Message {
         call_info: None,
         reply_to: None,
         forward_info: None,
-        reaction_count: 0,
+        reactions: Vec::new(),
         links: Vec::new(),
         is_edited: false,
         is_service: false,
+        inline_keyboard: Vec::new(),
+        reply_keyboard: None,
     }
 }
 
@@ -101,21 +129,50 @@ type MarkAsReadRecord = (i64, Option<i32>, Vec<i64>);
 struct RecordingDispatcher {
     dispatched_chat_list_count: RefCell<usize>,
     dispatched_chat_list_force: RefCell<Vec<bool>>,
+    dispatched_chat_lists: RefCell<Vec<ChatListId>>,
     dispatched_messages: RefCell<Vec<(i64, Option<i32>)>>,
     dispatched_older_messages: RefCell<Vec<(i64, Option<i32>, i64)>>,
+    dispatched_messages_around_date: RefCell<Vec<(i64, Option<i32>, i64)>>,
+    dispatched_unread_windows:
+        RefCell<Vec<(i64, Option<i32>, crate::domain::chat::UnreadJumpKind)>>,
     dispatched_sends: RefCell<Vec<SendRecord>>,
+    last_send_mentions: RefCell<Vec<crate::domain::message_input_state::TextMention>>,
     dispatched_open_chats: RefCell<Vec<i64>>,
     dispatched_close_chats: RefCell<Vec<i64>>,
     dispatched_mark_as_read: RefCell<Vec<MarkAsReadRecord>>,
     dispatched_mark_chat_as_read: RefCell<Vec<(i64, i64)>>,
+    dispatched_chat_actions: RefCell<Vec<(i64, Option<i32>, ChatAction)>>,
     dispatched_prefetches: RefCell<Vec<(i64, Option<i32>)>>,
     dispatched_forum_topics: RefCell<Vec<i64>>,
     dispatched_forum_unread_counts: RefCell<Vec<Vec<i64>>>,
-    dispatched_deletes: RefCell<Vec<(i64, i64)>>,
+    dispatched_deletes: RefCell<Vec<(i64, Vec<i64>, DeleteScope)>>,
+    blocking_deletes: RefCell<Vec<(i64, Vec<i64>, DeleteScope)>>,
+    blocking_chat_manage_ops: RefCell<Vec<(i64, ChatManageOp)>>,
+    dispatched_delete_permissions: RefCell<Vec<(i64, Vec<i64>)>>,
+    dispatched_forwards: RefCell<Vec<(i64, i64, Vec<i64>)>>,
     dispatched_voice_sends: RefCell<Vec<(i64, Option<i32>, String)>>,
     dispatched_subtitles: RefCell<Vec<ChatSubtitleQuery>>,
     dispatched_add_reactions: RefCell<Vec<(i64, i64, String)>>,
     dispatched_remove_reactions: RefCell<Vec<(i64, i64, String)>>,
+    dispatched_callback_queries: RefCell<Vec<crate::usecases::inline_keyboard::CallbackQuery>>,
+    dispatched_bot_commands: RefCell<Vec<i64>>,
+    dispatched_member_searches: RefCell<Vec<(i64, String)>>,
+    dispatched_message_links: RefCell<Vec<(i64, i64)>>,
+    dispatched_link_resolutions: RefCell<Vec<String>>,
+    dispatched_joins: RefCell<Vec<String>>,
+    dispatched_chat_lookups: RefCell<Vec<ChatQuery>>,
+    dispatched_chat_searches: RefCell<Vec<String>>,
+    dispatched_contacts_loads: RefCell<usize>,
+    dispatched_contact_adds: RefCell<Vec<i64>>,
+    dispatched_contact_removals: RefCell<Vec<i64>>,
+    dispatched_contact_chats: RefCell<Vec<i64>>,
+    dispatched_chat_creations: RefCell<Vec<NewChatRequest>>,
+    dispatched_chat_moves: RefCell<Vec<(i64, ChatListId)>>,
+    dispatched_chat_mutes: RefCell<Vec<(i64, Option<MuteDuration>)>>,
+    dispatched_unread_marks: RefCell<Vec<(i64, bool)>>,
+    dispatched_chat_pins: RefCell<Vec<(i64, ChatListId, bool)>>,
+    dispatched_pin_orders: RefCell<Vec<(ChatListId, Vec<i64>)>>,
+    dispatched_chat_manage_ops: RefCell<Vec<(i64, ChatManageOp)>>,
 }
 
 impl RecordingDispatcher {
@@ -123,21 +180,49 @@ impl RecordingDispatcher {
         Self {
             dispatched_chat_list_count: RefCell::new(0),
             dispatched_chat_list_force: RefCell::new(Vec::new()),
+            dispatched_chat_lists: RefCell::new(Vec::new()),
             dispatched_messages: RefCell::new(Vec::new()),
             dispatched_older_messages: RefCell::new(Vec::new()),
+            dispatched_messages_around_date: RefCell::new(Vec::new()),
+            dispatched_unread_windows: RefCell::new(Vec::new()),
             dispatched_sends: RefCell::new(Vec::new()),
+            last_send_mentions: RefCell::new(Vec::new()),
             dispatched_open_chats: RefCell::new(Vec::new()),
             dispatched_close_chats: RefCell::new(Vec::new()),
             dispatched_mark_as_read: RefCell::new(Vec::new()),
             dispatched_mark_chat_as_read: RefCell::new(Vec::new()),
+            dispatched_chat_actions: RefCell::new(Vec::new()),
             dispatched_prefetches: RefCell::new(Vec::new()),
             dispatched_forum_topics: RefCell::new(Vec::new()),
             dispatched_forum_unread_counts: RefCell::new(Vec::new()),
             dispatched_deletes: RefCell::new(Vec::new()),
+            blocking_deletes: RefCell::new(Vec::new()),
+            blocking_chat_manage_ops: RefCell::new(Vec::new()),
+            dispatched_delete_permissions: RefCell::new(Vec::new()),
+            dispatched_forwards: RefCell::new(Vec::new()),
             dispatched_voice_sends: RefCell::new(Vec::new()),
             dispatched_subtitles: RefCell::new(Vec::new()),
             dispatched_add_reactions: RefCell::new(Vec::new()),
             dispatched_remove_reactions: RefCell::new(Vec::new()),
+            dispatched_callback_queries: RefCell::new(Vec::new()),
+            dispatched_bot_commands: RefCell::new(Vec::new()),
+            dispatched_member_searches: RefCell::new(Vec::new()),
+            dispatched_message_links: RefCell::new(Vec::new()),
+            dispatched_link_resolutions: RefCell::new(Vec::new()),
+            dispatched_joins: RefCell::new(Vec::new()),
+            dispatched_chat_lookups: RefCell::new(Vec::new()),
+            dispatched_chat_searches: RefCell::new(Vec::new()),
+            dispatched_contacts_loads: RefCell::new(0),
+            dispatched_contact_adds: RefCell::new(Vec::new()),
+            dispatched_contact_removals: RefCell::new(Vec::new()),
+            dispatched_contact_chats: RefCell::new(Vec::new()),
+            dispatched_chat_creations: RefCell::new(Vec::new()),
+            dispatched_chat_moves: RefCell::new(Vec::new()),
+            dispatched_chat_mutes: RefCell::new(Vec::new()),
+            dispatched_unread_marks: RefCell::new(Vec::new()),
+            dispatched_chat_pins: RefCell::new(Vec::new()),
+            dispatched_pin_orders: RefCell::new(Vec::new()),
+            dispatched_chat_manage_ops: RefCell::new(Vec::new()),
         }
     }
 
@@ -149,6 +234,10 @@ impl RecordingDispatcher {
         self.dispatched_chat_list_force.borrow().last().copied()
     }
 
+    fn last_chat_list(&self) -> Option<ChatListId> {
+        self.dispatched_chat_lists.borrow().last().copied()
+    }
+
     fn messages_dispatch_count(&self) -> usize {
         self.dispatched_messages.borrow().len()
     }
@@ -169,6 +258,19 @@ impl RecordingDispatcher {
         self.dispatched_older_messages.borrow().last().copied()
     }
 
+    fn last_messages_around_date(&self) -> Option<(i64, Option<i32>, i64)> {
+        self.dispatched_messages_around_date
+            .borrow()
+            .last()
+            .copied()
+    }
+
+    fn last_unread_window(
+        &self,
+    ) -> Option<(i64, Option<i32>, crate::domain::chat::UnreadJumpKind)> {
+        self.dispatched_unread_windows.borrow().last().copied()
+    }
+
     #[allow(dead_code)]
     fn last_load_messages(&self) -> Option<(i64, Option<i32>)> {
         self.dispatched_messages.borrow().last().copied()
@@ -205,6 +307,10 @@ impl RecordingDispatcher {
             .map(|(c, _, t, r)| (*c, t.clone(), *r))
     }
 
+    fn last_send_mentions(&self) -> Vec<crate::domain::message_input_state::TextMention> {
+        self.last_send_mentions.borrow().clone()
+    }
+
     #[allow(dead_code)]
     fn last_send_full(&self) -> Option<(i64, Option<i32>, String, Option<i64>)> {
         self.dispatched_sends.borrow().last().cloned()
@@ -242,6 +348,10 @@ impl RecordingDispatcher {
         self.dispatched_mark_chat_as_read.borrow().last().cloned()
     }
 
+    fn chat_actions(&self) -> Vec<(i64, Option<i32>, ChatAction)> {
+        self.dispatched_chat_actions.borrow().clone()
+    }
+
     fn prefetch_dispatch_count(&self) -> usize {
         self.dispatched_prefetches.borrow().len()
     }
@@ -257,8 +367,24 @@ impl RecordingDispatcher {
         self.dispatched_deletes.borrow().len()
     }
 
-    fn last_delete(&self) -> Option<(i64, i64)> {
-        self.dispatched_deletes.borrow().last().copied()
+    fn last_delete(&self) -> Option<(i64, Vec<i64>, DeleteScope)> {
+        self.dispatched_deletes.borrow().last().cloned()
+    }
+
+    fn blocking_deletes(&self) -> Vec<(i64, Vec<i64>, DeleteScope)> {
+        self.blocking_deletes.borrow().clone()
+    }
+
+    fn blocking_chat_manage_ops(&self) -> Vec<(i64, ChatManageOp)> {
+        self.blocking_chat_manage_ops.borrow().clone()
+    }
+
+    fn delete_permission_queries(&self) -> Vec<(i64, Vec<i64>)> {
+        self.dispatched_delete_permissions.borrow().clone()
+    }
+
+    fn forwards(&self) -> Vec<(i64, i64, Vec<i64>)> {
+        self.dispatched_forwards.borrow().clone()
     }
 
     fn voice_send_dispatch_count(&self) -> usize {
@@ -292,12 +418,89 @@ impl RecordingDispatcher {
     fn last_remove_reaction(&self) -> Option<(i64, i64, String)> {
         self.dispatched_remove_reactions.borrow().last().cloned()
     }
+
+    fn callback_queries(&self) -> Vec<crate::usecases::inline_keyboard::CallbackQuery> {
+        self.dispatched_callback_queries.borrow().clone()
+    }
+
+    fn bot_commands_requests(&self) -> Vec<i64> {
+        self.dispatched_bot_commands.borrow().clone()
+    }
+
+    fn member_searches(&self) -> Vec<(i64, String)> {
+        self.dispatched_member_searches.borrow().clone()
+    }
+
+    fn message_link_requests(&self) -> Vec<(i64, i64)> {
+        self.dispatched_message_links.borrow().clone()
+    }
+
+    fn link_resolutions(&self) -> Vec<String> {
+        self.dispatched_link_resolutions.borrow().clone()
+    }
+
+    fn joins(&self) -> Vec<String> {
+        self.dispatched_joins.borrow().clone()
+    }
+
+    fn chat_lookups(&self) -> Vec<ChatQuery> {
+        self.dispatched_chat_lookups.borrow().clone()
+    }
+
+    fn chat_searches(&self) -> Vec<String> {
+        self.dispatched_chat_searches.borrow().clone()
+    }
+
+    fn contacts_load_count(&self) -> usize {
+        *self.dispatched_contacts_loads.borrow()
+    }
+
+    fn contact_adds(&self) -> Vec<i64> {
+        self.dispatched_contact_adds.borrow().clone()
+    }
+
+    fn contact_removals(&self) -> Vec<i64> {
+        self.dispatched_contact_removals.borrow().clone()
+    }
+
+    fn contact_chats(&self) -> Vec<i64> {
+        self.dispatched_contact_chats.borrow().clone()
+    }
+
+    fn chat_creations(&self) -> Vec<NewChatRequest> {
+        self.dispatched_chat_creations.borrow().clone()
+    }
+
+    fn chat_moves(&self) -> Vec<(i64, ChatListId)> {
+        self.dispatched_chat_moves.borrow().clone()
+    }
+
+    fn chat_mutes(&self) -> Vec<(i64, Option<MuteDuration>)> {
+        self.dispatched_chat_mutes.borrow().clone()
+    }
+
+    fn unread_marks(&self) -> Vec<(i64, bool)> {
+        self.dispatched_unread_marks.borrow().clone()
+    }
+
+    fn chat_pins(&self) -> Vec<(i64, ChatListId, bool)> {
+        self.dispatched_chat_pins.borrow().clone()
+    }
+
+    fn pin_orders(&self) -> Vec<(ChatListId, Vec<i64>)> {
+        self.dispatched_pin_orders.borrow().clone()
+    }
+
+    fn chat_manage_ops(&self) -> Vec<(i64, ChatManageOp)> {
+        self.dispatched_chat_manage_ops.borrow().clone()
+    }
 }
 
 impl TaskDispatcher for RecordingDispatcher {
-    fn dispatch_chat_list(&self, force: bool, _limit: usize) {
+    fn dispatch_chat_list(&self, list: ChatListId, force: bool, _limit: usize) {
         *self.dispatched_chat_list_count.borrow_mut() += 1;
         self.dispatched_chat_list_force.borrow_mut().push(force);
+        self.dispatched_chat_lists.borrow_mut().push(list);
     }
 
     fn dispatch_load_forum_topics(&self, chat_id: i64) {
@@ -327,16 +530,40 @@ impl TaskDispatcher for RecordingDispatcher {
             .push((chat_id, topic_id, from_message_id));
     }
 
+    fn dispatch_load_messages_around_date(
+        &self,
+        chat_id: i64,
+        topic_id: Option<i32>,
+        unix_time: i64,
+    ) {
+        self.dispatched_messages_around_date
+            .borrow_mut()
+            .push((chat_id, topic_id, unix_time));
+    }
+
+    fn dispatch_load_unread_window(
+        &self,
+        chat_id: i64,
+        topic_id: Option<i32>,
+        kind: crate::domain::chat::UnreadJumpKind,
+    ) {
+        self.dispatched_unread_windows
+            .borrow_mut()
+            .push((chat_id, topic_id, kind));
+    }
+
     fn dispatch_send_message(
         &self,
         chat_id: i64,
         topic_id: Option<i32>,
         text: String,
         reply_to_message_id: Option<i64>,
+        mentions: Vec<crate::domain::message_input_state::TextMention>,
     ) {
         self.dispatched_sends
             .borrow_mut()
             .push((chat_id, topic_id, text, reply_to_message_id));
+        *self.last_send_mentions.borrow_mut() = mentions;
     }
 
     fn dispatch_open_chat(&self, chat_id: i64) {
@@ -359,16 +586,47 @@ impl TaskDispatcher for RecordingDispatcher {
             .push((chat_id, last_message_id));
     }
 
+    fn dispatch_send_chat_action(&self, chat_id: i64, topic_id: Option<i32>, action: ChatAction) {
+        self.dispatched_chat_actions
+            .borrow_mut()
+            .push((chat_id, topic_id, action));
+    }
+
     fn dispatch_prefetch_messages(&self, chat_id: i64, topic_id: Option<i32>) {
         self.dispatched_prefetches
             .borrow_mut()
             .push((chat_id, topic_id));
     }
 
-    fn dispatch_delete_message(&self, chat_id: i64, message_id: i64) {
+    fn dispatch_delete_messages(&self, chat_id: i64, message_ids: Vec<i64>, scope: DeleteScope) {
         self.dispatched_deletes
             .borrow_mut()
-            .push((chat_id, message_id));
+            .push((chat_id, message_ids, scope));
+    }
+
+    fn delete_messages_blocking(
+        &self,
+        chat_id: i64,
+        message_ids: Vec<i64>,
+        scope: DeleteScope,
+        _timeout: std::time::Duration,
+    ) -> Result<(), BackgroundError> {
+        self.blocking_deletes
+            .borrow_mut()
+            .push((chat_id, message_ids, scope));
+        Ok(())
+    }
+
+    fn dispatch_delete_permissions(&self, chat_id: i64, message_ids: Vec<i64>) {
+        self.dispatched_delete_permissions
+            .borrow_mut()
+            .push((chat_id, message_ids));
+    }
+
+    fn dispatch_forward_messages(&self, to_chat_id: i64, from_chat_id: i64, message_ids: Vec<i64>) {
+        self.dispatched_forwards
+            .borrow_mut()
+            .push((to_chat_id, from_chat_id, message_ids));
     }
 
     fn dispatch_chat_subtitle(&self, query: ChatSubtitleQuery) {
@@ -420,6 +678,110 @@ impl TaskDispatcher for RecordingDispatcher {
             .borrow_mut()
             .push((chat_id, message_id, emoji));
     }
+
+    fn dispatch_callback_query(&self, query: crate::usecases::inline_keyboard::CallbackQuery) {
+        self.dispatched_callback_queries.borrow_mut().push(query);
+    }
+
+    fn dispatch_load_bot_commands(&self, chat_id: i64) {
+        self.dispatched_bot_commands.borrow_mut().push(chat_id);
+    }
+
+    fn dispatch_search_chat_members(&self, chat_id: i64, query: String) {
+        self.dispatched_member_searches
+            .borrow_mut()
+            .push((chat_id, query));
+    }
+
+    fn dispatch_message_link(&self, chat_id: i64, message_id: i64) {
+        self.dispatched_message_links
+            .borrow_mut()
+            .push((chat_id, message_id));
+    }
+
+    fn dispatch_resolve_link(&self, url: String) {
+        self.dispatched_link_resolutions.borrow_mut().push(url);
+    }
+
+    fn dispatch_join_chat(&self, invite_link: String) {
+        self.dispatched_joins.borrow_mut().push(invite_link);
+    }
+
+    fn dispatch_find_chat(&self, query: ChatQuery) {
+        self.dispatched_chat_lookups.borrow_mut().push(query);
+    }
+
+    fn dispatch_search_chats(&self, query: String) {
+        self.dispatched_chat_searches.borrow_mut().push(query);
+    }
+
+    fn dispatch_load_contacts(&self) {
+        *self.dispatched_contacts_loads.borrow_mut() += 1;
+    }
+
+    fn dispatch_add_contact(&self, chat_id: i64) {
+        self.dispatched_contact_adds.borrow_mut().push(chat_id);
+    }
+
+    fn dispatch_remove_contact(&self, user_id: i64) {
+        self.dispatched_contact_removals.borrow_mut().push(user_id);
+    }
+
+    fn dispatch_open_contact_chat(&self, user_id: i64) {
+        self.dispatched_contact_chats.borrow_mut().push(user_id);
+    }
+
+    fn dispatch_create_chat(&self, request: NewChatRequest) {
+        self.dispatched_chat_creations.borrow_mut().push(request);
+    }
+
+    fn dispatch_move_chat_to_list(&self, chat_id: i64, list: ChatListId) {
+        self.dispatched_chat_moves
+            .borrow_mut()
+            .push((chat_id, list));
+    }
+
+    fn dispatch_set_chat_mute(&self, chat_id: i64, mute_for: Option<MuteDuration>) {
+        self.dispatched_chat_mutes
+            .borrow_mut()
+            .push((chat_id, mute_for));
+    }
+
+    fn dispatch_set_chat_marked_as_unread(&self, chat_id: i64, marked: bool) {
+        self.dispatched_unread_marks
+            .borrow_mut()
+            .push((chat_id, marked));
+    }
+
+    fn dispatch_set_chat_pinned(&self, chat_id: i64, list: ChatListId, pinned: bool) {
+        self.dispatched_chat_pins
+            .borrow_mut()
+            .push((chat_id, list, pinned));
+    }
+
+    fn dispatch_set_pinned_chats(&self, list: ChatListId, chat_ids: Vec<i64>) {
+        self.dispatched_pin_orders
+            .borrow_mut()
+            .push((list, chat_ids));
+    }
+
+    fn dispatch_manage_chat(&self, chat_id: i64, op: ChatManageOp) {
+        self.dispatched_chat_manage_ops
+            .borrow_mut()
+            .push((chat_id, op));
+    }
+
+    fn manage_chat_blocking(
+        &self,
+        chat_id: i64,
+        op: ChatManageOp,
+        _timeout: std::time::Duration,
+    ) -> Result<(), BackgroundError> {
+        self.blocking_chat_manage_ops
+            .borrow_mut()
+            .push((chat_id, op));
+        Ok(())
+    }
 }
 
 // ── Test orchestrator factory ──
@@ -449,6 +811,7 @@ fn inject_chat_list(orchestrator: &mut TestOrchestrator, chats: Vec<ChatSummary>
     orchestrator
         .handle_event(AppEvent::BackgroundTaskCompleted(
             BackgroundTaskResult::ChatListLoaded {
+                list: ChatListId::Main,
                 result: Ok(chats),
                 all_loaded: false,
             },
@@ -631,6 +994,9 @@ fn make_orchestrator_with_cached_chats(chats: Vec<ChatSummary>) -> TestOrchestra
         std::collections::HashMap::new(),
         10_000_000,
         std::collections::HashMap::new(),
+        true,
+        true,
+        std::time::Duration::from_secs(5),
     )
 }
 
@@ -650,6 +1016,9 @@ fn make_orchestrator_with_cache(
         std::collections::HashMap::new(),
         10_000_000,
         std::collections::HashMap::new(),
+        true,
+        true,
+        std::time::Duration::from_secs(5),
     )
 }
 
@@ -669,6 +1038,9 @@ fn make_orchestrator_with_threshold(
         std::collections::HashMap::new(),
         10_000_000,
         std::collections::HashMap::new(),
+        true,
+        true,
+        std::time::Duration::from_secs(5),
     )
 }
 
@@ -689,6 +1061,9 @@ fn make_orchestrator_with_cache_and_threshold(
         std::collections::HashMap::new(),
         10_000_000,
         std::collections::HashMap::new(),
+        true,
+        true,
+        std::time::Duration::from_secs(5),
     )
 }
 
@@ -0,0 +1,192 @@
+use crate::domain::{
+    contact::Contact,
+    new_chat_wizard_state::{CreatedChat, NewChatKind, NewChatStep},
+};
+
+use super::*;
+
+fn press(o: &mut TestOrchestrator, key: &str) {
+    o.handle_event(AppEvent::InputKey(KeyInput::new(key, false)))
+        .unwrap();
+}
+
+fn type_text(o: &mut TestOrchestrator, text: &str) {
+    for ch in text.chars() {
+        press(o, &ch.to_string());
+    }
+}
+
+fn complete(o: &mut TestOrchestrator, result: BackgroundTaskResult) {
+    o.handle_event(AppEvent::BackgroundTaskCompleted(result))
+        .unwrap();
+}
+
+fn contact(user_id: i64, name: &str) -> Contact {
+    Contact {
+        user_id,
+        display_name: name.to_owned(),
+        username: String::new(),
+        is_online: false,
+    }
+}
+
+/// Opens the wizard and walks it to the members step with the given title.
+fn wizard_at_members(kind_steps: usize, title: &str) -> TestOrchestrator {
+    let mut o = orchestrator_with_chats(vec![chat(1, "Alice")]);
+    press(&mut o, "N");
+    for _ in 0..kind_steps {
+        press(&mut o, "j");
+    }
+    press(&mut o, "enter");
+    type_text(&mut o, title);
+    press(&mut o, "enter");
+    press(&mut o, "enter");
+    complete(
+        &mut o,
+        BackgroundTaskResult::ContactsLoaded {
+            result: Ok(vec![contact(10, "Alice"), contact(20, "Bob")]),
+        },
+    );
+    o
+}
+
+#[test]
+fn n_opens_wizard_and_loads_contacts() {
+    let mut o = orchestrator_with_chats(vec![chat(1, "Alice")]);
+
+    press(&mut o, "N");
+
+    let wizard = o.state().new_chat_wizard().expect("wizard is open");
+    assert_eq!(wizard.step(), NewChatStep::Kind);
+    assert_eq!(o.dispatcher.contacts_load_count(), 1);
+}
+
+#[test]
+fn typed_keys_go_to_the_wizard_not_bindings() {
+    let mut o = orchestrator_with_chats(vec![chat(1, "Alice")]);
+    press(&mut o, "N");
+    press(&mut o, "enter");
+
+    type_text(&mut o, "q/C");
+
+    assert!(o.state().is_running());
+    assert!(o.state().contacts().is_none());
+    assert_eq!(o.state().new_chat_wizard().unwrap().title(), "q/C");
+}
+
+#[test]
+fn enter_on_members_creates_supergroup_with_picked_members() {
+    let mut o = wizard_at_members(1, "War room");
+
+    press(&mut o, "down");
+    press(&mut o, " ");
+    press(&mut o, "enter");
+
+    assert!(o.state().new_chat_wizard().is_none());
+    let requests = o.dispatcher.chat_creations();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].kind, NewChatKind::Supergroup);
+    assert_eq!(requests[0].title, "War room");
+    assert_eq!(requests[0].member_ids, vec![20]);
+    assert_eq!(
+        o.state().active_notification(),
+        Some("Creating War room...")
+    );
+}
+
+#[test]
+fn basic_group_needs_a_member() {
+    let mut o = wizard_at_members(0, "Ops");
+
+    press(&mut o, "enter");
+
+    assert!(o.dispatcher.chat_creations().is_empty());
+    assert!(o.state().new_chat_wizard().is_some());
+    assert_eq!(
+        o.state().active_notification(),
+        Some("Pick at least one member for a group")
+    );
+}
+
+#[test]
+fn esc_steps_back_then_closes() {
+    let mut o = orchestrator_with_chats(vec![chat(1, "Alice")]);
+    press(&mut o, "N");
+    press(&mut o, "enter");
+
+    press(&mut o, "esc");
+    assert_eq!(
+        o.state().new_chat_wizard().map(|w| w.step()),
+        Some(NewChatStep::Kind)
+    );
+
+    press(&mut o, "esc");
+    assert!(o.state().new_chat_wizard().is_none());
+}
+
+#[test]
+fn created_chat_is_opened() {
+    let mut o = orchestrator_with_chats(vec![chat(1, "Alice")]);
+
+    complete(
+        &mut o,
+        BackgroundTaskResult::ChatCreated {
+            title: "War room".to_owned(),
+            result: Ok(created(chat(77, "War room"))),
+        },
+    );
+
+    assert_eq!(o.state().open_chat().chat_id(), Some(77));
+    assert_eq!(o.state().active_pane(), ActivePane::Messages);
+    assert_eq!(o.state().active_notification(), Some("Created War room"));
+}
+
+fn created(chat: ChatSummary) -> CreatedChat {
+    CreatedChat {
+        chat,
+        members_not_added: 0,
+        description_failed: false,
+    }
+}
+
+#[test]
+fn created_chat_reports_what_telegram_did_not_apply() {
+    let mut o = orchestrator_with_chats(vec![chat(1, "Alice")]);
+
+    complete(
+        &mut o,
+        BackgroundTaskResult::ChatCreated {
+            title: "War room".to_owned(),
+            result: Ok(CreatedChat {
+                members_not_added: 2,
+                description_failed: true,
+                ..created(chat(77, "War room"))
+            }),
+        },
+    );
+
+    assert_eq!(o.state().open_chat().chat_id(), Some(77));
+    assert_eq!(
+        o.state().active_notification(),
+        Some("Created War room, 2 members could not be added, description not set")
+    );
+}
+
+#[test]
+fn refused_creation_is_reported() {
+    let mut o = orchestrator_with_chats(vec![chat(1, "Alice")]);
+
+    complete(
+        &mut o,
+        BackgroundTaskResult::ChatCreated {
+            title: "War room".to_owned(),
+            result: Err(BackgroundError::new("CHAT_CREATION_REJECTED")),
+        },
+    );
+
+    assert!(!o.state().open_chat().is_open());
+    assert_eq!(
+        o.state().active_notification(),
+        Some("Telegram refused to create War room")
+    );
+}
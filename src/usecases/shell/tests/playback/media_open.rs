@@ -178,10 +178,12 @@ fn l_on_document_with_custom_handler_opens_playback_popup() {
             call_info: None,
             reply_to: None,
             forward_info: None,
-            reaction_count: 0,
+            reactions: Vec::new(),
             links: Vec::new(),
             is_edited: false,
             is_service: false,
+            inline_keyboard: Vec::new(),
+            reply_keyboard: None,
         }],
     );
     o.open_handlers
@@ -225,10 +227,12 @@ fn l_on_sticker_is_ignored() {
             call_info: None,
             reply_to: None,
             forward_info: None,
-            reaction_count: 0,
+            reactions: Vec::new(),
             links: Vec::new(),
             is_edited: false,
             is_service: false,
+            inline_keyboard: Vec::new(),
+            reply_keyboard: None,
         }],
     );
 
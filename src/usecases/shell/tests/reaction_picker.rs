@@ -81,11 +81,13 @@ fn available_reactions_loaded_updates_picker_to_ready() {
                     emoji: "👍".into(),
                     needs_premium: false,
                     is_chosen: false,
+                    is_recent: false,
                 },
                 AvailableReaction {
                     emoji: "❤".into(),
                     needs_premium: false,
                     is_chosen: false,
+                    is_recent: false,
                 },
             ]),
         },
@@ -166,16 +168,19 @@ fn j_k_navigates_in_ready_picker() {
                     emoji: "👍".into(),
                     needs_premium: false,
                     is_chosen: false,
+                    is_recent: false,
                 },
                 AvailableReaction {
                     emoji: "❤".into(),
                     needs_premium: false,
                     is_chosen: false,
+                    is_recent: false,
                 },
                 AvailableReaction {
                     emoji: "🔥".into(),
                     needs_premium: false,
                     is_chosen: false,
+                    is_recent: false,
                 },
             ]),
         },
@@ -233,11 +238,13 @@ fn enter_on_unchosen_reaction_dispatches_add_and_closes_picker() {
                     emoji: "👍".into(),
                     needs_premium: false,
                     is_chosen: false,
+                    is_recent: false,
                 },
                 AvailableReaction {
                     emoji: "❤".into(),
                     needs_premium: false,
                     is_chosen: false,
+                    is_recent: false,
                 },
             ]),
         },
@@ -276,11 +283,13 @@ fn enter_on_chosen_reaction_dispatches_remove_and_closes_picker() {
                     emoji: "🔥".into(),
                     needs_premium: false,
                     is_chosen: true,
+                    is_recent: false,
                 },
                 AvailableReaction {
                     emoji: "❤".into(),
                     needs_premium: false,
                     is_chosen: false,
+                    is_recent: false,
                 },
             ]),
         },
@@ -316,6 +325,7 @@ fn stale_reactions_result_ignored() {
                 emoji: "👍".into(),
                 needs_premium: false,
                 is_chosen: false,
+                is_recent: false,
             }]),
         },
     ))
@@ -347,3 +357,48 @@ fn picker_blocks_other_message_keys() {
     assert!(o.state().reaction_picker().is_some());
     assert_eq!(o.state().active_pane(), ActivePane::Messages);
 }
+
+#[test]
+fn search_filters_reactions_and_q_goes_to_query() {
+    use crate::domain::reaction_picker_state::{AvailableReaction, ReactionPickerState};
+
+    let mut o =
+        orchestrator_in_messages_pane(vec![chat(1, "Alice")], 1, vec![message(10, "hello")]);
+    o.handle_event(AppEvent::InputKey(KeyInput::new("R", false)))
+        .unwrap();
+    let reaction = |emoji: &str| AvailableReaction {
+        emoji: emoji.into(),
+        needs_premium: false,
+        is_chosen: false,
+        is_recent: false,
+    };
+    o.handle_event(AppEvent::BackgroundTaskCompleted(
+        BackgroundTaskResult::AvailableReactionsLoaded {
+            chat_id: 1,
+            message_id: 10,
+            result: Ok(vec![reaction("👍"), reaction("🔥"), reaction("🤔")]),
+        },
+    ))
+    .unwrap();
+
+    for key in ["/", "q", "esc", "/", "t", "h", "i", "enter"] {
+        o.handle_event(AppEvent::InputKey(KeyInput::new(key, false)))
+            .unwrap();
+    }
+
+    match o.state().reaction_picker().unwrap() {
+        ReactionPickerState::Ready(data) => {
+            assert!(!data.is_searching());
+            assert_eq!(data.query(), "thi");
+            assert_eq!(data.selected_reaction().unwrap().emoji, "🤔");
+        }
+        _ => panic!("expected Ready"),
+    }
+
+    o.handle_event(AppEvent::InputKey(KeyInput::new("enter", false)))
+        .unwrap();
+    assert_eq!(
+        o.dispatcher.last_add_reaction(),
+        Some((1, 10, "🤔".to_owned()))
+    );
+}
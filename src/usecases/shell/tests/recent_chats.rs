@@ -0,0 +1,247 @@
+use super::*;
+use crate::domain::{recent_chats::RecentChat, shell_state::ActivePane};
+
+fn press(o: &mut TestOrchestrator, key: &str) {
+    o.handle_event(AppEvent::InputKey(KeyInput::new(key, false)))
+        .unwrap();
+}
+
+fn press_ctrl(o: &mut TestOrchestrator, key: &str) {
+    o.handle_event(AppEvent::InputKey(KeyInput::new(key, true)))
+        .unwrap();
+}
+
+fn type_text(o: &mut TestOrchestrator, text: &str) {
+    for ch in text.chars() {
+        press(o, &ch.to_string());
+    }
+}
+
+fn switcher_titles(o: &TestOrchestrator) -> Vec<String> {
+    o.state()
+        .quick_switcher()
+        .expect("switcher open")
+        .entries()
+        .iter()
+        .map(|entry| entry.chat.display_title())
+        .collect()
+}
+
+/// Opens Alice, then Bob, from the chat list.
+fn orchestrator_after_alice_then_bob() -> TestOrchestrator {
+    let mut o = orchestrator_with_chats(vec![chat(1, "Alice"), chat(2, "Bob"), chat(3, "Carol")]);
+    press(&mut o, "enter");
+    press(&mut o, "h");
+    press(&mut o, "j");
+    press(&mut o, "enter");
+    o
+}
+
+#[test]
+fn opened_chats_are_saved_most_recent_first_on_tick() {
+    let mut o = orchestrator_after_alice_then_bob();
+    assert!(o.storage.recent_chats.is_empty(), "saved on the next tick");
+
+    o.handle_event(AppEvent::Tick).unwrap();
+
+    let saved: Vec<i64> = o.storage.recent_chats.iter().map(|c| c.chat_id).collect();
+    assert_eq!(saved, vec![2, 1]);
+}
+
+#[test]
+fn quit_key_saves_opened_chats() {
+    let mut o = orchestrator_after_alice_then_bob();
+    press(&mut o, "h");
+
+    press(&mut o, "q");
+
+    assert!(!o.state().is_running());
+    let saved: Vec<i64> = o.storage.recent_chats.iter().map(|c| c.chat_id).collect();
+    assert_eq!(saved, vec![2, 1]);
+}
+
+#[test]
+fn ctrl_k_preselects_the_previous_chat_and_enter_opens_it() {
+    let mut o = orchestrator_after_alice_then_bob();
+
+    press_ctrl(&mut o, "k");
+
+    assert_eq!(switcher_titles(&o), vec!["Bob", "Alice"]);
+    press(&mut o, "enter");
+    assert!(o.state().quick_switcher().is_none());
+    assert_eq!(o.state().open_chat().chat_id(), Some(1));
+    assert_eq!(o.state().active_pane(), ActivePane::Messages);
+    assert_eq!(
+        o.state().chat_list().selected_chat().map(|c| c.chat_id),
+        Some(1)
+    );
+}
+
+#[test]
+fn typing_filters_the_switcher_without_triggering_bindings() {
+    let mut o = orchestrator_after_alice_then_bob();
+    press_ctrl(&mut o, "k");
+
+    type_text(&mut o, "q");
+    press(&mut o, "backspace");
+    type_text(&mut o, "bo");
+
+    assert!(o.state().is_running());
+    assert_eq!(switcher_titles(&o), vec!["Bob"]);
+    press(&mut o, "esc");
+    assert!(o.state().quick_switcher().is_none());
+    assert_eq!(o.state().open_chat().chat_id(), Some(2));
+}
+
+#[test]
+fn ctrl_k_in_the_switcher_moves_down() {
+    let mut o = orchestrator_after_alice_then_bob();
+    press_ctrl(&mut o, "k");
+
+    press_ctrl(&mut o, "p");
+    press_ctrl(&mut o, "k");
+
+    assert_eq!(
+        o.state()
+            .quick_switcher()
+            .and_then(|s| s.selected_chat())
+            .map(|c| c.chat_id),
+        Some(1)
+    );
+}
+
+#[test]
+fn ctrl_k_without_recent_chats_says_so() {
+    let mut o = orchestrator_with_chats(vec![chat(1, "Alice")]);
+
+    press_ctrl(&mut o, "k");
+
+    assert!(o.state().quick_switcher().is_none());
+    assert_eq!(o.state().active_notification(), Some("No recent chats yet"));
+}
+
+#[test]
+fn previous_chat_toggles_between_the_last_two_chats() {
+    let mut o = orchestrator_after_alice_then_bob();
+
+    press_ctrl(&mut o, "6");
+    assert_eq!(o.state().open_chat().chat_id(), Some(1));
+
+    press_ctrl(&mut o, "6");
+    assert_eq!(o.state().open_chat().chat_id(), Some(2));
+}
+
+#[test]
+fn recent_chats_from_the_last_run_open_without_being_loaded() {
+    let storage = StubStorageAdapter {
+        recent_chats: vec![
+            RecentChat::chat(42, "Old friend", ChatType::Private),
+            RecentChat::chat(1, "Alice", ChatType::Private),
+        ],
+        ..StubStorageAdapter::default()
+    };
+    let mut o = DefaultShellOrchestrator::new(
+        storage,
+        RecordingOpener::default(),
+        RecordingDispatcher::new(),
+    );
+    inject_chat_list(&mut o, vec![chat(1, "Alice")]);
+
+    press_ctrl(&mut o, "6");
+
+    assert_eq!(o.state().open_chat().chat_id(), Some(42));
+    assert_eq!(o.state().open_chat().chat_title(), "Old friend");
+    assert_eq!(o.dispatcher.last_load_messages(), Some((42, None)));
+}
+
+#[test]
+fn forum_topics_are_separate_entries_and_reopen_directly() {
+    let mut o = orchestrator_with_chats(vec![forum_chat(7, "Rust"), chat(1, "Alice")]);
+    press(&mut o, "enter");
+    inject_forum_topics(
+        &mut o,
+        7,
+        vec![topic(7, 10, "Async", 2), topic(7, 11, "Macros", 1)],
+    );
+    press(&mut o, "enter");
+    // Back to the topic list, out of the forum, then open Alice.
+    press(&mut o, "h");
+    press(&mut o, "h");
+    press(&mut o, "j");
+    press(&mut o, "enter");
+    assert_eq!(o.state().open_chat().chat_id(), Some(1));
+
+    press_ctrl(&mut o, "k");
+    assert_eq!(switcher_titles(&o), vec!["Alice", "Rust > Async"]);
+    press(&mut o, "enter");
+
+    assert_eq!(o.state().open_chat().chat_id(), Some(7));
+    assert_eq!(o.state().open_chat().topic_id(), Some(10));
+    assert_eq!(o.state().active_pane(), ActivePane::Messages);
+    assert_eq!(
+        o.state().forum_topic_list().map(|f| f.parent_chat_id()),
+        Some(7)
+    );
+    assert_eq!(o.dispatcher.last_load_messages(), Some((7, Some(10))));
+}
+
+#[test]
+fn reopening_an_unread_topic_drops_the_forum_badge() {
+    let mut forum = forum_chat(7, "Rust");
+    forum.unread_topic_count = Some(1);
+    let mut o = orchestrator_with_chats(vec![forum]);
+    press(&mut o, "enter");
+    inject_forum_topics(
+        &mut o,
+        7,
+        vec![topic(7, 10, "Async", 2), topic(7, 11, "Macros", 1)],
+    );
+    // Open Async, then Macros, and go back to the topic list.
+    press(&mut o, "enter");
+    press(&mut o, "h");
+    press(&mut o, "j");
+    press(&mut o, "enter");
+    press(&mut o, "h");
+    assert_eq!(o.state().chat_list().chats()[0].unread_topic_count, Some(1));
+
+    let mut unread = topic(7, 10, "Async", 2);
+    unread.unread_count = 3;
+    inject_forum_topics(&mut o, 7, vec![unread, topic(7, 11, "Macros", 1)]);
+    press_ctrl(&mut o, "k");
+    assert_eq!(switcher_titles(&o), vec!["Rust > Macros", "Rust > Async"]);
+    press(&mut o, "enter");
+
+    assert_eq!(o.state().open_chat().topic_id(), Some(10));
+    assert_eq!(o.state().chat_list().chats()[0].unread_topic_count, Some(0));
+}
+
+#[test]
+fn deleting_a_chat_forgets_it() {
+    let mut o = orchestrator_after_alice_then_bob();
+    press(&mut o, "h");
+
+    press(&mut o, "d");
+    press(&mut o, "d");
+    press(&mut o, "e");
+    o.pending_ops.expire_all();
+    o.handle_event(AppEvent::Tick).unwrap();
+    press_ctrl(&mut o, "k");
+    assert_eq!(
+        switcher_titles(&o),
+        vec!["Bob", "Alice"],
+        "kept until it is gone"
+    );
+    press(&mut o, "esc");
+
+    o.handle_event(AppEvent::BackgroundTaskCompleted(
+        BackgroundTaskResult::ChatManaged {
+            chat_id: 2,
+            op: ChatManageOp::Delete,
+            result: Ok(()),
+        },
+    ))
+    .unwrap();
+    press_ctrl(&mut o, "k");
+
+    assert_eq!(switcher_titles(&o), vec!["Alice"]);
+}
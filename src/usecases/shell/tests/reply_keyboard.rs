@@ -0,0 +1,76 @@
+use super::*;
+use crate::domain::message::ReplyKeyboard;
+
+fn press(o: &mut TestOrchestrator, keys: &[&str]) {
+    for key in keys {
+        o.handle_event(AppEvent::InputKey(KeyInput::new(*key, false)))
+            .unwrap();
+    }
+}
+
+fn keyboard_message(id: i64, one_time: bool) -> Message {
+    let mut msg = message(id, "Choose a size");
+    msg.reply_keyboard = Some(ReplyKeyboard {
+        rows: vec![
+            vec!["Small".to_owned(), "Large".to_owned()],
+            vec!["Cancel".to_owned()],
+        ],
+        one_time,
+        placeholder: String::new(),
+    });
+    msg
+}
+
+#[test]
+fn shift_b_without_keyboard_shows_notification() {
+    let mut o = orchestrator_with_open_chat(vec![chat(1, "Shop")], 1, vec![message(10, "hi")]);
+
+    press(&mut o, &["B"]);
+
+    assert!(o.state.reply_keyboard_selection().is_none());
+    assert_eq!(
+        o.state.active_notification(),
+        Some("No reply keyboard in this chat")
+    );
+}
+
+#[test]
+fn enter_sends_selected_button_text() {
+    let mut o =
+        orchestrator_with_open_chat(vec![chat(1, "Shop")], 1, vec![keyboard_message(10, false)]);
+
+    press(&mut o, &["B", "l", "enter"]);
+
+    assert!(o.state.reply_keyboard_selection().is_none());
+    assert_eq!(
+        o.dispatcher.last_send(),
+        Some((1, "Large".to_owned(), None))
+    );
+    assert_eq!(o.state.open_chat().messages().last().unwrap().text, "Large");
+    assert!(o.state.open_chat().reply_keyboard().is_some());
+}
+
+#[test]
+fn one_time_keyboard_is_hidden_after_use() {
+    let mut o =
+        orchestrator_with_open_chat(vec![chat(1, "Shop")], 1, vec![keyboard_message(10, true)]);
+
+    press(&mut o, &["B", "j", "enter"]);
+
+    assert_eq!(
+        o.dispatcher.last_send(),
+        Some((1, "Cancel".to_owned(), None))
+    );
+    assert!(o.state.open_chat().reply_keyboard().is_none());
+}
+
+#[test]
+fn esc_leaves_selection_without_sending() {
+    let mut o =
+        orchestrator_with_open_chat(vec![chat(1, "Shop")], 1, vec![keyboard_message(10, false)]);
+
+    press(&mut o, &["B", "esc"]);
+
+    assert!(o.state.reply_keyboard_selection().is_none());
+    assert_eq!(o.dispatcher.send_dispatch_count(), 0);
+}
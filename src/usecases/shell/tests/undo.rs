@@ -0,0 +1,195 @@
+use super::*;
+
+fn press(o: &mut TestOrchestrator, keys: &[&str]) {
+    for key in keys {
+        o.handle_event(AppEvent::InputKey(KeyInput::new(*key, false)))
+            .unwrap();
+    }
+}
+
+fn open_chat_deleting_without_confirmation() -> TestOrchestrator {
+    let mut o = orchestrator_with_open_chat(
+        vec![chat(1, "Chat"), chat(2, "Other")],
+        1,
+        vec![message(10, "hello"), message(20, "world")],
+    );
+    o.confirm_delete = false;
+    o
+}
+
+fn message_ids(o: &TestOrchestrator) -> Vec<i64> {
+    o.state()
+        .open_chat()
+        .messages()
+        .iter()
+        .map(|m| m.id)
+        .collect()
+}
+
+#[test]
+fn delete_is_queued_and_offers_undo() {
+    let mut o = open_chat_deleting_without_confirmation();
+
+    press(&mut o, &["d", "d"]);
+
+    assert_eq!(message_ids(&o), vec![10]);
+    assert_eq!(o.dispatcher.delete_dispatch_count(), 0);
+    assert_eq!(
+        o.state().active_notification(),
+        Some("Deleted 1 message — press u to undo")
+    );
+}
+
+#[test]
+fn delete_is_sent_once_grace_period_ends() {
+    let mut o = open_chat_deleting_without_confirmation();
+    press(&mut o, &["d", "d"]);
+
+    o.handle_event(AppEvent::Tick).unwrap();
+    assert_eq!(o.dispatcher.delete_dispatch_count(), 0);
+
+    o.pending_ops.expire_all();
+    o.handle_event(AppEvent::Tick).unwrap();
+
+    assert_eq!(
+        o.dispatcher.last_delete(),
+        Some((1, vec![20], DeleteScope::Auto))
+    );
+    o.handle_event(AppEvent::Tick).unwrap();
+    assert_eq!(o.dispatcher.delete_dispatch_count(), 1);
+}
+
+#[test]
+fn u_restores_message_without_network_call() {
+    let mut o = open_chat_deleting_without_confirmation();
+    press(&mut o, &["d", "d", "u"]);
+
+    assert_eq!(message_ids(&o), vec![10, 20]);
+    assert_eq!(o.state().open_chat().selected_message().unwrap().id, 20);
+    assert_eq!(o.state().active_notification(), Some("Restored 1 message"));
+
+    o.pending_ops.expire_all();
+    o.handle_event(AppEvent::Tick).unwrap();
+    assert_eq!(o.dispatcher.delete_dispatch_count(), 0);
+}
+
+#[test]
+fn u_undoes_newest_operation_first() {
+    let mut o = open_chat_deleting_without_confirmation();
+    press(&mut o, &["d", "d", "d", "d", "u"]);
+
+    assert_eq!(message_ids(&o), vec![10]);
+
+    o.pending_ops.expire_all();
+    o.handle_event(AppEvent::Tick).unwrap();
+    assert_eq!(
+        o.dispatcher.last_delete(),
+        Some((1, vec![20], DeleteScope::Auto))
+    );
+    assert_eq!(o.dispatcher.delete_dispatch_count(), 1);
+}
+
+#[test]
+fn u_without_pending_operation_says_so() {
+    let mut o = open_chat_deleting_without_confirmation();
+
+    press(&mut o, &["u"]);
+
+    assert_eq!(o.state().active_notification(), Some("Nothing to undo"));
+}
+
+#[test]
+fn refresh_during_grace_period_keeps_message_hidden() {
+    let mut o = open_chat_deleting_without_confirmation();
+    press(&mut o, &["d", "d"]);
+
+    o.handle_event(AppEvent::BackgroundTaskCompleted(
+        BackgroundTaskResult::MessagesLoaded {
+            chat_id: 1,
+            topic_id: None,
+            result: Ok(vec![message(10, "hello"), message(20, "world")]),
+        },
+    ))
+    .unwrap();
+
+    assert_eq!(message_ids(&o), vec![10]);
+}
+
+#[test]
+fn quit_waits_for_pending_deletes() {
+    let mut o = open_chat_deleting_without_confirmation();
+    press(&mut o, &["d", "d"]);
+
+    o.handle_event(AppEvent::QuitRequested).unwrap();
+
+    assert_eq!(o.dispatcher.delete_dispatch_count(), 0);
+    assert_eq!(
+        o.dispatcher.blocking_deletes(),
+        vec![(1, vec![20], DeleteScope::Auto)]
+    );
+}
+
+#[test]
+fn quit_key_waits_for_pending_deletes() {
+    let mut o = open_chat_deleting_without_confirmation();
+    press(&mut o, &["d", "d", "q"]);
+
+    assert!(!o.state().is_running());
+    assert_eq!(o.dispatcher.blocking_deletes().len(), 1);
+}
+
+fn deleted(o: &mut TestOrchestrator, result: Result<(), BackgroundError>) {
+    o.handle_event(AppEvent::BackgroundTaskCompleted(
+        BackgroundTaskResult::MessagesDeleted {
+            chat_id: 1,
+            message_ids: vec![20],
+            result,
+        },
+    ))
+    .unwrap();
+}
+
+#[test]
+fn failed_delete_reloads_the_messages_and_says_so() {
+    let mut o = open_chat_deleting_without_confirmation();
+    press(&mut o, &["d", "d"]);
+    o.pending_ops.expire_all();
+    o.handle_event(AppEvent::Tick).unwrap();
+    let loads = o.dispatcher.dispatched_messages.borrow().len();
+
+    deleted(&mut o, Err(BackgroundError::new("DELETE_MESSAGES_FAILED")));
+
+    assert_eq!(
+        o.state().active_notification(),
+        Some("Failed to delete 1 message")
+    );
+    assert_eq!(o.dispatcher.dispatched_messages.borrow().len(), loads + 1);
+    assert_eq!(o.dispatcher.last_load_messages(), Some((1, None)));
+    o.handle_event(AppEvent::BackgroundTaskCompleted(
+        BackgroundTaskResult::MessagesLoaded {
+            chat_id: 1,
+            topic_id: None,
+            result: Ok(vec![message(10, "hello"), message(20, "world")]),
+        },
+    ))
+    .unwrap();
+    assert_eq!(message_ids(&o), vec![10, 20]);
+}
+
+#[test]
+fn successful_delete_stops_hiding_the_message() {
+    let mut o = open_chat_deleting_without_confirmation();
+    press(&mut o, &["d", "d"]);
+    o.pending_ops.expire_all();
+    o.handle_event(AppEvent::Tick).unwrap();
+    let loads = o.dispatcher.dispatched_messages.borrow().len();
+
+    deleted(&mut o, Ok(()));
+
+    assert_eq!(message_ids(&o), vec![10]);
+    assert_eq!(o.dispatcher.dispatched_messages.borrow().len(), loads);
+    assert_eq!(
+        o.state().active_notification(),
+        Some("Deleted 1 message — press u to undo")
+    );
+}
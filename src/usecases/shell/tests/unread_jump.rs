@@ -0,0 +1,121 @@
+use crate::domain::{chat::UnreadJumpKind, message::UnreadMessageWindow};
+
+use super::*;
+
+fn press(o: &mut TestOrchestrator, key: &str) {
+    o.handle_event(AppEvent::InputKey(KeyInput::new(key, false)))
+        .unwrap();
+}
+
+fn inject_unread_window(
+    o: &mut TestOrchestrator,
+    chat_id: i64,
+    kind: UnreadJumpKind,
+    result: Result<Option<UnreadMessageWindow>, BackgroundError>,
+) {
+    o.handle_event(AppEvent::BackgroundTaskCompleted(
+        BackgroundTaskResult::UnreadWindowLoaded {
+            chat_id,
+            topic_id: None,
+            kind,
+            result,
+        },
+    ))
+    .unwrap();
+}
+
+#[test]
+fn g_m_dispatches_mention_search_for_open_chat() {
+    let mut o = orchestrator_with_open_chat(vec![chat(1, "Chat")], 1, vec![message(1, "a")]);
+
+    press(&mut o, "g");
+    press(&mut o, "m");
+
+    assert_eq!(
+        o.dispatcher.last_unread_window(),
+        Some((1, None, UnreadJumpKind::Mention))
+    );
+}
+
+#[test]
+fn g_r_dispatches_reaction_search_for_open_chat() {
+    let mut o = orchestrator_with_open_chat(vec![chat(1, "Chat")], 1, vec![message(1, "a")]);
+
+    press(&mut o, "g");
+    press(&mut o, "r");
+
+    assert_eq!(
+        o.dispatcher.last_unread_window(),
+        Some((1, None, UnreadJumpKind::Reaction))
+    );
+}
+
+#[test]
+fn loaded_target_is_selected_without_replacing_messages() {
+    let messages = vec![message(1, "a"), message(2, "b"), message(3, "c")];
+    let mut o = orchestrator_with_open_chat(vec![chat(1, "Chat")], 1, messages);
+
+    inject_unread_window(
+        &mut o,
+        1,
+        UnreadJumpKind::Mention,
+        Ok(Some(UnreadMessageWindow {
+            target_message_id: 1,
+            messages: vec![message(1, "a")],
+        })),
+    );
+
+    let open_chat = o.state().open_chat();
+    assert!(!open_chat.is_history_window());
+    assert_eq!(open_chat.messages().len(), 3);
+    assert_eq!(open_chat.selected_message().map(|m| m.id), Some(1));
+    assert_eq!(o.dispatcher.last_mark_as_read(), Some((1, vec![1])));
+}
+
+#[test]
+fn unloaded_target_opens_history_window_and_marks_read() {
+    let mut o = orchestrator_with_open_chat(vec![chat(1, "Chat")], 1, vec![message(50, "latest")]);
+
+    inject_unread_window(
+        &mut o,
+        1,
+        UnreadJumpKind::Reaction,
+        Ok(Some(UnreadMessageWindow {
+            target_message_id: 11,
+            messages: vec![message(10, "x"), message(11, "y"), message(12, "z")],
+        })),
+    );
+
+    let open_chat = o.state().open_chat();
+    assert!(open_chat.is_history_window());
+    assert_eq!(open_chat.selected_message().map(|m| m.id), Some(11));
+    assert_eq!(o.dispatcher.last_mark_as_read(), Some((1, vec![11])));
+}
+
+#[test]
+fn nothing_unread_shows_notification() {
+    let mut o = orchestrator_with_open_chat(vec![chat(1, "Chat")], 1, vec![message(1, "a")]);
+
+    inject_unread_window(&mut o, 1, UnreadJumpKind::Mention, Ok(None));
+
+    assert_eq!(o.state().active_notification(), Some("No unread mentions"));
+}
+
+#[test]
+fn stale_unread_window_is_discarded() {
+    let mut o = orchestrator_with_open_chat(vec![chat(1, "Chat")], 1, vec![message(1, "a")]);
+    let marks_before = o.dispatcher.mark_as_read_dispatch_count();
+
+    inject_unread_window(
+        &mut o,
+        2,
+        UnreadJumpKind::Mention,
+        Ok(Some(UnreadMessageWindow {
+            target_message_id: 7,
+            messages: vec![message(7, "x")],
+        })),
+    );
+
+    assert!(!o.state().open_chat().is_history_window());
+    assert_eq!(o.dispatcher.mark_as_read_dispatch_count(), marks_before);
+}
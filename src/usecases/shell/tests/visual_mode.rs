@@ -0,0 +1,169 @@
+use super::*;
+
+fn press(o: &mut TestOrchestrator, keys: &[&str]) {
+    for key in keys {
+        o.handle_event(AppEvent::InputKey(KeyInput::new(*key, false)))
+            .unwrap();
+    }
+}
+
+fn open_chat_with_four_messages() -> TestOrchestrator {
+    orchestrator_with_open_chat(
+        vec![chat(1, "General"), chat(2, "Archive")],
+        1,
+        vec![
+            message(10, "a"),
+            message(20, "b"),
+            message(30, "c"),
+            message(40, "d"),
+        ],
+    )
+}
+
+fn downloaded_file_message(id: i64) -> Message {
+    Message {
+        media: crate::domain::message::MessageMedia::Document,
+        file_info: Some(crate::domain::message::FileInfo {
+            file_id: id as i32,
+            local_path: Some(format!("/tmp/rtg-visual-{id}")),
+            mime_type: "application/pdf".to_owned(),
+            size: Some(1000),
+            duration: None,
+            file_name: Some(format!("file-{id}.pdf")),
+            is_listened: false,
+            download_status: crate::domain::message::DownloadStatus::Completed,
+        }),
+        ..message(id, "")
+    }
+}
+
+#[test]
+fn v_then_k_extends_selection_upwards() {
+    let mut o = open_chat_with_four_messages();
+
+    press(&mut o, &["v", "k", "k"]);
+
+    assert_eq!(o.state().open_chat().visual_range(), Some(1..=3));
+}
+
+#[test]
+fn dd_in_visual_mode_deletes_range_in_one_request() {
+    let mut o = open_chat_with_four_messages();
+
+    o.confirm_delete = false;
+    o.undo_grace = std::time::Duration::ZERO;
+
+    press(&mut o, &["v", "k", "k", "d", "d"]);
+
+    assert_eq!(o.dispatcher.delete_dispatch_count(), 1);
+    assert_eq!(
+        o.dispatcher.last_delete(),
+        Some((1, vec![20, 30, 40], DeleteScope::Auto))
+    );
+    let remaining: Vec<i64> = o
+        .state()
+        .open_chat()
+        .messages()
+        .iter()
+        .map(|m| m.id)
+        .collect();
+    assert_eq!(remaining, vec![10]);
+    assert!(!o.state().open_chat().is_visual());
+    assert_eq!(o.state().active_notification(), Some("Deleted 3 messages"));
+}
+
+#[test]
+fn esc_and_v_leave_visual_mode_without_leaving_chat() {
+    let mut o = open_chat_with_four_messages();
+
+    press(&mut o, &["v", "k", "esc"]);
+    assert!(!o.state().open_chat().is_visual());
+    assert_eq!(o.state().active_pane(), ActivePane::Messages);
+
+    press(&mut o, &["v", "v"]);
+    assert!(!o.state().open_chat().is_visual());
+    assert_eq!(o.state().active_pane(), ActivePane::Messages);
+}
+
+#[test]
+fn forward_picks_target_in_chat_list() {
+    let mut o = open_chat_with_four_messages();
+
+    press(&mut o, &["v", "k", "f"]);
+
+    assert_eq!(o.state().active_pane(), ActivePane::ChatList);
+    assert!(!o.state().open_chat().is_visual());
+    assert!(o.dispatcher.forwards().is_empty());
+
+    press(&mut o, &["j", "enter"]);
+
+    assert_eq!(o.dispatcher.forwards(), vec![(2, 1, vec![30, 40])]);
+    assert!(o.state().pending_forward().is_none());
+    assert_eq!(o.state().open_chat().chat_id(), Some(2));
+    assert_eq!(
+        o.state().active_notification(),
+        Some("Forwarding 2 messages...")
+    );
+
+    forwarded(&mut o, Ok(()));
+    assert_eq!(
+        o.state().active_notification(),
+        Some("2 messages forwarded")
+    );
+}
+
+fn forwarded(o: &mut TestOrchestrator, result: Result<(), BackgroundError>) {
+    o.handle_event(AppEvent::BackgroundTaskCompleted(
+        BackgroundTaskResult::MessagesForwarded {
+            to_chat_id: 2,
+            count: 2,
+            result,
+        },
+    ))
+    .unwrap();
+}
+
+#[test]
+fn failed_forward_is_reported() {
+    let mut o = open_chat_with_four_messages();
+    press(&mut o, &["v", "k", "f", "j", "enter"]);
+
+    forwarded(&mut o, Err(BackgroundError::new("FORWARD_MESSAGES_FAILED")));
+
+    assert_eq!(
+        o.state().active_notification(),
+        Some("Failed to forward 2 messages")
+    );
+}
+
+#[test]
+fn esc_in_chat_list_cancels_pending_forward() {
+    let mut o = open_chat_with_four_messages();
+
+    press(&mut o, &["f", "esc"]);
+
+    assert!(o.state().pending_forward().is_none());
+    assert_eq!(o.state().active_notification(), Some("Forward cancelled"));
+
+    press(&mut o, &["enter"]);
+    assert!(o.dispatcher.forwards().is_empty());
+}
+
+#[test]
+fn save_in_visual_mode_saves_every_file_in_range() {
+    let mut o = orchestrator_with_open_chat(
+        vec![chat(1, "General")],
+        1,
+        vec![
+            downloaded_file_message(10),
+            message(20, "no file"),
+            downloaded_file_message(30),
+        ],
+    );
+
+    press(&mut o, &["v", "k", "k", "S"]);
+
+    assert_eq!(o.pending_saves.len(), 2);
+    assert_eq!(o.state().active_notification(), Some("Saving 2 files..."));
+    assert!(!o.state().open_chat().is_visual());
+}
@@ -4,7 +4,7 @@ use super::*;
 fn v_ignored_when_no_chat_open() {
     let mut o = orchestrator_with_chats(vec![chat(1, "Chat")]);
 
-    o.handle_event(AppEvent::InputKey(KeyInput::new("v", false)))
+    o.handle_event(AppEvent::InputKey(KeyInput::new("V", false)))
         .unwrap();
 
     assert!(o.state().command_popup().is_none());
@@ -15,7 +15,7 @@ fn v_ignored_when_popup_already_active() {
     let mut o = orchestrator_with_open_chat(vec![chat(1, "Chat")], 1, vec![message(10, "hi")]);
     simulate_voice_recording_started(&mut o, "/tmp/test.oga");
 
-    o.handle_event(AppEvent::InputKey(KeyInput::new("v", false)))
+    o.handle_event(AppEvent::InputKey(KeyInput::new("V", false)))
         .unwrap();
 
     assert!(o.state().command_popup().is_some());
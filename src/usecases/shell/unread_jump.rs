@@ -0,0 +1,71 @@
+use crate::{
+    domain::{chat::UnreadJumpKind, events::BackgroundError, message::UnreadMessageWindow},
+    usecases::background::TaskDispatcher,
+};
+
+use super::OrchestratorCtx;
+
+pub(super) fn jump_to_next_unread<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    kind: UnreadJumpKind,
+) {
+    let Some(chat_id) = ctx.state.open_chat().chat_id() else {
+        return;
+    };
+    let topic_id = ctx.state.open_chat().topic_id();
+
+    tracing::debug!(chat_id, ?topic_id, ?kind, "jumping to next unread");
+    ctx.dispatcher
+        .dispatch_load_unread_window(chat_id, topic_id, kind);
+}
+
+pub(super) fn handle_unread_window_loaded<D: TaskDispatcher>(
+    ctx: &mut OrchestratorCtx<'_, D>,
+    chat_id: i64,
+    topic_id: Option<i32>,
+    kind: UnreadJumpKind,
+    result: Result<Option<UnreadMessageWindow>, BackgroundError>,
+) {
+    if ctx.state.open_chat().chat_id() != Some(chat_id)
+        || ctx.state.open_chat().topic_id() != topic_id
+    {
+        tracing::debug!(
+            chat_id,
+            ?topic_id,
+            "background: discarding stale unread window result"
+        );
+        return;
+    }
+
+    match result {
+        Ok(None) => {
+            ctx.state.set_notification(match kind {
+                UnreadJumpKind::Mention => "No unread mentions",
+                UnreadJumpKind::Reaction => "No unread reactions",
+            });
+        }
+        Ok(Some(window)) => {
+            let target_id = window.target_message_id;
+            // Stay on the current page when the target is already loaded.
+            if !ctx.state.open_chat_mut().select_message_by_id(target_id) {
+                ctx.state
+                    .open_chat_mut()
+                    .show_history_window_at(window.messages, target_id);
+            }
+            ctx.dispatcher
+                .dispatch_mark_as_read(chat_id, topic_id, vec![target_id]);
+        }
+        Err(error) => {
+            tracing::warn!(
+                chat_id,
+                ?kind,
+                code = error.code,
+                "background: jump to unread failed"
+            );
+            ctx.state.set_notification(match kind {
+                UnreadJumpKind::Mention => "Failed to load unread mentions",
+                UnreadJumpKind::Reaction => "Failed to load unread reactions",
+            });
+        }
+    }
+}
@@ -1,6 +1,6 @@
-use crate::usecases::background::TaskDispatcher;
+use crate::{domain::chat_action::ChatAction, usecases::background::TaskDispatcher};
 
-use super::OrchestratorCtx;
+use super::{chat_actions, OrchestratorCtx};
 
 pub(super) fn start_voice_recording<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>) {
     use crate::usecases::voice_recording;
@@ -20,6 +20,7 @@ pub(super) fn start_voice_recording<D: TaskDispatcher>(ctx: &mut OrchestratorCtx
                 "Recording Voice",
                 crate::domain::command_popup_state::CommandPopupKind::Recording,
             );
+            chat_actions::send_chat_action(ctx, ChatAction::RecordingVoiceNote);
             tracing::info!("voice recording started");
         }
         Err(err) => {
@@ -28,6 +29,19 @@ pub(super) fn start_voice_recording<D: TaskDispatcher>(ctx: &mut OrchestratorCtx
     }
 }
 
+/// Keeps the "recording voice" action alive while the recorder runs.
+/// Called on every tick; the chat action throttle limits the resend rate.
+pub(super) fn refresh_recording_action<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>) {
+    use crate::domain::command_popup_state::{CommandPhase, CommandPopupKind};
+
+    let recording = ctx.state.command_popup().is_some_and(|popup| {
+        popup.kind() == CommandPopupKind::Recording && *popup.phase() == CommandPhase::Running
+    });
+    if recording {
+        chat_actions::send_chat_action(ctx, ChatAction::RecordingVoiceNote);
+    }
+}
+
 /// Stops the recording process in a background thread to avoid blocking the UI.
 ///
 /// The handle is moved to the thread, which calls `stop()` and then drops it.
@@ -125,6 +139,7 @@ pub(super) fn handle_command_exited<D: TaskDispatcher>(
         }
         CommandPopupKind::Recording => match phase {
             CommandPhase::Running => {
+                chat_actions::cancel_chat_action(ctx);
                 let process_succeeded = ctx
                     .recording_handle
                     .as_mut()
@@ -148,6 +163,7 @@ pub(super) fn handle_command_exited<D: TaskDispatcher>(
                 }
             }
             CommandPhase::Stopping => {
+                chat_actions::cancel_chat_action(ctx);
                 let file_ok = ctx
                     .recording_file_path
                     .as_ref()
@@ -199,6 +215,10 @@ pub(super) fn send_voice_recording<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<
     let topic_id = ctx.state.open_chat().topic_id();
     ctx.dispatcher
         .dispatch_send_voice(chat_id, topic_id, file_path);
+
+    // Announce the upload once; Telegram clears it when the message arrives.
+    chat_actions::send_chat_action(ctx, ChatAction::UploadingVoiceNote);
+    ctx.chat_action_throttle.reset();
 }
 
 /// Stops the playback process immediately. Unlike recording stop,
@@ -216,6 +236,7 @@ pub(super) fn stop_playback<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>)
 }
 
 pub(super) fn discard_voice_recording<D: TaskDispatcher>(ctx: &mut OrchestratorCtx<'_, D>) {
+    chat_actions::cancel_chat_action(ctx);
     if let Some(file_path) = ctx.recording_file_path.take() {
         let _ = std::fs::remove_file(&file_path);
         tracing::info!(file_path, "voice recording discarded");